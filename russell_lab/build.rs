@@ -1,3 +1,6 @@
 fn main() {
-    cc::Build::new().file("c_code/math_functions.c").compile("c_code");
+    // there is no C math library on wasm32; thus, the pure-Rust libm crate is used instead
+    if std::env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default() != "wasm32" {
+        cc::Build::new().file("c_code/math_functions.c").compile("c_code");
+    }
 }
@@ -0,0 +1,140 @@
+//! Implements the traits of the approx crate for Vector and Matrix (requires the `approx` feature)
+//!
+//! Two vectors (or matrices) are approximately equal if they have the same dimensions and
+//! all pairs of components are approximately equal. Vectors (or matrices) with different
+//! dimensions are never equal.
+
+use crate::{Matrix, Vector};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl AbsDiffEq for Vector {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.dim() == other.dim()
+            && self
+                .as_data()
+                .iter()
+                .zip(other.as_data())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for Vector {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.dim() == other.dim()
+            && self
+                .as_data()
+                .iter()
+                .zip(other.as_data())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for Vector {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+        self.dim() == other.dim()
+            && self
+                .as_data()
+                .iter()
+                .zip(other.as_data())
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+impl AbsDiffEq for Matrix {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.dims() == other.dims()
+            && self
+                .as_data()
+                .iter()
+                .zip(other.as_data())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl RelativeEq for Matrix {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.dims() == other.dims()
+            && self
+                .as_data()
+                .iter()
+                .zip(other.as_data())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl UlpsEq for Matrix {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+        self.dims() == other.dims()
+            && self
+                .as_data()
+                .iter()
+                .zip(other.as_data())
+                .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Vector};
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, AbsDiffEq, RelativeEq};
+
+    #[test]
+    fn vector_approx_works() {
+        let u = Vector::from(&[1.0, 2.0, 3.0]);
+        let v = Vector::from(&[1.0, 2.0, 3.0 + 1e-12]);
+        assert_abs_diff_eq!(u, v, epsilon = 1e-11);
+        assert_relative_eq!(u, v, max_relative = 1e-12);
+        assert_ulps_eq!(u, u.clone());
+        assert!(!u.abs_diff_eq(&v, 1e-13));
+        assert!(!u.relative_eq(&v, 1e-15, 1e-14));
+        let w = Vector::from(&[1.0, 2.0]);
+        assert!(!u.abs_diff_eq(&w, 1.0));
+    }
+
+    #[test]
+    fn matrix_approx_works() {
+        let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from(&[[1.0, 2.0], [3.0, 4.0 + 1e-12]]);
+        assert_abs_diff_eq!(a, b, epsilon = 1e-11);
+        assert_relative_eq!(a, b, max_relative = 1e-12);
+        assert_ulps_eq!(a, a.clone());
+        assert!(!a.abs_diff_eq(&b, 1e-13));
+
+        // same data with different shapes
+        let c = Matrix::from(&[[1.0, 3.0, 2.0, 4.0]]);
+        let d = Matrix::from(&[[1.0], [3.0], [2.0], [4.0]]);
+        assert_eq!(c.as_data(), a.as_data());
+        assert!(!a.abs_diff_eq(&c, 1.0));
+        assert!(!c.relative_eq(&d, 1.0, 1.0));
+    }
+}
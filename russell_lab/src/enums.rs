@@ -1,4 +1,7 @@
 /// Options to compute vector and matrix norms
+///
+/// This option is shared by vectors, dense matrices, and sparse matrices (e.g., in russell_sparse).
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Norm {
     /// Specifies the Euclidean-norm
     ///
@@ -68,4 +71,162 @@ pub enum Norm {
     /// ‖u‖_1 := sum_i |uᵢ|
     /// ```
     One,
+
+    /// Specifies the p-norm with p ≥ 1 (p may be infinity)
+    ///
+    /// **Note:** The dense norm functions (e.g., `vec_norm`) panic if `p < 1` or `p` is NaN.
+    ///
+    /// **matrix**
+    ///
+    /// Will compute the entry-wise p-norm (i.e., the matrix is treated as a vector)
+    ///
+    /// ```text
+    /// ‖a‖_p = (Σ_i Σ_j |aᵢⱼ|ᵖ)^(1/p)
+    /// ```
+    ///
+    /// **vector**
+    ///
+    /// ```text
+    /// ‖u‖_p = (Σ_i |uᵢ|ᵖ)^(1/p)
+    /// ```
+    ///
+    /// **Note:** `P(1.0)`, `P(2.0)`, and `P(f64::INFINITY)` are equivalent to [Norm::One], [Norm::Euc], and [Norm::Max].
+    P(f64),
+}
+
+/// Defines the kind of one-dimensional interpolation (see [crate::Interp1d])
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterpKind {
+    /// Piecewise-linear interpolation (C⁰ continuous)
+    Linear,
+
+    /// Piecewise cubic Hermite interpolation with monotone slopes (C¹ continuous)
+    ///
+    /// The slopes are computed with the Fritsch-Carlson method; thus, the interpolant
+    /// does not overshoot the data and preserves monotonicity (e.g., of tabulated material curves).
+    Pchip,
+}
+
+/// Defines how to evaluate an interpolant outside the range of the data (see [crate::Interp1d])
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extrapolation {
+    /// Returns an error if x is outside the data range
+    Error,
+
+    /// Returns the value at the nearest end point (the derivative is zero)
+    Clamp,
+
+    /// Extends the interpolant linearly using the value and derivative at the nearest end point
+    Linear,
+}
+
+/// Defines how to balance a general matrix before the eigen-decomposition (see [crate::mat_balance])
+///
+/// Balancing applies a similarity transformation `a := D⁻¹ ⋅ Pᵀ ⋅ a ⋅ P ⋅ D`, where `P` is a permutation
+/// matrix and `D` is a diagonal matrix (of powers of two), such that the rows and columns of the result
+/// have similar norms. This improves the accuracy of the eigenvalues of badly scaled matrices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Balancing {
+    /// Does not balance the matrix
+    None,
+
+    /// Permutes only (isolates eigenvalues accessible without computations)
+    Permute,
+
+    /// Scales only (makes the norms of rows and columns as close as possible)
+    Scale,
+
+    /// Permutes and scales
+    Both,
+}
+
+/// Defines the algorithm to accumulate a sum of floating-point numbers (see [crate::vec_sum])
+///
+/// The compensated algorithms carry a running correction for the rounding error of each addition;
+/// thus, the error does not grow with the number of terms (e.g., when accumulating energy norms
+/// over millions of elements).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Summation {
+    /// Adds the terms in sequence (the error bound grows linearly with the number of terms)
+    Naive,
+
+    /// Kahan's compensated summation
+    ///
+    /// The error bound does not depend on the number of terms, unless the terms have
+    /// very different magnitudes and opposite signs (e.g., `[1, 1e100, 1, -1e100]`).
+    Kahan,
+
+    /// Neumaier's improved Kahan-Babuška summation
+    ///
+    /// Also handles terms larger in magnitude than the running sum.
+    Neumaier,
+}
+
+/// Panics if the norm is `Norm::P(p)` with `p < 1` or `p = NaN`
+///
+/// With `p < 1`, the "norm" is not a norm (the triangle inequality does not hold).
+pub(crate) fn assert_valid_norm(kind: Norm) {
+    if let Norm::P(p) = kind {
+        assert!(p >= 1.0, "the p-norm requires p ≥ 1");
+    }
+}
+
+/// Computes the p-norm given the absolute values of the components
+///
+/// The components are scaled by the maximum absolute value to avoid overflow/underflow.
+pub(crate) fn p_norm_of_abs<I>(abs_values: I, p: f64) -> f64
+where
+    I: Iterator<Item = f64> + Clone,
+{
+    let max = abs_values.clone().fold(0.0, f64::max);
+    if max == 0.0 || p == f64::INFINITY {
+        return max;
+    }
+    let sum: f64 = abs_values.map(|x| f64::powf(x / max, p)).sum();
+    max * f64::powf(sum, 1.0 / p)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{p_norm_of_abs, Balancing, Extrapolation, InterpKind, Norm, Summation};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn clone_copy_and_debug_work() {
+        let norm = Norm::P(3.0);
+        let copy = norm;
+        assert_eq!(format!("{:?}", norm), "P(3.0)");
+        assert_eq!(copy, Norm::P(3.0));
+        let kind = InterpKind::Pchip;
+        let copy = kind;
+        assert_eq!(format!("{:?}", kind), "Pchip");
+        assert_eq!(copy, InterpKind::Pchip);
+        let extrapolation = Extrapolation::Clamp;
+        let copy = extrapolation;
+        assert_eq!(format!("{:?}", extrapolation), "Clamp");
+        assert_eq!(copy, Extrapolation::Clamp);
+        let balancing = Balancing::Both;
+        let copy = balancing;
+        assert_eq!(format!("{:?}", balancing), "Both");
+        assert_eq!(copy, Balancing::Both);
+        let summation = Summation::Neumaier;
+        let copy = summation;
+        assert_eq!(format!("{:?}", summation), "Neumaier");
+        assert_eq!(copy, Summation::Neumaier);
+    }
+
+    #[test]
+    fn p_norm_of_abs_works() {
+        let empty: [f64; 0] = [];
+        assert_eq!(p_norm_of_abs(empty.iter().copied(), 2.0), 0.0);
+        let values = [3.0, 4.0];
+        assert_eq!(p_norm_of_abs(values.iter().copied(), 1.0), 7.0);
+        approx_eq(p_norm_of_abs(values.iter().copied(), 2.0), 5.0, 1e-15);
+        approx_eq(p_norm_of_abs(values.iter().copied(), 3.0), f64::cbrt(91.0), 1e-14);
+        assert_eq!(p_norm_of_abs(values.iter().copied(), f64::INFINITY), 4.0);
+        let huge = [3e300, 4e300];
+        approx_eq(p_norm_of_abs(huge.iter().copied(), 2.0) / 1e300, 5.0, 1e-14);
+    }
 }
@@ -153,7 +153,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::{generate2d, generate3d};
-    use russell_openblas::col_major;
+    use crate::kernels::col_major;
 
     #[test]
     fn generate2d_edge_cases_work() {
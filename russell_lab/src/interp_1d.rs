@@ -0,0 +1,344 @@
+use crate::{Extrapolation, InterpKind, StrError, Vector};
+use std::cmp::Ordering;
+
+/// Implements one-dimensional interpolation of tabulated data
+///
+/// The data points `(xᵢ, yᵢ)` must have strictly increasing `xᵢ`. The interpolant is
+/// either piecewise-linear ([InterpKind::Linear]) or a monotone piecewise cubic
+/// Hermite polynomial ([InterpKind::Pchip]).
+///
+/// The behavior outside `[x₀, xₙ₋₁]` is explicitly given by an [Extrapolation] policy;
+/// with [Extrapolation::Error], evaluating outside the range returns an error instead
+/// of silently extrapolating.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Extrapolation, Interp1d, InterpKind, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = Vector::from(&[0.0, 1.0, 2.0, 3.0]);
+///     let y = Vector::from(&[0.0, 1.0, 1.0, 2.0]);
+///     let interp = Interp1d::new(InterpKind::Pchip, Extrapolation::Error, &x, &y)?;
+///     assert_eq!(interp.eval(1.0)?, 1.0);
+///     assert_eq!(interp.eval(1.5)?, 1.0); // no overshoot on the flat segment
+///     assert_eq!(interp.deriv(1.5)?, 0.0);
+///     assert_eq!(interp.eval(3.5).err(), Some("x is outside the interpolation range"));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Interp1d {
+    kind: InterpKind,
+    extrapolation: Extrapolation,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    d: Vec<f64>, // derivatives at the data points
+}
+
+impl Interp1d {
+    /// Allocates a new instance
+    ///
+    /// # Input
+    ///
+    /// * `kind` -- the kind of interpolant
+    /// * `extrapolation` -- the policy for evaluating outside the data range
+    /// * `x` -- the (strictly increasing) abscissae; at least 2 points
+    /// * `y` -- the ordinates
+    pub fn new(kind: InterpKind, extrapolation: Extrapolation, x: &Vector, y: &Vector) -> Result<Self, StrError> {
+        let n = x.dim();
+        if n < 2 {
+            return Err("at least 2 points are required");
+        }
+        if y.dim() != n {
+            return Err("x and y vectors must have the same dimension");
+        }
+        for i in 1..n {
+            if x[i].partial_cmp(&x[i - 1]) != Some(Ordering::Greater) {
+                return Err("x values must be strictly increasing");
+            }
+        }
+        let x = x.as_data().clone();
+        let y = y.as_data().clone();
+        let d = match kind {
+            InterpKind::Linear => linear_slopes(&x, &y),
+            InterpKind::Pchip => pchip_slopes(&x, &y),
+        };
+        Ok(Interp1d {
+            kind,
+            extrapolation,
+            x,
+            y,
+            d,
+        })
+    }
+
+    /// Returns the kind of interpolant
+    pub fn kind(&self) -> InterpKind {
+        self.kind
+    }
+
+    /// Returns the extrapolation policy
+    pub fn extrapolation(&self) -> Extrapolation {
+        self.extrapolation
+    }
+
+    /// Sets the extrapolation policy
+    pub fn set_extrapolation(&mut self, extrapolation: Extrapolation) -> &mut Self {
+        self.extrapolation = extrapolation;
+        self
+    }
+
+    /// Evaluates the interpolant at x
+    pub fn eval(&self, x: f64) -> Result<f64, StrError> {
+        if let Some((x_end, y_end, d_end)) = self.end_point(x)? {
+            return Ok(match self.extrapolation {
+                Extrapolation::Clamp => y_end,
+                _ => y_end + d_end * (x - x_end),
+            });
+        }
+        let k = self.find_interval(x);
+        let h = self.x[k + 1] - self.x[k];
+        let t = (x - self.x[k]) / h;
+        match self.kind {
+            InterpKind::Linear => Ok(self.y[k] + t * (self.y[k + 1] - self.y[k])),
+            InterpKind::Pchip => {
+                let (t2, t3) = (t * t, t * t * t);
+                let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+                let h10 = t3 - 2.0 * t2 + t;
+                let h01 = -2.0 * t3 + 3.0 * t2;
+                let h11 = t3 - t2;
+                Ok(h00 * self.y[k] + h10 * h * self.d[k] + h01 * self.y[k + 1] + h11 * h * self.d[k + 1])
+            }
+        }
+    }
+
+    /// Evaluates the derivative of the interpolant at x
+    ///
+    /// **Note:** For [InterpKind::Linear], the derivative at an interior data point
+    /// is the slope of the segment on the right-hand side.
+    pub fn deriv(&self, x: f64) -> Result<f64, StrError> {
+        if let Some((_, _, d_end)) = self.end_point(x)? {
+            return Ok(match self.extrapolation {
+                Extrapolation::Clamp => 0.0,
+                _ => d_end,
+            });
+        }
+        let k = self.find_interval(x);
+        let h = self.x[k + 1] - self.x[k];
+        match self.kind {
+            InterpKind::Linear => Ok((self.y[k + 1] - self.y[k]) / h),
+            InterpKind::Pchip => {
+                let t = (x - self.x[k]) / h;
+                let t2 = t * t;
+                let dh00 = 6.0 * t2 - 6.0 * t;
+                let dh10 = 3.0 * t2 - 4.0 * t + 1.0;
+                let dh01 = -6.0 * t2 + 6.0 * t;
+                let dh11 = 3.0 * t2 - 2.0 * t;
+                Ok((dh00 * self.y[k] + dh01 * self.y[k + 1]) / h + dh10 * self.d[k] + dh11 * self.d[k + 1])
+            }
+        }
+    }
+
+    /// Returns the (x, y, dy/dx) of the nearest end point if x is outside the data range
+    ///
+    /// Returns an error if x is NaN or if x is outside the range and the policy is [Extrapolation::Error].
+    fn end_point(&self, x: f64) -> Result<Option<(f64, f64, f64)>, StrError> {
+        if x.is_nan() {
+            return Err("x must not be NaN");
+        }
+        let n = self.x.len();
+        let k = if x < self.x[0] {
+            0
+        } else if x > self.x[n - 1] {
+            n - 1
+        } else {
+            return Ok(None);
+        };
+        if self.extrapolation == Extrapolation::Error {
+            return Err("x is outside the interpolation range");
+        }
+        Ok(Some((self.x[k], self.y[k], self.d[k])))
+    }
+
+    /// Returns the index k of the interval [xₖ, xₖ₊₁] containing x (x must be within the range)
+    fn find_interval(&self, x: f64) -> usize {
+        let n = self.x.len();
+        let k = self.x.partition_point(|&xi| xi <= x);
+        usize::min(k.saturating_sub(1), n - 2)
+    }
+}
+
+/// Computes the derivatives at the data points of the piecewise-linear interpolant
+///
+/// The end points take the slope of the adjacent segment; the interior points
+/// take the slope of the segment on the right-hand side.
+fn linear_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let mut d = vec![0.0; n];
+    for k in 0..(n - 1) {
+        d[k] = (y[k + 1] - y[k]) / (x[k + 1] - x[k]);
+    }
+    d[n - 1] = d[n - 2];
+    d
+}
+
+/// Computes the derivatives at the data points with the Fritsch-Carlson method
+///
+/// ```text
+/// hₖ = xₖ₊₁ - xₖ    δₖ = (yₖ₊₁ - yₖ) / hₖ
+///
+/// dₖ = 0                                   if δₖ₋₁ ⋅ δₖ ≤ 0
+/// dₖ = (w₁ + w₂) / (w₁/δₖ₋₁ + w₂/δₖ)        otherwise
+///
+/// w₁ = 2hₖ + hₖ₋₁    w₂ = hₖ + 2hₖ₋₁
+/// ```
+///
+/// The end points use a one-sided three-point formula, limited to preserve the shape.
+fn pchip_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let h: Vec<f64> = (0..(n - 1)).map(|k| x[k + 1] - x[k]).collect();
+    let delta: Vec<f64> = (0..(n - 1)).map(|k| (y[k + 1] - y[k]) / h[k]).collect();
+    let mut d = vec![0.0; n];
+    if n == 2 {
+        d[0] = delta[0];
+        d[1] = delta[0];
+        return d;
+    }
+    for k in 1..(n - 1) {
+        if delta[k - 1] * delta[k] > 0.0 {
+            let w1 = 2.0 * h[k] + h[k - 1];
+            let w2 = h[k] + 2.0 * h[k - 1];
+            d[k] = (w1 + w2) / (w1 / delta[k - 1] + w2 / delta[k]);
+        }
+    }
+    d[0] = pchip_end_slope(h[0], h[1], delta[0], delta[1]);
+    d[n - 1] = pchip_end_slope(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+    d
+}
+
+/// Computes the shape-preserving one-sided three-point estimate of the derivative at an end point
+fn pchip_end_slope(h0: f64, h1: f64, delta0: f64, delta1: f64) -> f64 {
+    let d = ((2.0 * h0 + h1) * delta0 - h0 * delta1) / (h0 + h1);
+    if d * delta0 <= 0.0 {
+        0.0
+    } else if delta0 * delta1 <= 0.0 && f64::abs(d) > f64::abs(3.0 * delta0) {
+        3.0 * delta0
+    } else {
+        d
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Interp1d;
+    use crate::{Extrapolation, InterpKind, Vector};
+    use russell_chk::{approx_eq, deriv_approx_eq};
+
+    #[test]
+    fn new_fails_on_wrong_input() {
+        let k = InterpKind::Linear;
+        let e = Extrapolation::Error;
+        let x = Vector::from(&[0.0, 1.0, 2.0]);
+        let y = Vector::from(&[0.0, 1.0, 2.0]);
+        assert_eq!(
+            Interp1d::new(k, e, &Vector::new(1), &Vector::new(1)).err(),
+            Some("at least 2 points are required")
+        );
+        assert_eq!(
+            Interp1d::new(k, e, &x, &Vector::new(2)).err(),
+            Some("x and y vectors must have the same dimension")
+        );
+        assert_eq!(
+            Interp1d::new(k, e, &Vector::from(&[0.0, 1.0, 1.0]), &y).err(),
+            Some("x values must be strictly increasing")
+        );
+        assert_eq!(
+            Interp1d::new(k, e, &Vector::from(&[0.0, f64::NAN, 1.0]), &y).err(),
+            Some("x values must be strictly increasing")
+        );
+    }
+
+    #[test]
+    fn linear_works() {
+        let x = Vector::from(&[0.0, 1.0, 3.0]);
+        let y = Vector::from(&[1.0, 3.0, 2.0]);
+        let interp = Interp1d::new(InterpKind::Linear, Extrapolation::Error, &x, &y).unwrap();
+        assert_eq!(interp.kind(), InterpKind::Linear);
+        assert_eq!(interp.eval(0.0).unwrap(), 1.0);
+        assert_eq!(interp.eval(0.5).unwrap(), 2.0);
+        assert_eq!(interp.eval(1.0).unwrap(), 3.0);
+        assert_eq!(interp.eval(2.0).unwrap(), 2.5);
+        assert_eq!(interp.eval(3.0).unwrap(), 2.0);
+        assert_eq!(interp.deriv(0.5).unwrap(), 2.0);
+        assert_eq!(interp.deriv(1.0).unwrap(), -0.5);
+        assert_eq!(interp.deriv(3.0).unwrap(), -0.5);
+    }
+
+    #[test]
+    fn extrapolation_works() {
+        let x = Vector::from(&[0.0, 1.0, 3.0]);
+        let y = Vector::from(&[1.0, 3.0, 2.0]);
+        let mut interp = Interp1d::new(InterpKind::Linear, Extrapolation::Error, &x, &y).unwrap();
+        assert_eq!(interp.extrapolation(), Extrapolation::Error);
+        assert_eq!(interp.eval(-0.1).err(), Some("x is outside the interpolation range"));
+        assert_eq!(interp.deriv(3.1).err(), Some("x is outside the interpolation range"));
+        assert_eq!(interp.eval(f64::NAN).err(), Some("x must not be NaN"));
+
+        interp.set_extrapolation(Extrapolation::Clamp);
+        assert_eq!(interp.eval(-1.0).unwrap(), 1.0);
+        assert_eq!(interp.eval(5.0).unwrap(), 2.0);
+        assert_eq!(interp.deriv(-1.0).unwrap(), 0.0);
+        assert_eq!(interp.deriv(5.0).unwrap(), 0.0);
+
+        interp.set_extrapolation(Extrapolation::Linear);
+        assert_eq!(interp.eval(-1.0).unwrap(), -1.0);
+        assert_eq!(interp.eval(5.0).unwrap(), 1.0);
+        assert_eq!(interp.deriv(-1.0).unwrap(), 2.0);
+        assert_eq!(interp.deriv(5.0).unwrap(), -0.5);
+        assert_eq!(interp.eval(f64::NAN).err(), Some("x must not be NaN"));
+    }
+
+    #[test]
+    fn pchip_two_points_is_linear() {
+        let x = Vector::from(&[1.0, 3.0]);
+        let y = Vector::from(&[0.0, 4.0]);
+        let interp = Interp1d::new(InterpKind::Pchip, Extrapolation::Linear, &x, &y).unwrap();
+        approx_eq(interp.eval(2.0).unwrap(), 2.0, 1e-15);
+        approx_eq(interp.eval(4.0).unwrap(), 6.0, 1e-15);
+        approx_eq(interp.deriv(1.5).unwrap(), 2.0, 1e-15);
+    }
+
+    #[test]
+    fn pchip_preserves_monotonicity() {
+        // step-like data: a regular cubic spline would overshoot
+        let x = Vector::from(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        let y = Vector::from(&[0.0, 0.0, 0.1, 1.0, 1.0, 1.0]);
+        let interp = Interp1d::new(InterpKind::Pchip, Extrapolation::Error, &x, &y).unwrap();
+        let mut previous = interp.eval(0.0).unwrap();
+        for i in 1..=500 {
+            let xi = (i as f64) / 100.0;
+            let yi = interp.eval(xi).unwrap();
+            assert!(yi >= previous - 1e-15);
+            assert!((0.0..=1.0).contains(&yi));
+            previous = yi;
+        }
+        for i in 0..6 {
+            assert_eq!(interp.eval(x[i]).unwrap(), y[i]);
+        }
+        assert_eq!(interp.deriv(4.5).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn pchip_deriv_works() {
+        let x = Vector::from(&[0.0, 0.5, 1.5, 2.0, 3.5]);
+        let y = Vector::from(&[0.0, 0.25, 2.25, 4.0, 12.25]);
+        let interp = Interp1d::new(InterpKind::Pchip, Extrapolation::Error, &x, &y).unwrap();
+        for xi in [0.1, 0.7, 1.2, 1.9, 2.5, 3.3] {
+            let d = interp.deriv(xi).unwrap();
+            deriv_approx_eq(d, xi, &mut (), 1e-9, |t, _| interp.eval(t).unwrap());
+        }
+    }
+}
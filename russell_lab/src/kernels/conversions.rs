@@ -0,0 +1,74 @@
+use std::convert::TryFrom;
+
+/// Converts number to i32
+#[inline]
+pub fn to_i32(num: usize) -> i32 {
+    i32::try_from(num).unwrap()
+}
+
+/// Converts a non-negative BLAS dimension to usize (negative values become zero)
+#[inline]
+pub(super) fn to_len(n: i32) -> usize {
+    if n > 0 {
+        n as usize
+    } else {
+        0
+    }
+}
+
+/// Returns the position of the i-th component of an array with n components and increment inc
+///
+/// ```text
+/// inc > 0:  x[0], x[inc], x[2⋅inc], ..., x[(n-1)⋅inc]
+/// inc < 0:  x[(n-1)⋅|inc|], ..., x[|inc|], x[0]   (reversed)
+/// ```
+#[inline]
+pub(super) fn position(i: usize, n: usize, inc: i32) -> usize {
+    let s = inc.unsigned_abs() as usize;
+    if inc >= 0 {
+        i * s
+    } else {
+        (n - 1 - i) * s
+    }
+}
+
+/// Converts an array from row-major to col-major
+#[cfg(test)]
+pub fn col_major(m: usize, n: usize, row_major: &[f64]) -> Vec<f64> {
+    let mut out = vec![0.0; m * n];
+    for i in 0..m {
+        for j in 0..n {
+            out[i + j * m] = row_major[i * n + j];
+        }
+    }
+    out
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{col_major, position, to_i32, to_len};
+
+    #[test]
+    fn to_i32_and_to_len_work() {
+        assert_eq!(to_i32(3), 3);
+        assert_eq!(to_len(3), 3);
+        assert_eq!(to_len(0), 0);
+        assert_eq!(to_len(-2), 0);
+    }
+
+    #[test]
+    fn position_works() {
+        assert_eq!((0..3).map(|i| position(i, 3, 2)).collect::<Vec<_>>(), &[0, 2, 4]);
+        assert_eq!((0..3).map(|i| position(i, 3, -2)).collect::<Vec<_>>(), &[4, 2, 0]);
+    }
+
+    #[test]
+    fn col_major_works() {
+        assert_eq!(
+            col_major(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+            &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]
+        );
+    }
+}
@@ -0,0 +1,283 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+// dimensions of the micro-kernel (MR×NR block of c kept in registers)
+const MR: usize = 8;
+const NR: usize = 4;
+
+// dimensions of the blocks of a (MC×KC, kept in the L2 cache) and b (KC×NC, kept in the L3 cache)
+const MC: usize = 128;
+const KC: usize = 256;
+const NC: usize = 2048;
+
+// minimum m⋅n⋅k to use more than one thread
+#[cfg(feature = "rayon")]
+const MIN_WORK_PARALLEL: usize = 128 * 128 * 128;
+
+/// Performs the matrix-matrix multiplication with a cache-blocked (and possibly multithreaded) algorithm
+///
+/// ```text
+///   c  := α ⋅ op(a) ⋅ op(b) + β ⋅ c
+/// (m,n)       (m,k)   (k,n)       (m,n)
+/// ```
+///
+/// where `op(a) = aᵀ` if trans_a == true and `op(b) = bᵀ` if trans_b == true.
+///
+/// # Algorithm
+///
+/// The matrix c is split into panels of columns. For each panel, blocks of op(b) and op(a)
+/// are copied ("packed") into contiguous buffers such that a micro-kernel computes MR×NR
+/// blocks of c with unit-stride accesses only. The scaling by α is applied while packing a.
+///
+/// With the `rayon` feature, the panels of c are computed in parallel; each panel is
+/// computed with the same sequence of operations regardless of the number of threads.
+///
+/// **Note:** The data must be in **col-major** order
+#[allow(clippy::too_many_arguments)]
+pub fn dgemm_blocked(
+    trans_a: bool,
+    trans_b: bool,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: f64,
+    a: &[f64],
+    b: &[f64],
+    beta: f64,
+    c: &mut [f64],
+) {
+    let c = &mut c[..m * n];
+    if beta == 0.0 {
+        c.fill(0.0);
+    } else if beta != 1.0 {
+        c.iter_mut().for_each(|cij| *cij *= beta);
+    }
+    if m == 0 || n == 0 || k == 0 || alpha == 0.0 {
+        return;
+    }
+    let op = Operands {
+        trans_a,
+        trans_b,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        b,
+    };
+    let nc = panel_width(m, n, k);
+    let compute_panel = |(index, c_panel): (usize, &mut [f64])| op.panel(index * nc, c_panel);
+    #[cfg(feature = "rayon")]
+    if m * n * k >= MIN_WORK_PARALLEL {
+        c.par_chunks_mut(m * nc).enumerate().for_each(compute_panel);
+        return;
+    }
+    c.chunks_mut(m * nc).enumerate().for_each(compute_panel);
+}
+
+/// Returns the number of columns of the panels of c
+#[allow(unused_variables)]
+fn panel_width(m: usize, n: usize, k: usize) -> usize {
+    #[cfg(feature = "rayon")]
+    if m * n * k >= MIN_WORK_PARALLEL {
+        // give at least one panel to each thread (panels are multiples of NR)
+        let nt = rayon::current_num_threads();
+        let width = n.div_ceil(nt);
+        return usize::min(NC, usize::max(NR, width.div_ceil(NR) * NR));
+    }
+    usize::min(NC, n)
+}
+
+/// Holds the (read-only) input of dgemm_blocked
+struct Operands<'a> {
+    trans_a: bool,
+    trans_b: bool,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: f64,
+    a: &'a [f64],
+    b: &'a [f64],
+}
+
+impl<'a> Operands<'a> {
+    /// Returns op(a)[i,p]
+    #[inline]
+    fn a(&self, i: usize, p: usize) -> f64 {
+        if self.trans_a {
+            self.a[p + i * self.k]
+        } else {
+            self.a[i + p * self.m]
+        }
+    }
+
+    /// Returns op(b)[p,j]
+    #[inline]
+    fn b(&self, p: usize, j: usize) -> f64 {
+        if self.trans_b {
+            self.b[j + p * self.n]
+        } else {
+            self.b[p + j * self.k]
+        }
+    }
+
+    /// Computes the panel of c starting at column j0
+    fn panel(&self, j0: usize, c_panel: &mut [f64]) {
+        let m = self.m;
+        let nc = c_panel.len() / m;
+        let n_sliver_b = nc.div_ceil(NR);
+        let mut a_packed = vec![0.0; usize::min(MC, m).div_ceil(MR) * MR * KC];
+        let mut b_packed = vec![0.0; n_sliver_b * NR * KC];
+        for p0 in (0..self.k).step_by(KC) {
+            let kc = usize::min(KC, self.k - p0);
+            self.pack_b(&mut b_packed, p0, kc, j0, nc);
+            for i0 in (0..m).step_by(MC) {
+                let mc = usize::min(MC, m - i0);
+                self.pack_a(&mut a_packed, i0, mc, p0, kc);
+                for jr in 0..n_sliver_b {
+                    let b_sliver = &b_packed[jr * NR * kc..(jr + 1) * NR * kc];
+                    let ncols = usize::min(NR, nc - jr * NR);
+                    for ir in 0..mc.div_ceil(MR) {
+                        let a_sliver = &a_packed[ir * MR * kc..(ir + 1) * MR * kc];
+                        let nrows = usize::min(MR, mc - ir * MR);
+                        let acc = micro_kernel(kc, a_sliver, b_sliver);
+                        for (jj, acc_col) in acc.iter().enumerate().take(ncols) {
+                            let start = i0 + ir * MR + (jr * NR + jj) * m;
+                            for (cij, aij) in c_panel[start..start + nrows].iter_mut().zip(acc_col) {
+                                *cij += aij;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Packs α⋅op(a)[i0..i0+mc, p0..p0+kc] into slivers of MR rows (padded with zeros)
+    fn pack_a(&self, packed: &mut [f64], i0: usize, mc: usize, p0: usize, kc: usize) {
+        for ir in 0..mc.div_ceil(MR) {
+            let sliver = &mut packed[ir * MR * kc..(ir + 1) * MR * kc];
+            let nrows = usize::min(MR, mc - ir * MR);
+            for p in 0..kc {
+                for ii in 0..MR {
+                    sliver[p * MR + ii] = if ii < nrows {
+                        self.alpha * self.a(i0 + ir * MR + ii, p0 + p)
+                    } else {
+                        0.0
+                    };
+                }
+            }
+        }
+    }
+
+    /// Packs op(b)[p0..p0+kc, j0..j0+nc] into slivers of NR columns (padded with zeros)
+    fn pack_b(&self, packed: &mut [f64], p0: usize, kc: usize, j0: usize, nc: usize) {
+        for jr in 0..nc.div_ceil(NR) {
+            let sliver = &mut packed[jr * NR * kc..(jr + 1) * NR * kc];
+            let ncols = usize::min(NR, nc - jr * NR);
+            for p in 0..kc {
+                for jj in 0..NR {
+                    sliver[p * NR + jj] = if jj < ncols {
+                        self.b(p0 + p, j0 + jr * NR + jj)
+                    } else {
+                        0.0
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// Computes the MR×NR block (column by column) given packed slivers of a (MR rows) and b (NR columns)
+#[inline(always)]
+fn micro_kernel(kc: usize, a_sliver: &[f64], b_sliver: &[f64]) -> [[f64; MR]; NR] {
+    let mut acc = [[0.0; MR]; NR];
+    for (a_p, b_p) in a_sliver.chunks_exact(MR).zip(b_sliver.chunks_exact(NR)).take(kc) {
+        for (acc_col, b_pj) in acc.iter_mut().zip(b_p) {
+            for (acc_ij, a_ip) in acc_col.iter_mut().zip(a_p) {
+                *acc_ij += a_ip * b_pj;
+            }
+        }
+    }
+    acc
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{dgemm_blocked, KC, MC, MR, NR};
+    use russell_chk::vec_approx_eq;
+
+    /// Computes the reference solution with the triple loop
+    fn reference(trans_a: bool, trans_b: bool, m: usize, n: usize, k: usize, a: &[f64], b: &[f64]) -> Vec<f64> {
+        let mut c = vec![0.0; m * n];
+        for i in 0..m {
+            for j in 0..n {
+                for p in 0..k {
+                    let aip = if trans_a { a[p + i * k] } else { a[i + p * m] };
+                    let bpj = if trans_b { b[j + p * n] } else { b[p + j * k] };
+                    c[i + j * m] += aip * bpj;
+                }
+            }
+        }
+        c
+    }
+
+    /// Returns some deterministic pseudo-random values in [-1, 1)
+    fn values(len: usize, seed: usize) -> Vec<f64> {
+        (0..len)
+            .map(|i| (((i * 7919 + seed * 104729) % 2003) as f64) / 1001.5 - 1.0)
+            .collect()
+    }
+
+    #[test]
+    fn dgemm_blocked_handles_edge_cases() {
+        // zero dimensions
+        let mut c = vec![1.0; 4];
+        dgemm_blocked(false, false, 2, 2, 0, 1.0, &[], &[], 0.5, &mut c);
+        assert_eq!(c, &[0.5, 0.5, 0.5, 0.5]);
+        dgemm_blocked(false, false, 0, 0, 2, 1.0, &[], &[], 0.0, &mut []);
+        // α = 0 and β = 0 with NaN in c
+        let mut c = vec![f64::NAN; 4];
+        dgemm_blocked(false, false, 2, 2, 1, 0.0, &[1.0, 1.0], &[1.0, 1.0], 0.0, &mut c);
+        assert_eq!(c, &[0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn dgemm_blocked_works() {
+        // the dimensions cross the boundaries of the blocks and micro-kernel
+        for (m, n, k) in [
+            (1, 1, 1),
+            (MR + 1, NR - 1, 3),
+            (MC + 9, 2 * NR + 1, KC + 7),
+            (5, 37, 2 * KC + 1),
+        ] {
+            for (trans_a, trans_b) in [(false, false), (true, false), (false, true), (true, true)] {
+                let a = values(m * k, 1);
+                let b = values(k * n, 2);
+                let c0 = values(m * n, 3);
+                let mut c = c0.clone();
+                dgemm_blocked(trans_a, trans_b, m, n, k, 2.0, &a, &b, -1.0, &mut c);
+                let ab = reference(trans_a, trans_b, m, n, k, &a, &b);
+                let correct: Vec<_> = ab.iter().zip(&c0).map(|(x, y)| 2.0 * x - y).collect();
+                vec_approx_eq(&c, &correct, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn dgemm_blocked_is_reproducible() {
+        let (m, n, k) = (150, 140, 130);
+        let a = values(m * k, 4);
+        let b = values(k * n, 5);
+        let mut first = vec![0.0; m * n];
+        dgemm_blocked(false, false, m, n, k, 1.0, &a, &b, 0.0, &mut first);
+        for _ in 0..3 {
+            let mut c = vec![0.0; m * n];
+            dgemm_blocked(false, false, m, n, k, 1.0, &a, &b, 0.0, &mut c);
+            assert_eq!(c, first);
+        }
+        vec_approx_eq(&first, &reference(false, false, m, n, k, &a, &b), 1e-12);
+    }
+}
@@ -0,0 +1,222 @@
+use super::conversions::to_len;
+use super::dgemm_blocked::dgemm_blocked;
+use super::vector::euclidean;
+use num_complex::Complex64;
+use num_traits::Num;
+
+// minimum m⋅n⋅k to use the cache-blocked algorithm (the packing is not worth it for small matrices)
+const MIN_WORK_BLOCKED: usize = 32 * 32 * 32;
+
+/// Performs the matrix-matrix multiplication (pure-Rust version of BLAS dgemm)
+///
+/// ```text
+///   c  := α ⋅ op(a) ⋅ op(b) + β ⋅ c
+/// (m,n)       (m,k)   (k,n)       (m,n)
+/// ```
+///
+/// where `op(a) = aᵀ` if trans_a == true and `op(b) = bᵀ` if trans_b == true.
+///
+/// Large matrices are multiplied by [dgemm_blocked] (multithreaded with the `rayon` feature).
+///
+/// **Note:** The data must be in **col-major** order
+#[allow(clippy::too_many_arguments)]
+pub fn dgemm(
+    trans_a: bool,
+    trans_b: bool,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: f64,
+    a: &[f64],
+    b: &[f64],
+    beta: f64,
+    c: &mut [f64],
+) {
+    let (mm, nn, kk) = (to_len(m), to_len(n), to_len(k));
+    if mm * nn * kk >= MIN_WORK_BLOCKED {
+        dgemm_blocked(trans_a, trans_b, mm, nn, kk, alpha, a, b, beta, c);
+    } else {
+        gemm(trans_a, trans_b, m, n, k, alpha, a, b, beta, c);
+    }
+}
+
+/// Performs the matrix-matrix multiplication (pure-Rust version of BLAS zgemm)
+///
+/// ```text
+///   c  := α ⋅ op(a) ⋅ op(b) + β ⋅ c
+/// (m,n)       (m,k)   (k,n)       (m,n)
+/// ```
+///
+/// where `op(a) = aᵀ` if trans_a == true and `op(b) = bᵀ` if trans_b == true.
+///
+/// **Note:** The data must be in **col-major** order
+#[allow(clippy::too_many_arguments)]
+pub fn zgemm(
+    trans_a: bool,
+    trans_b: bool,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: Complex64,
+    a: &[Complex64],
+    b: &[Complex64],
+    beta: Complex64,
+    c: &mut [Complex64],
+) {
+    gemm(trans_a, trans_b, m, n, k, alpha, a, b, beta, c);
+}
+
+/// Implements dgemm and zgemm
+#[allow(clippy::too_many_arguments)]
+fn gemm<T: Num + Copy>(
+    trans_a: bool,
+    trans_b: bool,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: &[T],
+    b: &[T],
+    beta: T,
+    c: &mut [T],
+) {
+    let (m, n, k) = (to_len(m), to_len(n), to_len(k));
+    for cij in c[..m * n].iter_mut() {
+        *cij = if beta.is_zero() { T::zero() } else { beta * *cij };
+    }
+    if alpha.is_zero() {
+        return;
+    }
+    let a_il = |i: usize, l: usize| if trans_a { a[l + i * k] } else { a[i + l * m] };
+    let b_lj = |l: usize, j: usize| if trans_b { b[j + l * n] } else { b[l + j * k] };
+    for j in 0..n {
+        for l in 0..k {
+            let t = alpha * b_lj(l, j);
+            if t.is_zero() {
+                continue;
+            }
+            for i in 0..m {
+                c[i + j * m] = c[i + j * m] + t * a_il(i, l);
+            }
+        }
+    }
+}
+
+/// Computes the matrix norm (pure-Rust version of LAPACK dlange)
+///
+/// # Input
+///
+/// * norm == b'1' -- computes the 1-norm (maximum column sum)
+/// * norm == b'I' -- computes the infinity-norm (maximum row sum)
+/// * norm == b'F' -- computes the Frobenius-norm (square root of sum of abs of squares)
+/// * norm == b'M' -- computes max(abs(a(i,j))). Note that this is not a consistent matrix norm
+///
+/// **Note:** The data must be in **col-major** order
+pub fn dlange(norm: u8, m: i32, n: i32, a: &[f64]) -> f64 {
+    lange(norm, to_len(m), to_len(n), a, f64::abs)
+}
+
+/// Computes the matrix norm (pure-Rust version of LAPACK zlange)
+///
+/// See [dlange] for the options.
+///
+/// **Note:** The data must be in **col-major** order
+pub fn zlange(norm: u8, m: i32, n: i32, a: &[Complex64]) -> f64 {
+    lange(norm, to_len(m), to_len(n), a, Complex64::norm)
+}
+
+/// Implements dlange and zlange given the function computing the absolute value
+fn lange<T: Copy>(norm: u8, m: usize, n: usize, a: &[T], abs: fn(T) -> f64) -> f64 {
+    if m == 0 || n == 0 {
+        return 0.0;
+    }
+    let a = &a[..m * n];
+    match norm.to_ascii_uppercase() {
+        b'M' => a.iter().fold(0.0, |acc, &x| f64::max(acc, abs(x))),
+        b'1' | b'O' => (0..n)
+            .map(|j| a[j * m..(j + 1) * m].iter().map(|&x| abs(x)).sum())
+            .fold(0.0, f64::max),
+        b'I' => (0..m)
+            .map(|i| (0..n).map(|j| abs(a[i + j * m])).sum())
+            .fold(0.0, f64::max),
+        _ => euclidean(a.iter().map(|&x| abs(x))),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{dgemm, dlange, zgemm, zlange};
+    use crate::kernels::col_major;
+    use num_complex::Complex64;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn dgemm_works() {
+        #[rustfmt::skip]
+        let a = col_major(2, 3, &[
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+        ]);
+        #[rustfmt::skip]
+        let b = col_major(3, 2, &[
+            1.0, 0.0,
+            0.0, 1.0,
+            1.0, 1.0,
+        ]);
+        // c := a b
+        let mut c = vec![f64::NAN; 4];
+        dgemm(false, false, 2, 2, 3, 1.0, &a, &b, 0.0, &mut c);
+        assert_eq!(c, col_major(2, 2, &[4.0, 5.0, 10.0, 11.0]));
+        // c := 2 bᵀ aᵀ + c
+        dgemm(true, true, 2, 2, 3, 2.0, &b, &a, 1.0, &mut c);
+        assert_eq!(c, col_major(2, 2, &[12.0, 25.0, 20.0, 33.0]));
+        // c := aᵀ a (3×3)
+        let mut c = vec![0.0; 9];
+        dgemm(true, false, 3, 3, 2, 1.0, &a, &a, 0.0, &mut c);
+        assert_eq!(
+            c,
+            col_major(3, 3, &[17.0, 22.0, 27.0, 22.0, 29.0, 36.0, 27.0, 36.0, 45.0])
+        );
+        // c := a aᵀ (2×2)
+        let mut c = vec![0.0; 4];
+        dgemm(false, true, 2, 2, 3, 1.0, &a, &a, 0.0, &mut c);
+        assert_eq!(c, col_major(2, 2, &[14.0, 32.0, 32.0, 77.0]));
+        // α = 0 only scales c
+        dgemm(false, true, 2, 2, 3, 0.0, &a, &a, 0.5, &mut c);
+        assert_eq!(c, col_major(2, 2, &[7.0, 16.0, 16.0, 38.5]));
+    }
+
+    #[test]
+    fn zgemm_works() {
+        let i = Complex64::new(0.0, 1.0);
+        let one = Complex64::new(1.0, 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+        // (1×2) ⋅ (2×1)
+        let a = [one, i];
+        let b = [i, one];
+        let mut c = [zero];
+        zgemm(false, false, 1, 1, 2, one, &a, &b, zero, &mut c);
+        assert_eq!(c, [Complex64::new(0.0, 2.0)]);
+    }
+
+    #[test]
+    fn dlange_and_zlange_work() {
+        #[rustfmt::skip]
+        let a = col_major(2, 3, &[
+            -1.0,  2.0, -3.0,
+             4.0, -5.0,  6.0,
+        ]);
+        assert_eq!(dlange(b'M', 2, 3, &a), 6.0);
+        assert_eq!(dlange(b'1', 2, 3, &a), 9.0);
+        assert_eq!(dlange(b'I', 2, 3, &a), 15.0);
+        approx_eq(dlange(b'F', 2, 3, &a), f64::sqrt(91.0), 1e-14);
+        assert_eq!(dlange(b'F', 0, 3, &a), 0.0);
+        let z = [Complex64::new(3.0, 4.0), Complex64::new(0.0, -1.0)];
+        assert_eq!(zlange(b'M', 1, 2, &z), 5.0);
+        assert_eq!(zlange(b'1', 1, 2, &z), 5.0);
+        assert_eq!(zlange(b'I', 1, 2, &z), 6.0);
+        approx_eq(zlange(b'F', 1, 2, &z), f64::sqrt(26.0), 1e-14);
+    }
+}
@@ -0,0 +1,216 @@
+use super::conversions::{position, to_len};
+use num_complex::Complex64;
+
+/// Performs the rank 1 operation (pure-Rust version of BLAS dger)
+///
+/// ```text
+///   a  :=  α ⋅  x  ⋅  yᵀ + a
+/// (m,n)       (m)   (n)   (m,n)
+/// ```
+///
+/// **Note:** The data must be in **col-major** order
+#[allow(clippy::too_many_arguments)]
+pub fn dger(m: i32, n: i32, alpha: f64, x: &[f64], incx: i32, y: &[f64], incy: i32, a: &mut [f64]) {
+    let (m, n) = (to_len(m), to_len(n));
+    for j in 0..n {
+        let t = alpha * y[position(j, n, incy)];
+        for i in 0..m {
+            a[i + j * m] += t * x[position(i, m, incx)];
+        }
+    }
+}
+
+/// Performs the symmetric rank 1 operation (pure-Rust version of BLAS dsyr)
+///
+/// ```text
+///   a  :=  α ⋅  x  ⋅  xᵀ + a
+/// (n,n)       (n)   (n)   (n,n)
+/// ```
+///
+/// Only the upper (if up == true) or lower triangle of `a` is updated.
+///
+/// **Note:** The data must be in **col-major** order
+pub fn dsyr(up: bool, n: i32, alpha: f64, x: &[f64], incx: i32, a: &mut [f64]) {
+    let n = to_len(n);
+    for j in 0..n {
+        let t = alpha * x[position(j, n, incx)];
+        let (start, end) = if up { (0, j + 1) } else { (j, n) };
+        for i in start..end {
+            a[i + j * n] += t * x[position(i, n, incx)];
+        }
+    }
+}
+
+/// Performs one of the matrix-vector multiplication (pure-Rust version of BLAS dgemv)
+///
+/// ```text
+/// trans = false:
+///
+///   y  := α ⋅  a  ⋅  x  +  β ⋅  y
+///  (m)       (m,n)  (n)        (m)
+///
+/// trans = true:
+///
+///   y  := α ⋅  aᵀ ⋅  x  +  β ⋅  y
+///  (n)       (n,m)  (m)        (n)
+/// ```
+///
+/// **Note:** The data must be in **col-major** order
+#[allow(clippy::too_many_arguments)]
+pub fn dgemv(
+    trans: bool,
+    m: i32,
+    n: i32,
+    alpha: f64,
+    a: &[f64],
+    x: &[f64],
+    incx: i32,
+    beta: f64,
+    y: &mut [f64],
+    incy: i32,
+) {
+    let (m, n) = (to_len(m), to_len(n));
+    let (len_x, len_y) = if trans { (m, n) } else { (n, m) };
+    for i in 0..len_y {
+        let k = position(i, len_y, incy);
+        y[k] = if beta == 0.0 { 0.0 } else { beta * y[k] };
+    }
+    if alpha == 0.0 {
+        return;
+    }
+    if trans {
+        for j in 0..n {
+            let column = &a[j * m..(j + 1) * m];
+            let sum: f64 = (0..m).map(|i| column[i] * x[position(i, len_x, incx)]).sum();
+            y[position(j, len_y, incy)] += alpha * sum;
+        }
+    } else {
+        for j in 0..n {
+            let t = alpha * x[position(j, len_x, incx)];
+            let column = &a[j * m..(j + 1) * m];
+            for i in 0..m {
+                y[position(i, len_y, incy)] += t * column[i];
+            }
+        }
+    }
+}
+
+/// Performs one of the matrix-vector multiplication (pure-Rust version of BLAS zgemv)
+///
+/// ```text
+/// trans = false:
+///
+///   y  := α ⋅  a  ⋅  x  +  β ⋅  y
+///  (m)       (m,n)  (n)        (m)
+///
+/// trans = true:
+///
+///   y  := α ⋅  aᵀ ⋅  x  +  β ⋅  y
+///  (n)       (n,m)  (m)        (n)
+/// ```
+///
+/// **Note:** The data must be in **col-major** order
+#[allow(clippy::too_many_arguments)]
+pub fn zgemv(
+    trans: bool,
+    m: i32,
+    n: i32,
+    alpha: Complex64,
+    a: &[Complex64],
+    x: &[Complex64],
+    incx: i32,
+    beta: Complex64,
+    y: &mut [Complex64],
+    incy: i32,
+) {
+    let zero = Complex64::new(0.0, 0.0);
+    let (m, n) = (to_len(m), to_len(n));
+    let (len_x, len_y) = if trans { (m, n) } else { (n, m) };
+    for i in 0..len_y {
+        let k = position(i, len_y, incy);
+        y[k] = if beta == zero { zero } else { beta * y[k] };
+    }
+    if alpha == zero {
+        return;
+    }
+    if trans {
+        for j in 0..n {
+            let column = &a[j * m..(j + 1) * m];
+            let sum: Complex64 = (0..m).map(|i| column[i] * x[position(i, len_x, incx)]).sum();
+            y[position(j, len_y, incy)] += alpha * sum;
+        }
+    } else {
+        for j in 0..n {
+            let t = alpha * x[position(j, len_x, incx)];
+            let column = &a[j * m..(j + 1) * m];
+            for i in 0..m {
+                y[position(i, len_y, incy)] += t * column[i];
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{dgemv, dger, dsyr, zgemv};
+    use crate::kernels::col_major;
+    use num_complex::Complex64;
+
+    #[test]
+    fn dger_works() {
+        // a := 0.5 x yᵀ + a
+        let mut a = col_major(2, 3, &[1.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
+        dger(2, 3, 0.5, &[2.0, 4.0], 1, &[1.0, 2.0, 3.0], 1, &mut a);
+        assert_eq!(a, col_major(2, 3, &[2.0, 3.0, 4.0, 3.0, 5.0, 7.0]));
+    }
+
+    #[test]
+    fn dsyr_works() {
+        let x = [1.0, 2.0, 3.0];
+        let mut up = vec![0.0; 9];
+        dsyr(true, 3, 1.0, &x, 1, &mut up);
+        assert_eq!(up, col_major(3, 3, &[1.0, 2.0, 3.0, 0.0, 4.0, 6.0, 0.0, 0.0, 9.0]));
+        let mut lo = vec![0.0; 9];
+        dsyr(false, 3, 1.0, &x, 1, &mut lo);
+        assert_eq!(lo, col_major(3, 3, &[1.0, 0.0, 0.0, 2.0, 4.0, 0.0, 3.0, 6.0, 9.0]));
+    }
+
+    #[test]
+    fn dgemv_works() {
+        #[rustfmt::skip]
+        let a = col_major(3, 2, &[
+            1.0, 2.0,
+            3.0, 4.0,
+            5.0, 6.0,
+        ]);
+        // y := 2 a x + 10 y
+        let mut y = [1.0, 1.0, 1.0];
+        dgemv(false, 3, 2, 2.0, &a, &[1.0, -1.0], 1, 10.0, &mut y, 1);
+        assert_eq!(y, [8.0, 8.0, 8.0]);
+        // y := aᵀ x (with NaN in y, which must be ignored since β = 0)
+        let mut y = [f64::NAN, f64::NAN];
+        dgemv(true, 3, 2, 1.0, &a, &[1.0, 0.0, 1.0], 1, 0.0, &mut y, 1);
+        assert_eq!(y, [6.0, 8.0]);
+        // strided x and y
+        let mut y = [0.0, 123.0, 0.0, 123.0, 0.0];
+        dgemv(false, 3, 2, 1.0, &a, &[1.0, 0.0, 1.0], 2, 0.0, &mut y, 2);
+        assert_eq!(y, [3.0, 123.0, 7.0, 123.0, 11.0]);
+    }
+
+    #[test]
+    fn zgemv_works() {
+        let (one, i) = (Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0));
+        // a = [[1, i], [2, 1 + i]] (col-major)
+        let a = [one, 2.0 * one, i, one + i];
+        // y := i a x + y
+        let mut y = [one, one];
+        zgemv(false, 2, 2, i, &a, &[one, -i], 1, one, &mut y, 1);
+        assert_eq!(y, [Complex64::new(1.0, 2.0), Complex64::new(2.0, 3.0)]);
+        // y := aᵀ x (with NaN in y, which must be ignored since β = 0)
+        let mut y = [Complex64::new(f64::NAN, 0.0); 2];
+        zgemv(true, 2, 2, one, &a, &[one, i], 1, Complex64::new(0.0, 0.0), &mut y, 1);
+        assert_eq!(y, [Complex64::new(1.0, 2.0), Complex64::new(-1.0, 2.0)]);
+    }
+}
@@ -0,0 +1,38 @@
+//! This module selects the BLAS-like kernels called by the vector and matrix functions
+//!
+//! With the `blas` feature (enabled by default), the kernels are the OpenBLAS wrappers of
+//! russell_openblas. Otherwise, the pure-Rust kernels of this module are used; they have the
+//! same signatures as the wrappers (col-major data and BLAS increments).
+
+#[cfg_attr(feature = "blas", allow(dead_code))]
+mod conversions;
+#[cfg_attr(feature = "blas", allow(dead_code))]
+mod dgemm_blocked;
+#[cfg_attr(feature = "blas", allow(dead_code))]
+mod matrix;
+#[cfg_attr(feature = "blas", allow(dead_code))]
+mod matvec;
+#[cfg_attr(feature = "blas", allow(dead_code))]
+mod vector;
+
+#[cfg(test)]
+pub(crate) use crate::kernels::conversions::col_major;
+
+#[cfg(feature = "blas")]
+pub(crate) use russell_openblas::{
+    add_vectors_native, add_vectors_oblas, complex_add_vectors_native, complex_add_vectors_oblas, dasum, daxpy, dcopy,
+    ddot, dgemm, dgemv, dger, dlange, dnrm2, dscal, dsyr, dznrm2, idamax, to_i32, zcopy, zgemm, zgemv, zlange, zscal,
+};
+
+#[cfg(not(feature = "blas"))]
+pub(crate) use crate::kernels::conversions::to_i32;
+#[cfg(not(feature = "blas"))]
+pub(crate) use crate::kernels::matrix::{dgemm, dlange, zgemm, zlange};
+#[cfg(not(feature = "blas"))]
+pub(crate) use crate::kernels::matvec::{dgemv, dger, dsyr, zgemv};
+#[cfg(not(feature = "blas"))]
+pub(crate) use crate::kernels::vector::{
+    add_vectors as add_vectors_native, add_vectors as add_vectors_oblas,
+    complex_add_vectors as complex_add_vectors_native, complex_add_vectors as complex_add_vectors_oblas, dasum, daxpy,
+    dcopy, ddot, dnrm2, dscal, dznrm2, idamax, zcopy, zscal,
+};
@@ -0,0 +1,305 @@
+use super::conversions::{position, to_len};
+use num_complex::Complex64;
+
+/// Computes the dot product (pure-Rust version of BLAS ddot)
+///
+/// ```text
+/// ddot := xᵀ ⋅ y
+/// ```
+pub fn ddot(n: i32, x: &[f64], incx: i32, y: &[f64], incy: i32) -> f64 {
+    let n = to_len(n);
+    if incx == 1 && incy == 1 {
+        return x[..n].iter().zip(&y[..n]).map(|(a, b)| a * b).sum();
+    }
+    (0..n).map(|i| x[position(i, n, incx)] * y[position(i, n, incy)]).sum()
+}
+
+/// Copies a vector into another (pure-Rust version of BLAS dcopy)
+///
+/// ```text
+/// y := x
+/// ```
+pub fn dcopy(n: i32, x: &[f64], incx: i32, y: &mut [f64], incy: i32) {
+    let n = to_len(n);
+    if incx == 1 && incy == 1 {
+        y[..n].copy_from_slice(&x[..n]);
+        return;
+    }
+    for i in 0..n {
+        y[position(i, n, incy)] = x[position(i, n, incx)];
+    }
+}
+
+/// Copies a vector into another (pure-Rust version of BLAS zcopy)
+///
+/// ```text
+/// y := x
+/// ```
+pub fn zcopy(n: i32, x: &[Complex64], incx: i32, y: &mut [Complex64], incy: i32) {
+    let n = to_len(n);
+    for i in 0..n {
+        y[position(i, n, incy)] = x[position(i, n, incx)];
+    }
+}
+
+/// Scales a vector by a constant (pure-Rust version of BLAS dscal)
+///
+/// ```text
+/// x := alpha * x
+/// ```
+///
+/// **Note:** Nothing is done if incx ≤ 0 (as in BLAS).
+pub fn dscal(n: i32, alpha: f64, x: &mut [f64], incx: i32) {
+    if incx <= 0 {
+        return;
+    }
+    let s = incx as usize;
+    for i in 0..to_len(n) {
+        x[i * s] *= alpha;
+    }
+}
+
+/// Scales a vector by a constant (pure-Rust version of BLAS zscal)
+///
+/// ```text
+/// x := alpha * x
+/// ```
+///
+/// **Note:** Nothing is done if incx ≤ 0 (as in BLAS).
+pub fn zscal(n: i32, alpha: Complex64, x: &mut [Complex64], incx: i32) {
+    if incx <= 0 {
+        return;
+    }
+    let s = incx as usize;
+    for i in 0..to_len(n) {
+        x[i * s] *= alpha;
+    }
+}
+
+/// Computes constant times a vector plus a vector (pure-Rust version of BLAS daxpy)
+///
+/// ```text
+/// y += alpha * x
+/// ```
+pub fn daxpy(n: i32, alpha: f64, x: &[f64], incx: i32, y: &mut [f64], incy: i32) {
+    if alpha == 0.0 {
+        return;
+    }
+    let n = to_len(n);
+    if incx == 1 && incy == 1 {
+        for (b, a) in y[..n].iter_mut().zip(&x[..n]) {
+            *b += alpha * a;
+        }
+        return;
+    }
+    for i in 0..n {
+        y[position(i, n, incy)] += alpha * x[position(i, n, incx)];
+    }
+}
+
+/// Computes the sum of the absolute values (pure-Rust version of BLAS dasum)
+///
+/// **Note:** Returns zero if incx ≤ 0 (as in BLAS).
+pub fn dasum(n: i32, x: &[f64], incx: i32) -> f64 {
+    if incx <= 0 {
+        return 0.0;
+    }
+    let s = incx as usize;
+    (0..to_len(n)).map(|i| f64::abs(x[i * s])).sum()
+}
+
+/// Computes the Euclidean norm (pure-Rust version of BLAS dnrm2)
+///
+/// **Note:** Returns zero if incx ≤ 0 (as in BLAS).
+pub fn dnrm2(n: i32, x: &[f64], incx: i32) -> f64 {
+    if incx <= 0 {
+        return 0.0;
+    }
+    let s = incx as usize;
+    euclidean((0..to_len(n)).map(|i| f64::abs(x[i * s])))
+}
+
+/// Computes the Euclidean norm (pure-Rust version of BLAS dznrm2)
+///
+/// **Note:** Returns zero if incx ≤ 0 (as in BLAS).
+pub fn dznrm2(n: i32, x: &[Complex64], incx: i32) -> f64 {
+    if incx <= 0 {
+        return 0.0;
+    }
+    let s = incx as usize;
+    let parts = (0..to_len(n)).flat_map(|i| [f64::abs(x[i * s].re), f64::abs(x[i * s].im)]);
+    euclidean(parts)
+}
+
+/// Computes the square root of the sum of squares of non-negative values
+///
+/// The plain sum of squares is used unless it overflows or underflows; in this case,
+/// the sum is recomputed with scaling by the largest value.
+pub(super) fn euclidean<I: Iterator<Item = f64> + Clone>(values: I) -> f64 {
+    let sum: f64 = values.clone().map(|v| v * v).sum();
+    if sum.is_finite() && sum > f64::MIN_POSITIVE / f64::EPSILON {
+        return f64::sqrt(sum);
+    }
+    let mut scale = 0.0;
+    let mut ssq = 1.0;
+    for v in values {
+        if v > 0.0 {
+            if scale < v {
+                ssq = 1.0 + ssq * (scale / v) * (scale / v);
+                scale = v;
+            } else {
+                ssq += (v / scale) * (v / scale);
+            }
+        }
+    }
+    scale * f64::sqrt(ssq)
+}
+
+/// Finds the index of the component with the largest absolute value (pure-Rust version of BLAS idamax)
+///
+/// **Note:** The index is zero-based (as in CBLAS) and the first one is returned in case of ties.
+pub fn idamax(n: i32, x: &[f64], incx: i32) -> i32 {
+    if incx <= 0 {
+        return 0;
+    }
+    let s = incx as usize;
+    let mut imax = 0;
+    let mut vmax = -1.0;
+    for i in 0..to_len(n) {
+        let a = f64::abs(x[i * s]);
+        if a > vmax {
+            imax = i;
+            vmax = a;
+        }
+    }
+    imax as i32
+}
+
+/// Adds two vectors
+///
+/// ```text
+/// w := alpha * u + beta * v
+/// ```
+pub fn add_vectors(w: &mut [f64], alpha: f64, u: &[f64], beta: f64, v: &[f64]) {
+    for ((c, a), b) in w.iter_mut().zip(u).zip(v) {
+        *c = alpha * a + beta * b;
+    }
+}
+
+/// Adds two vectors (complex version)
+///
+/// ```text
+/// w := alpha * u + beta * v
+/// ```
+pub fn complex_add_vectors(w: &mut [Complex64], alpha: Complex64, u: &[Complex64], beta: Complex64, v: &[Complex64]) {
+    for ((c, a), b) in w.iter_mut().zip(u).zip(v) {
+        *c = alpha * a + beta * b;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        add_vectors, complex_add_vectors, dasum, daxpy, dcopy, ddot, dnrm2, dscal, dznrm2, euclidean, idamax, zcopy,
+        zscal,
+    };
+    use num_complex::Complex64;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn ddot_works() {
+        let x = [20.0, 10.0, 30.0, 123.0];
+        let y = [-15.0, -5.0, -24.0, 666.0];
+        assert_eq!(ddot(3, &x, 1, &y, 1), -1070.0);
+        assert_eq!(ddot(2, &x, 2, &y, 2), -1020.0);
+        assert_eq!(ddot(2, &x, -2, &y, 2), -24.0 * 20.0 - 15.0 * 30.0);
+        assert_eq!(ddot(0, &x, 1, &y, 1), 0.0);
+    }
+
+    #[test]
+    fn dcopy_and_zcopy_work() {
+        let x = [1.0, 2.0, 3.0];
+        let mut y = [0.0; 3];
+        dcopy(3, &x, 1, &mut y, 1);
+        assert_eq!(y, [1.0, 2.0, 3.0]);
+        let mut y = [0.0; 3];
+        dcopy(2, &x, 2, &mut y, -1);
+        assert_eq!(y, [3.0, 1.0, 0.0]);
+        let x = [Complex64::new(1.0, 2.0), Complex64::new(3.0, 4.0)];
+        let mut y = [Complex64::new(0.0, 0.0); 2];
+        zcopy(2, &x, 1, &mut y, 1);
+        assert_eq!(y, x);
+    }
+
+    #[test]
+    fn dscal_and_daxpy_work() {
+        let mut x = [1.0, 2.0, 3.0];
+        dscal(2, 10.0, &mut x, 2);
+        assert_eq!(x, [10.0, 2.0, 30.0]);
+        dscal(3, 10.0, &mut x, 0);
+        assert_eq!(x, [10.0, 2.0, 30.0]);
+        let mut y = [1.0, 1.0, 1.0];
+        daxpy(3, 0.5, &x, 1, &mut y, 1);
+        assert_eq!(y, [6.0, 2.0, 16.0]);
+        daxpy(2, 1.0, &[1.0, 2.0], 1, &mut y, -2);
+        assert_eq!(y, [8.0, 2.0, 17.0]);
+    }
+
+    #[test]
+    fn dasum_dnrm2_and_idamax_work() {
+        let x = [1.0, -3.0, 4.0, -4.0];
+        assert_eq!(dasum(4, &x, 1), 12.0);
+        assert_eq!(dasum(2, &x, 2), 5.0);
+        assert_eq!(dnrm2(2, &x[1..], 1), 5.0);
+        approx_eq(dnrm2(4, &x, 1), f64::sqrt(42.0), 1e-15);
+        assert_eq!(dnrm2(2, &[1e200, 1e200], 1), f64::sqrt(2.0) * 1e200);
+        approx_eq(dnrm2(2, &[3e-200, 4e-200], 1) / 1e-200, 5.0, 1e-15);
+        assert_eq!(dnrm2(0, &x, 1), 0.0);
+        assert_eq!(idamax(4, &x, 1), 2);
+        assert_eq!(idamax(2, &x, 2), 1);
+    }
+
+    #[test]
+    fn zscal_and_dznrm2_work() {
+        let mut x = [
+            Complex64::new(1.0, 2.0),
+            Complex64::new(3.0, 4.0),
+            Complex64::new(-1.0, 0.0),
+        ];
+        zscal(2, Complex64::new(0.0, 1.0), &mut x, 2);
+        assert_eq!(
+            x,
+            [
+                Complex64::new(-2.0, 1.0),
+                Complex64::new(3.0, 4.0),
+                Complex64::new(0.0, -1.0)
+            ]
+        );
+        zscal(3, Complex64::new(2.0, 0.0), &mut x, 0);
+        assert_eq!(x[0], Complex64::new(-2.0, 1.0));
+        assert_eq!(dznrm2(1, &x[1..], 1), 5.0);
+        approx_eq(dznrm2(3, &x, 1), f64::sqrt(31.0), 1e-15);
+        approx_eq(dznrm2(1, &[Complex64::new(3e200, 4e200)], 1) / 1e200, 5.0, 1e-15);
+        assert_eq!(dznrm2(0, &x, 1), 0.0);
+    }
+
+    #[test]
+    fn euclidean_works() {
+        assert_eq!(euclidean([].into_iter()), 0.0);
+        assert_eq!(euclidean([3.0, 4.0].into_iter()), 5.0);
+        assert_eq!(euclidean([0.0, 3e300, 4e300].into_iter()), 5e300);
+    }
+
+    #[test]
+    fn add_vectors_work() {
+        let mut w = [0.0; 2];
+        add_vectors(&mut w, 2.0, &[1.0, 2.0], -1.0, &[3.0, 4.0]);
+        assert_eq!(w, [-1.0, 0.0]);
+        let mut w = [Complex64::new(0.0, 0.0); 1];
+        let i = Complex64::new(0.0, 1.0);
+        complex_add_vectors(&mut w, i, &[Complex64::new(1.0, 0.0)], Complex64::new(1.0, 0.0), &[i]);
+        assert_eq!(w, [Complex64::new(0.0, 2.0)]);
+    }
+}
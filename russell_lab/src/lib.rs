@@ -5,8 +5,10 @@
 //! # Example - Cholesky factorization
 //!
 //! ```
+//! # #[cfg(feature = "blas")]
 //! use russell_lab::{mat_cholesky, Matrix, StrError};
 //!
+//! # #[cfg(feature = "blas")]
 //! fn main() -> Result<(), StrError> {
 //!     // set matrix
 //!     let a = Matrix::from(&[
@@ -29,21 +31,35 @@
 //!     assert_eq!(format!("{}", l), l_correct);
 //!     Ok(())
 //! }
+//! # #[cfg(not(feature = "blas"))]
+//! # fn main() {}
 //! ```
 
 /// Defines a type alias for the error type as a static string
 pub type StrError = &'static str;
 
+#[cfg(feature = "approx")]
+mod approx_traits;
 mod as_array;
 mod constants;
 mod enums;
 mod formatters;
 mod generators;
+mod interp_1d;
+mod kernels;
 pub mod math;
 mod matrix;
 mod matvec;
+#[cfg(feature = "blas")]
+mod optimization;
+mod permutation;
+mod polynomial;
 pub mod prelude;
+#[cfg(feature = "python")]
+pub mod python;
 mod read_table;
+mod russell_error;
+mod small;
 mod sort;
 mod sort_vec_mat;
 mod stopwatch;
@@ -54,16 +70,23 @@ use crate::constants::*;
 pub use crate::enums::*;
 pub use crate::formatters::*;
 pub use crate::generators::*;
+pub use crate::interp_1d::*;
 pub use crate::matrix::*;
 pub use crate::matvec::*;
+#[cfg(feature = "blas")]
+pub use crate::optimization::*;
+pub use crate::permutation::*;
+pub use crate::polynomial::*;
 pub use crate::read_table::*;
+pub use crate::russell_error::*;
+pub use crate::small::*;
 pub use crate::sort::*;
 pub use crate::sort_vec_mat::*;
 pub use crate::stopwatch::*;
 pub use crate::vector::*;
 
 // run code from README file
-#[cfg(doctest)]
+#[cfg(all(doctest, feature = "blas"))]
 mod test_readme {
     macro_rules! external_doc_test {
         ($x:expr) => {
@@ -1,7 +1,29 @@
+#[cfg(not(target_arch = "wasm32"))]
 extern "C" {
     fn c_erf(x: f64) -> f64;
     fn c_erfc(x: f64) -> f64;
     fn c_gamma(x: f64) -> f64;
+    fn c_ln_gamma(x: f64) -> f64;
+}
+
+// on wasm32, the C-code is replaced by the pure-Rust libm crate (see build.rs)
+#[cfg(target_arch = "wasm32")]
+use wasm_shims::*;
+
+#[cfg(target_arch = "wasm32")]
+mod wasm_shims {
+    pub(super) unsafe fn c_erf(x: f64) -> f64 {
+        libm::erf(x)
+    }
+    pub(super) unsafe fn c_erfc(x: f64) -> f64 {
+        libm::erfc(x)
+    }
+    pub(super) unsafe fn c_gamma(x: f64) -> f64 {
+        libm::tgamma(x)
+    }
+    pub(super) unsafe fn c_ln_gamma(x: f64) -> f64 {
+        libm::lgamma(x)
+    }
 }
 
 /// Returns the error function (wraps C-code: erf)
@@ -32,11 +54,21 @@ pub fn gamma(x: f64) -> f64 {
     unsafe { c_gamma(x) }
 }
 
+/// Returns the natural logarithm of the absolute value of the Gamma function ln(|Γ(x)|) (wraps C-code: lgamma)
+///
+/// This function is useful to avoid overflow, since Γ(x) grows very quickly.
+///
+/// Code from: <https://www.cplusplus.com/reference/cmath/lgamma/>
+#[inline]
+pub fn ln_gamma(x: f64) -> f64 {
+    unsafe { c_ln_gamma(x) }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{erf, erfc, gamma};
+    use super::{erf, erfc, gamma, ln_gamma};
     use crate::math::PI;
     use russell_chk::approx_eq;
 
@@ -137,4 +169,15 @@ mod tests {
         approx_eq(gamma(10.1), 454760.7514415859508673358368319076190405047458218916492282448, 1e-7);
         approx_eq(gamma(150.0 + 1.0e-12), 3.8089226376496421386707466577615064443807882167327097140e+260, 1e248);
     }
+
+    #[test]
+    fn ln_gamma_works() {
+        assert_eq!(ln_gamma(1.0), 0.0);
+        assert_eq!(ln_gamma(2.0), 0.0);
+        approx_eq(ln_gamma(5.0), f64::ln(24.0), 1e-14);
+        approx_eq(ln_gamma(0.5), 0.5 * f64::ln(PI), 1e-15);
+        approx_eq(ln_gamma(-0.5), f64::ln(2.0 * f64::sqrt(PI)), 1e-15);
+        approx_eq(ln_gamma(200.0), 857.9336698258574, 1e-10);
+        assert_eq!(ln_gamma(0.0), f64::INFINITY);
+    }
 }
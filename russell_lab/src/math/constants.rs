@@ -49,13 +49,39 @@ pub const COS_PI_BY_8: f64 =
 pub const SIN_PI_BY_8: f64 =
     0.382683432365089771728459984030398866761344562485627041433800635627546033960089692237013785342283547148424f64;
 
+/// 2π (τ) <https://oeis.org/A019692>
+pub const TWO_PI: f64 = std::f64::consts::TAU;
+
+/// π/2 <https://oeis.org/A019669>
+pub const PI_BY_2: f64 = std::f64::consts::FRAC_PI_2;
+
+/// π/4 <https://oeis.org/A003881>
+pub const PI_BY_4: f64 = std::f64::consts::FRAC_PI_4;
+
+/// 1/π <https://oeis.org/A049541>
+pub const ONE_BY_PI: f64 = std::f64::consts::FRAC_1_PI;
+
+/// sqrt(2π) <https://oeis.org/A019727>
+pub const SQRT_2_PI: f64 =
+    2.50662827463100050241576528481104525300698674060993831662992357634229365460784197494659583837805726611601f64;
+
+/// ln(2) <https://oeis.org/A002162>
+pub const LN_2: f64 = std::f64::consts::LN_2;
+
+/// ln(10) <https://oeis.org/A002392>
+pub const LN_10: f64 = std::f64::consts::LN_10;
+
+/// Golden ratio φ = (1 + sqrt(5))/2 <https://oeis.org/A001622>
+pub const GOLDEN_RATIO: f64 =
+    1.61803398874989484820458683436563811772030917980576286213544862270526046281890244970720720418939113748475f64;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
     use super::{
-        COS_PI_BY_8, ONE_BY_3, ONE_BY_SQRT_2, PI, SIN_PI_BY_8, SQRT_2, SQRT_2_BY_3, SQRT_3, SQRT_3_BY_2, SQRT_6,
-        SQRT_PI, TWO_BY_3,
+        COS_PI_BY_8, EULER, GOLDEN_RATIO, LN_10, LN_2, ONE_BY_3, ONE_BY_PI, ONE_BY_SQRT_2, PI, PI_BY_2, PI_BY_4,
+        SIN_PI_BY_8, SQRT_2, SQRT_2_BY_3, SQRT_2_PI, SQRT_3, SQRT_3_BY_2, SQRT_6, SQRT_PI, TWO_BY_3, TWO_PI,
     };
     use russell_chk::approx_eq;
 
@@ -73,5 +99,14 @@ mod tests {
         assert_eq!(ONE_BY_SQRT_2, std::f64::consts::FRAC_1_SQRT_2);
         assert_eq!(COS_PI_BY_8, f64::cos(std::f64::consts::PI / 8.0));
         assert_eq!(SIN_PI_BY_8, f64::sin(std::f64::consts::PI / 8.0));
+        assert_eq!(TWO_PI, std::f64::consts::TAU);
+        assert_eq!(PI_BY_2, std::f64::consts::FRAC_PI_2);
+        assert_eq!(PI_BY_4, std::f64::consts::FRAC_PI_4);
+        assert_eq!(ONE_BY_PI, std::f64::consts::FRAC_1_PI);
+        approx_eq(SQRT_2_PI, f64::sqrt(2.0 * PI), 1e-15);
+        assert_eq!(LN_2, std::f64::consts::LN_2);
+        assert_eq!(LN_10, std::f64::consts::LN_10);
+        approx_eq(GOLDEN_RATIO, (1.0 + f64::sqrt(5.0)) / 2.0, 1e-15);
+        approx_eq(EULER, 0.5772156649015329, 1e-15);
     }
 }
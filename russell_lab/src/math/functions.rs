@@ -1,3 +1,5 @@
+use crate::math::ln_gamma;
+
 /// Implements the sign function
 ///
 /// ```text
@@ -179,13 +181,157 @@ pub fn suq_cos(x: f64, k: f64) -> f64 {
     sign(f64::cos(x)) * f64::powf(f64::abs(f64::cos(x)), k)
 }
 
+/// Returns the factorial n!
+///
+/// ```text
+/// n! = 1 · 2 · … · n    with 0! = 1
+/// ```
+///
+/// **Note:** The result overflows (returns infinity) if n > 170.
+///
+/// Reference: <https://en.wikipedia.org/wiki/Factorial>
+pub fn factorial(n: usize) -> f64 {
+    if n > 170 {
+        return f64::INFINITY;
+    }
+    (1..=n).fold(1.0, |acc, k| acc * (k as f64))
+}
+
+/// Returns the binomial coefficient "n choose k"
+///
+/// ```text
+/// ⎛n⎞      n!
+/// ⎜ ⎟ = ————————     with binomial(n,k) = 0 if k > n
+/// ⎝k⎠   k!(n-k)!
+/// ```
+///
+/// The coefficient is computed by the multiplicative formula to avoid computing the factorials.
+///
+/// Reference: <https://en.wikipedia.org/wiki/Binomial_coefficient>
+pub fn binomial(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    let k = usize::min(k, n - k);
+    let mut res = 1.0;
+    for i in 1..=k {
+        res = res * ((n - k + i) as f64) / (i as f64);
+    }
+    f64::round(res)
+}
+
+/// Returns sqrt(x² + y²) avoiding overflow and underflow
+///
+/// Delegates to [f64::hypot], which scales the arguments internally.
+pub fn hypot(x: f64, y: f64) -> f64 {
+    f64::hypot(x, y)
+}
+
+/// Returns ln(Σ exp(xᵢ)) avoiding overflow and underflow
+///
+/// ```text
+///                                 ⎛              ⎞
+/// logsumexp(x) = max(x) + ln ⎜ Σ exp(xᵢ - max(x)) ⎟
+///                                 ⎝  i           ⎠
+/// ```
+///
+/// **Note:** Returns negative infinity if `x` is empty.
+///
+/// Reference: <https://en.wikipedia.org/wiki/LogSumExp>
+pub fn logsumexp(x: &[f64]) -> f64 {
+    let max = x.iter().fold(f64::NEG_INFINITY, |acc, &v| f64::max(acc, v));
+    if max.is_infinite() {
+        return max;
+    }
+    let sum: f64 = x.iter().map(|v| f64::exp(v - max)).sum();
+    max + f64::ln(sum)
+}
+
+/// Returns the regularized incomplete beta function Iₓ(a, b)
+///
+/// ```text
+///              1     x
+/// Iₓ(a, b) = ————— ∫  tᵃ⁻¹ (1 - t)ᵇ⁻¹ dt     with 0 ≤ x ≤ 1, a > 0, b > 0
+///            B(a,b)  0
+/// ```
+///
+/// The function is evaluated by the continued fraction of Lentz's method, using the symmetry
+/// `Iₓ(a, b) = 1 - I₁₋ₓ(b, a)` to ensure fast convergence. This function gives, for instance,
+/// the CDF of the Student's t, F, and binomial distributions.
+///
+/// **Note:** Returns NaN if the arguments are out of range.
+///
+/// Reference: <https://en.wikipedia.org/wiki/Beta_function#Incomplete_beta_function>
+pub fn beta_inc(a: f64, b: f64, x: f64) -> f64 {
+    if a.is_nan() || b.is_nan() || a <= 0.0 || b <= 0.0 || !(0.0..=1.0).contains(&x) {
+        return f64::NAN;
+    }
+    if x == 0.0 || x == 1.0 {
+        return x;
+    }
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * f64::ln(x) + b * f64::ln_1p(-x);
+    if x < (a + 1.0) / (a + b + 2.0) {
+        f64::exp(ln_front) * beta_inc_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - f64::exp(ln_front) * beta_inc_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Evaluates the continued fraction of the incomplete beta function (modified Lentz's method)
+fn beta_inc_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    const EPSILON: f64 = 1e-16;
+    const MAX_ITERATIONS: usize = 1000;
+    let (qab, qap, qam) = (a + b, a + 1.0, a - 1.0);
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if f64::abs(d) < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        // even step
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
+        if f64::abs(d) < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if f64::abs(c) < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+        // odd step
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
+        if f64::abs(d) < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if f64::abs(c) < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if f64::abs(delta - 1.0) < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
     use super::{
-        boxcar, heaviside, logistic, logistic_deriv, ramp, sign, smooth_ramp, smooth_ramp_deriv, smooth_ramp_deriv2,
-        suq_cos, suq_sin,
+        beta_inc, binomial, boxcar, factorial, heaviside, hypot, logistic, logistic_deriv, logsumexp, ramp, sign,
+        smooth_ramp, smooth_ramp_deriv, smooth_ramp_deriv2, suq_cos, suq_sin,
     };
     use russell_chk::{approx_eq, deriv_approx_eq};
     use std::f64::consts::PI;
@@ -269,4 +415,80 @@ mod tests {
         approx_eq(suq_cos(PI / 4.0, 2.0), 0.5, 1e-14);
         approx_eq(suq_cos(-PI / 4.0, 2.0), 0.5, 1e-14);
     }
+
+    #[test]
+    fn factorial_works() {
+        assert_eq!(factorial(0), 1.0);
+        assert_eq!(factorial(1), 1.0);
+        assert_eq!(factorial(5), 120.0);
+        assert_eq!(factorial(10), 3628800.0);
+        approx_eq(factorial(170) / 7.257415615307994e306, 1.0, 1e-14);
+        assert_eq!(factorial(171), f64::INFINITY);
+    }
+
+    #[test]
+    fn binomial_works() {
+        assert_eq!(binomial(0, 0), 1.0);
+        assert_eq!(binomial(5, 0), 1.0);
+        assert_eq!(binomial(5, 5), 1.0);
+        assert_eq!(binomial(5, 2), 10.0);
+        assert_eq!(binomial(5, 3), 10.0);
+        assert_eq!(binomial(3, 4), 0.0);
+        assert_eq!(binomial(52, 5), 2598960.0);
+        approx_eq(binomial(100, 50) / 100891344545564193334812497256.0, 1.0, 1e-14);
+        for n in 0..20 {
+            for k in 0..=n {
+                assert_eq!(binomial(n, k), factorial(n) / (factorial(k) * factorial(n - k)));
+            }
+        }
+    }
+
+    #[test]
+    fn hypot_works() {
+        assert_eq!(hypot(0.0, 0.0), 0.0);
+        assert_eq!(hypot(3.0, -4.0), 5.0);
+        assert_eq!(hypot(-4.0, 3.0), 5.0);
+        approx_eq(hypot(1e300, 1e300), 1e300 * f64::sqrt(2.0), 1e285);
+        approx_eq(hypot(1e-300, 1e-300), 1e-300 * f64::sqrt(2.0), 1e-315);
+        assert_eq!(hypot(f64::INFINITY, 1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn logsumexp_works() {
+        assert_eq!(logsumexp(&[]), f64::NEG_INFINITY);
+        assert_eq!(logsumexp(&[2.0]), 2.0);
+        approx_eq(
+            logsumexp(&[1.0, 2.0, 3.0]),
+            f64::ln(f64::exp(1.0) + f64::exp(2.0) + f64::exp(3.0)),
+            1e-15,
+        );
+        approx_eq(logsumexp(&[1000.0, 1000.0]), 1000.0 + f64::ln(2.0), 1e-13);
+        approx_eq(logsumexp(&[-1000.0, -1000.0]), -1000.0 + f64::ln(2.0), 1e-13);
+        assert_eq!(logsumexp(&[f64::NEG_INFINITY, 0.0]), 0.0);
+        assert_eq!(logsumexp(&[f64::INFINITY, 0.0]), f64::INFINITY);
+    }
+
+    #[test]
+    fn beta_inc_works() {
+        assert!(beta_inc(0.0, 1.0, 0.5).is_nan());
+        assert!(beta_inc(1.0, 1.0, 1.5).is_nan());
+        assert_eq!(beta_inc(2.0, 3.0, 0.0), 0.0);
+        assert_eq!(beta_inc(2.0, 3.0, 1.0), 1.0);
+        for x in [0.01, 0.2, 0.5, 0.7, 0.99] {
+            // closed-form expressions
+            approx_eq(beta_inc(1.0, 3.0, x), 1.0 - f64::powi(1.0 - x, 3), 1e-15);
+            approx_eq(beta_inc(2.5, 1.0, x), f64::powf(x, 2.5), 1e-15);
+            approx_eq(beta_inc(0.5, 0.5, x), 2.0 * f64::asin(f64::sqrt(x)) / PI, 1e-14);
+            // I(2,2) = x² (3 - 2x)
+            approx_eq(beta_inc(2.0, 2.0, x), x * x * (3.0 - 2.0 * x), 1e-15);
+            // symmetry
+            approx_eq(beta_inc(3.5, 7.0, x), 1.0 - beta_inc(7.0, 3.5, 1.0 - x), 1e-15);
+        }
+        // binomial CDF: P(K ≤ k) = I₁₋ₚ(n - k, k + 1)
+        let (n, k, p) = (20, 7, 0.3);
+        let cdf: f64 = (0..=k)
+            .map(|i| binomial(n, i) * f64::powi(p, i as i32) * f64::powi(1.0 - p, (n - i) as i32))
+            .sum();
+        approx_eq(beta_inc((n - k) as f64, (k + 1) as f64, 1.0 - p), cdf, 1e-14);
+    }
 }
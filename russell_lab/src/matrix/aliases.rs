@@ -6,3 +6,9 @@ pub type Matrix = NumMatrix<f64>;
 
 /// ComplexMatrix is an alias to NumMatrix&lt;Complex64&gt; and is used in most functions that call OpenBLAS
 pub type ComplexMatrix = NumMatrix<Complex64>;
+
+/// MatrixF32 is an alias to NumMatrix&lt;f32&gt; and is used in the single-precision functions that call OpenBLAS
+///
+/// Single precision halves the memory (and memory bandwidth) required by large matrices at the cost of accuracy
+/// (about 7 significant digits). See, e.g., mat_mat_mul_f32.
+pub type MatrixF32 = NumMatrix<f32>;
@@ -0,0 +1,291 @@
+use super::Matrix;
+use crate::StrError;
+use std::fmt;
+
+/// Implements a (square) band matrix using the LAPACK band storage
+///
+/// A band matrix has `kl` sub-diagonals and `ku` super-diagonals; i.e., `aᵢⱼ = 0`
+/// if `j > i + ku` or `i > j + kl`. Only the components within the band are stored;
+/// thus, the required memory is `(kl + ku + 1) ⋅ n` instead of `n²`.
+///
+/// # Remarks
+///
+/// The data is stored in the **col-major** order, as in LAPACK:
+///
+/// ```text
+/// ldab = kl + ku + 1
+/// aᵢⱼ = data[(ku + i - j) + j ⋅ ldab]    for max(0, j-ku) ≤ i ≤ min(n-1, j+kl)
+/// ```
+///
+/// For instance, with n = 5, kl = 1, and ku = 2:
+///
+/// ```text
+///  ┌                    ┐
+///  │ a00 a01 a02  ·   · │            ┌                     ┐
+///  │ a10 a11 a12 a13  · │            │  *   *  a02 a13 a24 │
+///  │  ·  a21 a22 a23 a24│   data =   │  *  a01 a12 a23 a34 │ (ldab × n)
+///  │  ·   ·  a32 a33 a34│            │ a00 a11 a22 a33 a44 │
+///  │  ·   ·   ·  a43 a44│            │ a10 a21 a32 a43  *  │
+///  └                    ┘            └                     ┘
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{BandMatrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = BandMatrix::new(4, 1, 1)?;
+///     for i in 0..4 {
+///         a.set(i, i, 2.0);
+///         if i > 0 {
+///             a.set(i, i - 1, -1.0);
+///             a.set(i - 1, i, -1.0);
+///         }
+///     }
+///     assert_eq!(a.get(0, 3), 0.0);
+///     assert_eq!(
+///         format!("{}", a.to_matrix()),
+///         "┌             ┐\n\
+///          │  2 -1  0  0 │\n\
+///          │ -1  2 -1  0 │\n\
+///          │  0 -1  2 -1 │\n\
+///          │  0  0 -1  2 │\n\
+///          └             ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BandMatrix {
+    n: usize,       // number of rows = number of columns
+    kl: usize,      // number of sub-diagonals
+    ku: usize,      // number of super-diagonals
+    data: Vec<f64>, // (kl + ku + 1) × n col-major band storage
+}
+
+impl BandMatrix {
+    /// Creates a new (zeroed) band matrix
+    ///
+    /// # Input
+    ///
+    /// * `n` -- number of rows = number of columns (must be ≥ 1)
+    /// * `kl` -- number of sub-diagonals (must be < n)
+    /// * `ku` -- number of super-diagonals (must be < n)
+    pub fn new(n: usize, kl: usize, ku: usize) -> Result<Self, StrError> {
+        if n == 0 {
+            return Err("matrix dimension must be ≥ 1");
+        }
+        if kl >= n || ku >= n {
+            return Err("the number of sub- and super-diagonals must be smaller than the dimension");
+        }
+        Ok(BandMatrix {
+            n,
+            kl,
+            ku,
+            data: vec![0.0; (kl + ku + 1) * n],
+        })
+    }
+
+    /// Creates a new band matrix from a dense matrix
+    ///
+    /// Returns an error if there are non-zero components outside the band.
+    pub fn from_matrix(a: &Matrix, kl: usize, ku: usize) -> Result<Self, StrError> {
+        let (m, n) = a.dims();
+        if m != n {
+            return Err("matrix must be square");
+        }
+        let mut band = BandMatrix::new(n, kl, ku)?;
+        for j in 0..n {
+            for i in 0..n {
+                let aij = a.get(i, j);
+                if band.in_band(i, j) {
+                    band.set(i, j, aij);
+                } else if aij != 0.0 {
+                    return Err("matrix has non-zero components outside the band");
+                }
+            }
+        }
+        Ok(band)
+    }
+
+    /// Returns the dimension (number of rows = number of columns)
+    pub fn dim(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of sub-diagonals
+    pub fn kl(&self) -> usize {
+        self.kl
+    }
+
+    /// Returns the number of super-diagonals
+    pub fn ku(&self) -> usize {
+        self.ku
+    }
+
+    /// Returns the leading dimension of the band storage (kl + ku + 1)
+    pub fn ldab(&self) -> usize {
+        self.kl + self.ku + 1
+    }
+
+    /// Returns true if the symmetric band structure is used (kl == ku)
+    pub fn has_symmetric_band(&self) -> bool {
+        self.kl == self.ku
+    }
+
+    /// Returns an access to the underlying band storage (col-major)
+    pub fn as_data(&self) -> &Vec<f64> {
+        &self.data
+    }
+
+    /// Returns a mutable access to the underlying band storage (col-major)
+    pub fn as_mut_data(&mut self) -> &mut Vec<f64> {
+        &mut self.data
+    }
+
+    /// Returns true if (i,j) is within the band
+    #[inline]
+    pub fn in_band(&self, i: usize, j: usize) -> bool {
+        i <= j + self.kl && j <= i + self.ku
+    }
+
+    /// Returns the (i,j) component (zero if outside the band)
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the indices are out-of-bounds.
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.n);
+        assert!(j < self.n);
+        if self.in_band(i, j) {
+            self.data[self.ku + i - j + j * self.ldab()]
+        } else {
+            0.0
+        }
+    }
+
+    /// Changes the (i,j) component
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the indices are out-of-bounds or outside the band.
+    #[inline]
+    pub fn set(&mut self, i: usize, j: usize, value: f64) {
+        assert!(i < self.n);
+        assert!(j < self.n);
+        assert!(self.in_band(i, j));
+        let ldab = self.ldab();
+        self.data[self.ku + i - j + j * ldab] = value;
+    }
+
+    /// Converts this band matrix to a dense matrix
+    pub fn to_matrix(&self) -> Matrix {
+        let mut a = Matrix::new(self.n, self.n);
+        for j in 0..self.n {
+            let i_min = j.saturating_sub(self.ku);
+            let i_max = usize::min(self.n - 1, j + self.kl);
+            for i in i_min..=i_max {
+                a.set(i, j, self.get(i, j));
+            }
+        }
+        a
+    }
+}
+
+impl fmt::Display for BandMatrix {
+    /// Prints the dense representation of the band matrix
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_matrix(), f)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::BandMatrix;
+    use crate::Matrix;
+
+    #[test]
+    fn new_fails_on_wrong_input() {
+        assert_eq!(BandMatrix::new(0, 0, 0).err(), Some("matrix dimension must be ≥ 1"));
+        assert_eq!(
+            BandMatrix::new(2, 2, 0).err(),
+            Some("the number of sub- and super-diagonals must be smaller than the dimension")
+        );
+        assert_eq!(
+            BandMatrix::new(2, 0, 2).err(),
+            Some("the number of sub- and super-diagonals must be smaller than the dimension")
+        );
+    }
+
+    #[test]
+    fn new_works() {
+        let a = BandMatrix::new(5, 1, 2).unwrap();
+        assert_eq!(a.dim(), 5);
+        assert_eq!(a.kl(), 1);
+        assert_eq!(a.ku(), 2);
+        assert_eq!(a.ldab(), 4);
+        assert_eq!(a.as_data().len(), 20);
+        assert!(!a.has_symmetric_band());
+    }
+
+    #[test]
+    fn set_get_and_storage_work() {
+        let mut a = BandMatrix::new(5, 1, 2).unwrap();
+        for j in 0..5 {
+            for i in 0..5 {
+                if a.in_band(i, j) {
+                    a.set(i, j, (10 * i + j) as f64);
+                }
+            }
+        }
+        #[rustfmt::skip]
+        let correct = &[
+            0.0,  0.0,  0.0, 10.0, // column 0: *, *, a00, a10
+            0.0,  1.0, 11.0, 21.0, // column 1: *, a01, a11, a21
+            2.0, 12.0, 22.0, 32.0, // column 2
+           13.0, 23.0, 33.0, 43.0, // column 3
+           24.0, 34.0, 44.0,  0.0, // column 4: a24, a34, a44, *
+        ];
+        assert_eq!(a.as_data(), correct);
+        assert_eq!(a.get(0, 3), 0.0);
+        assert_eq!(a.get(2, 0), 0.0);
+        assert_eq!(a.get(3, 4), 34.0);
+        assert_eq!(
+            format!("{}", a),
+            "┌                ┐\n\
+             │  0  1  2  0  0 │\n\
+             │ 10 11 12 13  0 │\n\
+             │  0 21 22 23 24 │\n\
+             │  0  0 32 33 34 │\n\
+             │  0  0  0 43 44 │\n\
+             └                ┘"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_panics_outside_the_band() {
+        let mut a = BandMatrix::new(3, 0, 0).unwrap();
+        a.set(0, 1, 1.0);
+    }
+
+    #[test]
+    fn from_matrix_works() {
+        let a = Matrix::from(&[[1.0, 2.0, 0.0], [3.0, 4.0, 5.0], [0.0, 6.0, 7.0]]);
+        let band = BandMatrix::from_matrix(&a, 1, 1).unwrap();
+        assert!(band.has_symmetric_band());
+        assert_eq!(band.to_matrix().as_data(), a.as_data());
+        assert_eq!(
+            BandMatrix::from_matrix(&a, 0, 1).err(),
+            Some("matrix has non-zero components outside the band")
+        );
+        assert_eq!(
+            BandMatrix::from_matrix(&Matrix::new(2, 3), 0, 0).err(),
+            Some("matrix must be square")
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use super::ComplexMatrix;
+use crate::kernels::{complex_add_vectors_native, complex_add_vectors_oblas};
 use crate::StrError;
 use crate::NATIVE_VERSUS_OPENBLAS_BOUNDARY;
 use num_complex::Complex64;
-use russell_openblas::{complex_add_vectors_native, complex_add_vectors_oblas};
 
 /// Performs the addition of two matrices
 ///
@@ -0,0 +1,179 @@
+use super::ComplexMatrix;
+use crate::{ComplexVector, StrError};
+use russell_openblas::{to_i32, zgeev};
+
+/// Performs the eigen-decomposition of a square matrix (complex version)
+///
+/// Computes the eigenvalues `l` and right eigenvectors `v`, such that:
+///
+/// ```text
+/// a ⋅ vj = lj ⋅ vj
+/// ```
+///
+/// where `lj` is the component j of `l` and `vj` is the column j of `v`.
+///
+/// # Output
+///
+/// * `l` -- (m) eigenvalues
+/// * `v` -- (m,m) **right** eigenvectors (as columns)
+///
+/// # Input
+///
+/// * `a` -- (m,m) general matrix [will be modified]
+///
+/// # Note
+///
+/// * The matrix `a` will be modified
+/// * The eigenvectors are normalized to have Euclidean norm equal to 1 and largest component real
+/// * In contrast to [crate::mat_eigen], the real and imaginary parts do not need to be separated
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::{complex_mat_approx_eq, complex_mat_eigen, complex_mat_mat_mul};
+/// use russell_lab::{ComplexMatrix, ComplexVector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // set matrix (non-symmetric and complex)
+///     let data = [
+///         [Complex64::new(1.0, 2.0), Complex64::new(2.0, 0.0)],
+///         [Complex64::new(0.0, 0.0), Complex64::new(3.0, -1.0)],
+///     ];
+///     let mut a = ComplexMatrix::from(&data);
+///
+///     // perform the eigen-decomposition
+///     let m = a.nrow();
+///     let mut l = ComplexVector::new(m);
+///     let mut v = ComplexMatrix::new(m, m);
+///     complex_mat_eigen(&mut l, &mut v, &mut a)?;
+///
+///     // check the similarity transformation a⋅v = v⋅λ
+///     let a_copy = ComplexMatrix::from(&data);
+///     let lam = ComplexMatrix::diagonal(l.as_data());
+///     let one = Complex64::new(1.0, 0.0);
+///     let mut a_v = ComplexMatrix::new(m, m);
+///     let mut v_l = ComplexMatrix::new(m, m);
+///     complex_mat_mat_mul(&mut a_v, one, &a_copy, &v)?;
+///     complex_mat_mat_mul(&mut v_l, one, &v, &lam)?;
+///     complex_mat_approx_eq(&a_v, &v_l, 1e-15);
+///     Ok(())
+/// }
+/// ```
+pub fn complex_mat_eigen(l: &mut ComplexVector, v: &mut ComplexMatrix, a: &mut ComplexMatrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if l.dim() != m {
+        return Err("vectors are incompatible");
+    }
+    if v.nrow() != m || v.ncol() != m {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    let mut empty = Vec::new();
+    zgeev(
+        false,
+        true,
+        m_i32,
+        a.as_mut_data(),
+        l.as_mut_data(),
+        &mut empty,
+        v.as_mut_data(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::complex_mat_eigen;
+    use crate::{ComplexMatrix, ComplexVector};
+    use num_complex::Complex64;
+    use russell_chk::{approx_eq, complex_approx_eq};
+
+    /// Checks a⋅vj = lj⋅vj and ‖vj‖ = 1
+    fn check_eigen(data: &[Vec<Complex64>], l: &ComplexVector, v: &ComplexMatrix, tol: f64) {
+        let m = l.dim();
+        for j in 0..m {
+            let mut norm = 0.0;
+            for (i, row) in data.iter().enumerate() {
+                let mut av = Complex64::new(0.0, 0.0);
+                for (k, aik) in row.iter().enumerate() {
+                    av += aik * v.get(k, j);
+                }
+                complex_approx_eq(av, l[j] * v.get(i, j), tol);
+                norm += v.get(i, j).norm_sqr();
+            }
+            approx_eq(norm, 1.0, tol);
+        }
+    }
+
+    #[test]
+    fn complex_mat_eigen_captures_errors() {
+        let mut a = ComplexMatrix::new(2, 1);
+        let mut l = ComplexVector::new(2);
+        let mut v = ComplexMatrix::new(2, 2);
+        assert_eq!(
+            complex_mat_eigen(&mut l, &mut v, &mut a).err(),
+            Some("matrix must be square")
+        );
+        let mut a = ComplexMatrix::new(2, 2);
+        let mut l_wrong = ComplexVector::new(1);
+        assert_eq!(
+            complex_mat_eigen(&mut l_wrong, &mut v, &mut a).err(),
+            Some("vectors are incompatible")
+        );
+        let mut v_wrong = ComplexMatrix::new(2, 1);
+        assert_eq!(
+            complex_mat_eigen(&mut l, &mut v_wrong, &mut a).err(),
+            Some("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn complex_mat_eigen_works() {
+        complex_mat_eigen(
+            &mut ComplexVector::new(0),
+            &mut ComplexMatrix::new(0, 0),
+            &mut ComplexMatrix::new(0, 0),
+        )
+        .unwrap();
+
+        // hermitian matrix: the eigenvalues are real (1 and 3)
+        let (one, i) = (Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0));
+        let data = vec![vec![2.0 * one, i], vec![-i, 2.0 * one]];
+        let mut a = ComplexMatrix::from(&data);
+        let mut l = ComplexVector::new(2);
+        let mut v = ComplexMatrix::new(2, 2);
+        complex_mat_eigen(&mut l, &mut v, &mut a).unwrap();
+        let mut l_real: Vec<_> = l.as_data().iter().map(|z| z.re).collect();
+        l_real.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        approx_eq(l_real[0], 1.0, 1e-15);
+        approx_eq(l_real[1], 3.0, 1e-15);
+        approx_eq(l[0].im, 0.0, 1e-15);
+        approx_eq(l[1].im, 0.0, 1e-15);
+        check_eigen(&data, &l, &v, 1e-15);
+
+        // general matrix
+        #[rustfmt::skip]
+        let data = vec![
+            vec![Complex64::new( 1.0, 2.0), Complex64::new(2.0, 0.0), Complex64::new(0.0,  0.0)],
+            vec![Complex64::new(-1.0, 0.0), Complex64::new(3.0, 0.0), Complex64::new(1.0, -1.0)],
+            vec![Complex64::new( 0.0, 0.5), Complex64::new(0.0, 0.0), Complex64::new(2.0, -1.0)],
+        ];
+        let mut a = ComplexMatrix::from(&data);
+        let mut l = ComplexVector::new(3);
+        let mut v = ComplexMatrix::new(3, 3);
+        complex_mat_eigen(&mut l, &mut v, &mut a).unwrap();
+        let trace: Complex64 = l.as_data().iter().sum();
+        let det: Complex64 = l.as_data().iter().product();
+        complex_approx_eq(trace, Complex64::new(6.0, 1.0), 1e-14);
+        complex_approx_eq(det, Complex64::new(17.0, 8.0), 1e-13);
+        check_eigen(&data, &l, &v, 1e-13);
+    }
+}
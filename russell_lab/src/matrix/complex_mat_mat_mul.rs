@@ -1,7 +1,7 @@
 use super::ComplexMatrix;
+use crate::kernels::{to_i32, zgemm};
 use crate::StrError;
 use num_complex::Complex64;
-use russell_openblas::{to_i32, zgemm};
 
 /// Performs the matrix-matrix multiplication resulting in a matrix (complex version)
 ///
@@ -1,9 +1,14 @@
 use super::ComplexMatrix;
+use crate::enums::{assert_valid_norm, p_norm_of_abs};
+use crate::kernels::{to_i32, zlange};
 use crate::Norm;
-use russell_openblas::{to_i32, zlange};
 
 /// Computes the matrix norm (complex version)
 ///
+/// # Panics
+///
+/// Panics if `kind` is `Norm::P(p)` with `p < 1` or `p = NaN`.
+///
 /// # Example
 ///
 /// ```
@@ -22,6 +27,7 @@ use russell_openblas::{to_i32, zlange};
 /// }
 /// ```
 pub fn complex_mat_norm(a: &ComplexMatrix, kind: Norm) -> f64 {
+    assert_valid_norm(kind);
     let (m, n) = a.dims();
     if m == 0 || n == 0 {
         return 0.0;
@@ -31,6 +37,7 @@ pub fn complex_mat_norm(a: &ComplexMatrix, kind: Norm) -> f64 {
         Norm::Inf => b'I',
         Norm::Max => b'M',
         Norm::One => b'1',
+        Norm::P(p) => return p_norm_of_abs(a.as_data().iter().map(|z| z.norm()), p),
     };
     let (m_i32, n_i32) = (to_i32(m), to_i32(n));
     zlange(norm, m_i32, n_i32, &a.as_data())
@@ -85,5 +92,16 @@ mod tests {
         fro = f64::sqrt(fro);
         approx_eq(complex_mat_norm(&a, Norm::Fro), fro, 1e-15);
         approx_eq(complex_mat_norm(&a, Norm::Max), Complex64::new(5.0, 1.0).abs(), 1e-15);
+        approx_eq(complex_mat_norm(&a, Norm::P(2.0)), fro, 1e-14);
+        approx_eq(
+            complex_mat_norm(&a, Norm::P(f64::INFINITY)),
+            Complex64::new(5.0, 1.0).abs(),
+            1e-15,
+        );
+        let mut one = 0.0;
+        for v in a.as_data() {
+            one += v.abs();
+        }
+        approx_eq(complex_mat_norm(&a, Norm::P(1.0)), one, 1e-14);
     }
 }
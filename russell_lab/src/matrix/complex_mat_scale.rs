@@ -0,0 +1,62 @@
+use super::ComplexMatrix;
+use crate::kernels::{to_i32, zscal};
+use num_complex::Complex64;
+
+/// Scales matrix (complex version)
+///
+/// ```text
+/// a := alpha * a
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{complex_mat_scale, ComplexMatrix};
+/// use num_complex::Complex64;
+///
+/// fn main() {
+///     let mut a = ComplexMatrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///     ]);
+///
+///     complex_mat_scale(&mut a, Complex64::new(0.0, 0.5));
+///
+///     let correct = "┌                      ┐\n\
+///                    │ 0+0.5i   0+1i 0+1.5i │\n\
+///                    │   0+2i 0+2.5i   0+3i │\n\
+///                    └                      ┘";
+///
+///     assert_eq!(format!("{}", a), correct);
+/// }
+/// ```
+pub fn complex_mat_scale(a: &mut ComplexMatrix, alpha: Complex64) {
+    let data = a.as_mut_data();
+    let n: i32 = to_i32(data.len());
+    zscal(n, alpha, data, 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{complex_mat_scale, ComplexMatrix};
+    use crate::complex_mat_approx_eq;
+    use num_complex::Complex64;
+
+    #[test]
+    fn complex_mat_scale_works() {
+        #[rustfmt::skip]
+        let mut a = ComplexMatrix::from(&[
+            [Complex64::new( 6.0, 3.0), Complex64::new(9.0, 0.0)],
+            [Complex64::new(-6.0, 0.0), Complex64::new(0.0, -9.0)],
+        ]);
+        complex_mat_scale(&mut a, Complex64::new(1.0 / 3.0, 1.0 / 3.0));
+        #[rustfmt::skip]
+        let correct = &[
+            [Complex64::new( 1.0,  3.0), Complex64::new(3.0,  3.0)],
+            [Complex64::new(-2.0, -2.0), Complex64::new(3.0, -3.0)],
+        ];
+        complex_mat_approx_eq(&a, correct, 1e-15);
+    }
+}
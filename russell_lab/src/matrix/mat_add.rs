@@ -1,7 +1,7 @@
 use super::Matrix;
+use crate::kernels::{add_vectors_native, add_vectors_oblas};
 use crate::StrError;
 use crate::NATIVE_VERSUS_OPENBLAS_BOUNDARY;
-use russell_openblas::{add_vectors_native, add_vectors_oblas};
 
 /// Performs the addition of two matrices
 ///
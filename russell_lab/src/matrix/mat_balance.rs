@@ -0,0 +1,197 @@
+use super::Matrix;
+use crate::{Balancing, StrError, Vector};
+use russell_openblas::{dgebak, dgebal, to_i32};
+
+/// Returns the LAPACK job code corresponding to a balancing option
+pub(crate) fn balancing_job(balancing: Balancing) -> u8 {
+    match balancing {
+        Balancing::None => b'N',
+        Balancing::Permute => b'P',
+        Balancing::Scale => b'S',
+        Balancing::Both => b'B',
+    }
+}
+
+/// Balances a general square matrix
+///
+/// Computes the similarity transformation:
+///
+/// ```text
+/// a := D⁻¹ ⋅ Pᵀ ⋅ a ⋅ P ⋅ D
+/// ```
+///
+/// where `P` is a permutation matrix and `D` is a diagonal matrix (of powers of two), such that
+/// the rows and columns of the balanced matrix have norms as close as possible. The eigenvalues are
+/// preserved and the eigenvectors `vb` of the balanced matrix give the eigenvectors of the original matrix
+/// via `v = P ⋅ D ⋅ vb` (see [mat_balance_back]).
+///
+/// # Output
+///
+/// * `scale` -- (m) the details of the permutations and scaling factors (see LAPACK's dgebal)
+/// * Returns `(ilo, ihi)` (zero-based) such that the rows and columns outside `ilo..=ihi` of the
+///   balanced matrix are already in upper triangular form (i.e., they hold isolated eigenvalues)
+///
+/// # Input
+///
+/// * `a` -- (m,m) general matrix [will be modified]
+/// * `balancing` -- the balancing option
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_balance, mat_norm, Balancing, Matrix, Norm, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::from(&[
+///         [1.0, 1e-4, 0.0],
+///         [1e4, 2.0, 1e4],
+///         [0.0, 1e-4, 3.0],
+///     ]);
+///     let mut scale = Vector::new(3);
+///     let (ilo, ihi) = mat_balance(&mut scale, &mut a, Balancing::Scale)?;
+///     assert_eq!((ilo, ihi), (0, 2));
+///     assert!(mat_norm(&a, Norm::Max) < 1e3);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_balance(scale: &mut Vector, a: &mut Matrix, balancing: Balancing) -> Result<(usize, usize), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if scale.dim() != m {
+        return Err("vectors are incompatible");
+    }
+    if m == 0 {
+        return Ok((0, 0));
+    }
+    let (ilo, ihi) = dgebal(
+        balancing_job(balancing),
+        to_i32(m),
+        a.as_mut_data(),
+        scale.as_mut_data(),
+    )?;
+    Ok(((ilo - 1) as usize, (ihi - 1) as usize))
+}
+
+/// Back-transforms the right eigenvectors of a matrix balanced by [mat_balance]
+///
+/// Computes:
+///
+/// ```text
+/// v := P ⋅ D ⋅ v
+/// ```
+///
+/// Thus, if the columns of `v` are eigenvectors of the balanced matrix, on output, they are
+/// eigenvectors of the original matrix. For complex eigenvectors, call this function with
+/// the real and imaginary parts separately.
+///
+/// **Note:** The back-transformed eigenvectors are not normalized.
+///
+/// # Input
+///
+/// * `v` -- (m,k) the eigenvectors (as columns) [will be modified]
+/// * `scale`, `ilo`, `ihi` -- the output of [mat_balance]
+/// * `balancing` -- the same option given to [mat_balance]
+pub fn mat_balance_back(
+    v: &mut Matrix,
+    scale: &Vector,
+    ilo: usize,
+    ihi: usize,
+    balancing: Balancing,
+) -> Result<(), StrError> {
+    let (m, k) = v.dims();
+    if scale.dim() != m {
+        return Err("vectors are incompatible");
+    }
+    if m == 0 || k == 0 {
+        return Ok(());
+    }
+    if ilo > ihi || ihi >= m {
+        return Err("ilo and ihi must satisfy ilo ≤ ihi < m");
+    }
+    dgebak(
+        balancing_job(balancing),
+        true,
+        to_i32(m),
+        to_i32(ilo + 1),
+        to_i32(ihi + 1),
+        scale.as_data(),
+        to_i32(k),
+        v.as_mut_data(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{balancing_job, mat_balance, mat_balance_back};
+    use crate::{mat_mat_mul, mat_norm, Balancing, Matrix, Norm, Vector};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn balancing_job_works() {
+        assert_eq!(balancing_job(Balancing::None), b'N');
+        assert_eq!(balancing_job(Balancing::Permute), b'P');
+        assert_eq!(balancing_job(Balancing::Scale), b'S');
+        assert_eq!(balancing_job(Balancing::Both), b'B');
+    }
+
+    #[test]
+    fn mat_balance_captures_errors() {
+        let mut a = Matrix::new(2, 3);
+        let mut scale = Vector::new(2);
+        assert_eq!(
+            mat_balance(&mut scale, &mut a, Balancing::Both).err(),
+            Some("matrix must be square")
+        );
+        let mut a = Matrix::new(3, 3);
+        assert_eq!(
+            mat_balance(&mut scale, &mut a, Balancing::Both).err(),
+            Some("vectors are incompatible")
+        );
+        let mut v = Matrix::new(3, 3);
+        assert_eq!(
+            mat_balance_back(&mut v, &scale, 0, 1, Balancing::Both).err(),
+            Some("vectors are incompatible")
+        );
+        let scale = Vector::new(3);
+        assert_eq!(
+            mat_balance_back(&mut v, &scale, 2, 1, Balancing::Both).err(),
+            Some("ilo and ihi must satisfy ilo ≤ ihi < m")
+        );
+        assert_eq!(
+            mat_balance_back(&mut v, &scale, 0, 3, Balancing::Both).err(),
+            Some("ilo and ihi must satisfy ilo ≤ ihi < m")
+        );
+    }
+
+    #[test]
+    fn mat_balance_and_back_work() {
+        let data = [[1.0, 1e-4, 0.0], [1e4, 2.0, 1e4], [0.0, 1e-4, 3.0]];
+        let mut a = Matrix::from(&data);
+        let mut scale = Vector::new(3);
+        let (ilo, ihi) = mat_balance(&mut scale, &mut a, Balancing::Scale).unwrap();
+        assert_eq!((ilo, ihi), (0, 2));
+        assert!(mat_norm(&a, Norm::Max) < 1e3);
+
+        // a ⋅ D = D ⋅ balanced, where D is obtained by back-transforming the identity
+        let mut d = Matrix::identity(3);
+        mat_balance_back(&mut d, &scale, ilo, ihi, Balancing::Scale).unwrap();
+        let original = Matrix::from(&data);
+        let mut a_d = Matrix::new(3, 3);
+        let mut d_b = Matrix::new(3, 3);
+        mat_mat_mul(&mut a_d, 1.0, &original, &d).unwrap();
+        mat_mat_mul(&mut d_b, 1.0, &d, &a).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                approx_eq(
+                    a_d.get(i, j),
+                    d_b.get(i, j),
+                    1e-12 * f64::max(1.0, f64::abs(a_d.get(i, j))),
+                );
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::Matrix;
-use crate::StrError;
-use russell_openblas::{dpotrf, to_i32};
+use crate::{StrError, Vector};
+use russell_openblas::{dpotrf, dpotrs, to_i32};
 
 /// Performs the Cholesky factorization of a symmetric positive-definite matrix
 ///
@@ -57,29 +57,126 @@ use russell_openblas::{dpotrf, to_i32};
 /// }
 /// ```
 pub fn mat_cholesky(l: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
+    cholesky_factorize(l, a, false)
+}
+
+/// Performs the Cholesky factorization of a symmetric positive-definite matrix (upper factor)
+///
+/// Finds `u` such that:
+///
+/// ```text
+/// a = uᵀ⋅u
+/// ```
+///
+/// where `u` is an upper-triangular matrix (the transpose of the factor computed by [mat_cholesky])
+///
+/// # Examples
+///
+/// ```
+/// use russell_lab::{mat_cholesky_upper, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [  4.0,  12.0, -16.0],
+///         [ 12.0,  37.0, -43.0],
+///         [-16.0, -43.0,  98.0],
+///     ]);
+///     let mut u = Matrix::new(3, 3);
+///     mat_cholesky_upper(&mut u, &a)?;
+///     let u_correct = "┌          ┐\n\
+///                      │  2  6 -8 │\n\
+///                      │  0  1  5 │\n\
+///                      │  0  0  3 │\n\
+///                      └          ┘";
+///     assert_eq!(format!("{}", u), u_correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_cholesky_upper(u: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
+    cholesky_factorize(u, a, true)
+}
+
+/// Solves a linear system with a symmetric positive-definite matrix using its Cholesky factor
+///
+/// Given the factor computed by [mat_cholesky] (`upper = false`) or [mat_cholesky_upper]
+/// (`upper = true`), finds `x` such that:
+///
+/// ```text
+///   a   ⋅  x  =  b
+/// (m,m)   (m)   (m)
+/// ```
+///
+/// The right-hand-side will hold the solution (`b := a⁻¹⋅b == x`). The factor is not modified;
+/// thus, it may be reused to solve many systems with the same matrix at the cost of two
+/// triangular solutions each.
+///
+/// # Examples
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{mat_cholesky, mat_cholesky_solve, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [  4.0,  12.0, -16.0],
+///         [ 12.0,  37.0, -43.0],
+///         [-16.0, -43.0,  98.0],
+///     ]);
+///     let mut l = Matrix::new(3, 3);
+///     mat_cholesky(&mut l, &a)?;
+///
+///     // first right-hand side
+///     let mut b = Vector::from(&[-24.0, -68.0, 125.0]);
+///     mat_cholesky_solve(&mut b, &l, false)?;
+///     vec_approx_eq(b.as_data(), &[1.0, -1.0, 1.0], 1e-12);
+///
+///     // second right-hand side (same factor)
+///     let mut b = Vector::from(&[0.0, 6.0, 39.0]);
+///     mat_cholesky_solve(&mut b, &l, false)?;
+///     vec_approx_eq(b.as_data(), &[1.0, 1.0, 1.0], 1e-12);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_cholesky_solve(b: &mut Vector, factor: &Matrix, upper: bool) -> Result<(), StrError> {
+    let (m, n) = factor.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if b.dim() != m {
+        return Err("vector has wrong dimension");
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    dpotrs(upper, m_i32, 1, factor.as_data(), b.as_mut_data())
+}
+
+/// Copies the lower (or upper) part of a into the factor and performs the factorization
+fn cholesky_factorize(factor: &mut Matrix, a: &Matrix, upper: bool) -> Result<(), StrError> {
     // check
     let (m, n) = a.dims();
     if m != n {
         return Err("matrix must be square");
     }
-    if l.nrow() != m || l.ncol() != n {
+    if factor.nrow() != m || factor.ncol() != n {
         return Err("matrices are incompatible");
     }
 
-    // copy lower+diagonal part and set upper part to zero
+    // copy lower+diagonal (or upper+diagonal) part and set the other part to zero
     for i in 0..m {
         for j in 0..n {
-            if i >= j {
-                l.set(i, j, a.get(i, j));
+            if (!upper && i >= j) || (upper && i <= j) {
+                factor.set(i, j, a.get(i, j));
             } else {
-                l.set(i, j, 0.0);
+                factor.set(i, j, 0.0);
             }
         }
     }
 
     // perform factorization
     let m_i32 = to_i32(m);
-    dpotrf(false, m_i32, l.as_mut_data())?;
+    dpotrf(upper, m_i32, factor.as_mut_data())?;
 
     // done
     Ok(())
@@ -89,8 +186,9 @@ pub fn mat_cholesky(l: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
 
 #[cfg(test)]
 mod tests {
-    use super::{mat_cholesky, Matrix};
-    use crate::mat_approx_eq;
+    use super::{mat_cholesky, mat_cholesky_solve, mat_cholesky_upper, Matrix};
+    use crate::{mat_approx_eq, Vector};
+    use russell_chk::vec_approx_eq;
 
     #[test]
     fn mat_cholesky_fails_on_wrong_dims() {
@@ -166,4 +264,67 @@ mod tests {
         }
         mat_approx_eq(&l_lt, &a, 1e-15);
     }
+
+    #[test]
+    fn mat_cholesky_upper_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [25.0, 15.0, -5.0],
+            [15.0, 18.0,  0.0],
+            [-5.0,  0.0, 11.0],
+        ]);
+        let mut u = Matrix::new(3, 3);
+        let mut u_wrong = Matrix::new(2, 3);
+        assert_eq!(mat_cholesky_upper(&mut u_wrong, &a), Err("matrices are incompatible"));
+        mat_cholesky_upper(&mut u, &a).unwrap();
+        #[rustfmt::skip]
+        let u_correct = Matrix::from(&[
+            [5.0, 3.0, -1.0],
+            [0.0, 3.0,  1.0],
+            [0.0, 0.0,  3.0],
+        ]);
+        mat_approx_eq(&u, &u_correct, 1e-15);
+    }
+
+    #[test]
+    fn mat_cholesky_solve_fails_on_wrong_dims() {
+        let l = Matrix::new(2, 2);
+        let l_wrong = Matrix::new(2, 3);
+        let mut b = Vector::new(2);
+        let mut b_wrong = Vector::new(3);
+        assert_eq!(
+            mat_cholesky_solve(&mut b, &l_wrong, false),
+            Err("matrix must be square")
+        );
+        assert_eq!(
+            mat_cholesky_solve(&mut b_wrong, &l, false),
+            Err("vector has wrong dimension")
+        );
+        let mut b_empty = Vector::new(0);
+        assert_eq!(mat_cholesky_solve(&mut b_empty, &Matrix::new(0, 0), false), Ok(()));
+    }
+
+    #[test]
+    fn mat_cholesky_solve_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [2.0, 1.0, 1.0, 3.0, 2.0],
+            [1.0, 2.0, 2.0, 1.0, 1.0],
+            [1.0, 2.0, 9.0, 1.0, 5.0],
+            [3.0, 1.0, 1.0, 7.0, 1.0],
+            [2.0, 1.0, 5.0, 1.0, 8.0],
+        ]);
+        let mut l = Matrix::new(5, 5);
+        let mut u = Matrix::new(5, 5);
+        mat_cholesky(&mut l, &a).unwrap();
+        mat_cholesky_upper(&mut u, &a).unwrap();
+        let x_correct = &[-2.0, 1.0, 0.0, 1.0, 3.0];
+        let rhs = &[6.0, 4.0, 16.0, 5.0, 22.0];
+        let mut b = Vector::from(rhs);
+        mat_cholesky_solve(&mut b, &l, false).unwrap();
+        vec_approx_eq(b.as_data(), x_correct, 1e-14);
+        let mut b = Vector::from(rhs);
+        mat_cholesky_solve(&mut b, &u, true).unwrap();
+        vec_approx_eq(b.as_data(), x_correct, 1e-14);
+    }
 }
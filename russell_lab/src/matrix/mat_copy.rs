@@ -1,6 +1,6 @@
 use super::Matrix;
+use crate::kernels::{dcopy, to_i32};
 use crate::StrError;
-use russell_openblas::{dcopy, to_i32};
 
 /// Copies matrix
 ///
@@ -0,0 +1,165 @@
+use crate::matrix::Matrix;
+use crate::StrError;
+use russell_openblas::{dgetrf, to_i32};
+
+/// Computes the determinant of a square matrix
+///
+/// ```text
+/// d := det(a)
+/// ```
+///
+/// The determinant of 1 x 1, 2 x 2, and 3 x 3 matrices is computed with explicit formulae.
+/// Otherwise, the LU factorization (dgetrf) of a copy of `a` is computed and:
+///
+/// ```text
+/// det(a) = det(p) ⋅ Π uᵢᵢ
+/// ```
+///
+/// where `det(p) = ±1` depends on the number of row interchanges.
+///
+/// See also [crate::mat_inverse] (which also returns the determinant) and [crate::LuFactors].
+///
+/// **Note:** Returns 1.0 if the dimension is zero.
+///
+/// # Input
+///
+/// * `a` -- (m,m) matrix, symmetric or not
+///
+/// # Examples
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{mat_det, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [0.0, 1.0, 4.0],
+///         [5.0, 6.0, 0.0],
+///     ]);
+///     assert_eq!(mat_det(&a)?, 1.0);
+///
+///     let a = Matrix::from(&[
+///         [2.0, 1.0, 1.0, 3.0],
+///         [1.0, 2.0, 2.0, 1.0],
+///         [1.0, 2.0, 9.0, 1.0],
+///         [3.0, 1.0, 1.0, 7.0],
+///     ]);
+///     approx_eq(mat_det(&a)?, 49.0, 1e-13);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_det(a: &Matrix) -> Result<f64, StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    match m {
+        0 => Ok(1.0),
+        1 => Ok(a.get(0, 0)),
+        2 => Ok(a.get(0, 0) * a.get(1, 1) - a.get(0, 1) * a.get(1, 0)),
+        3 => {
+            #[rustfmt::skip]
+            let det =
+                  a.get(0,0) * (a.get(1,1) * a.get(2,2) - a.get(1,2) * a.get(2,1))
+                - a.get(0,1) * (a.get(1,0) * a.get(2,2) - a.get(1,2) * a.get(2,0))
+                + a.get(0,2) * (a.get(1,0) * a.get(2,1) - a.get(1,1) * a.get(2,0));
+            Ok(det)
+        }
+        _ => {
+            let m_i32 = to_i32(m);
+            let mut lu = a.as_data().clone();
+            let mut ipiv = vec![0_i32; m];
+            // with valid dimensions, dgetrf only fails if an exact zero pivot is found
+            if dgetrf(m_i32, m_i32, &mut lu, &mut ipiv).is_err() {
+                return Ok(0.0);
+            }
+            let mut det = 1.0;
+            for i in 0..m {
+                // NOTE: ipiv are 1-based indices
+                det *= lu[i + i * m];
+                if ipiv[i] != to_i32(i + 1) {
+                    det = -det;
+                }
+            }
+            Ok(det)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::mat_det;
+    use crate::{mat_inverse, Matrix};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn mat_det_fails_on_wrong_dims() {
+        let a = Matrix::new(2, 3);
+        assert_eq!(mat_det(&a), Err("matrix must be square"));
+    }
+
+    #[test]
+    fn mat_det_small_matrices_work() {
+        assert_eq!(mat_det(&Matrix::new(0, 0)).unwrap(), 1.0);
+        assert_eq!(mat_det(&Matrix::from(&[[-3.0]])).unwrap(), -3.0);
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [-1.0,  1.5],
+            [ 1.0, -1.0],
+        ]);
+        assert_eq!(mat_det(&a).unwrap(), -0.5);
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0],
+            [0.0, 1.0, 4.0],
+            [5.0, 6.0, 0.0],
+        ]);
+        assert_eq!(mat_det(&a).unwrap(), 1.0);
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0],
+            [2.0, 4.0, 6.0],
+            [5.0, 6.0, 0.0],
+        ]);
+        assert_eq!(mat_det(&a).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn mat_det_large_matrices_work() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [2.0, 1.0, 1.0, 3.0, 2.0],
+            [1.0, 2.0, 2.0, 1.0, 1.0],
+            [1.0, 2.0, 9.0, 1.0, 5.0],
+            [3.0, 1.0, 1.0, 7.0, 1.0],
+            [2.0, 1.0, 5.0, 1.0, 8.0],
+        ]);
+        approx_eq(mat_det(&a).unwrap(), 98.0, 1e-12);
+        let mut ai = Matrix::new(5, 5);
+        let det = mat_inverse(&mut ai, &a).unwrap();
+        approx_eq(mat_det(&a).unwrap(), det, 1e-12);
+
+        // row interchanges are required
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [0.0, 1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 2.0],
+            [0.0, 0.0, 3.0, 0.0],
+        ]);
+        approx_eq(mat_det(&a).unwrap(), 6.0, 1e-15);
+
+        // singular matrix
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [1.0, 0.0, 1.0, 0.0],
+        ]);
+        approx_eq(mat_det(&a).unwrap(), 0.0, 1e-15);
+    }
+}
@@ -1,6 +1,6 @@
-use super::Matrix;
-use crate::{StrError, Vector};
-use russell_openblas::{dgeev, dgeev_data, dgeev_data_lr, to_i32};
+use super::{balancing_job, Matrix};
+use crate::{Balancing, StrError, Vector};
+use russell_openblas::{dgeev, dgeev_data, dgeev_data_lr, dgeevx, to_i32};
 
 /// Performs the eigen-decomposition of a square matrix
 ///
@@ -26,6 +26,8 @@ use russell_openblas::{dgeev, dgeev_data, dgeev_data_lr, to_i32};
 /// # Note
 ///
 /// * The matrix `a` will be modified
+/// * See [mat_eigen_lr] to compute the left eigenvectors as well
+/// * The real and imaginary parts may be combined with [crate::complex_vec_zip] and [crate::complex_mat_zip]
 ///
 /// # Similarity transformation
 ///
@@ -298,14 +300,132 @@ pub fn mat_eigen_lr(
     Ok(())
 }
 
+/// Performs the eigen-decomposition of a square matrix with a given balancing option
+///
+/// Computes the eigenvalues `l` and right eigenvectors `v` (of the original matrix), such that:
+///
+/// ```text
+/// a ⋅ vj = lj ⋅ vj
+/// ```
+///
+/// The matrix is first balanced according to `balancing` (see [crate::mat_balance]),
+/// which improves the accuracy of the eigenvalues of badly scaled matrices. The eigenvectors
+/// are back-transformed and normalized to have Euclidean norm equal to 1 and largest component real.
+///
+/// **Note:** [mat_eigen] always permutes and scales the matrix (i.e., uses [Balancing::Both]).
+///
+/// # Output
+///
+/// * `l_real` -- (m) eigenvalues; real part
+/// * `l_imag` -- (m) eigenvalues; imaginary part
+/// * `v_real` -- (m,m) **right** eigenvectors (as columns); real part
+/// * `v_imag` -- (m,m) **right** eigenvectors (as columns); imaginary part
+/// * `scale` -- (m) the details of the permutations and scaling factors of the balancing transformation
+/// * Returns `(ilo, ihi)` (zero-based) of the balancing transformation
+///
+/// The balancing transformation (`scale`, `ilo`, and `ihi`) may be given to [crate::mat_balance_back]
+/// to back-transform other vectors computed in the balanced space.
+///
+/// # Input
+///
+/// * `a` -- (m,m) general matrix [will be modified]
+/// * `balancing` -- the balancing option
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{mat_eigen_balanced, Balancing, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // set a badly scaled matrix
+///     let mut a = Matrix::from(&[
+///         [1.0, 1e-8, 0.0],
+///         [1e8, 2.0, 1e8],
+///         [0.0, 1e-8, 3.0],
+///     ]);
+///
+///     // allocate output arrays
+///     let m = a.nrow();
+///     let mut l_real = Vector::new(m);
+///     let mut l_imag = Vector::new(m);
+///     let mut v_real = Matrix::new(m, m);
+///     let mut v_imag = Matrix::new(m, m);
+///     let mut scale = Vector::new(m);
+///
+///     // perform the eigen-decomposition
+///     let (ilo, ihi) = mat_eigen_balanced(
+///         &mut l_real,
+///         &mut l_imag,
+///         &mut v_real,
+///         &mut v_imag,
+///         &mut scale,
+///         &mut a,
+///         Balancing::Scale,
+///     )?;
+///     assert_eq!((ilo, ihi), (0, 2));
+///
+///     // check the eigenvalues (the matrix is similar to [[1,1,0],[1,2,1],[0,1,3]])
+///     let mut l = l_real.as_data().clone();
+///     l.sort_by(|a, b| a.partial_cmp(b).unwrap());
+///     vec_approx_eq(&l, &[2.0 - f64::sqrt(3.0), 2.0, 2.0 + f64::sqrt(3.0)], 1e-14);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_eigen_balanced(
+    l_real: &mut Vector,
+    l_imag: &mut Vector,
+    v_real: &mut Matrix,
+    v_imag: &mut Matrix,
+    scale: &mut Vector,
+    a: &mut Matrix,
+    balancing: Balancing,
+) -> Result<(usize, usize), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if l_real.dim() != m || l_imag.dim() != m || scale.dim() != m {
+        return Err("vectors are incompatible");
+    }
+    if v_real.nrow() != m || v_real.ncol() != m || v_imag.nrow() != m || v_imag.ncol() != m {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 {
+        return Ok((0, 0));
+    }
+    let m_i32 = to_i32(m);
+    let mut v = vec![0.0; m * m];
+    let mut empty: Vec<f64> = Vec::new();
+    let mut rconde: Vec<f64> = Vec::new();
+    let mut rcondv: Vec<f64> = Vec::new();
+    let (ilo, ihi, _) = dgeevx(
+        balancing_job(balancing),
+        false,
+        true,
+        b'N',
+        m_i32,
+        a.as_mut_data(),
+        l_real.as_mut_data(),
+        l_imag.as_mut_data(),
+        &mut empty,
+        &mut v,
+        scale.as_mut_data(),
+        &mut rconde,
+        &mut rcondv,
+    )?;
+    dgeev_data(v_real.as_mut_data(), v_imag.as_mut_data(), l_imag.as_data(), &v)?;
+    Ok(((ilo - 1) as usize, (ihi - 1) as usize))
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{mat_eigen, mat_eigen_lr};
+    use super::{mat_eigen, mat_eigen_balanced, mat_eigen_lr};
     use crate::mat_approx_eq;
     use crate::testing::{check_eigen_general, check_eigen_real};
-    use crate::{Matrix, Vector};
+    use crate::{Balancing, Matrix, Vector};
     use russell_chk::vec_approx_eq;
 
     #[test]
@@ -557,4 +677,88 @@ mod tests {
         vec_approx_eq(l_imag.as_data(), l_imag_correct, 1e-15);
         check_eigen_general(&data, &v_real, &l_real, &v_imag, &l_imag, 1e-15);
     }
+
+    #[test]
+    fn mat_eigen_balanced_fails_on_wrong_dims() {
+        let mut a = Matrix::new(2, 2);
+        let mut l_real = Vector::new(2);
+        let mut l_imag = Vector::new(2);
+        let mut v_real = Matrix::new(2, 2);
+        let mut v_imag = Matrix::new(2, 2);
+        let mut scale = Vector::new(2);
+        let mut scale_wrong = Vector::new(3);
+        let mut v_imag_wrong = Matrix::new(2, 3);
+        let mut a_wrong = Matrix::new(2, 3);
+        assert_eq!(
+            mat_eigen_balanced(
+                &mut l_real,
+                &mut l_imag,
+                &mut v_real,
+                &mut v_imag,
+                &mut scale,
+                &mut a_wrong,
+                Balancing::Both,
+            ),
+            Err("matrix must be square")
+        );
+        assert_eq!(
+            mat_eigen_balanced(
+                &mut l_real,
+                &mut l_imag,
+                &mut v_real,
+                &mut v_imag,
+                &mut scale_wrong,
+                &mut a,
+                Balancing::Both,
+            ),
+            Err("vectors are incompatible")
+        );
+        assert_eq!(
+            mat_eigen_balanced(
+                &mut l_real,
+                &mut l_imag,
+                &mut v_real,
+                &mut v_imag_wrong,
+                &mut scale,
+                &mut a,
+                Balancing::Both,
+            ),
+            Err("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn mat_eigen_balanced_works() {
+        #[rustfmt::skip]
+        let data = [
+            [1.0, 1e-4, 0.0],
+            [1e4,  2.0, 1e4],
+            [0.0, 1e-4, 3.0],
+        ];
+        for balancing in [Balancing::None, Balancing::Permute, Balancing::Scale, Balancing::Both] {
+            let mut a = Matrix::from(&data);
+            let m = a.nrow();
+            let mut l_real = Vector::new(m);
+            let mut l_imag = Vector::new(m);
+            let mut v_real = Matrix::new(m, m);
+            let mut v_imag = Matrix::new(m, m);
+            let mut scale = Vector::new(m);
+            mat_eigen_balanced(
+                &mut l_real,
+                &mut l_imag,
+                &mut v_real,
+                &mut v_imag,
+                &mut scale,
+                &mut a,
+                balancing,
+            )
+            .unwrap();
+            let mut l = l_real.as_data().clone();
+            l.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let s3 = f64::sqrt(3.0);
+            vec_approx_eq(&l, &[2.0 - s3, 2.0, 2.0 + s3], 1e-11);
+            vec_approx_eq(l_imag.as_data(), &[0.0, 0.0, 0.0], 1e-15);
+            check_eigen_general(&data, &v_real, &l_real, &v_imag, &l_imag, 1e-10);
+        }
+    }
 }
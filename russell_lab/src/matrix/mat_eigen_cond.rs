@@ -0,0 +1,261 @@
+use super::Matrix;
+use crate::{StrError, Vector};
+use russell_openblas::{dgeev_data, dgeev_data_lr, dgeevx, to_i32};
+
+/// Performs the eigen-decomposition of a square matrix and computes the condition numbers of the eigenvalues
+///
+/// Computes the eigenvalues `l` and right eigenvectors `v`, such that:
+///
+/// ```text
+/// a ⋅ vj = lj ⋅ vj
+/// ```
+///
+/// Also, computes the condition number of each eigenvalue:
+///
+/// ```text
+///           ‖uj‖₂ ‖vj‖₂
+/// condj = ─────────────
+///          |ujᴴ ⋅ vj|
+/// ```
+///
+/// where `uj` is the left eigenvector corresponding to `lj`. A perturbation `δa` of the matrix
+/// changes the eigenvalue `lj` by approximately `condj ⋅ ‖δa‖₂` (at most). Thus, large condition
+/// numbers indicate eigenvalues that are sensitive to perturbations (e.g., due to uncertain data).
+/// The condition numbers of a normal matrix (e.g., symmetric) are all equal to one.
+///
+/// # Output
+///
+/// * `l_real` -- (m) eigenvalues; real part
+/// * `l_imag` -- (m) eigenvalues; imaginary part
+/// * `v_real` -- (m,m) **right** eigenvectors (as columns); real part
+/// * `v_imag` -- (m,m) **right** eigenvectors (as columns); imaginary part
+/// * `cond` -- (m) condition numbers of the eigenvalues
+/// * `left` -- (optional) (m,m) **left** eigenvectors (as columns); real and imaginary parts
+///
+/// # Input
+///
+/// * `a` -- (m,m) general matrix [will be modified]
+///
+/// # Note
+///
+/// * The matrix `a` will be modified
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::approx_eq;
+/// use russell_lab::{mat_eigen_cond, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // set a non-normal matrix
+///     let data = [[1.0, 100.0], [0.0, 2.0]];
+///     let mut a = Matrix::from(&data);
+///
+///     // allocate output arrays
+///     let m = a.nrow();
+///     let mut l_real = Vector::new(m);
+///     let mut l_imag = Vector::new(m);
+///     let mut v_real = Matrix::new(m, m);
+///     let mut v_imag = Matrix::new(m, m);
+///     let mut cond = Vector::new(m);
+///
+///     // perform the eigen-decomposition
+///     mat_eigen_cond(&mut l_real, &mut l_imag, &mut v_real, &mut v_imag, &mut cond, None, &mut a)?;
+///
+///     // check results
+///     assert_eq!(
+///         format!("{:.1}", l_real),
+///         "┌     ┐\n\
+///          │ 1.0 │\n\
+///          │ 2.0 │\n\
+///          └     ┘"
+///     );
+///
+///     // the eigenvalues are sensitive to perturbations
+///     approx_eq(cond[0], f64::sqrt(10001.0), 1e-10);
+///     approx_eq(cond[1], f64::sqrt(10001.0), 1e-10);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_eigen_cond(
+    l_real: &mut Vector,
+    l_imag: &mut Vector,
+    v_real: &mut Matrix,
+    v_imag: &mut Matrix,
+    cond: &mut Vector,
+    left: Option<(&mut Matrix, &mut Matrix)>,
+    a: &mut Matrix,
+) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if l_real.dim() != m || l_imag.dim() != m || cond.dim() != m {
+        return Err("vectors are incompatible");
+    }
+    if v_real.nrow() != m || v_real.ncol() != m || v_imag.nrow() != m || v_imag.ncol() != m {
+        return Err("matrices are incompatible");
+    }
+    if let Some((u_real, u_imag)) = &left {
+        if u_real.nrow() != m || u_real.ncol() != m || u_imag.nrow() != m || u_imag.ncol() != m {
+            return Err("matrices are incompatible");
+        }
+    }
+    let m_i32 = to_i32(m);
+    let mut u = vec![0.0; m * m];
+    let mut v = vec![0.0; m * m];
+    let mut scale = vec![0.0; m];
+    let mut rcondv: Vec<f64> = Vec::new();
+    dgeevx(
+        b'N',
+        true,
+        true,
+        b'E',
+        m_i32,
+        a.as_mut_data(),
+        l_real.as_mut_data(),
+        l_imag.as_mut_data(),
+        &mut u,
+        &mut v,
+        &mut scale,
+        cond.as_mut_data(),
+        &mut rcondv,
+    )?;
+    for j in 0..m {
+        cond[j] = if cond[j] > 0.0 { 1.0 / cond[j] } else { f64::INFINITY };
+    }
+    match left {
+        Some((u_real, u_imag)) => dgeev_data_lr(
+            u_real.as_mut_data(),
+            u_imag.as_mut_data(),
+            v_real.as_mut_data(),
+            v_imag.as_mut_data(),
+            l_imag.as_data(),
+            &u,
+            &v,
+        )?,
+        None => dgeev_data(v_real.as_mut_data(), v_imag.as_mut_data(), l_imag.as_data(), &v)?,
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::mat_eigen_cond;
+    use crate::testing::check_eigen_general;
+    use crate::{Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn mat_eigen_cond_fails_on_wrong_dims() {
+        let mut a = Matrix::new(2, 2);
+        let mut l_real = Vector::new(2);
+        let mut l_imag = Vector::new(2);
+        let mut v_real = Matrix::new(2, 2);
+        let mut v_imag = Matrix::new(2, 2);
+        let mut cond = Vector::new(2);
+        let mut cond_wrong = Vector::new(3);
+        let mut u_real = Matrix::new(2, 2);
+        let mut u_imag_wrong = Matrix::new(2, 3);
+        let mut a_wrong = Matrix::new(2, 3);
+        assert_eq!(
+            mat_eigen_cond(
+                &mut l_real,
+                &mut l_imag,
+                &mut v_real,
+                &mut v_imag,
+                &mut cond,
+                None,
+                &mut a_wrong
+            ),
+            Err("matrix must be square")
+        );
+        assert_eq!(
+            mat_eigen_cond(
+                &mut l_real,
+                &mut l_imag,
+                &mut v_real,
+                &mut v_imag,
+                &mut cond_wrong,
+                None,
+                &mut a
+            ),
+            Err("vectors are incompatible")
+        );
+        assert_eq!(
+            mat_eigen_cond(
+                &mut l_real,
+                &mut l_imag,
+                &mut v_real,
+                &mut v_imag,
+                &mut cond,
+                Some((&mut u_real, &mut u_imag_wrong)),
+                &mut a
+            ),
+            Err("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn mat_eigen_cond_works() {
+        // non-normal matrix
+        let data = [[1.0, 100.0], [0.0, 2.0]];
+        let mut a = Matrix::from(&data);
+        let m = a.nrow();
+        let mut l_real = Vector::new(m);
+        let mut l_imag = Vector::new(m);
+        let mut v_real = Matrix::new(m, m);
+        let mut v_imag = Matrix::new(m, m);
+        let mut u_real = Matrix::new(m, m);
+        let mut u_imag = Matrix::new(m, m);
+        let mut cond = Vector::new(m);
+        mat_eigen_cond(
+            &mut l_real,
+            &mut l_imag,
+            &mut v_real,
+            &mut v_imag,
+            &mut cond,
+            Some((&mut u_real, &mut u_imag)),
+            &mut a,
+        )
+        .unwrap();
+        vec_approx_eq(l_real.as_data(), &[1.0, 2.0], 1e-13);
+        vec_approx_eq(l_imag.as_data(), &[0.0, 0.0], 1e-15);
+        let c = f64::sqrt(10001.0);
+        vec_approx_eq(cond.as_data(), &[c, c], 1e-10);
+        check_eigen_general(&data, &v_real, &l_real, &v_imag, &l_imag, 1e-13);
+
+        // the left eigenvectors of a are the right eigenvectors of aᵀ
+        let data_t = [[1.0, 0.0], [100.0, 2.0]];
+        check_eigen_general(&data_t, &u_real, &l_real, &u_imag, &l_imag, 1e-13);
+
+        // normal matrix with complex eigenvalues
+        #[rustfmt::skip]
+        let data = [
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0],
+        ];
+        let mut a = Matrix::from(&data);
+        let m = a.nrow();
+        let mut l_real = Vector::new(m);
+        let mut l_imag = Vector::new(m);
+        let mut v_real = Matrix::new(m, m);
+        let mut v_imag = Matrix::new(m, m);
+        let mut cond = Vector::new(m);
+        mat_eigen_cond(
+            &mut l_real,
+            &mut l_imag,
+            &mut v_real,
+            &mut v_imag,
+            &mut cond,
+            None,
+            &mut a,
+        )
+        .unwrap();
+        vec_approx_eq(cond.as_data(), &[1.0, 1.0, 1.0], 1e-14);
+        check_eigen_general(&data, &v_real, &l_real, &v_imag, &l_imag, 1e-15);
+    }
+}
@@ -20,6 +20,12 @@ use russell_openblas::{dsyev, to_i32};
 ///
 /// * `l` -- the eigenvalues
 /// * `a` -- will hold the eigenvectors as columns
+///
+/// # Note
+///
+/// For general (non-symmetric) matrices, see [crate::mat_eigen] (right eigenvectors) and
+/// [crate::mat_eigen_lr] (left and right eigenvectors), which compute the complex eigenvalues
+/// and eigenvectors (as real and imaginary parts) using dgeev.
 pub fn mat_eigen_sym(l: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
     let (m, n) = a.dims();
     if m != n {
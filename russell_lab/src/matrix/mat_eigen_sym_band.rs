@@ -0,0 +1,216 @@
+use super::{BandMatrix, Matrix};
+use crate::{StrError, Vector};
+use russell_openblas::{dsbev, to_i32};
+
+/// Calculates the eigenvalues and eigenvectors of a symmetric band matrix
+///
+/// Computes the eigenvalues `l` and eigenvectors `v`, such that:
+///
+/// ```text
+/// a ⋅ vj = lj ⋅ vj
+/// ```
+///
+/// where `lj` is the component j of `l` and `vj` is the column j of `v`.
+///
+/// The band storage avoids the `n²` memory of a dense matrix; thus, this function is suitable
+/// for vibration problems with banded stiffness matrices (e.g., 1D/2D finite differences).
+///
+/// **Note:** Because LAPACK overwrites the band, a temporary copy of the upper part of the band
+/// (`(ku + 1) ⋅ n` values) is allocated; the lower part is not copied.
+///
+/// # Input
+///
+/// * `a` -- band matrix (SYMMETRIC) with `kl == ku`; only the upper part of the band is read.
+///
+/// # Output
+///
+/// * `l` -- the eigenvalues (n), in ascending order
+/// * `v` -- the eigenvectors (n × n) as columns
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_eigen_sym_band, BandMatrix, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = BandMatrix::new(3, 1, 1)?;
+///     for i in 0..3 {
+///         a.set(i, i, 2.0);
+///     }
+///     a.set(0, 1, 1.0);
+///     a.set(1, 0, 1.0);
+///     let mut l = Vector::new(3);
+///     let mut v = Matrix::new(3, 3);
+///     mat_eigen_sym_band(&mut l, &mut v, &a)?;
+///     assert_eq!(format!("{:.1}", l), "┌     ┐\n\
+///                                      │ 1.0 │\n\
+///                                      │ 2.0 │\n\
+///                                      │ 3.0 │\n\
+///                                      └     ┘");
+///     Ok(())
+/// }
+/// ```
+pub fn mat_eigen_sym_band(l: &mut Vector, v: &mut Matrix, a: &BandMatrix) -> Result<(), StrError> {
+    let n = a.dim();
+    if !a.has_symmetric_band() {
+        return Err("band matrix must have kl == ku");
+    }
+    if l.dim() != n {
+        return Err("l vector has incompatible dimension");
+    }
+    if v.dims() != (n, n) {
+        return Err("v matrix has incompatible dimensions");
+    }
+    let mut ab = upper_band(a);
+    dsbev(
+        true,
+        true,
+        to_i32(n),
+        to_i32(a.ku()),
+        &mut ab,
+        to_i32(a.ku() + 1),
+        l.as_mut_data(),
+        v.as_mut_data(),
+    )
+}
+
+/// Calculates the eigenvalues of a symmetric band matrix (eigenvectors are not computed)
+///
+/// # Input
+///
+/// * `a` -- band matrix (SYMMETRIC) with `kl == ku`; only the upper part of the band is read.
+///
+/// # Output
+///
+/// * `l` -- the eigenvalues (n), in ascending order
+///
+/// **Note:** A temporary copy of the upper part of the band (`(ku + 1) ⋅ n` values) is allocated.
+pub fn mat_eigenvalues_sym_band(l: &mut Vector, a: &BandMatrix) -> Result<(), StrError> {
+    let n = a.dim();
+    if !a.has_symmetric_band() {
+        return Err("band matrix must have kl == ku");
+    }
+    if l.dim() != n {
+        return Err("l vector has incompatible dimension");
+    }
+    let mut ab = upper_band(a);
+    dsbev(
+        false,
+        true,
+        to_i32(n),
+        to_i32(a.ku()),
+        &mut ab,
+        to_i32(a.ku() + 1),
+        l.as_mut_data(),
+        &mut [],
+    )
+}
+
+/// Copies the diagonal and super-diagonals of a band matrix into a (ku + 1) × n col-major array
+///
+/// This is the part of the band read (and overwritten) by dsbev with `up = true`.
+fn upper_band(a: &BandMatrix) -> Vec<f64> {
+    let (n, ku, ldab) = (a.dim(), a.ku(), a.ldab());
+    let data = a.as_data();
+    let mut ab = Vec::with_capacity((ku + 1) * n);
+    for j in 0..n {
+        ab.extend_from_slice(&data[j * ldab..j * ldab + ku + 1]);
+    }
+    ab
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_eigen_sym_band, mat_eigenvalues_sym_band, upper_band};
+    use crate::testing::check_eigen_real;
+    use crate::{BandMatrix, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+    use std::f64::consts::PI;
+
+    fn tridiagonal(n: usize) -> BandMatrix {
+        let mut a = BandMatrix::new(n, 1, 1).unwrap();
+        for i in 0..n {
+            a.set(i, i, 2.0);
+            if i > 0 {
+                a.set(i, i - 1, -1.0);
+                a.set(i - 1, i, -1.0);
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn upper_band_works() {
+        let a = tridiagonal(3);
+        assert_eq!(a.as_data(), &[0.0, 2.0, -1.0, -1.0, 2.0, -1.0, -1.0, 2.0, 0.0]);
+        assert_eq!(upper_band(&a), &[0.0, 2.0, -1.0, 2.0, -1.0, 2.0]);
+    }
+
+    #[test]
+    fn mat_eigen_sym_band_handles_errors() {
+        let a = BandMatrix::new(2, 0, 1).unwrap();
+        let mut l = Vector::new(2);
+        let mut v = Matrix::new(2, 2);
+        assert_eq!(
+            mat_eigen_sym_band(&mut l, &mut v, &a).err(),
+            Some("band matrix must have kl == ku")
+        );
+        assert_eq!(
+            mat_eigenvalues_sym_band(&mut l, &a).err(),
+            Some("band matrix must have kl == ku")
+        );
+        let a = BandMatrix::new(2, 1, 1).unwrap();
+        let mut l_wrong = Vector::new(3);
+        assert_eq!(
+            mat_eigen_sym_band(&mut l_wrong, &mut v, &a).err(),
+            Some("l vector has incompatible dimension")
+        );
+        assert_eq!(
+            mat_eigenvalues_sym_band(&mut l_wrong, &a).err(),
+            Some("l vector has incompatible dimension")
+        );
+        let mut v_wrong = Matrix::new(2, 3);
+        assert_eq!(
+            mat_eigen_sym_band(&mut l, &mut v_wrong, &a).err(),
+            Some("v matrix has incompatible dimensions")
+        );
+    }
+
+    #[test]
+    fn mat_eigen_sym_band_works() {
+        let n = 4;
+        let a = tridiagonal(n);
+        let mut l = Vector::new(n);
+        let mut v = Matrix::new(n, n);
+        mat_eigen_sym_band(&mut l, &mut v, &a).unwrap();
+        let correct: Vec<_> = (1..=n)
+            .map(|k| 2.0 - 2.0 * f64::cos((k as f64) * PI / ((n + 1) as f64)))
+            .collect();
+        vec_approx_eq(l.as_data(), &correct, 1e-14);
+        check_eigen_real(&a.to_matrix(), &v, &l, 1e-14);
+
+        // eigenvalues only
+        let mut ll = Vector::new(n);
+        mat_eigenvalues_sym_band(&mut ll, &a).unwrap();
+        vec_approx_eq(ll.as_data(), &correct, 1e-14);
+    }
+
+    #[test]
+    fn mat_eigen_sym_band_works_pentadiagonal() {
+        #[rustfmt::skip]
+        let dense = Matrix::from(&[
+            [ 6.0, -4.0,  1.0,  0.0,  0.0],
+            [-4.0,  6.0, -4.0,  1.0,  0.0],
+            [ 1.0, -4.0,  6.0, -4.0,  1.0],
+            [ 0.0,  1.0, -4.0,  6.0, -4.0],
+            [ 0.0,  0.0,  1.0, -4.0,  6.0],
+        ]);
+        let a = BandMatrix::from_matrix(&dense, 2, 2).unwrap();
+        let mut l = Vector::new(5);
+        let mut v = Matrix::new(5, 5);
+        mat_eigen_sym_band(&mut l, &mut v, &a).unwrap();
+        check_eigen_real(&dense, &v, &l, 1e-13);
+    }
+}
@@ -0,0 +1,61 @@
+use super::Matrix;
+
+/// Checks whether two matrices are approximately equal to each other
+///
+/// Returns `true` if the matrices have the same dimensions and:
+///
+/// ```text
+/// |aᵢⱼ - bᵢⱼ| ≤ tol   for all i and j
+/// ```
+///
+/// Unlike [crate::mat_approx_eq], this function does not panic;
+/// thus, it can be used at runtime (e.g., to check the convergence of fixed-point iterations).
+///
+/// **Note:** Returns `false` if the matrix dimensions differ or if any component is NaN.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_eq, Matrix};
+///
+/// fn main() {
+///     let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+///     let b = Matrix::from(&[[1.0, 2.0], [3.0, 4.0 + 1e-12]]);
+///     assert!(mat_eq(&a, &b, 1e-10));
+///     assert!(!mat_eq(&a, &b, 1e-14));
+///     assert!(!mat_eq(&a, &Matrix::new(2, 3), 1e-10));
+/// }
+/// ```
+pub fn mat_eq(a: &Matrix, b: &Matrix, tol: f64) -> bool {
+    if a.dims() != b.dims() {
+        return false;
+    }
+    a.as_data().iter().zip(b.as_data()).all(|(x, y)| f64::abs(x - y) <= tol)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_eq, Matrix};
+
+    #[test]
+    fn mat_eq_handles_dimensions() {
+        let a = Matrix::new(2, 3);
+        assert!(!mat_eq(&a, &Matrix::new(3, 2), 1.0));
+        assert!(!mat_eq(&a, &Matrix::new(2, 2), 1.0));
+        assert!(mat_eq(&a, &Matrix::new(2, 3), 0.0));
+    }
+
+    #[test]
+    fn mat_eq_works() {
+        let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        assert!(mat_eq(&a, &b, 0.0));
+        let b = Matrix::from(&[[1.0, 2.25], [3.0, 4.0]]);
+        assert!(mat_eq(&a, &b, 0.25));
+        assert!(!mat_eq(&a, &b, 0.2));
+        let b = Matrix::from(&[[1.0, 2.0], [f64::NAN, 4.0]]);
+        assert!(!mat_eq(&a, &b, 1e10));
+    }
+}
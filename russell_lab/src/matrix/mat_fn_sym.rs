@@ -0,0 +1,302 @@
+use super::{mat_eigen_sym, mat_norm, Matrix};
+use crate::{Norm, StrError, Vector};
+
+/// Computes a function of a symmetric matrix
+///
+/// The matrix is diagonalized (see [mat_eigen_sym]) and the function is applied to the eigenvalues:
+///
+/// ```text
+/// a = v ⋅ diag(l) ⋅ vᵀ
+///
+/// b = f(a) = v ⋅ diag(f(l)) ⋅ vᵀ
+/// ```
+///
+/// # Output
+///
+/// * `b` -- (m,m) matrix (symmetric)
+///
+/// # Input
+///
+/// * `a` -- (m,m) matrix (SYMMETRIC; only the upper triangle is accessed)
+/// * `f` -- the function applied to each eigenvalue
+///
+/// **Note:** Nothing is done if m = 0.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_fn_sym, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // eigenvalues: 1 and 3
+///     let a = Matrix::from(&[
+///         [2.0, 1.0],
+///         [1.0, 2.0],
+///     ]);
+///
+///     // b = a³
+///     let mut b = Matrix::new(2, 2);
+///     mat_fn_sym(&mut b, &a, |l| l * l * l)?;
+///     let correct = "┌           ┐\n\
+///                    │ 14.0 13.0 │\n\
+///                    │ 13.0 14.0 │\n\
+///                    └           ┘";
+///     assert_eq!(format!("{:.1}", b), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_fn_sym<F>(b: &mut Matrix, a: &Matrix, mut f: F) -> Result<(), StrError>
+where
+    F: FnMut(f64) -> f64,
+{
+    fn_sym(b, a, |l| Ok(f(l)))
+}
+
+/// Computes the square root of a symmetric positive semi-definite matrix
+///
+/// Computes the (unique) symmetric positive semi-definite matrix `b` such that:
+///
+/// ```text
+/// b ⋅ b = a
+/// ```
+///
+/// See [mat_fn_sym]. An error is returned if an eigenvalue of `a` is negative. Nonetheless, tiny
+/// negative eigenvalues due to round-off errors (relative to the Frobenius norm of `a`) are set to zero.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_sqrt_sym, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [5.0, 4.0],
+///         [4.0, 5.0],
+///     ]);
+///     let mut b = Matrix::new(2, 2);
+///     mat_sqrt_sym(&mut b, &a)?;
+///     let correct = "┌         ┐\n\
+///                    │ 2.0 1.0 │\n\
+///                    │ 1.0 2.0 │\n\
+///                    └         ┘";
+///     assert_eq!(format!("{:.1}", b), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_sqrt_sym(b: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
+    let tol = (a.nrow() as f64) * f64::EPSILON * mat_norm(a, Norm::Fro);
+    fn_sym(b, a, |l| {
+        if l < -tol {
+            return Err("cannot compute the square root because an eigenvalue is negative");
+        }
+        Ok(f64::sqrt(f64::max(l, 0.0)))
+    })
+}
+
+/// Computes the (principal) logarithm of a symmetric positive definite matrix
+///
+/// Computes the symmetric matrix `b` such that:
+///
+/// ```text
+/// exp(b) = a
+/// ```
+///
+/// See [mat_fn_sym]. An error is returned if an eigenvalue of `a` is not positive.
+///
+/// For instance, the logarithmic (Hencky) strain tensor is `ε = ½ ln(C)`, where `C = Fᵀ⋅F` is
+/// the right Cauchy-Green deformation tensor.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_log_sym, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // uniaxial stretch λ = 2 along x
+///     let c = Matrix::from(&[
+///         [4.0, 0.0, 0.0],
+///         [0.0, 1.0, 0.0],
+///         [0.0, 0.0, 1.0],
+///     ]);
+///     let mut ln_c = Matrix::new(3, 3);
+///     mat_log_sym(&mut ln_c, &c)?;
+///     assert_eq!(format!("{:.6}", 0.5 * ln_c.get(0, 0)), "0.693147"); // ln(2)
+///     assert_eq!(format!("{:.6}", ln_c.get(1, 1)), "0.000000");
+///     Ok(())
+/// }
+/// ```
+pub fn mat_log_sym(b: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
+    fn_sym(b, a, |l| {
+        if l <= 0.0 {
+            return Err("cannot compute the logarithm because an eigenvalue is not positive");
+        }
+        Ok(f64::ln(l))
+    })
+}
+
+/// Implements the functions of symmetric matrices with a fallible function of the eigenvalues
+fn fn_sym<F>(b: &mut Matrix, a: &Matrix, mut f: F) -> Result<(), StrError>
+where
+    F: FnMut(f64) -> Result<f64, StrError>,
+{
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if b.dims() != (m, n) {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    let mut v = a.clone();
+    let mut l = Vector::new(m);
+    mat_eigen_sym(&mut l, &mut v)?;
+    let mut fl = Vector::new(m);
+    for k in 0..m {
+        fl[k] = f(l[k])?;
+    }
+    for i in 0..m {
+        for j in i..m {
+            let mut sum = 0.0;
+            for k in 0..m {
+                sum += v.get(i, k) * fl[k] * v.get(j, k);
+            }
+            b.set(i, j, sum);
+            b.set(j, i, sum);
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_fn_sym, mat_log_sym, mat_sqrt_sym};
+    use crate::{mat_approx_eq, mat_mat_mul, Matrix};
+
+    #[test]
+    fn mat_fn_sym_captures_errors() {
+        let mut b = Matrix::new(2, 2);
+        assert_eq!(
+            mat_fn_sym(&mut b, &Matrix::new(2, 3), |l| l).err(),
+            Some("matrix must be square")
+        );
+        assert_eq!(
+            mat_fn_sym(&mut b, &Matrix::new(3, 3), |l| l).err(),
+            Some("matrices are incompatible")
+        );
+        let a = Matrix::from(&[[1.0, 2.0], [2.0, 1.0]]); // eigenvalues: -1 and 3
+        assert_eq!(
+            mat_sqrt_sym(&mut b, &a).err(),
+            Some("cannot compute the square root because an eigenvalue is negative")
+        );
+        assert_eq!(
+            mat_log_sym(&mut b, &a).err(),
+            Some("cannot compute the logarithm because an eigenvalue is not positive")
+        );
+        assert_eq!(
+            mat_log_sym(&mut b, &Matrix::new(2, 2)).err(),
+            Some("cannot compute the logarithm because an eigenvalue is not positive")
+        );
+    }
+
+    #[test]
+    fn mat_fn_sym_works() {
+        // empty matrix
+        let mut b = Matrix::new(0, 0);
+        mat_fn_sym(&mut b, &Matrix::new(0, 0), |l| l).unwrap();
+
+        // identity function and square
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 2.0, -1.0,  0.0],
+            [-1.0,  2.0, -1.0],
+            [ 0.0, -1.0,  2.0],
+        ]);
+        let mut b = Matrix::new(3, 3);
+        mat_fn_sym(&mut b, &a, |l| l).unwrap();
+        mat_approx_eq(&b, &a, 1e-14);
+        let mut a2 = Matrix::new(3, 3);
+        mat_mat_mul(&mut a2, 1.0, &a, &a).unwrap();
+        mat_fn_sym(&mut b, &a, |l| l * l).unwrap();
+        mat_approx_eq(&b, &a2, 1e-14);
+
+        // inverse
+        mat_fn_sym(&mut b, &a, |l| 1.0 / l).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [0.75, 0.5, 0.25],
+            [0.5,  1.0, 0.5 ],
+            [0.25, 0.5, 0.75],
+        ];
+        mat_approx_eq(&b, correct, 1e-14);
+
+        // the closure may capture its environment
+        let mut count = 0;
+        mat_fn_sym(&mut b, &a, |l| {
+            count += 1;
+            l
+        })
+        .unwrap();
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn mat_sqrt_sym_works() {
+        // positive semi-definite
+        let a = Matrix::from(&[[1.0, 1.0], [1.0, 1.0]]);
+        let mut b = Matrix::new(2, 2);
+        mat_sqrt_sym(&mut b, &a).unwrap();
+        let s = 1.0 / f64::sqrt(2.0);
+        mat_approx_eq(&b, &[[s, s], [s, s]], 1e-15);
+
+        // b ⋅ b = a
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [4.0, 1.0, 0.5],
+            [1.0, 3.0, 0.2],
+            [0.5, 0.2, 2.0],
+        ]);
+        let mut b = Matrix::new(3, 3);
+        mat_sqrt_sym(&mut b, &a).unwrap();
+        let mut bb = Matrix::new(3, 3);
+        mat_mat_mul(&mut bb, 1.0, &b, &b).unwrap();
+        mat_approx_eq(&bb, &a, 1e-14);
+    }
+
+    #[test]
+    fn mat_log_sym_works() {
+        // diagonal
+        let a = Matrix::from(&[[1.0, 0.0], [0.0, f64::exp(2.0)]]);
+        let mut b = Matrix::new(2, 2);
+        mat_log_sym(&mut b, &a).unwrap();
+        mat_approx_eq(&b, &[[0.0, 0.0], [0.0, 2.0]], 1e-15);
+
+        // exp(ln(a)) = a
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [4.0, 1.0, 0.5],
+            [1.0, 3.0, 0.2],
+            [0.5, 0.2, 2.0],
+        ]);
+        let mut ln_a = Matrix::new(3, 3);
+        mat_log_sym(&mut ln_a, &a).unwrap();
+        let mut exp_ln_a = Matrix::new(3, 3);
+        mat_fn_sym(&mut exp_ln_a, &ln_a, f64::exp).unwrap();
+        mat_approx_eq(&exp_ln_a, &a, 1e-14);
+
+        // ln(a²) = 2 ln(a)
+        let mut a2 = Matrix::new(3, 3);
+        mat_mat_mul(&mut a2, 1.0, &a, &a).unwrap();
+        let mut ln_a2 = Matrix::new(3, 3);
+        mat_log_sym(&mut ln_a2, &a2).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                ln_a.set(i, j, 2.0 * ln_a.get(i, j));
+            }
+        }
+        mat_approx_eq(&ln_a2, &ln_a, 1e-14);
+    }
+}
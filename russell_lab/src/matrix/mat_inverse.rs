@@ -20,6 +20,9 @@ const ZERO_DETERMINANT: f64 = 1e-15;
 ///
 /// * `a` -- (m,m) matrix, symmetric or not
 ///
+/// **Note:** The inverse of 1 x 1, 2 x 2, and 3 x 3 matrices is computed with explicit formulae.
+/// Otherwise, LAPACK dgetrf and dgetri are used. See [crate::mat_det] to compute the determinant only.
+///
 /// # Examples
 ///
 /// ## First -- 2 x 2 square matrix
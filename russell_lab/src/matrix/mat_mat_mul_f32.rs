@@ -0,0 +1,101 @@
+use super::MatrixF32;
+use crate::StrError;
+use russell_openblas::{sgemm, to_i32};
+
+/// Performs the matrix-matrix multiplication resulting in a matrix (single precision)
+///
+/// ```text
+///   c  :=  α ⋅  a   ⋅   b
+/// (m,n)       (m,k)   (k,n)
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_mat_mul_f32, MatrixF32, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = MatrixF32::from(&[
+///         [1.0_f32, 2.0],
+///         [3.0_f32, 4.0],
+///         [5.0_f32, 6.0],
+///     ]);
+///     let b = MatrixF32::from(&[
+///         [-1.0_f32, -2.0, -3.0],
+///         [-4.0_f32, -5.0, -6.0],
+///     ]);
+///     let mut c = MatrixF32::new(3, 3);
+///     mat_mat_mul_f32(&mut c, 1.0, &a, &b)?;
+///     let correct = "┌             ┐\n\
+///                    │  -9 -12 -15 │\n\
+///                    │ -19 -26 -33 │\n\
+///                    │ -29 -40 -51 │\n\
+///                    └             ┘";
+///     assert_eq!(format!("{}", c), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_mat_mul_f32(c: &mut MatrixF32, alpha: f32, a: &MatrixF32, b: &MatrixF32) -> Result<(), StrError> {
+    let (m, n) = c.dims();
+    let k = a.ncol();
+    if a.nrow() != m || b.nrow() != k || b.ncol() != n {
+        return Err("matrices are incompatible");
+    }
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+    let m_i32: i32 = to_i32(m);
+    let n_i32: i32 = to_i32(n);
+    let k_i32: i32 = to_i32(k);
+    sgemm(
+        false,
+        false,
+        m_i32,
+        n_i32,
+        k_i32,
+        alpha,
+        a.as_data(),
+        b.as_data(),
+        0.0,
+        c.as_mut_data(),
+    );
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_mat_mul_f32, MatrixF32};
+
+    #[test]
+    fn mat_mat_mul_f32_fails_on_wrong_dims() {
+        let mut c = MatrixF32::new(2, 2);
+        assert_eq!(
+            mat_mat_mul_f32(&mut c, 1.0, &MatrixF32::new(2, 1), &MatrixF32::new(2, 1)),
+            Err("matrices are incompatible")
+        );
+        assert_eq!(
+            mat_mat_mul_f32(&mut c, 1.0, &MatrixF32::new(1, 2), &MatrixF32::new(2, 2)),
+            Err("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn mat_mat_mul_f32_works() {
+        #[rustfmt::skip]
+        let a = MatrixF32::from(&[
+            [1.0_f32, 2.0, 3.0],
+            [0.5_f32, 0.0, 1.0],
+        ]);
+        #[rustfmt::skip]
+        let b = MatrixF32::from(&[
+            [1.0_f32, 0.0],
+            [0.0_f32, 1.0],
+            [2.0_f32, 0.5],
+        ]);
+        let mut c = MatrixF32::new(2, 2);
+        mat_mat_mul_f32(&mut c, 2.0, &a, &b).unwrap();
+        assert_eq!(c.as_data(), &[14.0, 5.0, 7.0, 1.0]); // col-major
+    }
+}
@@ -0,0 +1,320 @@
+use super::NumMatrix;
+use crate::StrError;
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Write as IoWrite};
+use std::path::Path;
+
+impl NumMatrix<f64> {
+    /// Reads a dense matrix from a file in MatrixMarket array format
+    ///
+    /// The file must have the header `%%MatrixMarket matrix array real general` (or `integer`
+    /// instead of `real`). The `symmetric` and `skew-symmetric` keywords are also accepted; in this
+    /// case, only the lower triangle (with or without the diagonal, respectively) is given in the file.
+    ///
+    /// After the header, lines starting with `%` and empty lines are ignored. The next line contains
+    /// the dimensions `m n` followed by one value per line in column-major order.
+    ///
+    /// # Input
+    ///
+    /// * `full_path` -- may be a String, &str, or Path
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::{Matrix, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let path = "/tmp/russell_lab/doc_read_mm_array.mtx";
+    ///     a.write_mm_array(path)?;
+    ///     let b = Matrix::read_mm_array(path)?;
+    ///     assert_eq!(b.as_data(), a.as_data());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn read_mm_array<P>(full_path: &P) -> Result<Self, StrError>
+    where
+        P: AsRef<OsStr> + ?Sized,
+    {
+        let path = Path::new(full_path).to_path_buf();
+        let input = File::open(path).map_err(|_| "cannot open file")?;
+        let buffered = BufReader::new(input);
+        let mut lines_iter = buffered.lines();
+
+        // parse header
+        let header = match lines_iter.next() {
+            Some(v) => v.map_err(|_| "cannot read file")?,
+            None => return Err("file is empty"),
+        };
+        let options: Vec<&str> = header.split_whitespace().collect();
+        if options.len() != 5 || options[0] != "%%MatrixMarket" || options[1] != "matrix" {
+            return Err("the header (first line) must be %%MatrixMarket matrix array <field> <symmetry>");
+        }
+        if options[2] != "array" {
+            return Err("the format must be \"array\"");
+        }
+        if options[3] != "real" && options[3] != "integer" {
+            return Err("the field must be either \"real\" or \"integer\"");
+        }
+        let (symmetric, skew) = match options[4] {
+            "general" => (false, false),
+            "symmetric" => (true, false),
+            "skew-symmetric" => (true, true),
+            _ => return Err("the symmetry must be \"general\", \"symmetric\" or \"skew-symmetric\""),
+        };
+
+        // parse dimensions and values
+        let mut dims: Option<(usize, usize)> = None;
+        let mut values = Vec::new();
+        for line in lines_iter {
+            let line = line.map_err(|_| "cannot read file")?;
+            let maybe_data = line.trim();
+            if maybe_data.starts_with('%') || maybe_data.is_empty() {
+                continue;
+            }
+            if dims.is_none() {
+                let mut data = maybe_data.split_whitespace();
+                let m = data.next().and_then(|s| s.parse::<usize>().ok());
+                let n = data.next().and_then(|s| s.parse::<usize>().ok());
+                match (m, n) {
+                    (Some(m), Some(n)) => dims = Some((m, n)),
+                    _ => return Err("cannot parse the dimensions"),
+                }
+                continue;
+            }
+            for s in maybe_data.split_whitespace() {
+                values.push(s.parse::<f64>().map_err(|_| "cannot parse value")?);
+            }
+        }
+        let (m, n) = match dims {
+            Some(d) => d,
+            None => return Err("cannot find the dimensions"),
+        };
+
+        // fill matrix
+        let mut a = NumMatrix::new(m, n);
+        if symmetric {
+            if m != n {
+                return Err("symmetric matrices must be square");
+            }
+            let expected = if skew {
+                n * n.saturating_sub(1) / 2
+            } else {
+                n * (n + 1) / 2
+            };
+            if values.len() != expected {
+                return Err("the number of values is incorrect");
+            }
+            let mut k = 0;
+            for j in 0..n {
+                let start = if skew { j + 1 } else { j };
+                for i in start..n {
+                    a.set(i, j, values[k]);
+                    a.set(j, i, if skew { -values[k] } else { values[k] });
+                    k += 1;
+                }
+            }
+        } else {
+            if values.len() != m * n {
+                return Err("the number of values is incorrect");
+            }
+            a.as_mut_data().copy_from_slice(&values);
+        }
+        Ok(a)
+    }
+
+    /// Writes this matrix to a file in MatrixMarket array format
+    ///
+    /// The header is `%%MatrixMarket matrix array real general` and the values are written in
+    /// column-major order (one per line) with full precision. The directory is created if needed.
+    ///
+    /// # Input
+    ///
+    /// * `full_path` -- may be a String, &str, or Path
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::{Matrix, StrError};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+    ///     let path = "/tmp/russell_lab/doc_write_mm_array.mtx";
+    ///     a.write_mm_array(path)?;
+    ///     let contents = fs::read_to_string(path).map_err(|_| "cannot open file")?;
+    ///     assert_eq!(
+    ///         contents,
+    ///         "%%MatrixMarket matrix array real general\n\
+    ///          2 2\n\
+    ///          1.0\n\
+    ///          3.0\n\
+    ///          2.0\n\
+    ///          4.0\n"
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn write_mm_array<P>(&self, full_path: &P) -> Result<(), StrError>
+    where
+        P: AsRef<OsStr> + ?Sized,
+    {
+        // prepare content
+        let mut buffer = String::new();
+        writeln!(&mut buffer, "%%MatrixMarket matrix array real general").unwrap();
+        writeln!(&mut buffer, "{} {}", self.nrow(), self.ncol()).unwrap();
+        for value in self.as_data() {
+            writeln!(&mut buffer, "{:?}", value).unwrap();
+        }
+
+        // create directory
+        let path = Path::new(full_path);
+        if let Some(p) = path.parent() {
+            fs::create_dir_all(p).map_err(|_| "cannot create directory")?;
+        }
+
+        // write data to file
+        let mut file = File::create(path).map_err(|_| "cannot create file")?;
+        file.write_all(buffer.as_bytes()).map_err(|_| "cannot write file")?;
+
+        // force sync
+        file.sync_all().map_err(|_| "cannot sync file")?;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+    use std::fs;
+
+    fn write_file(name: &str, contents: &str) -> String {
+        let path = format!("/tmp/russell_lab/test_{}.mtx", name);
+        fs::create_dir_all("/tmp/russell_lab").unwrap();
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn read_mm_array_captures_errors() {
+        assert_eq!(
+            Matrix::read_mm_array("/tmp/russell_lab/__wrong__.mtx").err(),
+            Some("cannot open file")
+        );
+        let path = write_file("mm_array_empty", "");
+        assert_eq!(Matrix::read_mm_array(&path).err(), Some("file is empty"));
+        let path = write_file("mm_array_bad_header", "%%MatrixMarket matrix\n");
+        assert_eq!(
+            Matrix::read_mm_array(&path).err(),
+            Some("the header (first line) must be %%MatrixMarket matrix array <field> <symmetry>")
+        );
+        let path = write_file("mm_array_coordinate", "%%MatrixMarket matrix coordinate real general\n");
+        assert_eq!(Matrix::read_mm_array(&path).err(), Some("the format must be \"array\""));
+        let path = write_file("mm_array_complex", "%%MatrixMarket matrix array complex general\n");
+        assert_eq!(
+            Matrix::read_mm_array(&path).err(),
+            Some("the field must be either \"real\" or \"integer\"")
+        );
+        let path = write_file("mm_array_hermitian", "%%MatrixMarket matrix array real hermitian\n");
+        assert_eq!(
+            Matrix::read_mm_array(&path).err(),
+            Some("the symmetry must be \"general\", \"symmetric\" or \"skew-symmetric\"")
+        );
+        let path = write_file(
+            "mm_array_no_dims",
+            "%%MatrixMarket matrix array real general\n% comment\n",
+        );
+        assert_eq!(Matrix::read_mm_array(&path).err(), Some("cannot find the dimensions"));
+        let path = write_file("mm_array_bad_dims", "%%MatrixMarket matrix array real general\n2 x\n");
+        assert_eq!(Matrix::read_mm_array(&path).err(), Some("cannot parse the dimensions"));
+        let path = write_file(
+            "mm_array_bad_value",
+            "%%MatrixMarket matrix array real general\n1 1\nabc\n",
+        );
+        assert_eq!(Matrix::read_mm_array(&path).err(), Some("cannot parse value"));
+        let path = write_file(
+            "mm_array_few_values",
+            "%%MatrixMarket matrix array real general\n2 1\n1.0\n",
+        );
+        assert_eq!(
+            Matrix::read_mm_array(&path).err(),
+            Some("the number of values is incorrect")
+        );
+        let path = write_file(
+            "mm_array_sym_rect",
+            "%%MatrixMarket matrix array real symmetric\n2 1\n1\n2\n",
+        );
+        assert_eq!(
+            Matrix::read_mm_array(&path).err(),
+            Some("symmetric matrices must be square")
+        );
+    }
+
+    #[test]
+    fn read_mm_array_works() {
+        let path = write_file(
+            "mm_array_general",
+            "%%MatrixMarket matrix array real general\n\
+             % a comment\n\
+             \n\
+             2 3\n\
+             1\n4\n2\n5\n3\n6\n",
+        );
+        let a = Matrix::read_mm_array(&path).unwrap();
+        assert_eq!(
+            format!("{}", a),
+            "┌       ┐\n\
+             │ 1 2 3 │\n\
+             │ 4 5 6 │\n\
+             └       ┘"
+        );
+
+        let path = write_file(
+            "mm_array_symmetric",
+            "%%MatrixMarket matrix array integer symmetric\n3 3\n1\n2\n3\n4\n5\n6\n",
+        );
+        let a = Matrix::read_mm_array(&path).unwrap();
+        assert_eq!(
+            format!("{}", a),
+            "┌       ┐\n\
+             │ 1 2 3 │\n\
+             │ 2 4 5 │\n\
+             │ 3 5 6 │\n\
+             └       ┘"
+        );
+
+        let path = write_file(
+            "mm_array_skew",
+            "%%MatrixMarket matrix array real skew-symmetric\n3 3\n1\n2\n3\n",
+        );
+        let a = Matrix::read_mm_array(&path).unwrap();
+        assert_eq!(
+            format!("{}", a),
+            "┌          ┐\n\
+             │  0 -1 -2 │\n\
+             │  1  0 -3 │\n\
+             │  2  3  0 │\n\
+             └          ┘"
+        );
+    }
+
+    #[test]
+    fn write_and_read_mm_array_round_trip() {
+        let a = Matrix::from(&[[1.0 / 3.0, -2.5e-300], [f64::MAX, 0.1]]);
+        let path = "/tmp/russell_lab/test_mm_array_round_trip.mtx";
+        a.write_mm_array(path).unwrap();
+        let b = Matrix::read_mm_array(path).unwrap();
+        assert_eq!(b.dims(), (2, 2));
+        assert_eq!(b.as_data(), a.as_data());
+
+        let empty = Matrix::new(0, 3);
+        let path = "/tmp/russell_lab/test_mm_array_empty.mtx";
+        empty.write_mm_array(path).unwrap();
+        let b = Matrix::read_mm_array(path).unwrap();
+        assert_eq!(b.dims(), (0, 3));
+    }
+}
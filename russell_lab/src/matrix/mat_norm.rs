@@ -1,9 +1,14 @@
 use super::Matrix;
+use crate::enums::{assert_valid_norm, p_norm_of_abs};
+use crate::kernels::{dlange, to_i32};
 use crate::Norm;
-use russell_openblas::{dlange, to_i32};
 
 /// Computes the matrix norm
 ///
+/// # Panics
+///
+/// Panics if `kind` is `Norm::P(p)` with `p < 1` or `p = NaN`.
+///
 /// # Example
 ///
 /// ```
@@ -21,6 +26,7 @@ use russell_openblas::{dlange, to_i32};
 /// }
 /// ```
 pub fn mat_norm(a: &Matrix, kind: Norm) -> f64 {
+    assert_valid_norm(kind);
     let (m, n) = a.dims();
     if m == 0 || n == 0 {
         return 0.0;
@@ -30,6 +36,7 @@ pub fn mat_norm(a: &Matrix, kind: Norm) -> f64 {
         Norm::Inf => b'I',
         Norm::Max => b'M',
         Norm::One => b'1',
+        Norm::P(p) => return p_norm_of_abs(a.as_data().iter().map(|x| f64::abs(*x)), p),
     };
     let (m_i32, n_i32) = (to_i32(m), to_i32(n));
     dlange(norm, m_i32, n_i32, &a.as_data())
@@ -84,4 +91,25 @@ mod tests {
         assert_eq!(mat_norm(&diff, Norm::One), 1.83);
         approx_eq(mat_norm(&diff, Norm::Fro), 1.87, 0.01);
     }
+
+    #[test]
+    fn mat_norm_p_works() {
+        let a_0x0 = Matrix::new(0, 0);
+        assert_eq!(mat_norm(&a_0x0, Norm::P(3.0)), 0.0);
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 5.0, -4.0, 2.0],
+            [-1.0,  2.0, 3.0],
+            [-2.0,  1.0, 0.0],
+        ]);
+        approx_eq(mat_norm(&a, Norm::P(1.0)), 20.0, 1e-15);
+        approx_eq(mat_norm(&a, Norm::P(2.0)), 8.0, 1e-15);
+        assert_eq!(mat_norm(&a, Norm::P(f64::INFINITY)), 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the p-norm requires p ≥ 1")]
+    fn mat_norm_panics_on_invalid_p() {
+        mat_norm(&Matrix::from(&[[1.0, 2.0]]), Norm::P(0.5));
+    }
 }
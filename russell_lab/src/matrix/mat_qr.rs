@@ -0,0 +1,391 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+use russell_openblas::{dgeqrf, dorgqr, dormqr, to_i32};
+
+/// Computes the QR factorization of a matrix
+///
+/// Finds `q` and `r` such that:
+///
+/// ```text
+///   a  :=  q   ⋅   r
+/// (m,n)  (m,m)   (m,n)
+/// ```
+///
+/// where `q` is orthogonal and `r` is upper triangular (trapezoidal if m ≠ n).
+///
+/// # Output
+///
+/// * `q` -- (m,m) orthogonal matrix
+/// * `r` -- (m,n) upper triangular matrix
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix [will be modified]
+///
+/// # Note
+///
+/// 1. The matrix `a` will be modified
+/// 2. The signs of the rows of `r` (and columns of `q`) are defined by LAPACK; i.e., the diagonal
+///    of `r` may contain negative values
+/// 3. See [mat_qr_thin] for the economy version with (m,min(m,n)) `q`
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_qr, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // set matrix
+///     let mut a = Matrix::from(&[
+///         [3.0, 2.0],
+///         [4.0, 1.0],
+///     ]);
+///
+///     // perform factorization
+///     let (m, n) = a.dims();
+///     let mut q = Matrix::new(m, m);
+///     let mut r = Matrix::new(m, n);
+///     mat_qr(&mut q, &mut r, &mut a)?;
+///
+///     // check results
+///     let q_correct = "┌           ┐\n\
+///                      │ -0.6 -0.8 │\n\
+///                      │ -0.8  0.6 │\n\
+///                      └           ┘";
+///     let r_correct = "┌           ┐\n\
+///                      │ -5.0 -2.0 │\n\
+///                      │  0.0 -1.0 │\n\
+///                      └           ┘";
+///     assert_eq!(format!("{:.1}", q), q_correct);
+///     assert_eq!(format!("{:.1}", r), r_correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_qr(q: &mut Matrix, r: &mut Matrix, a: &mut Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if q.nrow() != m || q.ncol() != m {
+        return Err("[q] must be an m-by-m square matrix");
+    }
+    if r.nrow() != m || r.ncol() != n {
+        return Err("[r] must be an m-by-n matrix");
+    }
+    qr_factorize(q, r, a, m)
+}
+
+/// Computes the thin (economy) QR factorization of a matrix
+///
+/// Finds `q` and `r` such that:
+///
+/// ```text
+///   a  :=  q   ⋅   r
+/// (m,n)  (m,k)   (k,n)     with  k = min(m,n)
+/// ```
+///
+/// where `q` has orthonormal columns and `r` is upper triangular (trapezoidal if m < n).
+/// If m > n, this version saves the memory and computations of the last `m - n` columns of `q`.
+///
+/// # Output
+///
+/// * `q` -- (m,k) matrix with orthonormal columns
+/// * `r` -- (k,n) upper triangular matrix
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix [will be modified]
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_qr_thin, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // set matrix
+///     let mut a = Matrix::from(&[
+///         [1.0, -1.0],
+///         [1.0,  4.0],
+///         [1.0,  4.0],
+///         [1.0, -1.0],
+///     ]);
+///
+///     // perform factorization
+///     let mut q = Matrix::new(4, 2);
+///     let mut r = Matrix::new(2, 2);
+///     mat_qr_thin(&mut q, &mut r, &mut a)?;
+///
+///     // check results
+///     let r_correct = "┌           ┐\n\
+///                      │ -2.0 -3.0 │\n\
+///                      │  0.0 -5.0 │\n\
+///                      └           ┘";
+///     assert_eq!(format!("{:.1}", r), r_correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_qr_thin(q: &mut Matrix, r: &mut Matrix, a: &mut Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    let k = if m < n { m } else { n };
+    if q.nrow() != m || q.ncol() != k {
+        return Err("[q] must be an m-by-min(m,n) matrix");
+    }
+    if r.nrow() != k || r.ncol() != n {
+        return Err("[r] must be a min(m,n)-by-n matrix");
+    }
+    qr_factorize(q, r, a, k)
+}
+
+/// Computes the QR factorization of a matrix in compact (Householder) form
+///
+/// On exit, the elements on and above the diagonal of `a` contain `r` and the elements below the
+/// diagonal, with the vector `tau`, represent `q` as a product of min(m,n) elementary reflectors.
+/// This form is used by [mat_qr_apply_qt] to compute `qᵀ ⋅ b` without forming `q`; e.g., to solve
+/// a least-squares problem `r ⋅ x = qᵀ ⋅ b` with several right-hand sides.
+///
+/// # Output
+///
+/// * `tau` -- min(m,n) vector with the scalar factors of the elementary reflectors
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix [will be overwritten by the compact factorization]
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_qr_apply_qt, mat_qr_compact, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // factorize
+///     let mut a = Matrix::from(&[
+///         [3.0, 2.0],
+///         [4.0, 1.0],
+///     ]);
+///     let mut tau = Vector::new(2);
+///     mat_qr_compact(&mut tau, &mut a)?;
+///
+///     // compute qᵀ ⋅ b
+///     let mut b = Vector::from(&[1.0, 0.0]);
+///     mat_qr_apply_qt(&mut b, &a, &tau)?;
+///     assert_eq!(format!("{:.1}", b), "┌      ┐\n\
+///                                      │ -0.6 │\n\
+///                                      │ -0.8 │\n\
+///                                      └      ┘");
+///     Ok(())
+/// }
+/// ```
+pub fn mat_qr_compact(tau: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    let k = if m < n { m } else { n };
+    if tau.dim() != k {
+        return Err("[tau] must be a min(m,n) vector");
+    }
+    dgeqrf(to_i32(m), to_i32(n), a.as_mut_data(), tau.as_mut_data())
+}
+
+/// Computes qᵀ ⋅ b using the compact QR factorization (without forming q)
+///
+/// ```text
+///  b  :=  qᵀ  ⋅  b
+/// (m)   (m,m)   (m)
+/// ```
+///
+/// # Input
+///
+/// * `b` -- m vector [will be overwritten by qᵀ ⋅ b]
+/// * `a` -- (m,n) compact factorization computed by [mat_qr_compact]
+/// * `tau` -- min(m,n) vector computed by [mat_qr_compact]
+pub fn mat_qr_apply_qt(b: &mut Vector, a: &Matrix, tau: &Vector) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    let k = if m < n { m } else { n };
+    if tau.dim() != k {
+        return Err("[tau] must be a min(m,n) vector");
+    }
+    if b.dim() != m {
+        return Err("[b] must be an m vector");
+    }
+    dormqr(
+        true,
+        true,
+        to_i32(m),
+        1,
+        to_i32(k),
+        a.as_data(),
+        tau.as_data(),
+        b.as_mut_data(),
+    )
+}
+
+/// Performs the factorization and generates the first ncol_q columns of q
+fn qr_factorize(q: &mut Matrix, r: &mut Matrix, a: &mut Matrix, ncol_q: usize) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    let k = if m < n { m } else { n };
+    if k == 0 {
+        return Ok(());
+    }
+    let mut tau = vec![0.0; k];
+    dgeqrf(to_i32(m), to_i32(n), a.as_mut_data(), &mut tau)?;
+
+    // extract r from the upper part of a
+    for i in 0..r.nrow() {
+        for j in 0..n {
+            r.set(i, j, if i <= j { a.get(i, j) } else { 0.0 });
+        }
+    }
+
+    // copy the elementary reflectors to q and generate q
+    for j in 0..ncol_q {
+        for i in 0..m {
+            q.set(i, j, if j < k { a.get(i, j) } else { 0.0 });
+        }
+    }
+    dorgqr(to_i32(m), to_i32(ncol_q), to_i32(k), q.as_mut_data(), &tau)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_qr, mat_qr_apply_qt, mat_qr_compact, mat_qr_thin, Matrix, Vector};
+    use crate::{mat_approx_eq, mat_mat_mul, mat_t_mat_mul};
+    use russell_chk::vec_approx_eq;
+
+    /// Checks that q ⋅ r = a, qᵀ ⋅ q = I, and r is upper triangular
+    fn check_qr(q: &Matrix, r: &Matrix, a: &Matrix, tol: f64) {
+        let (m, n) = a.dims();
+        let k = q.ncol();
+        let mut qr = Matrix::new(m, n);
+        mat_mat_mul(&mut qr, 1.0, q, r).unwrap();
+        mat_approx_eq(&qr, a, tol);
+        let mut qtq = Matrix::new(k, k);
+        mat_t_mat_mul(&mut qtq, 1.0, q, q).unwrap();
+        mat_approx_eq(&qtq, &Matrix::identity(k), tol);
+        for i in 0..r.nrow() {
+            for j in 0..i {
+                assert_eq!(r.get(i, j), 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn mat_qr_fails_on_wrong_dims() {
+        let mut a = Matrix::new(3, 2);
+        let mut q_2x2 = Matrix::new(2, 2);
+        let mut q_3x2 = Matrix::new(3, 2);
+        let mut q_3x3 = Matrix::new(3, 3);
+        let mut r_2x2 = Matrix::new(2, 2);
+        let mut r_3x2 = Matrix::new(3, 2);
+        assert_eq!(
+            mat_qr(&mut q_2x2, &mut r_3x2, &mut a),
+            Err("[q] must be an m-by-m square matrix")
+        );
+        assert_eq!(
+            mat_qr(&mut q_3x3, &mut r_2x2, &mut a),
+            Err("[r] must be an m-by-n matrix")
+        );
+        assert_eq!(
+            mat_qr_thin(&mut q_3x3, &mut r_2x2, &mut a),
+            Err("[q] must be an m-by-min(m,n) matrix")
+        );
+        assert_eq!(
+            mat_qr_thin(&mut q_3x2, &mut r_3x2, &mut a),
+            Err("[r] must be a min(m,n)-by-n matrix")
+        );
+        let mut tau = Vector::new(3);
+        assert_eq!(mat_qr_compact(&mut tau, &mut a), Err("[tau] must be a min(m,n) vector"));
+        let mut b = Vector::new(3);
+        assert_eq!(
+            mat_qr_apply_qt(&mut b, &a, &tau),
+            Err("[tau] must be a min(m,n) vector")
+        );
+        let tau = Vector::new(2);
+        let mut b = Vector::new(2);
+        assert_eq!(mat_qr_apply_qt(&mut b, &a, &tau), Err("[b] must be an m vector"));
+    }
+
+    #[test]
+    fn mat_qr_works() {
+        #[rustfmt::skip]
+        let a_original = Matrix::from(&[
+            [12.0, -51.0,   4.0],
+            [ 6.0, 167.0, -68.0],
+            [-4.0,  24.0, -41.0],
+        ]);
+        let mut a = a_original.clone();
+        let mut q = Matrix::new(3, 3);
+        let mut r = Matrix::new(3, 3);
+        mat_qr(&mut q, &mut r, &mut a).unwrap();
+        check_qr(&q, &r, &a_original, 1e-13);
+        let diag: Vec<_> = (0..3).map(|i| f64::abs(r.get(i, i))).collect();
+        vec_approx_eq(&diag, &[14.0, 175.0, 35.0], 1e-13);
+    }
+
+    #[test]
+    fn mat_qr_rectangular_works() {
+        #[rustfmt::skip]
+        let a_original = Matrix::from(&[
+            [1.0, -1.0,  4.0],
+            [1.0,  4.0, -2.0],
+            [1.0,  4.0,  2.0],
+            [1.0, -1.0,  0.0],
+        ]);
+
+        // full
+        let mut a = a_original.clone();
+        let mut q = Matrix::new(4, 4);
+        let mut r = Matrix::new(4, 3);
+        mat_qr(&mut q, &mut r, &mut a).unwrap();
+        check_qr(&q, &r, &a_original, 1e-14);
+
+        // thin
+        let mut a = a_original.clone();
+        let mut q_thin = Matrix::new(4, 3);
+        let mut r_thin = Matrix::new(3, 3);
+        mat_qr_thin(&mut q_thin, &mut r_thin, &mut a).unwrap();
+        check_qr(&q_thin, &r_thin, &a_original, 1e-14);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(r_thin.get(i, j), r.get(i, j));
+            }
+        }
+
+        // wide matrix (m < n)
+        let mut a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let a_original = a.clone();
+        let mut q = Matrix::new(2, 2);
+        let mut r = Matrix::new(2, 3);
+        mat_qr_thin(&mut q, &mut r, &mut a).unwrap();
+        check_qr(&q, &r, &a_original, 1e-14);
+    }
+
+    #[test]
+    fn mat_qr_apply_qt_works() {
+        #[rustfmt::skip]
+        let a_original = Matrix::from(&[
+            [1.0, -1.0,  4.0],
+            [1.0,  4.0, -2.0],
+            [1.0,  4.0,  2.0],
+            [1.0, -1.0,  0.0],
+        ]);
+        let mut a = a_original.clone();
+        let mut q = Matrix::new(4, 4);
+        let mut r = Matrix::new(4, 3);
+        mat_qr(&mut q, &mut r, &mut a).unwrap();
+
+        let mut a = a_original.clone();
+        let mut tau = Vector::new(3);
+        mat_qr_compact(&mut tau, &mut a).unwrap();
+        let b_original = [1.0, 2.0, 3.0, 4.0];
+        let mut b = Vector::from(&b_original);
+        mat_qr_apply_qt(&mut b, &a, &tau).unwrap();
+
+        // compare with qᵀ ⋅ b computed with the explicit q
+        let qt_b: Vec<_> = (0..4)
+            .map(|j| (0..4).map(|i| q.get(i, j) * b_original[i]).sum::<f64>())
+            .collect();
+        vec_approx_eq(b.as_data(), &qt_b, 1e-14);
+
+        // the norm is preserved
+        let norm: f64 = b.as_data().iter().map(|v| v * v).sum();
+        vec_approx_eq(&[norm], &[30.0], 1e-13);
+    }
+}
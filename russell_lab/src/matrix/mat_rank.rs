@@ -0,0 +1,206 @@
+use super::{mat_svd, Matrix};
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Computes the numerical rank of a matrix
+///
+/// The rank is the number of singular values of `a` (see [mat_svd]) that are greater than
+/// the relative tolerance times the largest singular value:
+///
+/// ```text
+/// rank(a) = #{ sᵢ > tol ⋅ s₀ }
+/// ```
+///
+/// **Note:** The rank of a matrix with zero rows or columns (or of a zero matrix) is zero.
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix (any shape)
+/// * `tol` -- relative tolerance for the singular values (`0 ≤ tol < 1`; e.g., `1e-12`)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_rank, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // the third row is the sum of the first two rows
+///     let a = Matrix::from(&[
+///         [1.0, 2.0, 3.0],
+///         [4.0, 5.0, 6.0],
+///         [5.0, 7.0, 9.0],
+///     ]);
+///     assert_eq!(mat_rank(&a, 1e-12)?, 2);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_rank(a: &Matrix, tol: f64) -> Result<usize, StrError> {
+    let (rank, _) = svd_rank(a, tol)?;
+    Ok(rank)
+}
+
+/// Computes an orthonormal basis of the null space of a matrix
+///
+/// Finds the (n,d) matrix `z` with orthonormal columns such that:
+///
+/// ```text
+///   a   ⋅   z   =   0
+/// (m,n)   (n,d)   (m,d)
+/// ```
+///
+/// where `d = n - rank(a)` is the dimension of the null space (see [mat_rank]). The columns of `z`
+/// are the right singular vectors of `a` (see [mat_svd]) corresponding to the singular values
+/// `sᵢ ≤ tol ⋅ s₀` (including the missing ones if m < n).
+///
+/// For example, the null space of a singular stiffness matrix contains the rigid-body modes
+/// of the (unsupported) structure.
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix (any shape)
+/// * `tol` -- relative tolerance for the singular values (`0 ≤ tol < 1`; e.g., `1e-12`)
+///
+/// # Output
+///
+/// Returns the (n,d) matrix `z` (d = 0 if `a` has full column rank)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_null_space, mat_vec_mul, vec_norm, Matrix, Norm, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // stiffness matrix of two unsupported springs (0)--(1)--(2)
+///     let a = Matrix::from(&[
+///         [ 1.0, -1.0,  0.0],
+///         [-1.0,  2.0, -1.0],
+///         [ 0.0, -1.0,  1.0],
+///     ]);
+///     let z = mat_null_space(&a, 1e-12)?;
+///
+///     // the rigid-body translation
+///     assert_eq!(z.dims(), (3, 1));
+///     let u = Vector::from(&[z.get(0, 0), z.get(1, 0), z.get(2, 0)]);
+///     assert_eq!(format!("{:.6}", u[0] * u[0] * 3.0), "1.000000");
+///     assert_eq!(format!("{:.6}", u[1] / u[0]), "1.000000");
+///     assert_eq!(format!("{:.6}", u[2] / u[0]), "1.000000");
+///
+///     // a ⋅ u = 0
+///     let mut v = Vector::new(3);
+///     mat_vec_mul(&mut v, 1.0, &a, &u)?;
+///     assert_eq!(format!("{:.6}", vec_norm(&v, Norm::Euc)), "0.000000");
+///     Ok(())
+/// }
+/// ```
+pub fn mat_null_space(a: &Matrix, tol: f64) -> Result<Matrix, StrError> {
+    let (rank, vt) = svd_rank(a, tol)?;
+    let n = a.ncol();
+    let mut z = Matrix::new(n, n - rank);
+    for j in rank..n {
+        for i in 0..n {
+            z.set(i, j - rank, vt.get(j, i));
+        }
+    }
+    Ok(z)
+}
+
+/// Computes the numerical rank and the (n,n) transposed right singular vectors
+fn svd_rank(a: &Matrix, tol: f64) -> Result<(usize, Matrix), StrError> {
+    if !(0.0..1.0).contains(&tol) {
+        return Err("tol must satisfy 0 ≤ tol < 1");
+    }
+    let (m, n) = a.dims();
+    let min_mn = usize::min(m, n);
+    if min_mn == 0 {
+        return Ok((0, Matrix::identity(n)));
+    }
+    let mut aa = a.clone();
+    let mut s = Vector::new(min_mn);
+    let mut u = Matrix::new(m, m);
+    let mut vt = Matrix::new(n, n);
+    mat_svd(&mut s, &mut u, &mut vt, &mut aa)?;
+    let threshold = tol * s[0];
+    let rank = s.as_data().iter().take_while(|&&si| si > threshold).count();
+    Ok((rank, vt))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_null_space, mat_rank};
+    use crate::{mat_approx_eq, mat_mat_mul, mat_t_mat_mul, Matrix};
+
+    /// Checks that z has orthonormal columns and that a ⋅ z = 0
+    fn check_null_space(a: &Matrix, z: &Matrix, tol: f64) {
+        let (m, d) = (a.nrow(), z.ncol());
+        let mut ztz = Matrix::new(d, d);
+        mat_t_mat_mul(&mut ztz, 1.0, z, z).unwrap();
+        mat_approx_eq(&ztz, &Matrix::identity(d), tol);
+        let mut az = Matrix::new(m, d);
+        mat_mat_mul(&mut az, 1.0, a, z).unwrap();
+        mat_approx_eq(&az, &Matrix::new(m, d), tol);
+    }
+
+    #[test]
+    fn mat_rank_and_null_space_capture_errors() {
+        let a = Matrix::new(2, 2);
+        assert_eq!(mat_rank(&a, -1.0).err(), Some("tol must satisfy 0 ≤ tol < 1"));
+        assert_eq!(mat_rank(&a, 1.0).err(), Some("tol must satisfy 0 ≤ tol < 1"));
+        assert_eq!(mat_null_space(&a, f64::NAN).err(), Some("tol must satisfy 0 ≤ tol < 1"));
+    }
+
+    #[test]
+    fn mat_rank_works() {
+        assert_eq!(mat_rank(&Matrix::new(0, 3), 1e-12), Ok(0));
+        assert_eq!(mat_rank(&Matrix::new(3, 2), 1e-12), Ok(0));
+        assert_eq!(mat_rank(&Matrix::identity(4), 1e-12), Ok(4));
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0],
+            [2.0, 4.0],
+            [3.0, 6.0],
+        ]);
+        assert_eq!(mat_rank(&a, 1e-12), Ok(1));
+        assert_eq!(
+            mat_rank(&Matrix::from(&[[1.0, 0.0, 2.0], [0.0, 1.0, 3.0]]), 1e-12),
+            Ok(2)
+        );
+
+        // the tolerance decides whether the tiny singular value counts
+        let a = Matrix::from(&[[1.0, 0.0], [0.0, 1e-10]]);
+        assert_eq!(mat_rank(&a, 1e-12), Ok(2));
+        assert_eq!(mat_rank(&a, 1e-8), Ok(1));
+    }
+
+    #[test]
+    fn mat_null_space_works() {
+        // full column rank
+        let a = Matrix::from(&[[2.0, 1.0], [1.0, 3.0], [0.0, 1.0]]);
+        assert_eq!(mat_null_space(&a, 1e-12).unwrap().dims(), (2, 0));
+
+        // empty matrix: the whole space
+        let z = mat_null_space(&Matrix::new(0, 2), 1e-12).unwrap();
+        mat_approx_eq(&z, &Matrix::identity(2), 1e-15);
+
+        // wide matrix
+        let a = Matrix::from(&[[1.0, 2.0, 3.0]]);
+        let z = mat_null_space(&a, 1e-12).unwrap();
+        assert_eq!(z.dims(), (3, 2));
+        check_null_space(&a, &z, 1e-14);
+
+        // free-free bar with two elements plus a disconnected bar: two rigid-body modes
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 1.0, -1.0,  0.0,  0.0,  0.0],
+            [-1.0,  2.0, -1.0,  0.0,  0.0],
+            [ 0.0, -1.0,  1.0,  0.0,  0.0],
+            [ 0.0,  0.0,  0.0,  3.0, -3.0],
+            [ 0.0,  0.0,  0.0, -3.0,  3.0],
+        ]);
+        assert_eq!(mat_rank(&a, 1e-12), Ok(3));
+        let z = mat_null_space(&a, 1e-12).unwrap();
+        assert_eq!(z.dims(), (5, 2));
+        check_null_space(&a, &z, 1e-14);
+    }
+}
@@ -0,0 +1,105 @@
+use super::Matrix;
+use crate::StrError;
+
+/// Selects the rows of a matrix flagged by a boolean mask
+///
+/// Returns `(b, indices)` where `b` holds the rows `i` of `a` with `mask[i] == true`
+/// (in ascending order of `i`) and `indices` maps the new rows to the original ones:
+///
+/// ```text
+/// b[k][j] = a[indices[k]][j]
+/// ```
+///
+/// For instance, with `mask` flagging the free (unknown) degrees-of-freedom of a finite element
+/// model, `b` holds the rows of the free equations. See also [crate::vec_select].
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_select_rows, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 2.0],
+///         [3.0, 4.0],
+///         [5.0, 6.0],
+///     ]);
+///     let (b, indices) = mat_select_rows(&a, &[true, false, true])?;
+///     let correct = "┌     ┐\n\
+///                    │ 1 2 │\n\
+///                    │ 5 6 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", b), correct);
+///     assert_eq!(indices, &[0, 2]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_select_rows(a: &Matrix, mask: &[bool]) -> Result<(Matrix, Vec<usize>), StrError> {
+    let (m, n) = a.dims();
+    if mask.len() != m {
+        return Err("the length of mask must equal the number of rows of the matrix");
+    }
+    let indices: Vec<usize> = (0..m).filter(|&i| mask[i]).collect();
+    let mut b = Matrix::new(indices.len(), n);
+    for (k, &i) in indices.iter().enumerate() {
+        for j in 0..n {
+            b.set(k, j, a.get(i, j));
+        }
+    }
+    Ok((b, indices))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_select_rows, Matrix};
+    use crate::{mat_approx_eq, vec_select, Vector};
+
+    #[test]
+    fn mat_select_rows_captures_errors() {
+        let a = Matrix::new(3, 2);
+        assert_eq!(
+            mat_select_rows(&a, &[true, false]).err(),
+            Some("the length of mask must equal the number of rows of the matrix")
+        );
+    }
+
+    #[test]
+    fn mat_select_rows_works() {
+        let (b, indices) = mat_select_rows(&Matrix::new(0, 3), &[]).unwrap();
+        assert_eq!(b.dims(), (0, 3));
+        assert_eq!(indices.len(), 0);
+
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]);
+        let (b, indices) = mat_select_rows(&a, &[false, false, false]).unwrap();
+        assert_eq!(b.dims(), (0, 3));
+        assert_eq!(indices.len(), 0);
+        let (b, indices) = mat_select_rows(&a, &[false, true, true]).unwrap();
+        mat_approx_eq(&b, &[[4.0, 5.0, 6.0], [7.0, 8.0, 9.0]], 1e-15);
+        assert_eq!(indices, &[1, 2]);
+    }
+
+    #[test]
+    fn free_and_prescribed_partition_works() {
+        // k⋅u = f with u₀ = 0 prescribed: the free equations are the rows 1 and 2
+        #[rustfmt::skip]
+        let k = Matrix::from(&[
+            [ 2.0, -1.0,  0.0],
+            [-1.0,  2.0, -1.0],
+            [ 0.0, -1.0,  1.0],
+        ]);
+        let f = Vector::from(&[0.0, 1.0, 2.0]);
+        let free = [false, true, true];
+        let (k_free_rows, rows) = mat_select_rows(&k, &free).unwrap();
+        let (f_free, indices) = vec_select(&f, &free).unwrap();
+        assert_eq!(rows, indices);
+        mat_approx_eq(&k_free_rows, &[[-1.0, 2.0, -1.0], [0.0, -1.0, 1.0]], 1e-15);
+        assert_eq!(f_free.as_data(), &[1.0, 2.0]);
+    }
+}
@@ -0,0 +1,92 @@
+use super::Matrix;
+use crate::StrError;
+
+/// Copies a block (submatrix) into a matrix
+///
+/// ```text
+/// a[i0 + i, j0 + j] := block[i, j]
+/// ```
+///
+/// This function is useful to assemble partitioned matrices (e.g., saddle-point systems)
+/// without manual index loops. See also [crate::NumMatrix::from_blocks].
+///
+/// # Input
+///
+/// * `a` -- (m,n) the matrix to be modified
+/// * `i0` -- the row index of `a` corresponding to the first row of `block`
+/// * `j0` -- the column index of `a` corresponding to the first column of `block`
+/// * `block` -- (mb,nb) the submatrix with `i0 + mb ≤ m` and `j0 + nb ≤ n`
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_set_block, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::new(3, 4);
+///     let block = Matrix::from(&[
+///         [1.0, 2.0],
+///         [3.0, 4.0],
+///     ]);
+///     mat_set_block(&mut a, 1, 2, &block)?;
+///     let correct = "┌         ┐\n\
+///                    │ 0 0 0 0 │\n\
+///                    │ 0 0 1 2 │\n\
+///                    │ 0 0 3 4 │\n\
+///                    └         ┘";
+///     assert_eq!(format!("{}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_set_block(a: &mut Matrix, i0: usize, j0: usize, block: &Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    let (mb, nb) = block.dims();
+    if i0 + mb > m || j0 + nb > n {
+        return Err("block does not fit into the matrix");
+    }
+    let data = a.as_mut_data();
+    let block_data = block.as_data();
+    for j in 0..nb {
+        let start = i0 + (j0 + j) * m;
+        data[start..start + mb].copy_from_slice(&block_data[j * mb..(j + 1) * mb]);
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_set_block, Matrix};
+    use crate::mat_approx_eq;
+
+    #[test]
+    fn mat_set_block_fails_on_wrong_dims() {
+        let mut a = Matrix::new(3, 3);
+        let block = Matrix::new(2, 2);
+        assert_eq!(
+            mat_set_block(&mut a, 2, 0, &block),
+            Err("block does not fit into the matrix")
+        );
+        assert_eq!(
+            mat_set_block(&mut a, 0, 2, &block),
+            Err("block does not fit into the matrix")
+        );
+    }
+
+    #[test]
+    fn mat_set_block_works() {
+        let mut a = Matrix::filled(3, 3, -1.0);
+        mat_set_block(&mut a, 1, 1, &Matrix::new(0, 0)).unwrap();
+        mat_set_block(&mut a, 3, 3, &Matrix::new(0, 0)).unwrap();
+        mat_set_block(&mut a, 0, 1, &Matrix::from(&[[1.0, 2.0], [3.0, 4.0]])).unwrap();
+        mat_set_block(&mut a, 2, 0, &Matrix::from(&[[5.0, 6.0, 7.0]])).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [-1.0, 1.0, 2.0],
+            [-1.0, 3.0, 4.0],
+            [ 5.0, 6.0, 7.0],
+        ];
+        mat_approx_eq(&a, correct, 1e-15);
+    }
+}
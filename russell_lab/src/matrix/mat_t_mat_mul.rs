@@ -1,6 +1,6 @@
 use super::Matrix;
+use crate::kernels::{dgemm, to_i32};
 use crate::StrError;
-use russell_openblas::{dgemm, to_i32};
 
 /// Performs the matrix(transposed)-matrix multiplication resulting in a matrix
 ///
@@ -1,6 +1,6 @@
 use super::Matrix;
+use crate::kernels::{daxpy, to_i32};
 use crate::StrError;
-use russell_openblas::{daxpy, to_i32};
 
 /// Updates matrix based on another matrix
 ///
@@ -1,48 +1,107 @@
 //! This module contains functions for calculations with matrices
 
 mod aliases;
+mod band_matrix;
 mod complex_mat_add;
 mod complex_mat_approx_eq;
+#[cfg(feature = "blas")]
+mod complex_mat_eigen;
 mod complex_mat_mat_mul;
 mod complex_mat_norm;
+mod complex_mat_scale;
 mod complex_mat_zip;
 mod mat_add;
 mod mat_approx_eq;
+#[cfg(feature = "blas")]
+mod mat_balance;
+#[cfg(feature = "blas")]
 mod mat_cholesky;
 mod mat_copy;
+#[cfg(feature = "blas")]
+mod mat_det;
+#[cfg(feature = "blas")]
 mod mat_eigen;
+#[cfg(feature = "blas")]
+mod mat_eigen_cond;
+#[cfg(feature = "blas")]
 mod mat_eigen_sym;
+#[cfg(feature = "blas")]
+mod mat_eigen_sym_band;
 mod mat_eigen_sym_jacobi;
+mod mat_eq;
+#[cfg(feature = "blas")]
+mod mat_fn_sym;
+#[cfg(feature = "blas")]
 mod mat_inverse;
 mod mat_mat_mul;
+#[cfg(feature = "blas")]
+mod mat_mat_mul_f32;
+mod mat_matrix_market;
 mod mat_max_abs_diff;
 mod mat_norm;
+#[cfg(feature = "blas")]
 mod mat_pseudo_inverse;
+#[cfg(feature = "blas")]
+mod mat_qr;
+#[cfg(feature = "blas")]
+mod mat_rank;
 mod mat_scale;
+mod mat_select_rows;
+mod mat_set_block;
+#[cfg(feature = "blas")]
 mod mat_svd;
 mod mat_t_mat_mul;
 mod mat_update;
 mod mat_write_vismatrix;
 mod num_matrix;
 pub use crate::matrix::aliases::*;
+pub use crate::matrix::band_matrix::*;
 pub use crate::matrix::complex_mat_add::*;
 pub use crate::matrix::complex_mat_approx_eq::*;
+#[cfg(feature = "blas")]
+pub use crate::matrix::complex_mat_eigen::*;
 pub use crate::matrix::complex_mat_mat_mul::*;
 pub use crate::matrix::complex_mat_norm::*;
+pub use crate::matrix::complex_mat_scale::*;
 pub use crate::matrix::complex_mat_zip::*;
 pub use crate::matrix::mat_add::*;
 pub use crate::matrix::mat_approx_eq::*;
+#[cfg(feature = "blas")]
+pub use crate::matrix::mat_balance::*;
+#[cfg(feature = "blas")]
 pub use crate::matrix::mat_cholesky::*;
 pub use crate::matrix::mat_copy::*;
+#[cfg(feature = "blas")]
+pub use crate::matrix::mat_det::*;
+#[cfg(feature = "blas")]
 pub use crate::matrix::mat_eigen::*;
+#[cfg(feature = "blas")]
+pub use crate::matrix::mat_eigen_cond::*;
+#[cfg(feature = "blas")]
 pub use crate::matrix::mat_eigen_sym::*;
+#[cfg(feature = "blas")]
+pub use crate::matrix::mat_eigen_sym_band::*;
 pub use crate::matrix::mat_eigen_sym_jacobi::*;
+pub use crate::matrix::mat_eq::*;
+#[cfg(feature = "blas")]
+pub use crate::matrix::mat_fn_sym::*;
+#[cfg(feature = "blas")]
 pub use crate::matrix::mat_inverse::*;
 pub use crate::matrix::mat_mat_mul::*;
+#[cfg(feature = "blas")]
+pub use crate::matrix::mat_mat_mul_f32::*;
 pub use crate::matrix::mat_max_abs_diff::*;
 pub use crate::matrix::mat_norm::*;
+#[cfg(feature = "blas")]
 pub use crate::matrix::mat_pseudo_inverse::*;
+#[cfg(feature = "blas")]
+pub use crate::matrix::mat_qr::*;
+#[cfg(feature = "blas")]
+pub use crate::matrix::mat_rank::*;
 pub use crate::matrix::mat_scale::*;
+pub use crate::matrix::mat_select_rows::*;
+pub use crate::matrix::mat_set_block::*;
+#[cfg(feature = "blas")]
 pub use crate::matrix::mat_svd::*;
 pub use crate::matrix::mat_t_mat_mul::*;
 pub use crate::matrix::mat_update::*;
@@ -39,6 +39,11 @@ use std::path::Path;
 /// to use the Index trait to mimic the access of matrix components in the form of a\[i\]\[j\].
 /// Therefore, only the `get(i, j)` and `set(i, j, value)` functions are implemented.
 ///
+/// The `==` operator compares the dimensions and the components exactly (thus, matrices with
+/// the same data but different shapes are not equal). For floating-point comparisons with a
+/// tolerance, use [crate::mat_approx_eq] or enable the `approx` feature to use the
+/// `AbsDiffEq`, `RelativeEq` and `UlpsEq` traits of the [approx](https://docs.rs/approx) crate.
+///
 /// # Examples
 ///
 /// ## Initialization, setting values and printing
@@ -68,8 +73,10 @@ use std::path::Path;
 /// ## Inverse and matrix multiplication
 ///
 /// ```
+/// # #[cfg(feature = "blas")]
 /// use russell_lab::{mat_inverse, mat_mat_mul, NumMatrix, StrError};
 ///
+/// # #[cfg(feature = "blas")]
 /// fn main() -> Result<(), StrError> {
 ///     // create new matrix filled with ones
 ///     let mut a = NumMatrix::<f64>::filled(2, 2, 1.0);
@@ -112,6 +119,8 @@ use std::path::Path;
 ///     assert_eq!(aia.as_data(), ii.as_data());
 ///     Ok(())
 /// }
+/// # #[cfg(not(feature = "blas"))]
+/// # fn main() {}
 /// ```
 ///
 /// ## Copying a matrix (cloning)
@@ -152,7 +161,7 @@ use std::path::Path;
 ///     Ok(())
 /// }
 /// ```
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct NumMatrix<T>
 where
     T: AddAssign + MulAssign + Num + Copy + DeserializeOwned + Serialize,
@@ -347,6 +356,79 @@ where
         matrix
     }
 
+    /// Creates new matrix by assembling a partitioned (block) matrix
+    ///
+    /// ```text
+    ///     ┌       ┐
+    ///     │ a  b  │
+    /// m = │ c  d  │
+    ///     └       ┘
+    /// ```
+    ///
+    /// # Input
+    ///
+    /// * `blocks` -- the block-rows; each block-row has the same number of blocks
+    ///
+    /// **Note:** All blocks in the same block-row must have the same number of rows and all blocks in the
+    /// same block-column must have the same number of columns. Zero blocks must be given explicitly
+    /// (e.g., `NumMatrix::new(m, n)`). See also [crate::mat_set_block].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::{NumMatrix, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // saddle-point (KKT) matrix
+    ///     let h = NumMatrix::<f64>::diagonal(&[2.0, 2.0]);
+    ///     let b = NumMatrix::<f64>::from(&[[1.0, 1.0]]);
+    ///     let bt = NumMatrix::<f64>::from(&[[1.0], [1.0]]);
+    ///     let zero = NumMatrix::<f64>::new(1, 1);
+    ///     let a = NumMatrix::from_blocks(&[[&h, &bt], [&b, &zero]])?;
+    ///     assert_eq!(
+    ///         format!("{}", a),
+    ///         "┌       ┐\n\
+    ///          │ 2 0 1 │\n\
+    ///          │ 0 2 1 │\n\
+    ///          │ 1 1 0 │\n\
+    ///          └       ┘"
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_blocks<const N: usize>(blocks: &[[&NumMatrix<T>; N]]) -> Result<Self, StrError> {
+        if blocks.is_empty() || N == 0 {
+            return Ok(NumMatrix::new(0, 0));
+        }
+        for row in blocks {
+            if row.iter().any(|block| block.nrow != row[0].nrow) {
+                return Err("blocks in the same block-row must have the same number of rows");
+            }
+        }
+        for j in 0..N {
+            if blocks.iter().any(|row| row[j].ncol != blocks[0][j].ncol) {
+                return Err("blocks in the same block-column must have the same number of columns");
+            }
+        }
+        let nrow = blocks.iter().map(|row| row[0].nrow).sum();
+        let ncol = blocks[0].iter().map(|block| block.ncol).sum();
+        let mut matrix = NumMatrix::new(nrow, ncol);
+        let mut i0 = 0;
+        for row in blocks {
+            let mut j0 = 0;
+            for block in row {
+                for j in 0..block.ncol {
+                    let start = i0 + (j0 + j) * nrow;
+                    matrix.data[start..start + block.nrow]
+                        .copy_from_slice(&block.data[j * block.nrow..(j + 1) * block.nrow]);
+                }
+                j0 += block.ncol;
+            }
+            i0 += row[0].nrow;
+        }
+        Ok(matrix)
+    }
+
     /// Creates matrix from text file
     ///
     /// # Input
@@ -919,6 +1001,46 @@ mod tests {
         assert_eq!(a.data, [-8.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0]);
     }
 
+    #[test]
+    fn from_blocks_captures_errors() {
+        let a = NumMatrix::<f64>::new(2, 2);
+        let b = NumMatrix::<f64>::new(1, 2);
+        let c = NumMatrix::<f64>::new(2, 1);
+        assert_eq!(
+            NumMatrix::from_blocks(&[[&a, &b]]).err(),
+            Some("blocks in the same block-row must have the same number of rows")
+        );
+        assert_eq!(
+            NumMatrix::from_blocks(&[[&a], [&c]]).err(),
+            Some("blocks in the same block-column must have the same number of columns")
+        );
+    }
+
+    #[test]
+    fn from_blocks_works() {
+        let empty: &[[&NumMatrix<f64>; 2]] = &[];
+        let m = NumMatrix::from_blocks(empty).unwrap();
+        assert_eq!(m.dims(), (0, 0));
+
+        #[rustfmt::skip]
+        let a = NumMatrix::<f64>::from(&[
+            [1.0, 2.0],
+            [3.0, 4.0],
+        ]);
+        let b = NumMatrix::<f64>::from(&[[5.0], [6.0]]);
+        let c = NumMatrix::<f64>::from(&[[7.0, 8.0]]);
+        let d = NumMatrix::<f64>::from(&[[9.0]]);
+        let m = NumMatrix::from_blocks(&[[&a, &b], [&c, &d]]).unwrap();
+        assert_eq!(m.dims(), (3, 3));
+        assert_eq!(m.data, &[1.0, 3.0, 7.0, 2.0, 4.0, 8.0, 5.0, 6.0, 9.0]);
+
+        // single block-row with an empty block
+        let e = NumMatrix::<f64>::new(2, 0);
+        let m = NumMatrix::from_blocks(&[[&a, &e, &b]]).unwrap();
+        assert_eq!(m.dims(), (2, 3));
+        assert_eq!(m.data, &[1.0, 3.0, 2.0, 4.0, 5.0, 6.0]);
+    }
+
     #[test]
     fn from_text_file_handles_problems() {
         assert_eq!(NumMatrix::<f64>::from_text_file("").err(), Some("cannot open file"),);
@@ -1038,6 +1160,20 @@ mod tests {
         assert_eq!(format!("{:?}", a), "NumMatrix { nrow: 1, ncol: 1, data: [0.0] }");
     }
 
+    #[test]
+    fn partial_eq_works() {
+        let a = NumMatrix::<f64>::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let b = NumMatrix::<f64>::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let c = NumMatrix::<f64>::from(&[[1.0, 2.0], [3.0, 4.000001]]);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        // same data but different dimensions
+        let d = NumMatrix::<f64>::from(&[[1.0, 3.0, 2.0, 4.0]]);
+        assert_eq!(d.as_data(), a.as_data());
+        assert_ne!(a, d);
+        assert_ne!(NumMatrix::<f64>::new(0, 2), NumMatrix::<f64>::new(2, 0));
+    }
+
     #[test]
     fn fill_works() {
         let mut a = NumMatrix::<f64>::new(2, 2);
@@ -0,0 +1,123 @@
+use crate::matrix::ComplexMatrix;
+use crate::vector::ComplexVector;
+use crate::StrError;
+use russell_openblas::{to_i32, zgesv};
+
+/// Solves a general linear system (complex numbers)
+///
+/// For a general complex matrix `a`, find `x` such that:
+///
+/// ```text
+///   a   ⋅  x  =  b
+/// (m,m)   (m)   (m)
+/// ```
+///
+/// However, the right-hand-side will hold the solution:
+///
+/// ```text
+/// b := a⁻¹⋅b == x
+/// ```
+///
+/// The solution is obtained via LU decomposition using Lapack zgesv routine. Complex systems arise,
+/// for instance, in the frequency-domain analysis of damped structures, where the dynamic stiffness
+/// `a = k - ω²⋅m + i⋅ω⋅c` relates the (complex) amplitudes of the forces and displacements.
+///
+/// # Note
+///
+/// 1. The matrix `a` will be modified
+/// 2. The right-hand-side `b` will contain the solution `x`
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_chk::complex_vec_approx_eq;
+/// use russell_lab::{complex_solve_lin_sys, ComplexMatrix, ComplexVector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // set matrix and right-hand side
+///     let mut a = ComplexMatrix::from(&[
+///         [Complex64::new(2.0, 1.0), Complex64::new(1.0, 0.0)],
+///         [Complex64::new(1.0, 0.0), Complex64::new(3.0, -1.0)],
+///     ]);
+///     let mut b = ComplexVector::from(&[Complex64::new(3.0, 1.0), Complex64::new(3.0, 5.0)]);
+///
+///     // solve linear system b := a⁻¹⋅b
+///     complex_solve_lin_sys(&mut b, &mut a)?;
+///
+///     // check
+///     let x_correct = &[Complex64::new(1.0, -1.0), Complex64::new(0.0, 2.0)];
+///     complex_vec_approx_eq(b.as_data(), x_correct, 1e-15);
+///     Ok(())
+/// }
+/// ```
+pub fn complex_solve_lin_sys(b: &mut ComplexVector, a: &mut ComplexMatrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if b.dim() != m {
+        return Err("vector has wrong dimension");
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    let mut ipiv = vec![0; m];
+    let m_i32 = to_i32(m);
+    zgesv(m_i32, 1, a.as_mut_data(), &mut ipiv, b.as_mut_data())?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::complex_solve_lin_sys;
+    use crate::{ComplexMatrix, ComplexVector};
+    use num_complex::Complex64;
+    use russell_chk::complex_vec_approx_eq;
+
+    #[test]
+    fn complex_solve_lin_sys_fails_on_non_square() {
+        let mut a = ComplexMatrix::new(2, 3);
+        let mut b = ComplexVector::new(3);
+        assert_eq!(complex_solve_lin_sys(&mut b, &mut a), Err("matrix must be square"));
+    }
+
+    #[test]
+    fn complex_solve_lin_sys_fails_on_wrong_dims() {
+        let mut a = ComplexMatrix::new(2, 2);
+        let mut b = ComplexVector::new(3);
+        assert_eq!(complex_solve_lin_sys(&mut b, &mut a), Err("vector has wrong dimension"));
+    }
+
+    #[test]
+    fn complex_solve_lin_sys_fails_on_singular_matrix() {
+        let mut a = ComplexMatrix::from(&[[1.0, 2.0], [2.0, 4.0]]);
+        let mut b = ComplexVector::new(2);
+        assert_eq!(complex_solve_lin_sys(&mut b, &mut a), Err("LAPACK zgesv failed"));
+    }
+
+    #[test]
+    fn complex_solve_lin_sys_works() {
+        complex_solve_lin_sys(&mut ComplexVector::new(0), &mut ComplexMatrix::new(0, 0)).unwrap();
+        #[rustfmt::skip]
+        let mut a = ComplexMatrix::from(&[
+            [Complex64::new(1.0, 1.0), Complex64::new(2.0, 0.0), Complex64::new(0.0,  0.0)],
+            [Complex64::new(0.0, 0.0), Complex64::new(0.0, 3.0), Complex64::new(1.0,  0.0)],
+            [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0), Complex64::new(2.0, -1.0)],
+        ]);
+        let mut b = ComplexVector::from(&[
+            Complex64::new(1.0, 3.0),
+            Complex64::new(-4.0, 2.0),
+            Complex64::new(1.0, 5.0),
+        ]);
+        complex_solve_lin_sys(&mut b, &mut a).unwrap();
+        let x_correct = &[
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 1.0),
+            Complex64::new(-1.0, 2.0),
+        ];
+        complex_vec_approx_eq(b.as_data(), x_correct, 1e-15);
+    }
+}
@@ -0,0 +1,330 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::{RussellError, StrError};
+use russell_openblas::{dgemm, dgetrf, dgetrs, to_i32};
+
+/// Holds a low-rank modification u⋅vᵀ of the factorized matrix
+struct LowRankUpdate {
+    k: usize,           // rank of the update (number of columns of u and v)
+    z: Vec<f64>,        // (n,k) z = a_prev⁻¹ ⋅ u (col-major)
+    v: Vec<f64>,        // (n,k) v (col-major)
+    cap_lu: Vec<f64>,   // (k,k) LU factors of the capacitance matrix c = I + vᵀ⋅z
+    cap_ipiv: Vec<i32>, // (k) pivots of the capacitance matrix
+}
+
+/// Holds the LU factorization of a dense square matrix (possibly modified by low-rank updates)
+///
+/// The matrix is factorized once with LAPACK dgetrf:
+///
+/// ```text
+/// a = p ⋅ l ⋅ u
+/// ```
+///
+/// Afterwards, small modifications of `a` can be considered without refactorization:
+///
+/// ```text
+/// rank-1 (Sherman–Morrison):  a := a + u ⋅ vᵀ        u, v: (n)
+/// rank-k (Woodbury):          a := a + U ⋅ Vᵀ        U, V: (n,k)
+/// ```
+///
+/// The updates are stored in product form. Thus, each [DenseLu::solve] costs one solution with the
+/// original factors plus `O(n⋅k)` operations per stored update. Iterative design loops should
+/// create a new DenseLu (refactorize) once many updates have been accumulated.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{DenseLu, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [2.0, 0.0, 0.0],
+///         [0.0, 4.0, 0.0],
+///         [0.0, 0.0, 8.0],
+///     ]);
+///     let mut lu = DenseLu::new(&a)?;
+///     let mut x = Vector::new(3);
+///     lu.solve(&mut x, &Vector::from(&[2.0, 4.0, 8.0]))?;
+///     vec_approx_eq(x.as_data(), &[1.0, 1.0, 1.0], 1e-15);
+///
+///     // a := a + u⋅vᵀ (adds 2 to the first row)
+///     let u = Vector::from(&[1.0, 0.0, 0.0]);
+///     let v = Vector::from(&[2.0, 2.0, 2.0]);
+///     lu.lu_update_rank1(&u, &v)?;
+///     lu.solve(&mut x, &Vector::from(&[8.0, 4.0, 8.0]))?;
+///     vec_approx_eq(x.as_data(), &[1.0, 1.0, 1.0], 1e-15);
+///     Ok(())
+/// }
+/// ```
+pub struct DenseLu {
+    pub(super) n: usize,         // dimension of the matrix
+    pub(super) lu: Vec<f64>,     // (n,n) LU factors of the original matrix (col-major)
+    pub(super) ipiv: Vec<i32>,   // (n) pivots of the original matrix (one-based)
+    updates: Vec<LowRankUpdate>, // low-rank updates applied after the factorization
+}
+
+impl DenseLu {
+    /// Computes the LU factorization of a square matrix
+    ///
+    /// **Note:** The matrix `a` is not modified (a copy is factorized).
+    pub fn new(a: &Matrix) -> Result<Self, StrError> {
+        let (m, n) = a.dims();
+        if m != n {
+            return Err("matrix must be square");
+        }
+        let mut lu = a.as_data().clone();
+        let mut ipiv = vec![0; n];
+        if n > 0 {
+            let n_i32 = to_i32(n);
+            dgetrf(n_i32, n_i32, &mut lu, &mut ipiv)?;
+        }
+        Ok(DenseLu {
+            n,
+            lu,
+            ipiv,
+            updates: Vec::new(),
+        })
+    }
+
+    /// Returns the dimension of the (square) matrix
+    pub fn dim(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of low-rank updates applied after the factorization
+    pub fn num_updates(&self) -> usize {
+        self.updates.len()
+    }
+
+    /// Discards all low-rank updates (i.e., goes back to the originally factorized matrix)
+    pub fn clear_updates(&mut self) {
+        self.updates.clear();
+    }
+
+    /// Solves the linear system with the current (possibly updated) matrix
+    ///
+    /// ```text
+    ///   a   ⋅  x  =  b
+    /// (n,n)   (n)   (n)
+    /// ```
+    pub fn solve(&self, x: &mut Vector, b: &Vector) -> Result<(), RussellError> {
+        self.check_dim(x.dim())?;
+        self.check_dim(b.dim())?;
+        x.as_mut_data().copy_from_slice(b.as_data());
+        self.apply_inverse(x.as_mut_data(), 1)?;
+        Ok(())
+    }
+
+    /// Applies a rank-1 update to the factorized matrix (Sherman–Morrison formula)
+    ///
+    /// ```text
+    ///   a  :=  a  +  u  ⋅  vᵀ
+    /// (n,n)  (n,n)  (n)   (n)
+    /// ```
+    ///
+    /// The inverse of the updated matrix is (with `z = a⁻¹⋅u`):
+    ///
+    /// ```text
+    ///               z ⋅ vᵀ ⋅ a⁻¹
+    /// a⁻¹ := a⁻¹ - ─────────────
+    ///               1 + vᵀ ⋅ z
+    /// ```
+    pub fn lu_update_rank1(&mut self, u: &Vector, v: &Vector) -> Result<(), RussellError> {
+        self.check_dim(u.dim())?;
+        self.check_dim(v.dim())?;
+        self.update(1, u.as_data().clone(), v.as_data().clone())?;
+        Ok(())
+    }
+
+    /// Applies a rank-k update to the factorized matrix (Woodbury formula)
+    ///
+    /// ```text
+    ///   a  :=  a  +  U  ⋅  Vᵀ
+    /// (n,n)  (n,n) (n,k)  (k,n)
+    /// ```
+    ///
+    /// The inverse of the updated matrix is (with `Z = a⁻¹⋅U` and `C = I + Vᵀ⋅Z`):
+    ///
+    /// ```text
+    /// a⁻¹ := a⁻¹ - Z ⋅ C⁻¹ ⋅ Vᵀ ⋅ a⁻¹
+    /// ```
+    ///
+    /// where the (k,k) capacitance matrix `C` is factorized with dgetrf.
+    pub fn lu_update_low_rank(&mut self, u: &Matrix, v: &Matrix) -> Result<(), RussellError> {
+        let (nu, k) = u.dims();
+        self.check_dim(nu)?;
+        if v.dims() != (nu, k) {
+            return Err("matrices u and v must have dimensions (n,k)".into());
+        }
+        self.update(k, u.as_data().clone(), v.as_data().clone())?;
+        Ok(())
+    }
+
+    /// Returns an error if the dimension of a vector (or number of rows) differs from the dimension of the matrix
+    pub(super) fn check_dim(&self, dim: usize) -> Result<(), RussellError> {
+        if dim != self.n {
+            return Err(RussellError::DimensionMismatch {
+                expected: self.n,
+                found: dim,
+            });
+        }
+        Ok(())
+    }
+
+    /// Computes and stores the low-rank update with (n,k) col-major arrays u and v
+    fn update(&mut self, k: usize, mut u: Vec<f64>, v: Vec<f64>) -> Result<(), StrError> {
+        if self.n == 0 || k == 0 {
+            return Ok(());
+        }
+        let (n_i32, k_i32) = (to_i32(self.n), to_i32(k));
+        // z := a⁻¹ ⋅ u
+        self.apply_inverse(&mut u, k)?;
+        let z = u;
+        // c := I + vᵀ ⋅ z
+        let mut cap_lu = vec![0.0; k * k];
+        for i in 0..k {
+            cap_lu[i + i * k] = 1.0;
+        }
+        dgemm(true, false, k_i32, k_i32, n_i32, 1.0, &v, &z, 1.0, &mut cap_lu);
+        let mut cap_ipiv = vec![0; k];
+        dgetrf(k_i32, k_i32, &mut cap_lu, &mut cap_ipiv)
+            .map_err(|_| "the low-rank update makes the matrix singular")?;
+        self.updates.push(LowRankUpdate {
+            k,
+            z,
+            v,
+            cap_lu,
+            cap_ipiv,
+        });
+        Ok(())
+    }
+
+    /// Computes y := a⁻¹ ⋅ y with the current matrix for (n,nrhs) col-major y
+    fn apply_inverse(&self, y: &mut [f64], nrhs: usize) -> Result<(), StrError> {
+        if self.n == 0 {
+            return Ok(());
+        }
+        let (n_i32, nrhs_i32) = (to_i32(self.n), to_i32(nrhs));
+        dgetrs(false, n_i32, nrhs_i32, &self.lu, &self.ipiv, y)?;
+        let mut w = Vec::new();
+        for up in &self.updates {
+            let k_i32 = to_i32(up.k);
+            // w := c⁻¹ ⋅ vᵀ ⋅ y
+            w.resize(up.k * nrhs, 0.0);
+            dgemm(true, false, k_i32, nrhs_i32, n_i32, 1.0, &up.v, y, 0.0, &mut w);
+            dgetrs(false, k_i32, nrhs_i32, &up.cap_lu, &up.cap_ipiv, &mut w)?;
+            // y := y - z ⋅ w
+            dgemm(false, false, n_i32, nrhs_i32, k_i32, -1.0, &up.z, &w, 1.0, y);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::DenseLu;
+    use crate::{mat_add, solve_lin_sys, Matrix, RussellError, Vector};
+    use russell_chk::vec_approx_eq;
+
+    /// Returns the solution computed by solve_lin_sys (with the full matrix)
+    fn reference(a: &Matrix, b: &Vector) -> Vector {
+        let mut aa = a.clone();
+        let mut x = b.clone();
+        solve_lin_sys(&mut x, &mut aa).unwrap();
+        x
+    }
+
+    #[test]
+    fn dense_lu_captures_errors() {
+        assert_eq!(DenseLu::new(&Matrix::new(2, 3)).err(), Some("matrix must be square"));
+        assert_eq!(DenseLu::new(&Matrix::new(2, 2)).err(), Some("LAPACK dgetrf failed"));
+        let mut lu = DenseLu::new(&Matrix::diagonal(&[1.0, 2.0])).unwrap();
+        let mut x = Vector::new(2);
+        assert_eq!(
+            lu.solve(&mut x, &Vector::new(3)).err(),
+            Some(RussellError::DimensionMismatch { expected: 2, found: 3 })
+        );
+        assert_eq!(
+            lu.lu_update_rank1(&Vector::new(2), &Vector::new(1)).err(),
+            Some(RussellError::DimensionMismatch { expected: 2, found: 1 })
+        );
+        assert_eq!(
+            lu.lu_update_low_rank(&Matrix::new(2, 1), &Matrix::new(2, 2)).err(),
+            Some(RussellError::Message("matrices u and v must have dimensions (n,k)"))
+        );
+        // a + u⋅vᵀ = [[0, 0], [0, 2]]
+        assert_eq!(
+            lu.lu_update_rank1(&Vector::from(&[1.0, 0.0]), &Vector::from(&[-1.0, 0.0]))
+                .err(),
+            Some(RussellError::Message("the low-rank update makes the matrix singular"))
+        );
+        assert_eq!(lu.num_updates(), 0);
+    }
+
+    #[test]
+    fn dense_lu_solve_works() {
+        let a = Matrix::from(&[[1.0, 3.0, -2.0], [3.0, 5.0, 6.0], [2.0, 4.0, 3.0]]);
+        let lu = DenseLu::new(&a).unwrap();
+        assert_eq!(lu.dim(), 3);
+        let mut x = Vector::new(3);
+        lu.solve(&mut x, &Vector::from(&[5.0, 7.0, 8.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[-15.0, 8.0, 2.0], 1e-13);
+    }
+
+    #[test]
+    fn lu_update_rank1_works() {
+        let mut a = Matrix::from(&[[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0]]);
+        let mut lu = DenseLu::new(&a).unwrap();
+        let b = Vector::from(&[1.0, 2.0, 3.0]);
+        let mut x = Vector::new(3);
+        for (u, v) in [([1.0, 0.0, 2.0], [0.5, -1.0, 1.0]), ([0.0, 3.0, 1.0], [1.0, 1.0, 0.0])] {
+            lu.lu_update_rank1(&Vector::from(&u), &Vector::from(&v)).unwrap();
+            for (i, ui) in u.iter().enumerate() {
+                for (j, vj) in v.iter().enumerate() {
+                    a.add(i, j, ui * vj);
+                }
+            }
+            lu.solve(&mut x, &b).unwrap();
+            vec_approx_eq(x.as_data(), reference(&a, &b).as_data(), 1e-13);
+        }
+        assert_eq!(lu.num_updates(), 2);
+
+        // back to the original matrix
+        lu.clear_updates();
+        lu.solve(&mut x, &b).unwrap();
+        let a0 = Matrix::from(&[[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0]]);
+        vec_approx_eq(x.as_data(), reference(&a0, &b).as_data(), 1e-13);
+    }
+
+    #[test]
+    fn lu_update_low_rank_works() {
+        let a = Matrix::from(&[
+            [5.0, 1.0, 0.0, 0.0],
+            [1.0, 5.0, 1.0, 0.0],
+            [0.0, 1.0, 5.0, 1.0],
+            [0.0, 0.0, 1.0, 5.0],
+        ]);
+        let u = Matrix::from(&[[1.0, 0.0], [0.0, 1.0], [2.0, 0.0], [0.0, -1.0]]);
+        let v = Matrix::from(&[[0.5, 1.0], [1.0, 0.0], [0.0, 2.0], [1.0, 1.0]]);
+        let mut lu = DenseLu::new(&a).unwrap();
+        lu.lu_update_low_rank(&u, &v).unwrap();
+
+        // a + u⋅vᵀ
+        let mut uvt = Matrix::new(4, 4);
+        for i in 0..4 {
+            for j in 0..4 {
+                uvt.set(i, j, u.get(i, 0) * v.get(j, 0) + u.get(i, 1) * v.get(j, 1));
+            }
+        }
+        let mut a_new = Matrix::new(4, 4);
+        mat_add(&mut a_new, 1.0, &a, 1.0, &uvt).unwrap();
+
+        let b = Vector::from(&[1.0, -2.0, 3.0, 4.0]);
+        let mut x = Vector::new(4);
+        lu.solve(&mut x, &b).unwrap();
+        vec_approx_eq(x.as_data(), reference(&a_new, &b).as_data(), 1e-13);
+    }
+}
@@ -0,0 +1,240 @@
+use super::solve_lsq;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::{Polynomial, StrError};
+
+/// Holds the results of the least squares fit of a straight line
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearFit {
+    /// The intercept `a` of `y = a + b x`
+    pub intercept: f64,
+
+    /// The slope `b` of `y = a + b x`
+    pub slope: f64,
+
+    /// The standard error of the intercept
+    pub intercept_std_err: f64,
+
+    /// The standard error of the slope
+    pub slope_std_err: f64,
+
+    /// The coefficient of determination `R² = 1 - SSE / SST`
+    pub r_squared: f64,
+}
+
+/// Fits a straight line `y = a + b x` to the data points by (ordinary) least squares
+///
+/// The standard errors are computed with the unbiased estimate of the variance of the residuals:
+///
+/// ```text
+/// s² = SSE / (n - 2)
+/// σ_b = s / √Sxx
+/// σ_a = s ⋅ √(1/n + x̄² / Sxx)
+/// ```
+///
+/// where `SSE = Σ (yᵢ - a - b xᵢ)²` and `Sxx = Σ (xᵢ - x̄)²`.
+///
+/// # Input
+///
+/// * `x` -- (n) the abscissae (not all equal)
+/// * `y` -- (n) the observations (n ≥ 3)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{fit_linear, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = Vector::from(&[0.0, 1.0, 2.0, 3.0]);
+///     let y = Vector::from(&[1.0, 3.0, 5.0, 7.0]);
+///     let fit = fit_linear(&x, &y)?;
+///     assert_eq!(format!("{:.6}", fit.intercept), "1.000000");
+///     assert_eq!(format!("{:.6}", fit.slope), "2.000000");
+///     assert_eq!(format!("{:.6}", fit.r_squared), "1.000000");
+///     Ok(())
+/// }
+/// ```
+pub fn fit_linear(x: &Vector, y: &Vector) -> Result<LinearFit, StrError> {
+    let n = x.dim();
+    if y.dim() != n {
+        return Err("x and y vectors must have the same dimension");
+    }
+    if n < 3 {
+        return Err("at least 3 points are required");
+    }
+    let nf = n as f64;
+    let x_mean = x.as_data().iter().sum::<f64>() / nf;
+    let y_mean = y.as_data().iter().sum::<f64>() / nf;
+    let sxx: f64 = x.as_data().iter().map(|xi| (xi - x_mean) * (xi - x_mean)).sum();
+    if sxx == 0.0 {
+        return Err("x values must not be all equal");
+    }
+
+    // solve the least squares problem
+    let mut a = Matrix::new(n, 2);
+    for i in 0..n {
+        a.set(i, 0, 1.0);
+        a.set(i, 1, x[i]);
+    }
+    let mut c = Vector::new(2);
+    solve_lsq(&mut c, &a, y)?;
+    let (intercept, slope) = (c[0], c[1]);
+
+    // statistics
+    let mut sse = 0.0;
+    let mut sst = 0.0;
+    for i in 0..n {
+        let e = y[i] - intercept - slope * x[i];
+        sse += e * e;
+        sst += (y[i] - y_mean) * (y[i] - y_mean);
+    }
+    let s = f64::sqrt(sse / (nf - 2.0));
+    Ok(LinearFit {
+        intercept,
+        slope,
+        intercept_std_err: s * f64::sqrt(1.0 / nf + x_mean * x_mean / sxx),
+        slope_std_err: s / f64::sqrt(sxx),
+        r_squared: if sst > 0.0 { 1.0 - sse / sst } else { 1.0 },
+    })
+}
+
+/// Fits a polynomial of given degree to the data points by (ordinary) least squares
+///
+/// Finds the coefficients minimizing `Σ (yᵢ - p(xᵢ))²` with the Vandermonde matrix (see [solve_lsq]).
+///
+/// # Input
+///
+/// * `x` -- (n) the abscissae (with at least `degree + 1` distinct values)
+/// * `y` -- (n) the observations
+/// * `degree` -- the degree of the polynomial (n > degree)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{fit_poly, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // y = 1 - x²
+///     let x = Vector::from(&[-2.0, -1.0, 0.0, 1.0, 2.0]);
+///     let y = Vector::from(&[-3.0, 0.0, 1.0, 0.0, -3.0]);
+///     let p = fit_poly(&x, &y, 2)?;
+///     assert_eq!(p.degree(), 2);
+///     assert_eq!(format!("{:.6}", p.eval(3.0)), "-8.000000");
+///     Ok(())
+/// }
+/// ```
+pub fn fit_poly(x: &Vector, y: &Vector, degree: usize) -> Result<Polynomial, StrError> {
+    let n = x.dim();
+    if y.dim() != n {
+        return Err("x and y vectors must have the same dimension");
+    }
+    if n <= degree {
+        return Err("the number of points must be greater than the degree");
+    }
+    let mut a = Matrix::new(n, degree + 1);
+    for i in 0..n {
+        let mut xk = 1.0;
+        for k in 0..=degree {
+            a.set(i, k, xk);
+            xk *= x[i];
+        }
+    }
+    let mut c = Vector::new(degree + 1);
+    solve_lsq(&mut c, &a, y)?;
+    Polynomial::new(c.as_data())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{fit_linear, fit_poly};
+    use crate::Vector;
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn fit_linear_fails_on_wrong_input() {
+        let x = Vector::from(&[1.0, 2.0, 3.0]);
+        assert_eq!(
+            fit_linear(&x, &Vector::new(2)).err(),
+            Some("x and y vectors must have the same dimension")
+        );
+        assert_eq!(
+            fit_linear(&Vector::new(2), &Vector::new(2)).err(),
+            Some("at least 3 points are required")
+        );
+        assert_eq!(
+            fit_linear(&Vector::filled(3, 1.0), &x).err(),
+            Some("x values must not be all equal")
+        );
+    }
+
+    #[test]
+    fn fit_linear_works() {
+        // nearly linear data
+        let x = Vector::from(&[0.2, 338.8, 118.2, 888.0, 9.2, 228.1]);
+        let y = Vector::from(&[0.1, 338.8, 118.1, 888.0, 9.2, 228.1]);
+        let fit = fit_linear(&x, &y).unwrap();
+
+        // reference values computed with the closed-form expressions
+        let n = 6.0;
+        let x_mean = x.as_data().iter().sum::<f64>() / n;
+        let y_mean = y.as_data().iter().sum::<f64>() / n;
+        let mut sxx = 0.0;
+        let mut sxy = 0.0;
+        for i in 0..6 {
+            sxx += (x[i] - x_mean) * (x[i] - x_mean);
+            sxy += (x[i] - x_mean) * (y[i] - y_mean);
+        }
+        let slope = sxy / sxx;
+        approx_eq(fit.slope, slope, 1e-12);
+        approx_eq(fit.intercept, y_mean - slope * x_mean, 1e-10);
+        assert!(fit.r_squared > 0.999999 && fit.r_squared <= 1.0);
+        assert!(fit.slope_std_err > 0.0 && fit.slope_std_err < 1e-3);
+        assert!(fit.intercept_std_err > fit.slope_std_err);
+    }
+
+    #[test]
+    fn fit_linear_gives_textbook_standard_errors() {
+        // y = x + e with e = (1, -1, -1, 1): SSE = 4, Sxx = 5, x̄ = 1.5
+        let x = Vector::from(&[0.0, 1.0, 2.0, 3.0]);
+        let y = Vector::from(&[1.0, 0.0, 1.0, 4.0]);
+        let fit = fit_linear(&x, &y).unwrap();
+        approx_eq(fit.slope, 1.0, 1e-14);
+        approx_eq(fit.intercept, 0.0, 1e-14);
+        let s = f64::sqrt(4.0 / 2.0);
+        approx_eq(fit.slope_std_err, s / f64::sqrt(5.0), 1e-14);
+        approx_eq(fit.intercept_std_err, s * f64::sqrt(0.25 + 2.25 / 5.0), 1e-14);
+        approx_eq(fit.r_squared, 1.0 - 4.0 / 9.0, 1e-14);
+    }
+
+    #[test]
+    fn fit_poly_works() {
+        let x = Vector::from(&[1.0, 2.0]);
+        assert_eq!(
+            fit_poly(&x, &Vector::new(3), 1).err(),
+            Some("x and y vectors must have the same dimension")
+        );
+        assert_eq!(
+            fit_poly(&x, &x, 2).err(),
+            Some("the number of points must be greater than the degree")
+        );
+
+        // exact cubic
+        let x = Vector::linspace(-1.0, 2.0, 7).unwrap();
+        let mut y = Vector::new(7);
+        for i in 0..7 {
+            y[i] = 2.0 - x[i] + 0.5 * x[i] * x[i] * x[i];
+        }
+        let p = fit_poly(&x, &y, 3).unwrap();
+        vec_approx_eq(p.coefficients(), &[2.0, -1.0, 0.0, 0.5], 1e-13);
+
+        // straight line through noisy data equals fit_linear
+        let x = Vector::from(&[0.0, 1.0, 2.0, 3.0]);
+        let y = Vector::from(&[1.0, 0.0, 1.0, 4.0]);
+        let p = fit_poly(&x, &y, 1).unwrap();
+        let fit = fit_linear(&x, &y).unwrap();
+        approx_eq(p.coefficients()[0], fit.intercept, 1e-14);
+        approx_eq(p.coefficients()[1], fit.slope, 1e-14);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+use russell_openblas::{dsytrf, dsytrs, to_i32};
+
+/// Holds the LDLᵀ factorization (Bunch-Kaufman) of a dense symmetric (indefinite) matrix
+///
+/// The matrix is factorized once with LAPACK dsytrf (see [mat_factor_ldlt]):
+///
+/// ```text
+/// a = p ⋅ l ⋅ d ⋅ lᵀ ⋅ pᵀ
+/// ```
+///
+/// where `p` is a permutation matrix, `l` is lower triangular with unit diagonal, and `d` is
+/// symmetric and block diagonal with 1×1 and 2×2 blocks. In contrast to the Cholesky factorization
+/// (see [crate::mat_cholesky]), the matrix does not need to be positive definite; e.g., the
+/// saddle-point (KKT) matrices of constrained optimization and mixed finite element methods:
+///
+/// ```text
+///     ┌       ┐
+///     │ h  bᵀ │
+/// a = │ b  0  │
+///     └       ┘
+/// ```
+///
+/// Afterwards, the factors can be reused to solve systems with many right-hand sides, and to
+/// compute the determinant and the inertia (by Sylvester's law of inertia, `a` and `d` have the
+/// same numbers of positive and negative eigenvalues).
+pub struct LdltFactors {
+    n: usize,       // dimension of the matrix
+    ld: Vec<f64>,   // (n,n) d and the multipliers of l (lower triangle; col-major)
+    ipiv: Vec<i32>, // (n) pivot indices and block structure (one-based) as computed by dsytrf
+}
+
+/// Computes the LDLᵀ factorization of a symmetric (indefinite) matrix
+///
+/// See [LdltFactors].
+///
+/// # Input
+///
+/// * `a` -- (n,n) matrix (SYMMETRIC; only the lower triangle is accessed)
+///
+/// **Note:** The matrix `a` is not modified (a copy is factorized). An error is returned if the matrix is singular.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::{approx_eq, vec_approx_eq};
+/// use russell_lab::{mat_factor_ldlt, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // minimize x₀² + x₁² subject to x₀ + x₁ = 1
+///     let a = Matrix::from(&[
+///         [2.0, 0.0, 1.0],
+///         [0.0, 2.0, 1.0],
+///         [1.0, 1.0, 0.0],
+///     ]);
+///     let ldlt = mat_factor_ldlt(&a)?;
+///
+///     // two positive and one negative eigenvalues
+///     assert_eq!(ldlt.inertia(), (2, 1));
+///     approx_eq(ldlt.det(), -4.0, 1e-15);
+///
+///     // solution [x₀, x₁, λ]
+///     let mut x = Vector::new(3);
+///     ldlt.solve(&mut x, &Vector::from(&[0.0, 0.0, 1.0]))?;
+///     vec_approx_eq(x.as_data(), &[0.5, 0.5, -1.0], 1e-15);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_factor_ldlt(a: &Matrix) -> Result<LdltFactors, StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    let mut ld = a.as_data().clone();
+    let mut ipiv = vec![0; n];
+    if n > 0 {
+        dsytrf(false, to_i32(n), &mut ld, &mut ipiv)?;
+    }
+    Ok(LdltFactors { n, ld, ipiv })
+}
+
+impl LdltFactors {
+    /// Returns the dimension of the (square) matrix
+    pub fn dim(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the pivot indices as computed by dsytrf
+    ///
+    /// **Note:** The indices are one-based (as in Fortran). If `ipiv[k] > 0`, `d[k,k]` is a 1×1 block and
+    /// the row and column `k` were interchanged with `ipiv[k] - 1`. If `ipiv[k] = ipiv[k+1] < 0`,
+    /// `d[k..k+2,k..k+2]` is a 2×2 block and the row and column `k+1` were interchanged with `-ipiv[k] - 1`.
+    pub fn get_ipiv(&self) -> &[i32] {
+        &self.ipiv
+    }
+
+    /// Solves the linear system
+    ///
+    /// ```text
+    ///   a   ⋅  x  =  b
+    /// (n,n)   (n)   (n)
+    /// ```
+    pub fn solve(&self, x: &mut Vector, b: &Vector) -> Result<(), StrError> {
+        if x.dim() != self.n || b.dim() != self.n {
+            return Err("vectors must have dimension equal to the dimension of the matrix");
+        }
+        x.as_mut_data().copy_from_slice(b.as_data());
+        if self.n == 0 {
+            return Ok(());
+        }
+        let n_i32 = to_i32(self.n);
+        dsytrs(false, n_i32, 1, &self.ld, &self.ipiv, x.as_mut_data())
+    }
+
+    /// Returns the determinant of the matrix
+    ///
+    /// ```text
+    /// det(a) = det(d) = Π det(dₖ)
+    /// ```
+    ///
+    /// where `dₖ` are the 1×1 and 2×2 diagonal blocks of `d`.
+    ///
+    /// **Note:** Returns 1.0 if the dimension is zero.
+    pub fn det(&self) -> f64 {
+        self.blocks()
+            .map(|(d11, off)| match off {
+                Some((d21, d22)) => d11 * d22 - d21 * d21,
+                None => d11,
+            })
+            .product()
+    }
+
+    /// Returns the inertia of the matrix
+    ///
+    /// # Output
+    ///
+    /// Returns `(n_positive, n_negative)`, the numbers of positive and negative eigenvalues.
+    /// Because the matrix is not singular, `n_positive + n_negative = n`.
+    ///
+    /// For example, a KKT matrix with a positive definite (on the null space of the constraints)
+    /// `h` block and `m` linearly independent constraints has exactly `m` negative eigenvalues.
+    pub fn inertia(&self) -> (usize, usize) {
+        let (mut n_positive, mut n_negative) = (0, 0);
+        for (d11, off) in self.blocks() {
+            match off {
+                None if d11 > 0.0 => n_positive += 1,
+                None => n_negative += 1,
+                Some((d21, d22)) => {
+                    let det = d11 * d22 - d21 * d21;
+                    if det < 0.0 {
+                        // eigenvalues of opposite signs
+                        n_positive += 1;
+                        n_negative += 1;
+                    } else if d11 + d22 > 0.0 {
+                        n_positive += 2;
+                    } else {
+                        n_negative += 2;
+                    }
+                }
+            }
+        }
+        (n_positive, n_negative)
+    }
+
+    /// Returns an iterator over the diagonal blocks of d as (d11, None) or (d11, Some((d21, d22)))
+    fn blocks(&self) -> impl Iterator<Item = (f64, Option<(f64, f64)>)> + '_ {
+        let n = self.n;
+        let mut k = 0;
+        std::iter::from_fn(move || {
+            if k >= n {
+                return None;
+            }
+            let d11 = self.ld[k + k * n];
+            if self.ipiv[k] > 0 {
+                k += 1;
+                Some((d11, None))
+            } else {
+                let (d21, d22) = (self.ld[k + 1 + k * n], self.ld[k + 1 + (k + 1) * n]);
+                k += 2;
+                Some((d11, Some((d21, d22))))
+            }
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::mat_factor_ldlt;
+    use crate::{Matrix, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn mat_factor_ldlt_captures_errors() {
+        assert_eq!(mat_factor_ldlt(&Matrix::new(2, 3)).err(), Some("matrix must be square"));
+        assert_eq!(mat_factor_ldlt(&Matrix::new(2, 2)).err(), Some("LAPACK dsytrf failed"));
+        let ldlt = mat_factor_ldlt(&Matrix::diagonal(&[1.0, 2.0])).unwrap();
+        assert_eq!(
+            ldlt.solve(&mut Vector::new(2), &Vector::new(3)).err(),
+            Some("vectors must have dimension equal to the dimension of the matrix")
+        );
+    }
+
+    #[test]
+    fn mat_factor_ldlt_handles_empty_matrix() {
+        let ldlt = mat_factor_ldlt(&Matrix::new(0, 0)).unwrap();
+        assert_eq!(ldlt.dim(), 0);
+        assert_eq!(ldlt.det(), 1.0);
+        assert_eq!(ldlt.inertia(), (0, 0));
+        ldlt.solve(&mut Vector::new(0), &Vector::new(0)).unwrap();
+    }
+
+    #[test]
+    fn mat_factor_ldlt_works_with_definite_matrices() {
+        // positive definite (the upper triangle is not accessed)
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [  4.0, 99.0, 99.0],
+            [ 12.0, 37.0, 99.0],
+            [-16.0,-43.0, 98.0],
+        ]);
+        let ldlt = mat_factor_ldlt(&a).unwrap();
+        assert_eq!(ldlt.dim(), 3);
+        assert_eq!(ldlt.inertia(), (3, 0));
+        approx_eq(ldlt.det(), 36.0, 1e-12);
+        let mut x = Vector::new(3);
+        ldlt.solve(&mut x, &Vector::from(&[-44.0, -116.0, 283.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[-2.0, 1.0, 3.0], 1e-13);
+
+        // negative definite
+        let a = Matrix::diagonal(&[-1.0, -2.0, -4.0]);
+        let ldlt = mat_factor_ldlt(&a).unwrap();
+        assert_eq!(ldlt.inertia(), (0, 3));
+        approx_eq(ldlt.det(), -8.0, 1e-15);
+    }
+
+    #[test]
+    fn mat_factor_ldlt_works_with_saddle_point_matrices() {
+        // KKT matrix: h is positive definite and b has two independent rows
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [4.0, 1.0, 0.0, 1.0, 0.0],
+            [1.0, 3.0, 1.0, 0.0, 1.0],
+            [0.0, 1.0, 2.0, 1.0, 1.0],
+            [1.0, 0.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0, 0.0, 0.0],
+        ]);
+        let ldlt = mat_factor_ldlt(&a).unwrap();
+        assert_eq!(ldlt.inertia(), (3, 2));
+        approx_eq(ldlt.det(), 9.0, 1e-13);
+        let x_correct = &[1.0, -1.0, 2.0, 0.5, -3.0];
+        let mut b = Vector::new(5);
+        for i in 0..5 {
+            for (j, xj) in x_correct.iter().enumerate() {
+                b[i] += a.get(i, j) * xj;
+            }
+        }
+        let mut x = Vector::new(5);
+        ldlt.solve(&mut x, &b).unwrap();
+        vec_approx_eq(x.as_data(), x_correct, 1e-14);
+
+        // zero diagonal: a 2×2 pivot is required
+        let a = Matrix::from(&[[0.0, 1.0], [1.0, 0.0]]);
+        let ldlt = mat_factor_ldlt(&a).unwrap();
+        assert_eq!(ldlt.get_ipiv(), &[-2, -2]);
+        assert_eq!(ldlt.inertia(), (1, 1));
+        approx_eq(ldlt.det(), -1.0, 1e-15);
+        let mut x = Vector::new(2);
+        ldlt.solve(&mut x, &Vector::from(&[2.0, 3.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[3.0, 2.0], 1e-15);
+    }
+}
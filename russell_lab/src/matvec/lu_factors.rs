@@ -0,0 +1,289 @@
+use super::DenseLu;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::{Permutation, RussellError, StrError};
+use russell_openblas::{dgetri, dgetrs, to_i32};
+
+/// Holds the LU factorization (with partial pivoting) of a dense square matrix
+///
+/// The matrix is factorized once with LAPACK dgetrf:
+///
+/// ```text
+/// a = p ⋅ l ⋅ u
+/// ```
+///
+/// where `p` is a permutation matrix, `l` is lower triangular with unit diagonal, and `u` is
+/// upper triangular. Afterwards, the factors can be reused to solve systems with many
+/// right-hand sides (with `a` or `aᵀ`), and to compute the determinant and the inverse.
+/// In contrast, [crate::solve_lin_sys] refactorizes the matrix at each call.
+///
+/// The factorization is performed and stored by [DenseLu] (without low-rank modifications);
+/// this structure gives access to the factors and to the operations that require them unmodified.
+/// See [DenseLu] to consider low-rank modifications of the factorized matrix.
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::{approx_eq, vec_approx_eq};
+/// use russell_lab::{LuFactors, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0,  3.0, -2.0],
+///         [3.0,  5.0,  6.0],
+///         [2.0,  4.0,  3.0],
+///     ]);
+///     let lu = LuFactors::new(&a)?;
+///     approx_eq(lu.det(), -4.0, 1e-14);
+///
+///     // a ⋅ x = b
+///     let mut x = Vector::new(3);
+///     lu.solve(&mut x, &Vector::from(&[5.0, 7.0, 8.0]))?;
+///     vec_approx_eq(x.as_data(), &[-15.0, 8.0, 2.0], 1e-13);
+///
+///     // aᵀ ⋅ x = b (same factors)
+///     lu.solve_transpose(&mut x, &Vector::from(&[8.0, 16.0, 10.0]))?;
+///     vec_approx_eq(x.as_data(), &[1.0, 1.0, 2.0], 1e-13);
+///     Ok(())
+/// }
+/// ```
+pub struct LuFactors {
+    dense: DenseLu, // l (below the diagonal) and u (upper part) factors and pivots (never updated)
+}
+
+impl LuFactors {
+    /// Computes the LU factorization of a square matrix
+    ///
+    /// **Note:** The matrix `a` is not modified (a copy is factorized).
+    pub fn new(a: &Matrix) -> Result<Self, StrError> {
+        Ok(LuFactors {
+            dense: DenseLu::new(a)?,
+        })
+    }
+
+    /// Returns the dimension of the (square) matrix
+    pub fn dim(&self) -> usize {
+        self.dense.n
+    }
+
+    /// Returns the lower triangular factor `l` (with unit diagonal)
+    pub fn get_l(&self) -> Matrix {
+        let (n, lu) = (self.dense.n, &self.dense.lu);
+        let mut l = Matrix::new(n, n);
+        for j in 0..n {
+            l.set(j, j, 1.0);
+            for i in (j + 1)..n {
+                l.set(i, j, lu[i + j * n]);
+            }
+        }
+        l
+    }
+
+    /// Returns the upper triangular factor `u`
+    pub fn get_u(&self) -> Matrix {
+        let (n, lu) = (self.dense.n, &self.dense.lu);
+        let mut u = Matrix::new(n, n);
+        for j in 0..n {
+            for i in 0..(j + 1) {
+                u.set(i, j, lu[i + j * n]);
+            }
+        }
+        u
+    }
+
+    /// Returns the pivot indices as computed by dgetrf
+    ///
+    /// **Note:** The indices are one-based (as in Fortran); i.e., the row `i` of the matrix was
+    /// interchanged with the row `ipiv[i] - 1` during the factorization.
+    pub fn get_ipiv(&self) -> &[i32] {
+        &self.dense.ipiv
+    }
+
+    /// Returns the row permutation corresponding to the pivot indices
+    ///
+    /// The rows of `a` permuted by the returned permutation equal `l ⋅ u` (see [Permutation::from_ipiv]).
+    pub fn get_permutation(&self) -> Permutation {
+        Permutation::from_ipiv(&self.dense.ipiv).unwrap() // the pivot indices computed by dgetrf are always valid
+    }
+
+    /// Solves the linear system
+    ///
+    /// ```text
+    ///   a   ⋅  x  =  b
+    /// (n,n)   (n)   (n)
+    /// ```
+    pub fn solve(&self, x: &mut Vector, b: &Vector) -> Result<(), RussellError> {
+        self.dense.solve(x, b)
+    }
+
+    /// Solves the linear system with the transposed matrix
+    ///
+    /// ```text
+    ///   aᵀ  ⋅  x  =  b
+    /// (n,n)   (n)   (n)
+    /// ```
+    pub fn solve_transpose(&self, x: &mut Vector, b: &Vector) -> Result<(), RussellError> {
+        self.dense.check_dim(x.dim())?;
+        self.dense.check_dim(b.dim())?;
+        x.as_mut_data().copy_from_slice(b.as_data());
+        let n = self.dense.n;
+        if n == 0 {
+            return Ok(());
+        }
+        dgetrs(true, to_i32(n), 1, &self.dense.lu, &self.dense.ipiv, x.as_mut_data())?;
+        Ok(())
+    }
+
+    /// Returns the determinant of the matrix
+    ///
+    /// ```text
+    /// det(a) = det(p) ⋅ Π uᵢᵢ
+    /// ```
+    ///
+    /// where `det(p) = ±1` depends on the number of row interchanges.
+    ///
+    /// **Note:** Returns 1.0 if the dimension is zero.
+    pub fn det(&self) -> f64 {
+        let (n, lu, ipiv) = (self.dense.n, &self.dense.lu, &self.dense.ipiv);
+        let mut det = 1.0;
+        for i in 0..n {
+            det *= lu[i + i * n];
+            if ipiv[i] != to_i32(i + 1) {
+                det = -det;
+            }
+        }
+        det
+    }
+
+    /// Computes the inverse of the matrix with LAPACK dgetri
+    ///
+    /// # Output
+    ///
+    /// * `ai` -- (n,n) the inverse matrix
+    pub fn inverse(&self, ai: &mut Matrix) -> Result<(), StrError> {
+        let n = self.dense.n;
+        if ai.dims() != (n, n) {
+            return Err("matrices are incompatible");
+        }
+        if n == 0 {
+            return Ok(());
+        }
+        let data = ai.as_mut_data();
+        data.copy_from_slice(&self.dense.lu);
+        dgetri(to_i32(n), data, &self.dense.ipiv)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::LuFactors;
+    use crate::{mat_approx_eq, mat_mat_mul, Matrix, RussellError, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn lu_factors_captures_errors() {
+        assert_eq!(LuFactors::new(&Matrix::new(2, 3)).err(), Some("matrix must be square"));
+        assert_eq!(LuFactors::new(&Matrix::new(2, 2)).err(), Some("LAPACK dgetrf failed"));
+        let lu = LuFactors::new(&Matrix::diagonal(&[1.0, 2.0])).unwrap();
+        let mut x = Vector::new(2);
+        assert_eq!(
+            lu.solve(&mut x, &Vector::new(3)).err(),
+            Some(RussellError::DimensionMismatch { expected: 2, found: 3 })
+        );
+        assert_eq!(
+            lu.solve_transpose(&mut Vector::new(1), &Vector::new(2)).err(),
+            Some(RussellError::DimensionMismatch { expected: 2, found: 1 })
+        );
+        let mut ai = Matrix::new(2, 3);
+        assert_eq!(lu.inverse(&mut ai).err(), Some("matrices are incompatible"));
+    }
+
+    #[test]
+    fn lu_factors_handles_empty_matrix() {
+        let lu = LuFactors::new(&Matrix::new(0, 0)).unwrap();
+        assert_eq!(lu.dim(), 0);
+        assert_eq!(lu.det(), 1.0);
+        let mut x = Vector::new(0);
+        lu.solve(&mut x, &Vector::new(0)).unwrap();
+        lu.inverse(&mut Matrix::new(0, 0)).unwrap();
+    }
+
+    #[test]
+    fn lu_factors_reconstructs_matrix() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0,  3.0, -2.0],
+            [3.0,  5.0,  6.0],
+            [2.0,  4.0,  3.0],
+        ]);
+        let lu = LuFactors::new(&a).unwrap();
+        assert_eq!(lu.dim(), 3);
+        assert_eq!(lu.get_ipiv(), &[2, 2, 3]);
+        let (l, u) = (lu.get_l(), lu.get_u());
+        #[rustfmt::skip]
+        let l_correct = Matrix::from(&[
+            [1.0,       0.0,       0.0],
+            [1.0 / 3.0, 1.0,       0.0],
+            [2.0 / 3.0, 1.0 / 2.0, 1.0],
+        ]);
+        #[rustfmt::skip]
+        let u_correct = Matrix::from(&[
+            [3.0, 5.0,        6.0],
+            [0.0, 4.0 / 3.0, -4.0],
+            [0.0, 0.0,        1.0],
+        ]);
+        mat_approx_eq(&l, &l_correct, 1e-15);
+        mat_approx_eq(&u, &u_correct, 1e-15);
+        // the rows of a permuted as recorded in ipiv equal l ⋅ u
+        let mut lu_prod = Matrix::new(3, 3);
+        mat_mat_mul(&mut lu_prod, 1.0, &l, &u).unwrap();
+        let perm = lu.get_permutation();
+        assert_eq!(perm.as_slice(), &[1, 0, 2]);
+        let mut pa = Matrix::new(3, 3);
+        perm.apply_mat_rows(&mut pa, &a).unwrap();
+        mat_approx_eq(&lu_prod, &pa, 1e-14);
+        approx_eq(perm.sign() * u.get(0, 0) * u.get(1, 1) * u.get(2, 2), lu.det(), 1e-14);
+        approx_eq(lu.det(), -4.0, 1e-14);
+    }
+
+    #[test]
+    fn lu_factors_solve_det_and_inverse_work() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [2.0, 1.0, 1.0, 3.0, 2.0],
+            [1.0, 2.0, 2.0, 1.0, 1.0],
+            [1.0, 2.0, 9.0, 1.0, 5.0],
+            [3.0, 1.0, 1.0, 7.0, 1.0],
+            [2.0, 1.0, 5.0, 1.0, 8.0],
+        ]);
+        let lu = LuFactors::new(&a).unwrap();
+        let mut x = Vector::new(5);
+        lu.solve(&mut x, &Vector::from(&[6.0, 4.0, 16.0, 5.0, 22.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[-2.0, 1.0, 0.0, 1.0, 3.0], 1e-13);
+        lu.solve_transpose(&mut x, &Vector::from(&[6.0, 4.0, 16.0, 5.0, 22.0]))
+            .unwrap();
+        vec_approx_eq(x.as_data(), &[-2.0, 1.0, 0.0, 1.0, 3.0], 1e-13);
+        approx_eq(lu.det(), 98.0, 1e-12);
+        let mut ai = Matrix::new(5, 5);
+        lu.inverse(&mut ai).unwrap();
+        let mut a_ai = Matrix::new(5, 5);
+        mat_mat_mul(&mut a_ai, 1.0, &a, &ai).unwrap();
+        mat_approx_eq(&a_ai, &Matrix::identity(5), 1e-14);
+
+        // non-symmetric matrix: a ⋅ x = b and aᵀ ⋅ x = b differ
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0,  3.0, -2.0],
+            [3.0,  5.0,  6.0],
+            [2.0,  4.0,  3.0],
+        ]);
+        let lu = LuFactors::new(&a).unwrap();
+        let mut x = Vector::new(3);
+        lu.solve(&mut x, &Vector::from(&[5.0, 7.0, 8.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[-15.0, 8.0, 2.0], 1e-13);
+        lu.solve_transpose(&mut x, &Vector::from(&[8.0, 16.0, 10.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[1.0, 1.0, 2.0], 1e-13);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Equilibrates a matrix by scaling its rows and columns (similar to LAPACK's dgeequ)
+///
+/// Computes the row scale factors `r` and column scale factors `c` such that the largest
+/// absolute value in each row and column of the scaled matrix is equal to one:
+///
+/// ```text
+/// rᵢ = 1 / maxⱼ |aᵢⱼ|
+/// cⱼ = 1 / maxᵢ |rᵢ aᵢⱼ|
+///
+/// a := diag(r) ⋅ a ⋅ diag(c)
+/// ```
+///
+/// # Input
+///
+/// * `r` -- vector with dim = m (will hold the row scale factors)
+/// * `c` -- vector with dim = n (will hold the column scale factors)
+/// * `a` -- (m, n) matrix (will be scaled)
+///
+/// # Note
+///
+/// To solve a linear system `a⋅x = b` using the scaled matrix, solve
+/// `(diag(r)⋅a⋅diag(c))⋅y = diag(r)⋅b` and then compute `x = diag(c)⋅y`
+/// (see [crate::solve_lin_sys_equilibrated]).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_equilibrate, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut a = Matrix::from(&[
+///         [1e4, 2e4],
+///         [3e-4, 1e-4],
+///     ]);
+///     let mut r = Vector::new(2);
+///     let mut c = Vector::new(2);
+///     mat_equilibrate(&mut r, &mut c, &mut a)?;
+///     let correct = "┌             ┐\n\
+///                    │ 0.500 1.000 │\n\
+///                    │ 1.000 0.333 │\n\
+///                    └             ┘";
+///     assert_eq!(format!("{:.3}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_equilibrate(r: &mut Vector, c: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if r.dim() != m || c.dim() != n {
+        return Err("vectors are incompatible");
+    }
+    for i in 0..m {
+        let mut max_abs = 0.0;
+        for j in 0..n {
+            max_abs = f64::max(max_abs, f64::abs(a.get(i, j)));
+        }
+        if max_abs == 0.0 {
+            return Err("cannot equilibrate matrix with a zero row");
+        }
+        r[i] = 1.0 / max_abs;
+    }
+    for j in 0..n {
+        let mut max_abs = 0.0;
+        for i in 0..m {
+            max_abs = f64::max(max_abs, r[i] * f64::abs(a.get(i, j)));
+        }
+        if max_abs == 0.0 {
+            return Err("cannot equilibrate matrix with a zero column");
+        }
+        c[j] = 1.0 / max_abs;
+    }
+    for i in 0..m {
+        for j in 0..n {
+            a.mul(i, j, r[i] * c[j]);
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_equilibrate, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn mat_equilibrate_fails_on_wrong_input() {
+        let mut a = Matrix::new(2, 3);
+        let mut r = Vector::new(2);
+        let mut c = Vector::new(2);
+        assert_eq!(
+            mat_equilibrate(&mut r, &mut c, &mut a).err(),
+            Some("vectors are incompatible")
+        );
+        let mut c = Vector::new(3);
+        assert_eq!(
+            mat_equilibrate(&mut r, &mut c, &mut a).err(),
+            Some("cannot equilibrate matrix with a zero row")
+        );
+        let mut a = Matrix::from(&[[1.0, 0.0, 2.0], [3.0, 0.0, 4.0]]);
+        assert_eq!(
+            mat_equilibrate(&mut r, &mut c, &mut a).err(),
+            Some("cannot equilibrate matrix with a zero column")
+        );
+    }
+
+    #[test]
+    fn mat_equilibrate_works() {
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [ 1e6, -2e6, 0.0],
+            [ 1.0,  4.0, 1e-3],
+            [-2e-6, 0.0, 5e-6],
+        ]);
+        let mut r = Vector::new(3);
+        let mut c = Vector::new(3);
+        mat_equilibrate(&mut r, &mut c, &mut a).unwrap();
+        vec_approx_eq(r.as_data(), &[0.5e-6, 0.25, 2e5], 1e-10);
+        vec_approx_eq(c.as_data(), &[1.0 / 0.5, 1.0, 1.0], 1e-15);
+        #[rustfmt::skip]
+        let correct = &[
+            [ 1.0, -1.0, 0.0],
+            [ 0.5,  1.0, 0.25e-3],
+            [-0.8,  0.0, 1.0],
+        ];
+        for (i, row) in correct.iter().enumerate() {
+            vec_approx_eq(&a.extract_row(i), row, 1e-15);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Computes the mean of the columns of a matrix (i.e., the mean of each row)
+///
+/// ```text
+/// vᵢ = (1/n) Σ_j aᵢⱼ
+/// ```
+///
+/// # Input
+///
+/// * `v` -- vector with dim = m
+/// * `a` -- (m, n) matrix with n ≥ 1
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_mean_cols, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 9.0]]);
+///     let mut v = Vector::new(2);
+///     mat_mean_cols(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[2.0, 6.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_mean_cols(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if v.dim() != m {
+        return Err("vector is incompatible");
+    }
+    if n == 0 {
+        return Err("the matrix must have at least one column");
+    }
+    for i in 0..m {
+        v[i] = 0.0;
+        for j in 0..n {
+            v[i] += a.get(i, j);
+        }
+        v[i] /= n as f64;
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_mean_cols, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn mat_mean_cols_fails_on_wrong_dims() {
+        let mut v = Vector::new(3);
+        assert_eq!(mat_mean_cols(&mut v, &Matrix::new(1, 2)), Err("vector is incompatible"));
+        assert_eq!(
+            mat_mean_cols(&mut v, &Matrix::new(3, 0)),
+            Err("the matrix must have at least one column")
+        );
+    }
+
+    #[test]
+    fn mat_mean_cols_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 5.0, -2.0, 0.0, 1.0],
+            [10.0, -4.0, 0.0, 2.0],
+            [15.0, -6.0, 0.0, 3.0],
+        ]);
+        let mut v = Vector::new(a.nrow());
+        mat_mean_cols(&mut v, &a).unwrap();
+        vec_approx_eq(v.as_data(), &[1.0, 2.0, 3.0], 1e-15);
+    }
+}
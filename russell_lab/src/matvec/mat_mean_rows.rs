@@ -0,0 +1,76 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Computes the mean of the rows of a matrix (i.e., the mean of each column)
+///
+/// ```text
+/// vⱼ = (1/m) Σ_i aᵢⱼ
+/// ```
+///
+/// # Input
+///
+/// * `v` -- vector with dim = n
+/// * `a` -- (m, n) matrix with m ≥ 1
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_mean_rows, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 9.0]]);
+///     let mut v = Vector::new(3);
+///     mat_mean_rows(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[2.5, 3.5, 6.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_mean_rows(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if v.dim() != n {
+        return Err("vector is incompatible");
+    }
+    if m == 0 {
+        return Err("the matrix must have at least one row");
+    }
+    for j in 0..n {
+        v[j] = 0.0;
+        for i in 0..m {
+            v[j] += a.get(i, j);
+        }
+        v[j] /= m as f64;
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_mean_rows, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn mat_mean_rows_fails_on_wrong_dims() {
+        let mut v = Vector::new(3);
+        assert_eq!(mat_mean_rows(&mut v, &Matrix::new(1, 2)), Err("vector is incompatible"));
+        assert_eq!(
+            mat_mean_rows(&mut v, &Matrix::new(0, 3)),
+            Err("the matrix must have at least one row")
+        );
+    }
+
+    #[test]
+    fn mat_mean_rows_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 5.0, -2.0, 0.0, 1.0],
+            [10.0, -4.0, 0.0, 2.0],
+            [15.0, -6.0, 0.0, 3.0],
+        ]);
+        let mut v = Vector::new(a.ncol());
+        mat_mean_rows(&mut v, &a).unwrap();
+        vec_approx_eq(v.as_data(), &[10.0, -4.0, 0.0, 2.0], 1e-15);
+    }
+}
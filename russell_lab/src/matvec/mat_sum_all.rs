@@ -0,0 +1,38 @@
+use crate::matrix::Matrix;
+
+/// Sums all components of a matrix
+///
+/// ```text
+/// s = Σ_i Σ_j aᵢⱼ
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_sum_all, Matrix};
+///
+/// let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// assert_eq!(mat_sum_all(&a), 21.0);
+/// ```
+pub fn mat_sum_all(a: &Matrix) -> f64 {
+    a.as_data().iter().sum()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_sum_all, Matrix};
+
+    #[test]
+    fn mat_sum_all_works() {
+        assert_eq!(mat_sum_all(&Matrix::new(0, 0)), 0.0);
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 5.0, -2.0, 0.0, 1.0],
+            [10.0, -4.0, 0.0, 2.0],
+            [15.0, -6.0, 0.0, 3.0],
+        ]);
+        assert_eq!(mat_sum_all(&a), 24.0);
+    }
+}
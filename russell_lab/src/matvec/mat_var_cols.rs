@@ -0,0 +1,93 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Computes the (sample) variance of the columns of a matrix (i.e., the variance of each row)
+///
+/// ```text
+///        1
+/// vᵢ = ————— Σ_j (aᵢⱼ - āᵢ)²    with    āᵢ = (1/n) Σ_j aᵢⱼ
+///      n - 1
+/// ```
+///
+/// The two-pass algorithm (computing the mean first) is used to avoid cancellation errors.
+///
+/// # Input
+///
+/// * `v` -- vector with dim = m
+/// * `a` -- (m, n) matrix with n ≥ 2
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_var_cols, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 6.0, 8.0]]);
+///     let mut v = Vector::new(2);
+///     mat_var_cols(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[1.0, 4.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_var_cols(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if v.dim() != m {
+        return Err("vector is incompatible");
+    }
+    if n < 2 {
+        return Err("the matrix must have at least two columns");
+    }
+    for i in 0..m {
+        let mut mean = 0.0;
+        for j in 0..n {
+            mean += a.get(i, j);
+        }
+        mean /= n as f64;
+        v[i] = 0.0;
+        for j in 0..n {
+            let d = a.get(i, j) - mean;
+            v[i] += d * d;
+        }
+        v[i] /= (n - 1) as f64;
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_var_cols, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn mat_var_cols_fails_on_wrong_dims() {
+        let mut v = Vector::new(3);
+        assert_eq!(mat_var_cols(&mut v, &Matrix::new(1, 2)), Err("vector is incompatible"));
+        assert_eq!(
+            mat_var_cols(&mut v, &Matrix::new(3, 1)),
+            Err("the matrix must have at least two columns")
+        );
+    }
+
+    #[test]
+    fn mat_var_cols_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 5.0, -2.0, 0.0, 1.0],
+            [10.0, -4.0, 0.0, 2.0],
+            [ 1.0,  1.0, 1.0, 1.0],
+        ]);
+        let mut v = Vector::new(a.nrow());
+        mat_var_cols(&mut v, &a).unwrap();
+        // mean = 1 ⇒ Σ(aᵢⱼ - 1)² = 16 + 9 + 1 + 0 = 26
+        vec_approx_eq(v.as_data(), &[26.0 / 3.0, 104.0 / 3.0, 0.0], 1e-14);
+
+        // large offset (cancellation check)
+        let a = Matrix::from(&[[1e9 + 1.0, 1e9 + 2.0, 1e9 + 3.0]]);
+        let mut v = Vector::new(1);
+        mat_var_cols(&mut v, &a).unwrap();
+        vec_approx_eq(v.as_data(), &[1.0], 1e-15);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Computes the (sample) variance of the rows of a matrix (i.e., the variance of each column)
+///
+/// ```text
+///        1
+/// vⱼ = ————— Σ_i (aᵢⱼ - āⱼ)²    with    āⱼ = (1/m) Σ_i aᵢⱼ
+///      m - 1
+/// ```
+///
+/// The two-pass algorithm (computing the mean first) is used to avoid cancellation errors.
+///
+/// # Input
+///
+/// * `v` -- vector with dim = n
+/// * `a` -- (m, n) matrix with m ≥ 2
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_var_rows, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0, 4.0], [2.0, 6.0], [3.0, 8.0]]);
+///     let mut v = Vector::new(2);
+///     mat_var_rows(&mut v, &a)?;
+///     assert_eq!(v.as_data(), &[1.0, 4.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_var_rows(v: &mut Vector, a: &Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if v.dim() != n {
+        return Err("vector is incompatible");
+    }
+    if m < 2 {
+        return Err("the matrix must have at least two rows");
+    }
+    for j in 0..n {
+        let mut mean = 0.0;
+        for i in 0..m {
+            mean += a.get(i, j);
+        }
+        mean /= m as f64;
+        v[j] = 0.0;
+        for i in 0..m {
+            let d = a.get(i, j) - mean;
+            v[j] += d * d;
+        }
+        v[j] /= (m - 1) as f64;
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_var_rows, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn mat_var_rows_fails_on_wrong_dims() {
+        let mut v = Vector::new(3);
+        assert_eq!(mat_var_rows(&mut v, &Matrix::new(1, 2)), Err("vector is incompatible"));
+        assert_eq!(
+            mat_var_rows(&mut v, &Matrix::new(1, 3)),
+            Err("the matrix must have at least two rows")
+        );
+    }
+
+    #[test]
+    fn mat_var_rows_works() {
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 5.0, -2.0, 0.0, 1.0],
+            [10.0, -4.0, 0.0, 2.0],
+            [15.0, -6.0, 0.0, 3.0],
+        ]);
+        let mut v = Vector::new(a.ncol());
+        mat_var_rows(&mut v, &a).unwrap();
+        vec_approx_eq(v.as_data(), &[25.0, 4.0, 0.0, 1.0], 1e-14);
+    }
+}
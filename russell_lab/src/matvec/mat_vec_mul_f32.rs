@@ -0,0 +1,107 @@
+use crate::matrix::MatrixF32;
+use crate::vector::VectorF32;
+use crate::StrError;
+use russell_openblas::{sgemv, to_i32};
+
+/// Performs the matrix-vector multiplication resulting in a vector (single precision)
+///
+/// ```text
+///  v  :=  α ⋅  a   ⋅  u
+/// (m)        (m,n)   (n)
+/// ```
+///
+/// # Note
+///
+/// The length of vector `u` must equal the number of columns of matrix `a` and
+/// the length of vector `v` must equal the number of rows of matrix `a`.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_vec_mul_f32, MatrixF32, StrError, VectorF32};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = MatrixF32::from(&[
+///         [ 5.0_f32, -2.0, 1.0],
+///         [-4.0_f32,  0.0, 2.0],
+///     ]);
+///     let u = VectorF32::from(&[1.0_f32, 2.0, 3.0]);
+///     let mut v = VectorF32::new(a.nrow());
+///     mat_vec_mul_f32(&mut v, 0.5, &a, &u)?;
+///     let correct = "┌   ┐\n\
+///                    │ 2 │\n\
+///                    │ 1 │\n\
+///                    └   ┘";
+///     assert_eq!(format!("{}", v), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn mat_vec_mul_f32(v: &mut VectorF32, alpha: f32, a: &MatrixF32, u: &VectorF32) -> Result<(), StrError> {
+    let m = v.dim();
+    let n = u.dim();
+    if m != a.nrow() || n != a.ncol() {
+        return Err("matrix and vectors are incompatible");
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    if n == 0 {
+        v.fill(0.0);
+        return Ok(());
+    }
+    let m_i32: i32 = to_i32(m);
+    let n_i32: i32 = to_i32(n);
+    sgemv(
+        false,
+        m_i32,
+        n_i32,
+        alpha,
+        a.as_data(),
+        u.as_data(),
+        1,
+        0.0,
+        v.as_mut_data(),
+        1,
+    );
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{mat_vec_mul_f32, MatrixF32, VectorF32};
+
+    #[test]
+    fn mat_vec_mul_f32_fails_on_wrong_dims() {
+        let u = VectorF32::new(2);
+        let mut v = VectorF32::new(3);
+        assert_eq!(
+            mat_vec_mul_f32(&mut v, 1.0, &MatrixF32::new(1, 2), &u),
+            Err("matrix and vectors are incompatible")
+        );
+        assert_eq!(
+            mat_vec_mul_f32(&mut v, 1.0, &MatrixF32::new(3, 1), &u),
+            Err("matrix and vectors are incompatible")
+        );
+    }
+
+    #[test]
+    fn mat_vec_mul_f32_works() {
+        #[rustfmt::skip]
+        let a = MatrixF32::from(&[
+            [ 5.0_f32, -2.0, 0.0, 1.0],
+            [10.0_f32, -4.0, 0.0, 2.0],
+            [15.0_f32, -6.0, 0.0, 3.0],
+        ]);
+        let u = VectorF32::from(&[1.0_f32, 3.0, 8.0, 5.0]);
+        let mut v = VectorF32::new(a.nrow());
+        mat_vec_mul_f32(&mut v, 1.0, &a, &u).unwrap();
+        assert_eq!(v.as_data(), &[4.0, 8.0, 12.0]);
+
+        // empty u
+        let mut v = VectorF32::from(&[1.0_f32]);
+        mat_vec_mul_f32(&mut v, 1.0, &MatrixF32::new(1, 0), &VectorF32::new(0)).unwrap();
+        assert_eq!(v.as_data(), &[0.0]);
+    }
+}
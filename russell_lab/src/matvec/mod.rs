@@ -1,14 +1,72 @@
 //! This module contains functions for calculations with matrices and vectors
 
+mod complex_mat_vec_mul;
+#[cfg(feature = "blas")]
+mod complex_solve_lin_sys;
+#[cfg(feature = "blas")]
+mod dense_lu;
+#[cfg(feature = "blas")]
+mod fit_linear;
+#[cfg(feature = "blas")]
+mod ldlt_factors;
+#[cfg(feature = "blas")]
+mod lu_factors;
+mod mat_equilibrate;
+mod mat_mean_cols;
+mod mat_mean_rows;
+mod mat_sum_all;
 mod mat_sum_cols;
 mod mat_sum_rows;
+mod mat_var_cols;
+mod mat_var_rows;
 mod mat_vec_mul;
+#[cfg(feature = "blas")]
+mod mat_vec_mul_f32;
+#[cfg(feature = "blas")]
 mod solve_lin_sys;
+#[cfg(feature = "blas")]
+mod solve_lsq;
+#[cfg(feature = "blas")]
+mod solve_triangular;
+mod solve_tridiag;
+#[cfg(feature = "blas")]
+mod solve_tsvd;
 mod vec_mat_mul;
 mod vec_outer;
+mod vec_outer_sym;
+mod vec_outer_update;
+pub use crate::matvec::complex_mat_vec_mul::*;
+#[cfg(feature = "blas")]
+pub use crate::matvec::complex_solve_lin_sys::*;
+#[cfg(feature = "blas")]
+pub use crate::matvec::dense_lu::*;
+#[cfg(feature = "blas")]
+pub use crate::matvec::fit_linear::*;
+#[cfg(feature = "blas")]
+pub use crate::matvec::ldlt_factors::*;
+#[cfg(feature = "blas")]
+pub use crate::matvec::lu_factors::*;
+pub use crate::matvec::mat_equilibrate::*;
+pub use crate::matvec::mat_mean_cols::*;
+pub use crate::matvec::mat_mean_rows::*;
+pub use crate::matvec::mat_sum_all::*;
 pub use crate::matvec::mat_sum_cols::*;
 pub use crate::matvec::mat_sum_rows::*;
+pub use crate::matvec::mat_var_cols::*;
+pub use crate::matvec::mat_var_rows::*;
 pub use crate::matvec::mat_vec_mul::*;
+#[cfg(feature = "blas")]
+pub use crate::matvec::mat_vec_mul_f32::*;
+#[cfg(feature = "blas")]
 pub use crate::matvec::solve_lin_sys::*;
+#[cfg(feature = "blas")]
+pub use crate::matvec::solve_lsq::*;
+#[cfg(feature = "blas")]
+pub use crate::matvec::solve_triangular::*;
+pub use crate::matvec::solve_tridiag::*;
+#[cfg(feature = "blas")]
+pub use crate::matvec::solve_tsvd::*;
 pub use crate::matvec::vec_mat_mul::*;
 pub use crate::matvec::vec_outer::*;
+pub use crate::matvec::vec_outer_sym::*;
+pub use crate::matvec::vec_outer_update::*;
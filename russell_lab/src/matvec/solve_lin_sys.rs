@@ -1,7 +1,8 @@
+use super::mat_equilibrate;
 use crate::matrix::Matrix;
 use crate::vector::Vector;
 use crate::StrError;
-use russell_openblas::{dgesv, to_i32};
+use russell_openblas::{dgesv, dposv, dsgesv, to_i32};
 
 /// Solves a general linear system (real numbers)
 ///
@@ -20,6 +21,7 @@ use russell_openblas::{dgesv, to_i32};
 /// ```
 ///
 /// The solution is obtained via LU decomposition using Lapack dgesv routine.
+/// See [crate::LuFactors] to reuse the factorization for many right-hand sides.
 ///
 /// # Note
 ///
@@ -68,12 +70,196 @@ pub fn solve_lin_sys(b: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
     Ok(())
 }
 
+/// Solves a general linear system (real numbers) after equilibrating the matrix
+///
+/// Same as [solve_lin_sys]; however, the rows and columns of `a` are scaled first
+/// (see [crate::mat_equilibrate]) and the scaling is undone after the solution:
+///
+/// ```text
+/// (R⋅a⋅C) ⋅ y = R⋅b
+///           x = C⋅y
+/// ```
+///
+/// where `R = diag(r)` and `C = diag(c)` hold the row and column scale factors.
+/// This may improve the accuracy on badly scaled systems.
+///
+/// # Note
+///
+/// 1. The matrix `a` will be modified (it will hold the LU factors of the scaled matrix)
+/// 2. The right-hand-side `b` will contain the solution `x`
+///
+/// ```
+/// use russell_lab::{solve_lin_sys_equilibrated, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // set matrix and right-hand side
+///     let mut a = Matrix::from(&[
+///         [1e8,  3e8, -2e8],
+///         [3.0,  5.0,  6.0],
+///         [2e-8, 4e-8, 3e-8],
+///     ]);
+///     let mut b = Vector::from(&[5e8, 7.0, 8e-8]);
+///
+///     // solve linear system b := a⁻¹⋅b
+///     solve_lin_sys_equilibrated(&mut b, &mut a)?;
+///
+///     // check
+///     let x_correct = "┌         ┐\n\
+///                      │ -15.000 │\n\
+///                      │   8.000 │\n\
+///                      │   2.000 │\n\
+///                      └         ┘";
+///     assert_eq!(format!("{:.3}", b), x_correct);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_lin_sys_equilibrated(b: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if b.dim() != m {
+        return Err("vector has wrong dimension");
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    let mut r = Vector::new(m);
+    let mut c = Vector::new(m);
+    mat_equilibrate(&mut r, &mut c, a)?;
+    for i in 0..m {
+        b[i] *= r[i];
+    }
+    solve_lin_sys(b, a)?;
+    for i in 0..m {
+        b[i] *= c[i];
+    }
+    Ok(())
+}
+
+/// Solves a linear system with a symmetric positive-definite matrix (real numbers)
+///
+/// Same as [solve_lin_sys]; however, the matrix `a` must be symmetric and positive-definite
+/// (e.g., a stiffness matrix with enough boundary conditions) and the solution is obtained via
+/// Cholesky decomposition using Lapack dposv routine. The Cholesky decomposition requires about
+/// half the operations of the LU decomposition and no pivoting.
+///
+/// An error is returned if `a` is not positive-definite; in this case, [solve_lin_sys] may be used instead.
+///
+/// # Note
+///
+/// 1. Only the lower triangle of `a` is accessed; it will be overwritten by the Cholesky factor
+/// 2. The right-hand-side `b` will contain the solution `x`
+///
+/// ```
+/// use russell_lab::{solve_lin_sys_spd, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // set matrix and right-hand side
+///     let mut a = Matrix::from(&[
+///         [  4.0,  12.0, -16.0],
+///         [ 12.0,  37.0, -43.0],
+///         [-16.0, -43.0,  98.0],
+///     ]);
+///     let mut b = Vector::from(&[-44.0, -116.0, 283.0]);
+///
+///     // solve linear system b := a⁻¹⋅b
+///     solve_lin_sys_spd(&mut b, &mut a)?;
+///
+///     // check
+///     let x_correct = "┌        ┐\n\
+///                      │ -2.000 │\n\
+///                      │  1.000 │\n\
+///                      │  3.000 │\n\
+///                      └        ┘";
+///     assert_eq!(format!("{:.3}", b), x_correct);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_lin_sys_spd(b: &mut Vector, a: &mut Matrix) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if b.dim() != m {
+        return Err("vector has wrong dimension");
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    let m_i32 = to_i32(m);
+    dposv(false, m_i32, 1, a.as_mut_data(), b.as_mut_data())?;
+    Ok(())
+}
+
+/// Solves a general linear system (real numbers) using mixed precision iterative refinement
+///
+/// Same as [solve_lin_sys]; however, the LU factorization is computed in single precision
+/// (f32) and the solution is refined in double precision (f64) using Lapack dsgesv routine.
+/// The single precision factorization is about twice as fast (and requires half the memory)
+/// as the double precision one; thus, this function is advantageous for large and moderately
+/// conditioned systems. If the refinement does not converge (e.g., the condition number is
+/// larger than about 1e8), the factorization is automatically recomputed in double precision.
+///
+/// Returns the number of refinement iterations or a negative number if the double precision
+/// factorization was used instead (see [russell_openblas::dsgesv]).
+///
+/// # Note
+///
+/// 1. The matrix `a` will be modified if the double precision factorization is used
+/// 2. The right-hand-side `b` will contain the solution `x`
+///
+/// ```
+/// use russell_lab::{solve_lin_sys_mixed, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // set matrix and right-hand side
+///     let mut a = Matrix::from(&[
+///         [1.0,  3.0, -2.0],
+///         [3.0,  5.0,  6.0],
+///         [2.0,  4.0,  3.0],
+///     ]);
+///     let mut b = Vector::from(&[5.0, 7.0, 8.0]);
+///
+///     // solve linear system b := a⁻¹⋅b
+///     let iter = solve_lin_sys_mixed(&mut b, &mut a)?;
+///     assert!(iter >= 0);
+///
+///     // check
+///     let x_correct = "┌         ┐\n\
+///                      │ -15.000 │\n\
+///                      │   8.000 │\n\
+///                      │   2.000 │\n\
+///                      └         ┘";
+///     assert_eq!(format!("{:.3}", b), x_correct);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_lin_sys_mixed(b: &mut Vector, a: &mut Matrix) -> Result<i32, StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if b.dim() != m {
+        return Err("vector has wrong dimension");
+    }
+    if m == 0 {
+        return Ok(0);
+    }
+    let mut ipiv = vec![0; m];
+    let mut x = vec![0.0; m];
+    let m_i32 = to_i32(m);
+    let iter = dsgesv(m_i32, 1, a.as_mut_data(), &mut ipiv, b.as_mut_data(), &mut x)?;
+    b.as_mut_data().copy_from_slice(&x);
+    Ok(iter)
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{solve_lin_sys, Matrix, Vector};
-    use russell_chk::vec_approx_eq;
+    use super::{solve_lin_sys, solve_lin_sys_equilibrated, solve_lin_sys_mixed, solve_lin_sys_spd, Matrix, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
 
     #[test]
     fn solve_lin_sys_fails_on_non_square() {
@@ -154,4 +340,150 @@ mod tests {
         ];
         vec_approx_eq(b.as_data(), x_correct, 1e-14);
     }
+
+    #[test]
+    fn solve_lin_sys_equilibrated_fails_on_wrong_input() {
+        let mut a = Matrix::new(2, 3);
+        let mut b = Vector::new(3);
+        assert_eq!(solve_lin_sys_equilibrated(&mut b, &mut a), Err("matrix must be square"));
+        let mut a = Matrix::new(2, 2);
+        assert_eq!(
+            solve_lin_sys_equilibrated(&mut b, &mut a),
+            Err("vector has wrong dimension")
+        );
+        let mut b = Vector::new(2);
+        assert_eq!(
+            solve_lin_sys_equilibrated(&mut b, &mut a),
+            Err("cannot equilibrate matrix with a zero row")
+        );
+    }
+
+    #[test]
+    fn solve_lin_sys_equilibrated_works() {
+        // same as solve_lin_sys_works with the rows and columns badly scaled
+        let (sr, sc) = ([1e6, 1.0, 1e-6, 1e3, 1e-3], [1e-4, 1.0, 1e4, 1.0, 1e2]);
+        #[rustfmt::skip]
+        let aa = [
+            [2.0, 1.0, 1.0, 3.0, 2.0],
+            [1.0, 2.0, 2.0, 1.0, 1.0],
+            [1.0, 2.0, 9.0, 1.0, 5.0],
+            [3.0, 1.0, 1.0, 7.0, 1.0],
+            [2.0, 1.0, 5.0, 1.0, 8.0],
+        ];
+        let bb = [-2.0, 4.0, 3.0, -5.0, 1.0];
+        let mut a = Matrix::new(5, 5);
+        let mut b = Vector::new(5);
+        for i in 0..5 {
+            for j in 0..5 {
+                a.set(i, j, sr[i] * aa[i][j] * sc[j]);
+            }
+            b[i] = sr[i] * bb[i];
+        }
+        solve_lin_sys_equilibrated(&mut b, &mut a).unwrap();
+        #[rustfmt::skip]
+        let x_correct = &[
+            -629.0 / 98.0,
+             237.0 / 49.0,
+             -53.0 / 49.0,
+              62.0 / 49.0,
+              23.0 / 14.0,
+        ];
+        for i in 0..5 {
+            approx_eq(b[i] * sc[i], x_correct[i], 1e-13);
+        }
+    }
+
+    #[test]
+    fn solve_lin_sys_mixed_captures_errors() {
+        let mut a = Matrix::new(2, 3);
+        let mut b = Vector::new(2);
+        assert_eq!(solve_lin_sys_mixed(&mut b, &mut a), Err("matrix must be square"));
+        let mut a = Matrix::new(2, 2);
+        let mut b = Vector::new(3);
+        assert_eq!(solve_lin_sys_mixed(&mut b, &mut a), Err("vector has wrong dimension"));
+        let mut b = Vector::new(2);
+        assert_eq!(solve_lin_sys_mixed(&mut b, &mut a), Err("LAPACK dsgesv failed"));
+        let mut a = Matrix::new(0, 0);
+        let mut b = Vector::new(0);
+        assert_eq!(solve_lin_sys_mixed(&mut b, &mut a), Ok(0));
+    }
+
+    #[test]
+    fn solve_lin_sys_spd_captures_errors() {
+        let mut a = Matrix::new(2, 3);
+        let mut b = Vector::new(3);
+        assert_eq!(solve_lin_sys_spd(&mut b, &mut a), Err("matrix must be square"));
+        let mut a = Matrix::new(2, 2);
+        assert_eq!(solve_lin_sys_spd(&mut b, &mut a), Err("vector has wrong dimension"));
+        let mut a = Matrix::from(&[[1.0, 2.0], [2.0, 1.0]]);
+        let mut b = Vector::new(2);
+        assert_eq!(solve_lin_sys_spd(&mut b, &mut a), Err("LAPACK dposv failed"));
+        let mut a = Matrix::new(0, 0);
+        let mut b = Vector::new(0);
+        assert_eq!(solve_lin_sys_spd(&mut b, &mut a), Ok(()));
+    }
+
+    #[test]
+    fn solve_lin_sys_spd_works() {
+        // the upper triangle is not accessed
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [ 2.0, 99.0, 99.0, 99.0],
+            [-1.0,  2.0, 99.0, 99.0],
+            [ 0.0, -1.0,  2.0, 99.0],
+            [ 0.0,  0.0, -1.0,  2.0],
+        ]);
+        let mut b = Vector::from(&[0.0, 0.0, 0.0, 5.0]);
+        solve_lin_sys_spd(&mut b, &mut a).unwrap();
+        vec_approx_eq(b.as_data(), &[1.0, 2.0, 3.0, 4.0], 1e-14);
+
+        // same as the general solver
+        #[rustfmt::skip]
+        let data = [
+            [4.0, 1.0, 0.5, 0.0, 0.2],
+            [1.0, 5.0, 1.0, 0.3, 0.0],
+            [0.5, 1.0, 6.0, 1.0, 0.4],
+            [0.0, 0.3, 1.0, 7.0, 1.0],
+            [0.2, 0.0, 0.4, 1.0, 8.0],
+        ];
+        let mut a = Matrix::from(&data);
+        let mut a_general = Matrix::from(&data);
+        let mut b = Vector::from(&[1.0, -2.0, 3.0, -4.0, 5.0]);
+        let mut b_general = b.clone();
+        solve_lin_sys_spd(&mut b, &mut a).unwrap();
+        solve_lin_sys(&mut b_general, &mut a_general).unwrap();
+        vec_approx_eq(b.as_data(), b_general.as_data(), 1e-15);
+    }
+
+    #[test]
+    fn solve_lin_sys_mixed_works() {
+        #[rustfmt::skip]
+        let mut a = Matrix::from(&[
+            [2.0, 1.0, 1.0, 3.0, 2.0],
+            [1.0, 2.0, 2.0, 1.0, 1.0],
+            [1.0, 2.0, 9.0, 1.0, 5.0],
+            [3.0, 1.0, 1.0, 7.0, 1.0],
+            [2.0, 1.0, 5.0, 1.0, 8.0],
+        ]);
+        let mut b = Vector::from(&[-2.0, 4.0, 3.0, -5.0, 1.0]);
+        let iter = solve_lin_sys_mixed(&mut b, &mut a).unwrap();
+        assert!(iter >= 0);
+        #[rustfmt::skip]
+        let x_correct = &[
+            -629.0 / 98.0,
+             237.0 / 49.0,
+             -53.0 / 49.0,
+              62.0 / 49.0,
+              23.0 / 14.0,
+        ];
+        vec_approx_eq(b.as_data(), x_correct, 1e-13);
+
+        // the values are not representable in single precision: the refinement recovers the accuracy
+        let d = 1.0 + 1e-10;
+        let mut a = Matrix::from(&[[d, 1.0], [1.0, 2.0 * d]]);
+        let x_correct = &[1.0 / 3.0, 1.0 / 7.0];
+        let mut b = Vector::from(&[d / 3.0 + 1.0 / 7.0, 1.0 / 3.0 + 2.0 * d / 7.0]);
+        solve_lin_sys_mixed(&mut b, &mut a).unwrap();
+        vec_approx_eq(b.as_data(), x_correct, 1e-15);
+    }
 }
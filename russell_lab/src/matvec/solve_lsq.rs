@@ -0,0 +1,444 @@
+use crate::matrix::{mat_cholesky, Matrix};
+use crate::vector::Vector;
+use crate::StrError;
+use russell_openblas::{dgels, dgelsd, to_i32};
+
+/// Solves the (ordinary) linear least squares problem
+///
+/// Finds `x` that minimizes the residual:
+///
+/// ```text
+/// minimize ‖ b - a ⋅ x ‖₂
+///
+///   a   ⋅  x  ≈  b
+/// (m,n)   (n)   (m)     with m ≥ n
+/// ```
+///
+/// The solution is obtained via QR decomposition using Lapack dgels routine;
+/// thus, the matrix `a` must have full rank.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{solve_lsq, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // fit y = c0 + c1⋅t through (0,1), (1,3), (2,5)
+///     let a = Matrix::from(&[
+///         [1.0, 0.0],
+///         [1.0, 1.0],
+///         [1.0, 2.0],
+///     ]);
+///     let b = Vector::from(&[1.0, 3.0, 5.0]);
+///     let mut x = Vector::new(2);
+///     solve_lsq(&mut x, &a, &b)?;
+///     assert_eq!(format!("{:.3}", x), "┌       ┐\n\
+///                                      │ 1.000 │\n\
+///                                      │ 2.000 │\n\
+///                                      └       ┘");
+///     Ok(())
+/// }
+/// ```
+pub fn solve_lsq(x: &mut Vector, a: &Matrix, b: &Vector) -> Result<(), StrError> {
+    check_lsq_dims(x, a, b)?;
+    let mut aa = a.clone();
+    let mut bb = b.clone();
+    solve_lsq_in_place(x, &mut aa, &mut bb)
+}
+
+/// Solves the weighted linear least squares problem
+///
+/// Finds `x` that minimizes the weighted residual:
+///
+/// ```text
+/// minimize Σᵢ wᵢ ⋅ (bᵢ - (a ⋅ x)ᵢ)²
+/// ```
+///
+/// The problem is reduced to an ordinary least squares problem (see [solve_lsq]) by
+/// multiplying each row of `a` and `b` by `√wᵢ`. With `wᵢ = 1/σᵢ²`, where `σᵢ` is the
+/// standard deviation of the i-th measurement, this yields the maximum likelihood estimate
+/// for independent (heteroscedastic) errors.
+///
+/// # Input
+///
+/// * `a` -- (m,n) design matrix with m ≥ n
+/// * `b` -- (m) observations
+/// * `weights` -- (m) non-negative weights (a zero weight discards the observation)
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{solve_wlsq, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // the last (outlier) observation is strongly down-weighted
+///     let a = Matrix::from(&[[1.0], [1.0], [1.0]]);
+///     let b = Vector::from(&[2.0, 2.0, 100.0]);
+///     let w = Vector::from(&[1.0, 1.0, 1e-12]);
+///     let mut x = Vector::new(1);
+///     solve_wlsq(&mut x, &a, &b, &w)?;
+///     assert_eq!(format!("{:.6}", x[0]), "2.000000");
+///     Ok(())
+/// }
+/// ```
+pub fn solve_wlsq(x: &mut Vector, a: &Matrix, b: &Vector, weights: &Vector) -> Result<(), StrError> {
+    check_lsq_dims(x, a, b)?;
+    let (m, n) = a.dims();
+    if weights.dim() != m {
+        return Err("weights vector has wrong dimension");
+    }
+    let mut aa = Matrix::new(m, n);
+    let mut bb = Vector::new(m);
+    for i in 0..m {
+        if weights[i] < 0.0 || weights[i].is_nan() {
+            return Err("weights must be non-negative");
+        }
+        let s = f64::sqrt(weights[i]);
+        for j in 0..n {
+            aa.set(i, j, s * a.get(i, j));
+        }
+        bb[i] = s * b[i];
+    }
+    solve_lsq_in_place(x, &mut aa, &mut bb)
+}
+
+/// Solves the generalized linear least squares problem
+///
+/// Finds `x` that minimizes the Mahalanobis norm of the residual:
+///
+/// ```text
+/// minimize (b - a ⋅ x)ᵀ ⋅ c⁻¹ ⋅ (b - a ⋅ x)
+/// ```
+///
+/// where `c` is the (symmetric positive-definite) covariance matrix of the observations.
+///
+/// The problem is whitened with the Cholesky factorization `c = l ⋅ lᵀ`; i.e., the ordinary
+/// least squares problem `l⁻¹ ⋅ a ⋅ x ≈ l⁻¹ ⋅ b` is solved (see [solve_lsq]). The inverse
+/// of `l` is never computed; forward substitutions are used instead.
+///
+/// # Input
+///
+/// * `a` -- (m,n) design matrix with m ≥ n
+/// * `b` -- (m) observations
+/// * `cov` -- (m,m) covariance matrix (only the lower triangle is used)
+pub fn solve_glsq(x: &mut Vector, a: &Matrix, b: &Vector, cov: &Matrix) -> Result<(), StrError> {
+    check_lsq_dims(x, a, b)?;
+    let (m, n) = a.dims();
+    if cov.dims() != (m, m) {
+        return Err("covariance matrix has wrong dimensions");
+    }
+    let mut l = Matrix::new(m, m);
+    mat_cholesky(&mut l, cov)?;
+    let mut aa = a.clone();
+    let mut bb = b.clone();
+    for i in 0..m {
+        let lii = l.get(i, i);
+        if lii == 0.0 {
+            return Err("covariance matrix must be positive-definite");
+        }
+        for k in 0..i {
+            let lik = l.get(i, k);
+            for j in 0..n {
+                aa.set(i, j, aa.get(i, j) - lik * aa.get(k, j));
+            }
+            bb[i] -= lik * bb[k];
+        }
+        for j in 0..n {
+            aa.set(i, j, aa.get(i, j) / lii);
+        }
+        bb[i] /= lii;
+    }
+    solve_lsq_in_place(x, &mut aa, &mut bb)
+}
+
+/// Solves the linear least squares problem with a rectangular matrix of any shape
+///
+/// Finds the minimum-norm `x` that minimizes the residual:
+///
+/// ```text
+/// minimize ‖ b - a ⋅ x ‖₂
+///
+///   a   ⋅  x  ≈  b
+/// (m,n)   (n)   (m)     with any m and n
+/// ```
+///
+/// Thus, overdetermined (m > n) and underdetermined (m < n) systems are handled.
+///
+/// * If `rcond` is None, the matrix `a` must have full rank and the solution is obtained via
+///   QR (m ≥ n) or LQ (m < n) decomposition using Lapack dgels routine.
+/// * Otherwise, the rank-deficient case is handled by the singular value decomposition
+///   (divide and conquer method) using Lapack dgelsd routine. The singular values
+///   `sᵢ ≤ rcond ⋅ s₀` are treated as zero; thus, `rcond` is the tolerance that determines
+///   the effective rank of `a`.
+///
+/// See also [solve_lsq] (overdetermined only) and [crate::solve_tsvd] (which also returns the rank).
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix (any shape)
+/// * `b` -- (m) right-hand side
+/// * `rcond` -- relative threshold for the singular values (`rcond ≥ 0`; e.g., `1e-12`) or None if `a` has full rank
+///
+/// # Output
+///
+/// * `x` -- (n) solution
+/// * Returns the effective rank of `a` (`min(m,n)` if `rcond` is None)
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{solve_lin_sys_lsq, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // the second column equals twice the first column
+///     let a = Matrix::from(&[
+///         [1.0, 2.0],
+///         [1.0, 2.0],
+///         [1.0, 2.0],
+///     ]);
+///     let b = Vector::from(&[5.0, 5.0, 5.0]);
+///
+///     // minimum-norm solution of x0 + 2⋅x1 = 5
+///     let mut x = Vector::new(2);
+///     let rank = solve_lin_sys_lsq(&mut x, &a, &b, Some(1e-12))?;
+///     assert_eq!(rank, 1);
+///     vec_approx_eq(x.as_data(), &[1.0, 2.0], 1e-14);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_lin_sys_lsq(x: &mut Vector, a: &Matrix, b: &Vector, rcond: Option<f64>) -> Result<usize, StrError> {
+    let (m, n) = a.dims();
+    if b.dim() != m {
+        return Err("b vector has wrong dimension");
+    }
+    if x.dim() != n {
+        return Err("x vector has wrong dimension");
+    }
+    if let Some(r) = rcond {
+        if r < 0.0 || r.is_nan() {
+            return Err("rcond must be non-negative");
+        }
+    }
+    x.fill(0.0);
+    let min_mn = usize::min(m, n);
+    if min_mn == 0 {
+        return Ok(0);
+    }
+
+    // the right-hand side must have room for the solution (ldb = max(m,n))
+    let mut aa = a.clone();
+    let mut bb = vec![0.0; usize::max(m, n)];
+    bb[..m].copy_from_slice(b.as_data());
+    let (m_i32, n_i32) = (to_i32(m), to_i32(n));
+    let rank = match rcond {
+        None => {
+            dgels(m_i32, n_i32, 1, aa.as_mut_data(), &mut bb)?;
+            min_mn
+        }
+        Some(r) => {
+            let mut s = vec![0.0; min_mn];
+            let rank = dgelsd(m_i32, n_i32, 1, aa.as_mut_data(), &mut bb, &mut s, r)?;
+            rank as usize
+        }
+    };
+    x.as_mut_data().copy_from_slice(&bb[..n]);
+    Ok(rank)
+}
+
+/// Checks the dimensions of the least squares problem
+fn check_lsq_dims(x: &Vector, a: &Matrix, b: &Vector) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if n == 0 || m < n {
+        return Err("the number of rows must be ≥ the number of columns ≥ 1");
+    }
+    if b.dim() != m {
+        return Err("b vector has wrong dimension");
+    }
+    if x.dim() != n {
+        return Err("x vector has wrong dimension");
+    }
+    Ok(())
+}
+
+/// Solves the least squares problem with dgels (a and b are modified)
+fn solve_lsq_in_place(x: &mut Vector, a: &mut Matrix, b: &mut Vector) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    dgels(to_i32(m), to_i32(n), 1, a.as_mut_data(), b.as_mut_data())?;
+    for j in 0..n {
+        x[j] = b[j];
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{solve_glsq, solve_lin_sys_lsq, solve_lsq, solve_wlsq};
+    use crate::{Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn solve_lsq_fails_on_wrong_dims() {
+        let a = Matrix::new(3, 2);
+        let b = Vector::new(3);
+        let mut x = Vector::new(2);
+        assert_eq!(
+            solve_lsq(&mut x, &Matrix::new(1, 2), &Vector::new(1)).err(),
+            Some("the number of rows must be ≥ the number of columns ≥ 1")
+        );
+        assert_eq!(
+            solve_lsq(&mut x, &a, &Vector::new(2)).err(),
+            Some("b vector has wrong dimension")
+        );
+        assert_eq!(
+            solve_lsq(&mut Vector::new(3), &a, &b).err(),
+            Some("x vector has wrong dimension")
+        );
+    }
+
+    #[test]
+    fn solve_wlsq_fails_on_wrong_weights() {
+        let a = Matrix::new(3, 2);
+        let b = Vector::new(3);
+        let mut x = Vector::new(2);
+        assert_eq!(
+            solve_wlsq(&mut x, &a, &b, &Vector::new(2)).err(),
+            Some("weights vector has wrong dimension")
+        );
+        assert_eq!(
+            solve_wlsq(&mut x, &a, &b, &Vector::from(&[1.0, -1.0, 1.0])).err(),
+            Some("weights must be non-negative")
+        );
+    }
+
+    #[test]
+    fn solve_glsq_fails_on_wrong_cov() {
+        let a = Matrix::new(3, 2);
+        let b = Vector::new(3);
+        let mut x = Vector::new(2);
+        assert_eq!(
+            solve_glsq(&mut x, &a, &b, &Matrix::new(2, 2)).err(),
+            Some("covariance matrix has wrong dimensions")
+        );
+    }
+
+    #[test]
+    fn solve_lsq_works() {
+        // fit y = c0 + c1⋅t (noisy data; normal equations solution)
+        let a = Matrix::from(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let b = Vector::from(&[1.0, 2.0, 2.0, 4.0]);
+        let mut x = Vector::new(2);
+        solve_lsq(&mut x, &a, &b).unwrap();
+        vec_approx_eq(x.as_data(), &[0.9, 0.9], 1e-14);
+    }
+
+    #[test]
+    fn solve_wlsq_works() {
+        // weighted mean: x = Σ wᵢ bᵢ / Σ wᵢ
+        let a = Matrix::from(&[[1.0], [1.0], [1.0]]);
+        let b = Vector::from(&[1.0, 2.0, 4.0]);
+        let w = Vector::from(&[1.0, 2.0, 0.0]);
+        let mut x = Vector::new(1);
+        solve_wlsq(&mut x, &a, &b, &w).unwrap();
+        vec_approx_eq(x.as_data(), &[5.0 / 3.0], 1e-15);
+
+        // unit weights equal the ordinary least squares
+        let a = Matrix::from(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let b = Vector::from(&[1.0, 2.0, 2.0, 4.0]);
+        let mut x = Vector::new(2);
+        solve_wlsq(&mut x, &a, &b, &Vector::from(&[1.0, 1.0, 1.0, 1.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[0.9, 0.9], 1e-14);
+    }
+
+    #[test]
+    fn solve_glsq_works() {
+        // a diagonal covariance equals weighted least squares with wᵢ = 1/cᵢᵢ
+        let a = Matrix::from(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let b = Vector::from(&[1.0, 2.0, 2.0, 4.0]);
+        let cov = Matrix::from(&[
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 4.0, 0.0, 0.0],
+            [0.0, 0.0, 0.25, 0.0],
+            [0.0, 0.0, 0.0, 2.0],
+        ]);
+        let w = Vector::from(&[1.0, 0.25, 4.0, 0.5]);
+        let mut x_gls = Vector::new(2);
+        let mut x_wls = Vector::new(2);
+        solve_glsq(&mut x_gls, &a, &b, &cov).unwrap();
+        solve_wlsq(&mut x_wls, &a, &b, &w).unwrap();
+        vec_approx_eq(x_gls.as_data(), x_wls.as_data(), 1e-14);
+
+        // correlated errors: compare with the normal equations (aᵀ c⁻¹ a) x = aᵀ c⁻¹ b
+        let a = Matrix::from(&[[1.0], [1.0]]);
+        let b = Vector::from(&[1.0, 3.0]);
+        let cov = Matrix::from(&[[2.0, 1.0], [1.0, 2.0]]);
+        let mut x = Vector::new(1);
+        solve_glsq(&mut x, &a, &b, &cov).unwrap();
+        // c⁻¹ = [[2,-1],[-1,2]]/3 ⇒ aᵀc⁻¹a = 2/3 and aᵀc⁻¹b = 4/3
+        vec_approx_eq(x.as_data(), &[2.0], 1e-15);
+    }
+
+    #[test]
+    fn solve_lin_sys_lsq_captures_errors() {
+        let a = Matrix::new(2, 3);
+        let mut x = Vector::new(3);
+        assert_eq!(
+            solve_lin_sys_lsq(&mut x, &a, &Vector::new(3), None).err(),
+            Some("b vector has wrong dimension")
+        );
+        assert_eq!(
+            solve_lin_sys_lsq(&mut Vector::new(2), &a, &Vector::new(2), None).err(),
+            Some("x vector has wrong dimension")
+        );
+        assert_eq!(
+            solve_lin_sys_lsq(&mut x, &a, &Vector::new(2), Some(-1.0)).err(),
+            Some("rcond must be non-negative")
+        );
+        let mut x = Vector::from(&[1.0]);
+        assert_eq!(
+            solve_lin_sys_lsq(&mut x, &Matrix::new(0, 1), &Vector::new(0), None),
+            Ok(0)
+        );
+        assert_eq!(x.as_data(), &[0.0]);
+    }
+
+    #[test]
+    fn solve_lin_sys_lsq_full_rank_works() {
+        // overdetermined: equals solve_lsq
+        let a = Matrix::from(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let b = Vector::from(&[1.0, 2.0, 2.0, 4.0]);
+        let mut x = Vector::new(2);
+        assert_eq!(solve_lin_sys_lsq(&mut x, &a, &b, None).unwrap(), 2);
+        vec_approx_eq(x.as_data(), &[0.9, 0.9], 1e-14);
+        assert_eq!(solve_lin_sys_lsq(&mut x, &a, &b, Some(0.0)).unwrap(), 2);
+        vec_approx_eq(x.as_data(), &[0.9, 0.9], 1e-14);
+
+        // underdetermined: minimum-norm solution x = aᵀ ⋅ (a ⋅ aᵀ)⁻¹ ⋅ b
+        let a = Matrix::from(&[[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]);
+        let b = Vector::from(&[3.0, 0.0]);
+        let mut x = Vector::new(3);
+        assert_eq!(solve_lin_sys_lsq(&mut x, &a, &b, None).unwrap(), 2);
+        vec_approx_eq(x.as_data(), &[2.0, -1.0, 1.0], 1e-14);
+        assert_eq!(solve_lin_sys_lsq(&mut x, &a, &b, Some(1e-12)).unwrap(), 2);
+        vec_approx_eq(x.as_data(), &[2.0, -1.0, 1.0], 1e-14);
+    }
+
+    #[test]
+    fn solve_lin_sys_lsq_rank_deficient_works() {
+        // rank 1 and inconsistent: the least squares solution of x0 + x1 = mean(b) with minimum norm
+        let a = Matrix::from(&[[1.0, 1.0], [1.0, 1.0], [1.0, 1.0], [1.0, 1.0]]);
+        let b = Vector::from(&[1.0, 2.0, 3.0, 6.0]);
+        let mut x = Vector::new(2);
+        assert_eq!(solve_lin_sys_lsq(&mut x, &a, &b, Some(1e-12)).unwrap(), 1);
+        vec_approx_eq(x.as_data(), &[1.5, 1.5], 1e-14);
+
+        // underdetermined and rank-deficient (the second row is twice the first row)
+        let a = Matrix::from(&[[1.0, 0.0, 1.0], [2.0, 0.0, 2.0]]);
+        let b = Vector::from(&[2.0, 4.0]);
+        let mut x = Vector::new(3);
+        assert_eq!(solve_lin_sys_lsq(&mut x, &a, &b, Some(1e-12)).unwrap(), 1);
+        vec_approx_eq(x.as_data(), &[1.0, 0.0, 1.0], 1e-14);
+    }
+}
@@ -0,0 +1,328 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+use russell_openblas::{dtrsm, dtrsv, to_i32};
+
+/// Solves a triangular linear system (real numbers)
+///
+/// For an upper (`up = true`) or lower triangular matrix `a`, find `x` such that:
+///
+/// ```text
+///   a   ⋅  x  =  b       or       aᵀ  ⋅  x  =  b   (if trans = true)
+/// (m,m)   (m)   (m)             (m,m)   (m)   (m)
+/// ```
+///
+/// However, the right-hand-side will hold the solution:
+///
+/// ```text
+/// b := a⁻¹⋅b == x       or       b := a⁻ᵀ⋅b == x
+/// ```
+///
+/// The solution is obtained by forward or backward substitution using the BLAS dtrsv routine.
+/// Only the upper or lower triangle of `a` is accessed; thus, the factors computed by
+/// [crate::mat_cholesky] or stored by [crate::LuFactors] may be used directly. If `unit_diag = true`,
+/// the diagonal of `a` is assumed to be composed of ones and is not accessed (e.g., the `l` factor of LU).
+///
+/// # Note
+///
+/// 1. The matrix `a` is not modified
+/// 2. The right-hand-side `b` will contain the solution `x`
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{mat_cholesky, solve_triangular, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // symmetric positive definite matrix
+///     let a = Matrix::from(&[
+///         [  4.0,  12.0, -16.0],
+///         [ 12.0,  37.0, -43.0],
+///         [-16.0, -43.0,  98.0],
+///     ]);
+///
+///     // a = l⋅lᵀ
+///     let mut l = Matrix::new(3, 3);
+///     mat_cholesky(&mut l, &a)?;
+///
+///     // solve l⋅y = b and then lᵀ⋅x = y
+///     let mut b = Vector::from(&[-44.0, -116.0, 283.0]);
+///     solve_triangular(&mut b, &l, false, false, false)?;
+///     solve_triangular(&mut b, &l, false, true, false)?;
+///
+///     // check
+///     let x_correct = "┌        ┐\n\
+///                      │ -2.000 │\n\
+///                      │  1.000 │\n\
+///                      │  3.000 │\n\
+///                      └        ┘";
+///     assert_eq!(format!("{:.3}", b), x_correct);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_triangular(b: &mut Vector, a: &Matrix, up: bool, trans: bool, unit_diag: bool) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    if b.dim() != m {
+        return Err("vector has wrong dimension");
+    }
+    check_diagonal(a, unit_diag)?;
+    if m == 0 {
+        return Ok(());
+    }
+    dtrsv(up, trans, unit_diag, to_i32(m), a.as_data(), b.as_mut_data(), 1);
+    Ok(())
+}
+
+/// Solves a triangular linear system with multiple right-hand sides (real numbers)
+///
+/// For an upper (`up = true`) or lower triangular matrix `a`, find `x` such that:
+///
+/// ```text
+///   a   ⋅   x   =   b       or       aᵀ  ⋅   x   =   b   (if trans = true)
+/// (m,m)   (m,k)   (m,k)            (m,m)   (m,k)   (m,k)
+/// ```
+///
+/// However, the right-hand-side will hold the solution (each column of `b` is a right-hand side).
+///
+/// The solution is obtained using the BLAS dtrsm routine. See [solve_triangular] for details.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{solve_triangular_mat, Matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // upper triangular matrix (the lower triangle is not accessed)
+///     let u = Matrix::from(&[
+///         [2.0, 1.0, -1.0],
+///         [9.0, 4.0,  2.0],
+///         [9.0, 9.0,  5.0],
+///     ]);
+///
+///     // two right-hand sides
+///     let mut b = Matrix::from(&[
+///         [ 1.0, -3.0],
+///         [14.0,  2.0],
+///         [15.0,  5.0],
+///     ]);
+///     solve_triangular_mat(&mut b, &u, true, false, false)?;
+///
+///     // check
+///     let x_correct = "┌       ┐\n\
+///                      │  1 -1 │\n\
+///                      │  2  0 │\n\
+///                      │  3  1 │\n\
+///                      └       ┘";
+///     assert_eq!(format!("{}", b), x_correct);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_triangular_mat(
+    b: &mut Matrix,
+    a: &Matrix,
+    up: bool,
+    trans: bool,
+    unit_diag: bool,
+) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    if m != n {
+        return Err("matrix must be square");
+    }
+    let (mb, k) = b.dims();
+    if mb != m {
+        return Err("matrices are incompatible");
+    }
+    check_diagonal(a, unit_diag)?;
+    if m == 0 || k == 0 {
+        return Ok(());
+    }
+    dtrsm(
+        true,
+        up,
+        trans,
+        unit_diag,
+        to_i32(m),
+        to_i32(k),
+        1.0,
+        a.as_data(),
+        b.as_mut_data(),
+    );
+    Ok(())
+}
+
+/// Returns an error if a diagonal entry of the (square) matrix is zero
+fn check_diagonal(a: &Matrix, unit_diag: bool) -> Result<(), StrError> {
+    if !unit_diag {
+        for i in 0..a.nrow() {
+            if a.get(i, i) == 0.0 {
+                return Err("triangular matrix is singular (zero diagonal entry)");
+            }
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{solve_triangular, solve_triangular_mat};
+    use crate::{mat_approx_eq, mat_cholesky, Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn solve_triangular_captures_errors() {
+        let a = Matrix::new(2, 3);
+        let mut b = Vector::new(2);
+        assert_eq!(
+            solve_triangular(&mut b, &a, true, false, false).err(),
+            Some("matrix must be square")
+        );
+        let a = Matrix::from(&[[1.0, 2.0], [0.0, 0.0]]);
+        assert_eq!(
+            solve_triangular(&mut Vector::new(3), &a, true, false, false).err(),
+            Some("vector has wrong dimension")
+        );
+        assert_eq!(
+            solve_triangular(&mut b, &a, true, false, false).err(),
+            Some("triangular matrix is singular (zero diagonal entry)")
+        );
+        // unit diagonal: the diagonal is not accessed
+        assert_eq!(solve_triangular(&mut b, &a, true, false, true).err(), None);
+    }
+
+    #[test]
+    fn solve_triangular_mat_captures_errors() {
+        let mut b = Matrix::new(2, 2);
+        assert_eq!(
+            solve_triangular_mat(&mut b, &Matrix::new(2, 3), true, false, false).err(),
+            Some("matrix must be square")
+        );
+        let a = Matrix::from(&[[1.0, 2.0], [0.0, 0.0]]);
+        assert_eq!(
+            solve_triangular_mat(&mut Matrix::new(3, 2), &a, true, false, false).err(),
+            Some("matrices are incompatible")
+        );
+        assert_eq!(
+            solve_triangular_mat(&mut b, &a, false, false, false).err(),
+            Some("triangular matrix is singular (zero diagonal entry)")
+        );
+    }
+
+    #[test]
+    fn solve_triangular_works() {
+        // empty system
+        solve_triangular(&mut Vector::new(0), &Matrix::new(0, 0), true, false, false).unwrap();
+
+        // the other triangle is not accessed
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 2.0, 1.0, -1.0],
+            [ 1.0, 4.0,  2.0],
+            [-1.0, 2.0,  5.0],
+        ]);
+        let x_correct = &[1.0, 2.0, 3.0];
+
+        // upper
+        let mut b = Vector::from(&[1.0, 14.0, 15.0]);
+        solve_triangular(&mut b, &a, true, false, false).unwrap();
+        vec_approx_eq(b.as_data(), x_correct, 1e-15);
+
+        // upper transposed
+        let mut b = Vector::from(&[2.0, 9.0, 18.0]);
+        solve_triangular(&mut b, &a, true, true, false).unwrap();
+        vec_approx_eq(b.as_data(), x_correct, 1e-15);
+
+        // lower
+        let mut b = Vector::from(&[2.0, 9.0, 18.0]);
+        solve_triangular(&mut b, &a, false, false, false).unwrap();
+        vec_approx_eq(b.as_data(), x_correct, 1e-15);
+
+        // lower transposed
+        let mut b = Vector::from(&[1.0, 14.0, 15.0]);
+        solve_triangular(&mut b, &a, false, true, false).unwrap();
+        vec_approx_eq(b.as_data(), x_correct, 1e-15);
+
+        // unit lower
+        let mut b = Vector::from(&[1.0, 3.0, 6.0]);
+        solve_triangular(&mut b, &a, false, false, true).unwrap();
+        vec_approx_eq(b.as_data(), x_correct, 1e-15);
+
+        // Cholesky factor: a = l⋅lᵀ
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [  4.0,  12.0, -16.0],
+            [ 12.0,  37.0, -43.0],
+            [-16.0, -43.0,  98.0],
+        ]);
+        let mut l = Matrix::new(3, 3);
+        mat_cholesky(&mut l, &a).unwrap();
+        let mut b = Vector::from(&[-44.0, -116.0, 283.0]);
+        solve_triangular(&mut b, &l, false, false, false).unwrap();
+        solve_triangular(&mut b, &l, false, true, false).unwrap();
+        vec_approx_eq(b.as_data(), &[-2.0, 1.0, 3.0], 1e-13);
+    }
+
+    #[test]
+    fn solve_triangular_mat_works() {
+        // empty right-hand side
+        let a = Matrix::from(&[[2.0]]);
+        solve_triangular_mat(&mut Matrix::new(1, 0), &a, true, false, false).unwrap();
+
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [ 2.0, 1.0, -1.0],
+            [ 1.0, 4.0,  2.0],
+            [-1.0, 2.0,  5.0],
+        ]);
+        #[rustfmt::skip]
+        let x_correct = &[
+            [1.0, -1.0],
+            [2.0,  0.0],
+            [3.0,  1.0],
+        ];
+
+        // upper
+        #[rustfmt::skip]
+        let mut b = Matrix::from(&[
+            [ 1.0, -3.0],
+            [14.0,  2.0],
+            [15.0,  5.0],
+        ]);
+        solve_triangular_mat(&mut b, &a, true, false, false).unwrap();
+        mat_approx_eq(&b, x_correct, 1e-15);
+
+        // lower
+        #[rustfmt::skip]
+        let mut b = Matrix::from(&[
+            [ 2.0, -2.0],
+            [ 9.0, -1.0],
+            [18.0,  6.0],
+        ]);
+        solve_triangular_mat(&mut b, &a, false, false, false).unwrap();
+        mat_approx_eq(&b, x_correct, 1e-15);
+
+        // upper transposed
+        #[rustfmt::skip]
+        let mut b = Matrix::from(&[
+            [ 2.0, -2.0],
+            [ 9.0, -1.0],
+            [18.0,  6.0],
+        ]);
+        solve_triangular_mat(&mut b, &a, true, true, false).unwrap();
+        mat_approx_eq(&b, x_correct, 1e-15);
+
+        // unit upper
+        #[rustfmt::skip]
+        let mut b = Matrix::from(&[
+            [0.0, -2.0],
+            [8.0,  2.0],
+            [3.0,  1.0],
+        ]);
+        solve_triangular_mat(&mut b, &a, true, false, true).unwrap();
+        mat_approx_eq(&b, x_correct, 1e-15);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Solves a tridiagonal linear system using the Thomas algorithm
+///
+/// Finds `x` such that `a ⋅ x = b`, where `a` is the (n,n) tridiagonal matrix:
+///
+/// ```text
+///     ┌                                     ┐
+///     │ d₀   du₀                            │
+///     │ dl₀  d₁   du₁                       │
+/// a = │      dl₁  d₂   du₂                  │
+///     │           ⋱    ⋱     ⋱              │
+///     │               dlₙ₋₃  dₙ₋₂  duₙ₋₂    │
+///     │                      dlₙ₋₂  dₙ₋₁    │
+///     └                                     ┘
+/// ```
+///
+/// The Thomas algorithm is the Gaussian elimination without pivoting; thus, it requires
+/// O(n) operations only and does not allocate the (dense or sparse) matrix. Tridiagonal systems
+/// arise in cubic splines and in finite difference discretizations of 1D diffusion problems.
+///
+/// # Output
+///
+/// * `x` -- the solution vector with dimension n
+///
+/// # Input
+///
+/// * `dl` -- the (n-1) sub-diagonal entries
+/// * `d` -- the (n) diagonal entries
+/// * `du` -- the (n-1) super-diagonal entries
+/// * `b` -- the (n) right-hand side
+///
+/// # Note
+///
+/// The algorithm is stable if the matrix is (row or column) diagonally dominant or symmetric positive
+/// definite. Otherwise, a zero (or tiny) pivot may be found, even if the matrix is not singular; in this case,
+/// an error is returned and [crate::solve_lin_sys] (with partial pivoting) may be used instead.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{solve_tridiag, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // 1D diffusion: -u(i-1) + 2 u(i) - u(i+1) = h² f(i)
+///     let dl = Vector::from(&[-1.0, -1.0, -1.0]);
+///     let d = Vector::from(&[2.0, 2.0, 2.0, 2.0]);
+///     let du = Vector::from(&[-1.0, -1.0, -1.0]);
+///     let b = Vector::from(&[1.0, 0.0, 0.0, 1.0]);
+///     let mut x = Vector::new(4);
+///     solve_tridiag(&mut x, &dl, &d, &du, &b)?;
+///     let correct = "┌       ┐\n\
+///                    │ 1.000 │\n\
+///                    │ 1.000 │\n\
+///                    │ 1.000 │\n\
+///                    │ 1.000 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{:.3}", x), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_tridiag(x: &mut Vector, dl: &Vector, d: &Vector, du: &Vector, b: &Vector) -> Result<(), StrError> {
+    let n = d.dim();
+    if x.dim() != n || b.dim() != n {
+        return Err("vectors are incompatible");
+    }
+    if n == 0 {
+        return Ok(());
+    }
+    if dl.dim() != n - 1 || du.dim() != n - 1 {
+        return Err("dl and du must have dimension equal to n - 1");
+    }
+
+    // forward elimination (x holds the modified right-hand side and c the modified super-diagonal)
+    let mut c = vec![0.0; n - 1];
+    let mut pivot = d[0];
+    if pivot == 0.0 {
+        return Err("the Thomas algorithm found a zero pivot");
+    }
+    x[0] = b[0] / pivot;
+    for i in 1..n {
+        c[i - 1] = du[i - 1] / pivot;
+        pivot = d[i] - dl[i - 1] * c[i - 1];
+        if pivot == 0.0 {
+            return Err("the Thomas algorithm found a zero pivot");
+        }
+        x[i] = (b[i] - dl[i - 1] * x[i - 1]) / pivot;
+    }
+
+    // backward substitution
+    for i in (0..n - 1).rev() {
+        x[i] -= c[i] * x[i + 1];
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::solve_tridiag;
+    use crate::Vector;
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn solve_tridiag_captures_errors() {
+        let mut x = Vector::new(3);
+        let d = Vector::new(3);
+        let dl = Vector::new(2);
+        let du = Vector::new(2);
+        let b = Vector::new(3);
+        assert_eq!(
+            solve_tridiag(&mut Vector::new(2), &dl, &d, &du, &b).err(),
+            Some("vectors are incompatible")
+        );
+        assert_eq!(
+            solve_tridiag(&mut x, &dl, &d, &du, &Vector::new(2)).err(),
+            Some("vectors are incompatible")
+        );
+        assert_eq!(
+            solve_tridiag(&mut x, &Vector::new(3), &d, &du, &b).err(),
+            Some("dl and du must have dimension equal to n - 1")
+        );
+        assert_eq!(
+            solve_tridiag(&mut x, &dl, &d, &Vector::new(1), &b).err(),
+            Some("dl and du must have dimension equal to n - 1")
+        );
+        assert_eq!(
+            solve_tridiag(&mut x, &dl, &d, &du, &b).err(),
+            Some("the Thomas algorithm found a zero pivot")
+        );
+        // the second pivot is zero
+        let d = Vector::from(&[1.0, 1.0, 1.0]);
+        let ones = Vector::from(&[1.0, 1.0]);
+        assert_eq!(
+            solve_tridiag(&mut x, &ones, &d, &ones, &b).err(),
+            Some("the Thomas algorithm found a zero pivot")
+        );
+    }
+
+    #[test]
+    fn solve_tridiag_works() {
+        // empty system
+        let mut x = Vector::new(0);
+        let e = Vector::new(0);
+        solve_tridiag(&mut x, &e, &e, &e, &e).unwrap();
+
+        // 1 x 1 system
+        let mut x = Vector::new(1);
+        solve_tridiag(&mut x, &e, &Vector::from(&[4.0]), &e, &Vector::from(&[2.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[0.5], 1e-15);
+
+        // non-symmetric and diagonally dominant
+        // ┌             ┐
+        // │ 4  1  0  0  │
+        // │ 2  5  1  0  │
+        // │ 0 -1  6  2  │
+        // │ 0  0  3  7  │
+        // └             ┘
+        let dl = Vector::from(&[2.0, -1.0, 3.0]);
+        let d = Vector::from(&[4.0, 5.0, 6.0, 7.0]);
+        let du = Vector::from(&[1.0, 1.0, 2.0]);
+        let x_correct = &[1.0, -2.0, 3.0, -4.0];
+        let b = Vector::from(&[2.0, -5.0, 12.0, -19.0]);
+        let mut x = Vector::new(4);
+        solve_tridiag(&mut x, &dl, &d, &du, &b).unwrap();
+        vec_approx_eq(x.as_data(), x_correct, 1e-15);
+    }
+
+    #[test]
+    fn solve_tridiag_works_for_1d_diffusion() {
+        // -u'' = 1 with u(0) = u(1) = 0 => u = x (1 - x) / 2 (exact at the nodes)
+        let n = 9;
+        let h = 1.0 / ((n + 1) as f64);
+        let dl = Vector::filled(n - 1, -1.0);
+        let d = Vector::filled(n, 2.0);
+        let du = Vector::filled(n - 1, -1.0);
+        let b = Vector::filled(n, h * h);
+        let mut u = Vector::new(n);
+        solve_tridiag(&mut u, &dl, &d, &du, &b).unwrap();
+        let correct: Vec<_> = (1..=n)
+            .map(|i| {
+                let xi = (i as f64) * h;
+                xi * (1.0 - xi) / 2.0
+            })
+            .collect();
+        vec_approx_eq(u.as_data(), &correct, 1e-15);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::matrix::{mat_svd, Matrix};
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Solves a (possibly ill-posed) linear system via truncated singular value decomposition
+///
+/// Finds the minimum-norm `x` that minimizes the residual with the rank-r approximation of `a`:
+///
+/// ```text
+///        r-1   uᵢᵀ ⋅ b
+///   x =   Σ   ──────── vᵢ       with  r = #{ sᵢ > rcond ⋅ s₀ }
+///        i=0     sᵢ
+/// ```
+///
+/// where `s₀ ≥ s₁ ≥ … ≥ 0` are the singular values of `a` and `uᵢ` and `vᵢ` are the
+/// corresponding left and right singular vectors (see [mat_svd]). The truncation rank `r` is
+/// selected automatically by discarding the singular values smaller than or equal to `rcond ⋅ s₀`;
+/// this regularizes ill-posed inverse problems (and smooths noisy data) by filtering out
+/// the components that would amplify the noise in `b`.
+///
+/// # Input
+///
+/// * `a` -- (m,n) matrix (any shape)
+/// * `b` -- (m) right-hand side
+/// * `rcond` -- relative threshold for the singular values (`rcond ≥ 0`; e.g., `1e-8`)
+///
+/// # Output
+///
+/// * `x` -- (n) solution
+/// * Returns the truncation rank `r`
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::{solve_tsvd, Matrix, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // nearly singular matrix and a slightly noisy right-hand side
+///     let a = Matrix::from(&[
+///         [1.0, 1.0],
+///         [1.0, 1.0 + 1e-12],
+///     ]);
+///     let b = Vector::from(&[2.0, 2.0 + 1e-9]);
+///
+///     // the tiny singular value is discarded
+///     let mut x = Vector::new(2);
+///     let rank = solve_tsvd(&mut x, &a, &b, 1e-8)?;
+///     assert_eq!(rank, 1);
+///     vec_approx_eq(x.as_data(), &[1.0, 1.0], 1e-9);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_tsvd(x: &mut Vector, a: &Matrix, b: &Vector, rcond: f64) -> Result<usize, StrError> {
+    let (m, n) = a.dims();
+    if b.dim() != m {
+        return Err("b vector has wrong dimension");
+    }
+    if x.dim() != n {
+        return Err("x vector has wrong dimension");
+    }
+    if rcond < 0.0 {
+        return Err("rcond must be non-negative");
+    }
+    x.fill(0.0);
+    let min_mn = usize::min(m, n);
+    if min_mn == 0 {
+        return Ok(0);
+    }
+
+    // perform SVD
+    let mut aa = a.clone();
+    let mut s = Vector::new(min_mn);
+    let mut u = Matrix::new(m, m);
+    let mut vt = Matrix::new(n, n);
+    mat_svd(&mut s, &mut u, &mut vt, &mut aa)?;
+
+    // select the rank and accumulate the solution
+    let tol = rcond * s[0];
+    let rank = s.as_data().iter().take_while(|&&si| si > tol).count();
+    for k in 0..rank {
+        let mut utb = 0.0;
+        for i in 0..m {
+            utb += u.get(i, k) * b[i];
+        }
+        let coef = utb / s[k];
+        for j in 0..n {
+            x[j] += coef * vt.get(k, j);
+        }
+    }
+    Ok(rank)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::solve_tsvd;
+    use crate::{Matrix, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn solve_tsvd_fails_on_wrong_input() {
+        let a = Matrix::new(3, 2);
+        let mut x = Vector::new(2);
+        let mut x_wrong = Vector::new(3);
+        let b = Vector::new(3);
+        let b_wrong = Vector::new(2);
+        assert_eq!(
+            solve_tsvd(&mut x, &a, &b_wrong, 0.0).err(),
+            Some("b vector has wrong dimension")
+        );
+        assert_eq!(
+            solve_tsvd(&mut x_wrong, &a, &b, 0.0).err(),
+            Some("x vector has wrong dimension")
+        );
+        assert_eq!(
+            solve_tsvd(&mut x, &a, &b, -1.0).err(),
+            Some("rcond must be non-negative")
+        );
+        let mut x_empty = Vector::new(0);
+        assert_eq!(
+            solve_tsvd(&mut x_empty, &Matrix::new(0, 0), &Vector::new(0), 0.0),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn solve_tsvd_truncates_small_singular_values() {
+        let a = Matrix::diagonal(&[3.0, 2.0, 1e-12]);
+        let b = Vector::from(&[3.0, 4.0, 1.0]);
+        let mut x = Vector::new(3);
+        assert_eq!(solve_tsvd(&mut x, &a, &b, 1e-8).unwrap(), 2);
+        vec_approx_eq(x.as_data(), &[1.0, 2.0, 0.0], 1e-14);
+        assert_eq!(solve_tsvd(&mut x, &a, &b, 0.0).unwrap(), 3);
+        vec_approx_eq(x.as_data(), &[1.0, 2.0, 1e12], 1e-2);
+    }
+
+    #[test]
+    fn solve_tsvd_works_with_rectangular_matrices() {
+        // overdetermined (same as least squares)
+        let a = Matrix::from(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+        let mut x = Vector::new(2);
+        assert_eq!(
+            solve_tsvd(&mut x, &a, &Vector::from(&[1.0, 3.0, 5.0]), 1e-10).unwrap(),
+            2
+        );
+        vec_approx_eq(x.as_data(), &[1.0, 2.0], 1e-13);
+
+        // underdetermined (minimum-norm solution)
+        let a = Matrix::from(&[[1.0, 0.0, 1.0], [0.0, 1.0, 0.0]]);
+        let mut x = Vector::new(3);
+        assert_eq!(solve_tsvd(&mut x, &a, &Vector::from(&[2.0, 3.0]), 1e-10).unwrap(), 2);
+        vec_approx_eq(x.as_data(), &[1.0, 3.0, 1.0], 1e-13);
+    }
+}
@@ -1,7 +1,7 @@
+use crate::kernels::{dgemv, to_i32};
 use crate::matrix::Matrix;
 use crate::vector::Vector;
 use crate::StrError;
-use russell_openblas::{dgemv, to_i32};
 
 /// Performs the vector-matrix multiplication resulting in a vector
 ///
@@ -1,7 +1,7 @@
+use crate::kernels::{dger, to_i32};
 use crate::matrix::Matrix;
 use crate::vector::Vector;
 use crate::StrError;
-use russell_openblas::{dger, to_i32};
 
 /// Performs the outer (tensor) product between two vectors resulting in a matrix
 ///
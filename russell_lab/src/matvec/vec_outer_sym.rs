@@ -0,0 +1,77 @@
+use crate::kernels::{dsyr, to_i32};
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Adds the scaled symmetric outer product of a vector to the upper triangle of a matrix
+///
+/// ```text
+///   a  +=  α ⋅ u  outer  u     (only aᵢⱼ with i ≤ j are updated)
+/// (n,n)       (n)       (n)
+/// ```
+///
+/// Since `u ⊗ u` is symmetric, only about half of the operations of [crate::vec_outer_update] are
+/// performed. This is convenient to accumulate covariance (or Gram) matrices. The strictly lower
+/// triangle of `a` is not referenced; it may be filled afterwards if the full matrix is needed.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_outer_sym, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // accumulate Σ uₖ ⊗ uₖ
+///     let mut a = Matrix::new(2, 2);
+///     vec_outer_sym(&mut a, 1.0, &Vector::from(&[1.0, 2.0]))?;
+///     vec_outer_sym(&mut a, 1.0, &Vector::from(&[3.0, 1.0]))?;
+///     let correct = "┌       ┐\n\
+///                    │ 10  5 │\n\
+///                    │  0  5 │\n\
+///                    └       ┘";
+///     assert_eq!(format!("{}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_outer_sym(a: &mut Matrix, alpha: f64, u: &Vector) -> Result<(), StrError> {
+    let n = u.dim();
+    if a.nrow() != n || a.ncol() != n {
+        return Err("matrix and vector are incompatible");
+    }
+    if n == 0 {
+        return Ok(());
+    }
+    dsyr(true, to_i32(n), alpha, u.as_data(), 1, a.as_mut_data());
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_outer_sym, Matrix, Vector};
+    use crate::mat_approx_eq;
+
+    #[test]
+    fn vec_outer_sym_fails_on_wrong_dims() {
+        let u = Vector::new(2);
+        let mut a_2x3 = Matrix::new(2, 3);
+        assert_eq!(
+            vec_outer_sym(&mut a_2x3, 1.0, &u),
+            Err("matrix and vector are incompatible")
+        );
+    }
+
+    #[test]
+    fn vec_outer_sym_works() {
+        let u = Vector::from(&[1.0, 2.0, 3.0]);
+        let mut a = Matrix::from(&[[1.0, 1.0, 1.0], [-7.0, 1.0, 1.0], [-7.0, -7.0, 1.0]]);
+        vec_outer_sym(&mut a, 0.5, &u).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [ 1.5,  2.0, 2.5],
+            [-7.0,  3.0, 4.0],
+            [-7.0, -7.0, 5.5],
+        ];
+        mat_approx_eq(&a, correct, 1e-15);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::kernels::{dger, to_i32};
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use crate::StrError;
+
+/// Adds the scaled outer (tensor) product between two vectors to a matrix (rank-1 update)
+///
+/// ```text
+///   a  +=   α ⋅ u  outer  v
+/// (m,n)        (m)       (n)
+/// ```
+///
+/// # Note
+///
+/// The rows of matrix a must equal the length of vector u and
+/// the columns of matrix a must equal the length of vector v
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_outer_update, Matrix, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[1.0, 2.0]);
+///     let v = Vector::from(&[3.0, 4.0]);
+///     let mut a = Matrix::from(&[[1.0, 1.0], [1.0, 1.0]]);
+///     vec_outer_update(&mut a, 1.0, &u, &v)?;
+///     let correct = "┌     ┐\n\
+///                    │ 4 5 │\n\
+///                    │ 7 9 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", a), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_outer_update(a: &mut Matrix, alpha: f64, u: &Vector, v: &Vector) -> Result<(), StrError> {
+    let m = u.dim();
+    let n = v.dim();
+    if a.nrow() != m || a.ncol() != n {
+        return Err("matrix and vectors are incompatible");
+    }
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+    dger(
+        to_i32(m),
+        to_i32(n),
+        alpha,
+        u.as_data(),
+        1,
+        v.as_data(),
+        1,
+        a.as_mut_data(),
+    );
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_outer_update, Matrix, Vector};
+    use crate::mat_approx_eq;
+
+    #[test]
+    fn vec_outer_update_fails_on_wrong_dims() {
+        let u = Vector::new(2);
+        let v = Vector::new(3);
+        let mut a_2x1 = Matrix::new(2, 1);
+        assert_eq!(
+            vec_outer_update(&mut a_2x1, 1.0, &u, &v),
+            Err("matrix and vectors are incompatible")
+        );
+    }
+
+    #[test]
+    fn vec_outer_update_works() {
+        let u = Vector::from(&[1.0, 2.0, 3.0]);
+        let v = Vector::from(&[5.0, -2.0]);
+        let mut a = Matrix::from(&[[1.0, 1.0], [1.0, 1.0], [1.0, 1.0]]);
+        vec_outer_update(&mut a, 2.0, &u, &v).unwrap();
+        vec_outer_update(&mut a, -1.0, &u, &v).unwrap();
+        #[rustfmt::skip]
+        let correct = &[
+            [ 6.0, -1.0],
+            [11.0, -3.0],
+            [16.0, -5.0],
+        ];
+        mat_approx_eq(&a, correct, 1e-15);
+    }
+}
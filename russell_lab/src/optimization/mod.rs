@@ -0,0 +1,6 @@
+//! This module contains solvers for optimization problems
+
+mod solver_nelder_mead;
+mod solver_qp;
+pub use crate::optimization::solver_nelder_mead::*;
+pub use crate::optimization::solver_qp::*;
@@ -0,0 +1,316 @@
+use crate::{StrError, Vector};
+
+/// Holds statistics of the Nelder-Mead minimization
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NelderMeadStats {
+    /// Number of iterations performed
+    pub iterations: usize,
+
+    /// Number of calls to the objective function
+    pub evaluations: usize,
+
+    /// Objective function value at the solution
+    pub f_min: f64,
+
+    /// Indicates whether the tolerances have been reached or not
+    pub converged: bool,
+}
+
+/// Implements the Nelder-Mead (downhill simplex) method for unconstrained minimization
+///
+/// ```text
+/// minimize f(x)
+/// ```
+///
+/// The method only requires the values of `f` (no derivatives); thus, it is well suited to
+/// noisy or non-smooth objectives with a few variables (e.g., the calibration of hyperparameters).
+/// The standard coefficients are used (reflection 1, expansion 2, contraction ½, and shrink ½).
+///
+/// The iterations stop when both the spread of the function values and the size of the simplex
+/// (max-norm distance from the best vertex) are below the tolerances.
+///
+/// **Note:** The objective function may return `f64::INFINITY` to reject a point (e.g., outside
+/// the feasible region), as long as the initial guess is feasible.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{SolverNelderMead, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // minimize (x₀ - 1)² + 10 (x₁ + 2)²
+///     let mut x = Vector::from(&[0.0, 0.0]);
+///     let stats = SolverNelderMead::new().solve(&mut x, |x| {
+///         Ok(f64::powi(x[0] - 1.0, 2) + 10.0 * f64::powi(x[1] + 2.0, 2))
+///     })?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.5}", x), "┌          ┐\n\
+///                                      │  1.00000 │\n\
+///                                      │ -2.00000 │\n\
+///                                      └          ┘");
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SolverNelderMead {
+    pub(crate) initial_step: f64,     // size of the initial simplex
+    pub(crate) tol_x: f64,            // tolerance on the size of the simplex
+    pub(crate) tol_f: f64,            // tolerance on the spread of the function values
+    pub(crate) max_iterations: usize, // max number of iterations
+}
+
+impl SolverNelderMead {
+    /// Returns a new solver with default parameters
+    pub fn new() -> Self {
+        SolverNelderMead {
+            initial_step: 0.1,
+            tol_x: 1e-8,
+            tol_f: 1e-10,
+            max_iterations: 10_000,
+        }
+    }
+
+    /// Sets the size of the initial simplex
+    ///
+    /// The vertices are `x + h eᵢ` with `h = initial_step ⋅ max(|xᵢ|, 1)`.
+    pub fn initial_step(&mut self, value: f64) -> &mut Self {
+        self.initial_step = value;
+        self
+    }
+
+    /// Sets the tolerances on the size of the simplex and on the spread of the function values
+    pub fn tolerances(&mut self, tol_x: f64, tol_f: f64) -> &mut Self {
+        self.tol_x = tol_x;
+        self.tol_f = tol_f;
+        self
+    }
+
+    /// Sets the maximum number of iterations
+    pub fn max_iterations(&mut self, value: usize) -> &mut Self {
+        self.max_iterations = value;
+        self
+    }
+
+    /// Minimizes the objective function
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (n) on input, the initial guess; on output, the best point found
+    /// * `f` -- the objective function `f(x)`
+    pub fn solve<F>(&self, x: &mut Vector, mut f: F) -> Result<NelderMeadStats, StrError>
+    where
+        F: FnMut(&Vector) -> Result<f64, StrError>,
+    {
+        let n = x.dim();
+        if n < 1 {
+            return Err("x vector must have at least one component");
+        }
+        if self.initial_step <= 0.0 || self.tol_x <= 0.0 || self.tol_f <= 0.0 {
+            return Err("initial_step and tolerances must be greater than zero");
+        }
+        let mut stats = NelderMeadStats {
+            iterations: 0,
+            evaluations: 0,
+            f_min: 0.0,
+            converged: false,
+        };
+        let mut eval = |v: &Vector, stats: &mut NelderMeadStats| {
+            stats.evaluations += 1;
+            f(v)
+        };
+
+        // initial simplex
+        let mut simplex = vec![x.clone(); n + 1];
+        for (i, vertex) in simplex.iter_mut().skip(1).enumerate() {
+            vertex[i] += self.initial_step * f64::max(f64::abs(x[i]), 1.0);
+        }
+        let mut values = vec![0.0; n + 1];
+        for (value, vertex) in values.iter_mut().zip(&simplex) {
+            *value = eval(vertex, &mut stats)?;
+        }
+        if !values[0].is_finite() {
+            return Err("the objective function must be finite at the initial guess");
+        }
+
+        let mut centroid = Vector::new(n);
+        let mut trial = Vector::new(n);
+        let mut trial2 = Vector::new(n);
+        for iteration in 1..=self.max_iterations {
+            // sort the vertices (best first)
+            let mut order: Vec<usize> = (0..=n).collect();
+            order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+            simplex = order.iter().map(|&k| simplex[k].clone()).collect();
+            values = order.iter().map(|&k| values[k]).collect();
+            stats.iterations = iteration;
+
+            // check convergence
+            let spread_f = f64::abs(values[n] - values[0]);
+            let mut size_x: f64 = 0.0;
+            for vertex in &simplex[1..] {
+                for j in 0..n {
+                    size_x = f64::max(size_x, f64::abs(vertex[j] - simplex[0][j]));
+                }
+            }
+            if spread_f <= self.tol_f && size_x <= self.tol_x {
+                stats.converged = true;
+                break;
+            }
+
+            // centroid of all vertices but the worst
+            centroid.fill(0.0);
+            for vertex in &simplex[..n] {
+                for j in 0..n {
+                    centroid[j] += vertex[j] / (n as f64);
+                }
+            }
+
+            // reflection
+            combine(&mut trial, &centroid, &simplex[n], 1.0);
+            let f_reflected = eval(&trial, &mut stats)?;
+            if f_reflected < values[0] {
+                // expansion
+                combine(&mut trial2, &centroid, &simplex[n], 2.0);
+                let f_expanded = eval(&trial2, &mut stats)?;
+                if f_expanded < f_reflected {
+                    simplex[n].as_mut_data().copy_from_slice(trial2.as_data());
+                    values[n] = f_expanded;
+                } else {
+                    simplex[n].as_mut_data().copy_from_slice(trial.as_data());
+                    values[n] = f_reflected;
+                }
+                continue;
+            }
+            if f_reflected < values[n - 1] {
+                simplex[n].as_mut_data().copy_from_slice(trial.as_data());
+                values[n] = f_reflected;
+                continue;
+            }
+
+            // contraction (outside if the reflected point is better than the worst one)
+            let (coefficient, f_reference) = if f_reflected < values[n] {
+                (0.5, f_reflected)
+            } else {
+                (-0.5, values[n])
+            };
+            combine(&mut trial2, &centroid, &simplex[n], coefficient);
+            let f_contracted = eval(&trial2, &mut stats)?;
+            if f_contracted < f_reference {
+                simplex[n].as_mut_data().copy_from_slice(trial2.as_data());
+                values[n] = f_contracted;
+                continue;
+            }
+
+            // shrink towards the best vertex
+            let (best, others) = simplex.split_at_mut(1);
+            for (vertex, value) in others.iter_mut().zip(values[1..].iter_mut()) {
+                for j in 0..n {
+                    vertex[j] = best[0][j] + 0.5 * (vertex[j] - best[0][j]);
+                }
+                *value = eval(vertex, &mut stats)?;
+            }
+        }
+
+        // results
+        let best = (0..=n).fold(0, |b, k| if values[k] < values[b] { k } else { b });
+        x.as_mut_data().copy_from_slice(simplex[best].as_data());
+        stats.f_min = values[best];
+        Ok(stats)
+    }
+}
+
+impl Default for SolverNelderMead {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes `trial = centroid + coefficient ⋅ (centroid - worst)`
+fn combine(trial: &mut Vector, centroid: &Vector, worst: &Vector, coefficient: f64) {
+    for j in 0..trial.dim() {
+        trial[j] = centroid[j] + coefficient * (centroid[j] - worst[j]);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SolverNelderMead;
+    use crate::Vector;
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn solve_fails_on_wrong_input() {
+        let solver = SolverNelderMead::new();
+        assert_eq!(
+            solver.solve(&mut Vector::new(0), |_| Ok(0.0)).err(),
+            Some("x vector must have at least one component")
+        );
+        let mut x = Vector::new(1);
+        assert_eq!(
+            SolverNelderMead::new()
+                .initial_step(0.0)
+                .solve(&mut x, |_| Ok(0.0))
+                .err(),
+            Some("initial_step and tolerances must be greater than zero")
+        );
+        assert_eq!(
+            solver.solve(&mut x, |_| Ok(f64::INFINITY)).err(),
+            Some("the objective function must be finite at the initial guess")
+        );
+        assert_eq!(solver.solve(&mut x, |_| Err("stop")).err(), Some("stop"));
+    }
+
+    #[test]
+    fn solve_works_one_dimension() {
+        let mut x = Vector::from(&[10.0]);
+        let stats = SolverNelderMead::new()
+            .solve(&mut x, |x| Ok(f64::powi(x[0] - 3.0, 2) + 1.0))
+            .unwrap();
+        assert!(stats.converged);
+        assert!(f64::abs(x[0] - 3.0) < 1e-7);
+        assert!(f64::abs(stats.f_min - 1.0) < 1e-10);
+    }
+
+    #[test]
+    fn solve_works_rosenbrock() {
+        let mut x = Vector::from(&[-1.2, 1.0]);
+        let mut count = 0;
+        let stats = SolverNelderMead::new()
+            .tolerances(1e-10, 1e-14)
+            .solve(&mut x, |x| {
+                count += 1;
+                Ok(100.0 * f64::powi(x[1] - x[0] * x[0], 2) + f64::powi(1.0 - x[0], 2))
+            })
+            .unwrap();
+        assert!(stats.converged);
+        assert_eq!(stats.evaluations, count);
+        assert!(stats.iterations < 1000);
+        vec_approx_eq(x.as_data(), &[1.0, 1.0], 1e-8);
+    }
+
+    #[test]
+    fn solve_handles_rejected_points_and_max_iterations() {
+        // minimize x₀ + x₁ subject to x₀ ≥ 1 and x₁ ≥ 2 (infinite outside)
+        let objective = |x: &Vector| {
+            if x[0] < 1.0 || x[1] < 2.0 {
+                Ok(f64::INFINITY)
+            } else {
+                Ok(x[0] + x[1])
+            }
+        };
+        let mut x = Vector::from(&[3.0, 3.0]);
+        let stats = SolverNelderMead::new().solve(&mut x, objective).unwrap();
+        assert!(stats.converged);
+        vec_approx_eq(x.as_data(), &[1.0, 2.0], 1e-7);
+
+        let mut x = Vector::from(&[3.0, 3.0]);
+        let stats = SolverNelderMead::new()
+            .max_iterations(3)
+            .solve(&mut x, objective)
+            .unwrap();
+        assert!(!stats.converged);
+        assert_eq!(stats.iterations, 3);
+        assert!(stats.f_min < 6.0);
+    }
+}
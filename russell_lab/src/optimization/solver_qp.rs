@@ -0,0 +1,449 @@
+use crate::{mat_cholesky, mat_t_mat_mul, mat_vec_mul, vec_mat_mul, Matrix, StrError, Vector};
+
+/// Holds the data of a convex quadratic programming (QP) problem
+///
+/// ```text
+/// minimize    ½ xᵀ ⋅ Q ⋅ x + cᵀ ⋅ x
+/// subject to  l ≤ A ⋅ x ≤ u
+/// ```
+///
+/// where `Q` (n,n) is symmetric positive semi-definite and `A` is (m,n).
+///
+/// * Equality constraints are defined with `lᵢ = uᵢ`
+/// * One-sided constraints are defined with `lᵢ = -∞` or `uᵢ = +∞`
+/// * Bounds on the variables are defined with rows of the identity matrix in `A`
+#[derive(Clone, Debug)]
+pub struct QpProblem {
+    pub(crate) q: Matrix,     // (n,n) Hessian
+    pub(crate) c: Vector,     // (n) linear term
+    pub(crate) a: Matrix,     // (m,n) constraints matrix
+    pub(crate) lower: Vector, // (m) lower bounds
+    pub(crate) upper: Vector, // (m) upper bounds
+}
+
+impl QpProblem {
+    /// Allocates a new instance
+    pub fn new(q: &Matrix, c: &Vector, a: &Matrix, lower: &Vector, upper: &Vector) -> Result<Self, StrError> {
+        let n = c.dim();
+        let m = lower.dim();
+        if n == 0 {
+            return Err("the number of variables must be ≥ 1");
+        }
+        if q.dims() != (n, n) {
+            return Err("Q matrix must be (n,n) with n = dim(c)");
+        }
+        if a.dims() != (m, n) {
+            return Err("A matrix must be (m,n) with m = dim(lower)");
+        }
+        if upper.dim() != m {
+            return Err("lower and upper vectors must have the same dimension");
+        }
+        for i in 0..m {
+            if lower[i] > upper[i] || lower[i].is_nan() || upper[i].is_nan() {
+                return Err("lower bounds must be smaller than or equal to upper bounds");
+            }
+        }
+        Ok(QpProblem {
+            q: q.clone(),
+            c: c.clone(),
+            a: a.clone(),
+            lower: lower.clone(),
+            upper: upper.clone(),
+        })
+    }
+
+    /// Returns the number of variables (n) and the number of constraints (m)
+    pub fn dims(&self) -> (usize, usize) {
+        (self.c.dim(), self.lower.dim())
+    }
+
+    /// Computes the objective function `½ xᵀ⋅Q⋅x + cᵀ⋅x`
+    pub fn objective(&self, x: &Vector) -> Result<f64, StrError> {
+        let n = self.c.dim();
+        if x.dim() != n {
+            return Err("x vector has wrong dimension");
+        }
+        let mut f = 0.0;
+        for i in 0..n {
+            let mut qx = 0.0;
+            for j in 0..n {
+                qx += self.q.get(i, j) * x[j];
+            }
+            f += x[i] * (0.5 * qx + self.c[i]);
+        }
+        Ok(f)
+    }
+}
+
+/// Holds statistics of the QP solution
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QpStats {
+    /// Number of iterations performed
+    pub iterations: usize,
+
+    /// Max-norm of the primal residual `‖A⋅x - z‖∞`
+    pub primal_residual: f64,
+
+    /// Max-norm of the dual residual `‖Q⋅x + c + Aᵀ⋅y‖∞`
+    pub dual_residual: f64,
+
+    /// Indicates whether the tolerances have been reached or not
+    pub converged: bool,
+}
+
+/// Implements a solver for convex quadratic programming problems using ADMM
+///
+/// The alternating direction method of multipliers (ADMM) is implemented as in OSQP
+/// (Stellato et al. 2020). Each iteration solves the linear system:
+///
+/// ```text
+/// (Q + σ I + ρ Aᵀ A) ⋅ x̃ = σ x - c + Aᵀ (ρ z - y)
+/// ```
+///
+/// whose (fixed) matrix is factorized only once with the Cholesky factorization; then,
+/// the auxiliary variables `z` are projected onto `[l, u]` and the multipliers `y` are updated.
+///
+/// **Note:** Infeasible or unbounded problems are not detected; the solver stops after
+/// `max_iterations` with `converged = false`.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, QpProblem, SolverQp, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // minimize ½(x₀² + x₁²) - x₀ - x₁  subject to  x₀ + x₁ ≤ 1
+///     let q = Matrix::from(&[[1.0, 0.0], [0.0, 1.0]]);
+///     let c = Vector::from(&[-1.0, -1.0]);
+///     let a = Matrix::from(&[[1.0, 1.0]]);
+///     let lower = Vector::from(&[f64::NEG_INFINITY]);
+///     let upper = Vector::from(&[1.0]);
+///     let problem = QpProblem::new(&q, &c, &a, &lower, &upper)?;
+///     let mut x = Vector::new(2);
+///     let mut y = Vector::new(1);
+///     let stats = SolverQp::new().solve(&mut x, &mut y, &problem)?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.4}", x), "┌        ┐\n\
+///                                      │ 0.5000 │\n\
+///                                      │ 0.5000 │\n\
+///                                      └        ┘");
+///     assert_eq!(format!("{:.4}", y[0]), "0.5000"); // multiplier of the active constraint
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SolverQp {
+    pub(crate) rho: f64,              // penalty parameter
+    pub(crate) sigma: f64,            // regularization of the x-update
+    pub(crate) alpha: f64,            // relaxation parameter (0 < α < 2)
+    pub(crate) tol_abs: f64,          // absolute tolerance
+    pub(crate) tol_rel: f64,          // relative tolerance
+    pub(crate) max_iterations: usize, // max number of iterations
+}
+
+impl SolverQp {
+    /// Returns a new solver with default parameters
+    pub fn new() -> Self {
+        SolverQp {
+            rho: 0.1,
+            sigma: 1e-6,
+            alpha: 1.6,
+            tol_abs: 1e-8,
+            tol_rel: 1e-8,
+            max_iterations: 10_000,
+        }
+    }
+
+    /// Sets the penalty parameter ρ > 0
+    pub fn rho(&mut self, value: f64) -> &mut Self {
+        self.rho = value;
+        self
+    }
+
+    /// Sets the regularization parameter σ > 0
+    pub fn sigma(&mut self, value: f64) -> &mut Self {
+        self.sigma = value;
+        self
+    }
+
+    /// Sets the relaxation parameter 0 < α < 2
+    pub fn alpha(&mut self, value: f64) -> &mut Self {
+        self.alpha = value;
+        self
+    }
+
+    /// Sets the absolute and relative tolerances
+    pub fn tolerances(&mut self, tol_abs: f64, tol_rel: f64) -> &mut Self {
+        self.tol_abs = tol_abs;
+        self.tol_rel = tol_rel;
+        self
+    }
+
+    /// Sets the maximum number of iterations
+    pub fn max_iterations(&mut self, value: usize) -> &mut Self {
+        self.max_iterations = value;
+        self
+    }
+
+    /// Solves the QP problem
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (n) on input, the initial guess; on output, the solution
+    /// * `y` -- (m) on input, the initial guess; on output, the Lagrange multipliers of the constraints
+    ///   (positive if the upper bound is active and negative if the lower bound is active)
+    /// * `problem` -- the QP problem
+    pub fn solve(&self, x: &mut Vector, y: &mut Vector, problem: &QpProblem) -> Result<QpStats, StrError> {
+        let valid = self.rho > 0.0 && self.sigma > 0.0 && self.alpha > 0.0 && self.alpha < 2.0;
+        if !valid {
+            return Err("parameters must satisfy ρ > 0, σ > 0, and 0 < α < 2");
+        }
+        let (n, m) = problem.dims();
+        if x.dim() != n {
+            return Err("x vector has wrong dimension");
+        }
+        if y.dim() != m {
+            return Err("y vector has wrong dimension");
+        }
+        let (rho, sigma, alpha) = (self.rho, self.sigma, self.alpha);
+        let (q, c, a) = (&problem.q, &problem.c, &problem.a);
+
+        // factorize K = Q + σ I + ρ Aᵀ A
+        let mut kk = Matrix::new(n, n);
+        if m > 0 {
+            mat_t_mat_mul(&mut kk, rho, a, a)?;
+        }
+        for i in 0..n {
+            for j in 0..n {
+                kk.add(i, j, q.get(i, j));
+            }
+            kk.add(i, i, sigma);
+        }
+        let mut l = Matrix::new(n, n);
+        mat_cholesky(&mut l, &kk)?;
+
+        // auxiliary variables
+        let mut ax = Vector::new(m);
+        let mut z = Vector::new(m);
+        mat_vec_mul(&mut ax, 1.0, a, x)?;
+        for i in 0..m {
+            z[i] = f64::min(f64::max(ax[i], problem.lower[i]), problem.upper[i]);
+        }
+        let mut rhs = Vector::new(n);
+        let mut x_tilde = Vector::new(n);
+        let mut z_tilde = Vector::new(m);
+        let mut w = Vector::new(m);
+        let mut aty = Vector::new(n);
+        let mut qx = Vector::new(n);
+        let norm_c = max_abs(c);
+
+        let mut stats = QpStats {
+            iterations: 0,
+            primal_residual: 0.0,
+            dual_residual: 0.0,
+            converged: false,
+        };
+        for iteration in 1..=self.max_iterations {
+            // x̃ = K⁻¹ ⋅ (σ x - c + Aᵀ (ρ z - y))
+            for i in 0..m {
+                w[i] = rho * z[i] - y[i];
+            }
+            rhs.fill(0.0);
+            vec_mat_mul(&mut rhs, 1.0, &w, a)?;
+            for i in 0..n {
+                rhs[i] += sigma * x[i] - c[i];
+            }
+            cholesky_solve(&mut x_tilde, &l, &rhs);
+            mat_vec_mul(&mut z_tilde, 1.0, a, &x_tilde)?;
+
+            // relaxation, projection, and multipliers update
+            for i in 0..n {
+                x[i] = alpha * x_tilde[i] + (1.0 - alpha) * x[i];
+            }
+            for i in 0..m {
+                let z_relaxed = alpha * z_tilde[i] + (1.0 - alpha) * z[i];
+                let z_new = f64::min(f64::max(z_relaxed + y[i] / rho, problem.lower[i]), problem.upper[i]);
+                y[i] += rho * (z_relaxed - z_new);
+                z[i] = z_new;
+            }
+
+            // residuals
+            mat_vec_mul(&mut ax, 1.0, a, x)?;
+            mat_vec_mul(&mut qx, 1.0, q, x)?;
+            aty.fill(0.0);
+            vec_mat_mul(&mut aty, 1.0, y, a)?;
+            let mut r_prim: f64 = 0.0;
+            for i in 0..m {
+                r_prim = f64::max(r_prim, f64::abs(ax[i] - z[i]));
+            }
+            let mut r_dual: f64 = 0.0;
+            for i in 0..n {
+                r_dual = f64::max(r_dual, f64::abs(qx[i] + c[i] + aty[i]));
+            }
+            let eps_prim = self.tol_abs + self.tol_rel * f64::max(max_abs(&ax), max_abs(&z));
+            let eps_dual = self.tol_abs + self.tol_rel * f64::max(f64::max(max_abs(&qx), max_abs(&aty)), norm_c);
+            stats.iterations = iteration;
+            stats.primal_residual = r_prim;
+            stats.dual_residual = r_dual;
+            if r_prim <= eps_prim && r_dual <= eps_dual {
+                stats.converged = true;
+                break;
+            }
+        }
+        Ok(stats)
+    }
+}
+
+impl Default for SolverQp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the max-norm of a vector (zero if empty)
+fn max_abs(v: &Vector) -> f64 {
+    v.as_data().iter().fold(0.0, |acc, vi| f64::max(acc, f64::abs(*vi)))
+}
+
+/// Solves `l ⋅ lᵀ ⋅ x = b` given the lower triangular Cholesky factor `l`
+fn cholesky_solve(x: &mut Vector, l: &Matrix, b: &Vector) {
+    let n = b.dim();
+    // forward substitution: l ⋅ w = b
+    for i in 0..n {
+        let mut sum = b[i];
+        for k in 0..i {
+            sum -= l.get(i, k) * x[k];
+        }
+        x[i] = sum / l.get(i, i);
+    }
+    // backward substitution: lᵀ ⋅ x = w
+    for i in (0..n).rev() {
+        let mut sum = x[i];
+        for k in (i + 1)..n {
+            sum -= l.get(k, i) * x[k];
+        }
+        x[i] = sum / l.get(i, i);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{cholesky_solve, max_abs, QpProblem, SolverQp};
+    use crate::{Matrix, Vector};
+    use russell_chk::{approx_eq, vec_approx_eq};
+
+    #[test]
+    fn qp_problem_new_fails_on_wrong_input() {
+        let q = Matrix::new(2, 2);
+        let c = Vector::new(2);
+        let a = Matrix::new(1, 2);
+        let l = Vector::new(1);
+        let u = Vector::new(1);
+        assert_eq!(
+            QpProblem::new(&q, &Vector::new(0), &a, &l, &u).err(),
+            Some("the number of variables must be ≥ 1")
+        );
+        assert_eq!(
+            QpProblem::new(&Matrix::new(2, 3), &c, &a, &l, &u).err(),
+            Some("Q matrix must be (n,n) with n = dim(c)")
+        );
+        assert_eq!(
+            QpProblem::new(&q, &c, &Matrix::new(2, 2), &l, &u).err(),
+            Some("A matrix must be (m,n) with m = dim(lower)")
+        );
+        assert_eq!(
+            QpProblem::new(&q, &c, &a, &l, &Vector::new(2)).err(),
+            Some("lower and upper vectors must have the same dimension")
+        );
+        assert_eq!(
+            QpProblem::new(&q, &c, &a, &Vector::from(&[1.0]), &u).err(),
+            Some("lower bounds must be smaller than or equal to upper bounds")
+        );
+    }
+
+    #[test]
+    fn qp_problem_objective_works() {
+        let q = Matrix::from(&[[2.0, 1.0], [1.0, 4.0]]);
+        let c = Vector::from(&[1.0, -1.0]);
+        let problem = QpProblem::new(&q, &c, &Matrix::new(0, 2), &Vector::new(0), &Vector::new(0)).unwrap();
+        assert_eq!(problem.dims(), (2, 0));
+        // ½(2 + 2 + 4) + 1 - 1 = 4
+        assert_eq!(problem.objective(&Vector::from(&[1.0, 1.0])).unwrap(), 4.0);
+        assert_eq!(
+            problem.objective(&Vector::new(3)).err(),
+            Some("x vector has wrong dimension")
+        );
+    }
+
+    #[test]
+    fn helpers_work() {
+        assert_eq!(max_abs(&Vector::new(0)), 0.0);
+        assert_eq!(max_abs(&Vector::from(&[1.0, -3.0, 2.0])), 3.0);
+        // l = [[2, 0], [1, 3]] ⇒ l⋅lᵀ = [[4, 2], [2, 10]]
+        let l = Matrix::from(&[[2.0, 0.0], [1.0, 3.0]]);
+        let b = Vector::from(&[6.0, 12.0]);
+        let mut x = Vector::new(2);
+        cholesky_solve(&mut x, &l, &b);
+        vec_approx_eq(x.as_data(), &[1.0, 1.0], 1e-15);
+    }
+
+    #[test]
+    fn solve_fails_on_wrong_input() {
+        let q = Matrix::from(&[[1.0]]);
+        let c = Vector::from(&[1.0]);
+        let problem = QpProblem::new(&q, &c, &Matrix::new(0, 1), &Vector::new(0), &Vector::new(0)).unwrap();
+        let mut x = Vector::new(1);
+        let mut y = Vector::new(0);
+        assert_eq!(
+            SolverQp::new().rho(0.0).solve(&mut x, &mut y, &problem).err(),
+            Some("parameters must satisfy ρ > 0, σ > 0, and 0 < α < 2")
+        );
+        assert_eq!(
+            SolverQp::new().alpha(2.0).solve(&mut x, &mut y, &problem).err(),
+            Some("parameters must satisfy ρ > 0, σ > 0, and 0 < α < 2")
+        );
+        assert_eq!(
+            SolverQp::new().solve(&mut Vector::new(2), &mut y, &problem).err(),
+            Some("x vector has wrong dimension")
+        );
+        assert_eq!(
+            SolverQp::new().solve(&mut x, &mut Vector::new(1), &problem).err(),
+            Some("y vector has wrong dimension")
+        );
+    }
+
+    #[test]
+    fn solve_unconstrained_works() {
+        // minimize ½ xᵀ Q x + cᵀ x ⇒ Q x = -c
+        let q = Matrix::from(&[[4.0, 1.0], [1.0, 3.0]]);
+        let c = Vector::from(&[-1.0, -2.0]);
+        let problem = QpProblem::new(&q, &c, &Matrix::new(0, 2), &Vector::new(0), &Vector::new(0)).unwrap();
+        let mut x = Vector::new(2);
+        let mut y = Vector::new(0);
+        let stats = SolverQp::new().solve(&mut x, &mut y, &problem).unwrap();
+        assert!(stats.converged);
+        vec_approx_eq(x.as_data(), &[1.0 / 11.0, 7.0 / 11.0], 1e-7);
+    }
+
+    #[test]
+    fn solve_with_bounds_and_equality_works() {
+        // minimize (x₀ - 2)² + (x₁ - 2)² (constant omitted) subject to
+        // x₀ - x₁ = 0 and 0 ≤ x₀ ≤ 1 (contact-like upper bound)
+        let q = Matrix::from(&[[2.0, 0.0], [0.0, 2.0]]);
+        let c = Vector::from(&[-4.0, -4.0]);
+        let a = Matrix::from(&[[1.0, -1.0], [1.0, 0.0]]);
+        let lower = Vector::from(&[0.0, 0.0]);
+        let upper = Vector::from(&[0.0, 1.0]);
+        let problem = QpProblem::new(&q, &c, &a, &lower, &upper).unwrap();
+        let mut x = Vector::new(2);
+        let mut y = Vector::new(2);
+        let stats = SolverQp::new().solve(&mut x, &mut y, &problem).unwrap();
+        assert!(stats.converged);
+        vec_approx_eq(x.as_data(), &[1.0, 1.0], 1e-6);
+        // KKT: Q x + c + Aᵀ y = 0 ⇒ y₀ + y₁ = 2 and -y₀ = 2 ⇒ y = (-2, 4)
+        vec_approx_eq(y.as_data(), &[-2.0, 4.0], 1e-5);
+        approx_eq(problem.objective(&x).unwrap(), -6.0, 1e-5);
+    }
+}
@@ -0,0 +1,348 @@
+use crate::{Matrix, StrError, Vector};
+
+/// Holds a permutation of the indices 0..n
+///
+/// The permutation is stored as the array `perm` such that `perm[new] = old`; i.e., the
+/// component `perm[k]` of the original vector (or row/column of the original matrix) becomes
+/// the k-th component of the permuted vector. This is the convention of the fill-reducing and
+/// bandwidth-reducing orderings (e.g., the RCM ordering of russell_sparse) and of [Permutation::argsort].
+///
+/// The permutation matrix `p` corresponding to `perm` is such that `(p ⋅ v)[k] = v[perm[k]]`.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Permutation, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let v = Vector::from(&[30.0, 10.0, 20.0]);
+///
+///     // sort the components in ascending order
+///     let p = Permutation::argsort(v.as_data());
+///     assert_eq!(p.as_slice(), &[1, 2, 0]);
+///     let mut sorted = Vector::new(3);
+///     p.apply_vec(&mut sorted, &v)?;
+///     assert_eq!(sorted.as_data(), &[10.0, 20.0, 30.0]);
+///
+///     // undo the sorting
+///     let mut original = Vector::new(3);
+///     p.inverse().apply_vec(&mut original, &sorted)?;
+///     assert_eq!(original.as_data(), v.as_data());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Permutation {
+    perm: Vec<usize>, // (n) perm[new] = old
+}
+
+impl Permutation {
+    /// Allocates the identity permutation of dimension n
+    pub fn identity(n: usize) -> Self {
+        Permutation { perm: (0..n).collect() }
+    }
+
+    /// Allocates a new instance from the array `perm` such that `perm[new] = old`
+    ///
+    /// **Note:** An error is returned if `perm` is not a permutation of 0..n, where n = perm.len().
+    pub fn from_vec(perm: Vec<usize>) -> Result<Self, StrError> {
+        let n = perm.len();
+        let mut found = vec![false; n];
+        for &old in &perm {
+            if old >= n || found[old] {
+                return Err("perm must be a permutation of 0..n");
+            }
+            found[old] = true;
+        }
+        Ok(Permutation { perm })
+    }
+
+    /// Allocates a new instance from the pivot indices computed by LAPACK (e.g., dgetrf)
+    ///
+    /// The pivot indices are one-based (as in Fortran) and represent a sequence of row interchanges;
+    /// i.e., the row `i` was interchanged with the row `ipiv[i] - 1`, for `i = 0, 1, ..., n-1`.
+    /// The resulting permutation holds the final position of the rows; e.g., for the LU factorization
+    /// `a = pᵀ ⋅ l ⋅ u`, the rows of `a` permuted by the resulting permutation equal `l ⋅ u`.
+    ///
+    /// **Note:** An error is returned if an index is out of range (negative indices, as in the
+    /// 2×2 pivots of dsytrf, are not supported).
+    pub fn from_ipiv(ipiv: &[i32]) -> Result<Self, StrError> {
+        let n = ipiv.len();
+        let mut perm: Vec<usize> = (0..n).collect();
+        for (i, &p) in ipiv.iter().enumerate() {
+            if p < 1 || p as usize > n {
+                return Err("pivot indices must be in 1..=n");
+            }
+            perm.swap(i, p as usize - 1);
+        }
+        Ok(Permutation { perm })
+    }
+
+    /// Allocates a new instance that sorts the values in ascending order
+    ///
+    /// Returns `perm` such that `values[perm[0]] ≤ values[perm[1]] ≤ ...`. The sorting is stable
+    /// (equal values keep their order) and uses the total ordering of floating-point numbers;
+    /// thus, NaN values are placed last.
+    pub fn argsort(values: &[f64]) -> Self {
+        let mut perm: Vec<usize> = (0..values.len()).collect();
+        perm.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+        Permutation { perm }
+    }
+
+    /// Returns the dimension n
+    pub fn dim(&self) -> usize {
+        self.perm.len()
+    }
+
+    /// Returns the array `perm` such that `perm[new] = old`
+    pub fn as_slice(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Returns the inverse permutation such that `inv[old] = new`
+    pub fn inverse(&self) -> Self {
+        let mut inv = vec![0; self.perm.len()];
+        for (new, &old) in self.perm.iter().enumerate() {
+            inv[old] = new;
+        }
+        Permutation { perm: inv }
+    }
+
+    /// Returns the composition of this permutation with another one
+    ///
+    /// The result is equivalent to applying `other` first and then `self`:
+    ///
+    /// ```text
+    /// (p ∘ q) ⋅ v = p ⋅ (q ⋅ v)   ⇒   result[k] = q[p[k]]
+    /// ```
+    pub fn compose(&self, other: &Permutation) -> Result<Self, StrError> {
+        if other.perm.len() != self.perm.len() {
+            return Err("permutations must have the same dimension");
+        }
+        Ok(Permutation {
+            perm: self.perm.iter().map(|&k| other.perm[k]).collect(),
+        })
+    }
+
+    /// Returns the determinant of the permutation matrix (+1 if even or -1 if odd)
+    pub fn sign(&self) -> f64 {
+        let n = self.perm.len();
+        let mut visited = vec![false; n];
+        let mut n_cycles = 0;
+        for start in 0..n {
+            if !visited[start] {
+                n_cycles += 1;
+                let mut k = start;
+                while !visited[k] {
+                    visited[k] = true;
+                    k = self.perm[k];
+                }
+            }
+        }
+        if (n - n_cycles) % 2 == 1 {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Permutes the components of a vector
+    ///
+    /// ```text
+    /// u[k] := v[perm[k]]
+    /// ```
+    pub fn apply_vec(&self, u: &mut Vector, v: &Vector) -> Result<(), StrError> {
+        let n = self.perm.len();
+        if u.dim() != n || v.dim() != n {
+            return Err("vectors must have dimension equal to the dimension of the permutation");
+        }
+        for (new, &old) in self.perm.iter().enumerate() {
+            u[new] = v[old];
+        }
+        Ok(())
+    }
+
+    /// Permutes the rows of a matrix
+    ///
+    /// ```text
+    /// b[k][j] := a[perm[k]][j]
+    /// ```
+    pub fn apply_mat_rows(&self, b: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
+        let (m, n) = a.dims();
+        if m != self.perm.len() {
+            return Err("the number of rows must equal the dimension of the permutation");
+        }
+        if b.dims() != (m, n) {
+            return Err("matrices are incompatible");
+        }
+        for (new, &old) in self.perm.iter().enumerate() {
+            for j in 0..n {
+                b.set(new, j, a.get(old, j));
+            }
+        }
+        Ok(())
+    }
+
+    /// Permutes the columns of a matrix
+    ///
+    /// ```text
+    /// b[i][k] := a[i][perm[k]]
+    /// ```
+    pub fn apply_mat_cols(&self, b: &mut Matrix, a: &Matrix) -> Result<(), StrError> {
+        let (m, n) = a.dims();
+        if n != self.perm.len() {
+            return Err("the number of columns must equal the dimension of the permutation");
+        }
+        if b.dims() != (m, n) {
+            return Err("matrices are incompatible");
+        }
+        for (new, &old) in self.perm.iter().enumerate() {
+            for i in 0..m {
+                b.set(i, new, a.get(i, old));
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Permutation;
+    use crate::{Matrix, Vector};
+
+    #[test]
+    fn constructors_capture_errors() {
+        assert_eq!(
+            Permutation::from_vec(vec![0, 2]).err(),
+            Some("perm must be a permutation of 0..n")
+        );
+        assert_eq!(
+            Permutation::from_vec(vec![1, 1]).err(),
+            Some("perm must be a permutation of 0..n")
+        );
+        assert_eq!(
+            Permutation::from_ipiv(&[0, 2]).err(),
+            Some("pivot indices must be in 1..=n")
+        );
+        assert_eq!(
+            Permutation::from_ipiv(&[3, 2]).err(),
+            Some("pivot indices must be in 1..=n")
+        );
+    }
+
+    #[test]
+    fn constructors_work() {
+        let p = Permutation::identity(3);
+        assert_eq!(p.dim(), 3);
+        assert_eq!(p.as_slice(), &[0, 1, 2]);
+        assert_eq!(Permutation::identity(0).dim(), 0);
+        let p = Permutation::from_vec(vec![2, 0, 1]).unwrap();
+        assert_eq!(p.as_slice(), &[2, 0, 1]);
+        // rows 0 ↔ 2, then rows 1 ↔ 2
+        let p = Permutation::from_ipiv(&[3, 3, 3]).unwrap();
+        assert_eq!(p.as_slice(), &[2, 0, 1]);
+        let p = Permutation::from_ipiv(&[1, 2, 3]).unwrap();
+        assert_eq!(p, Permutation::identity(3));
+    }
+
+    #[test]
+    fn argsort_works() {
+        let p = Permutation::argsort(&[]);
+        assert_eq!(p.dim(), 0);
+        let p = Permutation::argsort(&[3.0, -1.0, 2.0, -1.0, f64::NAN, 0.0]);
+        assert_eq!(p.as_slice(), &[1, 3, 5, 2, 0, 4]);
+    }
+
+    #[test]
+    fn inverse_compose_and_sign_work() {
+        let p = Permutation::from_vec(vec![2, 0, 3, 1]).unwrap();
+        let q = Permutation::from_vec(vec![1, 0, 2, 3]).unwrap();
+        let inv = p.inverse();
+        assert_eq!(inv.as_slice(), &[1, 3, 0, 2]);
+        assert_eq!(p.compose(&inv).unwrap(), Permutation::identity(4));
+        assert_eq!(inv.compose(&p).unwrap(), Permutation::identity(4));
+        assert_eq!(p.compose(&q).unwrap().as_slice(), &[2, 1, 3, 0]);
+        assert_eq!(
+            p.compose(&Permutation::identity(3)).err(),
+            Some("permutations must have the same dimension")
+        );
+
+        // p ⋅ (q ⋅ v) = (p ∘ q) ⋅ v
+        let v = Vector::from(&[10.0, 20.0, 30.0, 40.0]);
+        let mut qv = Vector::new(4);
+        let mut pqv = Vector::new(4);
+        let mut pq_v = Vector::new(4);
+        q.apply_vec(&mut qv, &v).unwrap();
+        p.apply_vec(&mut pqv, &qv).unwrap();
+        p.compose(&q).unwrap().apply_vec(&mut pq_v, &v).unwrap();
+        assert_eq!(pqv.as_data(), pq_v.as_data());
+
+        // signs: p is a 4-cycle (odd) and q is a transposition (odd)
+        assert_eq!(Permutation::identity(4).sign(), 1.0);
+        assert_eq!(Permutation::identity(0).sign(), 1.0);
+        assert_eq!(p.sign(), -1.0);
+        assert_eq!(q.sign(), -1.0);
+        assert_eq!(p.compose(&q).unwrap().sign(), 1.0);
+        assert_eq!(inv.sign(), p.sign());
+    }
+
+    #[test]
+    fn apply_captures_errors() {
+        let p = Permutation::identity(2);
+        assert_eq!(
+            p.apply_vec(&mut Vector::new(2), &Vector::new(3)).err(),
+            Some("vectors must have dimension equal to the dimension of the permutation")
+        );
+        assert_eq!(
+            p.apply_mat_rows(&mut Matrix::new(3, 2), &Matrix::new(3, 2)).err(),
+            Some("the number of rows must equal the dimension of the permutation")
+        );
+        assert_eq!(
+            p.apply_mat_rows(&mut Matrix::new(2, 3), &Matrix::new(2, 2)).err(),
+            Some("matrices are incompatible")
+        );
+        assert_eq!(
+            p.apply_mat_cols(&mut Matrix::new(2, 3), &Matrix::new(2, 3)).err(),
+            Some("the number of columns must equal the dimension of the permutation")
+        );
+        assert_eq!(
+            p.apply_mat_cols(&mut Matrix::new(3, 2), &Matrix::new(2, 2)).err(),
+            Some("matrices are incompatible")
+        );
+    }
+
+    #[test]
+    fn apply_works() {
+        let p = Permutation::from_vec(vec![2, 0, 1]).unwrap();
+        let mut u = Vector::new(3);
+        p.apply_vec(&mut u, &Vector::from(&[10.0, 20.0, 30.0])).unwrap();
+        assert_eq!(u.as_data(), &[30.0, 10.0, 20.0]);
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]);
+        let mut b = Matrix::new(3, 3);
+        p.apply_mat_rows(&mut b, &a).unwrap();
+        assert_eq!(
+            format!("{}", b),
+            "┌       ┐\n\
+             │ 7 8 9 │\n\
+             │ 1 2 3 │\n\
+             │ 4 5 6 │\n\
+             └       ┘"
+        );
+        p.apply_mat_cols(&mut b, &a).unwrap();
+        assert_eq!(
+            format!("{}", b),
+            "┌       ┐\n\
+             │ 3 1 2 │\n\
+             │ 6 4 5 │\n\
+             │ 9 7 8 │\n\
+             └       ┘"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::StrError;
+
+/// Implements a polynomial with real coefficients
+///
+/// ```text
+/// p(x) = c₀ + c₁ x + c₂ x² + … + cₙ xⁿ
+/// ```
+///
+/// The coefficients are stored in increasing order of the power of `x`.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Polynomial, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // p(x) = 1 - 2x + 3x²
+///     let p = Polynomial::new(&[1.0, -2.0, 3.0])?;
+///     assert_eq!(p.degree(), 2);
+///     assert_eq!(p.eval(2.0), 9.0);
+///     assert_eq!(p.deriv(2.0), 10.0);
+///     assert_eq!(p.derivative().coefficients(), &[-2.0, 6.0]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<f64>, // c₀, c₁, …, cₙ
+}
+
+impl Polynomial {
+    /// Allocates a new instance
+    ///
+    /// # Input
+    ///
+    /// * `coefficients` -- the coefficients `c₀, c₁, …, cₙ` (at least one)
+    pub fn new(coefficients: &[f64]) -> Result<Self, StrError> {
+        if coefficients.is_empty() {
+            return Err("at least one coefficient is required");
+        }
+        Ok(Polynomial {
+            coefficients: coefficients.to_vec(),
+        })
+    }
+
+    /// Returns the degree `n` (the number of coefficients minus one)
+    ///
+    /// **Note:** Trailing zero coefficients are not removed.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Returns the coefficients `c₀, c₁, …, cₙ`
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Evaluates the polynomial at x (Horner's method)
+    pub fn eval(&self, x: f64) -> f64 {
+        self.coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+    }
+
+    /// Evaluates the derivative of the polynomial at x
+    pub fn deriv(&self, x: f64) -> f64 {
+        let mut acc = 0.0;
+        for k in (1..self.coefficients.len()).rev() {
+            acc = acc * x + (k as f64) * self.coefficients[k];
+        }
+        acc
+    }
+
+    /// Returns the derivative polynomial
+    pub fn derivative(&self) -> Polynomial {
+        if self.coefficients.len() == 1 {
+            return Polynomial {
+                coefficients: vec![0.0],
+            };
+        }
+        Polynomial {
+            coefficients: (1..self.coefficients.len())
+                .map(|k| (k as f64) * self.coefficients[k])
+                .collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Polynomial;
+
+    #[test]
+    fn new_fails_on_wrong_input() {
+        assert_eq!(Polynomial::new(&[]).err(), Some("at least one coefficient is required"));
+    }
+
+    #[test]
+    fn polynomial_works() {
+        let c = Polynomial::new(&[4.0]).unwrap();
+        assert_eq!(c.degree(), 0);
+        assert_eq!(c.eval(10.0), 4.0);
+        assert_eq!(c.deriv(10.0), 0.0);
+        assert_eq!(c.derivative().coefficients(), &[0.0]);
+
+        // p(x) = (x - 1)³ = -1 + 3x - 3x² + x³
+        let p = Polynomial::new(&[-1.0, 3.0, -3.0, 1.0]).unwrap();
+        assert_eq!(p.degree(), 3);
+        for x in [-2.0, 0.0, 0.5, 1.0, 3.0] {
+            assert_eq!(p.eval(x), f64::powi(x - 1.0, 3));
+            assert_eq!(p.deriv(x), 3.0 * f64::powi(x - 1.0, 2));
+            assert_eq!(p.derivative().eval(x), p.deriv(x));
+        }
+    }
+}
@@ -8,6 +8,7 @@ pub use crate::generators::*;
 pub use crate::matrix::*;
 pub use crate::matvec::*;
 pub use crate::read_table::*;
+pub use crate::small::*;
 pub use crate::sort::*;
 pub use crate::stopwatch::*;
 pub use crate::vector::*;
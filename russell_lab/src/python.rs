@@ -0,0 +1,246 @@
+//! Python bindings (requires the `python` feature)
+//!
+//! This module defines the Python classes `Vector` and `Matrix` and a few functions.
+//! The classes are registered into a Python module by [register]; for instance, the
+//! `russell` extension module of russell_sparse calls this function.
+//!
+//! ```text
+//! from russell import Matrix, Vector, solve_lin_sys
+//! a = Matrix.from_rows([[2.0, 0.0], [0.0, 4.0]])
+//! x = solve_lin_sys(a, Vector.from_list([2.0, 8.0]))
+//! print(x.to_list()) # [1.0, 2.0]
+//! ```
+
+use crate::{mat_vec_mul, solve_lin_sys, vec_norm, Matrix, Norm, RussellError, Vector};
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+
+/// Converts an error (StrError or RussellError) into a Python ValueError
+pub fn to_py_err<E: Into<RussellError>>(err: E) -> PyErr {
+    PyValueError::new_err(err.into().to_string())
+}
+
+/// Holds a Vector (Python class `Vector`)
+#[pyclass(name = "Vector")]
+#[derive(Clone)]
+pub struct PyVector {
+    pub data: Vector,
+}
+
+#[pymethods]
+impl PyVector {
+    /// Allocates a vector filled with zeros
+    #[new]
+    pub fn new(dim: usize) -> Self {
+        PyVector { data: Vector::new(dim) }
+    }
+
+    /// Allocates a vector from a list of values
+    #[staticmethod]
+    pub fn from_list(values: Vec<f64>) -> Self {
+        PyVector {
+            data: Vector::from(&values),
+        }
+    }
+
+    /// Returns the dimension
+    pub fn dim(&self) -> usize {
+        self.data.dim()
+    }
+
+    /// Returns the values as a list
+    pub fn to_list(&self) -> Vec<f64> {
+        self.data.as_data().clone()
+    }
+
+    /// Computes the norm; kind is one of "euc", "fro", "inf", "max", or "one"
+    #[pyo3(signature = (kind = "euc"))]
+    pub fn norm(&self, kind: &str) -> PyResult<f64> {
+        Ok(vec_norm(&self.data, parse_norm(kind)?))
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.data.dim()
+    }
+
+    pub fn __getitem__(&self, i: usize) -> PyResult<f64> {
+        if i >= self.data.dim() {
+            return Err(PyIndexError::new_err("index is out of bounds"));
+        }
+        Ok(self.data[i])
+    }
+
+    pub fn __setitem__(&mut self, i: usize, value: f64) -> PyResult<()> {
+        if i >= self.data.dim() {
+            return Err(PyIndexError::new_err("index is out of bounds"));
+        }
+        self.data[i] = value;
+        Ok(())
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("{}", self.data)
+    }
+}
+
+/// Holds a Matrix (Python class `Matrix`)
+#[pyclass(name = "Matrix")]
+#[derive(Clone)]
+pub struct PyMatrix {
+    pub data: Matrix,
+}
+
+#[pymethods]
+impl PyMatrix {
+    /// Allocates a matrix filled with zeros
+    #[new]
+    pub fn new(nrow: usize, ncol: usize) -> Self {
+        PyMatrix {
+            data: Matrix::new(nrow, ncol),
+        }
+    }
+
+    /// Allocates a matrix from a list of rows
+    #[staticmethod]
+    pub fn from_rows(rows: Vec<Vec<f64>>) -> PyResult<Self> {
+        if let Some(first) = rows.first() {
+            if rows.iter().any(|row| row.len() != first.len()) {
+                return Err(to_py_err("all rows must have the same number of columns"));
+            }
+        }
+        Ok(PyMatrix {
+            data: Matrix::from(&rows),
+        })
+    }
+
+    /// Returns the number of rows
+    pub fn nrow(&self) -> usize {
+        self.data.nrow()
+    }
+
+    /// Returns the number of columns
+    pub fn ncol(&self) -> usize {
+        self.data.ncol()
+    }
+
+    /// Returns the (i,j) component
+    pub fn get(&self, i: usize, j: usize) -> PyResult<f64> {
+        self.check_indices(i, j)?;
+        Ok(self.data.get(i, j))
+    }
+
+    /// Sets the (i,j) component
+    pub fn set(&mut self, i: usize, j: usize, value: f64) -> PyResult<()> {
+        self.check_indices(i, j)?;
+        self.data.set(i, j, value);
+        Ok(())
+    }
+
+    /// Returns the values as a list of rows
+    pub fn to_rows(&self) -> Vec<Vec<f64>> {
+        let (m, n) = self.data.dims();
+        (0..m).map(|i| (0..n).map(|j| self.data.get(i, j)).collect()).collect()
+    }
+
+    /// Computes the matrix-vector multiplication v = a ⋅ u
+    pub fn mat_vec_mul(&self, u: &PyVector) -> PyResult<PyVector> {
+        let mut v = Vector::new(self.data.nrow());
+        mat_vec_mul(&mut v, 1.0, &self.data, &u.data).map_err(to_py_err)?;
+        Ok(PyVector { data: v })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("{}", self.data)
+    }
+}
+
+impl PyMatrix {
+    fn check_indices(&self, i: usize, j: usize) -> PyResult<()> {
+        if i >= self.data.nrow() || j >= self.data.ncol() {
+            return Err(PyIndexError::new_err("indices are out of bounds"));
+        }
+        Ok(())
+    }
+}
+
+/// Solves the dense linear system a ⋅ x = b (the arguments are not modified)
+#[pyfunction(name = "solve_lin_sys")]
+pub fn py_solve_lin_sys(a: &PyMatrix, b: &PyVector) -> PyResult<PyVector> {
+    let mut aa = a.data.clone();
+    let mut x = b.data.clone();
+    solve_lin_sys(&mut x, &mut aa).map_err(to_py_err)?;
+    Ok(PyVector { data: x })
+}
+
+/// Returns the norm option corresponding to a name
+fn parse_norm(kind: &str) -> PyResult<Norm> {
+    match kind {
+        "euc" => Ok(Norm::Euc),
+        "fro" => Ok(Norm::Fro),
+        "inf" => Ok(Norm::Inf),
+        "max" => Ok(Norm::Max),
+        "one" => Ok(Norm::One),
+        _ => Err(to_py_err("the norm kind must be one of: euc, fro, inf, max, one")),
+    }
+}
+
+/// Registers the classes and functions of this module into a Python module
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyVector>()?;
+    m.add_class::<PyMatrix>()?;
+    m.add_function(wrap_pyfunction!(py_solve_lin_sys, m)?)?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_norm, PyMatrix, PyVector};
+    use crate::Norm;
+
+    #[test]
+    fn py_vector_works() {
+        let mut v = PyVector::from_list(vec![3.0, 4.0]);
+        assert_eq!(v.dim(), 2);
+        assert_eq!(v.__len__(), 2);
+        assert_eq!(v.__getitem__(1).unwrap(), 4.0);
+        assert!(v.__getitem__(2).is_err());
+        v.__setitem__(0, 1.0).unwrap();
+        assert!(v.__setitem__(2, 1.0).is_err());
+        assert_eq!(v.to_list(), &[1.0, 4.0]);
+        assert_eq!(PyVector::new(3).to_list(), &[0.0, 0.0, 0.0]);
+        assert_eq!(v.norm("max").unwrap(), 4.0);
+        assert!(v.norm("wrong").is_err());
+    }
+
+    #[test]
+    fn py_matrix_works() {
+        assert!(PyMatrix::from_rows(vec![vec![1.0, 2.0], vec![3.0]]).is_err());
+        let mut a = PyMatrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!((a.nrow(), a.ncol()), (2, 2));
+        assert_eq!(a.get(1, 0).unwrap(), 3.0);
+        assert!(a.get(2, 0).is_err());
+        a.set(0, 1, 5.0).unwrap();
+        assert!(a.set(0, 2, 5.0).is_err());
+        assert_eq!(a.to_rows(), vec![vec![1.0, 5.0], vec![3.0, 4.0]]);
+        assert_eq!(
+            a.__repr__(),
+            "┌     ┐\n\
+             │ 1 5 │\n\
+             │ 3 4 │\n\
+             └     ┘"
+        );
+        assert_eq!(PyMatrix::new(1, 2).to_rows(), vec![vec![0.0, 0.0]]);
+    }
+
+    #[test]
+    fn parse_norm_works() {
+        assert_eq!(parse_norm("euc").unwrap(), Norm::Euc);
+        assert_eq!(parse_norm("fro").unwrap(), Norm::Fro);
+        assert_eq!(parse_norm("inf").unwrap(), Norm::Inf);
+        assert_eq!(parse_norm("max").unwrap(), Norm::Max);
+        assert_eq!(parse_norm("one").unwrap(), Norm::One);
+        assert!(parse_norm("two").is_err());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::StrError;
+use std::fmt;
+
+/// Defines a typed error shared by all Russell crates
+///
+/// Most functions in Russell return [StrError] (a static string); however, [RussellError]
+/// (returned by the sparse Solver, for instance) carries additional context (e.g., indices, dimensions and backend codes), allowing
+/// downstream code to match on error kinds programmatically instead of comparing strings.
+///
+/// # Conversions
+///
+/// * Any [StrError] converts into [RussellError::Message]
+/// * Any [RussellError] converts back into a [StrError] via [RussellError::as_str];
+///   thus, the `?` operator works in functions returning either type
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{RussellError, StrError};
+///
+/// fn get(data: &[f64], index: usize) -> Result<f64, RussellError> {
+///     if index >= data.len() {
+///         return Err(RussellError::IndexOutOfBounds { index, dim: data.len() });
+///     }
+///     Ok(data[index])
+/// }
+///
+/// fn legacy(data: &[f64]) -> Result<f64, StrError> {
+///     let value = get(data, 3)?; // converts into StrError
+///     Ok(value)
+/// }
+///
+/// fn main() {
+///     let data = &[1.0, 2.0];
+///     match get(data, 5) {
+///         Err(RussellError::IndexOutOfBounds { index, dim }) => assert_eq!((index, dim), (5, 2)),
+///         _ => panic!("should fail"),
+///     }
+///     assert_eq!(legacy(data).err(), Some("index is out of bounds"));
+///     let err: RussellError = "something went wrong".into();
+///     assert_eq!(format!("{}", err), "something went wrong");
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RussellError {
+    /// Generic error described by a message (e.g., converted from [StrError])
+    Message(StrError),
+
+    /// An index is out of bounds
+    IndexOutOfBounds {
+        /// The offending index
+        index: usize,
+        /// The dimension (upper bound, exclusive)
+        dim: usize,
+    },
+
+    /// The dimensions of vectors, matrices or other structures are incompatible
+    DimensionMismatch {
+        /// The expected dimension
+        expected: usize,
+        /// The given dimension
+        found: usize,
+    },
+
+    /// An external library (e.g., LAPACK, MUMPS, UMFPACK) returned an error code
+    Backend {
+        /// The name of the external library
+        backend: &'static str,
+        /// The code returned by the external library
+        code: i32,
+        /// The description of the error
+        message: StrError,
+    },
+
+    /// The time limit of a computation phase has been exceeded
+    Timeout {
+        /// The name of the phase (e.g., "Factorize")
+        phase: &'static str,
+        /// The elapsed time in nanoseconds
+        elapsed: u128,
+        /// The time limit in nanoseconds
+        limit: u128,
+    },
+
+    /// A computation phase has been cancelled (e.g., from another thread)
+    Cancelled {
+        /// The name of the phase (e.g., "Factorize")
+        phase: &'static str,
+    },
+}
+
+impl RussellError {
+    /// Returns a static description of the error (without the context data)
+    pub fn as_str(&self) -> StrError {
+        match self {
+            RussellError::Message(message) => message,
+            RussellError::IndexOutOfBounds { .. } => "index is out of bounds",
+            RussellError::DimensionMismatch { .. } => "dimensions are incompatible",
+            RussellError::Backend { message, .. } => message,
+            RussellError::Timeout { .. } => "the time limit has been exceeded",
+            RussellError::Cancelled { .. } => "the computation has been cancelled",
+        }
+    }
+}
+
+impl fmt::Display for RussellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RussellError::Message(message) => write!(f, "{}", message),
+            RussellError::IndexOutOfBounds { index, dim } => {
+                write!(f, "index is out of bounds: index = {}, dim = {}", index, dim)
+            }
+            RussellError::DimensionMismatch { expected, found } => write!(
+                f,
+                "dimensions are incompatible: expected = {}, found = {}",
+                expected, found
+            ),
+            RussellError::Backend { backend, code, message } => {
+                write!(f, "{} failed with code {}: {}", backend, code, message)
+            }
+            RussellError::Timeout { phase, elapsed, limit } => write!(
+                f,
+                "the time limit has been exceeded: phase = {}, elapsed = {}ns, limit = {}ns",
+                phase, elapsed, limit
+            ),
+            RussellError::Cancelled { phase } => {
+                write!(f, "the computation has been cancelled: phase = {}", phase)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RussellError {}
+
+impl From<StrError> for RussellError {
+    fn from(message: StrError) -> Self {
+        RussellError::Message(message)
+    }
+}
+
+impl From<RussellError> for StrError {
+    fn from(err: RussellError) -> Self {
+        err.as_str()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::RussellError;
+    use crate::StrError;
+
+    fn fails_with_str() -> Result<(), StrError> {
+        Err("str error")
+    }
+
+    fn fails_with_russell_error() -> Result<(), RussellError> {
+        fails_with_str()?;
+        Ok(())
+    }
+
+    fn fails_with_str_again() -> Result<(), StrError> {
+        Err(RussellError::DimensionMismatch { expected: 3, found: 2 })?;
+        Ok(())
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(fails_with_russell_error(), Err(RussellError::Message("str error")));
+        assert_eq!(fails_with_str_again(), Err("dimensions are incompatible"));
+        let err: StrError = RussellError::Backend {
+            backend: "LAPACK",
+            code: -1,
+            message: "LAPACK dgesv failed",
+        }
+        .into();
+        assert_eq!(err, "LAPACK dgesv failed");
+    }
+
+    #[test]
+    fn as_str_works() {
+        assert_eq!(RussellError::Message("hello").as_str(), "hello");
+        assert_eq!(
+            RussellError::IndexOutOfBounds { index: 1, dim: 1 }.as_str(),
+            "index is out of bounds"
+        );
+        assert_eq!(
+            RussellError::DimensionMismatch { expected: 1, found: 2 }.as_str(),
+            "dimensions are incompatible"
+        );
+        assert_eq!(
+            RussellError::Timeout {
+                phase: "Factorize",
+                elapsed: 2,
+                limit: 1
+            }
+            .as_str(),
+            "the time limit has been exceeded"
+        );
+        assert_eq!(
+            RussellError::Cancelled { phase: "Solve" }.as_str(),
+            "the computation has been cancelled"
+        );
+    }
+
+    #[test]
+    fn display_trait_works() {
+        assert_eq!(format!("{}", RussellError::Message("hello")), "hello");
+        assert_eq!(
+            format!("{}", RussellError::IndexOutOfBounds { index: 3, dim: 2 }),
+            "index is out of bounds: index = 3, dim = 2"
+        );
+        assert_eq!(
+            format!("{}", RussellError::DimensionMismatch { expected: 3, found: 2 }),
+            "dimensions are incompatible: expected = 3, found = 2"
+        );
+        let err = RussellError::Backend {
+            backend: "UMFPACK",
+            code: 1,
+            message: "Error(1): Matrix is singular",
+        };
+        assert_eq!(
+            format!("{}", err),
+            "UMFPACK failed with code 1: Error(1): Matrix is singular"
+        );
+        let err = RussellError::Timeout {
+            phase: "Factorize",
+            elapsed: 2000,
+            limit: 1000,
+        };
+        assert_eq!(
+            format!("{}", err),
+            "the time limit has been exceeded: phase = Factorize, elapsed = 2000ns, limit = 1000ns"
+        );
+        assert_eq!(
+            format!("{}", RussellError::Cancelled { phase: "Solve" }),
+            "the computation has been cancelled: phase = Solve"
+        );
+    }
+}
@@ -0,0 +1,6 @@
+//! This module contains small fixed-size vectors and matrices stored on the stack
+
+mod small_matrix;
+mod small_vector;
+pub use crate::small::small_matrix::*;
+pub use crate::small::small_vector::*;
@@ -0,0 +1,542 @@
+use super::SmallVector;
+use crate::{Matrix, StrError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// constants
+const ZERO_DETERMINANT: f64 = 1e-15;
+
+/// Implements a small fixed-size (M,N) matrix with the components stored on the stack
+///
+/// # Remarks
+///
+/// * SmallMatrix is meant for geometry and tensor calculations in 2D, 3D, or 4D,
+///   where allocating a [Matrix] on the heap is overkill
+/// * The dimensions are const generic parameters; thus, the loops in the kernels
+///   (e.g., `mat_vec_mul`, `mat_mat_mul`) have fixed lengths and are unrolled by the compiler
+/// * The determinant and inverse are implemented with explicit (unrolled) formulae for
+///   the (2,2), (3,3), and (4,4) matrices
+/// * Unlike [Matrix], the components are stored in **row-major** order
+/// * SmallMatrix implements Copy; thus, it can be passed around by value
+/// * Use [SmallMatrix::to_matrix] and [SmallMatrix::from_matrix] to convert
+///   to/from a (heap-allocated) [Matrix]
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{SmallMatrix, SmallVector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // rotation by 90 degrees about z
+///     let r = SmallMatrix::from([
+///         [0.0, -1.0, 0.0],
+///         [1.0,  0.0, 0.0],
+///         [0.0,  0.0, 1.0],
+///     ]);
+///     assert_eq!(r.determinant(), 1.0);
+///
+///     // rotate a vector
+///     let u = SmallVector::from([1.0, 0.0, 0.0]);
+///     let v = r.mat_vec_mul(&u);
+///     assert_eq!(v.as_data(), &[0.0, 1.0, 0.0]);
+///
+///     // the inverse of a rotation is its transpose
+///     assert_eq!(r.inverse()?, r.transpose());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SmallMatrix<const M: usize, const N: usize> {
+    data: [[f64; N]; M],
+}
+
+impl<const M: usize, const N: usize> SmallMatrix<M, N> {
+    /// Creates a new (zeroed) matrix
+    #[inline]
+    pub const fn new() -> Self {
+        SmallMatrix { data: [[0.0; N]; M] }
+    }
+
+    /// Creates a new matrix completely filled with the same value
+    #[inline]
+    pub const fn filled(value: f64) -> Self {
+        SmallMatrix { data: [[value; N]; M] }
+    }
+
+    /// Creates a new matrix from an array of rows
+    #[inline]
+    pub const fn from(data: [[f64; N]; M]) -> Self {
+        SmallMatrix { data }
+    }
+
+    /// Creates a new matrix from a (heap-allocated) Matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::{Matrix, SmallMatrix, StrError};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let b = SmallMatrix::<2, 3>::from_matrix(&a)?;
+    ///     assert_eq!(b.get(1, 0), 4.0);
+    ///     assert_eq!(b.to_matrix(), a);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_matrix(a: &Matrix) -> Result<Self, StrError> {
+        if a.dims() != (M, N) {
+            return Err("matrix has incompatible dimensions");
+        }
+        let mut data = [[0.0; N]; M];
+        for (i, row) in data.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = a.get(i, j);
+            }
+        }
+        Ok(SmallMatrix { data })
+    }
+
+    /// Returns a (heap-allocated) Matrix with a copy of the components
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::from(&self.data)
+    }
+
+    /// Returns the number of rows
+    #[inline]
+    pub const fn nrow(&self) -> usize {
+        M
+    }
+
+    /// Returns the number of columns
+    #[inline]
+    pub const fn ncol(&self) -> usize {
+        N
+    }
+
+    /// Returns the dimensions (nrow, ncol) of this matrix
+    #[inline]
+    pub const fn dims(&self) -> (usize, usize) {
+        (M, N)
+    }
+
+    /// Returns an access to the underlying array of rows
+    #[inline]
+    pub fn as_data(&self) -> &[[f64; N]; M] {
+        &self.data
+    }
+
+    /// Returns a mutable access to the underlying array of rows
+    #[inline]
+    pub fn as_mut_data(&mut self) -> &mut [[f64; N]; M] {
+        &mut self.data
+    }
+
+    /// Returns the (i,j) component
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the indices are out-of-bounds.
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        self.data[i][j]
+    }
+
+    /// Change the (i,j) component
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the indices are out-of-bounds.
+    #[inline]
+    pub fn set(&mut self, i: usize, j: usize, value: f64) {
+        self.data[i][j] = value;
+    }
+
+    /// Fills this matrix with a given value
+    #[inline]
+    pub fn fill(&mut self, value: f64) {
+        self.data = [[value; N]; M];
+    }
+
+    /// Scales this matrix
+    ///
+    /// ```text
+    /// a := α⋅a
+    /// ```
+    #[inline]
+    pub fn scale(&mut self, alpha: f64) {
+        for row in self.data.iter_mut() {
+            for value in row.iter_mut() {
+                *value *= alpha;
+            }
+        }
+    }
+
+    /// Updates this matrix with another (scaled) matrix
+    ///
+    /// ```text
+    /// a += α⋅b
+    /// ```
+    #[inline]
+    pub fn update(&mut self, alpha: f64, b: &Self) {
+        for (row, row_b) in self.data.iter_mut().zip(&b.data) {
+            for (value, value_b) in row.iter_mut().zip(row_b) {
+                *value += alpha * value_b;
+            }
+        }
+    }
+
+    /// Returns the transpose matrix
+    #[inline]
+    pub fn transpose(&self) -> SmallMatrix<N, M> {
+        let mut data = [[0.0; M]; N];
+        for (i, row) in self.data.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                data[j][i] = *value;
+            }
+        }
+        SmallMatrix { data }
+    }
+
+    /// Performs the matrix-vector multiplication
+    ///
+    /// ```text
+    ///  v  :=  a  ⋅  u
+    /// (m)   (m,n)  (n)
+    /// ```
+    #[inline]
+    pub fn mat_vec_mul(&self, u: &SmallVector<N>) -> SmallVector<M> {
+        let mut v = SmallVector::<M>::new();
+        for (vi, row) in v.as_mut_data().iter_mut().zip(&self.data) {
+            *vi = row.iter().zip(u.as_data()).map(|(aij, uj)| aij * uj).sum();
+        }
+        v
+    }
+
+    /// Performs the matrix-matrix multiplication
+    ///
+    /// ```text
+    ///   c   :=   a   ⋅   b
+    /// (m,p)    (m,n)   (n,p)
+    /// ```
+    #[inline]
+    pub fn mat_mat_mul<const P: usize>(&self, b: &SmallMatrix<N, P>) -> SmallMatrix<M, P> {
+        let mut data = [[0.0; P]; M];
+        for (row_c, row_a) in data.iter_mut().zip(&self.data) {
+            for (aik, row_b) in row_a.iter().zip(&b.data) {
+                for (cij, bkj) in row_c.iter_mut().zip(row_b) {
+                    *cij += aik * bkj;
+                }
+            }
+        }
+        SmallMatrix { data }
+    }
+
+    /// Returns the Frobenius norm
+    ///
+    /// ```text
+    /// ‖a‖_F = sqrt(Σ_i Σ_j aij⋅aij)
+    /// ```
+    #[inline]
+    pub fn norm(&self) -> f64 {
+        let sum: f64 = self.data.iter().flatten().map(|v| v * v).sum();
+        f64::sqrt(sum)
+    }
+}
+
+impl<const N: usize> SmallMatrix<N, N> {
+    /// Creates the identity matrix
+    #[inline]
+    pub fn identity() -> Self {
+        let mut data = [[0.0; N]; N];
+        for (i, row) in data.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+        SmallMatrix { data }
+    }
+
+    /// Returns the trace (sum of the diagonal components)
+    #[inline]
+    pub fn trace(&self) -> f64 {
+        self.data.iter().enumerate().map(|(i, row)| row[i]).sum()
+    }
+}
+
+impl SmallMatrix<2, 2> {
+    /// Returns the determinant
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        let a = &self.data;
+        a[0][0] * a[1][1] - a[0][1] * a[1][0]
+    }
+
+    /// Returns the inverse matrix
+    ///
+    /// **Note:** Returns an error if the absolute value of the determinant is smaller than or equal to 1e-15
+    pub fn inverse(&self) -> Result<Self, StrError> {
+        let det = self.determinant();
+        if f64::abs(det) <= ZERO_DETERMINANT {
+            return Err("cannot compute inverse due to zero determinant");
+        }
+        let a = &self.data;
+        Ok(SmallMatrix {
+            data: [[a[1][1] / det, -a[0][1] / det], [-a[1][0] / det, a[0][0] / det]],
+        })
+    }
+}
+
+impl SmallMatrix<3, 3> {
+    /// Returns the determinant
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        let a = &self.data;
+        a[0][0] * (a[1][1] * a[2][2] - a[1][2] * a[2][1]) - a[0][1] * (a[1][0] * a[2][2] - a[1][2] * a[2][0])
+            + a[0][2] * (a[1][0] * a[2][1] - a[1][1] * a[2][0])
+    }
+
+    /// Returns the inverse matrix
+    ///
+    /// **Note:** Returns an error if the absolute value of the determinant is smaller than or equal to 1e-15
+    pub fn inverse(&self) -> Result<Self, StrError> {
+        let det = self.determinant();
+        if f64::abs(det) <= ZERO_DETERMINANT {
+            return Err("cannot compute inverse due to zero determinant");
+        }
+        let a = &self.data;
+        #[rustfmt::skip]
+        let data = [
+            [
+                (a[1][1] * a[2][2] - a[1][2] * a[2][1]) / det,
+                (a[0][2] * a[2][1] - a[0][1] * a[2][2]) / det,
+                (a[0][1] * a[1][2] - a[0][2] * a[1][1]) / det,
+            ],
+            [
+                (a[1][2] * a[2][0] - a[1][0] * a[2][2]) / det,
+                (a[0][0] * a[2][2] - a[0][2] * a[2][0]) / det,
+                (a[0][2] * a[1][0] - a[0][0] * a[1][2]) / det,
+            ],
+            [
+                (a[1][0] * a[2][1] - a[1][1] * a[2][0]) / det,
+                (a[0][1] * a[2][0] - a[0][0] * a[2][1]) / det,
+                (a[0][0] * a[1][1] - a[0][1] * a[1][0]) / det,
+            ],
+        ];
+        Ok(SmallMatrix { data })
+    }
+}
+
+impl SmallMatrix<4, 4> {
+    /// Returns the 2×2 minors of the first two rows (s) and of the last two rows (c)
+    #[inline]
+    fn minors(&self) -> ([f64; 6], [f64; 6]) {
+        let a = &self.data;
+        let s = [
+            a[0][0] * a[1][1] - a[1][0] * a[0][1],
+            a[0][0] * a[1][2] - a[1][0] * a[0][2],
+            a[0][0] * a[1][3] - a[1][0] * a[0][3],
+            a[0][1] * a[1][2] - a[1][1] * a[0][2],
+            a[0][1] * a[1][3] - a[1][1] * a[0][3],
+            a[0][2] * a[1][3] - a[1][2] * a[0][3],
+        ];
+        let c = [
+            a[2][0] * a[3][1] - a[3][0] * a[2][1],
+            a[2][0] * a[3][2] - a[3][0] * a[2][2],
+            a[2][0] * a[3][3] - a[3][0] * a[2][3],
+            a[2][1] * a[3][2] - a[3][1] * a[2][2],
+            a[2][1] * a[3][3] - a[3][1] * a[2][3],
+            a[2][2] * a[3][3] - a[3][2] * a[2][3],
+        ];
+        (s, c)
+    }
+
+    /// Returns the determinant
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Returns the inverse matrix
+    ///
+    /// **Note:** Returns an error if the absolute value of the determinant is smaller than or equal to 1e-15
+    pub fn inverse(&self) -> Result<Self, StrError> {
+        let (s, c) = self.minors();
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if f64::abs(det) <= ZERO_DETERMINANT {
+            return Err("cannot compute inverse due to zero determinant");
+        }
+        let a = &self.data;
+        #[rustfmt::skip]
+        let data = [
+            [
+                ( a[1][1] * c[5] - a[1][2] * c[4] + a[1][3] * c[3]) / det,
+                (-a[0][1] * c[5] + a[0][2] * c[4] - a[0][3] * c[3]) / det,
+                ( a[3][1] * s[5] - a[3][2] * s[4] + a[3][3] * s[3]) / det,
+                (-a[2][1] * s[5] + a[2][2] * s[4] - a[2][3] * s[3]) / det,
+            ],
+            [
+                (-a[1][0] * c[5] + a[1][2] * c[2] - a[1][3] * c[1]) / det,
+                ( a[0][0] * c[5] - a[0][2] * c[2] + a[0][3] * c[1]) / det,
+                (-a[3][0] * s[5] + a[3][2] * s[2] - a[3][3] * s[1]) / det,
+                ( a[2][0] * s[5] - a[2][2] * s[2] + a[2][3] * s[1]) / det,
+            ],
+            [
+                ( a[1][0] * c[4] - a[1][1] * c[2] + a[1][3] * c[0]) / det,
+                (-a[0][0] * c[4] + a[0][1] * c[2] - a[0][3] * c[0]) / det,
+                ( a[3][0] * s[4] - a[3][1] * s[2] + a[3][3] * s[0]) / det,
+                (-a[2][0] * s[4] + a[2][1] * s[2] - a[2][3] * s[0]) / det,
+            ],
+            [
+                (-a[1][0] * c[3] + a[1][1] * c[1] - a[1][2] * c[0]) / det,
+                ( a[0][0] * c[3] - a[0][1] * c[1] + a[0][2] * c[0]) / det,
+                (-a[3][0] * s[3] + a[3][1] * s[1] - a[3][2] * s[0]) / det,
+                ( a[2][0] * s[3] - a[2][1] * s[1] + a[2][2] * s[0]) / det,
+            ],
+        ];
+        Ok(SmallMatrix { data })
+    }
+}
+
+impl<const M: usize, const N: usize> Default for SmallMatrix<M, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const M: usize, const N: usize> fmt::Display for SmallMatrix<M, N> {
+    /// Generates a string representation of the SmallMatrix (same as [Matrix])
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_matrix(), f)
+    }
+}
+
+/// Allows to access SmallMatrix components using indices
+impl<const M: usize, const N: usize> Index<(usize, usize)> for SmallMatrix<M, N> {
+    type Output = f64;
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        &self.data[i][j]
+    }
+}
+
+/// Allows to change SmallMatrix components using indices
+impl<const M: usize, const N: usize> IndexMut<(usize, usize)> for SmallMatrix<M, N> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[i][j]
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SmallMatrix;
+    use crate::{mat_approx_eq, Matrix, SmallVector};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn new_and_accessors_work() {
+        let mut a = SmallMatrix::<2, 3>::new();
+        assert_eq!(a.dims(), (2, 3));
+        assert_eq!((a.nrow(), a.ncol()), (2, 3));
+        assert_eq!(a, SmallMatrix::default());
+        a.set(0, 1, 2.0);
+        a[(1, 2)] = 3.0;
+        a.as_mut_data()[1][0] = 4.0;
+        assert_eq!(a.get(0, 1), 2.0);
+        assert_eq!(a[(1, 2)], 3.0);
+        assert_eq!(a.as_data(), &[[0.0, 2.0, 0.0], [4.0, 0.0, 3.0]]);
+        a.fill(1.0);
+        assert_eq!(a, SmallMatrix::filled(1.0));
+        assert_eq!(SmallMatrix::<2, 2>::identity().as_data(), &[[1.0, 0.0], [0.0, 1.0]]);
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(
+            SmallMatrix::<2, 2>::from_matrix(&Matrix::new(2, 3)).err(),
+            Some("matrix has incompatible dimensions")
+        );
+        let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = SmallMatrix::<2, 3>::from_matrix(&a).unwrap();
+        assert_eq!(b.as_data(), &[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(b.to_matrix(), a);
+        assert_eq!(
+            format!("{}", b),
+            "┌       ┐\n\
+             │ 1 2 3 │\n\
+             │ 4 5 6 │\n\
+             └       ┘"
+        );
+    }
+
+    #[test]
+    fn kernels_work() {
+        let mut a = SmallMatrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = SmallMatrix::from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+        assert_eq!(a.transpose().as_data(), &[[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        assert_eq!(a.mat_mat_mul(&b).as_data(), &[[4.0, 5.0], [10.0, 11.0]]);
+        assert_eq!(b.mat_mat_mul(&a).dims(), (3, 3));
+        let v = a.mat_vec_mul(&SmallVector::from([1.0, 1.0, -1.0]));
+        assert_eq!(v.as_data(), &[0.0, 3.0]);
+        approx_eq(a.norm(), f64::sqrt(91.0), 1e-15);
+        a.update(-1.0, &SmallMatrix::filled(1.0));
+        assert_eq!(a.as_data(), &[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        a.scale(2.0);
+        assert_eq!(a.as_data(), &[[0.0, 2.0, 4.0], [6.0, 8.0, 10.0]]);
+        assert_eq!(SmallMatrix::from([[1.0, 2.0], [3.0, 4.0]]).trace(), 5.0);
+    }
+
+    #[test]
+    fn inverse_2x2_works() {
+        let a = SmallMatrix::from([[-1.0, 1.5], [1.0, -1.0]]);
+        assert_eq!(a.determinant(), -0.5);
+        let ai = a.inverse().unwrap();
+        assert_eq!(ai.as_data(), &[[2.0, 3.0], [2.0, 2.0]]);
+        assert_eq!(a.mat_mat_mul(&ai), SmallMatrix::identity());
+        assert_eq!(
+            SmallMatrix::from([[1.0, 2.0], [2.0, 4.0]]).inverse().err(),
+            Some("cannot compute inverse due to zero determinant")
+        );
+    }
+
+    #[test]
+    fn inverse_3x3_works() {
+        let a = SmallMatrix::from([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+        assert_eq!(a.determinant(), 1.0);
+        let ai = a.inverse().unwrap();
+        assert_eq!(
+            ai.as_data(),
+            &[[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]]
+        );
+        assert_eq!(a.mat_mat_mul(&ai), SmallMatrix::identity());
+        assert_eq!(
+            SmallMatrix::<3, 3>::filled(1.0).inverse().err(),
+            Some("cannot compute inverse due to zero determinant")
+        );
+    }
+
+    #[test]
+    fn inverse_4x4_works() {
+        let a = SmallMatrix::from([
+            [4.0, 7.0, 2.0, 3.0],
+            [0.0, 5.0, 0.0, 1.0],
+            [1.0, 0.0, 3.0, 0.0],
+            [2.0, 1.0, 0.0, 6.0],
+        ]);
+        // compare with the cofactor expansion along the second row
+        let minor = |rows: [usize; 3], cols: [usize; 3]| {
+            SmallMatrix::from(rows.map(|i| cols.map(|j| a.get(i, j)))).determinant()
+        };
+        let det = 5.0 * minor([0, 2, 3], [0, 2, 3]) + 1.0 * minor([0, 2, 3], [0, 1, 2]);
+        approx_eq(a.determinant(), det, 1e-13);
+        let ai = a.inverse().unwrap();
+        mat_approx_eq(&a.mat_mat_mul(&ai).to_matrix(), &Matrix::identity(4), 1e-15);
+        mat_approx_eq(&ai.mat_mat_mul(&a).to_matrix(), &Matrix::identity(4), 1e-15);
+        assert_eq!(
+            SmallMatrix::<4, 4>::filled(2.0).inverse().err(),
+            Some("cannot compute inverse due to zero determinant")
+        );
+    }
+}
@@ -0,0 +1,317 @@
+use crate::{StrError, Vector};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Implements a small fixed-size vector with the components stored on the stack
+///
+/// # Remarks
+///
+/// * SmallVector is meant for geometry and tensor calculations in 2D, 3D, or 4D,
+///   where allocating a [Vector] on the heap is overkill
+/// * The dimension is a const generic parameter; thus, the loops in the kernels
+///   (e.g., `inner`, `update`) have a fixed length and are unrolled by the compiler
+/// * SmallVector implements Copy; thus, it can be passed around by value
+/// * Use [SmallVector::to_vector] and [SmallVector::from_vector] to convert
+///   to/from a (heap-allocated) [Vector]
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::SmallVector;
+///
+/// let u = SmallVector::from([1.0, 0.0, 0.0]);
+/// let v = SmallVector::from([0.0, 1.0, 0.0]);
+/// let w = u.cross(&v);
+/// assert_eq!(w.as_data(), &[0.0, 0.0, 1.0]);
+/// assert_eq!(u.inner(&w), 0.0);
+/// assert_eq!(
+///     format!("{}", w),
+///     "┌   ┐\n\
+///      │ 0 │\n\
+///      │ 0 │\n\
+///      │ 1 │\n\
+///      └   ┘"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SmallVector<const N: usize> {
+    data: [f64; N],
+}
+
+impl<const N: usize> SmallVector<N> {
+    /// Creates a new (zeroed) vector
+    #[inline]
+    pub const fn new() -> Self {
+        SmallVector { data: [0.0; N] }
+    }
+
+    /// Creates a new vector completely filled with the same value
+    #[inline]
+    pub const fn filled(value: f64) -> Self {
+        SmallVector { data: [value; N] }
+    }
+
+    /// Creates a new vector from an array
+    #[inline]
+    pub const fn from(data: [f64; N]) -> Self {
+        SmallVector { data }
+    }
+
+    /// Creates a new vector from a (heap-allocated) Vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::{SmallVector, StrError, Vector};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let u = Vector::from(&[1.0, 2.0]);
+    ///     let v = SmallVector::<2>::from_vector(&u)?;
+    ///     assert_eq!(v.as_data(), &[1.0, 2.0]);
+    ///     assert_eq!(v.to_vector(), u);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_vector(u: &Vector) -> Result<Self, StrError> {
+        if u.dim() != N {
+            return Err("vector has incompatible dimension");
+        }
+        let mut data = [0.0; N];
+        data.copy_from_slice(u.as_data());
+        Ok(SmallVector { data })
+    }
+
+    /// Returns a (heap-allocated) Vector with a copy of the components
+    pub fn to_vector(&self) -> Vector {
+        Vector::from(&self.data)
+    }
+
+    /// Returns the dimension of this vector
+    #[inline]
+    pub const fn dim(&self) -> usize {
+        N
+    }
+
+    /// Returns an access to the underlying array
+    #[inline]
+    pub fn as_data(&self) -> &[f64; N] {
+        &self.data
+    }
+
+    /// Returns a mutable access to the underlying array
+    #[inline]
+    pub fn as_mut_data(&mut self) -> &mut [f64; N] {
+        &mut self.data
+    }
+
+    /// Returns the i-th component
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the index is out-of-bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> f64 {
+        self.data[i]
+    }
+
+    /// Change the i-th component
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the index is out-of-bounds.
+    #[inline]
+    pub fn set(&mut self, i: usize, value: f64) {
+        self.data[i] = value;
+    }
+
+    /// Fills this vector with a given value
+    #[inline]
+    pub fn fill(&mut self, value: f64) {
+        self.data = [value; N];
+    }
+
+    /// Scales this vector
+    ///
+    /// ```text
+    /// u := α⋅u
+    /// ```
+    #[inline]
+    pub fn scale(&mut self, alpha: f64) {
+        for value in self.data.iter_mut() {
+            *value *= alpha;
+        }
+    }
+
+    /// Updates this vector with another (scaled) vector
+    ///
+    /// ```text
+    /// u += α⋅v
+    /// ```
+    #[inline]
+    pub fn update(&mut self, alpha: f64, v: &Self) {
+        for (value, value_v) in self.data.iter_mut().zip(&v.data) {
+            *value += alpha * value_v;
+        }
+    }
+
+    /// Returns the linear combination of two vectors
+    ///
+    /// ```text
+    /// w := α⋅u + β⋅v
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::SmallVector;
+    /// let u = SmallVector::from([10.0, 20.0]);
+    /// let v = SmallVector::from([2.0, 1.5]);
+    /// let w = SmallVector::add(0.1, &u, 2.0, &v);
+    /// assert_eq!(w.as_data(), &[5.0, 5.0]);
+    /// ```
+    #[inline]
+    pub fn add(alpha: f64, u: &Self, beta: f64, v: &Self) -> Self {
+        let mut data = [0.0; N];
+        for ((w, a), b) in data.iter_mut().zip(&u.data).zip(&v.data) {
+            *w = alpha * a + beta * b;
+        }
+        SmallVector { data }
+    }
+
+    /// Returns the inner (dot) product with another vector
+    ///
+    /// ```text
+    /// s := u ⋅ v
+    /// ```
+    #[inline]
+    pub fn inner(&self, v: &Self) -> f64 {
+        self.data.iter().zip(&v.data).map(|(a, b)| a * b).sum()
+    }
+
+    /// Returns the Euclidean norm
+    ///
+    /// ```text
+    /// ‖u‖ = √(u ⋅ u)
+    /// ```
+    #[inline]
+    pub fn norm(&self) -> f64 {
+        f64::sqrt(self.inner(self))
+    }
+}
+
+impl SmallVector<3> {
+    /// Returns the cross product with another vector
+    ///
+    /// ```text
+    /// w := u × v
+    /// ```
+    #[inline]
+    pub fn cross(&self, v: &Self) -> Self {
+        let (u, v) = (&self.data, &v.data);
+        SmallVector {
+            data: [
+                u[1] * v[2] - u[2] * v[1],
+                u[2] * v[0] - u[0] * v[2],
+                u[0] * v[1] - u[1] * v[0],
+            ],
+        }
+    }
+}
+
+impl<const N: usize> Default for SmallVector<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Display for SmallVector<N> {
+    /// Generates a string representation of the SmallVector (same as [Vector])
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_vector(), f)
+    }
+}
+
+/// Allows to access SmallVector components using indices
+impl<const N: usize> Index<usize> for SmallVector<N> {
+    type Output = f64;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+/// Allows to change SmallVector components using indices
+impl<const N: usize> IndexMut<usize> for SmallVector<N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SmallVector;
+    use crate::Vector;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn new_and_accessors_work() {
+        let mut u = SmallVector::<3>::new();
+        assert_eq!(u.dim(), 3);
+        assert_eq!(u.as_data(), &[0.0, 0.0, 0.0]);
+        assert_eq!(SmallVector::<2>::default(), SmallVector::from([0.0, 0.0]));
+        u.set(1, 2.0);
+        u[2] = 3.0;
+        assert_eq!(u.get(1), 2.0);
+        assert_eq!(u[2], 3.0);
+        u.as_mut_data()[0] = 1.0;
+        assert_eq!(u.as_data(), &[1.0, 2.0, 3.0]);
+        u.fill(4.0);
+        assert_eq!(u, SmallVector::filled(4.0));
+    }
+
+    #[test]
+    fn conversions_work() {
+        assert_eq!(
+            SmallVector::<3>::from_vector(&Vector::new(2)).err(),
+            Some("vector has incompatible dimension")
+        );
+        let u = SmallVector::<2>::from_vector(&Vector::from(&[1.0, 2.0])).unwrap();
+        assert_eq!(u.as_data(), &[1.0, 2.0]);
+        assert_eq!(u.to_vector().as_data(), &[1.0, 2.0]);
+        assert_eq!(
+            format!("{:.1}", u),
+            "┌     ┐\n\
+             │ 1.0 │\n\
+             │ 2.0 │\n\
+             └     ┘"
+        );
+    }
+
+    #[test]
+    fn kernels_work() {
+        let mut u = SmallVector::from([3.0, 4.0]);
+        let v = SmallVector::from([1.0, -1.0]);
+        assert_eq!(u.inner(&v), -1.0);
+        assert_eq!(u.norm(), 5.0);
+        assert_eq!(SmallVector::add(1.0, &u, 2.0, &v).as_data(), &[5.0, 2.0]);
+        u.update(-3.0, &v);
+        assert_eq!(u.as_data(), &[0.0, 7.0]);
+        u.scale(0.5);
+        assert_eq!(u.as_data(), &[0.0, 3.5]);
+        let w = SmallVector::from([1.0, 2.0, 3.0, 4.0]);
+        approx_eq(w.norm(), f64::sqrt(30.0), 1e-15);
+    }
+
+    #[test]
+    fn cross_works() {
+        let u = SmallVector::from([1.0, 2.0, 3.0]);
+        let v = SmallVector::from([4.0, 5.0, 6.0]);
+        let w = u.cross(&v);
+        assert_eq!(w.as_data(), &[-3.0, 6.0, -3.0]);
+        assert_eq!(w.inner(&u), 0.0);
+        assert_eq!(w.inner(&v), 0.0);
+    }
+}
@@ -6,3 +6,9 @@ pub type Vector = NumVector<f64>;
 
 /// ComplexVector is an alias to NumVector&lt;Complex64&gt; and is used in most functions that call OpenBLAS
 pub type ComplexVector = NumVector<Complex64>;
+
+/// VectorF32 is an alias to NumVector&lt;f32&gt; and is used in the single-precision functions that call OpenBLAS
+///
+/// Single precision halves the memory (and memory bandwidth) required by large vectors at the cost of accuracy
+/// (about 7 significant digits). See, e.g., vec_update_f32.
+pub type VectorF32 = NumVector<f32>;
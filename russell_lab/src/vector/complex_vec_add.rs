@@ -1,8 +1,8 @@
 use super::ComplexVector;
 use crate::constants;
+use crate::kernels::{complex_add_vectors_native, complex_add_vectors_oblas};
 use crate::StrError;
 use num_complex::Complex64;
-use russell_openblas::{complex_add_vectors_native, complex_add_vectors_oblas};
 
 /// Performs the addition of two vectors
 ///
@@ -1,6 +1,6 @@
 use super::ComplexVector;
+use crate::kernels::{to_i32, zcopy};
 use crate::StrError;
-use russell_openblas::{to_i32, zcopy};
 
 /// Copies vector (complex version)
 ///
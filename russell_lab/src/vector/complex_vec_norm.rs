@@ -0,0 +1,81 @@
+use super::ComplexVector;
+use crate::enums::{assert_valid_norm, p_norm_of_abs};
+use crate::kernels::{dznrm2, to_i32};
+use crate::Norm;
+
+/// Returns the vector norm (complex version)
+///
+/// The norms are computed with the modulus `|zᵢ|` of the components; e.g.,
+/// `Norm::One` yields `Σ|zᵢ|` and `Norm::Max` yields `max|zᵢ|`.
+///
+/// # Panics
+///
+/// Panics if `kind` is `Norm::P(p)` with `p < 1` or `p = NaN`.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{complex_vec_norm, ComplexVector, Norm};
+/// use num_complex::Complex64;
+///
+/// fn main() {
+///     let u = ComplexVector::from(&[
+///         Complex64::new(3.0, 4.0),
+///         Complex64::new(0.0, -2.0),
+///         Complex64::new(-1.0, 0.0),
+///     ]);
+///     assert_eq!(complex_vec_norm(&u, Norm::One), 8.0);
+///     assert_eq!(complex_vec_norm(&u, Norm::Euc), f64::sqrt(30.0));
+///     assert_eq!(complex_vec_norm(&u, Norm::Max), 5.0);
+/// }
+/// ```
+pub fn complex_vec_norm(v: &ComplexVector, kind: Norm) -> f64 {
+    assert_valid_norm(kind);
+    let n = to_i32(v.dim());
+    if n == 0 {
+        return 0.0;
+    }
+    let moduli = v.as_data().iter().map(|z| z.norm());
+    match kind {
+        Norm::Euc | Norm::Fro => dznrm2(n, v.as_data(), 1),
+        Norm::Inf | Norm::Max => moduli.fold(0.0, f64::max),
+        Norm::One => moduli.sum(),
+        Norm::P(p) => p_norm_of_abs(moduli, p),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{complex_vec_norm, ComplexVector};
+    use crate::Norm;
+    use num_complex::Complex64;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn complex_vec_norm_works() {
+        let u0 = ComplexVector::new(0);
+        assert_eq!(complex_vec_norm(&u0, Norm::Euc), 0.0);
+        assert_eq!(complex_vec_norm(&u0, Norm::Fro), 0.0);
+        assert_eq!(complex_vec_norm(&u0, Norm::Inf), 0.0);
+        assert_eq!(complex_vec_norm(&u0, Norm::Max), 0.0);
+        assert_eq!(complex_vec_norm(&u0, Norm::One), 0.0);
+        assert_eq!(complex_vec_norm(&u0, Norm::P(3.0)), 0.0);
+
+        let u = ComplexVector::from(&[
+            Complex64::new(0.0, -3.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(1.0, 1.0),
+            Complex64::new(0.0, 1.0),
+        ]);
+        approx_eq(complex_vec_norm(&u, Norm::Euc), 4.0, 1e-15);
+        approx_eq(complex_vec_norm(&u, Norm::Fro), 4.0, 1e-15);
+        assert_eq!(complex_vec_norm(&u, Norm::Inf), 3.0);
+        assert_eq!(complex_vec_norm(&u, Norm::Max), 3.0);
+        approx_eq(complex_vec_norm(&u, Norm::One), 6.0 + f64::sqrt(2.0), 1e-15);
+        approx_eq(complex_vec_norm(&u, Norm::P(1.0)), 6.0 + f64::sqrt(2.0), 1e-15);
+        approx_eq(complex_vec_norm(&u, Norm::P(2.0)), 4.0, 1e-15);
+        assert_eq!(complex_vec_norm(&u, Norm::P(f64::INFINITY)), 3.0);
+    }
+}
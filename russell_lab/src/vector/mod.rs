@@ -3,28 +3,62 @@
 mod aliases;
 mod complex_vec_add;
 mod complex_vec_copy;
+mod complex_vec_norm;
+mod complex_vec_scale;
 mod complex_vec_zip;
 mod num_vector;
+mod simpson;
+mod trapz;
 mod vec_add;
 mod vec_copy;
+mod vec_eq;
+mod vec_gather;
 mod vec_inner;
+#[cfg(feature = "blas")]
+mod vec_inner_f32;
 mod vec_max_abs_diff;
 mod vec_max_scaled;
 mod vec_norm;
 mod vec_rms_scaled;
+mod vec_rolling;
 mod vec_scale;
+#[cfg(feature = "blas")]
+mod vec_scale_f32;
+mod vec_scatter_add;
+mod vec_select;
+mod vec_sum;
 mod vec_update;
+#[cfg(feature = "blas")]
+mod vec_update_f32;
+mod vec_view;
 pub use crate::vector::aliases::*;
 pub use crate::vector::complex_vec_add::*;
 pub use crate::vector::complex_vec_copy::*;
+pub use crate::vector::complex_vec_norm::*;
+pub use crate::vector::complex_vec_scale::*;
 pub use crate::vector::complex_vec_zip::*;
 pub use crate::vector::num_vector::*;
+pub use crate::vector::simpson::*;
+pub use crate::vector::trapz::*;
 pub use crate::vector::vec_add::*;
 pub use crate::vector::vec_copy::*;
+pub use crate::vector::vec_eq::*;
+pub use crate::vector::vec_gather::*;
 pub use crate::vector::vec_inner::*;
+#[cfg(feature = "blas")]
+pub use crate::vector::vec_inner_f32::*;
 pub use crate::vector::vec_max_abs_diff::*;
 pub use crate::vector::vec_max_scaled::*;
 pub use crate::vector::vec_norm::*;
 pub use crate::vector::vec_rms_scaled::*;
+pub use crate::vector::vec_rolling::*;
 pub use crate::vector::vec_scale::*;
+#[cfg(feature = "blas")]
+pub use crate::vector::vec_scale_f32::*;
+pub use crate::vector::vec_scatter_add::*;
+pub use crate::vector::vec_select::*;
+pub use crate::vector::vec_sum::*;
 pub use crate::vector::vec_update::*;
+#[cfg(feature = "blas")]
+pub use crate::vector::vec_update_f32::*;
+pub use crate::vector::vec_view::*;
@@ -17,6 +17,9 @@ use std::ops::{Index, IndexMut};
 /// * For faster computations, we recommend using the set of functions that
 ///   operate on Vectors and Matrices; e.g., `vec_add`, `vec_inner`, `vec_outer`,
 ///   `vec_copy`, `mat_vec_mul`, and others.
+/// * The `==` operator compares the dimensions and the components exactly; for
+///   comparisons with a tolerance, use `russell_chk::vec_approx_eq` or enable the `approx` feature
+///   to use the traits of the [approx](https://docs.rs/approx) crate.
 ///
 /// # Example
 ///
@@ -87,7 +90,7 @@ use std::ops::{Index, IndexMut};
 ///     Ok(())
 /// }
 /// ```
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct NumVector<T>
 where
     T: Num + NumCast + Copy + DeserializeOwned + Serialize,
@@ -909,6 +912,15 @@ mod tests {
         assert_eq!(format!("{:?}", u), "NumVector { data: [0.0] }");
     }
 
+    #[test]
+    fn partial_eq_works() {
+        let u = NumVector::<f64>::from(&[1.0, 2.0, 3.0]);
+        assert_eq!(u, NumVector::<f64>::from(&[1.0, 2.0, 3.0]));
+        assert_ne!(u, NumVector::<f64>::from(&[1.0, 2.0, 3.000001]));
+        assert_ne!(u, NumVector::<f64>::from(&[1.0, 2.0]));
+        assert_ne!(NumVector::<f64>::from(&[f64::NAN]), NumVector::<f64>::from(&[f64::NAN]));
+    }
+
     #[test]
     fn index_works() {
         let mut x = NumVector::<f64>::new(3);
@@ -0,0 +1,124 @@
+use super::Vector;
+use crate::StrError;
+
+/// Integrates tabulated data using the composite Simpson's rule
+///
+/// Each pair of consecutive intervals `h₀ = x₁ - x₀` and `h₁ = x₂ - x₁` is integrated with
+/// the quadratic polynomial passing through the three points; thus, the spacing of `x`
+/// may be non-uniform:
+///
+/// ```text
+/// I₀₂ = (h₀ + h₁)/6 ⋅ [(2 - h₁/h₀) y₀ + (h₀ + h₁)²/(h₀ h₁) y₁ + (2 - h₀/h₁) y₂]
+/// ```
+///
+/// If there is an odd number of intervals, the last interval is integrated with the
+/// quadratic polynomial passing through the last three points. If there are only two
+/// points, the trapezoidal rule is used.
+///
+/// # Note
+///
+/// The `x` values must be strictly increasing.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{simpson, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // cubic polynomials are integrated exactly (with uniform spacing)
+///     let x = Vector::linspace(0.0, 2.0, 5)?;
+///     let y = x.get_mapped(|v| v * v * v);
+///     assert_eq!(simpson(&x, &y)?, 4.0);
+///     Ok(())
+/// }
+/// ```
+pub fn simpson(x: &Vector, y: &Vector) -> Result<f64, StrError> {
+    let n = x.dim();
+    if y.dim() != n {
+        return Err("x and y vectors must have the same dimension");
+    }
+    if n < 2 {
+        return Err("at least 2 points are required");
+    }
+    for i in 1..n {
+        if x[i] <= x[i - 1] {
+            return Err("x values must be strictly increasing");
+        }
+    }
+    if n == 2 {
+        return Ok((x[1] - x[0]) * (y[0] + y[1]) / 2.0);
+    }
+    let n_intervals = n - 1;
+    let mut sum = 0.0;
+    let mut i = 0;
+    while i + 2 < n {
+        let h0 = x[i + 1] - x[i];
+        let h1 = x[i + 2] - x[i + 1];
+        let hs = h0 + h1;
+        sum += hs / 6.0 * ((2.0 - h1 / h0) * y[i] + hs * hs / (h0 * h1) * y[i + 1] + (2.0 - h0 / h1) * y[i + 2]);
+        i += 2;
+    }
+    if n_intervals % 2 == 1 {
+        let h0 = x[n - 2] - x[n - 3];
+        let h1 = x[n - 1] - x[n - 2];
+        let alpha = (2.0 * h1 * h1 + 3.0 * h0 * h1) / (6.0 * (h0 + h1));
+        let beta = (h1 * h1 + 3.0 * h0 * h1) / (6.0 * h0);
+        let eta = h1 * h1 * h1 / (6.0 * h0 * (h0 + h1));
+        sum += alpha * y[n - 1] + beta * y[n - 2] - eta * y[n - 3];
+    }
+    Ok(sum)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{simpson, Vector};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn simpson_fails_on_wrong_input() {
+        assert_eq!(
+            simpson(&Vector::new(2), &Vector::new(3)).err(),
+            Some("x and y vectors must have the same dimension")
+        );
+        assert_eq!(
+            simpson(&Vector::new(1), &Vector::new(1)).err(),
+            Some("at least 2 points are required")
+        );
+        assert_eq!(
+            simpson(&Vector::from(&[0.0, 1.0, 1.0]), &Vector::new(3)).err(),
+            Some("x values must be strictly increasing")
+        );
+    }
+
+    #[test]
+    fn simpson_two_points_works() {
+        let x = Vector::from(&[1.0, 3.0]);
+        let y = Vector::from(&[2.0, 4.0]);
+        assert_eq!(simpson(&x, &y).unwrap(), 6.0);
+    }
+
+    #[test]
+    fn simpson_integrates_quadratics_exactly() {
+        let f = |v: f64| 3.0 * v * v - 2.0 * v + 1.0;
+        let big_f = |v: f64| v * v * v - v * v + v;
+
+        // non-uniform spacing with an even number of intervals
+        let x = Vector::from(&[0.0, 0.3, 1.0, 1.2, 2.0]);
+        let y = x.get_mapped(f);
+        approx_eq(simpson(&x, &y).unwrap(), big_f(2.0), 1e-14);
+
+        // non-uniform spacing with an odd number of intervals
+        let x = Vector::from(&[0.0, 0.3, 1.0, 1.2, 2.0, 2.5]);
+        let y = x.get_mapped(f);
+        approx_eq(simpson(&x, &y).unwrap(), big_f(2.5), 1e-13);
+    }
+
+    #[test]
+    fn simpson_converges() {
+        let x = Vector::linspace(0.0, std::f64::consts::PI, 21).unwrap();
+        let y = x.get_mapped(f64::sin);
+        approx_eq(simpson(&x, &y).unwrap(), 2.0, 1e-5);
+    }
+}
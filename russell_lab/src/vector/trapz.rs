@@ -0,0 +1,75 @@
+use super::Vector;
+use crate::StrError;
+
+/// Integrates tabulated data using the trapezoidal rule
+///
+/// Computes:
+///
+/// ```text
+///        n-2
+/// I  ≈   Σ   (xᵢ₊₁ - xᵢ) ⋅ (yᵢ + yᵢ₊₁) / 2
+///       i=0
+/// ```
+///
+/// The spacing of `x` may be non-uniform.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{trapz, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // load-displacement curve: the area gives the work
+///     let u = Vector::from(&[0.0, 0.5, 1.0, 2.0]);
+///     let f = Vector::from(&[0.0, 10.0, 15.0, 15.0]);
+///     assert_eq!(trapz(&u, &f)?, 23.75);
+///     Ok(())
+/// }
+/// ```
+pub fn trapz(x: &Vector, y: &Vector) -> Result<f64, StrError> {
+    let n = x.dim();
+    if y.dim() != n {
+        return Err("x and y vectors must have the same dimension");
+    }
+    if n < 2 {
+        return Err("at least 2 points are required");
+    }
+    let mut sum = 0.0;
+    for i in 0..(n - 1) {
+        sum += (x[i + 1] - x[i]) * (y[i] + y[i + 1]);
+    }
+    Ok(sum / 2.0)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{trapz, Vector};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn trapz_fails_on_wrong_input() {
+        assert_eq!(
+            trapz(&Vector::new(2), &Vector::new(3)).err(),
+            Some("x and y vectors must have the same dimension")
+        );
+        assert_eq!(
+            trapz(&Vector::new(1), &Vector::new(1)).err(),
+            Some("at least 2 points are required")
+        );
+    }
+
+    #[test]
+    fn trapz_works() {
+        // linear functions are integrated exactly
+        let x = Vector::from(&[1.0, 1.5, 3.0, 3.2]);
+        let y = x.get_mapped(|v| 2.0 * v + 1.0);
+        approx_eq(trapz(&x, &y).unwrap(), 3.2 * 3.2 + 3.2 - 2.0, 1e-14);
+
+        // quadratic function with uniform spacing
+        let x = Vector::linspace(0.0, 1.0, 101).unwrap();
+        let y = x.get_mapped(|v| v * v);
+        approx_eq(trapz(&x, &y).unwrap(), 1.0 / 3.0, 1e-4);
+    }
+}
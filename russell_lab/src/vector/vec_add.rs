@@ -1,7 +1,7 @@
 use super::Vector;
 use crate::constants;
+use crate::kernels::{add_vectors_native, add_vectors_oblas};
 use crate::StrError;
-use russell_openblas::{add_vectors_native, add_vectors_oblas};
 
 /// Performs the addition of two vectors
 ///
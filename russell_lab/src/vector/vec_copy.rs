@@ -1,6 +1,6 @@
 use super::Vector;
+use crate::kernels::{dcopy, to_i32};
 use crate::StrError;
-use russell_openblas::{dcopy, to_i32};
 
 /// Copies vector
 ///
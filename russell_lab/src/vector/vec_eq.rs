@@ -0,0 +1,61 @@
+use super::Vector;
+
+/// Checks whether two vectors are approximately equal to each other
+///
+/// Returns `true` if the vectors have the same dimension and:
+///
+/// ```text
+/// |uᵢ - vᵢ| ≤ tol   for all i
+/// ```
+///
+/// Unlike the assertions in `russell_chk`, this function does not panic;
+/// thus, it can be used at runtime (e.g., to check the convergence of fixed-point iterations).
+///
+/// **Note:** Returns `false` if the vector dimensions differ or if any component is NaN.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_eq, Vector};
+///
+/// fn main() {
+///     let u = Vector::from(&[1.0, 2.0]);
+///     let v = Vector::from(&[1.0, 2.0 + 1e-12]);
+///     assert!(vec_eq(&u, &v, 1e-10));
+///     assert!(!vec_eq(&u, &v, 1e-14));
+///     assert!(!vec_eq(&u, &Vector::new(3), 1e-10));
+/// }
+/// ```
+pub fn vec_eq(u: &Vector, v: &Vector, tol: f64) -> bool {
+    if u.dim() != v.dim() {
+        return false;
+    }
+    u.as_data().iter().zip(v.as_data()).all(|(a, b)| f64::abs(a - b) <= tol)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_eq, Vector};
+
+    #[test]
+    fn vec_eq_handles_dimensions() {
+        let u = Vector::new(2);
+        let v = Vector::new(3);
+        assert!(!vec_eq(&u, &v, 1.0));
+        assert!(vec_eq(&Vector::new(0), &Vector::new(0), 0.0));
+    }
+
+    #[test]
+    fn vec_eq_works() {
+        let u = Vector::from(&[1.0, 2.0, 3.0]);
+        let v = Vector::from(&[1.0, 2.0, 3.0]);
+        assert!(vec_eq(&u, &v, 0.0));
+        let v = Vector::from(&[1.0, 2.0, 3.5]);
+        assert!(vec_eq(&u, &v, 0.5));
+        assert!(!vec_eq(&u, &v, 0.49));
+        let v = Vector::from(&[1.0, f64::NAN, 3.0]);
+        assert!(!vec_eq(&u, &v, 1e10));
+    }
+}
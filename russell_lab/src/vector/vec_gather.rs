@@ -0,0 +1,80 @@
+use super::Vector;
+use crate::StrError;
+
+/// Gathers the components of a vector into a smaller vector
+///
+/// ```text
+/// dest[i] := src[indices[i]]
+/// ```
+///
+/// This is the "global-to-local" transfer of finite element methods, where `src` is the
+/// global vector, `indices` holds the global equation numbers of an element, and `dest`
+/// is the local vector of the element. See also [vec_scatter_add].
+///
+/// # Note
+///
+/// The length of `indices` must equal the dimension of `dest` and all indices must be
+/// smaller than the dimension of `src`. The indices may be repeated.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_gather, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let global = Vector::from(&[10.0, 20.0, 30.0, 40.0]);
+///     let mut local = Vector::new(2);
+///     vec_gather(&mut local, &global, &[3, 1])?;
+///     let correct = "┌    ┐\n\
+///                    │ 40 │\n\
+///                    │ 20 │\n\
+///                    └    ┘";
+///     assert_eq!(format!("{}", local), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_gather(dest: &mut Vector, src: &Vector, indices: &[usize]) -> Result<(), StrError> {
+    if indices.len() != dest.dim() {
+        return Err("the length of indices must equal the dimension of dest");
+    }
+    let n = src.dim();
+    if indices.iter().any(|&k| k >= n) {
+        return Err("index is out of bounds");
+    }
+    for (d, &k) in dest.as_mut_data().iter_mut().zip(indices) {
+        *d = src[k];
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_gather, Vector};
+
+    #[test]
+    fn vec_gather_captures_errors() {
+        let src = Vector::from(&[1.0, 2.0, 3.0]);
+        let mut dest = Vector::new(2);
+        assert_eq!(
+            vec_gather(&mut dest, &src, &[0]).err(),
+            Some("the length of indices must equal the dimension of dest")
+        );
+        assert_eq!(
+            vec_gather(&mut dest, &src, &[0, 3]).err(),
+            Some("index is out of bounds")
+        );
+        assert_eq!(dest.as_data(), &[0.0, 0.0]);
+    }
+
+    #[test]
+    fn vec_gather_works() {
+        let src = Vector::from(&[1.0, 2.0, 3.0, 4.0]);
+        let mut dest = Vector::new(0);
+        vec_gather(&mut dest, &src, &[]).unwrap();
+        let mut dest = Vector::new(4);
+        vec_gather(&mut dest, &src, &[2, 0, 2, 3]).unwrap();
+        assert_eq!(dest.as_data(), &[3.0, 1.0, 3.0, 4.0]);
+    }
+}
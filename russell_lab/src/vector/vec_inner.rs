@@ -1,5 +1,5 @@
 use super::Vector;
-use russell_openblas::{ddot, to_i32};
+use crate::kernels::{ddot, to_i32};
 
 /// Performs the inner (dot) product between two vectors resulting in a scalar value
 ///
@@ -0,0 +1,42 @@
+use super::VectorF32;
+use russell_openblas::{sdot, to_i32};
+
+/// Performs the inner (dot) product between two vectors resulting in a scalar value (single precision)
+///
+/// ```text
+///  s := u dot v
+/// ```
+///
+/// # Note
+///
+/// The lengths of both vectors may be different; the smallest length will be selected.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_inner_f32, VectorF32};
+/// let u = VectorF32::from(&[1.0_f32, 2.0, 3.0]);
+/// let v = VectorF32::from(&[5.0_f32, -2.0, 0.0, 1.0]);
+/// let s = vec_inner_f32(&u, &v);
+/// assert_eq!(s, 1.0);
+/// ```
+pub fn vec_inner_f32(u: &VectorF32, v: &VectorF32) -> f32 {
+    let n = if u.dim() < v.dim() { u.dim() } else { v.dim() };
+    let n_i32 = to_i32(n);
+    sdot(n_i32, u.as_data(), 1, v.as_data(), 1)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_inner_f32, VectorF32};
+
+    #[test]
+    fn vec_inner_f32_works() {
+        let x = VectorF32::from(&[20.0_f32, 10.0, 30.0, 100000.0]);
+        let y = VectorF32::from(&[-15.0_f32, -5.0, -24.0]);
+        assert_eq!(vec_inner_f32(&x, &y), -1070.0);
+        assert_eq!(vec_inner_f32(&VectorF32::new(0), &y), 0.0);
+    }
+}
@@ -1,9 +1,14 @@
 use super::Vector;
+use crate::enums::{assert_valid_norm, p_norm_of_abs};
+use crate::kernels::{dasum, dnrm2, idamax, to_i32};
 use crate::Norm;
-use russell_openblas::{dasum, dnrm2, idamax, to_i32};
 
 /// Returns the vector norm
 ///
+/// # Panics
+///
+/// Panics if `kind` is `Norm::P(p)` with `p < 1` or `p = NaN`.
+///
 /// # Example
 ///
 /// ```
@@ -14,9 +19,11 @@ use russell_openblas::{dasum, dnrm2, idamax, to_i32};
 ///     assert_eq!(vec_norm(&u, Norm::One), 11.0);
 ///     assert_eq!(vec_norm(&u, Norm::Euc), 5.0);
 ///     assert_eq!(vec_norm(&u, Norm::Max), 3.0);
+///     assert_eq!(vec_norm(&u, Norm::P(1.0)), 11.0);
 /// }
 /// ```
 pub fn vec_norm(v: &Vector, kind: Norm) -> f64 {
+    assert_valid_norm(kind);
     let n = to_i32(v.dim());
     if n == 0 {
         return 0.0;
@@ -28,6 +35,7 @@ pub fn vec_norm(v: &Vector, kind: Norm) -> f64 {
             f64::abs(v.get(idx as usize))
         }
         Norm::One => dasum(n, &v.as_data(), 1),
+        Norm::P(p) => p_norm_of_abs(v.as_data().iter().map(|x| f64::abs(*x)), p),
     }
 }
 
@@ -61,4 +69,34 @@ mod tests {
         assert_eq!(vec_norm(&diff, Norm::Inf), 2.0);
         assert_eq!(vec_norm(&diff, Norm::One), 3.1);
     }
+
+    #[test]
+    fn vec_norm_p_works() {
+        let u0 = Vector::new(0);
+        assert_eq!(vec_norm(&u0, Norm::P(3.0)), 0.0);
+
+        let u = Vector::from(&[-3.0, 2.0, 1.0, 1.0, 1.0]);
+        approx_eq(vec_norm(&u, Norm::P(1.0)), 8.0, 1e-15);
+        approx_eq(vec_norm(&u, Norm::P(2.0)), 4.0, 1e-15);
+        approx_eq(vec_norm(&u, Norm::P(3.0)), f64::cbrt(38.0), 1e-14);
+        assert_eq!(vec_norm(&u, Norm::P(f64::INFINITY)), 3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the p-norm requires p ≥ 1")]
+    fn vec_norm_panics_on_p_less_than_one() {
+        vec_norm(&Vector::from(&[1.0, 2.0]), Norm::P(0.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "the p-norm requires p ≥ 1")]
+    fn vec_norm_panics_on_p_zero() {
+        vec_norm(&Vector::from(&[1.0, 2.0]), Norm::P(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "the p-norm requires p ≥ 1")]
+    fn vec_norm_panics_on_p_nan() {
+        vec_norm(&Vector::from(&[1.0, 2.0]), Norm::P(f64::NAN));
+    }
 }
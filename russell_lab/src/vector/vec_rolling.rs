@@ -0,0 +1,219 @@
+use super::Vector;
+use crate::StrError;
+
+/// Checks the window size and returns the number of output components
+fn rolling_count(u: &Vector, window: usize) -> Result<usize, StrError> {
+    if window == 0 {
+        return Err("window must be greater than zero");
+    }
+    if window > u.dim() {
+        return Err("window must not be greater than the vector dimension");
+    }
+    Ok(u.dim() - window + 1)
+}
+
+/// Returns the rolling (moving) mean of a vector
+///
+/// ```text
+///          1   i+w-1
+/// resᵢ = ——— ·   Σ   uₖ       for i = 0, …, n-w
+///         w     k=i
+/// ```
+///
+/// where `w` is the window size and `n = u.dim()`.
+///
+/// # Output
+///
+/// Returns a vector with `n - w + 1` components; i.e., only complete windows are considered.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_rolling_mean, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+///     let res = vec_rolling_mean(&u, 3)?;
+///     let correct = "┌   ┐\n\
+///                    │ 2 │\n\
+///                    │ 3 │\n\
+///                    │ 4 │\n\
+///                    └   ┘";
+///     assert_eq!(format!("{}", res), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_rolling_mean(u: &Vector, window: usize) -> Result<Vector, StrError> {
+    let count = rolling_count(u, window)?;
+    let w = window as f64;
+    let data = u.as_data();
+    let mut res = Vector::new(count);
+    let mut sum: f64 = data[0..window].iter().sum();
+    res[0] = sum / w;
+    for i in 1..count {
+        sum += data[i + window - 1] - data[i - 1];
+        res[i] = sum / w;
+    }
+    Ok(res)
+}
+
+/// Returns the rolling (moving) standard deviation of a vector
+///
+/// The (sample) standard deviation of each window is computed applying Bessel's correction;
+/// thus, the results are zero if the window size is equal to one.
+///
+/// # Output
+///
+/// Returns a vector with `n - w + 1` components; i.e., only complete windows are considered.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_rolling_std, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[1.0, 3.0, 5.0, 5.0, 5.0]);
+///     let res = vec_rolling_std(&u, 2)?;
+///     assert_eq!(res.as_data(), &[f64::sqrt(2.0), f64::sqrt(2.0), 0.0, 0.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_rolling_std(u: &Vector, window: usize) -> Result<Vector, StrError> {
+    let count = rolling_count(u, window)?;
+    let mut res = Vector::new(count);
+    if window == 1 {
+        return Ok(res);
+    }
+    let w = window as f64;
+    for (i, values) in u.as_data().windows(window).enumerate() {
+        let mean = values.iter().sum::<f64>() / w;
+        let variance = values.iter().fold(0.0, |acc, x| acc + (x - mean) * (x - mean)) / (w - 1.0);
+        res[i] = f64::sqrt(variance);
+    }
+    Ok(res)
+}
+
+/// Returns the rolling (moving) minimum of a vector
+///
+/// # Output
+///
+/// Returns a vector with `n - w + 1` components; i.e., only complete windows are considered.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_rolling_min, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[3.0, 1.0, 4.0, 1.0, 5.0]);
+///     let res = vec_rolling_min(&u, 2)?;
+///     assert_eq!(res.as_data(), &[1.0, 1.0, 1.0, 1.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_rolling_min(u: &Vector, window: usize) -> Result<Vector, StrError> {
+    let count = rolling_count(u, window)?;
+    let mut res = Vector::new(count);
+    for (i, values) in u.as_data().windows(window).enumerate() {
+        res[i] = values.iter().fold(f64::INFINITY, |acc, &x| f64::min(acc, x));
+    }
+    Ok(res)
+}
+
+/// Returns the rolling (moving) maximum of a vector
+///
+/// # Output
+///
+/// Returns a vector with `n - w + 1` components; i.e., only complete windows are considered.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_rolling_max, Vector, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = Vector::from(&[3.0, 1.0, 4.0, 1.0, 5.0]);
+///     let res = vec_rolling_max(&u, 2)?;
+///     assert_eq!(res.as_data(), &[3.0, 4.0, 4.0, 5.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_rolling_max(u: &Vector, window: usize) -> Result<Vector, StrError> {
+    let count = rolling_count(u, window)?;
+    let mut res = Vector::new(count);
+    for (i, values) in u.as_data().windows(window).enumerate() {
+        res[i] = values.iter().fold(f64::NEG_INFINITY, |acc, &x| f64::max(acc, x));
+    }
+    Ok(res)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_rolling_max, vec_rolling_mean, vec_rolling_min, vec_rolling_std, Vector};
+    use russell_chk::vec_approx_eq;
+
+    #[test]
+    fn vec_rolling_fails_on_wrong_window() {
+        let u = Vector::from(&[1.0, 2.0]);
+        assert_eq!(vec_rolling_mean(&u, 0).err(), Some("window must be greater than zero"));
+        assert_eq!(vec_rolling_std(&u, 0).err(), Some("window must be greater than zero"));
+        assert_eq!(vec_rolling_min(&u, 0).err(), Some("window must be greater than zero"));
+        assert_eq!(vec_rolling_max(&u, 0).err(), Some("window must be greater than zero"));
+        assert_eq!(
+            vec_rolling_mean(&u, 3).err(),
+            Some("window must not be greater than the vector dimension")
+        );
+        let empty = Vector::new(0);
+        assert_eq!(
+            vec_rolling_max(&empty, 1).err(),
+            Some("window must not be greater than the vector dimension")
+        );
+    }
+
+    #[test]
+    fn vec_rolling_mean_works() {
+        let u = Vector::from(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let res = vec_rolling_mean(&u, 1).unwrap();
+        assert_eq!(res.as_data(), u.as_data());
+        let res = vec_rolling_mean(&u, 2).unwrap();
+        vec_approx_eq(res.as_data(), &[1.5, 2.5, 3.5, 4.5, 5.5], 1e-15);
+        let res = vec_rolling_mean(&u, 4).unwrap();
+        vec_approx_eq(res.as_data(), &[2.5, 3.5, 4.5], 1e-15);
+        let res = vec_rolling_mean(&u, 6).unwrap();
+        vec_approx_eq(res.as_data(), &[3.5], 1e-15);
+    }
+
+    #[test]
+    fn vec_rolling_std_works() {
+        let u = Vector::from(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        let res = vec_rolling_std(&u, 1).unwrap();
+        assert_eq!(res.as_data(), &[0.0; 8]);
+        let res = vec_rolling_std(&u, 8).unwrap();
+        vec_approx_eq(res.as_data(), &[f64::sqrt(32.0 / 7.0)], 1e-15);
+        let res = vec_rolling_std(&u, 3).unwrap();
+        let correct = &[
+            f64::sqrt(4.0 / 3.0),
+            0.0,
+            f64::sqrt(1.0 / 3.0),
+            f64::sqrt(1.0 / 3.0),
+            f64::sqrt(4.0 / 3.0),
+            2.0,
+        ];
+        vec_approx_eq(res.as_data(), correct, 1e-15);
+    }
+
+    #[test]
+    fn vec_rolling_min_and_max_work() {
+        let u = Vector::from(&[-1.0, 3.0, 2.0, -4.0, 0.0, 5.0]);
+        let res = vec_rolling_min(&u, 3).unwrap();
+        assert_eq!(res.as_data(), &[-1.0, -4.0, -4.0, -4.0]);
+        let res = vec_rolling_max(&u, 3).unwrap();
+        assert_eq!(res.as_data(), &[3.0, 3.0, 2.0, 5.0]);
+        let res = vec_rolling_min(&u, 6).unwrap();
+        assert_eq!(res.as_data(), &[-4.0]);
+        let res = vec_rolling_max(&u, 1).unwrap();
+        assert_eq!(res.as_data(), u.as_data());
+    }
+}
@@ -1,5 +1,5 @@
 use super::Vector;
-use russell_openblas::{dscal, to_i32};
+use crate::kernels::{dscal, to_i32};
 
 /// Scales vector
 ///
@@ -0,0 +1,43 @@
+use super::VectorF32;
+use russell_openblas::{sscal, to_i32};
+
+/// Scales vector (single precision)
+///
+/// ```text
+/// u := alpha * u
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_scale_f32, VectorF32};
+///
+/// fn main() {
+///     let mut u = VectorF32::from(&[1.0_f32, 2.0, 3.0]);
+///     vec_scale_f32(&mut u, 0.5);
+///     let correct = "┌     ┐\n\
+///                    │ 0.5 │\n\
+///                    │   1 │\n\
+///                    │ 1.5 │\n\
+///                    └     ┘";
+///     assert_eq!(format!("{}", u), correct);
+/// }
+/// ```
+pub fn vec_scale_f32(v: &mut VectorF32, alpha: f32) {
+    let n_i32: i32 = to_i32(v.dim());
+    sscal(n_i32, alpha, v.as_mut_data(), 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_scale_f32, VectorF32};
+
+    #[test]
+    fn vec_scale_f32_works() {
+        let mut u = VectorF32::from(&[6.0_f32, 9.0, 12.0]);
+        vec_scale_f32(&mut u, 0.5);
+        assert_eq!(u.as_data(), &[3.0, 4.5, 6.0]);
+    }
+}
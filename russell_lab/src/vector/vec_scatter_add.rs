@@ -0,0 +1,100 @@
+use super::Vector;
+use crate::StrError;
+
+/// Adds (scatters) the components of a vector into a larger vector
+///
+/// ```text
+/// dest[indices[i]] += α⋅src[i]
+/// ```
+///
+/// This is the "local-to-global" transfer (assembly) of finite element methods, where `src` is
+/// the local vector of an element, `indices` holds the global equation numbers of the element,
+/// and `dest` is the global vector. See also [crate::vec_gather].
+///
+/// # Note
+///
+/// The length of `indices` must equal the dimension of `src` and all indices must be
+/// smaller than the dimension of `dest`. Repeated indices accumulate their contributions.
+/// Nothing is modified if an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_scatter_add, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     // two 1D elements (with two nodes each) sharing node 1
+///     let mut global = Vector::new(3);
+///     let local = Vector::from(&[1.0, 2.0]);
+///     vec_scatter_add(&mut global, 1.0, &local, &[0, 1])?;
+///     vec_scatter_add(&mut global, 1.0, &local, &[1, 2])?;
+///     let correct = "┌   ┐\n\
+///                    │ 1 │\n\
+///                    │ 3 │\n\
+///                    │ 2 │\n\
+///                    └   ┘";
+///     assert_eq!(format!("{}", global), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_scatter_add(dest: &mut Vector, alpha: f64, src: &Vector, indices: &[usize]) -> Result<(), StrError> {
+    if indices.len() != src.dim() {
+        return Err("the length of indices must equal the dimension of src");
+    }
+    let n = dest.dim();
+    if indices.iter().any(|&k| k >= n) {
+        return Err("index is out of bounds");
+    }
+    for (s, &k) in src.as_data().iter().zip(indices) {
+        dest[k] += alpha * s;
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_scatter_add, Vector};
+    use crate::vec_gather;
+
+    #[test]
+    fn vec_scatter_add_captures_errors() {
+        let src = Vector::from(&[1.0, 2.0]);
+        let mut dest = Vector::new(3);
+        assert_eq!(
+            vec_scatter_add(&mut dest, 1.0, &src, &[0]).err(),
+            Some("the length of indices must equal the dimension of src")
+        );
+        assert_eq!(
+            vec_scatter_add(&mut dest, 1.0, &src, &[1, 3]).err(),
+            Some("index is out of bounds")
+        );
+        assert_eq!(dest.as_data(), &[0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn vec_scatter_add_works() {
+        let mut dest = Vector::from(&[1.0, 1.0, 1.0, 1.0]);
+        vec_scatter_add(&mut dest, 2.0, &Vector::new(0), &[]).unwrap();
+        assert_eq!(dest.as_data(), &[1.0, 1.0, 1.0, 1.0]);
+        let src = Vector::from(&[1.0, 2.0, 3.0]);
+        vec_scatter_add(&mut dest, 2.0, &src, &[3, 0, 3]).unwrap();
+        assert_eq!(dest.as_data(), &[5.0, 1.0, 1.0, 9.0]);
+    }
+
+    #[test]
+    fn vec_gather_and_scatter_add_are_consistent() {
+        // the scatter (with α = 1) is the transpose of the gather: (g⋅x)ᵀ y = xᵀ (gᵀ⋅y)
+        let x = Vector::from(&[1.0, -2.0, 3.0, 0.5, 4.0]);
+        let y = Vector::from(&[2.0, 1.0, -1.0]);
+        let indices = &[4, 1, 4];
+        let mut gx = Vector::new(3);
+        vec_gather(&mut gx, &x, indices).unwrap();
+        let mut gty = Vector::new(5);
+        vec_scatter_add(&mut gty, 1.0, &y, indices).unwrap();
+        let lhs: f64 = gx.as_data().iter().zip(y.as_data()).map(|(a, b)| a * b).sum();
+        let rhs: f64 = x.as_data().iter().zip(gty.as_data()).map(|(a, b)| a * b).sum();
+        assert_eq!(lhs, rhs);
+    }
+}
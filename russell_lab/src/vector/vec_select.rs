@@ -0,0 +1,77 @@
+use super::Vector;
+use crate::StrError;
+
+/// Selects the components of a vector flagged by a boolean mask
+///
+/// Returns `(u, indices)` where `u` holds the components `v[i]` with `mask[i] == true`
+/// (in ascending order of `i`) and `indices` maps the new positions to the original ones:
+///
+/// ```text
+/// u[k] = v[indices[k]]
+/// ```
+///
+/// For instance, with `mask` flagging the free (unknown) degrees-of-freedom of a finite element
+/// model, `u` holds the free part of `v`; the complementary mask yields the prescribed part. The
+/// `indices` may then be used with [crate::vec_gather] and [crate::vec_scatter_add] to transfer
+/// values between the reduced and the full vectors.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_select, StrError, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let v = Vector::from(&[10.0, 20.0, 30.0, 40.0]);
+///     let prescribed = [true, false, false, true];
+///     let free: Vec<_> = prescribed.iter().map(|p| !p).collect();
+///     let (u, indices) = vec_select(&v, &free)?;
+///     assert_eq!(u.as_data(), &[20.0, 30.0]);
+///     assert_eq!(indices, &[1, 2]);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_select(v: &Vector, mask: &[bool]) -> Result<(Vector, Vec<usize>), StrError> {
+    if mask.len() != v.dim() {
+        return Err("the length of mask must equal the dimension of the vector");
+    }
+    let indices: Vec<usize> = (0..mask.len()).filter(|&i| mask[i]).collect();
+    let mut u = Vector::new(indices.len());
+    for (k, &i) in indices.iter().enumerate() {
+        u[k] = v[i];
+    }
+    Ok((u, indices))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_select, Vector};
+
+    #[test]
+    fn vec_select_captures_errors() {
+        let v = Vector::new(3);
+        assert_eq!(
+            vec_select(&v, &[true, false]).err(),
+            Some("the length of mask must equal the dimension of the vector")
+        );
+    }
+
+    #[test]
+    fn vec_select_works() {
+        let (u, indices) = vec_select(&Vector::new(0), &[]).unwrap();
+        assert_eq!(u.dim(), 0);
+        assert_eq!(indices.len(), 0);
+
+        let v = Vector::from(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let (u, indices) = vec_select(&v, &[false, false, false, false, false]).unwrap();
+        assert_eq!(u.dim(), 0);
+        assert_eq!(indices.len(), 0);
+        let (u, indices) = vec_select(&v, &[true, true, true, true, true]).unwrap();
+        assert_eq!(u.as_data(), v.as_data());
+        assert_eq!(indices, &[0, 1, 2, 3, 4]);
+        let (u, indices) = vec_select(&v, &[true, false, true, false, true]).unwrap();
+        assert_eq!(u.as_data(), &[1.0, 3.0, 5.0]);
+        assert_eq!(indices, &[0, 2, 4]);
+    }
+}
@@ -0,0 +1,164 @@
+use super::Vector;
+use crate::Summation;
+
+/// Sums all components of a vector
+///
+/// ```text
+/// s = Σ_i uᵢ
+/// ```
+///
+/// See [Summation] for the available algorithms. The compensated algorithms are recommended
+/// for very long vectors or when the components have very different magnitudes.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_sum, Summation, Vector};
+///
+/// let u = Vector::from(&[1.0, 1e100, 1.0, -1e100]);
+/// assert_eq!(vec_sum(&u, Summation::Naive), 0.0);
+/// assert_eq!(vec_sum(&u, Summation::Kahan), 0.0);
+/// assert_eq!(vec_sum(&u, Summation::Neumaier), 2.0);
+/// ```
+pub fn vec_sum(u: &Vector, method: Summation) -> f64 {
+    let data = u.as_data();
+    match method {
+        Summation::Naive => data.iter().sum(),
+        Summation::Kahan => {
+            let mut sum = 0.0;
+            let mut c = 0.0;
+            for x in data {
+                let y = x - c;
+                let t = sum + y;
+                c = (t - sum) - y;
+                sum = t;
+            }
+            sum
+        }
+        Summation::Neumaier => {
+            let mut sum = 0.0;
+            let mut c = 0.0;
+            for x in data {
+                let (t, err) = two_sum(sum, *x);
+                sum = t;
+                c += err;
+            }
+            sum + c
+        }
+    }
+}
+
+/// Performs the inner (dot) product between two vectors using compensated arithmetic
+///
+/// ```text
+///  s := u dot v
+/// ```
+///
+/// Implements the Dot2 algorithm of Ogita, Rump, and Oishi (2005): the rounding error of each
+/// product is computed exactly with a fused multiply-add and accumulated together with the
+/// rounding errors of the additions (as in [Summation::Neumaier]). The result is as accurate
+/// as if computed in twice the working precision and then rounded. See also [crate::vec_inner].
+///
+/// # Note
+///
+/// The lengths of both vectors may be different; the smallest length will be selected.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_inner, vec_inner_compensated, Vector};
+///
+/// let u = Vector::from(&[1e100, 1.0, -1e100]);
+/// let v = Vector::from(&[1.0, 2.0, 1.0]);
+/// assert_eq!(vec_inner(&u, &v), 0.0);
+/// assert_eq!(vec_inner_compensated(&u, &v), 2.0);
+/// ```
+pub fn vec_inner_compensated(u: &Vector, v: &Vector) -> f64 {
+    let mut sum = 0.0;
+    let mut c = 0.0;
+    for (a, b) in u.as_data().iter().zip(v.as_data()) {
+        let p = a * b;
+        let err_p = a.mul_add(*b, -p);
+        let (t, err_s) = two_sum(sum, p);
+        sum = t;
+        c += err_s + err_p;
+    }
+    sum + c
+}
+
+/// Returns the sum and its rounding error such that a + b = sum + err exactly (branch-based TwoSum)
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let err = if f64::abs(a) >= f64::abs(b) {
+        (a - sum) + b
+    } else {
+        (b - sum) + a
+    };
+    (sum, err)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_inner_compensated, vec_sum, Vector};
+    use crate::Summation;
+
+    #[test]
+    fn vec_sum_works() {
+        let empty = Vector::new(0);
+        assert_eq!(vec_sum(&empty, Summation::Naive), 0.0);
+        assert_eq!(vec_sum(&empty, Summation::Kahan), 0.0);
+        assert_eq!(vec_sum(&empty, Summation::Neumaier), 0.0);
+        let u = Vector::from(&[1.0, 2.0, 3.0, -4.0]);
+        assert_eq!(vec_sum(&u, Summation::Naive), 2.0);
+        assert_eq!(vec_sum(&u, Summation::Kahan), 2.0);
+        assert_eq!(vec_sum(&u, Summation::Neumaier), 2.0);
+    }
+
+    #[test]
+    fn vec_sum_compensates_long_series() {
+        // the naive sum drifts away from 1e5
+        let u = Vector::filled(1_000_000, 0.1);
+        let naive = vec_sum(&u, Summation::Naive);
+        let kahan = vec_sum(&u, Summation::Kahan);
+        let neumaier = vec_sum(&u, Summation::Neumaier);
+        assert!(f64::abs(naive - 1e5) > 1e-7);
+        assert!(f64::abs(kahan - 1e5) < 1e-10);
+        assert!(f64::abs(neumaier - 1e5) < 1e-10);
+    }
+
+    #[test]
+    fn vec_sum_neumaier_handles_large_terms() {
+        let u = Vector::from(&[1.0, 1e100, 1.0, -1e100]);
+        assert_eq!(vec_sum(&u, Summation::Naive), 0.0);
+        assert_eq!(vec_sum(&u, Summation::Kahan), 0.0);
+        assert_eq!(vec_sum(&u, Summation::Neumaier), 2.0);
+    }
+
+    #[test]
+    fn vec_inner_compensated_works() {
+        const IGNORED: f64 = 100000.0;
+        let x = Vector::from(&[20.0, 10.0, 30.0, IGNORED]);
+        let y = Vector::from(&[-15.0, -5.0, -24.0]);
+        assert_eq!(vec_inner_compensated(&x, &y), -1070.0);
+        assert_eq!(vec_inner_compensated(&y, &x), -1070.0);
+        assert_eq!(vec_inner_compensated(&Vector::new(0), &y), 0.0);
+    }
+
+    #[test]
+    fn vec_inner_compensated_recovers_product_errors() {
+        // (1 + ε)⋅(1 - ε) - 1 = -ε² is lost in the rounding of the product
+        let eps = f64::EPSILON;
+        let u = Vector::from(&[1.0 + eps, -1.0]);
+        let v = Vector::from(&[1.0 - eps, 1.0]);
+        assert_eq!(vec_inner_compensated(&u, &v), -eps * eps);
+
+        // energy-like norm of a long series
+        let n = 1_000_000;
+        let u = Vector::filled(n, 0.1);
+        let v = Vector::filled(n, 3.0);
+        assert!(f64::abs(vec_inner_compensated(&u, &v) - 3e5) < 1e-10);
+    }
+}
@@ -1,6 +1,6 @@
 use super::Vector;
+use crate::kernels::{daxpy, to_i32};
 use crate::StrError;
-use russell_openblas::{daxpy, to_i32};
 
 /// Updates vector based on another vector
 ///
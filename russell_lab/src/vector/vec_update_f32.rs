@@ -0,0 +1,59 @@
+use super::VectorF32;
+use crate::StrError;
+use russell_openblas::{saxpy, to_i32};
+
+/// Updates vector based on another vector (single precision)
+///
+/// ```text
+/// v += α⋅u
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_update_f32, StrError, VectorF32};
+///
+/// fn main() -> Result<(), StrError> {
+///     let u = VectorF32::from(&[10.0_f32, 20.0, 30.0]);
+///     let mut v = VectorF32::from(&[10.0_f32, 20.0, 30.0]);
+///     vec_update_f32(&mut v, 0.5, &u)?;
+///     let correct = "┌    ┐\n\
+///                    │ 15 │\n\
+///                    │ 30 │\n\
+///                    │ 45 │\n\
+///                    └    ┘";
+///     assert_eq!(format!("{}", v), correct);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_update_f32(v: &mut VectorF32, alpha: f32, u: &VectorF32) -> Result<(), StrError> {
+    let n = v.dim();
+    if u.dim() != n {
+        return Err("vectors are incompatible");
+    }
+    let n_i32: i32 = to_i32(n);
+    saxpy(n_i32, alpha, u.as_data(), 1, v.as_mut_data(), 1);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{vec_update_f32, VectorF32};
+
+    #[test]
+    fn vec_update_f32_fails_on_wrong_dims() {
+        let u = VectorF32::new(4);
+        let mut v = VectorF32::new(3);
+        assert_eq!(vec_update_f32(&mut v, 1.0, &u), Err("vectors are incompatible"));
+    }
+
+    #[test]
+    fn vec_update_f32_works() {
+        let u = VectorF32::from(&[10.0_f32, 20.0, 30.0]);
+        let mut v = VectorF32::from(&[10.0_f32, 20.0, 30.0]);
+        vec_update_f32(&mut v, -0.25, &u).unwrap();
+        assert_eq!(v.as_data(), &[7.5, 15.0, 22.5]);
+    }
+}
@@ -0,0 +1,483 @@
+use super::Vector;
+use crate::kernels::{daxpy, dcopy, ddot, dnrm2, dscal, to_i32};
+use crate::matrix::Matrix;
+use crate::StrError;
+
+/// Holds a strided (read-only) view of some data; e.g., every k-th component of a vector or a matrix row
+///
+/// The view refers to the components:
+///
+/// ```text
+/// stride > 0:  data[0], data[stride], data[2⋅stride], ..., data[(len-1)⋅stride]
+/// stride < 0:  data[(len-1)⋅|stride|], ..., data[|stride|], data[0]   (reversed)
+/// ```
+///
+/// which is the convention of the `incx` and `incy` arguments of BLAS; thus, the
+/// `vec_view_*` functions call BLAS directly without copying the data.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_view_inner, Matrix, StrError, VecView, Vector};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+///     let row = VecView::row(&a, 1)?;
+///     assert_eq!(row.to_vector().as_data(), &[4.0, 5.0, 6.0]);
+///
+///     let u = Vector::from(&[1.0, 0.0, 1.0, 0.0, 1.0]);
+///     let every_other = VecView::every(&u, 0, 2)?;
+///     assert_eq!(every_other.len(), 3);
+///     assert_eq!(vec_view_inner(&row, &every_other)?, 15.0);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct VecView<'a> {
+    data: &'a [f64], // the slice starting at the first referenced component (in memory)
+    len: usize,      // number of components in the view
+    stride: i32,     // distance between consecutive components (negative means reversed)
+}
+
+/// Holds a strided mutable view of some data (see [VecView])
+#[derive(Debug)]
+pub struct VecViewMut<'a> {
+    data: &'a mut [f64], // the slice starting at the first referenced component (in memory)
+    len: usize,          // number of components in the view
+    stride: i32,         // distance between consecutive components (negative means reversed)
+}
+
+/// Checks the stride and returns the required slice length
+fn required_len(len: usize, stride: i32) -> Result<usize, StrError> {
+    if stride == 0 {
+        return Err("stride must not be zero");
+    }
+    if len == 0 {
+        return Ok(0);
+    }
+    Ok((len - 1) * stride.unsigned_abs() as usize + 1)
+}
+
+/// Maps the k-th component of the view to the index in the slice
+#[inline]
+fn data_index(k: usize, len: usize, stride: i32) -> usize {
+    let s = stride.unsigned_abs() as usize;
+    if stride > 0 {
+        k * s
+    } else {
+        (len - 1 - k) * s
+    }
+}
+
+impl<'a> VecView<'a> {
+    /// Creates a new view of `len` components separated by `stride` in `data`
+    ///
+    /// A negative stride reverses the order of the components (see [VecView]).
+    pub fn new(data: &'a [f64], len: usize, stride: i32) -> Result<Self, StrError> {
+        let required = required_len(len, stride)?;
+        if data.len() < required {
+            return Err("data slice is too short for the requested view");
+        }
+        Ok(VecView {
+            data: &data[..required],
+            len,
+            stride,
+        })
+    }
+
+    /// Creates a view of all components of a vector
+    pub fn all(u: &'a Vector) -> Self {
+        VecView {
+            data: u.as_data(),
+            len: u.dim(),
+            stride: 1,
+        }
+    }
+
+    /// Creates a view of the components of a vector in reversed order
+    pub fn reversed(u: &'a Vector) -> Self {
+        VecView {
+            data: u.as_data(),
+            len: u.dim(),
+            stride: -1,
+        }
+    }
+
+    /// Creates a view of every k-th component of a vector, starting at `start`
+    pub fn every(u: &'a Vector, start: usize, k: usize) -> Result<Self, StrError> {
+        if k == 0 {
+            return Err("stride must not be zero");
+        }
+        let n = u.dim();
+        let len = if start < n { (n - start).div_ceil(k) } else { 0 };
+        VecView::new(&u.as_data()[usize::min(start, n)..], len, to_i32(k))
+    }
+
+    /// Creates a view of the j-th column of a matrix (contiguous; col-major storage)
+    pub fn column(a: &'a Matrix, j: usize) -> Result<Self, StrError> {
+        let (m, n) = a.dims();
+        if j >= n {
+            return Err("column index is out of bounds");
+        }
+        VecView::new(&a.as_data()[j * m..], m, 1)
+    }
+
+    /// Creates a view of the i-th row of a matrix (stride equal to the number of rows)
+    pub fn row(a: &'a Matrix, i: usize) -> Result<Self, StrError> {
+        let (m, n) = a.dims();
+        if i >= m {
+            return Err("row index is out of bounds");
+        }
+        VecView::new(&a.as_data()[i..], n, to_i32(m))
+    }
+
+    /// Returns the number of components in the view
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view has no components
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the stride (negative if reversed)
+    pub fn stride(&self) -> i32 {
+        self.stride
+    }
+
+    /// Returns the k-th component of the view
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `k` is out of bounds
+    pub fn get(&self, k: usize) -> f64 {
+        assert!(k < self.len);
+        self.data[data_index(k, self.len, self.stride)]
+    }
+
+    /// Copies the components of the view into a new Vector
+    pub fn to_vector(&self) -> Vector {
+        let mut u = Vector::new(self.len);
+        for k in 0..self.len {
+            u[k] = self.get(k);
+        }
+        u
+    }
+}
+
+impl<'a> VecViewMut<'a> {
+    /// Creates a new mutable view of `len` components separated by `stride` in `data`
+    pub fn new(data: &'a mut [f64], len: usize, stride: i32) -> Result<Self, StrError> {
+        let required = required_len(len, stride)?;
+        if data.len() < required {
+            return Err("data slice is too short for the requested view");
+        }
+        Ok(VecViewMut {
+            data: &mut data[..required],
+            len,
+            stride,
+        })
+    }
+
+    /// Creates a mutable view of all components of a vector
+    pub fn all(u: &'a mut Vector) -> Self {
+        let len = u.dim();
+        VecViewMut {
+            data: u.as_mut_data(),
+            len,
+            stride: 1,
+        }
+    }
+
+    /// Creates a mutable view of the components of a vector in reversed order
+    pub fn reversed(u: &'a mut Vector) -> Self {
+        let len = u.dim();
+        VecViewMut {
+            data: u.as_mut_data(),
+            len,
+            stride: -1,
+        }
+    }
+
+    /// Creates a mutable view of every k-th component of a vector, starting at `start`
+    pub fn every(u: &'a mut Vector, start: usize, k: usize) -> Result<Self, StrError> {
+        if k == 0 {
+            return Err("stride must not be zero");
+        }
+        let n = u.dim();
+        let len = if start < n { (n - start).div_ceil(k) } else { 0 };
+        VecViewMut::new(&mut u.as_mut_data()[usize::min(start, n)..], len, to_i32(k))
+    }
+
+    /// Creates a mutable view of the j-th column of a matrix
+    pub fn column(a: &'a mut Matrix, j: usize) -> Result<Self, StrError> {
+        let (m, n) = a.dims();
+        if j >= n {
+            return Err("column index is out of bounds");
+        }
+        VecViewMut::new(&mut a.as_mut_data()[j * m..], m, 1)
+    }
+
+    /// Creates a mutable view of the i-th row of a matrix
+    pub fn row(a: &'a mut Matrix, i: usize) -> Result<Self, StrError> {
+        let (m, n) = a.dims();
+        if i >= m {
+            return Err("row index is out of bounds");
+        }
+        VecViewMut::new(&mut a.as_mut_data()[i..], n, to_i32(m))
+    }
+
+    /// Returns a read-only view of the same components
+    pub fn as_view(&self) -> VecView<'_> {
+        VecView {
+            data: self.data,
+            len: self.len,
+            stride: self.stride,
+        }
+    }
+
+    /// Returns the number of components in the view
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view has no components
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the k-th component of the view
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `k` is out of bounds
+    pub fn get(&self, k: usize) -> f64 {
+        assert!(k < self.len);
+        self.data[data_index(k, self.len, self.stride)]
+    }
+
+    /// Sets the k-th component of the view
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `k` is out of bounds
+    pub fn set(&mut self, k: usize, value: f64) {
+        assert!(k < self.len);
+        self.data[data_index(k, self.len, self.stride)] = value;
+    }
+}
+
+/// Performs the inner (dot) product between two strided views
+///
+/// ```text
+///  s := u dot v
+/// ```
+pub fn vec_view_inner(u: &VecView, v: &VecView) -> Result<f64, StrError> {
+    if u.len != v.len {
+        return Err("views must have the same length");
+    }
+    if u.len == 0 {
+        return Ok(0.0);
+    }
+    Ok(ddot(to_i32(u.len), u.data, u.stride, v.data, v.stride))
+}
+
+/// Updates a strided view with another (scaled) strided view
+///
+/// ```text
+///  v += α ⋅ u
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{vec_view_update, Matrix, StrError, VecView, VecViewMut};
+///
+/// fn main() -> Result<(), StrError> {
+///     // adds 10 × (first column) to the second row, without copies
+///     let b = Matrix::from(&[[1.0, 0.0], [2.0, 0.0]]);
+///     let mut a = Matrix::new(3, 2);
+///     vec_view_update(&mut VecViewMut::row(&mut a, 1)?, 10.0, &VecView::column(&b, 0)?)?;
+///     assert_eq!(a.as_data(), &[0.0, 10.0, 0.0, 0.0, 20.0, 0.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn vec_view_update(v: &mut VecViewMut, alpha: f64, u: &VecView) -> Result<(), StrError> {
+    if u.len != v.len {
+        return Err("views must have the same length");
+    }
+    if u.len == 0 {
+        return Ok(());
+    }
+    daxpy(to_i32(u.len), alpha, u.data, u.stride, v.data, v.stride);
+    Ok(())
+}
+
+/// Copies a strided view into another
+///
+/// ```text
+///  v := u
+/// ```
+pub fn vec_view_copy(v: &mut VecViewMut, u: &VecView) -> Result<(), StrError> {
+    if u.len != v.len {
+        return Err("views must have the same length");
+    }
+    if u.len == 0 {
+        return Ok(());
+    }
+    dcopy(to_i32(u.len), u.data, u.stride, v.data, v.stride);
+    Ok(())
+}
+
+/// Scales a strided view
+///
+/// ```text
+///  v := α ⋅ v
+/// ```
+pub fn vec_view_scale(v: &mut VecViewMut, alpha: f64) {
+    if v.len == 0 {
+        return;
+    }
+    // the order is irrelevant (and BLAS ignores negative increments here)
+    dscal(to_i32(v.len), alpha, v.data, v.stride.abs());
+}
+
+/// Returns the Euclidean norm of a strided view
+pub fn vec_view_norm_euc(u: &VecView) -> f64 {
+    if u.len == 0 {
+        return 0.0;
+    }
+    // the order is irrelevant (and BLAS ignores negative increments here)
+    dnrm2(to_i32(u.len), u.data, u.stride.abs())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        vec_view_copy, vec_view_inner, vec_view_norm_euc, vec_view_scale, vec_view_update, VecView, VecViewMut,
+    };
+    use crate::{Matrix, Vector};
+
+    #[test]
+    fn new_captures_errors() {
+        let data = [1.0, 2.0, 3.0];
+        assert_eq!(VecView::new(&data, 3, 0).err(), Some("stride must not be zero"));
+        assert_eq!(
+            VecView::new(&data, 2, 3).err(),
+            Some("data slice is too short for the requested view")
+        );
+        let mut data = [1.0, 2.0, 3.0];
+        assert_eq!(
+            VecViewMut::new(&mut data, 4, -1).err(),
+            Some("data slice is too short for the requested view")
+        );
+        let u = Vector::new(3);
+        assert_eq!(VecView::every(&u, 0, 0).err(), Some("stride must not be zero"));
+        let a = Matrix::new(2, 3);
+        assert_eq!(VecView::column(&a, 3).err(), Some("column index is out of bounds"));
+        assert_eq!(VecView::row(&a, 2).err(), Some("row index is out of bounds"));
+    }
+
+    #[test]
+    fn views_work() {
+        let u = Vector::from(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(VecView::all(&u).to_vector().as_data(), u.as_data());
+        let rev = VecView::reversed(&u);
+        assert_eq!(rev.stride(), -1);
+        assert_eq!(rev.to_vector().as_data(), &[6.0, 5.0, 4.0, 3.0, 2.0, 1.0, 0.0]);
+        assert_eq!(VecView::every(&u, 1, 3).unwrap().to_vector().as_data(), &[1.0, 4.0]);
+        assert_eq!(
+            VecView::every(&u, 0, 3).unwrap().to_vector().as_data(),
+            &[0.0, 3.0, 6.0]
+        );
+        assert!(VecView::every(&u, 7, 2).unwrap().is_empty());
+        let back = VecView::new(u.as_data(), 3, -3).unwrap();
+        assert_eq!(back.to_vector().as_data(), &[6.0, 3.0, 0.0]);
+
+        let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(VecView::column(&a, 2).unwrap().to_vector().as_data(), &[3.0, 6.0]);
+        assert_eq!(VecView::row(&a, 0).unwrap().to_vector().as_data(), &[1.0, 2.0, 3.0]);
+        assert_eq!(VecView::row(&a, 1).unwrap().get(2), 6.0);
+    }
+
+    #[test]
+    fn mutable_views_work() {
+        let mut a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mut row = VecViewMut::row(&mut a, 1).unwrap();
+        assert_eq!(row.len(), 3);
+        row.set(0, -4.0);
+        assert_eq!(row.as_view().to_vector().as_data(), &[-4.0, 5.0, 6.0]);
+        let mut col = VecViewMut::column(&mut a, 1).unwrap();
+        col.set(0, -2.0);
+        assert_eq!(col.get(1), 5.0);
+        assert_eq!(
+            format!("{}", a),
+            "┌          ┐\n\
+             │  1 -2  3 │\n\
+             │ -4  5  6 │\n\
+             └          ┘"
+        );
+
+        let mut u = Vector::from(&[1.0, 2.0, 3.0]);
+        let mut rev = VecViewMut::reversed(&mut u);
+        rev.set(0, 30.0);
+        assert_eq!(u.as_data(), &[1.0, 2.0, 30.0]);
+        let mut every = VecViewMut::every(&mut u, 1, 5).unwrap();
+        assert_eq!(every.len(), 1);
+        every.set(0, 20.0);
+        assert!(!every.is_empty());
+        assert_eq!(u.as_data(), &[1.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    fn blas_functions_capture_errors() {
+        let u = Vector::new(3);
+        let v = Vector::new(2);
+        let mut w = Vector::new(2);
+        assert_eq!(
+            vec_view_inner(&VecView::all(&u), &VecView::all(&v)).err(),
+            Some("views must have the same length")
+        );
+        assert_eq!(
+            vec_view_update(&mut VecViewMut::all(&mut w), 1.0, &VecView::all(&u)).err(),
+            Some("views must have the same length")
+        );
+        assert_eq!(
+            vec_view_copy(&mut VecViewMut::all(&mut w), &VecView::all(&u)).err(),
+            Some("views must have the same length")
+        );
+    }
+
+    #[test]
+    fn blas_functions_work() {
+        let u = Vector::from(&[1.0, 2.0, 3.0]);
+        let a = Matrix::from(&[[1.0, 10.0], [2.0, 20.0], [3.0, 30.0]]);
+        let col = VecView::column(&a, 1).unwrap();
+        assert_eq!(vec_view_inner(&VecView::all(&u), &col).unwrap(), 140.0);
+        assert_eq!(vec_view_inner(&VecView::reversed(&u), &col).unwrap(), 100.0);
+
+        let mut b = Matrix::new(2, 3);
+        vec_view_copy(&mut VecViewMut::row(&mut b, 0).unwrap(), &VecView::reversed(&u)).unwrap();
+        vec_view_update(&mut VecViewMut::row(&mut b, 1).unwrap(), 2.0, &VecView::all(&u)).unwrap();
+        assert_eq!(
+            format!("{}", b),
+            "┌       ┐\n\
+             │ 3 2 1 │\n\
+             │ 2 4 6 │\n\
+             └       ┘"
+        );
+
+        let mut v = Vector::from(&[3.0, 100.0, 4.0]);
+        let mut every = VecViewMut::every(&mut v, 0, 2).unwrap();
+        assert_eq!(vec_view_norm_euc(&every.as_view()), 5.0);
+        vec_view_scale(&mut every, 2.0);
+        assert_eq!(v.as_data(), &[6.0, 100.0, 8.0]);
+        let mut rev = VecViewMut::reversed(&mut v);
+        vec_view_scale(&mut rev, 0.5);
+        assert_eq!(v.as_data(), &[3.0, 50.0, 4.0]);
+        assert_eq!(vec_view_norm_euc(&VecView::new(v.as_data(), 2, -2).unwrap()), 5.0);
+    }
+}
@@ -22,7 +22,7 @@ fn test_lin_sys() -> Result<(), StrError> {
 
         // compare solution
         if m == 0 {
-            assert_eq!(b.as_data(), &[]);
+            assert!(b.as_data().is_empty());
         } else {
             let mut x_correct = Vector::new(m);
             x_correct[0] = TARGET;
@@ -7,6 +7,10 @@ pub(crate) const CBLAS_NO_TRANS: i32 = 111;
 pub(crate) const CBLAS_TRANS: i32 = 112;
 pub(crate) const CBLAS_UPPER: i32 = 121;
 pub(crate) const CBLAS_LOWER: i32 = 122;
+pub(crate) const CBLAS_NON_UNIT: i32 = 131;
+pub(crate) const CBLAS_UNIT: i32 = 132;
+pub(crate) const CBLAS_LEFT: i32 = 141;
+pub(crate) const CBLAS_RIGHT: i32 = 142;
 
 #[inline]
 pub(crate) fn cblas_transpose(transpose: bool) -> i32 {
@@ -24,6 +28,22 @@ pub(crate) fn cblas_uplo(up: bool) -> i32 {
     CBLAS_LOWER
 }
 
+#[inline]
+pub(crate) fn cblas_diag(unit: bool) -> i32 {
+    if unit {
+        return CBLAS_UNIT;
+    }
+    CBLAS_NON_UNIT
+}
+
+#[inline]
+pub(crate) fn cblas_side(left: bool) -> i32 {
+    if left {
+        return CBLAS_LEFT;
+    }
+    CBLAS_RIGHT
+}
+
 #[inline]
 pub(crate) fn lapack_uplo(up: bool) -> u8 {
     if up {
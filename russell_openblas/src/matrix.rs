@@ -1,4 +1,7 @@
-use super::{cblas_transpose, cblas_uplo, lapack_job_vlr, lapack_uplo, CBLAS_COL_MAJOR, LAPACK_COL_MAJOR};
+use super::{
+    cblas_diag, cblas_side, cblas_transpose, cblas_uplo, lapack_job_vlr, lapack_uplo, to_i32, CBLAS_COL_MAJOR,
+    LAPACK_COL_MAJOR,
+};
 use crate::StrError;
 use num_complex::Complex64;
 
@@ -6,6 +9,8 @@ use num_complex::Complex64;
 extern "C" {
     // from /usr/include/x86_64-linux-gnu/cblas.h
     fn cblas_dgemm(order: i32, transa: i32, transb: i32, m: i32, n: i32, k: i32, alpha: f64, a: *const f64, lda: i32, b: *const f64, ldb: i32, beta: f64, c: *mut f64, ldc: i32);
+    fn cblas_sgemm(order: i32, transa: i32, transb: i32, m: i32, n: i32, k: i32, alpha: f32, a: *const f32, lda: i32, b: *const f32, ldb: i32, beta: f32, c: *mut f32, ldc: i32);
+    fn cblas_dtrsm(order: i32, side: i32, uplo: i32, transa: i32, diag: i32, m: i32, n: i32, alpha: f64, a: *const f64, lda: i32, b: *mut f64, ldb: i32);
     fn cblas_zgemm(order: i32, transa: i32, transb: i32, m: i32, n: i32, k: i32, alpha: *const Complex64, a: *const Complex64, lda: i32, b: *const Complex64, ldb: i32, beta: *const Complex64, c: *mut Complex64, ldc: i32);
     fn cblas_dsyrk(order: i32, uplo: i32, trans: i32, n: i32, k: i32, alpha: f64, a: *const f64, lda: i32, beta: f64, c: *mut f64, ldc: i32);
     fn cblas_zsyrk(order: i32, uplo: i32, trans: i32, n: i32, k: i32, alpha: *const Complex64, a: *const Complex64, lda: i32, beta: *const Complex64, c: *mut Complex64, ldc: i32);
@@ -19,10 +24,22 @@ extern "C" {
     fn LAPACKE_zgetrf(matrix_layout: i32, m: i32, n: i32, a: *mut Complex64, lda: i32, ipiv: *mut i32) -> i32;
     fn LAPACKE_dgetri(matrix_layout: i32, n: i32, a: *mut f64, lda: i32, ipiv: *const i32) -> i32;
     fn LAPACKE_zgetri(matrix_layout: i32, n: i32, a: *mut Complex64, lda: i32, ipiv: *const i32) -> i32;
+    fn LAPACKE_dgetrs(matrix_layout: i32, trans: u8, n: i32, nrhs: i32, a: *const f64, lda: i32, ipiv: *const i32, b: *mut f64, ldb: i32) -> i32;
     fn LAPACKE_dpotrf(matrix_layout: i32, uplo: u8, n: i32, a: *mut f64, lda: i32) -> i32;
     fn LAPACKE_zpotrf(matrix_layout: i32, uplo: u8, n: i32, a: *mut Complex64, lda: i32) -> i32;
+    fn LAPACKE_dsytrf(matrix_layout: i32, uplo: u8, n: i32, a: *mut f64, lda: i32, ipiv: *mut i32) -> i32;
+    fn LAPACKE_dsytrs(matrix_layout: i32, uplo: u8, n: i32, nrhs: i32, a: *const f64, lda: i32, ipiv: *const i32, b: *mut f64, ldb: i32) -> i32;
+    fn LAPACKE_dpotrs(matrix_layout: i32, uplo: u8, n: i32, nrhs: i32, a: *const f64, lda: i32, b: *mut f64, ldb: i32) -> i32;
     fn LAPACKE_dgeev(matrix_layout: i32, jobvl: u8, jobvr: u8, n: i32, a: *mut f64, lda: i32, wr: *mut f64, wi: *mut f64, vl: *mut f64, ldvl: i32, vr: *mut f64, ldvr: i32) -> i32;
+    fn LAPACKE_zgeev(matrix_layout: i32, jobvl: u8, jobvr: u8, n: i32, a: *mut Complex64, lda: i32, w: *mut Complex64, vl: *mut Complex64, ldvl: i32, vr: *mut Complex64, ldvr: i32) -> i32;
+    fn LAPACKE_dgeevx(matrix_layout: i32, balanc: u8, jobvl: u8, jobvr: u8, sense: u8, n: i32, a: *mut f64, lda: i32, wr: *mut f64, wi: *mut f64, vl: *mut f64, ldvl: i32, vr: *mut f64, ldvr: i32, ilo: *mut i32, ihi: *mut i32, scale: *mut f64, abnrm: *mut f64, rconde: *mut f64, rcondv: *mut f64) -> i32;
+    fn LAPACKE_dgebal(matrix_layout: i32, job: u8, n: i32, a: *mut f64, lda: i32, ilo: *mut i32, ihi: *mut i32, scale: *mut f64) -> i32;
+    fn LAPACKE_dgebak(matrix_layout: i32, job: u8, side: u8, n: i32, ilo: i32, ihi: i32, scale: *const f64, m: i32, v: *mut f64, ldv: i32) -> i32;
     fn LAPACKE_dsyev(matrix_layout: i32, jobz: u8, uplo: u8, n: i32, a: *mut f64, lda: i32, w: *mut f64) -> i32;
+    fn LAPACKE_dsbev(matrix_layout: i32, jobz: u8, uplo: u8, n: i32, kd: i32, ab: *mut f64, ldab: i32, w: *mut f64, z: *mut f64, ldz: i32) -> i32;
+    fn LAPACKE_dgeqrf(matrix_layout: i32, m: i32, n: i32, a: *mut f64, lda: i32, tau: *mut f64) -> i32;
+    fn LAPACKE_dorgqr(matrix_layout: i32, m: i32, n: i32, k: i32, a: *mut f64, lda: i32, tau: *const f64) -> i32;
+    fn LAPACKE_dormqr(matrix_layout: i32, side: u8, trans: u8, m: i32, n: i32, k: i32, a: *const f64, lda: i32, tau: *const f64, c: *mut f64, ldc: i32) -> i32;
 }
 
 /// Performs the matrix-matrix multiplication
@@ -103,6 +120,111 @@ pub fn dgemm(
     }
 }
 
+/// Performs the matrix-matrix multiplication (single precision)
+///
+/// ```text
+///   c  := α ⋅  a  ⋅  b  +  β ⋅  c
+/// (m,n)      (m,k) (k,n)      (m,n)
+/// ```
+///
+/// or with the transposed `a` and/or `b` as in [dgemm].
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/sgemm.f.html>
+///
+#[inline]
+pub fn sgemm(
+    trans_a: bool,
+    trans_b: bool,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: f32,
+    a: &[f32],
+    b: &[f32],
+    beta: f32,
+    c: &mut [f32],
+) {
+    let lda = if trans_a { k } else { m };
+    let ldb = if trans_b { n } else { k };
+    unsafe {
+        cblas_sgemm(
+            CBLAS_COL_MAJOR,
+            cblas_transpose(trans_a),
+            cblas_transpose(trans_b),
+            m,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_ptr(),
+            ldb,
+            beta,
+            c.as_mut_ptr(),
+            m,
+        );
+    }
+}
+
+/// Solves a triangular system of equations with multiple right-hand sides
+///
+/// Computes one of:
+///
+/// ```text
+/// left = true:
+///
+///   b  := α ⋅ inv(op(a)) ⋅  b
+/// (m,n)          (m,m)    (m,n)
+/// ```
+///
+/// ```text
+/// left = false:
+///
+///   b  := α ⋅  b  ⋅ inv(op(a))
+/// (m,n)      (m,n)      (n,n)
+/// ```
+///
+/// where `op(a) = a` or `op(a) = aᵀ` if `trans = true`, and `a` is an upper (`up = true`) or
+/// lower triangular matrix. Only the corresponding triangle of `a` is referenced. If `unit_diag = true`,
+/// the diagonal of `a` is assumed to be composed of ones and is not referenced.
+///
+/// **Note:** No test for singularity is performed.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dtrsm.f.html>
+///
+#[inline]
+pub fn dtrsm(left: bool, up: bool, trans: bool, unit_diag: bool, m: i32, n: i32, alpha: f64, a: &[f64], b: &mut [f64]) {
+    let lda = if left { m } else { n };
+    unsafe {
+        cblas_dtrsm(
+            CBLAS_COL_MAJOR,
+            cblas_side(left),
+            cblas_uplo(up),
+            cblas_transpose(trans),
+            cblas_diag(unit_diag),
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_mut_ptr(),
+            m,
+        );
+    }
+}
+
 /// Performs the matrix-matrix multiplication (complex version)
 ///
 /// Computes one of:
@@ -623,6 +745,57 @@ pub fn dgetri(n: i32, a: &mut [f64], ipiv: &[i32]) -> Result<(), StrError> {
     Ok(())
 }
 
+/// Solves a system of linear equations using the LU factorization computed by dgetrf
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅  X =   B       (trans = false)
+///   Aᵀ ⋅  X =   B       (trans = true)
+/// (n,n)  (n,nrhs)  (n,nrhs)
+/// ```
+///
+/// # Note
+///
+/// 1. See **dgetrf** to compute the factorization
+/// 2. The length of ipiv must be equal to `n` and the length of b must equal `n ⋅ nrhs`
+/// 3. On exit, b contains the solution X
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://netlib.org/lapack/explore-html/d6/d49/dgetrs_8f.html>
+///
+#[inline]
+pub fn dgetrs(trans: bool, n: i32, nrhs: i32, a: &[f64], ipiv: &[i32], b: &mut [f64]) -> Result<(), StrError> {
+    if to_i32(ipiv.len()) != n {
+        return Err("the length of ipiv must equal n");
+    }
+    if to_i32(b.len()) != n * nrhs {
+        return Err("the length of b must equal n ⋅ nrhs");
+    }
+    unsafe {
+        let info = LAPACKE_dgetrs(
+            LAPACK_COL_MAJOR,
+            if trans { b'T' } else { b'N' },
+            n,
+            nrhs,
+            a.as_ptr(),
+            i32::max(1, n),
+            ipiv.as_ptr(),
+            b.as_mut_ptr(),
+            i32::max(1, n),
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dgetrs failed");
+        }
+    }
+    Ok(())
+}
+
 /// Computes the inverse of a matrix using the LU factorization computed by zgetrf (complex version)
 ///
 /// This method inverts U and then computes inv(A) by solving the system
@@ -691,6 +864,165 @@ pub fn dpotrf(up: bool, n: i32, a: &mut [f64]) -> Result<(), StrError> {
     Ok(())
 }
 
+/// Solves a system of linear equations using the Cholesky factorization computed by dpotrf
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅    X    =    B
+/// (n,n)  (n,nrhs)  (n,nrhs)
+/// ```
+///
+/// where `A = Uᵀ ⋅ U` (up = true) or `A = L ⋅ Lᵀ` (up = false).
+///
+/// # Note
+///
+/// 1. See **dpotrf** to compute the factorization (the same `up` flag must be used)
+/// 2. The length of b must equal `n ⋅ nrhs`
+/// 3. On exit, b contains the solution X
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://netlib.org/lapack/explore-html/d0/d6b/dpotrs_8f.html>
+///
+#[inline]
+pub fn dpotrs(up: bool, n: i32, nrhs: i32, a: &[f64], b: &mut [f64]) -> Result<(), StrError> {
+    if to_i32(b.len()) != n * nrhs {
+        return Err("the length of b must equal n ⋅ nrhs");
+    }
+    unsafe {
+        let info = LAPACKE_dpotrs(
+            LAPACK_COL_MAJOR,
+            lapack_uplo(up),
+            n,
+            nrhs,
+            a.as_ptr(),
+            i32::max(1, n),
+            b.as_mut_ptr(),
+            i32::max(1, n),
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dpotrs failed");
+        }
+    }
+    Ok(())
+}
+
+/// Computes the factorization of a real symmetric (indefinite) matrix using the Bunch-Kaufman diagonal pivoting method
+///
+/// The factorization has the form
+///
+/// ```text
+/// up = true:
+///
+/// A = U ⋅ D ⋅ Uᵀ
+///
+/// or
+///
+/// up = false:
+///
+/// A = L ⋅ D ⋅ Lᵀ
+/// ```
+///
+/// where U (or L) is a product of permutation and unit upper (lower) triangular matrices, and D
+/// is symmetric and block diagonal with 1-by-1 and 2-by-2 diagonal blocks. Only the upper
+/// (`up = true`) or lower triangle of A is accessed.
+///
+/// # Note
+///
+/// 1. The matrix `a` will be modified (it will contain D and the multipliers of U or L)
+/// 2. The length of ipiv must be equal to `n`. The indices are 1-based (i.e. Fortran) and describe
+///    the interchanges and the block structure of D: if `ipiv[k] > 0`, then D(k,k) is a 1-by-1 block
+///    and the row/column k was interchanged with the row/column `ipiv[k]`; if `ipiv[k] = ipiv[k+1] < 0`
+///    (with `up = false`), then D(k:k+1,k:k+1) is a 2-by-2 block (see the reference)
+/// 3. An error is returned if the matrix is singular (D has a zero diagonal block)
+/// 4. See **dsytrs** to solve linear systems with the factorization
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dsytrf.f.html>
+///
+#[inline]
+pub fn dsytrf(up: bool, n: i32, a: &mut [f64], ipiv: &mut [i32]) -> Result<(), StrError> {
+    if to_i32(ipiv.len()) != n {
+        return Err("the length of ipiv must equal n");
+    }
+    unsafe {
+        let info = LAPACKE_dsytrf(
+            LAPACK_COL_MAJOR,
+            lapack_uplo(up),
+            n,
+            a.as_mut_ptr(),
+            i32::max(1, n),
+            ipiv.as_mut_ptr(),
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dsytrf failed");
+        }
+    }
+    Ok(())
+}
+
+/// Solves a system of linear equations using the factorization computed by dsytrf
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅    X    =    B
+/// (n,n)  (n,nrhs)  (n,nrhs)
+/// ```
+///
+/// where `A = U ⋅ D ⋅ Uᵀ` (up = true) or `A = L ⋅ D ⋅ Lᵀ` (up = false).
+///
+/// # Note
+///
+/// 1. See **dsytrf** to compute the factorization (the same `up` flag must be used)
+/// 2. The length of b must equal `n ⋅ nrhs`
+/// 3. On exit, b contains the solution X
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dsytrs.f.html>
+///
+#[inline]
+pub fn dsytrs(up: bool, n: i32, nrhs: i32, a: &[f64], ipiv: &[i32], b: &mut [f64]) -> Result<(), StrError> {
+    if to_i32(ipiv.len()) != n {
+        return Err("the length of ipiv must equal n");
+    }
+    if to_i32(b.len()) != n * nrhs {
+        return Err("the length of b must equal n ⋅ nrhs");
+    }
+    unsafe {
+        let info = LAPACKE_dsytrs(
+            LAPACK_COL_MAJOR,
+            lapack_uplo(up),
+            n,
+            nrhs,
+            a.as_ptr(),
+            i32::max(1, n),
+            ipiv.as_ptr(),
+            b.as_mut_ptr(),
+            i32::max(1, n),
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dsytrs failed");
+        }
+    }
+    Ok(())
+}
+
 /// Computes the Cholesky factorization of a complex Hermitian positive definite matrix A
 ///
 /// The factorization has the form
@@ -704,10 +1036,516 @@ pub fn dpotrf(up: bool, n: i32, a: &mut [f64]) -> Result<(), StrError> {
 ///
 /// up = false:
 ///
-/// A = L ⋅ Lᴴ
+/// A = L ⋅ Lᴴ
+/// ```
+///
+/// where U is an upper triangular matrix and L is lower triangular.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d1/db9/zpotrf_8f.html>
+///
+#[inline]
+pub fn zpotrf(up: bool, n: i32, a: &mut [Complex64]) -> Result<(), StrError> {
+    unsafe {
+        let info = LAPACKE_zpotrf(LAPACK_COL_MAJOR, lapack_uplo(up), n, a.as_mut_ptr(), n);
+        if info != 0_i32 {
+            return Err("LAPACK zpotrf failed");
+        }
+    }
+    Ok(())
+}
+
+/// Computes the eigenvalues and eigenvectors of a general matrix
+///
+/// The right eigenvector v(j) of A satisfies
+///
+/// ```text
+/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// ```
+///
+/// where lambda(j) is its eigenvalue.
+///
+/// The left eigenvector u(j) of A satisfies
+///
+/// ```text
+/// u(j)ᴴ ⋅ A = lambda(j) ⋅ u(j)ᴴ
+/// ```
+///
+/// where u(j)ᴴ denotes the conjugate-transpose of u(j).
+///
+/// The computed eigenvectors are normalized to have Euclidean norm
+/// equal to 1 and largest component real.
+///
+/// # Notes
+///
+/// 1. The matrix will be modified
+/// 2. If calc_vl==false, you may pass an empty array
+/// 3. If calc_vr==false, you may pass an empty array
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d9/d28/dgeev_8f.html>
+///
+#[inline]
+pub fn dgeev(
+    calc_vl: bool,
+    calc_vr: bool,
+    n: i32,
+    a: &mut [f64],
+    wr: &mut [f64],
+    wi: &mut [f64],
+    vl: &mut [f64],
+    vr: &mut [f64],
+) -> Result<(), StrError> {
+    let ldvl = if calc_vl { n } else { 1 };
+    let ldvr = if calc_vr { n } else { 1 };
+    unsafe {
+        let info = LAPACKE_dgeev(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_vl),
+            lapack_job_vlr(calc_vr),
+            n,
+            a.as_mut_ptr(),
+            n,
+            wr.as_mut_ptr(),
+            wi.as_mut_ptr(),
+            vl.as_mut_ptr(),
+            ldvl,
+            vr.as_mut_ptr(),
+            ldvr,
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dgeev failed");
+        }
+    }
+    Ok(())
+}
+
+/// Computes the eigenvalues and eigenvectors of a general matrix (complex version)
+///
+/// The right eigenvector v(j) of A satisfies
+///
+/// ```text
+/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// ```
+///
+/// where lambda(j) is its eigenvalue.
+///
+/// The left eigenvector u(j) of A satisfies
+///
+/// ```text
+/// u(j)ᴴ ⋅ A = lambda(j) ⋅ u(j)ᴴ
+/// ```
+///
+/// where u(j)ᴴ denotes the conjugate-transpose of u(j).
+///
+/// The computed eigenvectors are normalized to have Euclidean norm
+/// equal to 1 and largest component real.
+///
+/// # Notes
+///
+/// 1. The matrix will be modified
+/// 2. If calc_vl==false, you may pass an empty array
+/// 3. If calc_vr==false, you may pass an empty array
+/// 4. In contrast to [dgeev], the eigenvalues and eigenvectors are stored directly as complex numbers
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/zgeev.f.html>
+///
+#[inline]
+pub fn zgeev(
+    calc_vl: bool,
+    calc_vr: bool,
+    n: i32,
+    a: &mut [Complex64],
+    w: &mut [Complex64],
+    vl: &mut [Complex64],
+    vr: &mut [Complex64],
+) -> Result<(), StrError> {
+    let ldvl = if calc_vl { n } else { 1 };
+    let ldvr = if calc_vr { n } else { 1 };
+    unsafe {
+        let info = LAPACKE_zgeev(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_vl),
+            lapack_job_vlr(calc_vr),
+            n,
+            a.as_mut_ptr(),
+            n,
+            w.as_mut_ptr(),
+            vl.as_mut_ptr(),
+            ldvl,
+            vr.as_mut_ptr(),
+            ldvr,
+        );
+        if info != 0_i32 {
+            return Err("LAPACK zgeev failed");
+        }
+    }
+    Ok(())
+}
+
+/// Computes the eigenvalues and eigenvectors of a general matrix (expert driver)
+///
+/// Computes the same quantities as [dgeev] and, optionally, balances the matrix and
+/// computes the reciprocal condition numbers of the eigenvalues and right eigenvectors.
+///
+/// The reciprocal condition number of the eigenvalue lambda(j) is
+///
+/// ```text
+/// rconde(j) = |u(j)ᴴ ⋅ v(j)|
+/// ```
+///
+/// where u(j) and v(j) are the left and right eigenvectors normalized to have Euclidean
+/// norm equal to 1. Therefore, small values of rconde(j) indicate sensitive eigenvalues.
+///
+/// # Input
+///
+/// * balanc == b'N' -- do not balance the matrix
+/// * balanc == b'P' -- permute only
+/// * balanc == b'S' -- scale only
+/// * balanc == b'B' -- permute and scale
+/// * sense == b'N' -- no condition numbers are computed
+/// * sense == b'E' -- computes the condition numbers of the eigenvalues only
+/// * sense == b'V' -- computes the condition numbers of the right eigenvectors only
+/// * sense == b'B' -- computes the condition numbers of the eigenvalues and right eigenvectors
+///
+/// # Output
+///
+/// Returns `(ilo, ihi, abnrm)` where `ilo` and `ihi` are the (one-based) indices determined
+/// by the balancing, and `abnrm` is the one-norm of the balanced matrix. Also, `scale` holds
+/// the details of the permutations and scaling factors applied when balancing.
+///
+/// # Notes
+///
+/// 1. The matrix will be modified
+/// 2. If calc_vl==false, you may pass an empty array
+/// 3. If calc_vr==false, you may pass an empty array
+/// 4. If sense==b'E' or sense==b'B', both calc_vl and calc_vr must be true
+/// 5. If rconde or rcondv are not computed, you may pass an empty array
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d4/d4a/dgeevx_8f.html>
+///
+#[inline]
+pub fn dgeevx(
+    balanc: u8,
+    calc_vl: bool,
+    calc_vr: bool,
+    sense: u8,
+    n: i32,
+    a: &mut [f64],
+    wr: &mut [f64],
+    wi: &mut [f64],
+    vl: &mut [f64],
+    vr: &mut [f64],
+    scale: &mut [f64],
+    rconde: &mut [f64],
+    rcondv: &mut [f64],
+) -> Result<(i32, i32, f64), StrError> {
+    if (sense == b'E' || sense == b'B') && !(calc_vl && calc_vr) {
+        return Err("dgeevx requires both left and right eigenvectors to compute the condition numbers");
+    }
+    let ldvl = if calc_vl { n } else { 1 };
+    let ldvr = if calc_vr { n } else { 1 };
+    let mut ilo = 0_i32;
+    let mut ihi = 0_i32;
+    let mut abnrm = 0.0;
+    unsafe {
+        let info = LAPACKE_dgeevx(
+            LAPACK_COL_MAJOR,
+            balanc,
+            lapack_job_vlr(calc_vl),
+            lapack_job_vlr(calc_vr),
+            sense,
+            n,
+            a.as_mut_ptr(),
+            n,
+            wr.as_mut_ptr(),
+            wi.as_mut_ptr(),
+            vl.as_mut_ptr(),
+            ldvl,
+            vr.as_mut_ptr(),
+            ldvr,
+            &mut ilo,
+            &mut ihi,
+            scale.as_mut_ptr(),
+            &mut abnrm,
+            rconde.as_mut_ptr(),
+            rcondv.as_mut_ptr(),
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dgeevx failed");
+        }
+    }
+    Ok((ilo, ihi, abnrm))
+}
+
+/// Balances a general matrix
+///
+/// Computes the similarity transformation
+///
+/// ```text
+/// a := D⁻¹ ⋅ Pᵀ ⋅ a ⋅ P ⋅ D
+/// ```
+///
+/// where P is a permutation matrix and D is a diagonal matrix, such that the rows and columns
+/// of the balanced matrix have norms as close as possible. The permutations isolate eigenvalues
+/// in the rows and columns 1 to ilo-1 and ihi+1 to n (one-based) of the balanced matrix.
+///
+/// # Input
+///
+/// * job == b'N' -- do nothing, but set ilo = 1, ihi = n, and scale(j) = 1
+/// * job == b'P' -- permute only
+/// * job == b'S' -- scale only
+/// * job == b'B' -- permute and scale
+///
+/// # Output
+///
+/// Returns `(ilo, ihi)` (one-based). Also, `scale` (n) holds the details of the permutations
+/// and scaling factors (see the reference).
+///
+/// # Note
+///
+/// * The matrix will be modified
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/df/df3/dgebal_8f.html>
+///
+#[inline]
+pub fn dgebal(job: u8, n: i32, a: &mut [f64], scale: &mut [f64]) -> Result<(i32, i32), StrError> {
+    let mut ilo = 0_i32;
+    let mut ihi = 0_i32;
+    unsafe {
+        let info = LAPACKE_dgebal(
+            LAPACK_COL_MAJOR,
+            job,
+            n,
+            a.as_mut_ptr(),
+            n,
+            &mut ilo,
+            &mut ihi,
+            scale.as_mut_ptr(),
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dgebal failed");
+        }
+    }
+    Ok((ilo, ihi))
+}
+
+/// Back-transforms the eigenvectors of a balanced matrix
+///
+/// Computes the eigenvectors of the original matrix from the eigenvectors of the matrix
+/// balanced by [dgebal]. For the right eigenvectors:
+///
+/// ```text
+/// v := P ⋅ D ⋅ v
+/// ```
+///
+/// and for the left eigenvectors:
+///
+/// ```text
+/// v := P ⋅ D⁻¹ ⋅ v
+/// ```
+///
+/// # Input
+///
+/// * `job` -- the same job given to [dgebal]
+/// * `right` -- the columns of `v` are right eigenvectors; otherwise, they are left eigenvectors
+/// * `n` -- the number of rows of `v`
+/// * `ilo`, `ihi`, and `scale` -- the output of [dgebal]
+/// * `m` -- the number of columns of `v`
+/// * `v` -- (n,m) the eigenvectors [will be modified]
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/db/d84/dgebak_8f.html>
+///
+#[inline]
+pub fn dgebak(
+    job: u8,
+    right: bool,
+    n: i32,
+    ilo: i32,
+    ihi: i32,
+    scale: &[f64],
+    m: i32,
+    v: &mut [f64],
+) -> Result<(), StrError> {
+    let side = if right { b'R' } else { b'L' };
+    unsafe {
+        let info = LAPACKE_dgebak(
+            LAPACK_COL_MAJOR,
+            job,
+            side,
+            n,
+            ilo,
+            ihi,
+            scale.as_ptr(),
+            m,
+            v.as_mut_ptr(),
+            n,
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dgebak failed");
+        }
+    }
+    Ok(())
+}
+
+/// Computes the eigenvalues and eigenvectors of a symmetric matrix
+///
+/// The eigenvector v(j) of A satisfies
+///
+/// ```text
+/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// ```
+///
+/// where lambda(j) is its eigenvalue.
+///
+/// The computed eigenvectors are normalized to have Euclidean norm
+/// equal to 1 and largest component real.
+///
+/// # Notes
+///
+/// * The matrix will be modified (it will contain the eigenvectors as columns)
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://netlib.org/lapack/explore-html/dd/d4c/dsyev_8f.html>
+///
+#[inline]
+pub fn dsyev(calc_v: bool, up: bool, n: i32, a: &mut [f64], w: &mut [f64]) -> Result<(), StrError> {
+    unsafe {
+        let info = LAPACKE_dsyev(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_v),
+            lapack_uplo(up),
+            n,
+            a.as_mut_ptr(),
+            n,
+            w.as_mut_ptr(),
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dsyev failed");
+        }
+    }
+    Ok(())
+}
+
+/// Computes the eigenvalues and eigenvectors of a symmetric band matrix
+///
+/// The eigenvector z(j) of A satisfies
+///
+/// ```text
+/// A ⋅ z(j) = lambda(j) ⋅ z(j)
+/// ```
+///
+/// where lambda(j) is its eigenvalue. The eigenvalues are returned in ascending order.
+///
+/// The matrix A (n × n) with `kd` super-diagonals (or sub-diagonals) is given in the
+/// LAPACK band storage `ab` (ldab × n), with `ldab ≥ kd + 1`:
+///
+/// ```text
+/// up = true:  ab[kd + i - j + j⋅ldab] = A(i,j)  for max(0,j-kd) ≤ i ≤ j
+/// up = false: ab[i - j + j⋅ldab]      = A(i,j)  for j ≤ i ≤ min(n-1,j+kd)
+/// ```
+///
+/// # Notes
+///
+/// * The band matrix `ab` will be modified (destroyed)
+/// * If `calc_v` is false, `z` is not referenced and may have any length
+/// * If `calc_v` is true, `z` (n × n) will contain the eigenvectors as columns
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://netlib.org/lapack/explore-html/d3/dfb/dsbev_8f.html>
+///
+#[inline]
+pub fn dsbev(
+    calc_v: bool,
+    up: bool,
+    n: i32,
+    kd: i32,
+    ab: &mut [f64],
+    ldab: i32,
+    w: &mut [f64],
+    z: &mut [f64],
+) -> Result<(), StrError> {
+    let ldz = if calc_v { n } else { 1 };
+    let mut unused = [0.0];
+    let z_ptr = if calc_v { z.as_mut_ptr() } else { unused.as_mut_ptr() };
+    unsafe {
+        let info = LAPACKE_dsbev(
+            LAPACK_COL_MAJOR,
+            lapack_job_vlr(calc_v),
+            lapack_uplo(up),
+            n,
+            kd,
+            ab.as_mut_ptr(),
+            ldab,
+            w.as_mut_ptr(),
+            z_ptr,
+            ldz,
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dsbev failed");
+        }
+    }
+    Ok(())
+}
+
+/// Computes the QR factorization of a real general matrix
+///
+/// ```text
+///   a  =  q  ⋅  r
+/// (m,n) (m,m) (m,n)
 /// ```
 ///
-/// where U is an upper triangular matrix and L is lower triangular.
+/// # Note
+///
+/// 1. The length of tau must be equal to `min(m,n)`
+/// 2. On exit, the elements on and above the diagonal of `a` contain the (min(m,n),n) upper
+///    trapezoidal matrix r; the elements below the diagonal, with the array tau, represent
+///    the orthogonal matrix q as a product of min(m,n) elementary reflectors
+/// 3. See **dorgqr** to generate q and **dormqr** to multiply a matrix by q
 ///
 /// # Important
 ///
@@ -715,45 +1553,37 @@ pub fn dpotrf(up: bool, n: i32, a: &mut [f64]) -> Result<(), StrError> {
 ///
 /// # Reference
 ///
-/// <http://www.netlib.org/lapack/explore-html/d1/db9/zpotrf_8f.html>
+/// <https://netlib.org/lapack/explore-html/dd/d9a/group__double_g_ecomputational_ga3766ea903391b5cf9008132f7440ec7b.html>
 ///
 #[inline]
-pub fn zpotrf(up: bool, n: i32, a: &mut [Complex64]) -> Result<(), StrError> {
+pub fn dgeqrf(m: i32, n: i32, a: &mut [f64], tau: &mut [f64]) -> Result<(), StrError> {
+    if to_i32(tau.len()) != i32::min(m, n) {
+        return Err("the length of tau must equal min(m,n)");
+    }
     unsafe {
-        let info = LAPACKE_zpotrf(LAPACK_COL_MAJOR, lapack_uplo(up), n, a.as_mut_ptr(), n);
+        let info = LAPACKE_dgeqrf(LAPACK_COL_MAJOR, m, n, a.as_mut_ptr(), i32::max(1, m), tau.as_mut_ptr());
         if info != 0_i32 {
-            return Err("LAPACK zpotrf failed");
+            return Err("LAPACK dgeqrf failed");
         }
     }
     Ok(())
 }
 
-/// Computes the eigenvalues and eigenvectors of a general matrix
-///
-/// The right eigenvector v(j) of A satisfies
-///
-/// ```text
-/// A ⋅ v(j) = lambda(j) ⋅ v(j)
-/// ```
-///
-/// where lambda(j) is its eigenvalue.
+/// Generates the real (m,n) matrix q with orthonormal columns defined by dgeqrf
 ///
-/// The left eigenvector u(j) of A satisfies
+/// The matrix q is defined as the first n columns of a product of k elementary reflectors
 ///
 /// ```text
-/// u(j)ᴴ ⋅ A = lambda(j) ⋅ u(j)ᴴ
+/// q = H(1) ⋅ H(2) ⋅ ... ⋅ H(k)
 /// ```
 ///
-/// where u(j)ᴴ denotes the conjugate-transpose of u(j).
-///
-/// The computed eigenvectors are normalized to have Euclidean norm
-/// equal to 1 and largest component real.
-///
-/// # Notes
+/// # Note
 ///
-/// 1. The matrix will be modified
-/// 2. If calc_vl==false, you may pass an empty array
-/// 3. If calc_vr==false, you may pass an empty array
+/// 1. It is required that `m ≥ n ≥ k`; thus, `n = m` gives the full (square) q and
+///    `n = k = min(m,n_a)` gives the thin (economy) q
+/// 2. On entry, the first k columns of `a` must contain the vectors defining the
+///    elementary reflectors, as returned by **dgeqrf**
+/// 3. On exit, `a` contains the (m,n) matrix q
 ///
 /// # Important
 ///
@@ -761,59 +1591,43 @@ pub fn zpotrf(up: bool, n: i32, a: &mut [Complex64]) -> Result<(), StrError> {
 ///
 /// # Reference
 ///
-/// <http://www.netlib.org/lapack/explore-html/d9/d28/dgeev_8f.html>
+/// <https://netlib.org/lapack/explore-html/d9/d1d/dorgqr_8f.html>
 ///
 #[inline]
-pub fn dgeev(
-    calc_vl: bool,
-    calc_vr: bool,
-    n: i32,
-    a: &mut [f64],
-    wr: &mut [f64],
-    wi: &mut [f64],
-    vl: &mut [f64],
-    vr: &mut [f64],
-) -> Result<(), StrError> {
-    let ldvl = if calc_vl { n } else { 1 };
-    let ldvr = if calc_vr { n } else { 1 };
+pub fn dorgqr(m: i32, n: i32, k: i32, a: &mut [f64], tau: &[f64]) -> Result<(), StrError> {
+    if m < n || n < k {
+        return Err("dorgqr requires m ≥ n ≥ k");
+    }
+    if to_i32(tau.len()) < k {
+        return Err("the length of tau must be at least k");
+    }
     unsafe {
-        let info = LAPACKE_dgeev(
-            LAPACK_COL_MAJOR,
-            lapack_job_vlr(calc_vl),
-            lapack_job_vlr(calc_vr),
-            n,
-            a.as_mut_ptr(),
-            n,
-            wr.as_mut_ptr(),
-            wi.as_mut_ptr(),
-            vl.as_mut_ptr(),
-            ldvl,
-            vr.as_mut_ptr(),
-            ldvr,
-        );
+        let info = LAPACKE_dorgqr(LAPACK_COL_MAJOR, m, n, k, a.as_mut_ptr(), i32::max(1, m), tau.as_ptr());
         if info != 0_i32 {
-            return Err("LAPACK dgeev failed");
+            return Err("LAPACK dorgqr failed");
         }
     }
     Ok(())
 }
 
-/// Computes the eigenvalues and eigenvectors of a symmetric matrix
+/// Multiplies a real matrix by the orthogonal matrix q defined by dgeqrf
 ///
-/// The eigenvector v(j) of A satisfies
+/// Computes one of:
 ///
 /// ```text
-/// A ⋅ v(j) = lambda(j) ⋅ v(j)
+/// left = true,  trans = false:  c := q ⋅ c
+/// left = true,  trans = true:   c := qᵀ ⋅ c
+/// left = false, trans = false:  c := c ⋅ q
+/// left = false, trans = true:   c := c ⋅ qᵀ
 /// ```
 ///
-/// where lambda(j) is its eigenvalue.
-///
-/// The computed eigenvectors are normalized to have Euclidean norm
-/// equal to 1 and largest component real.
+/// where q is the product of k elementary reflectors computed by **dgeqrf**. Thus, q is never formed.
 ///
-/// # Notes
+/// # Note
 ///
-/// * The matrix will be modified (it will contain the eigenvectors as columns)
+/// 1. The matrix c is (m,n); thus, q is (m,m) if `left = true` or (n,n) if `left = false`
+/// 2. The matrix `a` is the output of **dgeqrf** with leading dimension `m` if `left = true` or
+///    `n` if `left = false`; the length of tau must be at least k
 ///
 /// # Important
 ///
@@ -821,22 +1635,42 @@ pub fn dgeev(
 ///
 /// # Reference
 ///
-/// <https://netlib.org/lapack/explore-html/dd/d4c/dsyev_8f.html>
+/// <https://netlib.org/lapack/explore-html/da/d82/dormqr_8f.html>
 ///
 #[inline]
-pub fn dsyev(calc_v: bool, up: bool, n: i32, a: &mut [f64], w: &mut [f64]) -> Result<(), StrError> {
+pub fn dormqr(
+    left: bool,
+    trans: bool,
+    m: i32,
+    n: i32,
+    k: i32,
+    a: &[f64],
+    tau: &[f64],
+    c: &mut [f64],
+) -> Result<(), StrError> {
+    let lda = if left { i32::max(1, m) } else { i32::max(1, n) };
+    if to_i32(tau.len()) < k {
+        return Err("the length of tau must be at least k");
+    }
+    if to_i32(c.len()) != m * n {
+        return Err("the length of c must equal m ⋅ n");
+    }
     unsafe {
-        let info = LAPACKE_dsyev(
+        let info = LAPACKE_dormqr(
             LAPACK_COL_MAJOR,
-            lapack_job_vlr(calc_v),
-            lapack_uplo(up),
-            n,
-            a.as_mut_ptr(),
+            if left { b'L' } else { b'R' },
+            if trans { b'T' } else { b'N' },
+            m,
             n,
-            w.as_mut_ptr(),
+            k,
+            a.as_ptr(),
+            lda,
+            tau.as_ptr(),
+            c.as_mut_ptr(),
+            i32::max(1, m),
         );
         if info != 0_i32 {
-            return Err("LAPACK dsyev failed");
+            return Err("LAPACK dormqr failed");
         }
     }
     Ok(())
@@ -847,14 +1681,29 @@ pub fn dsyev(calc_v: bool, up: bool, n: i32, a: &mut [f64], w: &mut [f64]) -> Re
 #[cfg(test)]
 mod tests {
     use super::{
-        dgeev, dgemm, dgesvd, dgetrf, dgetri, dlange, dpotrf, dsyev, dsyrk, zgemm, zgesvd, zgetrf, zgetri, zherk,
-        zlange, zpotrf, zsyrk,
+        dgebak, dgebal, dgeev, dgeevx, dgemm, dgeqrf, dgesvd, dgetrf, dgetri, dgetrs, dlange, dorgqr, dormqr, dpotrf,
+        dpotrs, dsbev, dsyev, dsyrk, dsytrf, dsytrs, dtrsm, sgemm, zgeev, zgemm, zgesvd, zgetrf, zgetri, zherk, zlange,
+        zpotrf, zsyrk,
     };
     use crate::conversions::{col_major, col_major_complex, dgeev_data, dgeev_data_lr};
     use crate::{to_i32, StrError};
     use num_complex::{Complex64, ComplexFloat};
     use russell_chk::{approx_eq, complex_approx_eq, complex_vec_approx_eq, vec_approx_eq};
 
+    #[test]
+    fn sgemm_works() {
+        // a = [[1, 2], [3, 4], [5, 6]] and b = [[1, 0, -1], [2, 1, 0]] (col-major)
+        let a = [1.0_f32, 3.0, 5.0, 2.0, 4.0, 6.0];
+        let b = [1.0_f32, 2.0, 0.0, 1.0, -1.0, 0.0];
+        let mut c = [1.0_f32; 9];
+        // c := 2⋅a⋅b - c
+        sgemm(false, false, 3, 3, 2, 2.0, &a, &b, -1.0, &mut c);
+        assert_eq!(c, [9.0, 21.0, 33.0, 3.0, 7.0, 11.0, -3.0, -7.0, -11.0]);
+        // c := bᵀ⋅aᵀ = (a⋅b)ᵀ
+        sgemm(true, true, 3, 3, 2, 1.0, &b, &a, 0.0, &mut c);
+        assert_eq!(c, [5.0, 2.0, -1.0, 11.0, 4.0, -3.0, 17.0, 6.0, -5.0]);
+    }
+
     #[test]
     fn dgemm_notrans_notrans_works() {
         // 0.5⋅a⋅b + 2⋅c
@@ -1049,6 +1898,61 @@ mod tests {
         vec_approx_eq(&c, &correct, 1e-15);
     }
 
+    #[test]
+    fn dtrsm_works() {
+        // upper triangular matrix (the lower triangle is not referenced)
+        #[rustfmt::skip]
+        let a = col_major(3, 3, &[
+              2.0,   1.0, -1.0,
+            100.0,   4.0,  2.0,
+            100.0, 100.0,  5.0,
+        ]);
+
+        // a ⋅ x = α ⋅ b with x = [[1, -1], [2, 0], [3, 1]] and α = 0.5
+        #[rustfmt::skip]
+        let mut b = col_major(3, 2, &[
+             2.0, -6.0,
+            28.0,  4.0,
+            30.0, 10.0,
+        ]);
+        dtrsm(true, true, false, false, 3, 2, 0.5, &a, &mut b);
+        vec_approx_eq(&b, &[1.0, 2.0, 3.0, -1.0, 0.0, 1.0], 1e-15);
+
+        // aᵀ ⋅ x = b with the same x
+        #[rustfmt::skip]
+        let mut b = col_major(3, 2, &[
+             2.0, -2.0,
+             9.0, -1.0,
+            18.0,  6.0,
+        ]);
+        dtrsm(true, true, true, false, 3, 2, 1.0, &a, &mut b);
+        vec_approx_eq(&b, &[1.0, 2.0, 3.0, -1.0, 0.0, 1.0], 1e-15);
+
+        // x ⋅ a = b with x = [[1, 2, 3], [-1, 0, 1]] and unit diagonal
+        #[rustfmt::skip]
+        let mut b = col_major(2, 3, &[
+             1.0,  3.0, 6.0,
+            -1.0, -1.0, 2.0,
+        ]);
+        dtrsm(false, true, false, true, 2, 3, 1.0, &a, &mut b);
+        vec_approx_eq(&b, &[1.0, -1.0, 2.0, 0.0, 3.0, 1.0], 1e-15);
+
+        // lower triangular (the upper triangle is not referenced): x ⋅ lᵀ = b with l = aᵀ
+        #[rustfmt::skip]
+        let l = col_major(3, 3, &[
+             2.0, 100.0, 100.0,
+             1.0,   4.0, 100.0,
+            -1.0,   2.0,   5.0,
+        ]);
+        #[rustfmt::skip]
+        let mut b = col_major(2, 3, &[
+             2.0,  9.0, 18.0,
+            -2.0, -1.0,  6.0,
+        ]);
+        dtrsm(false, false, true, false, 2, 3, 1.0, &l, &mut b);
+        vec_approx_eq(&b, &[1.0, -1.0, 2.0, 0.0, 3.0, 1.0], 1e-15);
+    }
+
     #[test]
     fn zgemm_notrans_notrans_works() {
         // (0.5-2i)⋅a⋅b + (2-4i)⋅c
@@ -1654,6 +2558,41 @@ mod tests {
         assert_eq!(dgetri(n_i32, &mut a, &ipiv), Err("LAPACK dgetri failed"));
     }
 
+    #[test]
+    fn dgetrs_captures_errors() {
+        let a = vec![1.0; 4];
+        let mut b = vec![1.0; 2];
+        assert_eq!(
+            dgetrs(false, 2, 1, &a, &[1], &mut b),
+            Err("the length of ipiv must equal n")
+        );
+        assert_eq!(
+            dgetrs(false, 2, 2, &a, &[1, 2], &mut b),
+            Err("the length of b must equal n ⋅ nrhs")
+        );
+    }
+
+    #[test]
+    fn dgetrs_works() -> Result<(), StrError> {
+        #[rustfmt::skip]
+        let mut a = col_major(3, 3, &[
+            1.0,  3.0, -2.0,
+            3.0,  5.0,  6.0,
+            2.0,  4.0,  3.0,
+        ]);
+        let mut ipiv = vec![0_i32; 3];
+        dgetrf(3, 3, &mut a, &mut ipiv)?;
+        // a ⋅ x = b
+        let mut b = vec![5.0, 7.0, 8.0];
+        dgetrs(false, 3, 1, &a, &ipiv, &mut b)?;
+        vec_approx_eq(&b, &[-15.0, 8.0, 2.0], 1e-13);
+        // aᵀ ⋅ x = b (two right-hand sides)
+        let mut b = vec![6.0, 12.0, 7.0, 1.0, 3.0, -2.0];
+        dgetrs(true, 3, 2, &a, &ipiv, &mut b)?;
+        vec_approx_eq(&b, &[1.0, 1.0, 1.0, 1.0, 0.0, 0.0], 1e-13);
+        Ok(())
+    }
+
     #[test]
     fn dgetrf_and_dgetri_work() -> Result<(), StrError> {
         // matrix
@@ -1851,6 +2790,99 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn dpotrs_works() -> Result<(), StrError> {
+        // matrix a (symmetric positive definite)
+        #[rustfmt::skip]
+        let a = col_major(4, 4, &[
+             3.0,  0.0, -3.0,  0.0,
+             0.0,  3.0,  1.0,  2.0,
+            -3.0,  1.0,  4.0,  1.0,
+             0.0,  2.0,  1.0,  3.0,
+        ]);
+
+        // right-hand sides: b = a⋅[1,2,3,4] and b = a⋅[1,1,1,1]
+        let (n, nrhs) = (4_i32, 2_i32);
+        #[rustfmt::skip]
+        let b = vec![
+            -6.0, 17.0, 15.0, 19.0,
+             0.0,  6.0,  3.0,  6.0,
+        ];
+        #[rustfmt::skip]
+        let x_correct = vec![
+            1.0, 2.0, 3.0, 4.0,
+            1.0, 1.0, 1.0, 1.0,
+        ];
+
+        // check the wrong length of b
+        let mut b_wrong = vec![0.0; 3];
+        assert_eq!(
+            dpotrs(true, n, 1, &a, &mut b_wrong),
+            Err("the length of b must equal n ⋅ nrhs")
+        );
+
+        // solve with both the upper and the lower factors
+        for up in [true, false] {
+            let mut f = a.clone();
+            dpotrf(up, n, &mut f)?;
+            let mut x = b.clone();
+            dpotrs(up, n, nrhs, &f, &mut x)?;
+            vec_approx_eq(&x, &x_correct, 1e-14);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dsytrf_and_dsytrs_capture_errors() {
+        let mut a = vec![0.0; 4];
+        assert_eq!(
+            dsytrf(false, 2, &mut a, &mut [0; 1]),
+            Err("the length of ipiv must equal n")
+        );
+        assert_eq!(dsytrf(false, 2, &mut a, &mut [0; 2]), Err("LAPACK dsytrf failed"));
+        let ipiv = [1, 2];
+        assert_eq!(
+            dsytrs(false, 2, 1, &a, &ipiv[..1], &mut [0.0; 2]),
+            Err("the length of ipiv must equal n")
+        );
+        assert_eq!(
+            dsytrs(false, 2, 1, &a, &ipiv, &mut [0.0; 3]),
+            Err("the length of b must equal n ⋅ nrhs")
+        );
+    }
+
+    #[test]
+    fn dsytrf_and_dsytrs_work() -> Result<(), StrError> {
+        // symmetric indefinite (saddle-point) matrix with a zero diagonal block
+        #[rustfmt::skip]
+        let a = col_major(4, 4, &[
+            4.0, 1.0, 1.0, 0.0,
+            1.0, 3.0, 0.0, 1.0,
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+        ]);
+
+        // right-hand sides: b = a⋅[1,2,3,4] and b = a⋅[1,-1,1,-1]
+        let (n, nrhs) = (4_i32, 2_i32);
+        #[rustfmt::skip]
+        let b = vec![
+            9.0, 11.0, 1.0, 2.0,
+            4.0, -3.0, 1.0, -1.0,
+        ];
+        #[rustfmt::skip]
+        let x_correct = vec![
+            1.0,  2.0, 3.0,  4.0,
+            1.0, -1.0, 1.0, -1.0,
+        ];
+        let mut f = a.clone();
+        let mut ipiv = vec![0; n as usize];
+        dsytrf(false, n, &mut f, &mut ipiv)?;
+        let mut x = b.clone();
+        dsytrs(false, n, nrhs, &f, &ipiv, &mut x)?;
+        vec_approx_eq(&x, &x_correct, 1e-14);
+        Ok(())
+    }
+
     #[test]
     fn zpotrf_captures_errors() {
         let mut a = vec![Complex64::new(0.0, 0.0); 4];
@@ -2049,6 +3081,184 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn zgeev_captures_errors() {
+        let m = 1_usize;
+        let mut a = vec![Complex64::new(0.0, 0.0); m * m];
+        let mut w = vec![Complex64::new(0.0, 0.0); m];
+        let mut vl = vec![Complex64::new(0.0, 0.0); m * m];
+        let mut vr = vec![Complex64::new(0.0, 0.0); m * m];
+        let wrong = -1_i32; // <<< wrong
+        assert_eq!(
+            zgeev(true, true, wrong, &mut a, &mut w, &mut vl, &mut vr),
+            Err("LAPACK zgeev failed")
+        );
+    }
+
+    #[test]
+    fn zgeev_works() -> Result<(), StrError> {
+        // matrix a
+        #[rustfmt::skip]
+        let a_data = [
+            Complex64::new(1.0, 2.0), Complex64::new(2.0, 0.0), Complex64::new(0.0,  0.0),
+            Complex64::new(-1.0, 0.0), Complex64::new(3.0, 0.0), Complex64::new(1.0, -1.0),
+            Complex64::new(0.0, 0.5), Complex64::new(0.0, 0.0), Complex64::new(2.0, -1.0),
+        ];
+        let mut a = col_major_complex(3, 3, &a_data);
+        let a_copy = a.to_vec();
+
+        // compute eigen-things
+        let n = 3_i32;
+        let sz = n as usize;
+        let mut w = vec![Complex64::new(0.0, 0.0); sz];
+        let mut vl = vec![Complex64::new(0.0, 0.0); sz * sz];
+        let mut vr = vec![Complex64::new(0.0, 0.0); sz * sz];
+        zgeev(true, true, n, &mut a, &mut w, &mut vl, &mut vr)?;
+
+        // the sum and product of the eigenvalues equal the trace and determinant, respectively
+        let sum: Complex64 = w.iter().sum();
+        let prod: Complex64 = w.iter().product();
+        complex_approx_eq(sum, Complex64::new(6.0, 1.0), 1e-14);
+        complex_approx_eq(prod, Complex64::new(17.0, 8.0), 1e-13);
+
+        // check a ⋅ v(j) = lambda(j) ⋅ v(j) and u(j)ᴴ ⋅ a = lambda(j) ⋅ u(j)ᴴ
+        for j in 0..sz {
+            let mut norm_v = 0.0;
+            let mut norm_u = 0.0;
+            for i in 0..sz {
+                let mut av = Complex64::new(0.0, 0.0);
+                let mut ua = Complex64::new(0.0, 0.0);
+                for k in 0..sz {
+                    av += a_copy[i + k * sz] * vr[k + j * sz];
+                    ua += vl[k + j * sz].conj() * a_copy[k + i * sz];
+                }
+                complex_approx_eq(av, w[j] * vr[i + j * sz], 1e-13);
+                complex_approx_eq(ua, w[j] * vl[i + j * sz].conj(), 1e-13);
+                norm_v += vr[i + j * sz].norm_sqr();
+                norm_u += vl[i + j * sz].norm_sqr();
+            }
+            approx_eq(norm_v, 1.0, 1e-14);
+            approx_eq(norm_u, 1.0, 1e-14);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dgeevx_captures_errors() {
+        let mut a = col_major(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+        let (mut wr, mut wi, mut scale) = (vec![0.0; 2], vec![0.0; 2], vec![0.0; 2]);
+        let (mut rconde, mut rcondv) = (vec![0.0; 2], vec![0.0; 2]);
+        let mut vr = vec![0.0; 4];
+        let mut empty: Vec<f64> = Vec::new();
+        assert_eq!(
+            dgeevx(
+                b'N',
+                false,
+                true,
+                b'E',
+                2,
+                &mut a,
+                &mut wr,
+                &mut wi,
+                &mut empty,
+                &mut vr,
+                &mut scale,
+                &mut rconde,
+                &mut rcondv,
+            ),
+            Err("dgeevx requires both left and right eigenvectors to compute the condition numbers")
+        );
+    }
+
+    #[test]
+    fn dgeevx_works() -> Result<(), StrError> {
+        // upper triangular matrix with eigenvalues 1 and 2
+        //
+        // v(1) = (1, 0),   u(1) = (1, -100) / √10001
+        // v(2) = (100, 1) / √10001,   u(2) = (0, 1)
+        //
+        // thus rconde(1) = rconde(2) = 1 / √10001
+        #[rustfmt::skip]
+        let mut a = col_major(2, 2, &[
+            1.0, 100.0,
+            0.0,   2.0,
+        ]);
+        let n = 2_i32;
+        let sz = n as usize;
+        let mut wr = vec![0.0; sz];
+        let mut wi = vec![0.0; sz];
+        let mut vl = vec![0.0; sz * sz];
+        let mut vr = vec![0.0; sz * sz];
+        let mut scale = vec![0.0; sz];
+        let mut rconde = vec![0.0; sz];
+        let mut rcondv = vec![0.0; sz];
+        let (ilo, ihi, abnrm) = dgeevx(
+            b'N',
+            true,
+            true,
+            b'B',
+            n,
+            &mut a,
+            &mut wr,
+            &mut wi,
+            &mut vl,
+            &mut vr,
+            &mut scale,
+            &mut rconde,
+            &mut rcondv,
+        )?;
+        assert_eq!((ilo, ihi), (1, 2));
+        approx_eq(abnrm, 102.0, 1e-13);
+        vec_approx_eq(&wr, &[1.0, 2.0], 1e-13);
+        vec_approx_eq(&wi, &[0.0, 0.0], 1e-15);
+        let r = 1.0 / f64::sqrt(10001.0);
+        vec_approx_eq(&rconde, &[r, r], 1e-13);
+        Ok(())
+    }
+
+    #[test]
+    fn dgebal_and_dgebak_work() -> Result<(), StrError> {
+        // badly scaled matrix
+        #[rustfmt::skip]
+        let data = [
+            1.0,    1e-4, 0.0,
+            1e4,    2.0,  1e4,
+            0.0,    1e-4, 3.0,
+        ];
+        let original = col_major(3, 3, &data);
+        let mut a = original.clone();
+        let n = 3_i32;
+        let mut scale = vec![0.0; 3];
+
+        // nothing
+        let (ilo, ihi) = dgebal(b'N', n, &mut a, &mut scale)?;
+        assert_eq!((ilo, ihi), (1, 3));
+        assert_eq!(scale, &[1.0, 1.0, 1.0]);
+        assert_eq!(a, original);
+
+        // scale only: a := D⁻¹ ⋅ a ⋅ D with aᵢⱼ := aᵢⱼ ⋅ dⱼ / dᵢ
+        let (ilo, ihi) = dgebal(b'S', n, &mut a, &mut scale)?;
+        assert_eq!((ilo, ihi), (1, 3));
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(a[i + j * 3], original[i + j * 3] * scale[j] / scale[i]);
+            }
+        }
+        let max_abs = a.iter().fold(0.0, |acc, x| f64::max(acc, f64::abs(*x)));
+        assert!(max_abs < 1e3);
+
+        // back-transform the identity (i.e., the eigenvectors in the balanced space)
+        let mut v = col_major(3, 3, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        dgebak(b'S', true, n, ilo, ihi, &scale, n, &mut v)?;
+        for i in 0..3 {
+            for j in 0..3 {
+                let d = if i == j { scale[i] } else { 0.0 };
+                assert_eq!(v[i + j * 3], d);
+            }
+        }
+        Ok(())
+    }
+
     #[test]
     fn dsyev_captures_errors() {
         let m = 1_usize;
@@ -2158,4 +3368,152 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn dsbev_fails_on_wrong_dims() {
+        let mut ab = vec![0.0; 4];
+        let mut w = vec![0.0; 2];
+        let mut z = vec![0.0; 4];
+        let wrong = -1_i32; // <<< wrong
+        assert_eq!(
+            dsbev(true, true, wrong, 1, &mut ab, 2, &mut w, &mut z),
+            Err("LAPACK dsbev failed")
+        );
+    }
+
+    #[test]
+    fn dsbev_works() -> Result<(), StrError> {
+        // tridiagonal matrix with 2 on the diagonal and -1 off the diagonal
+        //   eigenvalues: 2 - 2⋅cos(k⋅π/(n+1)), k = 1..n
+        let (n, kd) = (4_i32, 1_i32);
+        let ldab = kd + 1;
+        #[rustfmt::skip]
+        let mut ab_upper = vec![
+            0.0, 2.0,  // column 0: (unused), a00
+            -1.0, 2.0, // column 1: a01, a11
+            -1.0, 2.0, // column 2: a12, a22
+            -1.0, 2.0, // column 3: a23, a33
+        ];
+        #[rustfmt::skip]
+        let mut ab_lower = vec![
+            2.0, -1.0, // column 0: a00, a10
+            2.0, -1.0, // column 1: a11, a21
+            2.0, -1.0, // column 2: a22, a32
+            2.0, 0.0,  // column 3: a33, (unused)
+        ];
+        let sz = n as usize;
+        let mut w_upper = vec![0.0; sz];
+        let mut w_lower = vec![0.0; sz];
+        let mut z = vec![0.0; sz * sz];
+        let mut unused = Vec::new();
+        dsbev(true, true, n, kd, &mut ab_upper, ldab, &mut w_upper, &mut z)?;
+        dsbev(false, false, n, kd, &mut ab_lower, ldab, &mut w_lower, &mut unused)?;
+
+        // check eigenvalues
+        let w_correct: Vec<f64> = (1..=sz)
+            .map(|k| 2.0 - 2.0 * f64::cos((k as f64) * std::f64::consts::PI / ((sz + 1) as f64)))
+            .collect();
+        vec_approx_eq(&w_upper, &w_correct, 1e-14);
+        vec_approx_eq(&w_lower, &w_correct, 1e-14);
+
+        // check the first eigenvector: a ⋅ z0 = w0 ⋅ z0
+        #[rustfmt::skip]
+        let a = col_major(4, 4, &[
+            2.0, -1.0, 0.0, 0.0,
+            -1.0, 2.0, -1.0, 0.0,
+            0.0, -1.0, 2.0, -1.0,
+            0.0, 0.0, -1.0, 2.0,
+        ]);
+        for i in 0..sz {
+            let mut az = 0.0;
+            for j in 0..sz {
+                az += a[i + j * sz] * z[j];
+            }
+            approx_eq(az, w_upper[0] * z[i], 1e-14);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dgeqrf_dorgqr_dormqr_work() -> Result<(), StrError> {
+        // matrix a
+        #[rustfmt::skip]
+        let a_original = col_major(4, 3, &[
+            1.0, -1.0,  4.0,
+            1.0,  4.0, -2.0,
+            1.0,  4.0,  2.0,
+            1.0, -1.0,  0.0,
+        ]);
+        let (m, n) = (4_i32, 3_i32);
+        let (mm, nn) = (4, 3);
+
+        // check errors
+        let mut a = a_original.clone();
+        let mut tau = vec![0.0; 3];
+        assert_eq!(
+            dgeqrf(m, n, &mut a, &mut tau[..2]).err(),
+            Some("the length of tau must equal min(m,n)")
+        );
+        assert_eq!(dorgqr(3, 4, 3, &mut a, &tau).err(), Some("dorgqr requires m ≥ n ≥ k"));
+        assert_eq!(dorgqr(m, 2, 3, &mut a, &tau).err(), Some("dorgqr requires m ≥ n ≥ k"));
+        assert_eq!(
+            dorgqr(m, n, n, &mut a, &tau[..2]).err(),
+            Some("the length of tau must be at least k")
+        );
+        let mut c_wrong = vec![0.0; 3];
+        assert_eq!(
+            dormqr(true, true, m, 1, n, &a, &tau, &mut c_wrong).err(),
+            Some("the length of c must equal m ⋅ n")
+        );
+
+        // factorize
+        dgeqrf(m, n, &mut a, &mut tau)?;
+
+        // extract r (upper triangular)
+        let mut r = vec![0.0; nn * nn];
+        for j in 0..nn {
+            for i in 0..=j {
+                r[i + j * nn] = a[i + j * mm];
+            }
+        }
+        let r_abs: Vec<_> = r.iter().map(|v| f64::abs(*v)).collect();
+        #[rustfmt::skip]
+        let r_abs_correct = col_major(3, 3, &[
+            2.0, 3.0, 2.0,
+            0.0, 5.0, 2.0,
+            0.0, 0.0, 4.0,
+        ]);
+        vec_approx_eq(&r_abs, &r_abs_correct, 1e-14);
+
+        // generate the thin q and check that q ⋅ r = a
+        let mut q = a.clone();
+        dorgqr(m, n, n, &mut q, &tau)?;
+        let mut qr = vec![0.0; mm * nn];
+        for i in 0..mm {
+            for j in 0..nn {
+                for k in 0..nn {
+                    qr[i + j * mm] += q[i + k * mm] * r[k + j * nn];
+                }
+            }
+        }
+        vec_approx_eq(&qr, &a_original, 1e-14);
+
+        // apply qᵀ to a and recover r (without forming q)
+        let mut c = a_original.clone();
+        dormqr(true, true, m, n, n, &a, &tau, &mut c)?;
+        for j in 0..nn {
+            for i in 0..mm {
+                let correct = if i < nn { r[i + j * nn] } else { 0.0 };
+                approx_eq(c[i + j * mm], correct, 1e-14);
+            }
+        }
+
+        // apply q from the right: (cᵀ ⋅ q)ᵀ = qᵀ ⋅ c with c = [1, 0, 0, 0]ᵀ
+        let mut ct = vec![1.0, 0.0, 0.0, 0.0];
+        dormqr(false, false, 1, m, n, &a, &tau, &mut ct)?;
+        let mut qt_e0 = vec![1.0, 0.0, 0.0, 0.0];
+        dormqr(true, true, m, 1, n, &a, &tau, &mut qt_e0)?;
+        vec_approx_eq(&ct, &qt_e0, 1e-15);
+        Ok(())
+    }
 }
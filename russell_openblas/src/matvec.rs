@@ -1,4 +1,4 @@
-use super::{cblas_transpose, to_i32, CBLAS_COL_MAJOR, LAPACK_COL_MAJOR};
+use super::{cblas_diag, cblas_transpose, cblas_uplo, lapack_uplo, to_i32, CBLAS_COL_MAJOR, LAPACK_COL_MAJOR};
 use crate::StrError;
 use num_complex::Complex64;
 
@@ -6,11 +6,18 @@ use num_complex::Complex64;
 extern "C" {
     // from /usr/include/x86_64-linux-gnu/cblas.h
     fn cblas_dgemv(order: i32, trans: i32, m: i32, n: i32, alpha: f64, a: *const f64, lda: i32, x: *const f64, incx: i32, beta: f64, y: *mut f64, incy: i32);
+    fn cblas_sgemv(order: i32, trans: i32, m: i32, n: i32, alpha: f32, a: *const f32, lda: i32, x: *const f32, incx: i32, beta: f32, y: *mut f32, incy: i32);
     fn cblas_zgemv(order: i32, trans: i32, m: i32, n: i32, alpha: *const Complex64, a: *const Complex64, lda: i32, x: *const Complex64, incx: i32, beta: *const Complex64, y: *mut Complex64, incy: i32);
     fn cblas_dger(order: i32, m: i32, n: i32, alpha: f64, x: *const f64, incx: i32, y: *const f64, incy: i32, a: *mut f64, lda: i32);
+    fn cblas_dsyr(order: i32, uplo: i32, n: i32, alpha: f64, x: *const f64, incx: i32, a: *mut f64, lda: i32);
+    fn cblas_dtrsv(order: i32, uplo: i32, trans: i32, diag: i32, n: i32, a: *const f64, lda: i32, x: *mut f64, incx: i32);
     // from /usr/include/lapacke.h
     fn LAPACKE_dgesv(matrix_layout: i32, n: i32, nrhs: i32, a: *mut f64, lda: i32, ipiv: *mut i32, b: *mut f64, ldb: i32) -> i32;
+    fn LAPACKE_dsgesv(matrix_layout: i32, n: i32, nrhs: i32, a: *mut f64, lda: i32, ipiv: *mut i32, b: *mut f64, ldb: i32, x: *mut f64, ldx: i32, iter: *mut i32) -> i32;
+    fn LAPACKE_dposv(matrix_layout: i32, uplo: u8, n: i32, nrhs: i32, a: *mut f64, lda: i32, b: *mut f64, ldb: i32) -> i32;
     fn LAPACKE_zgesv(matrix_layout: i32, n: i32, nrhs: i32, a: *mut Complex64, lda: i32, ipiv: *mut i32, b: *mut Complex64, ldb: i32) -> i32;
+    fn LAPACKE_dgels(matrix_layout: i32, trans: u8, m: i32, n: i32, nrhs: i32, a: *mut f64, lda: i32, b: *mut f64, ldb: i32) -> i32;
+    fn LAPACKE_dgelsd(matrix_layout: i32, m: i32, n: i32, nrhs: i32, a: *mut f64, lda: i32, b: *mut f64, ldb: i32, s: *mut f64, rcond: f64, rank: *mut i32) -> i32;
 }
 
 /// Performs the rank 1 operation (tensor product)
@@ -46,6 +53,77 @@ pub fn dger(m: i32, n: i32, alpha: f64, x: &[f64], incx: i32, y: &[f64], incy: i
     }
 }
 
+/// Performs the symmetric rank 1 operation
+///
+/// ```text
+///   a := α ⋅ x ⋅ xᵀ +  a
+/// (n,n)     (n) (n)  (n,n)
+/// ```
+///
+/// Only the upper (`up = true`) or lower triangle of `a` is referenced and updated.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <http://www.netlib.org/lapack/explore-html/d3/d60/dsyr_8f.html>
+///
+#[inline]
+pub fn dsyr(up: bool, n: i32, alpha: f64, x: &[f64], incx: i32, a: &mut [f64]) {
+    unsafe {
+        cblas_dsyr(
+            CBLAS_COL_MAJOR,
+            cblas_uplo(up),
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            a.as_mut_ptr(),
+            n,
+        );
+    }
+}
+
+/// Solves a triangular system of equations
+///
+/// ```text
+///   a  ⋅ x = b       or       aᵀ ⋅ x = b
+/// (n,n) (n) (n)             (n,n) (n) (n)
+/// ```
+///
+/// where `a` is an upper (`up = true`) or lower triangular matrix. Only the corresponding
+/// triangle of `a` is referenced. If `unit_diag = true`, the diagonal of `a` is assumed to
+/// be composed of ones and is not referenced.
+///
+/// **Note:** `x` contains `b` on input and the solution on output. No test for singularity is performed.
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dtrsv.f.html>
+///
+#[inline]
+pub fn dtrsv(up: bool, trans: bool, unit_diag: bool, n: i32, a: &[f64], x: &mut [f64], incx: i32) {
+    unsafe {
+        cblas_dtrsv(
+            CBLAS_COL_MAJOR,
+            cblas_uplo(up),
+            cblas_transpose(trans),
+            cblas_diag(unit_diag),
+            n,
+            a.as_ptr(),
+            n,
+            x.as_mut_ptr(),
+            incx,
+        );
+    }
+}
+
 /// Performs one of the matrix-vector multiplication
 ///
 /// ```text
@@ -97,6 +175,57 @@ pub fn dgemv(
     }
 }
 
+/// Performs one of the matrix-vector multiplication (single precision)
+///
+/// ```text
+///  y := α ⋅ a  ⋅ x  +  β ⋅ y
+/// (m)     (m,n) (n)       (m)
+///
+/// or
+///
+///  y := α ⋅  aᵀ ⋅ x  +  β ⋅ y
+/// (m)      (m,n) (n)       (m)
+/// ```
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/sgemv.f.html>
+///
+#[inline]
+pub fn sgemv(
+    trans: bool,
+    m: i32,
+    n: i32,
+    alpha: f32,
+    a: &[f32],
+    x: &[f32],
+    incx: i32,
+    beta: f32,
+    y: &mut [f32],
+    incy: i32,
+) {
+    unsafe {
+        cblas_sgemv(
+            CBLAS_COL_MAJOR,
+            cblas_transpose(trans),
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            m,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        );
+    }
+}
+
 /// Performs one of the matrix-vector multiplication (complex version)
 ///
 /// ```text
@@ -207,6 +336,143 @@ pub fn dgesv(n: i32, nrhs: i32, a: &mut [f64], ipiv: &mut [i32], b: &mut [f64])
     Ok(())
 }
 
+/// Computes the solution to a real system of linear equations using mixed precision iterative refinement
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅  X =   B
+/// (n,n)  (n)  (n,nrhs)
+/// ```
+///
+/// where A is an N-by-N matrix and X and B are N-by-NRHS matrices.
+///
+/// The LU factorization of A is computed in **single precision** and the solution is then
+/// refined in double precision (iterative refinement) until it achieves double precision accuracy.
+/// If the refinement does not converge (e.g., the matrix is too ill-conditioned for single precision),
+/// the LU factorization is recomputed in double precision as in [dgesv].
+///
+/// Returns `iter`:
+///
+/// * `iter ≥ 0` -- the number of refinement iterations
+/// * `iter < 0` -- the refinement failed and the double precision factorization was used
+///   (e.g., `-3` means that the single precision factorization failed; see the reference)
+///
+/// # Note
+///
+/// 1. The length of ipiv must be equal to `n`
+/// 2. The length of x must be equal to the length of b (i.e., `n ⋅ nrhs`)
+/// 3. The matrix will be modified if the double precision factorization is used
+/// 4. The right-hand side b is not modified
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dsgesv.f.html>
+///
+#[inline]
+pub fn dsgesv(
+    n: i32,
+    nrhs: i32,
+    a: &mut [f64],
+    ipiv: &mut [i32],
+    b: &mut [f64],
+    x: &mut [f64],
+) -> Result<i32, StrError> {
+    if to_i32(ipiv.len()) != n {
+        return Err("the length of ipiv must equal n");
+    }
+    if x.len() != b.len() {
+        return Err("the length of x must equal the length of b");
+    }
+    let mut iter: i32 = 0;
+    unsafe {
+        let info = LAPACKE_dsgesv(
+            LAPACK_COL_MAJOR,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            n,
+            ipiv.as_mut_ptr(),
+            b.as_mut_ptr(),
+            n,
+            x.as_mut_ptr(),
+            n,
+            &mut iter,
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dsgesv failed");
+        }
+    }
+    Ok(iter)
+}
+
+/// Computes the solution to a real system of linear equations with a symmetric positive-definite matrix
+///
+/// The system is:
+///
+/// ```text
+///   A  ⋅    X    =    B
+/// (n,n)  (n,nrhs)  (n,nrhs)
+/// ```
+///
+/// where A is an N-by-N symmetric positive-definite matrix and X and B are N-by-NRHS matrices.
+///
+/// The Cholesky decomposition is used to factor A as
+///
+/// ```text
+/// up = true:
+///
+/// A = Uᵀ ⋅ U
+///
+/// or
+///
+/// up = false:
+///
+/// A = L ⋅ Lᵀ
+/// ```
+///
+/// where U is an upper triangular matrix and L is lower triangular. The factored form of A
+/// is then used to solve the system of equations A * X = B. Only the upper (`up = true`) or
+/// lower triangle of A is accessed. An error is returned if A is not positive-definite.
+///
+/// # Note
+///
+/// 1. The matrix will be modified (it will contain the factor U or L)
+/// 2. The length of b must equal `n ⋅ nrhs`
+/// 3. On exit, b contains the solution X
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dposv.f.html>
+///
+#[inline]
+pub fn dposv(up: bool, n: i32, nrhs: i32, a: &mut [f64], b: &mut [f64]) -> Result<(), StrError> {
+    unsafe {
+        let info = LAPACKE_dposv(
+            LAPACK_COL_MAJOR,
+            lapack_uplo(up),
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            n,
+            b.as_mut_ptr(),
+            n,
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dposv failed");
+        }
+    }
+    Ok(())
+}
+
 /// Computes the solution to a real system of linear equations (complex version)
 ///
 /// The system is:
@@ -266,11 +532,136 @@ pub fn zgesv(n: i32, nrhs: i32, a: &mut [Complex64], ipiv: &mut [i32], b: &mut [
     Ok(())
 }
 
+/// Solves overdetermined or underdetermined real linear systems (least squares)
+///
+/// Solves the (full rank) problem:
+///
+/// ```text
+/// minimize ‖ B - A ⋅ X ‖₂
+///
+///   A  ⋅   X    ≈   B
+/// (m,n)  (n,nrhs)  (m,nrhs)
+/// ```
+///
+/// using the QR factorization of A (m ≥ n) or the minimum norm solution via the LQ
+/// factorization of A (m < n).
+///
+/// # Note
+///
+/// 1. The length of b must be equal to `max(m,n) ⋅ nrhs` (ldb = max(m,n))
+/// 2. On exit, the first `n` rows of b (for each column) contain the solution X
+/// 3. The matrix will be modified
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://netlib.org/lapack/explore-html/d8/d2d/dgels_8f.html>
+///
+#[inline]
+pub fn dgels(m: i32, n: i32, nrhs: i32, a: &mut [f64], b: &mut [f64]) -> Result<(), StrError> {
+    let ldb = i32::max(1, i32::max(m, n));
+    if to_i32(b.len()) != ldb * nrhs {
+        return Err("the length of b must equal max(m,n) ⋅ nrhs");
+    }
+    unsafe {
+        let info = LAPACKE_dgels(
+            LAPACK_COL_MAJOR,
+            b'N',
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            i32::max(1, m),
+            b.as_mut_ptr(),
+            ldb,
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dgels failed");
+        }
+    }
+    Ok(())
+}
+
+/// Computes the minimum norm solution of (possibly rank-deficient) real least squares problems
+///
+/// Solves the problem:
+///
+/// ```text
+/// minimize ‖ B - A ⋅ X ‖₂
+///
+///   A  ⋅   X    ≈   B
+/// (m,n)  (n,nrhs)  (m,nrhs)
+/// ```
+///
+/// using the singular value decomposition (SVD) of A (divide and conquer method).
+/// The singular values `s[i] ≤ rcond ⋅ s[0]` are treated as zero; thus, the
+/// effective rank of A is determined by `rcond`. If `rcond < 0`, the machine
+/// precision is used instead.
+///
+/// Returns the effective rank of A.
+///
+/// # Note
+///
+/// 1. The length of b must be equal to `max(m,n) ⋅ nrhs` (ldb = max(m,n))
+/// 2. On exit, the first `n` rows of b (for each column) contain the solution X
+/// 3. The length of s must be equal to `min(m,n)`; on exit, s contains the singular values of A in decreasing order
+/// 4. The matrix will be modified
+///
+/// # Important
+///
+/// * The data must be in **col-major** order
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dgelsd.f.html>
+///
+#[inline]
+pub fn dgelsd(
+    m: i32,
+    n: i32,
+    nrhs: i32,
+    a: &mut [f64],
+    b: &mut [f64],
+    s: &mut [f64],
+    rcond: f64,
+) -> Result<i32, StrError> {
+    let ldb = i32::max(1, i32::max(m, n));
+    if to_i32(b.len()) != ldb * nrhs {
+        return Err("the length of b must equal max(m,n) ⋅ nrhs");
+    }
+    if to_i32(s.len()) != i32::min(m, n) {
+        return Err("the length of s must equal min(m,n)");
+    }
+    let mut rank: i32 = 0;
+    unsafe {
+        let info = LAPACKE_dgelsd(
+            LAPACK_COL_MAJOR,
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            i32::max(1, m),
+            b.as_mut_ptr(),
+            ldb,
+            s.as_mut_ptr(),
+            rcond,
+            &mut rank,
+        );
+        if info != 0_i32 {
+            return Err("LAPACK dgelsd failed");
+        }
+    }
+    Ok(rank)
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{dgemv, dger, dgesv, zgemv, zgesv};
+    use super::{dgels, dgelsd, dgemv, dger, dgesv, dposv, dsgesv, dsyr, dtrsv, sgemv, zgemv, zgesv};
     use crate::conversions::{col_major, col_major_complex};
     use crate::{to_i32, StrError};
     use num_complex::Complex64;
@@ -302,6 +693,26 @@ mod tests {
         vec_approx_eq(&a, &correct, 1e-15);
     }
 
+    #[test]
+    fn dsyr_works() {
+        #[rustfmt::skip]
+        let mut a = col_major(3, 3, &[
+            1.0, 1.0, 1.0,
+            0.0, 1.0, 1.0,
+            0.0, 0.0, 1.0,
+        ]);
+        let x = &[1.0, 2.0, 3.0];
+        dsyr(true, 3, 2.0, x, 1, &mut a);
+        // a = a + 2⋅x⋅xᵀ (upper triangle only)
+        #[rustfmt::skip]
+        let correct = col_major(3, 3, &[
+            3.0, 5.0,  7.0,
+            0.0, 9.0, 13.0,
+            0.0, 0.0, 19.0,
+        ]);
+        vec_approx_eq(&a, &correct, 1e-15);
+    }
+
     #[test]
     fn dgemv_works() {
         // allocate matrix
@@ -328,6 +739,58 @@ mod tests {
         vec_approx_eq(&a, &[0.1, 1.0, 2.0, 3.0, 0.2, 0.2, 0.2, 0.2, 0.3, 0.3, 0.3, 0.3], 1e-15);
     }
 
+    #[test]
+    fn sgemv_works() {
+        // a = [[1, 2, 3], [4, 5, 6]] (col-major)
+        let a = [1.0_f32, 4.0, 2.0, 5.0, 3.0, 6.0];
+        let mut x = [1.0_f32, -1.0, 2.0];
+        let mut y = [1.0_f32, 1.0];
+        sgemv(false, 2, 3, 0.5, &a, &x, 1, 2.0, &mut y, 1);
+        assert_eq!(y, [4.5, 7.5]);
+        sgemv(true, 2, 3, 1.0, &a, &y, 1, 0.0, &mut x, 1);
+        assert_eq!(x, [34.5, 46.5, 58.5]);
+    }
+
+    #[test]
+    fn dtrsv_works() {
+        // upper triangular matrix (the lower triangle is not referenced)
+        #[rustfmt::skip]
+        let a = col_major(3, 3, &[
+              2.0,   1.0, -1.0,
+            100.0,   4.0,  2.0,
+            100.0, 100.0,  5.0,
+        ]);
+
+        // a ⋅ x = b with x = [1, 2, 3]
+        let mut x = [1.0, 14.0, 15.0];
+        dtrsv(true, false, false, 3, &a, &mut x, 1);
+        vec_approx_eq(&x, &[1.0, 2.0, 3.0], 1e-15);
+
+        // aᵀ ⋅ x = b with x = [1, 2, 3]
+        let mut x = [2.0, 9.0, 18.0];
+        dtrsv(true, true, false, 3, &a, &mut x, 1);
+        vec_approx_eq(&x, &[1.0, 2.0, 3.0], 1e-15);
+
+        // unit diagonal (the diagonal is not referenced)
+        let mut x = [0.0, 8.0, 3.0];
+        dtrsv(true, false, true, 3, &a, &mut x, 1);
+        vec_approx_eq(&x, &[1.0, 2.0, 3.0], 1e-15);
+
+        // lower triangular matrix (the upper triangle is not referenced)
+        #[rustfmt::skip]
+        let l = col_major(3, 3, &[
+             2.0, 100.0, 100.0,
+             1.0,   4.0, 100.0,
+            -1.0,   2.0,   5.0,
+        ]);
+        let mut x = [2.0, 9.0, 18.0];
+        dtrsv(false, false, false, 3, &l, &mut x, 1);
+        vec_approx_eq(&x, &[1.0, 2.0, 3.0], 1e-15);
+        let mut x = [1.0, 14.0, 15.0];
+        dtrsv(false, true, false, 3, &l, &mut x, 1);
+        vec_approx_eq(&x, &[1.0, 2.0, 3.0], 1e-15);
+    }
+
     #[test]
     fn zgemv_works() {
         // allocate matrix
@@ -429,6 +892,82 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn dposv_works() -> Result<(), StrError> {
+        // symmetric positive-definite matrix (the other triangle is not accessed)
+        #[rustfmt::skip]
+        let a = col_major(3, 3, &[
+              4.0,  12.0, -16.0,
+             12.0,  37.0, -43.0,
+            -16.0, -43.0,  98.0,
+        ]);
+        #[rustfmt::skip]
+        let b = col_major(3, 2, &[
+             -44.0,  4.0,
+            -116.0, 12.0,
+             283.0, -16.0,
+        ]);
+        let correct = &[-2.0, 1.0, 3.0, 1.0, 0.0, 0.0];
+        for up in [true, false] {
+            let mut a_copy = a.clone();
+            let mut x = b.clone();
+            dposv(up, 3, 2, &mut a_copy, &mut x)?;
+            vec_approx_eq(&x, correct, 1e-13);
+        }
+
+        // not positive-definite
+        let mut a = vec![1.0, 2.0, 2.0, 1.0];
+        let mut b = vec![1.0, 1.0];
+        assert_eq!(dposv(true, 2, 1, &mut a, &mut b), Err("LAPACK dposv failed"));
+        Ok(())
+    }
+
+    #[test]
+    fn dsgesv_captures_errors() {
+        let mut a = vec![0.0; 4];
+        let mut b = vec![0.0; 2];
+        let mut x = vec![0.0; 2];
+        assert_eq!(
+            dsgesv(2, 1, &mut a, &mut [0; 1], &mut b, &mut x),
+            Err("the length of ipiv must equal n")
+        );
+        assert_eq!(
+            dsgesv(2, 1, &mut a, &mut [0; 2], &mut b, &mut [0.0; 1]),
+            Err("the length of x must equal the length of b")
+        );
+        assert_eq!(
+            dsgesv(2, 1, &mut a, &mut [0; 2], &mut b, &mut x),
+            Err("LAPACK dsgesv failed")
+        );
+    }
+
+    #[test]
+    fn dsgesv_works() -> Result<(), StrError> {
+        // matrix
+        #[rustfmt::skip]
+        let mut a = col_major(5, 5, &[
+            2.0,  3.0,  0.0, 0.0, 0.0,
+            3.0,  0.0,  4.0, 0.0, 6.0,
+            0.0, -1.0, -3.0, 2.0, 0.0,
+            0.0,  0.0,  1.0, 0.0, 0.0,
+            0.0,  4.0,  2.0, 0.0, 1.0,
+        ]);
+
+        // right-hand-side
+        let mut b = vec![8.0, 45.0, -3.0, 3.0, 19.0];
+
+        // solve x := A⁻¹ b
+        let mut ipiv = vec![0; 5];
+        let mut x = vec![0.0; 5];
+        let iter = dsgesv(5, 1, &mut a, &mut ipiv, &mut b, &mut x)?;
+
+        // check
+        assert!(iter >= 0);
+        vec_approx_eq(&x, &[1.0, 2.0, 3.0, 4.0, 5.0], 1e-14);
+        assert_eq!(b, &[8.0, 45.0, -3.0, 3.0, 19.0]);
+        Ok(())
+    }
+
     #[test]
     fn zgesv_captures_errors() {
         let m = 2;
@@ -554,4 +1093,85 @@ mod tests {
         assert_eq!(ipiv, [1, 2, 3, 4, 5]);
         Ok(())
     }
+
+    #[test]
+    fn dgels_captures_wrong_b() {
+        let mut a = vec![0.0; 6];
+        let mut b = vec![0.0; 2];
+        assert_eq!(
+            dgels(3, 2, 1, &mut a, &mut b),
+            Err("the length of b must equal max(m,n) ⋅ nrhs")
+        );
+    }
+
+    #[test]
+    fn dgels_works() -> Result<(), StrError> {
+        // fit y = c0 + c1⋅x through (0,1), (1,3), (2,5), (3,7)
+        #[rustfmt::skip]
+        let mut a = col_major(4, 2, &[
+            1.0, 0.0,
+            1.0, 1.0,
+            1.0, 2.0,
+            1.0, 3.0,
+        ]);
+        let mut b = vec![1.0, 3.0, 5.0, 7.0];
+        dgels(4, 2, 1, &mut a, &mut b)?;
+        vec_approx_eq(&b[0..2], &[1.0, 2.0], 1e-14);
+
+        // underdetermined: minimum norm solution of x0 + x1 = 2
+        let mut a = vec![1.0, 1.0];
+        let mut b = vec![2.0, 0.0];
+        dgels(1, 2, 1, &mut a, &mut b)?;
+        vec_approx_eq(&b, &[1.0, 1.0], 1e-15);
+        Ok(())
+    }
+
+    #[test]
+    fn dgelsd_captures_wrong_dims() {
+        let mut a = vec![0.0; 6];
+        let mut b = vec![0.0; 2];
+        let mut s = vec![0.0; 2];
+        assert_eq!(
+            dgelsd(3, 2, 1, &mut a, &mut b, &mut s, -1.0),
+            Err("the length of b must equal max(m,n) ⋅ nrhs")
+        );
+        let mut b = vec![0.0; 3];
+        let mut s = vec![0.0; 3];
+        assert_eq!(
+            dgelsd(3, 2, 1, &mut a, &mut b, &mut s, -1.0),
+            Err("the length of s must equal min(m,n)")
+        );
+    }
+
+    #[test]
+    fn dgelsd_works() -> Result<(), StrError> {
+        // fit y = c0 + c1⋅x through (0,1), (1,3), (2,5), (3,7)
+        #[rustfmt::skip]
+        let mut a = col_major(4, 2, &[
+            1.0, 0.0,
+            1.0, 1.0,
+            1.0, 2.0,
+            1.0, 3.0,
+        ]);
+        let mut b = vec![1.0, 3.0, 5.0, 7.0];
+        let mut s = vec![0.0; 2];
+        let rank = dgelsd(4, 2, 1, &mut a, &mut b, &mut s, -1.0)?;
+        assert_eq!(rank, 2);
+        vec_approx_eq(&b[0..2], &[1.0, 2.0], 1e-14);
+
+        // rank-deficient: the columns are equal; minimum norm solution of x0 + x1 = 2
+        #[rustfmt::skip]
+        let mut a = col_major(3, 2, &[
+            1.0, 1.0,
+            1.0, 1.0,
+            1.0, 1.0,
+        ]);
+        let mut b = vec![2.0, 2.0, 2.0];
+        let mut s = vec![0.0; 2];
+        let rank = dgelsd(3, 2, 1, &mut a, &mut b, &mut s, 1e-12)?;
+        assert_eq!(rank, 1);
+        vec_approx_eq(&s, &[f64::sqrt(6.0), 0.0], 1e-14);
+        vec_approx_eq(&b[0..2], &[1.0, 1.0], 1e-14);
+        Ok(())
+    }
 }
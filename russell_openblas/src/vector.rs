@@ -10,8 +10,13 @@ extern "C" {
     fn cblas_daxpy(n: i32, alpha: f64, x: *const f64, incx: i32, y: *mut f64, incy: i32);
     fn cblas_zaxpy(n: i32, alpha: *const Complex64, x: *const Complex64, incx: i32, y: *mut Complex64, incy: i32);
     fn cblas_dnrm2(n: i32, x: *const f64, incx: i32) -> f64;
+    fn cblas_dznrm2(n: i32, x: *const Complex64, incx: i32) -> f64;
     fn cblas_dasum(n: i32, x: *const f64, incx: i32) -> f64;
     fn cblas_idamax(n: i32, x: *const f64, incx: i32) -> i32;
+    fn cblas_sdot(n: i32, x: *const f32, incx: i32, y: *const f32, incy: i32) -> f32;
+    fn cblas_sscal(n: i32, alpha: f32, x: *const f32, incx: i32);
+    fn cblas_saxpy(n: i32, alpha: f32, x: *const f32, incx: i32, y: *mut f32, incy: i32);
+    fn cblas_snrm2(n: i32, x: *const f32, incx: i32) -> f32;
 }
 
 /// Calculates the dot product of two vectors
@@ -173,6 +178,21 @@ pub fn dnrm2(n: i32, x: &[f64], incx: i32) -> f64 {
     unsafe { cblas_dnrm2(n, x.as_ptr(), incx) }
 }
 
+/// Computes the Euclidean norm (complex version)
+///
+/// ```text
+/// ‖x‖₂ := sqrt(xᴴ ⋅ x)
+/// ```
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/dznrm2.f.html>
+///
+#[inline]
+pub fn dznrm2(n: i32, x: &[Complex64], incx: i32) -> f64 {
+    unsafe { cblas_dznrm2(n, x.as_ptr(), incx) }
+}
+
 /// Finds the index of the maximum absolute value
 ///
 /// # Note
@@ -188,11 +208,77 @@ pub fn idamax(n: i32, x: &[f64], incx: i32) -> i32 {
     unsafe { cblas_idamax(n, x.as_ptr(), incx) }
 }
 
+/// Calculates the dot product of two vectors (single precision)
+///
+/// ```text
+/// x dot y
+/// ```
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/sdot.f.html>
+///
+#[inline]
+pub fn sdot(n: i32, x: &[f32], incx: i32, y: &[f32], incy: i32) -> f32 {
+    unsafe { cblas_sdot(n, x.as_ptr(), incx, y.as_ptr(), incy) }
+}
+
+/// Scales a vector by a constant (single precision)
+///
+/// ```text
+/// x := alpha * x
+/// ```
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/sscal.f.html>
+///
+#[inline]
+pub fn sscal(n: i32, alpha: f32, x: &mut [f32], incx: i32) {
+    unsafe {
+        cblas_sscal(n, alpha, x.as_ptr(), incx);
+    }
+}
+
+/// Computes constant times a vector plus a vector (single precision)
+///
+/// ```text
+/// y := alpha*x + y
+/// ```
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/saxpy.f.html>
+///
+#[inline]
+pub fn saxpy(n: i32, alpha: f32, x: &[f32], incx: i32, y: &mut [f32], incy: i32) {
+    unsafe {
+        cblas_saxpy(n, alpha, x.as_ptr(), incx, y.as_mut_ptr(), incy);
+    }
+}
+
+/// Computes the Euclidean norm (single precision)
+///
+/// ```text
+/// ‖x‖₂ := sqrt(xᵀ ⋅ x)
+/// ```
+///
+/// # Reference
+///
+/// <https://www.netlib.org/lapack/lapack-3.1.1/html/snrm2.f.html>
+///
+#[inline]
+pub fn snrm2(n: i32, x: &[f32], incx: i32) -> f32 {
+    unsafe { cblas_snrm2(n, x.as_ptr(), incx) }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{dasum, daxpy, dcopy, ddot, dnrm2, dscal, idamax, zaxpy, zcopy, zscal};
+    use super::{
+        dasum, daxpy, dcopy, ddot, dnrm2, dscal, dznrm2, idamax, saxpy, sdot, snrm2, sscal, zaxpy, zcopy, zscal,
+    };
     use crate::to_i32;
     use num_complex::Complex64;
     use russell_chk::{approx_eq, complex_vec_approx_eq, vec_approx_eq};
@@ -378,6 +464,19 @@ mod tests {
         approx_eq(dnrm2(n, &x, incx), 5.0, 1e-15);
     }
 
+    #[test]
+    fn dznrm2_works() {
+        let x = [
+            Complex64::new(1.0, 1.0),
+            Complex64::new(-1.0, 1.0),
+            Complex64::new(3.0, -4.0),
+            Complex64::new(0.0, 0.0),
+        ];
+        let (n, incx) = (to_i32(x.len()), 1_i32);
+        approx_eq(dznrm2(n, &x, incx), f64::sqrt(29.0), 1e-15);
+        approx_eq(dznrm2(2, &x, 2), f64::sqrt(27.0), 1e-15);
+    }
+
     #[test]
     fn idamax_works() {
         let x = [1.0, 2.0, 7.0, -8.0, -5.0, -10.0, -9.0, 10.0, 6.0];
@@ -385,4 +484,19 @@ mod tests {
         let idx = idamax(n, &x, incx);
         assert_eq!(idx, 5);
     }
+
+    #[test]
+    fn single_precision_functions_work() {
+        const IGNORED: f32 = 100000.0;
+        let x = [20.0_f32, 10.0, 30.0, IGNORED];
+        let y = [-15.0_f32, -5.0, -24.0, IGNORED];
+        assert_eq!(sdot(3, &x, 1, &y, 1), -1070.0);
+        assert_eq!(snrm2(2, &[3.0, 4.0], 1), 5.0);
+        let mut x = [20.0_f32, 10.0, -30.0, IGNORED];
+        sscal(3, 0.5, &mut x, 1);
+        assert_eq!(x, [10.0, 5.0, -15.0, IGNORED]);
+        let mut y = [1.0_f32, 1.0, 1.0, IGNORED];
+        saxpy(3, 2.0, &x, 1, &mut y, 1);
+        assert_eq!(y, [21.0, 11.0, -29.0, IGNORED]);
+    }
 }
@@ -26,4 +26,25 @@ fn main() {
         println!("cargo:rustc-link-lib=dylib=dmumps_seq");
         println!("cargo:rustc-link-lib=dylib=umfpack");
     }
+
+    #[cfg(feature = "capi")]
+    generate_c_header();
+}
+
+/// Generates the C header of the capi module into OUT_DIR/russell_sparse.h
+///
+/// **Note:** The header is not written into the source tree because build scripts must not modify the package.
+#[cfg(feature = "capi")]
+fn generate_c_header() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let header = format!("{}/russell_sparse.h", env::var("OUT_DIR").unwrap());
+    cbindgen::Builder::new()
+        .with_src(format!("{}/src/capi.rs", crate_dir))
+        .with_language(cbindgen::Language::C)
+        .with_after_include("\ntypedef struct SparseTriplet SparseTriplet;")
+        .with_include_guard("RUSSELL_SPARSE_H")
+        .with_documentation(true)
+        .generate()
+        .expect("cannot generate the C header")
+        .write_to_file(header);
 }
@@ -180,6 +180,29 @@ int32_t solver_mmp_factorize(struct SolverMMP *solver,
     return solver->data.INFOG(1);
 }
 
+int32_t solver_mmp_refactorize(struct SolverMMP *solver,
+                               double const *values_aij,
+                               int32_t verbose) {
+    if (solver == NULL) {
+        return NULL_POINTER_ERROR;
+    }
+
+    // set the values only (the indices and the analysis from the previous factorization are reused)
+
+    int32_t p;
+    for (p = 0; p < solver->data.nz; p++) {
+        solver->data.a[p] = values_aij[p];
+    }
+
+    // perform factorization
+
+    set_mmp_verbose(&solver->data, verbose);
+    solver->data.job = MUMPS_JOB_FACTORIZE;
+    dmumps_c(&solver->data);
+
+    return solver->data.INFOG(1);
+}
+
 int32_t solver_mmp_solve(struct SolverMMP *solver, double *rhs, int32_t verbose) {
     if (solver == NULL) {
         return NULL_POINTER_ERROR;
@@ -139,7 +139,14 @@ int32_t solver_umf_factorize(struct SolverUMF *solver,
         umfpack_di_report_status(solver->control, code);
     }
 
-    // perform factorization
+    // perform factorization (free the data from a previous factorization, if any)
+
+    if (solver->symbolic != NULL) {
+        umfpack_di_free_symbolic(&solver->symbolic);
+    }
+    if (solver->numeric != NULL) {
+        umfpack_di_free_numeric(&solver->numeric);
+    }
 
     code = umfpack_di_symbolic(solver->n, solver->n, solver->ap, solver->ai, solver->ax,
                                &solver->symbolic, solver->control, solver->info);
@@ -157,6 +164,42 @@ int32_t solver_umf_factorize(struct SolverUMF *solver,
     return code;
 }
 
+int32_t solver_umf_refactorize(struct SolverUMF *solver,
+                               int32_t const *indices_i,
+                               int32_t const *indices_j,
+                               double const *values_aij,
+                               int32_t verbose) {
+    if (solver == NULL || solver->symbolic == NULL) {
+        return NULL_POINTER_ERROR;
+    }
+
+    set_umf_verbose(solver, verbose);
+
+    // convert triplet to compressed column (the pattern is the same; only the values change)
+
+    int code = umfpack_di_triplet_to_col(solver->n, solver->n, solver->nnz,
+                                         indices_i, indices_j, values_aij,
+                                         solver->ap, solver->ai, solver->ax, NULL);
+    if (code != UMFPACK_OK) {
+        return code;
+    }
+
+    // perform the numeric factorization only (reusing the symbolic analysis and ordering)
+
+    if (solver->numeric != NULL) {
+        umfpack_di_free_numeric(&solver->numeric);
+    }
+
+    code = umfpack_di_numeric(solver->ap, solver->ai, solver->ax,
+                              solver->symbolic, &solver->numeric, solver->control, solver->info);
+
+    if (verbose == C_TRUE) {
+        umfpack_di_report_info(solver->control, solver->info);
+    }
+
+    return code;
+}
+
 int32_t solver_umf_solve(struct SolverUMF *solver, double *x, const double *rhs, int32_t verbose) {
     if (solver == NULL) {
         return NULL_POINTER_ERROR;
@@ -0,0 +1,335 @@
+use super::LinearOperator;
+use crate::StrError;
+use russell_lab::Vector;
+use std::cmp::Ordering;
+
+/// Computes the product of a matrix polynomial and a vector by the Horner scheme
+///
+/// Computes:
+///
+/// ```text
+/// y = p(a) ⋅ x = (c₀ I + c₁ a + c₂ a² + … + cₘ aᵐ) ⋅ x
+/// ```
+///
+/// with `coeffs = [c₀, c₁, …, cₘ]`. The Horner scheme requires m matrix-vector products
+/// and never forms the powers of `a`:
+///
+/// ```text
+/// y ← cₘ x    then    y ← a ⋅ y + cₖ x    for k = m-1, …, 0
+/// ```
+///
+/// # Input
+///
+/// * `op` -- the matrix or matrix-free operator (square; see [LinearOperator])
+/// * `coeffs` -- the coefficients of the monomials (y = 0 if empty)
+/// * `x` -- the vector
+///
+/// **Note:** The monomial basis is ill-conditioned for high degrees; see [apply_chebyshev] for
+/// polynomials expanded in Chebyshev polynomials (e.g., in Chebyshev smoothers).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_sparse::{apply_poly, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 1.0],
+///         [0.0, 2.0],
+///     ]);
+///     let x = Vector::from(&[1.0, 1.0]);
+///
+///     // y = (3 I - 2 a + a²) ⋅ x
+///     let y = apply_poly(&a, &[3.0, -2.0, 1.0], &x)?;
+///     assert_eq!(y.as_data(), &[3.0, 3.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn apply_poly(op: &dyn LinearOperator, coeffs: &[f64], x: &Vector) -> Result<Vector, StrError> {
+    let n = check_dims(op, x)?;
+    let mut y = Vector::new(n);
+    let m = match coeffs.len() {
+        0 => return Ok(y),
+        len => len - 1,
+    };
+    let mut t = Vector::new(n);
+    for i in 0..n {
+        y[i] = coeffs[m] * x[i];
+    }
+    for k in (0..m).rev() {
+        op.apply(&mut t, &y)?;
+        for i in 0..n {
+            y[i] = t[i] + coeffs[k] * x[i];
+        }
+    }
+    Ok(y)
+}
+
+/// Computes the product of a matrix power and a vector
+///
+/// Computes `y = aᵏ ⋅ x` with k matrix-vector products (y = x if k = 0).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_sparse::{apply_power, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [1.0, 1.0],
+///         [0.0, 1.0],
+///     ]);
+///     let y = apply_power(&a, 5, &Vector::from(&[0.0, 1.0]))?;
+///     assert_eq!(y.as_data(), &[5.0, 1.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn apply_power(op: &dyn LinearOperator, k: usize, x: &Vector) -> Result<Vector, StrError> {
+    let n = check_dims(op, x)?;
+    let mut y = x.clone();
+    let mut t = Vector::new(n);
+    for _ in 0..k {
+        op.apply(&mut t, &y)?;
+        std::mem::swap(&mut y, &mut t);
+    }
+    Ok(y)
+}
+
+/// Computes the product of a Chebyshev expansion of a matrix and a vector by the Clenshaw recurrence
+///
+/// Computes:
+///
+/// ```text
+/// y = (c₀ T₀(b) + c₁ T₁(b) + … + cₘ Tₘ(b)) ⋅ x
+///
+/// with    b = (2 a - (λₗ + λᵤ) I) / (λᵤ - λₗ)
+/// ```
+///
+/// where `Tₖ` are the Chebyshev polynomials of the first kind and `[λₗ, λᵤ]` is the interval
+/// mapped onto `[-1, 1]` (e.g., an interval containing the eigenvalues of `a`). The Clenshaw
+/// recurrence requires m matrix-vector products and is numerically stable:
+///
+/// ```text
+/// bₖ = 2 b ⋅ bₖ₊₁ - bₖ₊₂ + cₖ x    for k = m, …, 1  (with bₘ₊₁ = bₘ₊₂ = 0)
+///
+/// y = b ⋅ b₁ - b₂ + c₀ x
+/// ```
+///
+/// # Input
+///
+/// * `op` -- the matrix or matrix-free operator (square; see [LinearOperator])
+/// * `coeffs` -- the Chebyshev coefficients `[c₀, c₁, …, cₘ]` (y = 0 if empty)
+/// * `interval` -- the interval `(λₗ, λᵤ)` with `λₗ < λᵤ`
+/// * `x` -- the vector
+///
+/// **Note:** All coefficients are used as given; i.e., `c₀` is not halved.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_sparse::{apply_chebyshev, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // the eigenvalues of a are 1 and 3; thus b = a - 2 I for the interval (1, 3)
+///     let a = Matrix::from(&[
+///         [2.0, 1.0],
+///         [1.0, 2.0],
+///     ]);
+///     let x = Vector::from(&[1.0, 0.0]);
+///
+///     // T₂(b) = 2 b² - I = I because b² = I
+///     let y = apply_chebyshev(&a, &[0.0, 0.0, 1.0], (1.0, 3.0), &x)?;
+///     assert_eq!(y.as_data(), &[1.0, 0.0]);
+///     Ok(())
+/// }
+/// ```
+pub fn apply_chebyshev(
+    op: &dyn LinearOperator,
+    coeffs: &[f64],
+    interval: (f64, f64),
+    x: &Vector,
+) -> Result<Vector, StrError> {
+    let n = check_dims(op, x)?;
+    let (lower, upper) = interval;
+    if lower.partial_cmp(&upper) != Some(Ordering::Less) {
+        return Err("the interval must satisfy λₗ < λᵤ");
+    }
+    let mut y = Vector::new(n);
+    if coeffs.is_empty() {
+        return Ok(y);
+    }
+    let alpha = 2.0 / (upper - lower);
+    let beta = (upper + lower) / (upper - lower);
+    let mut b1 = Vector::new(n); // bₖ₊₁
+    let mut b2 = Vector::new(n); // bₖ₊₂
+    let mut t = Vector::new(n);
+    for k in (1..coeffs.len()).rev() {
+        // b2 ← bₖ = 2 b ⋅ bₖ₊₁ - bₖ₊₂ + cₖ x  with  b ⋅ v = α a ⋅ v - β v
+        op.apply(&mut t, &b1)?;
+        for i in 0..n {
+            b2[i] = 2.0 * (alpha * t[i] - beta * b1[i]) - b2[i] + coeffs[k] * x[i];
+        }
+        std::mem::swap(&mut b1, &mut b2);
+    }
+    op.apply(&mut t, &b1)?;
+    for i in 0..n {
+        y[i] = alpha * t[i] - beta * b1[i] - b2[i] + coeffs[0] * x[i];
+    }
+    Ok(y)
+}
+
+/// Checks the dimensions and returns the dimension of the (square) operator
+fn check_dims(op: &dyn LinearOperator, x: &Vector) -> Result<usize, StrError> {
+    let (m, n) = op.dims();
+    if m != n {
+        return Err("the operator must be square");
+    }
+    if x.dim() != n {
+        return Err("x vector is incompatible with the operator");
+    }
+    Ok(n)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_chebyshev, apply_poly, apply_power};
+    use crate::{SparseCsr, SparseTriplet, TripletSymmetry};
+    use russell_chk::vec_approx_eq;
+    use russell_lab::{mat_vec_mul, Matrix, Vector};
+
+    /// Returns the tridiagonal matrix of the 1D Laplacian as a CSR matrix and as a dense matrix
+    fn laplacian(n: usize) -> (SparseCsr, Matrix) {
+        let mut trip = SparseTriplet::new(n, 3 * n, TripletSymmetry::None).unwrap();
+        let mut dense = Matrix::new(n, n);
+        for i in 0..n {
+            trip.put(i, i, 2.0).unwrap();
+            dense.set(i, i, 2.0);
+            if i > 0 {
+                trip.put(i, i - 1, -1.0).unwrap();
+                dense.set(i, i - 1, -1.0);
+            }
+            if i + 1 < n {
+                trip.put(i, i + 1, -1.0).unwrap();
+                dense.set(i, i + 1, -1.0);
+            }
+        }
+        (SparseCsr::from_triplet(&trip), dense)
+    }
+
+    #[test]
+    fn apply_functions_capture_errors() {
+        let a = Matrix::new(2, 3);
+        let x = Vector::new(3);
+        assert_eq!(apply_poly(&a, &[1.0], &x).err(), Some("the operator must be square"));
+        assert_eq!(apply_power(&a, 1, &x).err(), Some("the operator must be square"));
+        let a = Matrix::new(2, 2);
+        assert_eq!(
+            apply_poly(&a, &[1.0], &x).err(),
+            Some("x vector is incompatible with the operator")
+        );
+        assert_eq!(
+            apply_chebyshev(&a, &[1.0], (0.0, 1.0), &x).err(),
+            Some("x vector is incompatible with the operator")
+        );
+        let x = Vector::new(2);
+        assert_eq!(
+            apply_chebyshev(&a, &[1.0], (1.0, 1.0), &x).err(),
+            Some("the interval must satisfy λₗ < λᵤ")
+        );
+        assert_eq!(
+            apply_chebyshev(&a, &[1.0], (f64::NAN, 1.0), &x).err(),
+            Some("the interval must satisfy λₗ < λᵤ")
+        );
+    }
+
+    #[test]
+    fn apply_poly_and_apply_power_work() {
+        let (csr, dense) = laplacian(5);
+        let x = Vector::from(&[1.0, -2.0, 3.0, 0.5, 1.5]);
+
+        // empty and constant polynomials
+        assert_eq!(apply_poly(&csr, &[], &x).unwrap().as_data(), &[0.0; 5]);
+        vec_approx_eq(
+            apply_poly(&csr, &[2.0], &x).unwrap().as_data(),
+            &[2.0, -4.0, 6.0, 1.0, 3.0],
+            1e-15,
+        );
+
+        // powers computed with dense matrix-vector products
+        let mut powers = vec![x.clone()];
+        for k in 1..4 {
+            let mut v = Vector::new(5);
+            mat_vec_mul(&mut v, 1.0, &dense, &powers[k - 1]).unwrap();
+            powers.push(v);
+        }
+        for (k, correct) in powers.iter().enumerate() {
+            vec_approx_eq(apply_power(&csr, k, &x).unwrap().as_data(), correct.as_data(), 1e-13);
+            vec_approx_eq(apply_power(&dense, k, &x).unwrap().as_data(), correct.as_data(), 1e-13);
+        }
+
+        // p(a) ⋅ x = (1 - 2 a + 0.5 a² + 3 a³) ⋅ x
+        let coeffs = [1.0, -2.0, 0.5, 3.0];
+        let mut correct = Vector::new(5);
+        for (k, c) in coeffs.iter().enumerate() {
+            for i in 0..5 {
+                correct[i] += c * powers[k][i];
+            }
+        }
+        vec_approx_eq(
+            apply_poly(&csr, &coeffs, &x).unwrap().as_data(),
+            correct.as_data(),
+            1e-13,
+        );
+        vec_approx_eq(
+            apply_poly(&dense, &coeffs, &x).unwrap().as_data(),
+            correct.as_data(),
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn apply_chebyshev_works() {
+        // diagonal matrix: the result is the Chebyshev series evaluated at each (mapped) eigenvalue
+        let eigenvalues = [0.5, 1.0, 2.5, 4.0];
+        let mut trip = SparseTriplet::new(4, 4, TripletSymmetry::None).unwrap();
+        for (i, l) in eigenvalues.iter().enumerate() {
+            trip.put(i, i, *l).unwrap();
+        }
+        let csr = SparseCsr::from_triplet(&trip);
+        let x = Vector::from(&[1.0, 2.0, -1.0, 0.5]);
+        let (lower, upper) = (0.5, 4.0);
+        let coeffs = [0.3, -1.0, 0.25, 2.0, -0.5];
+        let y = apply_chebyshev(&csr, &coeffs, (lower, upper), &x).unwrap();
+        let correct: Vec<_> = eigenvalues
+            .iter()
+            .enumerate()
+            .map(|(i, l)| {
+                let t = f64::acos((2.0 * l - (lower + upper)) / (upper - lower));
+                let sum: f64 = coeffs.iter().enumerate().map(|(k, c)| c * f64::cos(k as f64 * t)).sum();
+                sum * x[i]
+            })
+            .collect();
+        vec_approx_eq(y.as_data(), &correct, 1e-14);
+
+        // empty and constant series
+        assert_eq!(
+            apply_chebyshev(&csr, &[], (lower, upper), &x).unwrap().as_data(),
+            &[0.0; 4]
+        );
+        let y = apply_chebyshev(&csr, &[3.0], (lower, upper), &x).unwrap();
+        vec_approx_eq(y.as_data(), &[3.0, 6.0, -3.0, 1.5], 1e-15);
+
+        // the series equals the monomial expansion: T₀ + T₁(b) + T₂(b) = 2 b² + b with b = a - I for (0, 2)
+        let (csr, dense) = laplacian(4);
+        let x = Vector::from(&[1.0, 2.0, 3.0, 4.0]);
+        let y = apply_chebyshev(&dense, &[1.0, 1.0, 1.0], (0.0, 2.0), &x).unwrap();
+        // 2 (a - I)² + (a - I) = 2 a² - 3 a + I
+        let correct = apply_poly(&csr, &[1.0, -3.0, 2.0], &x).unwrap();
+        vec_approx_eq(y.as_data(), correct.as_data(), 1e-14);
+    }
+}
@@ -1,5 +1,4 @@
 use russell_lab::{format_nanoseconds, Stopwatch, StrError, Vector};
-use russell_openblas::set_num_threads;
 use russell_sparse::{
     enum_ordering, enum_scaling, read_matrix_market, ConfigSolver, LinSolKind, Solver, Symmetry, VerifyLinSys,
 };
@@ -28,10 +27,18 @@ struct Options {
     #[structopt(short = "s", long, default_value = "Auto")]
     scaling: String,
 
-    /// Number of threads for OpenMP
+    /// Number of threads for OpenMP (MMP-only)
     #[structopt(short = "n", long, default_value = "1")]
     omp_nt: u32,
 
+    /// Number of threads for OpenBLAS (0 => keep the OpenBLAS setting)
+    #[structopt(short = "b", long, default_value = "0")]
+    blas_nt: u32,
+
+    /// Force single-threaded sections for bitwise-reproducible results
+    #[structopt(short = "d", long)]
+    deterministic: bool,
+
     /// Activate verbose mode
     #[structopt(short = "v", long)]
     verbose: bool,
@@ -41,43 +48,31 @@ fn main() -> Result<(), StrError> {
     // parse options
     let opt = Options::from_args();
 
-    // set openblas num of threads to 1
-    if opt.omp_nt > 1 {
-        set_num_threads(1);
-    }
-
     // select linear solver
     let name = if opt.mmp { LinSolKind::Mmp } else { LinSolKind::Umf };
 
-    // set the sym_mirror flag
-    let sym_mirror = match name {
-        LinSolKind::Mmp => {
-            // MMP uses the lower-diagonal if symmetric.
-            false
-        }
-        LinSolKind::Umf => {
-            // UMF uses the full matrix, if symmetric or not
-            true
-        }
-    };
-
     // read matrix
     let mut sw = Stopwatch::new("");
-    let (trip, symmetric) = read_matrix_market(&opt.matrix_market_file, sym_mirror)?;
+    let (trip, _) = read_matrix_market(&opt.matrix_market_file)?;
     let time_read = sw.stop();
 
     // set the symmetry option
-    let symmetry = if symmetric { Some(Symmetry::General) } else { None };
+    let symmetry = if trip.symmetry().is_symmetric() {
+        Some(Symmetry::General)
+    } else {
+        None
+    };
 
     // set configuration
     let mut config = ConfigSolver::new();
     config
         .lin_sol_kind(name)
         .ordering(enum_ordering(opt.ordering.as_str()))
-        .scaling(enum_scaling(opt.scaling.as_str()));
-    if opt.omp_nt > 1 {
-        config.openmp_num_threads(opt.omp_nt as usize);
-    }
+        .scaling(enum_scaling(opt.scaling.as_str()))
+        .openmp_num_threads(opt.omp_nt as usize)
+        .blas_num_threads(opt.blas_nt as usize)
+        .deterministic(opt.deterministic)
+        .env_overrides()?;
     if opt.verbose {
         config.verbose();
     }
@@ -95,8 +90,7 @@ fn main() -> Result<(), StrError> {
     solver.solve(&mut x, &rhs)?;
 
     // verify solution
-    let triangular = symmetric && !sym_mirror;
-    let verify = VerifyLinSys::new(&trip, &x, &rhs, triangular)?;
+    let verify = VerifyLinSys::new(&trip, &x, &rhs)?;
 
     // matrix name
     let path = Path::new(&opt.matrix_market_file);
@@ -0,0 +1,75 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Holds a flag to cancel a computation from another thread
+///
+/// The clones share the same flag; thus, a clone may be sent to another thread
+/// (e.g., a job scheduler or a signal handler) and the computation is cancelled
+/// when [CancelToken::cancel] is called on any clone (see `Solver::set_cancel_token`).
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::CancelToken;
+/// use std::thread;
+///
+/// let token = CancelToken::new();
+/// let clone = token.clone();
+/// thread::spawn(move || clone.cancel()).join().unwrap();
+/// assert!(token.is_cancelled());
+/// token.reset();
+/// assert!(!token.is_cancelled());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    flag: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Returns a new (not cancelled) token
+    pub fn new() -> Self {
+        CancelToken {
+            flag: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Requests the cancellation of the computation
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns true if the cancellation has been requested
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+
+    /// Clears the cancellation request (e.g., to re-use the token in the next computation)
+    pub fn reset(&self) {
+        self.flag.store(false, Ordering::SeqCst);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::CancelToken;
+    use std::thread;
+
+    #[test]
+    fn cancel_token_works() {
+        let token = CancelToken::new();
+        assert!(!token.is_cancelled());
+        let clone = token.clone();
+        assert_eq!(format!("{:?}", clone), "CancelToken { flag: false }");
+        let handle = thread::spawn(move || {
+            clone.cancel();
+            clone.is_cancelled()
+        });
+        assert!(handle.join().unwrap());
+        assert!(token.is_cancelled());
+        token.reset();
+        assert!(!token.is_cancelled());
+        assert!(!CancelToken::default().is_cancelled());
+    }
+}
@@ -0,0 +1,417 @@
+//! C-compatible interface to the sparse and dense solvers (requires the `capi` feature)
+//!
+//! All functions returning `int32_t` return `RUSSELL_OK` (zero) on success or `RUSSELL_ERROR`
+//! otherwise; then, [russell_last_error] returns the error message. The functions returning
+//! pointers return NULL on failure. The objects allocated by the `new` functions must be
+//! released by the corresponding `drop` functions. A panic (e.g., an integer overflow) does not
+//! unwind into C; it is caught and reported as an error instead.
+//!
+//! The C header is generated (with cbindgen) into `OUT_DIR/russell_sparse.h` when building with
+//! `--features capi` (the source tree is not modified). To build a shared library and copy the header, run:
+//!
+//! ```text
+//! cargo rustc --release --lib --features capi -p russell_sparse --crate-type cdylib
+//! cp $(ls -t target/release/build/russell_sparse-*/out/russell_sparse.h | head -1) .
+//! ```
+
+use crate::{ConfigSolver, LinSolKind, RussellError, Solver, SparseTriplet, StrError, Symmetry, TripletSymmetry};
+use russell_lab::{solve_lin_sys, Matrix, Vector};
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// Indicates success
+pub const RUSSELL_OK: i32 = 0;
+
+/// Indicates failure (see russell_last_error)
+pub const RUSSELL_ERROR: i32 = 1;
+
+/// Triplet symmetry code: the matrix is not symmetric
+pub const RUSSELL_TRIPLET_NONE: i32 = 0;
+
+/// Triplet symmetry code: the matrix is symmetric and all entries are stored
+pub const RUSSELL_TRIPLET_GENERAL_FULL: i32 = 1;
+
+/// Triplet symmetry code: the matrix is symmetric and only the lower triangle is stored
+pub const RUSSELL_TRIPLET_LOWER_TRIANGULAR: i32 = 2;
+
+/// Solver kind code: UMFPACK
+pub const RUSSELL_SOLVER_UMF: i32 = 0;
+
+/// Solver kind code: Mu-M-P-S (not thread-safe)
+pub const RUSSELL_SOLVER_MMP: i32 = 1;
+
+/// Solver symmetry code: no symmetry
+pub const RUSSELL_SYMMETRY_NONE: i32 = 0;
+
+/// Solver symmetry code: general symmetric
+pub const RUSSELL_SYMMETRY_GENERAL: i32 = 1;
+
+/// Solver symmetry code: symmetric positive-definite
+pub const RUSSELL_SYMMETRY_POS_DEF: i32 = 2;
+
+/// Holds the sparse solver and the number of equations (opaque to C)
+pub struct RussellSolver {
+    solver: Solver,
+    neq: usize,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+/// Records the error message for russell_last_error and returns RUSSELL_ERROR
+fn fail<E: Into<RussellError>>(err: E) -> i32 {
+    let message = err.into().to_string();
+    LAST_ERROR.with(|last| {
+        *last.borrow_mut() = CString::new(message).unwrap_or_default();
+    });
+    RUSSELL_ERROR
+}
+
+/// Runs the body of a C function and reports a panic as an error (a panic must not unwind into C)
+///
+/// Returns the result of `body` or `on_panic` if `body` panics.
+fn guard<T, F>(on_panic: T, body: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(res) => res,
+        Err(_) => {
+            fail("an unexpected panic occurred (caught at the C interface)");
+            on_panic
+        }
+    }
+}
+
+/// Converts a C integer into a size
+fn to_usize(value: i32) -> Result<usize, StrError> {
+    if value < 0 {
+        return Err("integer arguments must not be negative");
+    }
+    Ok(value as usize)
+}
+
+/// Returns the message of the last error that occurred in the calling thread
+///
+/// The returned string is owned by the library and is valid until the next call in the same thread.
+#[no_mangle]
+pub extern "C" fn russell_last_error() -> *const c_char {
+    guard(ptr::null(), || LAST_ERROR.with(|last| last.borrow().as_ptr()))
+}
+
+/// Allocates a new triplet (returns NULL on failure)
+///
+/// See [SparseTriplet::new]; `symmetry` is one of the `RUSSELL_TRIPLET_*` codes.
+#[no_mangle]
+pub extern "C" fn russell_triplet_new(neq: i32, max: i32, symmetry: i32) -> *mut SparseTriplet {
+    guard(ptr::null_mut(), || {
+        let symmetry = match symmetry {
+            RUSSELL_TRIPLET_NONE => TripletSymmetry::None,
+            RUSSELL_TRIPLET_GENERAL_FULL => TripletSymmetry::GeneralFull,
+            RUSSELL_TRIPLET_LOWER_TRIANGULAR => TripletSymmetry::LowerTriangular,
+            _ => {
+                fail("the triplet symmetry code is invalid");
+                return ptr::null_mut();
+            }
+        };
+        let res = to_usize(neq).and_then(|neq| to_usize(max).and_then(|max| SparseTriplet::new(neq, max, symmetry)));
+        match res {
+            Ok(trip) => Box::into_raw(Box::new(trip)),
+            Err(err) => {
+                fail(err);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Puts the next triple (i,j,aij) into the triplet (zero-based indices)
+///
+/// # Safety
+///
+/// `trip` must be NULL or a pointer returned by [russell_triplet_new] (and not yet dropped).
+#[no_mangle]
+pub unsafe extern "C" fn russell_triplet_put(trip: *mut SparseTriplet, i: i32, j: i32, aij: f64) -> i32 {
+    guard(RUSSELL_ERROR, || {
+        let trip = match trip.as_mut() {
+            Some(t) => t,
+            None => return fail("the triplet pointer is null"),
+        };
+        let res = to_usize(i).and_then(|i| to_usize(j).and_then(|j| trip.put(i, j, aij)));
+        match res {
+            Ok(()) => RUSSELL_OK,
+            Err(err) => fail(err),
+        }
+    })
+}
+
+/// Resets the triplet, allowing using "put" from scratch
+///
+/// # Safety
+///
+/// `trip` must be NULL or a pointer returned by [russell_triplet_new] (and not yet dropped).
+#[no_mangle]
+pub unsafe extern "C" fn russell_triplet_reset(trip: *mut SparseTriplet) -> i32 {
+    guard(RUSSELL_ERROR, || match trip.as_mut() {
+        Some(t) => {
+            t.reset();
+            RUSSELL_OK
+        }
+        None => fail("the triplet pointer is null"),
+    })
+}
+
+/// Releases the memory allocated by [russell_triplet_new]
+///
+/// # Safety
+///
+/// `trip` must be NULL or a pointer returned by [russell_triplet_new] (and not yet dropped).
+#[no_mangle]
+pub unsafe extern "C" fn russell_triplet_drop(trip: *mut SparseTriplet) {
+    guard((), || {
+        if !trip.is_null() {
+            drop(Box::from_raw(trip));
+        }
+    })
+}
+
+/// Allocates a new sparse solver (returns NULL on failure)
+///
+/// See [Solver::new]; `kind` is one of the `RUSSELL_SOLVER_*` codes and `symmetry`
+/// is one of the `RUSSELL_SYMMETRY_*` codes.
+#[no_mangle]
+pub extern "C" fn russell_solver_new(kind: i32, neq: i32, nnz: i32, symmetry: i32) -> *mut RussellSolver {
+    guard(ptr::null_mut(), || {
+        let mut config = ConfigSolver::new();
+        match kind {
+            RUSSELL_SOLVER_UMF => config.lin_sol_kind(LinSolKind::Umf),
+            RUSSELL_SOLVER_MMP => config.lin_sol_kind(LinSolKind::Mmp),
+            _ => {
+                fail("the solver kind code is invalid");
+                return ptr::null_mut();
+            }
+        };
+        let symmetry = match symmetry {
+            RUSSELL_SYMMETRY_NONE => None,
+            RUSSELL_SYMMETRY_GENERAL => Some(Symmetry::General),
+            RUSSELL_SYMMETRY_POS_DEF => Some(Symmetry::PosDef),
+            _ => {
+                fail("the solver symmetry code is invalid");
+                return ptr::null_mut();
+            }
+        };
+        let res = to_usize(neq)
+            .and_then(|neq| to_usize(nnz).and_then(|nnz| Ok((Solver::new(config, neq, nnz, symmetry)?, neq))));
+        match res {
+            Ok((solver, neq)) => Box::into_raw(Box::new(RussellSolver { solver, neq })),
+            Err(err) => {
+                fail(err);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Performs the factorization of the matrix given by a triplet
+///
+/// # Safety
+///
+/// `solver` and `trip` must be NULL or pointers returned by [russell_solver_new]
+/// and [russell_triplet_new] (and not yet dropped).
+#[no_mangle]
+pub unsafe extern "C" fn russell_solver_factorize(solver: *mut RussellSolver, trip: *const SparseTriplet) -> i32 {
+    guard(RUSSELL_ERROR, || {
+        let (solver, trip) = match (solver.as_mut(), trip.as_ref()) {
+            (Some(s), Some(t)) => (s, t),
+            _ => return fail("the solver or triplet pointer is null"),
+        };
+        match solver.solver.factorize(trip) {
+            Ok(()) => RUSSELL_OK,
+            Err(err) => fail(err),
+        }
+    })
+}
+
+/// Performs a new numeric factorization reusing the ordering of the previous factorization
+///
+/// The triplet must have the same sparsity pattern as in [russell_solver_factorize].
+///
+/// # Safety
+///
+/// `solver` and `trip` must be NULL or pointers returned by [russell_solver_new]
+/// and [russell_triplet_new] (and not yet dropped).
+#[no_mangle]
+pub unsafe extern "C" fn russell_solver_update_values(solver: *mut RussellSolver, trip: *const SparseTriplet) -> i32 {
+    guard(RUSSELL_ERROR, || {
+        let (solver, trip) = match (solver.as_mut(), trip.as_ref()) {
+            (Some(s), Some(t)) => (s, t),
+            _ => return fail("the solver or triplet pointer is null"),
+        };
+        match solver.solver.update_values(trip) {
+            Ok(()) => RUSSELL_OK,
+            Err(err) => fail(err),
+        }
+    })
+}
+
+/// Computes the solution `x` of the linear system `a ⋅ x = rhs` (after factorize)
+///
+/// # Safety
+///
+/// `solver` must be NULL or a pointer returned by [russell_solver_new] (and not yet dropped).
+/// Also, `x` and `rhs` must be NULL or point to arrays with (at least) `neq` values.
+#[no_mangle]
+pub unsafe extern "C" fn russell_solver_solve(solver: *mut RussellSolver, x: *mut f64, rhs: *const f64) -> i32 {
+    guard(RUSSELL_ERROR, || {
+        let solver = match solver.as_mut() {
+            Some(s) => s,
+            None => return fail("the solver pointer is null"),
+        };
+        if x.is_null() || rhs.is_null() {
+            return fail("the x or rhs pointer is null");
+        }
+        let neq = solver.neq;
+        let mut xx = Vector::new(neq);
+        let rr = Vector::from(&std::slice::from_raw_parts(rhs, neq));
+        if let Err(err) = solver.solver.solve(&mut xx, &rr) {
+            return fail(err);
+        }
+        std::slice::from_raw_parts_mut(x, neq).copy_from_slice(xx.as_data());
+        RUSSELL_OK
+    })
+}
+
+/// Releases the memory allocated by [russell_solver_new]
+///
+/// # Safety
+///
+/// `solver` must be NULL or a pointer returned by [russell_solver_new] (and not yet dropped).
+#[no_mangle]
+pub unsafe extern "C" fn russell_solver_drop(solver: *mut RussellSolver) {
+    guard((), || {
+        if !solver.is_null() {
+            drop(Box::from_raw(solver));
+        }
+    })
+}
+
+/// Solves a dense linear system `a ⋅ x = b` (see [russell_lab::solve_lin_sys])
+///
+/// The matrix `a` (n × n) must be given in **col-major** order and is not modified.
+/// On output, `b` (n) holds the solution `x`.
+///
+/// # Safety
+///
+/// `a` and `b` must be NULL or point to arrays with (at least) `n × n` and `n` values, respectively.
+#[no_mangle]
+pub unsafe extern "C" fn russell_dense_solve(n: i32, a: *const f64, b: *mut f64) -> i32 {
+    guard(RUSSELL_ERROR, || {
+        if a.is_null() || b.is_null() {
+            return fail("the a or b pointer is null");
+        }
+        let n = match to_usize(n) {
+            Ok(n) => n,
+            Err(err) => return fail(err),
+        };
+        let mut aa = Matrix::new(n, n);
+        aa.as_mut_data().copy_from_slice(std::slice::from_raw_parts(a, n * n));
+        let bb = std::slice::from_raw_parts_mut(b, n);
+        let mut xx = Vector::new(n);
+        xx.as_mut_data().copy_from_slice(bb);
+        if let Err(err) = solve_lin_sys(&mut xx, &mut aa) {
+            return fail(err);
+        }
+        bb.copy_from_slice(xx.as_data());
+        RUSSELL_OK
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(russell_last_error()).to_string_lossy().into_owned() }
+    }
+
+    #[test]
+    fn guard_catches_panics() {
+        assert_eq!(guard(RUSSELL_ERROR, || RUSSELL_OK), RUSSELL_OK);
+        assert_eq!(guard(RUSSELL_ERROR, || panic!("integer overflow")), RUSSELL_ERROR);
+        assert_eq!(last_error(), "an unexpected panic occurred (caught at the C interface)");
+        let p: *mut SparseTriplet = guard(ptr::null_mut(), || panic!("index out of bounds"));
+        assert!(p.is_null());
+    }
+
+    #[test]
+    fn triplet_functions_work() {
+        assert!(russell_triplet_new(2, 2, 9).is_null());
+        assert_eq!(last_error(), "the triplet symmetry code is invalid");
+        assert!(russell_triplet_new(-1, 2, RUSSELL_TRIPLET_NONE).is_null());
+        assert_eq!(last_error(), "integer arguments must not be negative");
+        assert!(russell_triplet_new(0, 2, RUSSELL_TRIPLET_NONE).is_null());
+        assert_eq!(last_error(), "neq and max must be greater than zero");
+        unsafe {
+            assert_eq!(russell_triplet_put(ptr::null_mut(), 0, 0, 1.0), RUSSELL_ERROR);
+            assert_eq!(last_error(), "the triplet pointer is null");
+            let trip = russell_triplet_new(2, 2, RUSSELL_TRIPLET_NONE);
+            assert!(!trip.is_null());
+            assert_eq!(russell_triplet_put(trip, 0, 0, 1.0), RUSSELL_OK);
+            assert_eq!(russell_triplet_put(trip, 1, 1, 2.0), RUSSELL_OK);
+            assert_eq!(russell_triplet_put(trip, 1, 0, 3.0), RUSSELL_ERROR);
+            assert_eq!(last_error(), "current nnz (number of non-zeros) reached maximum limit");
+            assert_eq!((*trip).nnz_current(), 2);
+            assert_eq!(russell_triplet_reset(trip), RUSSELL_OK);
+            assert_eq!((*trip).nnz_current(), 0);
+            russell_triplet_drop(trip);
+            russell_triplet_drop(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn solver_functions_capture_errors() {
+        assert!(russell_solver_new(9, 2, 2, RUSSELL_SYMMETRY_NONE).is_null());
+        assert_eq!(last_error(), "the solver kind code is invalid");
+        assert!(russell_solver_new(RUSSELL_SOLVER_UMF, 2, 2, 9).is_null());
+        assert_eq!(last_error(), "the solver symmetry code is invalid");
+        unsafe {
+            assert_eq!(russell_solver_factorize(ptr::null_mut(), ptr::null()), RUSSELL_ERROR);
+            assert_eq!(last_error(), "the solver or triplet pointer is null");
+            assert_eq!(
+                russell_solver_update_values(ptr::null_mut(), ptr::null()),
+                RUSSELL_ERROR
+            );
+            assert_eq!(last_error(), "the solver or triplet pointer is null");
+            let mut x = [0.0; 2];
+            let rhs = [0.0; 2];
+            assert_eq!(
+                russell_solver_solve(ptr::null_mut(), x.as_mut_ptr(), rhs.as_ptr()),
+                RUSSELL_ERROR
+            );
+            assert_eq!(last_error(), "the solver pointer is null");
+            russell_solver_drop(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn dense_solve_works() {
+        unsafe {
+            assert_eq!(russell_dense_solve(2, ptr::null(), ptr::null_mut()), RUSSELL_ERROR);
+            assert_eq!(last_error(), "the a or b pointer is null");
+            let a = [2.0, 0.0, 0.0, 4.0]; // col-major
+            let mut b = [2.0, 8.0];
+            assert_eq!(russell_dense_solve(-2, a.as_ptr(), b.as_mut_ptr()), RUSSELL_ERROR);
+            assert_eq!(last_error(), "integer arguments must not be negative");
+            assert_eq!(russell_dense_solve(2, a.as_ptr(), b.as_mut_ptr()), RUSSELL_OK);
+            assert_eq!(b, [1.0, 2.0]);
+            assert_eq!(a, [2.0, 0.0, 0.0, 4.0]);
+        }
+    }
+}
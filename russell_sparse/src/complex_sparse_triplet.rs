@@ -0,0 +1,243 @@
+use crate::{StrError, TripletSymmetry};
+use num_complex::Complex64;
+use russell_lab::ComplexMatrix;
+use russell_openblas::to_i32;
+
+/// Holds triples (i,j,aij) representing a sparse matrix with complex values
+///
+/// # Remarks
+///
+/// - This structure mirrors [crate::SparseTriplet] (see its remarks), but holds [Complex64] values
+/// - Entries with repeated (i,j) indices are allowed and are summed up (e.g., by `get` and `to_matrix`)
+/// - With [TripletSymmetry::LowerTriangular], the matrix is complex symmetric (`a = aᵀ`, not Hermitian)
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_lab::ComplexMatrix;
+/// use russell_sparse::{ComplexSparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut trip = ComplexSparseTriplet::new(2, 3, TripletSymmetry::None)?;
+///     trip.put(0, 0, Complex64::new(1.0, 1.0))?;
+///     trip.put(0, 1, Complex64::new(2.0, -1.0))?;
+///     trip.put(1, 1, Complex64::new(0.0, 3.0))?;
+///     assert_eq!(trip.get(0, 1), Complex64::new(2.0, -1.0));
+///     let mut a = ComplexMatrix::new(2, 2);
+///     trip.to_matrix(&mut a)?;
+///     assert_eq!(
+///         format!("{}", a),
+///         "┌           ┐\n\
+///          │ 1+1i 2-1i │\n\
+///          │ 0+0i 0+3i │\n\
+///          └           ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct ComplexSparseTriplet {
+    pub(crate) neq: usize,                 // [i32] number of rows = number of columns = n_equation
+    pub(crate) pos: usize,                 // [i32] current index => nnz in the end
+    pub(crate) max: usize,                 // [i32] max allowed number of entries (may be > nnz)
+    pub(crate) symmetry: TripletSymmetry,  // symmetry and storage scheme
+    pub(crate) indices_i: Vec<i32>,        // [nnz] indices i
+    pub(crate) indices_j: Vec<i32>,        // [nnz] indices j
+    pub(crate) values_aij: Vec<Complex64>, // [nnz] values aij
+}
+
+impl ComplexSparseTriplet {
+    /// Creates a new ComplexSparseTriplet representing a sparse matrix
+    ///
+    /// # Input
+    ///
+    /// * `neq` -- The number of rows (= ncol) of the sparse matrix
+    /// * `max` -- The maximum number fo non-zero (nnz) values in the sparse matrix,
+    ///   including entries with repeated indices
+    /// * `symmetry` -- The symmetry and storage scheme (see [TripletSymmetry])
+    pub fn new(neq: usize, max: usize, symmetry: TripletSymmetry) -> Result<Self, StrError> {
+        if neq == 0 || max == 0 {
+            return Err("neq and max must be greater than zero");
+        }
+        Ok(ComplexSparseTriplet {
+            neq,
+            pos: 0,
+            max,
+            symmetry,
+            indices_i: vec![0; max],
+            indices_j: vec![0; max],
+            values_aij: vec![Complex64::new(0.0, 0.0); max],
+        })
+    }
+
+    /// Puts the next triple (i,j,aij) into the Triplet
+    ///
+    /// **Note:** If the symmetry is [TripletSymmetry::LowerTriangular], an upper triangular
+    /// entry (i < j) is stored as (j,i), i.e., in the lower triangle.
+    pub fn put(&mut self, i: usize, j: usize, aij: Complex64) -> Result<(), StrError> {
+        if i >= self.neq {
+            return Err("sparse matrix row index is out of bounds");
+        }
+        if j >= self.neq {
+            return Err("sparse matrix column index is out of bounds");
+        }
+        if self.pos >= self.max {
+            return Err("current nnz (number of non-zeros) reached maximum limit");
+        }
+        let (i, j) = match self.symmetry {
+            TripletSymmetry::LowerTriangular if i < j => (j, i),
+            _ => (i, j),
+        };
+        self.indices_i[self.pos] = to_i32(i);
+        self.indices_j[self.pos] = to_i32(j);
+        self.values_aij[self.pos] = aij;
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Returns the (i,j) component of the represented matrix (repeated entries are summed up)
+    ///
+    /// **Note:** This function loops over all entries; thus, it is not efficient for large matrices.
+    pub fn get(&self, i: usize, j: usize) -> Complex64 {
+        let (i, j) = match self.symmetry {
+            TripletSymmetry::LowerTriangular if i < j => (j, i),
+            _ => (i, j),
+        };
+        let (i, j) = (to_i32(i), to_i32(j));
+        let mut sum = Complex64::new(0.0, 0.0);
+        for p in 0..self.pos {
+            if self.indices_i[p] == i && self.indices_j[p] == j {
+                sum += self.values_aij[p];
+            }
+        }
+        sum
+    }
+
+    /// Returns the (nrow = ncol) dimensions of the matrix represented by this Triplet
+    pub fn neq(&self) -> usize {
+        self.neq
+    }
+
+    /// Returns the current number of non-zero values (nnz), including entries with repeated indices
+    pub fn nnz_current(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the maximum number of non-zero values (allocated size)
+    pub fn nnz_maximum(&self) -> usize {
+        self.max
+    }
+
+    /// Returns the symmetry (and storage scheme) of this Triplet
+    pub fn symmetry(&self) -> TripletSymmetry {
+        self.symmetry
+    }
+
+    /// Resets the position of the current non-zero value, allowing using "put" from scratch
+    pub fn reset(&mut self) {
+        self.pos = 0;
+    }
+
+    /// Converts the triplet data to a matrix, up to a limit
+    ///
+    /// # Input
+    ///
+    /// `a` -- (nrow_max, ncol_max) matrix to hold the triplet data.
+    ///  The output matrix may have fewer rows or fewer columns than the triplet data.
+    ///
+    /// **Note:** If the symmetry is [TripletSymmetry::LowerTriangular], the off-diagonal
+    /// entries are mirrored (without conjugation); i.e., the output is the full matrix.
+    pub fn to_matrix(&self, a: &mut ComplexMatrix) -> Result<(), StrError> {
+        let (m, n) = a.dims();
+        if m > self.neq || n > self.neq {
+            return Err("wrong matrix dimensions");
+        }
+        let mirror = self.symmetry == TripletSymmetry::LowerTriangular;
+        a.fill(Complex64::new(0.0, 0.0));
+        for p in 0..self.pos {
+            let (i, j) = (self.indices_i[p] as usize, self.indices_j[p] as usize);
+            if i < m && j < n {
+                a.add(i, j, self.values_aij[p]);
+            }
+            if mirror && i != j && j < m && i < n {
+                a.add(j, i, self.values_aij[p]);
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ComplexSparseTriplet;
+    use crate::TripletSymmetry;
+    use num_complex::Complex64;
+    use russell_lab::ComplexMatrix;
+
+    #[test]
+    fn new_and_put_capture_errors() {
+        assert_eq!(
+            ComplexSparseTriplet::new(0, 3, TripletSymmetry::None).err(),
+            Some("neq and max must be greater than zero")
+        );
+        let mut trip = ComplexSparseTriplet::new(2, 1, TripletSymmetry::None).unwrap();
+        let z = Complex64::new(1.0, 1.0);
+        assert_eq!(
+            trip.put(2, 0, z).err(),
+            Some("sparse matrix row index is out of bounds")
+        );
+        assert_eq!(
+            trip.put(0, 2, z).err(),
+            Some("sparse matrix column index is out of bounds")
+        );
+        trip.put(0, 0, z).unwrap();
+        assert_eq!(
+            trip.put(1, 1, z).err(),
+            Some("current nnz (number of non-zeros) reached maximum limit")
+        );
+        let mut a = ComplexMatrix::new(3, 3);
+        assert_eq!(trip.to_matrix(&mut a).err(), Some("wrong matrix dimensions"));
+    }
+
+    #[test]
+    fn getters_and_reset_work() {
+        let mut trip = ComplexSparseTriplet::new(3, 4, TripletSymmetry::None).unwrap();
+        assert_eq!(trip.neq(), 3);
+        assert_eq!(trip.nnz_maximum(), 4);
+        assert_eq!(trip.symmetry(), TripletSymmetry::None);
+        trip.put(1, 2, Complex64::new(1.0, 2.0)).unwrap();
+        trip.put(1, 2, Complex64::new(0.5, -1.0)).unwrap();
+        assert_eq!(trip.nnz_current(), 2);
+        assert_eq!(trip.get(1, 2), Complex64::new(1.5, 1.0));
+        assert_eq!(trip.get(2, 1), Complex64::new(0.0, 0.0));
+        trip.reset();
+        assert_eq!(trip.nnz_current(), 0);
+        assert_eq!(trip.get(1, 2), Complex64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn to_matrix_works() {
+        let mut trip = ComplexSparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(0, 0, Complex64::new(1.0, 0.0)).unwrap();
+        trip.put(0, 1, Complex64::new(2.0, 1.0)).unwrap(); // moved to (1, 0)
+        trip.put(1, 1, Complex64::new(3.0, 0.0)).unwrap();
+        trip.put(2, 1, Complex64::new(0.0, -4.0)).unwrap();
+        assert_eq!(trip.get(0, 1), Complex64::new(2.0, 1.0));
+        assert_eq!(trip.get(1, 0), Complex64::new(2.0, 1.0));
+        let mut a = ComplexMatrix::new(3, 3);
+        trip.to_matrix(&mut a).unwrap();
+        assert_eq!(
+            format!("{}", a),
+            "┌                ┐\n\
+             │ 1+0i 2+1i 0+0i │\n\
+             │ 2+1i 3+0i 0-4i │\n\
+             │ 0+0i 0-4i 0+0i │\n\
+             └                ┘"
+        );
+        let mut b = ComplexMatrix::new(2, 1);
+        trip.to_matrix(&mut b).unwrap();
+        assert_eq!(b.as_data(), &[Complex64::new(1.0, 0.0), Complex64::new(2.0, 1.0)]);
+    }
+}
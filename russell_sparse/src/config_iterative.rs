@@ -0,0 +1,79 @@
+/// Holds configuration options for the iterative solvers
+///
+/// See [crate::solve_pcg], [crate::solve_bicgstab], [crate::solve_gmres], and [crate::solve_lsqr].
+///
+/// **Note:** The `restart` option is ignored by the solvers other than GMRES.
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::ConfigIterative;
+///
+/// let config = *ConfigIterative::new().tolerance(1e-8).max_iterations(200).restart(20);
+/// assert_eq!(
+///     format!("{:?}", config),
+///     "ConfigIterative { tolerance: 1e-8, max_iterations: 200, restart: 20 }"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConfigIterative {
+    pub(crate) tolerance: f64,        // relative tolerance on the residual norm
+    pub(crate) max_iterations: usize, // max number of iterations
+    pub(crate) restart: usize,        // dimension of the Krylov subspace before restarting (GMRES-only)
+}
+
+impl ConfigIterative {
+    /// Returns a default configuration
+    pub fn new() -> Self {
+        ConfigIterative {
+            tolerance: 1e-10,
+            max_iterations: 1000,
+            restart: 30,
+        }
+    }
+
+    /// Sets the relative tolerance
+    ///
+    /// The iterations stop when `‖b - a⋅x‖₂ ≤ tolerance ⋅ ‖b‖₂`.
+    pub fn tolerance(&mut self, value: f64) -> &mut Self {
+        self.tolerance = value;
+        self
+    }
+
+    /// Sets the maximum number of iterations (including all restarts)
+    pub fn max_iterations(&mut self, value: usize) -> &mut Self {
+        self.max_iterations = value;
+        self
+    }
+
+    /// Sets the dimension of the Krylov subspace before restarting (GMRES-only)
+    pub fn restart(&mut self, value: usize) -> &mut Self {
+        self.restart = value;
+        self
+    }
+}
+
+impl Default for ConfigIterative {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigIterative;
+
+    #[test]
+    fn new_and_setters_work() {
+        let config = ConfigIterative::default();
+        assert_eq!(
+            (config.tolerance, config.max_iterations, config.restart),
+            (1e-10, 1000, 30)
+        );
+        let mut config = ConfigIterative::new();
+        config.tolerance(1e-6).max_iterations(10).restart(5);
+        assert_eq!((config.tolerance, config.max_iterations, config.restart), (1e-6, 10, 5));
+    }
+}
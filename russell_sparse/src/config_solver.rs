@@ -1,17 +1,53 @@
 use super::{str_enum_ordering, str_enum_scaling, LinSolKind, Ordering, Scaling};
-use russell_openblas::to_i32;
+use crate::StrError;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Environment variable overriding the number of OpenMP threads (see [ConfigSolver::env_overrides])
+pub const ENV_OPENMP_NUM_THREADS: &str = "RUSSELL_SPARSE_OPENMP_NUM_THREADS";
+
+/// Environment variable overriding the number of BLAS threads (see [ConfigSolver::env_overrides])
+pub const ENV_BLAS_NUM_THREADS: &str = "RUSSELL_SPARSE_BLAS_NUM_THREADS";
+
 /// Holds configuration options for the sparse Solver
-#[derive(Copy, Clone, Debug)]
+///
+/// The setters return `&mut Self`; thus, they can be chained (builder-style).
+/// The configuration is also serializable; thus, it can be loaded from (or recorded into)
+/// JSON or TOML files, for instance. Missing fields are set to their default values.
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::{ConfigSolver, LinSolKind, Ordering, Scaling};
+///
+/// let config = *ConfigSolver::new()
+///     .lin_sol_kind(LinSolKind::Mmp)
+///     .ordering(Ordering::Metis)
+///     .scaling(Scaling::No);
+/// assert_eq!(
+///     format!("{:?}", config),
+///     "ConfigSolver { lin_sol_kind: Mmp, ordering: Metis, scaling: No, \
+///      pct_inc_workspace: 100, max_work_memory: 0, openmp_num_threads: 1, \
+///      blas_num_threads: 0, verbose: false, equilibrate: false, mixed_precision: false, \
+///      deterministic: false, max_time_factorize: 0, max_time_solve: 0 }"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ConfigSolver {
-    pub(crate) lin_sol_kind: LinSolKind, // linear solver kind
-    pub(crate) ordering: i32,            // symmetric permutation (ordering)
-    pub(crate) scaling: i32,             // scaling strategy
-    pub(crate) pct_inc_workspace: i32,   // % increase in the estimated working space (MMP-only)
-    pub(crate) max_work_memory: i32,     // max size of the working memory in mega bytes (MMP-only)
-    pub(crate) openmp_num_threads: i32,  // number of OpenMP threads (MMP-only)
-    pub(crate) verbose: i32,             // show lower-level messages
+    pub(crate) lin_sol_kind: LinSolKind,  // linear solver kind
+    pub(crate) ordering: Ordering,        // symmetric permutation (ordering)
+    pub(crate) scaling: Scaling,          // scaling strategy
+    pub(crate) pct_inc_workspace: usize,  // % increase in the estimated working space (MMP-only)
+    pub(crate) max_work_memory: usize,    // max size of the working memory in mega bytes (MMP-only)
+    pub(crate) openmp_num_threads: usize, // number of OpenMP threads (MMP-only)
+    pub(crate) blas_num_threads: usize,   // number of BLAS threads (0 => unchanged)
+    pub(crate) verbose: bool,             // show lower-level messages
+    pub(crate) equilibrate: bool,         // apply Jacobi scaling before factorization
+    pub(crate) mixed_precision: bool,     // factorize in single precision and refine in double precision
+    pub(crate) deterministic: bool,       // force single-threaded sections for bitwise-reproducible results
+    pub(crate) max_time_factorize: u64,   // time limit of factorize in milliseconds (0 => unlimited)
+    pub(crate) max_time_solve: u64,       // time limit of solve in milliseconds (0 => unlimited)
 }
 
 impl ConfigSolver {
@@ -19,12 +55,18 @@ impl ConfigSolver {
     pub fn new() -> Self {
         ConfigSolver {
             lin_sol_kind: LinSolKind::Umf,
-            ordering: Ordering::Auto as i32,
-            scaling: Scaling::Auto as i32,
+            ordering: Ordering::Auto,
+            scaling: Scaling::Auto,
             pct_inc_workspace: 100, // (MMP-only)
             max_work_memory: 0,     // (MMP-only) 0 => Auto
             openmp_num_threads: 1,  // (MMP-only)
-            verbose: 0,
+            blas_num_threads: 0,    // 0 => do not change the OpenBLAS setting
+            verbose: false,
+            equilibrate: false,
+            mixed_precision: false,
+            deterministic: false,
+            max_time_factorize: 0, // 0 => unlimited
+            max_time_solve: 0,     // 0 => unlimited
         }
     }
 
@@ -36,39 +78,169 @@ impl ConfigSolver {
 
     /// Sets the method to compute a symmetric permutation (ordering)
     pub fn ordering(&mut self, selection: Ordering) -> &mut Self {
-        self.ordering = selection as i32;
+        self.ordering = selection;
         self
     }
 
     /// Sets the scaling strategy
     pub fn scaling(&mut self, selection: Scaling) -> &mut Self {
-        self.scaling = selection as i32;
+        self.scaling = selection;
         self
     }
 
     /// Sets the percentage increase in the estimated working space (MMP-only)
     pub fn pct_inc_workspace(&mut self, value: usize) -> &mut Self {
-        self.pct_inc_workspace = to_i32(value);
+        self.pct_inc_workspace = value;
         self
     }
 
     /// Sets the maximum size of the working memory in mega bytes (MMP-only)
     pub fn max_work_memory(&mut self, value: usize) -> &mut Self {
-        self.max_work_memory = to_i32(value);
+        self.max_work_memory = value;
         self
     }
 
     /// Sets the number of OpenMP threads (MMP-only)
     pub fn openmp_num_threads(&mut self, value: usize) -> &mut Self {
-        self.openmp_num_threads = to_i32(value);
+        self.openmp_num_threads = value;
+        self
+    }
+
+    /// Sets the number of BLAS (OpenBLAS) threads
+    ///
+    /// The number of threads is set by `Solver::new` if `value > 0`; otherwise (default),
+    /// the current OpenBLAS setting (e.g., from `OPENBLAS_NUM_THREADS`) is kept unchanged.
+    /// This option is independent from [ConfigSolver::openmp_num_threads].
+    ///
+    /// **Note:** The OpenBLAS setting is global; i.e., it affects the whole process.
+    pub fn blas_num_threads(&mut self, value: usize) -> &mut Self {
+        self.blas_num_threads = value;
         self
     }
 
+    /// Overrides the number of threads with the values of environment variables (if defined)
+    ///
+    /// * [ENV_OPENMP_NUM_THREADS] -- overrides `openmp_num_threads`
+    /// * [ENV_BLAS_NUM_THREADS] -- overrides `blas_num_threads`
+    ///
+    /// Returns an error if a variable is defined but does not hold a non-negative integer.
+    pub fn env_overrides(&mut self) -> Result<&mut Self, StrError> {
+        self.overrides_from(|name| std::env::var(name).ok())
+    }
+
+    /// Overrides the number of threads with the values given by `lookup` (variable name => value)
+    fn overrides_from<F>(&mut self, lookup: F) -> Result<&mut Self, StrError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(value) = parse_num_threads(lookup(ENV_OPENMP_NUM_THREADS))? {
+            self.openmp_num_threads = value;
+        }
+        if let Some(value) = parse_num_threads(lookup(ENV_BLAS_NUM_THREADS))? {
+            self.blas_num_threads = value;
+        }
+        Ok(self)
+    }
+
     /// Sets option to show lower-level messages
     pub fn verbose(&mut self) -> &mut Self {
-        self.verbose = 1;
+        self.verbose = true;
+        self
+    }
+
+    /// Sets option to equilibrate the matrix (Jacobi scaling) before the factorization
+    ///
+    /// The solver then factorizes `D⋅A⋅D` with `D = diag(1/√|aᵢᵢ|)` and transparently
+    /// scales the right-hand side and the solution; i.e., `solve` still returns `x` of `A⋅x = rhs`.
+    /// This may improve the accuracy on badly scaled systems and keeps the symmetry of `A`.
+    pub fn equilibrate(&mut self, flag: bool) -> &mut Self {
+        self.equilibrate = flag;
+        self
+    }
+
+    /// Sets option to factorize in single precision and refine the solution in double precision
+    ///
+    /// The values of the matrix are rounded to single precision (f32) before the factorization;
+    /// then, `solve` performs iterative refinement in double precision using the original matrix
+    /// (similar to Lapack dsgesv). The backends only work in double precision; thus, the single
+    /// precision factorization is emulated and the factorization time and memory are not reduced.
+    /// Nonetheless, this option allows assessing the accuracy of a single precision factorization.
+    ///
+    /// **Note:** A copy of the matrix is kept for computing the residuals.
+    pub fn mixed_precision(&mut self, flag: bool) -> &mut Self {
+        self.mixed_precision = flag;
+        self
+    }
+
+    /// Sets option to obtain bitwise-reproducible results across runs (e.g., for regression tests)
+    ///
+    /// The backends do not provide a reproducibility option for multithreaded runs because the
+    /// order of the floating-point operations depends on the scheduling of the threads. Thus,
+    /// this option forces the number of OpenMP threads (MMP) and BLAS threads to one,
+    /// overriding [ConfigSolver::openmp_num_threads] and [ConfigSolver::blas_num_threads].
+    ///
+    /// **Note:** The OpenBLAS setting is global; i.e., it affects the whole process.
+    pub fn deterministic(&mut self, flag: bool) -> &mut Self {
+        self.deterministic = flag;
         self
     }
+
+    /// Sets the time limit of the factorization in milliseconds (0 => unlimited)
+    ///
+    /// The Solver returns an error (see [crate::RussellError::Timeout]) if the limit is exceeded.
+    ///
+    /// **Note:** The backends cannot be interrupted; thus, the elapsed time is checked before and
+    /// after calling the backend. The factorization is discarded if the limit is exceeded.
+    pub fn max_time_factorize(&mut self, milliseconds: u64) -> &mut Self {
+        self.max_time_factorize = milliseconds;
+        self
+    }
+
+    /// Sets the time limit of the solution in milliseconds (0 => unlimited)
+    ///
+    /// The Solver returns an error (see [crate::RussellError::Timeout]) if the limit is exceeded.
+    ///
+    /// **Note:** The backends cannot be interrupted; thus, the elapsed time is checked after each
+    /// backend solution (e.g., after each step of the mixed precision iterative refinement).
+    pub fn max_time_solve(&mut self, milliseconds: u64) -> &mut Self {
+        self.max_time_solve = milliseconds;
+        self
+    }
+
+    /// Returns the number of OpenMP threads accounting for the deterministic option
+    pub(crate) fn actual_openmp_num_threads(&self) -> usize {
+        if self.deterministic {
+            1
+        } else {
+            self.openmp_num_threads
+        }
+    }
+
+    /// Returns the number of BLAS threads accounting for the deterministic option (0 => unchanged)
+    pub(crate) fn actual_blas_num_threads(&self) -> usize {
+        if self.deterministic {
+            1
+        } else {
+            self.blas_num_threads
+        }
+    }
+}
+
+/// Parses the number of threads given by an environment variable (if defined)
+fn parse_num_threads(value: Option<String>) -> Result<Option<usize>, StrError> {
+    match value {
+        Some(value) => match value.trim().parse::<usize>() {
+            Ok(n) => Ok(Some(n)),
+            Err(_) => Err("the number of threads given by the environment variable is invalid"),
+        },
+        None => Ok(None),
+    }
+}
+
+impl Default for ConfigSolver {
+    fn default() -> Self {
+        ConfigSolver::new()
+    }
 }
 
 impl fmt::Display for ConfigSolver {
@@ -90,13 +262,15 @@ impl fmt::Display for ConfigSolver {
              \x20\x20\x20\x20\"scaling\": \"{}\",\n\
              \x20\x20\x20\x20\"pctIncWorkspace\": {},\n\
              \x20\x20\x20\x20\"maxWorkMemory\": {},\n\
-             \x20\x20\x20\x20\"openmpNumThreads\": {}",
+             \x20\x20\x20\x20\"openmpNumThreads\": {},\n\
+             \x20\x20\x20\x20\"blasNumThreads\": {}",
             name,
-            str_enum_ordering(self.ordering),
-            str_enum_scaling(self.scaling),
+            str_enum_ordering(self.ordering as i32),
+            str_enum_scaling(self.scaling as i32),
             self.pct_inc_workspace,
             self.max_work_memory,
             self.openmp_num_threads,
+            self.blas_num_threads,
         )
         .unwrap();
         Ok(())
@@ -107,11 +281,11 @@ impl fmt::Display for ConfigSolver {
 
 #[cfg(test)]
 mod tests {
-    use super::{ConfigSolver, LinSolKind, Ordering, Scaling};
+    use super::{ConfigSolver, LinSolKind, Ordering, Scaling, ENV_BLAS_NUM_THREADS, ENV_OPENMP_NUM_THREADS};
 
     #[test]
     fn clone_copy_and_debug_work() {
-        let correct = "ConfigSolver { lin_sol_kind: Umf, ordering: 2, scaling: 0, pct_inc_workspace: 100, max_work_memory: 0, openmp_num_threads: 1, verbose: 0 }";
+        let correct = "ConfigSolver { lin_sol_kind: Umf, ordering: Auto, scaling: Auto, pct_inc_workspace: 100, max_work_memory: 0, openmp_num_threads: 1, blas_num_threads: 0, verbose: false, equilibrate: false, mixed_precision: false, deterministic: false, max_time_factorize: 0, max_time_solve: 0 }";
         let config = ConfigSolver::new();
         let copy = config;
         let clone = config.clone();
@@ -123,12 +297,18 @@ mod tests {
     #[test]
     fn new_works() {
         let config = ConfigSolver::new();
-        assert_eq!(config.ordering, Ordering::Auto as i32);
-        assert_eq!(config.scaling, Scaling::Auto as i32);
+        assert_eq!(config.ordering, Ordering::Auto);
+        assert_eq!(config.scaling, Scaling::Auto);
         assert_eq!(config.pct_inc_workspace, 100);
         assert_eq!(config.max_work_memory, 0);
         assert_eq!(config.openmp_num_threads, 1);
-        assert_eq!(config.verbose, 0);
+        assert_eq!(config.blas_num_threads, 0);
+        assert!(!config.verbose);
+        assert!(!config.equilibrate);
+        assert!(!config.mixed_precision);
+        assert!(!config.deterministic);
+        assert_eq!(config.max_time_factorize, 0);
+        assert_eq!(config.max_time_solve, 0);
     }
 
     #[test]
@@ -147,14 +327,14 @@ mod tests {
     fn set_ordering_works() {
         let mut config = ConfigSolver::new();
         config.ordering(Ordering::Metis);
-        assert_eq!(config.ordering, Ordering::Metis as i32);
+        assert_eq!(config.ordering, Ordering::Metis);
     }
 
     #[test]
     fn set_scaling_works() {
         let mut config = ConfigSolver::new();
         config.scaling(Scaling::No);
-        assert_eq!(config.scaling, Scaling::No as i32);
+        assert_eq!(config.scaling, Scaling::No);
     }
 
     #[test]
@@ -178,11 +358,85 @@ mod tests {
         assert_eq!(config.openmp_num_threads, 2);
     }
 
+    #[test]
+    fn set_blas_num_threads_works() {
+        let mut config = ConfigSolver::new();
+        config.blas_num_threads(3);
+        assert_eq!(config.blas_num_threads, 3);
+        assert_eq!(config.openmp_num_threads, 1);
+    }
+
+    #[test]
+    fn env_overrides_works() {
+        // the variables are given explicitly because the environment is shared by the test threads
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        let mut config = ConfigSolver::new();
+        config.openmp_num_threads(2).blas_num_threads(3);
+        config.overrides_from(env(&[])).unwrap();
+        assert_eq!((config.openmp_num_threads, config.blas_num_threads), (2, 3));
+        config.overrides_from(env(&[(ENV_OPENMP_NUM_THREADS, "4")])).unwrap();
+        assert_eq!((config.openmp_num_threads, config.blas_num_threads), (4, 3));
+        config.overrides_from(env(&[(ENV_BLAS_NUM_THREADS, " 1 ")])).unwrap();
+        assert_eq!((config.openmp_num_threads, config.blas_num_threads), (4, 1));
+        assert_eq!(
+            config.overrides_from(env(&[(ENV_BLAS_NUM_THREADS, "-1")])).err(),
+            Some("the number of threads given by the environment variable is invalid")
+        );
+    }
+
     #[test]
     fn set_verbose_works() {
         let mut config = ConfigSolver::new();
         config.verbose();
-        assert_eq!(config.verbose, 1);
+        assert!(config.verbose);
+    }
+
+    #[test]
+    fn set_equilibrate_works() {
+        let mut config = ConfigSolver::new();
+        config.equilibrate(true);
+        assert!(config.equilibrate);
+        config.equilibrate(false);
+        assert!(!config.equilibrate);
+    }
+
+    #[test]
+    fn set_mixed_precision_works() {
+        let mut config = ConfigSolver::new();
+        config.mixed_precision(true);
+        assert!(config.mixed_precision);
+        config.mixed_precision(false);
+        assert!(!config.mixed_precision);
+    }
+
+    #[test]
+    fn set_deterministic_works() {
+        let mut config = ConfigSolver::new();
+        config.openmp_num_threads(4).blas_num_threads(2);
+        assert_eq!(config.actual_openmp_num_threads(), 4);
+        assert_eq!(config.actual_blas_num_threads(), 2);
+        config.deterministic(true);
+        assert!(config.deterministic);
+        assert_eq!(config.actual_openmp_num_threads(), 1);
+        assert_eq!(config.actual_blas_num_threads(), 1);
+        config.deterministic(false);
+        assert!(!config.deterministic);
+        assert_eq!(config.actual_openmp_num_threads(), 4);
+        assert_eq!(config.actual_blas_num_threads(), 2);
+    }
+
+    #[test]
+    fn set_max_time_works() {
+        let mut config = ConfigSolver::new();
+        config.max_time_factorize(1000).max_time_solve(10);
+        assert_eq!(config.max_time_factorize, 1000);
+        assert_eq!(config.max_time_solve, 10);
     }
 
     #[test]
@@ -193,7 +447,8 @@ mod tests {
                               \x20\x20\x20\x20\"scaling\": \"Auto\",\n\
                               \x20\x20\x20\x20\"pctIncWorkspace\": 100,\n\
                               \x20\x20\x20\x20\"maxWorkMemory\": 0,\n\
-                              \x20\x20\x20\x20\"openmpNumThreads\": 1";
+                              \x20\x20\x20\x20\"openmpNumThreads\": 1,\n\
+                              \x20\x20\x20\x20\"blasNumThreads\": 0";
         assert_eq!(format!("{}", config1), correct1);
         let mut config2 = ConfigSolver::new();
         config2.lin_sol_kind(LinSolKind::Mmp);
@@ -203,15 +458,57 @@ mod tests {
              \x20\x20\x20\x20\"scaling\": \"Auto\",\n\
              \x20\x20\x20\x20\"pctIncWorkspace\": 100,\n\
              \x20\x20\x20\x20\"maxWorkMemory\": 0,\n\
-             \x20\x20\x20\x20\"openmpNumThreads\": 1"
+             \x20\x20\x20\x20\"openmpNumThreads\": 1,\n\
+             \x20\x20\x20\x20\"blasNumThreads\": 0"
         } else {
             "\x20\x20\x20\x20\"name\": \"MMP\",\n\
              \x20\x20\x20\x20\"ordering\": \"Auto\",\n\
              \x20\x20\x20\x20\"scaling\": \"Auto\",\n\
              \x20\x20\x20\x20\"pctIncWorkspace\": 100,\n\
              \x20\x20\x20\x20\"maxWorkMemory\": 0,\n\
-             \x20\x20\x20\x20\"openmpNumThreads\": 1"
+             \x20\x20\x20\x20\"openmpNumThreads\": 1,\n\
+             \x20\x20\x20\x20\"blasNumThreads\": 0"
         };
         assert_eq!(format!("{}", config2), correct2);
     }
+
+    #[test]
+    fn default_works() {
+        assert_eq!(ConfigSolver::default(), ConfigSolver::new());
+    }
+
+    #[test]
+    fn serialize_and_deserialize_work() {
+        let mut config = ConfigSolver::new();
+        config
+            .lin_sol_kind(LinSolKind::Mmp)
+            .ordering(Ordering::Metis)
+            .scaling(Scaling::RowCol)
+            .openmp_num_threads(4)
+            .equilibrate(true)
+            .mixed_precision(true)
+            .deterministic(true)
+            .max_time_factorize(60000);
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            "{\"linSolKind\":\"Mmp\",\"ordering\":\"Metis\",\"scaling\":\"RowCol\",\
+             \"pctIncWorkspace\":100,\"maxWorkMemory\":0,\"openmpNumThreads\":4,\
+             \"blasNumThreads\":0,\"verbose\":false,\"equilibrate\":true,\"mixedPrecision\":true,\
+             \"deterministic\":true,\"maxTimeFactorize\":60000,\"maxTimeSolve\":0}"
+        );
+        let from_json: ConfigSolver = serde_json::from_str(&json).unwrap();
+        assert_eq!(from_json, config);
+    }
+
+    #[test]
+    fn deserialize_uses_defaults_for_missing_fields() {
+        let config: ConfigSolver = serde_json::from_str("{\"ordering\":\"Amd\"}").unwrap();
+        let mut correct = ConfigSolver::new();
+        correct.ordering(Ordering::Amd);
+        assert_eq!(config, correct);
+        let config: ConfigSolver = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, ConfigSolver::new());
+        assert!(serde_json::from_str::<ConfigSolver>("{\"ordering\":\"Unknown\"}").is_err());
+    }
 }
@@ -0,0 +1,393 @@
+use super::{ConfigSolver, LinearOperator, Solver, SparseTriplet};
+use crate::StrError;
+use russell_lab::{vec_inner, vec_norm, DenseLu, Matrix, Norm, Vector};
+
+/// Holds the results of the power and inverse iterations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EigenIterationStats {
+    /// The eigenvalue estimate (Rayleigh quotient `λ = vᵀ ⋅ a ⋅ v` with `‖v‖₂ = 1`)
+    pub eigenvalue: f64,
+
+    /// Number of iterations performed
+    pub iterations: usize,
+
+    /// Euclidean norm of the eigen-residual `‖a ⋅ v - λ v‖₂`
+    pub residual_norm: f64,
+
+    /// Indicates whether `‖a ⋅ v - λ v‖₂ ≤ tolerance ⋅ |λ|` or not
+    pub converged: bool,
+}
+
+/// Computes the dominant eigenpair by the power method
+///
+/// Iterates `v ← a ⋅ v / ‖a ⋅ v‖₂` until the eigen-residual is small:
+///
+/// ```text
+/// ‖a ⋅ v - λ v‖₂ ≤ tolerance ⋅ |λ|    with    λ = vᵀ ⋅ a ⋅ v
+/// ```
+///
+/// The method converges to the eigenvalue with the largest magnitude (e.g., the spectral radius)
+/// if it is real and separated from the others; the rate is `|λ₂ / λ₁|`.
+///
+/// # Input
+///
+/// * `v` -- on input, the initial guess (nonzero); on output, the normalized eigenvector
+/// * `a` -- the matrix or matrix-free operator (square; see [LinearOperator])
+/// * `tolerance` -- the relative tolerance on the eigen-residual
+/// * `max_iterations` -- the maximum number of iterations (matrix-vector products)
+///
+/// **Note:** No error is returned if the tolerance is not reached; check [EigenIterationStats::converged] instead.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_sparse::{power_method, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [2.0, 1.0],
+///         [1.0, 2.0],
+///     ]);
+///     let mut v = Vector::from(&[1.0, 0.0]);
+///     let stats = power_method(&mut v, &a, 1e-10, 100)?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.8}", stats.eigenvalue), "3.00000000");
+///     assert_eq!(format!("{:.6}", v[0] / v[1]), "1.000000");
+///     Ok(())
+/// }
+/// ```
+pub fn power_method(
+    v: &mut Vector,
+    a: &dyn LinearOperator,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<EigenIterationStats, StrError> {
+    let n = check_input(v, a)?;
+    let mut w = Vector::new(n);
+    a.apply(&mut w, v)?;
+    let mut stats = rayleigh_quotient(v, &w, 0, tolerance);
+    for iteration in 1..=max_iterations {
+        if stats.converged {
+            break;
+        }
+        let norm_w = vec_norm(&w, Norm::Euc);
+        if norm_w == 0.0 {
+            break; // a ⋅ v = 0 (converged with λ = 0)
+        }
+        for i in 0..n {
+            v[i] = w[i] / norm_w;
+        }
+        a.apply(&mut w, v)?;
+        stats = rayleigh_quotient(v, &w, iteration, tolerance);
+    }
+    Ok(stats)
+}
+
+/// Computes the eigenpair closest to a shift by the inverse iteration (shift-invert) with a sparse matrix
+///
+/// Iterates:
+///
+/// ```text
+/// (a - σ I) ⋅ w = v    then    v ← w / ‖w‖₂
+/// ```
+///
+/// which is the power method applied to `(a - σ I)⁻¹`; thus, it converges to the eigenvalue
+/// closest to the shift `σ` with the rate `|λ₁ - σ| / |λ₂ - σ|`. The shifted matrix is
+/// factorized once by [Solver] and the stopping criterion is the same as in [power_method].
+///
+/// # Input
+///
+/// * `v` -- on input, the initial guess (nonzero); on output, the normalized eigenvector
+/// * `a` -- the sparse matrix
+/// * `shift` -- the shift `σ` (e.g., `0.0` for the eigenvalue with the smallest magnitude)
+/// * `tolerance` -- the relative tolerance on the eigen-residual
+/// * `max_iterations` -- the maximum number of iterations (linear solutions)
+/// * `config` -- the configuration of the linear solver
+///
+/// **Note:** The shift must not be an eigenvalue (otherwise the factorization fails).
+pub fn inverse_iteration(
+    v: &mut Vector,
+    a: &SparseTriplet,
+    shift: f64,
+    tolerance: f64,
+    max_iterations: usize,
+    config: ConfigSolver,
+) -> Result<EigenIterationStats, StrError> {
+    let n = check_input(v, a)?;
+    let mut shifted = SparseTriplet::new(n, a.pos + n, a.symmetry)?;
+    for p in 0..a.pos {
+        shifted.put(a.indices_i[p] as usize, a.indices_j[p] as usize, a.values_aij[p])?;
+    }
+    for i in 0..n {
+        shifted.put(i, i, -shift)?;
+    }
+    let mut solver = Solver::new(config, n, shifted.pos, None)?;
+    solver.factorize(&shifted)?;
+    shifted_inverse_iteration(v, a, tolerance, max_iterations, |w, v| Ok(solver.solve(w, v)?))
+}
+
+/// Computes the eigenpair closest to a shift by the inverse iteration (shift-invert) with a dense matrix
+///
+/// This function is the same as [inverse_iteration]; however, the shifted matrix is factorized by [DenseLu].
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_sparse::{inverse_iteration_dense, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[
+///         [2.0, 0.0, 0.0],
+///         [0.0, 5.0, 1.0],
+///         [0.0, 1.0, 5.0],
+///     ]);
+///     let mut v = Vector::from(&[1.0, 1.0, 0.0]);
+///     let stats = inverse_iteration_dense(&mut v, &a, 3.5, 1e-10, 100)?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.8}", stats.eigenvalue), "4.00000000");
+///     Ok(())
+/// }
+/// ```
+pub fn inverse_iteration_dense(
+    v: &mut Vector,
+    a: &Matrix,
+    shift: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<EigenIterationStats, StrError> {
+    let n = check_input(v, a)?;
+    let mut shifted = a.clone();
+    for i in 0..n {
+        shifted.add(i, i, -shift);
+    }
+    let lu = DenseLu::new(&shifted)?;
+    shifted_inverse_iteration(v, a, tolerance, max_iterations, |w, v| Ok(lu.solve(w, v)?))
+}
+
+/// Checks the dimensions and normalizes the initial vector; returns the dimension
+fn check_input(v: &mut Vector, a: &dyn LinearOperator) -> Result<usize, StrError> {
+    let (n, ncol) = a.dims();
+    if ncol != n {
+        return Err("the operator must be square");
+    }
+    if v.dim() != n {
+        return Err("v vector is incompatible with the operator");
+    }
+    let norm_v = vec_norm(v, Norm::Euc);
+    if norm_v == 0.0 {
+        return Err("the initial vector must not be zero");
+    }
+    for i in 0..n {
+        v[i] /= norm_v;
+    }
+    Ok(n)
+}
+
+/// Computes λ = vᵀ ⋅ w and the eigen-residual ‖w - λ v‖₂ with w = a ⋅ v and ‖v‖₂ = 1
+fn rayleigh_quotient(v: &Vector, w: &Vector, iterations: usize, tolerance: f64) -> EigenIterationStats {
+    let eigenvalue = vec_inner(v, w);
+    let mut sum = 0.0;
+    for i in 0..v.dim() {
+        let d = w[i] - eigenvalue * v[i];
+        sum += d * d;
+    }
+    let residual_norm = f64::sqrt(sum);
+    EigenIterationStats {
+        eigenvalue,
+        iterations,
+        residual_norm,
+        converged: residual_norm <= tolerance * f64::abs(eigenvalue),
+    }
+}
+
+/// Runs the inverse iteration given the solver of the shifted system
+///
+/// The eigenvalue and the residual are computed with the original operator.
+fn shifted_inverse_iteration<F>(
+    v: &mut Vector,
+    a: &dyn LinearOperator,
+    tolerance: f64,
+    max_iterations: usize,
+    mut solve_shifted: F,
+) -> Result<EigenIterationStats, StrError>
+where
+    F: FnMut(&mut Vector, &Vector) -> Result<(), StrError>,
+{
+    let n = v.dim();
+    let mut w = Vector::new(n);
+    a.apply(&mut w, v)?;
+    let mut stats = rayleigh_quotient(v, &w, 0, tolerance);
+    for iteration in 1..=max_iterations {
+        if stats.converged {
+            break;
+        }
+        solve_shifted(&mut w, v)?;
+        let norm_w = vec_norm(&w, Norm::Euc);
+        if norm_w == 0.0 {
+            return Err("the solution of the shifted system is zero");
+        }
+        for i in 0..n {
+            v[i] = w[i] / norm_w;
+        }
+        a.apply(&mut w, v)?;
+        stats = rayleigh_quotient(v, &w, iteration, tolerance);
+    }
+    Ok(stats)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        inverse_iteration, inverse_iteration_dense, power_method, rayleigh_quotient, shifted_inverse_iteration,
+    };
+    use crate::{ConfigSolver, LinearOperatorFn, SparseTriplet, TripletSymmetry};
+    use russell_lab::{vec_norm, Matrix, Norm, Vector};
+
+    // 1D Laplacian with eigenvalues λₖ = 2 - 2 cos(k π / (n + 1)), k = 1, …, n
+    fn laplacian_1d(n: usize) -> SparseTriplet {
+        let mut trip = SparseTriplet::new(n, 2 * n - 1, TripletSymmetry::LowerTriangular).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 2.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -1.0).unwrap();
+            }
+        }
+        trip
+    }
+
+    fn laplacian_eigenvalue(n: usize, k: usize) -> f64 {
+        2.0 - 2.0 * f64::cos((k as f64) * std::f64::consts::PI / ((n + 1) as f64))
+    }
+
+    #[test]
+    fn power_method_captures_errors() {
+        let a = Matrix::new(2, 3);
+        let mut v = Vector::new(2);
+        assert_eq!(
+            power_method(&mut v, &a, 1e-8, 10).err(),
+            Some("the operator must be square")
+        );
+        let a = Matrix::new(2, 2);
+        let mut v = Vector::new(3);
+        assert_eq!(
+            power_method(&mut v, &a, 1e-8, 10).err(),
+            Some("v vector is incompatible with the operator")
+        );
+        let mut v = Vector::new(2);
+        assert_eq!(
+            power_method(&mut v, &a, 1e-8, 10).err(),
+            Some("the initial vector must not be zero")
+        );
+    }
+
+    #[test]
+    fn rayleigh_quotient_works() {
+        let v = Vector::from(&[0.6, 0.8]);
+        let stats = rayleigh_quotient(&v, &Vector::from(&[1.2, 1.6]), 3, 1e-14);
+        assert_eq!(stats.eigenvalue, 2.0);
+        assert_eq!(stats.iterations, 3);
+        assert_eq!(stats.residual_norm, 0.0);
+        assert!(stats.converged);
+        let stats = rayleigh_quotient(&v, &Vector::from(&[0.0, 1.0]), 0, 1e-14);
+        assert_eq!(stats.eigenvalue, 0.8);
+        assert!(!stats.converged);
+    }
+
+    #[test]
+    fn power_method_works() {
+        // spectral radius of the 1D Laplacian
+        let n = 10;
+        let a = laplacian_1d(n);
+        let mut v = Vector::from(&[1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        let stats = power_method(&mut v, &a, 1e-8, 5000).unwrap();
+        assert!(stats.converged);
+        assert!(f64::abs(stats.eigenvalue - laplacian_eigenvalue(n, n)) < 1e-10);
+        assert!(f64::abs(vec_norm(&v, Norm::Euc) - 1.0) < 1e-14);
+
+        // negative dominant eigenvalue with a matrix-free operator
+        let op = LinearOperatorFn::new(2, 2, |y, x| {
+            y[0] = -3.0 * x[0];
+            y[1] = x[1];
+            Ok(())
+        });
+        let mut v = Vector::from(&[1.0, 1.0]);
+        let stats = power_method(&mut v, &op, 1e-12, 100).unwrap();
+        assert!(stats.converged);
+        assert!(f64::abs(stats.eigenvalue + 3.0) < 1e-12);
+
+        // no convergence
+        let mut v = Vector::from(&[1.0, 1.0]);
+        let stats = power_method(&mut v, &op, 1e-12, 2).unwrap();
+        assert!(!stats.converged);
+        assert_eq!(stats.iterations, 2);
+
+        // nilpotent matrix
+        let a = Matrix::from(&[[0.0, 1.0], [0.0, 0.0]]);
+        let mut v = Vector::from(&[1.0, 0.0]);
+        let stats = power_method(&mut v, &a, 1e-12, 100).unwrap();
+        assert_eq!(stats.eigenvalue, 0.0);
+        assert_eq!(stats.iterations, 0);
+    }
+
+    #[test]
+    fn shifted_inverse_iteration_works() {
+        // a = diag(1, 2, 4) with σ = 1.8 and the exact solution of the shifted system
+        let a = Matrix::diagonal(&[1.0, 2.0, 4.0]);
+        let shift = 1.8;
+        let mut v = Vector::from(&[1.0, 1.0, 1.0]);
+        let stats = shifted_inverse_iteration(&mut v, &a, 1e-12, 100, |w, v| {
+            for i in 0..3 {
+                w[i] = v[i] / (a.get(i, i) - shift);
+            }
+            Ok(())
+        })
+        .unwrap();
+        assert!(stats.converged);
+        assert!(f64::abs(stats.eigenvalue - 2.0) < 1e-14);
+        assert!(f64::abs(f64::abs(v[1]) - 1.0) < 1e-12);
+        let mut v = Vector::from(&[1.0, 1.0, 1.0]);
+        assert_eq!(
+            shifted_inverse_iteration(&mut v, &a, 1e-12, 100, |w, _| {
+                w.fill(0.0);
+                Ok(())
+            })
+            .err(),
+            Some("the solution of the shifted system is zero")
+        );
+    }
+
+    #[test]
+    fn inverse_iteration_works() {
+        // smallest eigenvalue of the 1D Laplacian
+        let n = 10;
+        let a = laplacian_1d(n);
+        let mut v = Vector::filled(n, 1.0);
+        let stats = inverse_iteration(&mut v, &a, 0.0, 1e-10, 100, ConfigSolver::new()).unwrap();
+        assert!(stats.converged);
+        assert!(f64::abs(stats.eigenvalue - laplacian_eigenvalue(n, 1)) < 1e-12);
+
+        // eigenvalue closest to the shift
+        let mut v = Vector::filled(n, 1.0);
+        v[0] = 2.0;
+        let target = laplacian_eigenvalue(n, 4);
+        let stats = inverse_iteration(&mut v, &a, target + 0.01, 1e-10, 100, ConfigSolver::new()).unwrap();
+        assert!(stats.converged);
+        assert!(f64::abs(stats.eigenvalue - target) < 1e-12);
+    }
+
+    #[test]
+    fn inverse_iteration_dense_works() {
+        let n = 10;
+        let a = laplacian_1d(n).as_matrix();
+        let mut v = Vector::filled(n, 1.0);
+        v[0] = 2.0;
+        let target = laplacian_eigenvalue(n, 7);
+        let stats = inverse_iteration_dense(&mut v, &a, target - 0.01, 1e-10, 100).unwrap();
+        assert!(stats.converged);
+        assert!(f64::abs(stats.eigenvalue - target) < 1e-12);
+    }
+}
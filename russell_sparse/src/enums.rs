@@ -1,4 +1,5 @@
 use crate::StrError;
+use serde::{Deserialize, Serialize};
 
 /// Matrix symmetry option
 #[derive(Clone, Copy, Debug)]
@@ -14,8 +15,38 @@ pub enum Symmetry {
     PosDef,
 }
 
+/// Symmetry (and storage scheme) of the matrix represented by a SparseTriplet
+///
+/// Each solver backend requires a specific storage scheme; e.g., UMFPACK requires
+/// the full matrix, whereas Mu-M-P-S requires only the lower triangle of symmetric
+/// matrices. With this option, the Solver mirrors or restricts the entries as required.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TripletSymmetry {
+    /// The matrix is not symmetric; all entries are stored
+    None,
+
+    /// The matrix is symmetric and all entries (both off-diagonal sides) are stored
+    GeneralFull,
+
+    /// The matrix is symmetric and only the lower triangle (including the diagonal) is stored
+    ///
+    /// **Note:** Upper triangular (i < j) entries given to `put` are moved to the lower triangle.
+    LowerTriangular,
+}
+
+impl TripletSymmetry {
+    /// Returns true if the matrix is symmetric
+    pub fn is_symmetric(&self) -> bool {
+        match self {
+            TripletSymmetry::None => false,
+            TripletSymmetry::GeneralFull => true,
+            TripletSymmetry::LowerTriangular => true,
+        }
+    }
+}
+
 /// Linear solver kind
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum LinSolKind {
     /// The NON-THREAD-SAFE (Mu-M-P) Solver (use in single-thread apps / with huge matrices)
     Mmp,
@@ -25,7 +56,7 @@ pub enum LinSolKind {
 }
 
 /// Ordering option
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Ordering {
     /// Ordering using the approximate minimum degree
     Amd = 0,
@@ -59,7 +90,7 @@ pub enum Ordering {
 }
 
 /// Scaling option
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Scaling {
     /// Automatic scaling method selection
     Auto = 0,
@@ -89,6 +120,41 @@ pub enum Scaling {
     Sum = 8,
 }
 
+/// Phase of the sparse Solver reported to the progress callback
+///
+/// **Note:** The analysis (ordering and symbolic factorization) is performed within [SolverPhase::Factorize].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolverPhase {
+    /// Analysis and numerical factorization (see `Solver::factorize`)
+    Factorize,
+
+    /// Forward and backward substitutions (see `Solver::solve`)
+    Solve,
+}
+
+/// Progress event reported by the sparse Solver to the progress callback
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolverEvent<'a> {
+    /// The phase has started
+    Started(SolverPhase),
+
+    /// The phase has finished successfully; holds the elapsed time in nanoseconds
+    Finished(SolverPhase, u128),
+
+    /// Diagnostic message issued during the phase (e.g., the used ordering or an error message)
+    Diagnostic(SolverPhase, &'a str),
+}
+
+/// Globalization strategy of the Newton solver (see [crate::SolverNewton])
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Globalization {
+    /// Full (projected) Newton steps
+    None,
+
+    /// Dogleg trust-region steps (the radius is adapted according to the reduction of the residual)
+    TrustRegion,
+}
+
 /// Returns the Ordering by name
 pub fn enum_ordering(ordering: &str) -> Ordering {
     match ordering {
@@ -229,38 +295,50 @@ pub(crate) fn str_umf_scaling(umf_code: i32) -> &'static str {
 mod tests {
     use super::{
         code_symmetry_mmp, code_symmetry_umf, enum_ordering, enum_scaling, str_enum_ordering, str_enum_scaling,
-        str_mmp_ordering, str_mmp_scaling, str_umf_ordering, str_umf_scaling, LinSolKind, Ordering, Scaling, Symmetry,
+        str_mmp_ordering, str_mmp_scaling, str_umf_ordering, str_umf_scaling, LinSolKind, Ordering, Scaling,
+        SolverEvent, SolverPhase, Symmetry, TripletSymmetry,
     };
 
     #[test]
-    fn clone_copy_and_debug_work() {
+    fn copy_and_debug_work() {
         let symmetry = Symmetry::General;
         let copy = symmetry;
-        let clone = symmetry.clone();
         assert_eq!(format!("{:?}", symmetry), "General");
         assert_eq!(format!("{:?}", copy), "General");
-        assert_eq!(format!("{:?}", clone), "General");
+
+        let triplet_symmetry = TripletSymmetry::LowerTriangular;
+        let copy = triplet_symmetry;
+        assert_eq!(format!("{:?}", triplet_symmetry), "LowerTriangular");
+        assert_eq!(copy, TripletSymmetry::LowerTriangular);
 
         let lin_sol_kind = LinSolKind::Mmp;
         let copy = lin_sol_kind;
-        let clone = lin_sol_kind.clone();
         assert_eq!(format!("{:?}", lin_sol_kind), "Mmp");
         assert_eq!(format!("{:?}", copy), "Mmp");
-        assert_eq!(format!("{:?}", clone), "Mmp");
 
         let ordering = Ordering::Amd;
         let copy = ordering;
-        let clone = ordering.clone();
         assert_eq!(format!("{:?}", ordering), "Amd");
         assert_eq!(format!("{:?}", copy), "Amd");
-        assert_eq!(format!("{:?}", clone), "Amd");
 
         let scaling = Scaling::Column;
         let copy = scaling;
-        let clone = scaling.clone();
         assert_eq!(format!("{:?}", scaling), "Column");
         assert_eq!(format!("{:?}", copy), "Column");
-        assert_eq!(format!("{:?}", clone), "Column");
+
+        let phase = SolverPhase::Factorize;
+        let copy = phase;
+        assert_eq!(format!("{:?}", phase), "Factorize");
+        assert_eq!(copy, SolverPhase::Factorize);
+
+        let event = SolverEvent::Diagnostic(SolverPhase::Solve, "hello");
+        let copy = event;
+        assert_eq!(format!("{:?}", event), "Diagnostic(Solve, \"hello\")");
+        assert_eq!(copy, SolverEvent::Diagnostic(SolverPhase::Solve, "hello"));
+        assert_eq!(
+            format!("{:?}", SolverEvent::Finished(SolverPhase::Factorize, 123)),
+            "Finished(Factorize, 123)"
+        );
     }
 
     #[test]
@@ -292,6 +370,13 @@ mod tests {
         assert!(matches!(enum_scaling("Unknown"), Scaling::Auto));
     }
 
+    #[test]
+    fn triplet_symmetry_is_symmetric_works() {
+        assert!(!TripletSymmetry::None.is_symmetric());
+        assert!(TripletSymmetry::GeneralFull.is_symmetric());
+        assert!(TripletSymmetry::LowerTriangular.is_symmetric());
+    }
+
     #[test]
     fn code_symmetry_works() {
         // mmp
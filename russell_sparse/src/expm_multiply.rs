@@ -0,0 +1,307 @@
+use super::LinearOperator;
+use crate::StrError;
+use russell_lab::{mat_mat_mul, mat_norm, vec_inner, vec_norm, Matrix, Norm, Vector};
+
+/// Defines the maximum dimension of the Krylov subspace
+const KRYLOV_DIM: usize = 30;
+
+/// Defines the relative tolerance on the (estimated) error of each sub-step
+const TOLERANCE: f64 = 1e-12;
+
+/// Defines the maximum number of times the sub-step is halved before giving up
+const MAX_HALVINGS: usize = 60;
+
+/// Defines the number of terms of the Taylor series of the (scaled) dense exponential
+const TAYLOR_TERMS: usize = 18;
+
+/// Computes the action of the matrix exponential on a vector
+///
+/// Computes:
+///
+/// ```text
+/// w = exp(t a) ⋅ v
+/// ```
+///
+/// without forming `exp(t a)`; thus, `w(t)` is the solution of the linear system of ODEs `dw/dt = a ⋅ w`
+/// with `w(0) = v`. The Arnoldi process builds an orthonormal basis `Vₘ` of the Krylov subspace
+/// `span{v, a v, …, aᵐ⁻¹ v}` and the Hessenberg matrix `Hₘ = Vₘᵀ ⋅ a ⋅ Vₘ`, with m ≤ 30, such that:
+///
+/// ```text
+/// exp(τ a) ⋅ v ≈ β Vₘ ⋅ exp(τ Hₘ) ⋅ e₀    with    β = ‖v‖₂
+/// ```
+///
+/// The exponential of the small matrix `τ Hₘ` is computed by scaling and squaring. The time interval
+/// is split into sub-steps `τ` such that the estimated error of each sub-step is smaller than 10⁻¹²
+/// relative to `β`; hence, stiff operators (e.g., the semi-discretization of a diffusion problem)
+/// are handled by taking more (restarted) sub-steps.
+///
+/// # Input
+///
+/// * `op` -- the matrix or matrix-free operator (square; see [LinearOperator])
+/// * `t` -- the time (may be negative)
+/// * `v` -- the vector
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_sparse::{expm_multiply, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // rotation: exp(t a) = [[cos(t), sin(t)], [-sin(t), cos(t)]]
+///     let a = Matrix::from(&[
+///         [ 0.0, 1.0],
+///         [-1.0, 0.0],
+///     ]);
+///     let v = Vector::from(&[1.0, 0.0]);
+///     let w = expm_multiply(&a, std::f64::consts::PI / 2.0, &v)?;
+///     assert_eq!(format!("{:.6}", w[0]), "0.000000");
+///     assert_eq!(format!("{:.6}", w[1]), "-1.000000");
+///     Ok(())
+/// }
+/// ```
+pub fn expm_multiply(op: &dyn LinearOperator, t: f64, v: &Vector) -> Result<Vector, StrError> {
+    let (m, n) = op.dims();
+    if m != n {
+        return Err("the operator must be square");
+    }
+    if v.dim() != n {
+        return Err("v vector is incompatible with the operator");
+    }
+    if !t.is_finite() {
+        return Err("the time t must be finite");
+    }
+    let mut w = v.clone();
+    if n == 0 || t == 0.0 {
+        return Ok(w);
+    }
+
+    // Krylov basis and Hessenberg matrix
+    let kdim = usize::min(KRYLOV_DIM, n);
+    let mut basis = vec![Vector::new(n); kdim + 1];
+    let mut h = Matrix::new(kdim + 1, kdim);
+    let mut z = Vector::new(n);
+
+    // the sub-steps are taken in the direction of t
+    let (sign, t_end) = (f64::signum(t), f64::abs(t));
+    let mut t_now = 0.0;
+    let mut tau_trial = t_end;
+    while t_now < t_end {
+        let beta = vec_norm(&w, Norm::Euc);
+        if beta == 0.0 {
+            break;
+        }
+
+        // Arnoldi process with modified Gram-Schmidt
+        for i in 0..n {
+            basis[0][i] = w[i] / beta;
+        }
+        h.fill(0.0);
+        let mut k_used = kdim;
+        let mut breakdown = false;
+        let mut h_norm: f64 = 0.0;
+        for k in 0..kdim {
+            op.apply(&mut z, &basis[k])?;
+            let mut col_norm = 0.0;
+            for (i, b) in basis.iter().enumerate().take(k + 1) {
+                let hik = vec_inner(&z, b);
+                h.set(i, k, hik);
+                col_norm += f64::abs(hik);
+                for l in 0..n {
+                    z[l] -= hik * b[l];
+                }
+            }
+            let norm_z = vec_norm(&z, Norm::Euc);
+            h_norm = f64::max(h_norm, col_norm + norm_z);
+            if norm_z <= f64::EPSILON * h_norm {
+                // happy breakdown: the Krylov subspace is invariant and the approximation is exact
+                k_used = k + 1;
+                breakdown = true;
+                break;
+            }
+            h.set(k + 1, k, norm_z);
+            for l in 0..n {
+                basis[k + 1][l] = z[l] / norm_z;
+            }
+        }
+
+        // find the sub-step by halving the trial step until the error estimate is acceptable
+        // (the last entry of the first column of the exponential of the augmented Hessenberg
+        // matrix holds the error estimate τ hₘ,ₘ₋₁ eₘ₋₁ᵀ φ₁(τ Hₘ) e₀)
+        let mut tau = f64::min(tau_trial, t_end - t_now);
+        let mut halvings = 0;
+        let e = loop {
+            let dim = if breakdown { k_used } else { k_used + 1 };
+            let mut th = Matrix::new(dim, dim);
+            for j in 0..k_used {
+                for i in 0..usize::min(j + 2, dim) {
+                    th.set(i, j, sign * tau * h.get(i, j));
+                }
+            }
+            let e = exp_dense(&th)?;
+            if breakdown || beta * f64::abs(e.get(k_used, 0)) <= TOLERANCE * beta {
+                break e;
+            }
+            halvings += 1;
+            if halvings > MAX_HALVINGS {
+                return Err("the Krylov approximation of the exponential did not converge");
+            }
+            tau /= 2.0;
+        };
+
+        // update the solution
+        w.fill(0.0);
+        for (j, b) in basis.iter().enumerate().take(k_used) {
+            let c = beta * e.get(j, 0);
+            for l in 0..n {
+                w[l] += c * b[l];
+            }
+        }
+        if breakdown {
+            break;
+        }
+        t_now += tau;
+        tau_trial = 2.0 * tau;
+    }
+    Ok(w)
+}
+
+/// Computes the exponential of a small dense matrix by scaling and squaring
+///
+/// The matrix is scaled by 2⁻ˢ such that its 1-norm is smaller than ½, the Taylor series is
+/// truncated, and the result is squared s times.
+fn exp_dense(x: &Matrix) -> Result<Matrix, StrError> {
+    let n = x.nrow();
+    let norm = mat_norm(x, Norm::One);
+    let s = if norm > 0.5 {
+        f64::ceil(f64::log2(norm / 0.5)) as i32
+    } else {
+        0
+    };
+    let scale = f64::powi(2.0, -s);
+    let mut e = Matrix::identity(n);
+    let mut term = Matrix::identity(n);
+    let mut next = Matrix::new(n, n);
+    for k in 1..=TAYLOR_TERMS {
+        mat_mat_mul(&mut next, scale / (k as f64), &term, x)?;
+        for (ei, ti) in e.as_mut_data().iter_mut().zip(next.as_data()) {
+            *ei += ti;
+        }
+        std::mem::swap(&mut term, &mut next);
+    }
+    for _ in 0..s {
+        mat_mat_mul(&mut next, 1.0, &e, &e)?;
+        std::mem::swap(&mut e, &mut next);
+    }
+    Ok(e)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{exp_dense, expm_multiply};
+    use crate::{LinearOperatorFn, SparseCsr, SparseTriplet, TripletSymmetry};
+    use russell_chk::vec_approx_eq;
+    use russell_lab::{mat_approx_eq, Matrix, Vector};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn expm_multiply_captures_errors() {
+        let a = Matrix::new(2, 3);
+        let v = Vector::new(3);
+        assert_eq!(expm_multiply(&a, 1.0, &v).err(), Some("the operator must be square"));
+        let a = Matrix::new(2, 2);
+        assert_eq!(
+            expm_multiply(&a, 1.0, &v).err(),
+            Some("v vector is incompatible with the operator")
+        );
+        let v = Vector::new(2);
+        assert_eq!(
+            expm_multiply(&a, f64::INFINITY, &v).err(),
+            Some("the time t must be finite")
+        );
+        let failing = LinearOperatorFn::new(2, 2, |_, _| Err("stop"));
+        assert_eq!(
+            expm_multiply(&failing, 1.0, &Vector::from(&[1.0, 0.0])).err(),
+            Some("stop")
+        );
+    }
+
+    #[test]
+    fn exp_dense_works() {
+        let x = Matrix::new(2, 2);
+        mat_approx_eq(&exp_dense(&x).unwrap(), &Matrix::identity(2), 1e-15);
+        let x = Matrix::from(&[[0.0, 3.0], [-3.0, 0.0]]);
+        let (c, s) = (f64::cos(3.0), f64::sin(3.0));
+        mat_approx_eq(&exp_dense(&x).unwrap(), &[[c, s], [-s, c]], 1e-14);
+        let x = Matrix::from(&[[-50.0, 0.0], [0.0, 2.0]]);
+        mat_approx_eq(
+            &exp_dense(&x).unwrap(),
+            &[[f64::exp(-50.0), 0.0], [0.0, f64::exp(2.0)]],
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn expm_multiply_works() {
+        // trivial cases
+        let a = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        let v = Vector::from(&[1.0, -1.0]);
+        assert_eq!(expm_multiply(&a, 0.0, &v).unwrap().as_data(), &[1.0, -1.0]);
+        assert_eq!(expm_multiply(&a, 1.0, &Vector::new(2)).unwrap().as_data(), &[0.0, 0.0]);
+        assert_eq!(
+            expm_multiply(&Matrix::new(0, 0), 1.0, &Vector::new(0)).unwrap().dim(),
+            0
+        );
+        let zero = Matrix::new(2, 2);
+        assert_eq!(expm_multiply(&zero, 1.0, &v).unwrap().as_data(), &[1.0, -1.0]);
+
+        // diagonal (happy breakdown) and negative time
+        let a = Matrix::from(&[[-1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 0.5]]);
+        let v = Vector::from(&[1.0, 2.0, 3.0]);
+        let w = expm_multiply(&a, -1.5, &v).unwrap();
+        let correct = &[f64::exp(1.5), 2.0 * f64::exp(-3.0), 3.0 * f64::exp(-0.75)];
+        vec_approx_eq(w.as_data(), correct, 1e-13);
+
+        // rotation
+        let a = Matrix::from(&[[0.0, 1.0], [-1.0, 0.0]]);
+        let w = expm_multiply(&a, 10.0 * PI + 1.0, &Vector::from(&[1.0, 0.0])).unwrap();
+        vec_approx_eq(w.as_data(), &[f64::cos(1.0), -f64::sin(1.0)], 1e-12);
+    }
+
+    #[test]
+    fn expm_multiply_works_for_stiff_diffusion() {
+        // semi-discretized heat equation du/dt = a ⋅ u with a = -(n+1)² tridiag(-1, 2, -1)
+        let n = 100;
+        let h2 = ((n + 1) * (n + 1)) as f64;
+        let mut trip = SparseTriplet::new(n, 3 * n, TripletSymmetry::None).unwrap();
+        for i in 0..n {
+            trip.put(i, i, -2.0 * h2).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, h2).unwrap();
+            }
+            if i + 1 < n {
+                trip.put(i, i + 1, h2).unwrap();
+            }
+        }
+        let csr = SparseCsr::from_triplet(&trip);
+
+        // the eigenvectors are sin(i k π / (n+1)) with eigenvalues -2 (n+1)² (1 - cos(k π / (n+1)));
+        // thus, the solution is obtained by expanding the (non-smooth) initial condition in eigenvectors
+        let v = Vector::filled(n, 1.0);
+        let t = 0.001;
+        let w = expm_multiply(&csr, t, &v).unwrap();
+        let mut correct = vec![0.0; n];
+        for k in 1..=n {
+            let theta = (k as f64) * PI / ((n + 1) as f64);
+            let lambda = -2.0 * h2 * (1.0 - f64::cos(theta));
+            let mode: Vec<_> = (0..n).map(|i| f64::sin(((i + 1) as f64) * theta)).collect();
+            let ck = 2.0 / ((n + 1) as f64) * mode.iter().sum::<f64>();
+            for i in 0..n {
+                correct[i] += ck * f64::exp(lambda * t) * mode[i];
+            }
+        }
+        vec_approx_eq(w.as_data(), &correct, 1e-10);
+    }
+}
@@ -0,0 +1,134 @@
+use russell_lab::Vector;
+
+/// Monitors the convergence of the iterative solvers
+///
+/// The monitor optionally records the history of residual norms and optionally calls an
+/// observer (closure) at each iteration. The observer receives the iteration number and
+/// the residual norm (iteration 0 corresponds to the initial residual) and returns `false`
+/// to stop the iterations early (e.g., to implement custom stopping logic).
+///
+/// **Note:** The monitor is reset at the beginning of each solution.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{solve_pcg_jacobi_monitored, IterativeMonitor};
+/// use russell_sparse::{SparseCsr, SparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular)?;
+///     trip.put(0, 0, 4.0)?;
+///     trip.put(1, 0, 1.0)?;
+///     trip.put(1, 1, 3.0)?;
+///     trip.put(2, 1, 1.0)?;
+///     trip.put(2, 2, 2.0)?;
+///     let csr = SparseCsr::from_triplet(&trip);
+///     let b = Vector::from(&[5.0, 5.0, 3.0]);
+///     let mut x = Vector::new(3);
+///     let mut count = 0;
+///     let mut monitor = IterativeMonitor::new();
+///     monitor.record_history(true).set_observer(|_, _| {
+///         count += 1;
+///         true
+///     });
+///     let stats = solve_pcg_jacobi_monitored(&mut x, &csr, &b, 1e-12, 10, &mut monitor)?;
+///     let history = monitor.get_history();
+///     assert!(stats.converged);
+///     assert_eq!(history.dim(), stats.iterations + 1);
+///     assert_eq!(history[stats.iterations], stats.residual_norm);
+///     drop(monitor);
+///     assert_eq!(count, stats.iterations + 1);
+///     Ok(())
+/// }
+/// ```
+pub struct IterativeMonitor<'a> {
+    record: bool,                                              // record the residual norms
+    history: Vec<f64>,                                         // residual norms (if recording)
+    observer: Option<Box<dyn FnMut(usize, f64) -> bool + 'a>>, // called at each iteration
+}
+
+impl<'a> IterativeMonitor<'a> {
+    /// Allocates a new instance (without recording and without observer)
+    pub fn new() -> Self {
+        IterativeMonitor {
+            record: false,
+            history: Vec::new(),
+            observer: None,
+        }
+    }
+
+    /// Enables or disables the recording of the residual norms
+    pub fn record_history(&mut self, flag: bool) -> &mut Self {
+        self.record = flag;
+        self
+    }
+
+    /// Sets the observer called at each iteration with `(iteration, residual_norm)`
+    ///
+    /// The observer must return `false` to stop the iterations.
+    pub fn set_observer<F>(&mut self, observer: F) -> &mut Self
+    where
+        F: FnMut(usize, f64) -> bool + 'a,
+    {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Returns the recorded residual norms (the first entry is the initial residual norm)
+    ///
+    /// **Note:** The history is empty if the recording is disabled.
+    pub fn get_history(&self) -> Vector {
+        Vector::from(&self.history)
+    }
+
+    /// Clears the history
+    pub(crate) fn reset(&mut self) {
+        self.history.clear();
+    }
+
+    /// Records the residual norm and calls the observer; returns `false` to stop the iterations
+    pub(crate) fn update(&mut self, iteration: usize, residual_norm: f64) -> bool {
+        if self.record {
+            self.history.push(residual_norm);
+        }
+        match self.observer.as_mut() {
+            Some(observer) => observer(iteration, residual_norm),
+            None => true,
+        }
+    }
+}
+
+impl<'a> Default for IterativeMonitor<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::IterativeMonitor;
+
+    #[test]
+    fn monitor_works() {
+        let mut monitor = IterativeMonitor::new();
+        assert!(monitor.update(0, 1.0));
+        assert_eq!(monitor.get_history().dim(), 0);
+
+        let mut calls = Vec::new();
+        let mut monitor = IterativeMonitor::default();
+        monitor.record_history(true).set_observer(|it, norm| {
+            calls.push((it, norm));
+            it < 1
+        });
+        assert!(monitor.update(0, 1.0));
+        assert!(!monitor.update(1, 0.5));
+        assert_eq!(monitor.get_history().as_data(), &[1.0, 0.5]);
+        monitor.reset();
+        assert_eq!(monitor.get_history().dim(), 0);
+        drop(monitor);
+        assert_eq!(calls, &[(0, 1.0), (1, 0.5)]);
+    }
+}
@@ -6,13 +6,13 @@
 //!
 //! ```
 //! use russell_lab::{Matrix, Vector};
-//! use russell_sparse::{ConfigSolver, Solver, SparseTriplet, StrError};
+//! use russell_sparse::{ConfigSolver, Solver, SparseTriplet, StrError, TripletSymmetry};
 //!
 //! fn main() -> Result<(), StrError> {
 //!
 //!     // allocate a square matrix
 //!     let (neq, nnz) = (5, 13);
-//!     let mut trip = SparseTriplet::new(neq, nnz)?;
+//!     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
 //!     trip.put(0, 0, 1.0)?; // << (0, 0, a00/2)
 //!     trip.put(0, 0, 1.0)?; // << (0, 0, a00/2)
 //!     trip.put(1, 0, 3.0)?;
@@ -63,19 +63,80 @@
 /// Defines a type alias for the error type as a static string
 pub type StrError = &'static str;
 
+/// Defines a typed error with context data (convertible from/into StrError)
+pub use russell_lab::RussellError;
+
+mod apply_poly;
+mod cancel_token;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod complex_sparse_triplet;
+mod config_iterative;
 mod config_solver;
+mod eigen_iteration;
 mod enums;
+mod expm_multiply;
+mod iterative_monitor;
+mod linear_operator;
+mod null_space;
+mod precond_schwarz;
+mod precond_spai;
+mod preconditioner;
 pub mod prelude;
+#[cfg(feature = "python")]
+pub mod python;
+mod randomized_svd;
 mod read_matrix_market;
+mod rect_sparse_triplet;
+mod solve_auto;
 mod solver;
+mod solver_arc_length;
+mod solver_bicgstab;
+mod solver_gmres;
+mod solver_jfnk;
+mod solver_lsqr;
+mod solver_newton;
+mod solver_pcg;
+mod sparse_approx_eq;
+mod sparse_csr;
+mod sparse_graph;
 mod sparse_triplet;
+mod triplet_shard;
 mod verify_lin_sys;
+mod write_matrix_market;
+pub use crate::apply_poly::*;
+pub use crate::cancel_token::*;
+pub use crate::complex_sparse_triplet::*;
+pub use crate::config_iterative::*;
 pub use crate::config_solver::*;
+pub use crate::eigen_iteration::*;
 pub use crate::enums::*;
+pub use crate::expm_multiply::*;
+pub use crate::iterative_monitor::*;
+pub use crate::linear_operator::*;
+pub use crate::null_space::*;
+pub use crate::precond_schwarz::*;
+pub use crate::precond_spai::*;
+pub use crate::preconditioner::*;
+pub use crate::randomized_svd::*;
 pub use crate::read_matrix_market::*;
+pub use crate::rect_sparse_triplet::*;
+pub use crate::solve_auto::*;
 pub use crate::solver::*;
+pub use crate::solver_arc_length::*;
+pub use crate::solver_bicgstab::*;
+pub use crate::solver_gmres::*;
+pub use crate::solver_jfnk::*;
+pub use crate::solver_lsqr::*;
+pub use crate::solver_newton::*;
+pub use crate::solver_pcg::*;
+pub use crate::sparse_approx_eq::*;
+pub use crate::sparse_csr::*;
+pub use crate::sparse_graph::*;
 pub use crate::sparse_triplet::*;
+pub use crate::triplet_shard::*;
 pub use crate::verify_lin_sys::*;
+pub use crate::write_matrix_market::*;
 
 // run code from README file
 #[cfg(doctest)]
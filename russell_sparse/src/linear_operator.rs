@@ -0,0 +1,367 @@
+use super::{RectSparseTriplet, SparseCsr, SparseTriplet, TripletSymmetry};
+use crate::StrError;
+use russell_lab::{mat_vec_mul, vec_mat_mul, Matrix, Vector};
+
+/// Defines a linear operator `a : ℝⁿ → ℝᵐ` for the iterative solvers
+///
+/// The iterative solvers (e.g., [crate::solve_pcg], [crate::solve_gmres]) only require the
+/// matrix-vector product; thus, the system may be defined without storing the matrix
+/// (e.g., by a closure; see [LinearOperatorFn]). [SparseCsr], [SparseTriplet], [RectSparseTriplet],
+/// and [Matrix] implement this trait. Rectangular operators may be used with [crate::solve_lsqr].
+pub trait LinearOperator {
+    /// Returns the dimensions `(m, n)` of the operator
+    fn dims(&self) -> (usize, usize);
+
+    /// Computes `y = a ⋅ x` with `x: (n)` and `y: (m)`
+    fn apply(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError>;
+
+    /// Computes `y = aᵀ ⋅ x` with `x: (m)` and `y: (n)`
+    ///
+    /// **Note:** The default implementation returns an error.
+    fn apply_transpose(&self, _y: &mut Vector, _x: &Vector) -> Result<(), StrError> {
+        Err("the transpose of the operator is not available")
+    }
+}
+
+/// Defines the type of the closures computing `y = a ⋅ x` (see [LinearOperatorFn])
+type ApplyFn<'a> = Box<dyn Fn(&mut Vector, &Vector) -> Result<(), StrError> + 'a>;
+
+/// Implements a (matrix-free) linear operator defined by closures
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{solve_gmres, ConfigIterative, IterativeMonitor, LinearOperatorFn, PrecondIdentity, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // 1D Laplacian without assembling the matrix
+///     let n = 5;
+///     let laplacian = LinearOperatorFn::new(n, n, |y, x| {
+///         for i in 0..n {
+///             y[i] = 2.0 * x[i];
+///             if i > 0 {
+///                 y[i] -= x[i - 1];
+///             }
+///             if i + 1 < n {
+///                 y[i] -= x[i + 1];
+///             }
+///         }
+///         Ok(())
+///     });
+///     let b = Vector::from(&[1.0, 0.0, 0.0, 0.0, 1.0]);
+///     let mut x = Vector::new(n);
+///     let config = *ConfigIterative::new().restart(n);
+///     let stats = solve_gmres(&mut x, &laplacian, &b, &mut PrecondIdentity, &config, &mut IterativeMonitor::new())?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.6}", x[2]), "1.000000");
+///     Ok(())
+/// }
+/// ```
+pub struct LinearOperatorFn<'a> {
+    nrow: usize,                          // m
+    ncol: usize,                          // n
+    apply: ApplyFn<'a>,                   // y = a ⋅ x
+    apply_transpose: Option<ApplyFn<'a>>, // y = aᵀ ⋅ x
+}
+
+impl<'a> LinearOperatorFn<'a> {
+    /// Allocates a new instance
+    ///
+    /// # Input
+    ///
+    /// * `nrow` -- the dimension `m` of the output vector `y`
+    /// * `ncol` -- the dimension `n` of the input vector `x`
+    /// * `apply` -- the closure `(y, x)` computing `y = a ⋅ x`
+    pub fn new<F>(nrow: usize, ncol: usize, apply: F) -> Self
+    where
+        F: Fn(&mut Vector, &Vector) -> Result<(), StrError> + 'a,
+    {
+        LinearOperatorFn {
+            nrow,
+            ncol,
+            apply: Box::new(apply),
+            apply_transpose: None,
+        }
+    }
+
+    /// Sets the closure `(y, x)` computing `y = aᵀ ⋅ x`
+    pub fn set_transpose<F>(&mut self, apply_transpose: F) -> &mut Self
+    where
+        F: Fn(&mut Vector, &Vector) -> Result<(), StrError> + 'a,
+    {
+        self.apply_transpose = Some(Box::new(apply_transpose));
+        self
+    }
+}
+
+impl<'a> LinearOperator for LinearOperatorFn<'a> {
+    fn dims(&self) -> (usize, usize) {
+        (self.nrow, self.ncol)
+    }
+
+    fn apply(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        if y.dim() != self.nrow || x.dim() != self.ncol {
+            return Err("vectors are incompatible with the operator");
+        }
+        (self.apply)(y, x)
+    }
+
+    fn apply_transpose(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        if y.dim() != self.ncol || x.dim() != self.nrow {
+            return Err("vectors are incompatible with the operator");
+        }
+        match &self.apply_transpose {
+            Some(f) => f(y, x),
+            None => Err("the transpose of the operator is not available"),
+        }
+    }
+}
+
+impl LinearOperator for SparseCsr {
+    fn dims(&self) -> (usize, usize) {
+        (self.neq, self.neq)
+    }
+
+    fn apply(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        if y.dim() != self.neq || x.dim() != self.neq {
+            return Err("vectors are incompatible with the operator");
+        }
+        for i in 0..self.neq {
+            let mut sum = 0.0;
+            for p in self.row_pointers[i]..self.row_pointers[i + 1] {
+                sum += self.values[p] * x[self.col_indices[p]];
+            }
+            y[i] = sum;
+        }
+        Ok(())
+    }
+
+    fn apply_transpose(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        if y.dim() != self.neq || x.dim() != self.neq {
+            return Err("vectors are incompatible with the operator");
+        }
+        y.fill(0.0);
+        for i in 0..self.neq {
+            for p in self.row_pointers[i]..self.row_pointers[i + 1] {
+                y[self.col_indices[p]] += self.values[p] * x[i];
+            }
+        }
+        Ok(())
+    }
+}
+
+impl LinearOperator for SparseTriplet {
+    fn dims(&self) -> (usize, usize) {
+        (self.neq, self.neq)
+    }
+
+    fn apply(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        if y.dim() != self.neq || x.dim() != self.neq {
+            return Err("vectors are incompatible with the operator");
+        }
+        let triangular = self.symmetry == TripletSymmetry::LowerTriangular;
+        y.fill(0.0);
+        for p in 0..self.pos {
+            let i = self.indices_i[p] as usize;
+            let j = self.indices_j[p] as usize;
+            let aij = self.values_aij[p];
+            y[i] += aij * x[j];
+            if triangular && i != j {
+                y[j] += aij * x[i];
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_transpose(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        if y.dim() != self.neq || x.dim() != self.neq {
+            return Err("vectors are incompatible with the operator");
+        }
+        let triangular = self.symmetry == TripletSymmetry::LowerTriangular;
+        y.fill(0.0);
+        for p in 0..self.pos {
+            let i = self.indices_i[p] as usize;
+            let j = self.indices_j[p] as usize;
+            let aij = self.values_aij[p];
+            y[j] += aij * x[i];
+            if triangular && i != j {
+                y[i] += aij * x[j];
+            }
+        }
+        Ok(())
+    }
+}
+
+impl LinearOperator for RectSparseTriplet {
+    fn dims(&self) -> (usize, usize) {
+        (self.nrow, self.ncol)
+    }
+
+    fn apply(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        if y.dim() != self.nrow || x.dim() != self.ncol {
+            return Err("vectors are incompatible with the operator");
+        }
+        y.fill(0.0);
+        for p in 0..self.pos {
+            y[self.indices_i[p] as usize] += self.values_aij[p] * x[self.indices_j[p] as usize];
+        }
+        Ok(())
+    }
+
+    fn apply_transpose(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        if y.dim() != self.ncol || x.dim() != self.nrow {
+            return Err("vectors are incompatible with the operator");
+        }
+        y.fill(0.0);
+        for p in 0..self.pos {
+            y[self.indices_j[p] as usize] += self.values_aij[p] * x[self.indices_i[p] as usize];
+        }
+        Ok(())
+    }
+}
+
+impl LinearOperator for Matrix {
+    fn dims(&self) -> (usize, usize) {
+        Matrix::dims(self)
+    }
+
+    fn apply(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        mat_vec_mul(y, 1.0, self, x)
+    }
+
+    fn apply_transpose(&self, y: &mut Vector, x: &Vector) -> Result<(), StrError> {
+        vec_mat_mul(y, 1.0, x, self)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearOperator, LinearOperatorFn};
+    use crate::{RectSparseTriplet, SparseCsr, SparseTriplet, TripletSymmetry};
+    use russell_lab::{Matrix, Vector};
+
+    #[test]
+    fn sparse_csr_operator_works() {
+        // | 1  2 |
+        // | 0  3 |
+        let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 1, 2.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        let csr = SparseCsr::from_triplet(&trip);
+        let op: &dyn LinearOperator = &csr;
+        assert_eq!(op.dims(), (2, 2));
+        let mut y = Vector::new(2);
+        assert_eq!(
+            op.apply(&mut y, &Vector::new(3)).err(),
+            Some("vectors are incompatible with the operator")
+        );
+        assert_eq!(
+            op.apply_transpose(&mut Vector::new(3), &y).err(),
+            Some("vectors are incompatible with the operator")
+        );
+        op.apply(&mut y, &Vector::from(&[1.0, 1.0])).unwrap();
+        assert_eq!(y.as_data(), &[3.0, 3.0]);
+        op.apply_transpose(&mut y, &Vector::from(&[1.0, 1.0])).unwrap();
+        assert_eq!(y.as_data(), &[1.0, 5.0]);
+    }
+
+    #[test]
+    fn linear_operator_fn_works() {
+        // a = [1 2 3] (1 × 3)
+        let mut op = LinearOperatorFn::new(1, 3, |y, x| {
+            y[0] = x[0] + 2.0 * x[1] + 3.0 * x[2];
+            Ok(())
+        });
+        assert_eq!(op.dims(), (1, 3));
+        let mut y = Vector::new(1);
+        let mut z = Vector::new(3);
+        assert_eq!(
+            op.apply(&mut z, &y).err(),
+            Some("vectors are incompatible with the operator")
+        );
+        assert_eq!(
+            op.apply_transpose(&mut z, &y).err(),
+            Some("the transpose of the operator is not available")
+        );
+        op.apply(&mut y, &Vector::from(&[1.0, 1.0, 1.0])).unwrap();
+        assert_eq!(y.as_data(), &[6.0]);
+        op.set_transpose(|z, y| {
+            for j in 0..3 {
+                z[j] = (j + 1) as f64 * y[0];
+            }
+            Ok(())
+        });
+        assert_eq!(
+            op.apply_transpose(&mut y, &Vector::new(1)).err(),
+            Some("vectors are incompatible with the operator")
+        );
+        op.apply_transpose(&mut z, &Vector::from(&[2.0])).unwrap();
+        assert_eq!(z.as_data(), &[2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn sparse_triplet_and_matrix_operators_work() {
+        // | 2  1  0 |
+        // | 1  3  4 |
+        // | 0  4  5 |
+        let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(1, 0, 1.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        trip.put(2, 1, 4.0).unwrap();
+        trip.put(2, 2, 5.0).unwrap();
+        let x = Vector::from(&[1.0, 2.0, 3.0]);
+        let mut y = Vector::new(3);
+        assert_eq!((&trip as &dyn LinearOperator).dims(), (3, 3));
+        assert_eq!(
+            LinearOperator::apply(&trip, &mut y, &Vector::new(2)).err(),
+            Some("vectors are incompatible with the operator")
+        );
+        LinearOperator::apply(&trip, &mut y, &x).unwrap();
+        assert_eq!(y.as_data(), &[4.0, 19.0, 23.0]);
+        trip.apply_transpose(&mut y, &x).unwrap();
+        assert_eq!(y.as_data(), &[4.0, 19.0, 23.0]);
+
+        // | 1  2  3 |
+        // | 4  5  6 |
+        let a = Matrix::from(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(LinearOperator::dims(&a), (2, 3));
+        let mut y = Vector::new(2);
+        LinearOperator::apply(&a, &mut y, &x).unwrap();
+        assert_eq!(y.as_data(), &[14.0, 32.0]);
+        let mut z = Vector::new(3);
+        a.apply_transpose(&mut z, &Vector::from(&[1.0, 1.0])).unwrap();
+        assert_eq!(z.as_data(), &[5.0, 7.0, 9.0]);
+    }
+
+    #[test]
+    fn rect_sparse_triplet_operator_works() {
+        // | 1  0  2 |
+        // | 0  3  4 |
+        let mut trip = RectSparseTriplet::new(2, 3, 4).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 2, 2.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        trip.put(1, 2, 4.0).unwrap();
+        let op: &dyn LinearOperator = &trip;
+        assert_eq!(op.dims(), (2, 3));
+        let mut y = Vector::new(2);
+        let mut z = Vector::new(3);
+        assert_eq!(
+            op.apply(&mut z, &y).err(),
+            Some("vectors are incompatible with the operator")
+        );
+        assert_eq!(
+            op.apply_transpose(&mut y, &z).err(),
+            Some("vectors are incompatible with the operator")
+        );
+        op.apply(&mut y, &Vector::from(&[1.0, 1.0, 1.0])).unwrap();
+        assert_eq!(y.as_data(), &[3.0, 7.0]);
+        op.apply_transpose(&mut z, &Vector::from(&[1.0, 1.0])).unwrap();
+        assert_eq!(z.as_data(), &[1.0, 3.0, 6.0]);
+    }
+}
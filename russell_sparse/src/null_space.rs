@@ -0,0 +1,237 @@
+use super::{solve_lsqr, ConfigIterative, IterativeMonitor, LinearOperator};
+use crate::StrError;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand_distr::{Distribution, StandardNormal};
+use russell_lab::{vec_inner, vec_norm, Matrix, Norm, Vector};
+
+/// Seed of the pseudo-random trial vectors (thus, the results are reproducible)
+const SEED: u64 = 314159;
+
+/// Computes an orthonormal basis of the null space of a (small-dimensional) singular matrix
+///
+/// Finds the (n,d) matrix `z` with orthonormal columns such that:
+///
+/// ```text
+///   a   ⋅   z   =   0
+/// (m,n)   (n,d)   (m,d)
+/// ```
+///
+/// The null space is sampled by pseudo-random trial vectors `w` orthogonal to the basis found so far.
+/// Each trial vector is projected onto the null space by the minimum-norm solution of the consistent
+/// system `a ⋅ x = a ⋅ w` computed by [solve_lsqr] (starting from zero):
+///
+/// ```text
+/// p = w - x = (I - a⁺ ⋅ a) ⋅ w
+/// ```
+///
+/// The procedure stops when a projection is negligible, i.e., `‖p‖₂ ≤ √tolerance ⋅ ‖w‖₂`
+/// (the null space is exhausted) or when `max_dim` vectors have been found.
+///
+/// This function is useful to detect floating substructures (e.g., rigid-body modes of
+/// unsupported parts of a structure) before calling the direct solver. The matrix `a` is only
+/// accessed by products with `a` and `aᵀ`; thus, it works with sparse matrices (e.g., [crate::SparseCsr]),
+/// rectangular matrices (e.g., [crate::RectSparseTriplet]), and matrix-free operators.
+///
+/// # Input
+///
+/// * `a` -- the (m,n) dense or sparse matrix or the matrix-free operator implementing
+///   [LinearOperator::apply_transpose]
+/// * `max_dim` -- the maximum dimension `d` of the computed basis
+/// * `config` -- the tolerance and maximum number of iterations of [solve_lsqr] (see [ConfigIterative])
+///
+/// # Output
+///
+/// Returns the (n,d) matrix `z` with `0 ≤ d ≤ max_dim` (d = 0 if `a` has full column rank).
+///
+/// **Note:** The accuracy of the basis depends on the condition number of the non-singular part of `a`.
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::{null_space, ConfigIterative, SparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     // stiffness matrix of two unsupported springs (0)--(1)--(2)
+///     let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular)?;
+///     trip.put(0, 0, 1.0)?;
+///     trip.put(1, 0, -1.0)?;
+///     trip.put(1, 1, 2.0)?;
+///     trip.put(2, 1, -1.0)?;
+///     trip.put(2, 2, 1.0)?;
+///     let z = null_space(&trip, 3, &ConfigIterative::new())?;
+///
+///     // the rigid-body translation
+///     assert_eq!(z.dims(), (3, 1));
+///     let u = f64::abs(z.get(0, 0));
+///     assert_eq!(format!("{:.6}", u * u * 3.0), "1.000000");
+///     assert_eq!(format!("{:.6}", z.get(1, 0) / z.get(0, 0)), "1.000000");
+///     assert_eq!(format!("{:.6}", z.get(2, 0) / z.get(0, 0)), "1.000000");
+///     Ok(())
+/// }
+/// ```
+pub fn null_space(a: &dyn LinearOperator, max_dim: usize, config: &ConfigIterative) -> Result<Matrix, StrError> {
+    let (m, n) = a.dims();
+    if max_dim > n {
+        return Err("max_dim must not be greater than the number of columns");
+    }
+    let threshold = f64::sqrt(config.tolerance);
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut basis: Vec<Vector> = Vec::new();
+    let mut aw = Vector::new(m);
+    let mut x = Vector::new(n);
+    while basis.len() < max_dim {
+        // random trial vector orthogonal to the current basis
+        let mut w = Vector::new(n);
+        for i in 0..n {
+            w[i] = StandardNormal.sample(&mut rng);
+        }
+        project_out(&mut w, &basis);
+        let norm_w = vec_norm(&w, Norm::Euc);
+
+        // p = w - x with x = a⁺ ⋅ a ⋅ w (the component of w in the row space)
+        a.apply(&mut aw, &w)?;
+        x.fill(0.0);
+        let stats = solve_lsqr(&mut x, a, &aw, config, &mut IterativeMonitor::new())?;
+        if !stats.converged {
+            return Err("LSQR did not converge while computing the null space");
+        }
+        let mut p = w;
+        for i in 0..n {
+            p[i] -= x[i];
+        }
+        project_out(&mut p, &basis);
+        let norm_p = vec_norm(&p, Norm::Euc);
+        if norm_p <= threshold * norm_w {
+            break;
+        }
+        for i in 0..n {
+            p[i] /= norm_p;
+        }
+        basis.push(p);
+    }
+    let mut z = Matrix::new(n, basis.len());
+    for (j, zj) in basis.iter().enumerate() {
+        for i in 0..n {
+            z.set(i, j, zj[i]);
+        }
+    }
+    Ok(z)
+}
+
+/// Removes the components of `v` along the orthonormal vectors in `basis` (classical Gram-Schmidt applied twice)
+fn project_out(v: &mut Vector, basis: &[Vector]) {
+    for _ in 0..2 {
+        for q in basis {
+            let c = vec_inner(q, v);
+            for k in 0..v.dim() {
+                v[k] -= c * q[k];
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::null_space;
+    use crate::{ConfigIterative, LinearOperator, RectSparseTriplet, SparseCsr, SparseTriplet, TripletSymmetry};
+    use russell_chk::approx_eq;
+    use russell_lab::{mat_approx_eq, mat_t_mat_mul, Matrix, Vector};
+
+    /// Returns the max-norm of a ⋅ z (column by column)
+    fn max_abs_residual(a: &dyn LinearOperator, z: &Matrix) -> f64 {
+        let (m, n) = a.dims();
+        let mut max = 0.0;
+        for j in 0..z.dims().1 {
+            let mut zj = Vector::new(n);
+            for i in 0..n {
+                zj[i] = z.get(i, j);
+            }
+            let mut y = Vector::new(m);
+            a.apply(&mut y, &zj).unwrap();
+            for i in 0..m {
+                max = f64::max(max, f64::abs(y[i]));
+            }
+        }
+        max
+    }
+
+    /// Checks that zᵀ ⋅ z = I
+    fn check_orthonormal(z: &Matrix) {
+        let d = z.dims().1;
+        let mut ztz = Matrix::new(d, d);
+        mat_t_mat_mul(&mut ztz, 1.0, z, z).unwrap();
+        mat_approx_eq(&ztz, &Matrix::identity(d), 1e-14);
+    }
+
+    #[test]
+    fn null_space_captures_errors() {
+        let a = Matrix::new(2, 3);
+        assert_eq!(
+            null_space(&a, 4, &ConfigIterative::new()).err(),
+            Some("max_dim must not be greater than the number of columns")
+        );
+        let config = *ConfigIterative::new().max_iterations(0);
+        let a = Matrix::from(&[[1.0, 2.0, 3.0]]);
+        assert_eq!(
+            null_space(&a, 2, &config).err(),
+            Some("LSQR did not converge while computing the null space")
+        );
+    }
+
+    #[test]
+    fn null_space_of_floating_substructures_works() {
+        // two disconnected bars (0)--(1) and (2)--(3)--(4) without supports
+        let mut trip = SparseTriplet::new(5, 12, TripletSymmetry::None).unwrap();
+        for (i, j) in [(0, 1), (2, 3), (3, 4)] {
+            trip.put(i, i, 1.0).unwrap();
+            trip.put(j, j, 1.0).unwrap();
+            trip.put(i, j, -1.0).unwrap();
+            trip.put(j, i, -1.0).unwrap();
+        }
+        let csr = SparseCsr::from_triplet(&trip);
+        let z = null_space(&csr, 5, &ConfigIterative::new()).unwrap();
+        assert_eq!(z.dims(), (5, 2));
+        check_orthonormal(&z);
+        assert!(max_abs_residual(&csr, &z) < 1e-10);
+
+        // the projector z ⋅ zᵀ onto the null space equals the one of the exact basis
+        let s2 = 1.0 / f64::sqrt(2.0);
+        let s3 = 1.0 / f64::sqrt(3.0);
+        let exact = Matrix::from(&[[s2, 0.0], [s2, 0.0], [0.0, s3], [0.0, s3], [0.0, s3]]);
+        for i in 0..5 {
+            for j in 0..5 {
+                let p = z.get(i, 0) * z.get(j, 0) + z.get(i, 1) * z.get(j, 1);
+                let p_exact = exact.get(i, 0) * exact.get(j, 0) + exact.get(i, 1) * exact.get(j, 1);
+                approx_eq(p, p_exact, 1e-10);
+            }
+        }
+
+        // limited dimension
+        let z = null_space(&csr, 1, &ConfigIterative::new()).unwrap();
+        assert_eq!(z.dims(), (5, 1));
+        assert!(max_abs_residual(&csr, &z) < 1e-10);
+    }
+
+    #[test]
+    fn null_space_of_full_rank_matrix_is_empty() {
+        let a = Matrix::from(&[[2.0, 1.0], [1.0, 3.0], [0.0, 1.0]]);
+        let z = null_space(&a, 2, &ConfigIterative::new()).unwrap();
+        assert_eq!(z.dims(), (2, 0));
+    }
+
+    #[test]
+    fn null_space_of_rectangular_matrix_works() {
+        // a = [1 2 3] has a two-dimensional null space
+        let mut trip = RectSparseTriplet::new(1, 3, 3).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 1, 2.0).unwrap();
+        trip.put(0, 2, 3.0).unwrap();
+        let z = null_space(&trip, 3, &ConfigIterative::new()).unwrap();
+        assert_eq!(z.dims(), (3, 2));
+        check_orthonormal(&z);
+        assert!(max_abs_residual(&trip, &z) < 1e-12);
+    }
+}
@@ -0,0 +1,378 @@
+use super::{ConfigSolver, Preconditioner, Solver, SparseCsr, SparseTriplet, TripletSymmetry};
+use crate::StrError;
+use russell_lab::Vector;
+
+/// Holds a subdomain (diagonal block) of the additive Schwarz preconditioner
+struct Subdomain {
+    indices: Vec<usize>, // global indices of the subdomain (sorted)
+    solver: Solver,      // direct solver holding the factorization of the diagonal block
+    r_local: Vector,     // restriction of the residual to the subdomain
+    z_local: Vector,     // local correction
+}
+
+/// Implements the Block-Jacobi and additive Schwarz preconditioners
+///
+/// The preconditioner is defined by a set of subdomains (sets of equation indices) and reads:
+///
+/// ```text
+///        nsub
+/// M⁻¹ =   Σ   Rᵢᵀ ⋅ Aᵢ⁻¹ ⋅ Rᵢ    with    Aᵢ = Rᵢ ⋅ a ⋅ Rᵢᵀ
+///        i=1
+/// ```
+///
+/// where `Rᵢ` is the restriction onto the i-th subdomain. Each diagonal block `Aᵢ` is factorized
+/// once by the direct [Solver]; thus, the preconditioner is applied with one (forward/backward)
+/// solution per subdomain. With non-overlapping subdomains, this is the Block-Jacobi preconditioner.
+/// With overlapping subdomains, this is the (one-level) additive Schwarz preconditioner, which is
+/// symmetric positive-definite if `a` is symmetric positive-definite; thus, it can be used with
+/// the conjugate gradient method.
+///
+/// **Note:** Every diagonal block must be non-singular.
+pub struct PrecondSchwarz {
+    neq: usize,                 // number of equations of the global system
+    subdomains: Vec<Subdomain>, // subdomains with the factorized diagonal blocks
+}
+
+impl PrecondSchwarz {
+    /// Allocates a new instance with the given subdomains and factorizes the diagonal blocks
+    ///
+    /// # Input
+    ///
+    /// * `config` -- configuration of the direct solver (used for all diagonal blocks)
+    /// * `a` -- the coefficient matrix
+    /// * `subdomains` -- the (possibly overlapping) sets of equation indices; every equation
+    ///   must belong to at least one subdomain
+    pub fn new(config: ConfigSolver, a: &SparseCsr, subdomains: &[Vec<usize>]) -> Result<Self, StrError> {
+        let neq = a.neq();
+        if subdomains.is_empty() {
+            return Err("there must be at least one subdomain");
+        }
+        let mut covered = vec![false; neq];
+        let mut local = vec![usize::MAX; neq]; // global to local map of the current subdomain
+        let mut blocks = Vec::with_capacity(subdomains.len());
+        for subdomain in subdomains {
+            let mut indices = subdomain.clone();
+            indices.sort_unstable();
+            indices.dedup();
+            if indices.is_empty() {
+                return Err("subdomains must not be empty");
+            }
+            if indices[indices.len() - 1] >= neq {
+                return Err("subdomain index is out of bounds");
+            }
+            for (l, &g) in indices.iter().enumerate() {
+                local[g] = l;
+                covered[g] = true;
+            }
+            let trip = extract_block(a, &indices, &local)?;
+            for &g in &indices {
+                local[g] = usize::MAX;
+            }
+            let n = indices.len();
+            let mut solver = Solver::new(config, n, trip.nnz_current(), None)?;
+            solver.factorize(&trip)?;
+            blocks.push(Subdomain {
+                indices,
+                solver,
+                r_local: Vector::new(n),
+                z_local: Vector::new(n),
+            });
+        }
+        if covered.iter().any(|c| !c) {
+            return Err("every equation must belong to at least one subdomain");
+        }
+        Ok(PrecondSchwarz {
+            neq,
+            subdomains: blocks,
+        })
+    }
+
+    /// Allocates a Block-Jacobi preconditioner with contiguous (non-overlapping) blocks
+    ///
+    /// # Input
+    ///
+    /// * `config` -- configuration of the direct solver (used for all diagonal blocks)
+    /// * `a` -- the coefficient matrix
+    /// * `n_block` -- the number of blocks (at most the number of equations); the blocks have
+    ///   nearly the same size
+    pub fn block_jacobi(config: ConfigSolver, a: &SparseCsr, n_block: usize) -> Result<Self, StrError> {
+        let subdomains = contiguous_blocks(a.neq(), n_block)?;
+        PrecondSchwarz::new(config, a, &subdomains)
+    }
+
+    /// Allocates an additive Schwarz preconditioner with overlapping subdomains
+    ///
+    /// The subdomains are contiguous blocks (see [PrecondSchwarz::block_jacobi]) extended by
+    /// `overlap` layers of neighbors in the graph of the sparsity pattern of `a`.
+    ///
+    /// # Input
+    ///
+    /// * `config` -- configuration of the direct solver (used for all diagonal blocks)
+    /// * `a` -- the coefficient matrix
+    /// * `n_block` -- the number of blocks (subdomains) before the extension by the overlap
+    /// * `overlap` -- the number of layers of neighbors added to each block (0 yields Block-Jacobi)
+    pub fn additive_schwarz(
+        config: ConfigSolver,
+        a: &SparseCsr,
+        n_block: usize,
+        overlap: usize,
+    ) -> Result<Self, StrError> {
+        let mut subdomains = contiguous_blocks(a.neq(), n_block)?;
+        let mut mark = vec![false; a.neq()];
+        for subdomain in &mut subdomains {
+            extend_by_neighbors(a, subdomain, overlap, &mut mark);
+        }
+        PrecondSchwarz::new(config, a, &subdomains)
+    }
+
+    /// Returns the number of subdomains
+    pub fn n_subdomain(&self) -> usize {
+        self.subdomains.len()
+    }
+
+    /// Returns the (sorted) equation indices of each subdomain
+    pub fn get_subdomains(&self) -> Vec<Vec<usize>> {
+        self.subdomains.iter().map(|s| s.indices.clone()).collect()
+    }
+}
+
+impl Preconditioner for PrecondSchwarz {
+    fn apply(&mut self, z: &mut Vector, r: &Vector) -> Result<(), StrError> {
+        if z.dim() != self.neq || r.dim() != self.neq {
+            return Err("vectors are incompatible with the preconditioner");
+        }
+        z.fill(0.0);
+        for sub in &mut self.subdomains {
+            for (l, &g) in sub.indices.iter().enumerate() {
+                sub.r_local[l] = r[g];
+            }
+            sub.solver.solve(&mut sub.z_local, &sub.r_local)?;
+            for (l, &g) in sub.indices.iter().enumerate() {
+                z[g] += sub.z_local[l];
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Extracts the diagonal block `Rᵢ ⋅ a ⋅ Rᵢᵀ` given the global-to-local map
+fn extract_block(a: &SparseCsr, indices: &[usize], local: &[usize]) -> Result<SparseTriplet, StrError> {
+    let mut nnz = 0;
+    for &g in indices {
+        for p in a.row_pointers[g]..a.row_pointers[g + 1] {
+            if local[a.col_indices[p]] != usize::MAX {
+                nnz += 1;
+            }
+        }
+    }
+    if nnz == 0 {
+        return Err("the diagonal block of a subdomain has no entries");
+    }
+    let mut trip = SparseTriplet::new(indices.len(), nnz, TripletSymmetry::None)?;
+    for (li, &g) in indices.iter().enumerate() {
+        for p in a.row_pointers[g]..a.row_pointers[g + 1] {
+            let lj = local[a.col_indices[p]];
+            if lj != usize::MAX {
+                trip.put(li, lj, a.values[p])?;
+            }
+        }
+    }
+    Ok(trip)
+}
+
+/// Splits `0..neq` into `n_block` contiguous blocks of nearly the same size
+fn contiguous_blocks(neq: usize, n_block: usize) -> Result<Vec<Vec<usize>>, StrError> {
+    if n_block < 1 || n_block > neq {
+        return Err("the number of blocks must be in [1, neq]");
+    }
+    let (size, rem) = (neq / n_block, neq % n_block);
+    let mut start = 0;
+    let mut blocks = Vec::with_capacity(n_block);
+    for b in 0..n_block {
+        let len = if b < rem { size + 1 } else { size };
+        blocks.push((start..start + len).collect());
+        start += len;
+    }
+    Ok(blocks)
+}
+
+/// Adds `levels` layers of neighbors (in the graph of the sparsity pattern) to the subdomain
+fn extend_by_neighbors(a: &SparseCsr, subdomain: &mut Vec<usize>, levels: usize, mark: &mut [bool]) {
+    for &g in subdomain.iter() {
+        mark[g] = true;
+    }
+    let mut front = subdomain.clone();
+    for _ in 0..levels {
+        let mut next = Vec::new();
+        for &g in &front {
+            for p in a.row_pointers[g]..a.row_pointers[g + 1] {
+                let j = a.col_indices[p];
+                if !mark[j] {
+                    mark[j] = true;
+                    next.push(j);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        subdomain.extend_from_slice(&next);
+        front = next;
+    }
+    for &g in subdomain.iter() {
+        mark[g] = false;
+    }
+    subdomain.sort_unstable();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{contiguous_blocks, extend_by_neighbors, extract_block, PrecondSchwarz};
+    use crate::{
+        solve_gmres, solve_pcg, ConfigIterative, ConfigSolver, IterativeMonitor, Preconditioner, SparseCsr,
+        SparseTriplet, TripletSymmetry,
+    };
+    use russell_lab::{Matrix, Vector};
+
+    fn laplacian_1d(n: usize) -> SparseCsr {
+        let mut trip = SparseTriplet::new(n, 2 * n, TripletSymmetry::LowerTriangular).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 2.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -1.0).unwrap();
+            }
+        }
+        SparseCsr::from_triplet(&trip)
+    }
+
+    #[test]
+    fn contiguous_blocks_works() {
+        assert_eq!(
+            contiguous_blocks(3, 0).err(),
+            Some("the number of blocks must be in [1, neq]")
+        );
+        assert_eq!(
+            contiguous_blocks(3, 4).err(),
+            Some("the number of blocks must be in [1, neq]")
+        );
+        assert_eq!(contiguous_blocks(3, 1).unwrap(), &[vec![0, 1, 2]]);
+        assert_eq!(
+            contiguous_blocks(7, 3).unwrap(),
+            &[vec![0, 1, 2], vec![3, 4], vec![5, 6]]
+        );
+    }
+
+    #[test]
+    fn extend_by_neighbors_works() {
+        let a = laplacian_1d(8);
+        let mut mark = vec![false; 8];
+        let mut subdomain = vec![3, 4];
+        extend_by_neighbors(&a, &mut subdomain, 0, &mut mark);
+        assert_eq!(subdomain, &[3, 4]);
+        extend_by_neighbors(&a, &mut subdomain, 2, &mut mark);
+        assert_eq!(subdomain, &[1, 2, 3, 4, 5, 6]);
+        extend_by_neighbors(&a, &mut subdomain, 10, &mut mark);
+        assert_eq!(subdomain, &[0, 1, 2, 3, 4, 5, 6, 7]);
+        assert!(mark.iter().all(|m| !m));
+    }
+
+    #[test]
+    fn extract_block_works() {
+        let a = laplacian_1d(5);
+        let indices = [1, 2, 4];
+        let mut local = vec![usize::MAX; 5];
+        for (l, &g) in indices.iter().enumerate() {
+            local[g] = l;
+        }
+        let trip = extract_block(&a, &indices, &local).unwrap();
+        let mut block = Matrix::new(3, 3);
+        trip.to_matrix(&mut block).unwrap();
+        assert_eq!(
+            format!("{}", block),
+            "┌          ┐\n\
+             │  2 -1  0 │\n\
+             │ -1  2  0 │\n\
+             │  0  0  2 │\n\
+             └          ┘"
+        );
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        let a = laplacian_1d(4);
+        let config = ConfigSolver::new();
+        assert_eq!(
+            PrecondSchwarz::new(config, &a, &[]).err(),
+            Some("there must be at least one subdomain")
+        );
+        assert_eq!(
+            PrecondSchwarz::new(config, &a, &[vec![]]).err(),
+            Some("subdomains must not be empty")
+        );
+        assert_eq!(
+            PrecondSchwarz::new(config, &a, &[vec![0, 4]]).err(),
+            Some("subdomain index is out of bounds")
+        );
+        assert_eq!(
+            PrecondSchwarz::block_jacobi(config, &a, 5).err(),
+            Some("the number of blocks must be in [1, neq]")
+        );
+    }
+
+    #[test]
+    fn block_jacobi_works() {
+        let n = 12;
+        let a = laplacian_1d(n);
+        let config = ConfigSolver::new();
+        let mut precond = PrecondSchwarz::block_jacobi(config, &a, 3).unwrap();
+        assert_eq!(precond.n_subdomain(), 3);
+        assert_eq!(precond.get_subdomains()[1], &[4, 5, 6, 7]);
+
+        // a single block is the exact inverse
+        let mut exact = PrecondSchwarz::block_jacobi(config, &a, 1).unwrap();
+        let b = Vector::filled(n, 1.0);
+        let mut z = Vector::new(n);
+        exact.apply(&mut z, &a.mat_vec_mul(&b).unwrap()).unwrap();
+        for i in 0..n {
+            assert!(f64::abs(z[i] - 1.0) < 1e-12);
+        }
+        assert_eq!(
+            exact.apply(&mut z, &Vector::new(2)).err(),
+            Some("vectors are incompatible with the preconditioner")
+        );
+
+        // preconditioned conjugate gradient
+        let mut x = Vector::new(n);
+        let iterative = *ConfigIterative::new().tolerance(1e-12).max_iterations(100);
+        let stats = solve_pcg(&mut x, &a, &b, &mut precond, &iterative, &mut IterativeMonitor::new()).unwrap();
+        assert!(stats.converged);
+        let mut x_ref = Vector::new(n);
+        let stats_ref = solve_pcg(&mut x_ref, &a, &b, &mut exact, &iterative, &mut IterativeMonitor::new()).unwrap();
+        assert_eq!(stats_ref.iterations, 1);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - x_ref[i]) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn additive_schwarz_works() {
+        let n = 24;
+        let a = laplacian_1d(n);
+        let config = ConfigSolver::new();
+        let mut schwarz = PrecondSchwarz::additive_schwarz(config, &a, 4, 1).unwrap();
+        assert_eq!(schwarz.get_subdomains()[1], &[5, 6, 7, 8, 9, 10, 11, 12]);
+        let mut jacobi = PrecondSchwarz::block_jacobi(config, &a, 4).unwrap();
+        let b = Vector::filled(n, 1.0);
+        let iterative = *ConfigIterative::new().tolerance(1e-10).restart(n);
+        let mut x = Vector::new(n);
+        let stats_schwarz =
+            solve_gmres(&mut x, &a, &b, &mut schwarz, &iterative, &mut IterativeMonitor::new()).unwrap();
+        assert!(stats_schwarz.converged);
+        let mut x = Vector::new(n);
+        let stats_jacobi = solve_gmres(&mut x, &a, &b, &mut jacobi, &iterative, &mut IterativeMonitor::new()).unwrap();
+        assert!(stats_jacobi.converged);
+        assert!(stats_schwarz.iterations <= stats_jacobi.iterations);
+    }
+}
@@ -0,0 +1,304 @@
+use super::{Preconditioner, SparseCsr};
+use crate::StrError;
+use russell_lab::{solve_tsvd, Matrix, Vector};
+
+/// Relative threshold of the singular values in the local least squares problems
+const RCOND: f64 = 1e-12;
+
+/// Implements the sparse approximate inverse (SPAI) preconditioner `M⁻¹ ≈ a⁻¹`
+///
+/// The approximate inverse `M⁻¹` is computed with a prescribed sparsity pattern by minimizing
+/// the Frobenius norm (column by column):
+///
+/// ```text
+/// min ‖a ⋅ M⁻¹ - I‖²_F  =   Σ  min ‖a ⋅ mⱼ - eⱼ‖²₂
+///                          j
+/// ```
+///
+/// where `mⱼ` is the j-th column of `M⁻¹`, whose nonzero entries are restricted to the
+/// sparsity pattern of the j-th column of `a^p` (`p` is the pattern power). Each column
+/// yields a small dense least squares problem, solved by truncated SVD (see [solve_tsvd]).
+///
+/// # Remarks
+///
+/// * This is a right approximate inverse; thus, it fits the right-preconditioned GMRES (see [crate::solve_gmres])
+/// * The columns are computed independently of each other and the preconditioner is applied
+///   by a sparse matrix-vector product (no triangular solves); thus, both the construction
+///   and the application are easy to parallelize (e.g., on GPUs)
+/// * `M⁻¹` is not symmetric in general; thus, it should not be used with the conjugate gradient method
+/// * A larger pattern power yields a better (but denser and more expensive) approximate inverse
+pub struct PrecondSpai {
+    m: SparseCsr, // the approximate inverse M⁻¹
+}
+
+impl PrecondSpai {
+    /// Computes the sparse approximate inverse
+    ///
+    /// # Input
+    ///
+    /// * `a` -- the coefficient matrix
+    /// * `pattern_power` -- the power `p ≥ 1` such that the sparsity pattern of `a^p` is used for `M⁻¹`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Vector;
+    /// use russell_sparse::{Preconditioner, PrecondSpai, SparseCsr, SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // a diagonal matrix has an exact sparse inverse
+    ///     let mut trip = SparseTriplet::new(2, 2, TripletSymmetry::None)?;
+    ///     trip.put(0, 0, 2.0)?;
+    ///     trip.put(1, 1, 4.0)?;
+    ///     let mut spai = PrecondSpai::new(&SparseCsr::from_triplet(&trip), 1)?;
+    ///     let mut z = Vector::new(2);
+    ///     spai.apply(&mut z, &Vector::from(&[2.0, 4.0]))?;
+    ///     assert_eq!(z.as_data(), &[1.0, 1.0]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(a: &SparseCsr, pattern_power: usize) -> Result<Self, StrError> {
+        if pattern_power < 1 {
+            return Err("the pattern power must be ≥ 1");
+        }
+        let neq = a.neq;
+        let at = transpose(a); // the rows of aᵀ are the columns of a
+        let mut mark = vec![false; neq];
+        let mut local = vec![usize::MAX; neq];
+
+        // the columns of M⁻¹ are assembled as the rows of (M⁻¹)ᵀ
+        let mut row_pointers = vec![0; neq + 1];
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        for j in 0..neq {
+            // allowed nonzero rows of mⱼ
+            let jj = column_pattern(&at, j, pattern_power, &mut mark);
+
+            // rows of a touched by the columns jj
+            let mut ii = Vec::new();
+            for &q in &jj {
+                for p in at.row_pointers[q]..at.row_pointers[q + 1] {
+                    let i = at.col_indices[p];
+                    if local[i] == usize::MAX {
+                        local[i] = ii.len();
+                        ii.push(i);
+                    }
+                }
+            }
+            if local[j] == usize::MAX {
+                return Err("the sparsity pattern does not reach the diagonal (a larger pattern power is required)");
+            }
+
+            // solve the local least squares problem a[ii,jj] ⋅ m̂ⱼ ≈ eⱼ[ii]
+            let mut aa = Matrix::new(ii.len(), jj.len());
+            for (c, &q) in jj.iter().enumerate() {
+                for p in at.row_pointers[q]..at.row_pointers[q + 1] {
+                    aa.set(local[at.col_indices[p]], c, at.values[p]);
+                }
+            }
+            let mut e = Vector::new(ii.len());
+            e[local[j]] = 1.0;
+            let mut mj = Vector::new(jj.len());
+            solve_tsvd(&mut mj, &aa, &e, RCOND)?;
+            for &i in &ii {
+                local[i] = usize::MAX;
+            }
+            col_indices.extend_from_slice(&jj);
+            values.extend_from_slice(mj.as_data());
+            row_pointers[j + 1] = col_indices.len();
+        }
+        let mt = SparseCsr {
+            neq,
+            row_pointers,
+            col_indices,
+            values,
+        };
+        Ok(PrecondSpai { m: transpose(&mt) })
+    }
+
+    /// Returns the approximate inverse M⁻¹
+    pub fn get_matrix(&self) -> &SparseCsr {
+        &self.m
+    }
+}
+
+impl Preconditioner for PrecondSpai {
+    fn apply(&mut self, z: &mut Vector, r: &Vector) -> Result<(), StrError> {
+        let n = self.m.neq;
+        if z.dim() != n || r.dim() != n {
+            return Err("vectors are incompatible with the preconditioner");
+        }
+        for i in 0..n {
+            let mut sum = 0.0;
+            for p in self.m.row_pointers[i]..self.m.row_pointers[i + 1] {
+                sum += self.m.values[p] * r[self.m.col_indices[p]];
+            }
+            z[i] = sum;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the transpose of a CSR matrix (with sorted column indices)
+fn transpose(a: &SparseCsr) -> SparseCsr {
+    let (neq, nnz) = (a.neq, a.values.len());
+    let mut row_pointers = vec![0; neq + 1];
+    for &j in &a.col_indices {
+        row_pointers[j + 1] += 1;
+    }
+    for i in 0..neq {
+        row_pointers[i + 1] += row_pointers[i];
+    }
+    let mut next = row_pointers.clone();
+    let mut col_indices = vec![0; nnz];
+    let mut values = vec![0.0; nnz];
+    for i in 0..neq {
+        for p in a.row_pointers[i]..a.row_pointers[i + 1] {
+            let j = a.col_indices[p];
+            col_indices[next[j]] = i;
+            values[next[j]] = a.values[p];
+            next[j] += 1;
+        }
+    }
+    SparseCsr {
+        neq,
+        row_pointers,
+        col_indices,
+        values,
+    }
+}
+
+/// Returns the (sorted) sparsity pattern of the j-th column of a^levels, including j
+///
+/// The rows of `at = aᵀ` hold the sparsity pattern of the columns of `a`.
+fn column_pattern(at: &SparseCsr, j: usize, levels: usize, mark: &mut [bool]) -> Vec<usize> {
+    let mut pattern = vec![j];
+    mark[j] = true;
+    let mut front = vec![j];
+    for _ in 0..levels {
+        let mut next = Vec::new();
+        for &q in &front {
+            for p in at.row_pointers[q]..at.row_pointers[q + 1] {
+                let i = at.col_indices[p];
+                if !mark[i] {
+                    mark[i] = true;
+                    next.push(i);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        pattern.extend_from_slice(&next);
+        front = next;
+    }
+    for &i in &pattern {
+        mark[i] = false;
+    }
+    pattern.sort_unstable();
+    pattern
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{column_pattern, transpose, PrecondSpai};
+    use crate::{
+        solve_gmres, ConfigIterative, IterativeMonitor, PrecondIdentity, Preconditioner, SparseCsr, SparseTriplet,
+        TripletSymmetry,
+    };
+    use russell_lab::{Matrix, Vector};
+
+    // non-symmetric tridiagonal matrix (convection-diffusion)
+    fn tridiagonal(n: usize) -> SparseCsr {
+        let mut trip = SparseTriplet::new(n, 3 * n, TripletSymmetry::None).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 4.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -2.0).unwrap();
+            }
+            if i + 1 < n {
+                trip.put(i, i + 1, -1.0).unwrap();
+            }
+        }
+        SparseCsr::from_triplet(&trip)
+    }
+
+    #[test]
+    fn transpose_works() {
+        let a = tridiagonal(3);
+        let at = transpose(&a);
+        let mut mat = Matrix::new(3, 3);
+        at.to_matrix(&mut mat).unwrap();
+        assert_eq!(
+            format!("{}", mat),
+            "┌          ┐\n\
+             │  4 -2  0 │\n\
+             │ -1  4 -2 │\n\
+             │  0 -1  4 │\n\
+             └          ┘"
+        );
+        assert_eq!(at.col_indices(), &[0, 1, 0, 1, 2, 1, 2]);
+    }
+
+    #[test]
+    fn column_pattern_works() {
+        let at = transpose(&tridiagonal(6));
+        let mut mark = vec![false; 6];
+        assert_eq!(column_pattern(&at, 0, 1, &mut mark), &[0, 1]);
+        assert_eq!(column_pattern(&at, 3, 1, &mut mark), &[2, 3, 4]);
+        assert_eq!(column_pattern(&at, 3, 2, &mut mark), &[1, 2, 3, 4, 5]);
+        assert!(mark.iter().all(|m| !m));
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        let a = tridiagonal(3);
+        assert_eq!(PrecondSpai::new(&a, 0).err(), Some("the pattern power must be ≥ 1"));
+        let mut trip = SparseTriplet::new(2, 1, TripletSymmetry::None).unwrap();
+        trip.put(0, 1, 1.0).unwrap();
+        assert_eq!(
+            PrecondSpai::new(&SparseCsr::from_triplet(&trip), 1).err(),
+            Some("the sparsity pattern does not reach the diagonal (a larger pattern power is required)")
+        );
+    }
+
+    #[test]
+    fn spai_works() {
+        let n = 20;
+        let a = tridiagonal(n);
+        let mut spai = PrecondSpai::new(&a, 2).unwrap();
+        assert_eq!(spai.get_matrix().neq(), n);
+        let mut z = Vector::new(n);
+        assert_eq!(
+            spai.apply(&mut z, &Vector::new(2)).err(),
+            Some("vectors are incompatible with the preconditioner")
+        );
+
+        // the diagonal of a ⋅ M⁻¹ is close to one
+        let mut e = Vector::new(n);
+        for j in 0..n {
+            e.fill(0.0);
+            e[j] = 1.0;
+            spai.apply(&mut z, &e).unwrap();
+            let az = a.mat_vec_mul(&z).unwrap();
+            assert!(f64::abs(az[j] - 1.0) < 0.1);
+        }
+
+        // GMRES converges faster
+        let b = Vector::filled(n, 1.0);
+        let config = *ConfigIterative::new().tolerance(1e-10).restart(n);
+        let mut x = Vector::new(n);
+        let stats_spai = solve_gmres(&mut x, &a, &b, &mut spai, &config, &mut IterativeMonitor::new()).unwrap();
+        assert!(stats_spai.converged);
+        let residual = a.mat_vec_mul(&x).unwrap();
+        for i in 0..n {
+            assert!(f64::abs(residual[i] - b[i]) < 1e-8);
+        }
+        let mut x = Vector::new(n);
+        let mut identity = PrecondIdentity;
+        let stats_none = solve_gmres(&mut x, &a, &b, &mut identity, &config, &mut IterativeMonitor::new()).unwrap();
+        assert!(stats_spai.iterations < stats_none.iterations);
+    }
+}
@@ -0,0 +1,105 @@
+use super::SparseCsr;
+use crate::StrError;
+use russell_lab::Vector;
+
+/// Defines a preconditioner `M ≈ a` for the iterative solvers
+///
+/// The preconditioner computes `z = M⁻¹⋅r`; thus, a good preconditioner is cheap to apply
+/// and makes `M⁻¹⋅a` close to the identity matrix.
+///
+/// **Note:** The conjugate gradient method requires a symmetric positive-definite preconditioner.
+pub trait Preconditioner {
+    /// Computes `z = M⁻¹⋅r`
+    fn apply(&mut self, z: &mut Vector, r: &Vector) -> Result<(), StrError>;
+}
+
+/// Implements the identity preconditioner (i.e., no preconditioning)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PrecondIdentity;
+
+impl Preconditioner for PrecondIdentity {
+    fn apply(&mut self, z: &mut Vector, r: &Vector) -> Result<(), StrError> {
+        if z.dim() != r.dim() {
+            return Err("vectors are incompatible with the preconditioner");
+        }
+        z.as_mut_data().copy_from_slice(r.as_data());
+        Ok(())
+    }
+}
+
+/// Implements the Jacobi (diagonal) preconditioner `M = diag(a)`
+pub struct PrecondJacobi {
+    inv_diag: Vec<f64>, // 1 / aᵢᵢ
+}
+
+impl PrecondJacobi {
+    /// Allocates a new instance
+    ///
+    /// **Note:** All diagonal entries must be positive (as required by the conjugate gradient method).
+    pub fn new(a: &SparseCsr) -> Result<Self, StrError> {
+        let diag = a.diagonal();
+        if diag.as_data().iter().any(|&d| d.is_nan() || d <= 0.0) {
+            return Err("the Jacobi preconditioner requires positive diagonal entries");
+        }
+        Ok(PrecondJacobi {
+            inv_diag: diag.as_data().iter().map(|d| 1.0 / d).collect(),
+        })
+    }
+}
+
+impl Preconditioner for PrecondJacobi {
+    fn apply(&mut self, z: &mut Vector, r: &Vector) -> Result<(), StrError> {
+        let n = self.inv_diag.len();
+        if z.dim() != n || r.dim() != n {
+            return Err("vectors are incompatible with the preconditioner");
+        }
+        for i in 0..n {
+            z[i] = r[i] * self.inv_diag[i];
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{PrecondIdentity, PrecondJacobi, Preconditioner};
+    use crate::{SparseCsr, SparseTriplet, TripletSymmetry};
+    use russell_lab::Vector;
+
+    #[test]
+    fn precond_identity_works() {
+        let mut precond = PrecondIdentity;
+        let mut z = Vector::new(2);
+        assert_eq!(
+            precond.apply(&mut z, &Vector::new(3)).err(),
+            Some("vectors are incompatible with the preconditioner")
+        );
+        precond.apply(&mut z, &Vector::from(&[1.0, 2.0])).unwrap();
+        assert_eq!(z.as_data(), &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn precond_jacobi_works() {
+        let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(0, 1, 1.0).unwrap();
+        trip.put(1, 1, -4.0).unwrap();
+        assert_eq!(
+            PrecondJacobi::new(&SparseCsr::from_triplet(&trip)).err(),
+            Some("the Jacobi preconditioner requires positive diagonal entries")
+        );
+        trip.reset();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(1, 1, 4.0).unwrap();
+        let mut precond = PrecondJacobi::new(&SparseCsr::from_triplet(&trip)).unwrap();
+        let mut z = Vector::new(2);
+        assert_eq!(
+            precond.apply(&mut z, &Vector::new(3)).err(),
+            Some("vectors are incompatible with the preconditioner")
+        );
+        precond.apply(&mut z, &Vector::from(&[1.0, 2.0])).unwrap();
+        assert_eq!(z.as_data(), &[0.5, 0.5]);
+    }
+}
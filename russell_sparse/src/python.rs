@@ -0,0 +1,173 @@
+//! Python bindings (requires the `python` feature)
+//!
+//! This module defines the `russell` Python extension module with the classes `SparseTriplet`
+//! and `Solver`, the classes and functions of `russell_lab::python` (e.g., `Matrix` and `Vector`),
+//! and the probability distributions of `russell_stat::python`.
+//!
+//! To build the extension module, run:
+//!
+//! ```text
+//! cargo rustc --release --lib -p russell_sparse --features python,pyo3/extension-module --crate-type cdylib
+//! cp target/release/librussell_sparse.so russell.so
+//! ```
+//!
+//! Then, in Python:
+//!
+//! ```text
+//! from russell import SparseTriplet, Solver, Vector
+//! trip = SparseTriplet(2, 2)
+//! trip.put(0, 0, 2.0)
+//! trip.put(1, 1, 4.0)
+//! solver = Solver(2, 2)
+//! solver.factorize(trip)
+//! x = solver.solve(Vector.from_list([2.0, 8.0]))
+//! print(x.to_list()) # [1.0, 2.0]
+//! ```
+
+use crate::{ConfigSolver, LinSolKind, Solver, SparseTriplet, Symmetry, TripletSymmetry};
+use pyo3::prelude::*;
+use russell_lab::python::{to_py_err, PyMatrix, PyVector};
+use russell_lab::{Matrix, Vector};
+
+/// Holds a SparseTriplet (Python class `SparseTriplet`)
+#[pyclass(name = "SparseTriplet")]
+pub struct PySparseTriplet {
+    pub data: SparseTriplet,
+}
+
+#[pymethods]
+impl PySparseTriplet {
+    /// Allocates a new triplet; symmetry is one of "none", "general_full", or "lower_triangular"
+    #[new]
+    #[pyo3(signature = (neq, max, symmetry = "none"))]
+    pub fn new(neq: usize, max: usize, symmetry: &str) -> PyResult<Self> {
+        let symmetry = match symmetry {
+            "none" => TripletSymmetry::None,
+            "general_full" => TripletSymmetry::GeneralFull,
+            "lower_triangular" => TripletSymmetry::LowerTriangular,
+            _ => {
+                return Err(to_py_err(
+                    "the symmetry must be one of: none, general_full, lower_triangular",
+                ))
+            }
+        };
+        Ok(PySparseTriplet {
+            data: SparseTriplet::new(neq, max, symmetry).map_err(to_py_err)?,
+        })
+    }
+
+    /// Puts the next triple (i,j,aij) into the triplet
+    pub fn put(&mut self, i: usize, j: usize, aij: f64) -> PyResult<()> {
+        self.data.put(i, j, aij).map_err(to_py_err)
+    }
+
+    /// Returns the number of equations
+    pub fn neq(&self) -> usize {
+        self.data.neq()
+    }
+
+    /// Returns the current number of non-zero values
+    pub fn nnz_current(&self) -> usize {
+        self.data.nnz_current()
+    }
+
+    /// Resets the triplet, allowing using "put" from scratch
+    pub fn reset(&mut self) {
+        self.data.reset();
+    }
+
+    /// Returns the dense matrix corresponding to this triplet
+    pub fn to_matrix(&self) -> PyResult<PyMatrix> {
+        let mut a = Matrix::new(self.data.neq(), self.data.neq());
+        self.data.to_matrix(&mut a).map_err(to_py_err)?;
+        Ok(PyMatrix { data: a })
+    }
+}
+
+/// Holds a sparse Solver (Python class `Solver`)
+#[pyclass(name = "Solver", unsendable)]
+pub struct PySolver {
+    solver: Solver,
+    neq: usize,
+}
+
+#[pymethods]
+impl PySolver {
+    /// Allocates a new solver; kind is "umf" or "mmp" and symmetry is None, "general", or "pos_def"
+    #[new]
+    #[pyo3(signature = (neq, nnz, kind = "umf", symmetry = None))]
+    pub fn new(neq: usize, nnz: usize, kind: &str, symmetry: Option<&str>) -> PyResult<Self> {
+        let mut config = ConfigSolver::new();
+        match kind {
+            "umf" => config.lin_sol_kind(LinSolKind::Umf),
+            "mmp" => config.lin_sol_kind(LinSolKind::Mmp),
+            _ => return Err(to_py_err("the solver kind must be one of: umf, mmp")),
+        };
+        let symmetry = match symmetry {
+            None => None,
+            Some("general") => Some(Symmetry::General),
+            Some("pos_def") => Some(Symmetry::PosDef),
+            Some(_) => return Err(to_py_err("the symmetry must be one of: None, general, pos_def")),
+        };
+        Ok(PySolver {
+            solver: Solver::new(config, neq, nnz, symmetry).map_err(to_py_err)?,
+            neq,
+        })
+    }
+
+    /// Performs the factorization
+    pub fn factorize(&mut self, trip: &PySparseTriplet) -> PyResult<()> {
+        self.solver.factorize(&trip.data).map_err(to_py_err)
+    }
+
+    /// Performs a new numeric factorization with the same sparsity pattern (reusing the ordering)
+    pub fn update_values(&mut self, trip: &PySparseTriplet) -> PyResult<()> {
+        self.solver.update_values(&trip.data).map_err(to_py_err)
+    }
+
+    /// Computes the solution of the linear system (after factorize)
+    pub fn solve(&mut self, rhs: &PyVector) -> PyResult<PyVector> {
+        let mut x = Vector::new(self.neq);
+        self.solver.solve(&mut x, &rhs.data).map_err(to_py_err)?;
+        Ok(PyVector { data: x })
+    }
+}
+
+/// Defines the `russell` Python module
+#[pymodule]
+fn russell(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    russell_lab::python::register(m)?;
+    russell_stat::python::register(m)?;
+    m.add_class::<PySparseTriplet>()?;
+    m.add_class::<PySolver>()?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{PySolver, PySparseTriplet};
+
+    #[test]
+    fn py_sparse_triplet_works() {
+        assert!(PySparseTriplet::new(2, 2, "wrong").is_err());
+        assert!(PySparseTriplet::new(0, 2, "none").is_err());
+        let mut trip = PySparseTriplet::new(2, 3, "lower_triangular").unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 1, 2.0).unwrap();
+        assert!(trip.put(2, 0, 1.0).is_err());
+        assert_eq!(trip.neq(), 2);
+        assert_eq!(trip.nnz_current(), 2);
+        let a = trip.to_matrix().unwrap();
+        assert_eq!(a.to_rows(), vec![vec![1.0, 2.0], vec![2.0, 0.0]]);
+        trip.reset();
+        assert_eq!(trip.nnz_current(), 0);
+    }
+
+    #[test]
+    fn py_solver_captures_errors() {
+        assert!(PySolver::new(2, 2, "wrong", None).is_err());
+        assert!(PySolver::new(2, 2, "umf", Some("wrong")).is_err());
+    }
+}
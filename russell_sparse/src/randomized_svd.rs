@@ -0,0 +1,292 @@
+use super::LinearOperator;
+use crate::StrError;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand_distr::{Distribution, StandardNormal};
+use russell_lab::{mat_svd, vec_inner, vec_norm, Matrix, Norm, Vector};
+
+/// Seed of the pseudo-random test matrix (thus, the results are reproducible)
+const SEED: u64 = 271828;
+
+/// Number of power (subspace) iterations to improve the accuracy when the singular values decay slowly
+const POWER_ITERATIONS: usize = 2;
+
+/// Computes a low-rank approximation of a (large) matrix by the randomized singular value decomposition
+///
+/// Finds the rank-k approximation:
+///
+/// ```text
+///   a   ≈   u   ⋅   Σ   ⋅   vt
+/// (m,n)   (m,k)   (k,k)   (k,n)
+/// ```
+///
+/// where `Σ = diag(s)` holds the k largest singular values (estimates) in decreasing order.
+///
+/// The range of `a` is sampled by a Gaussian random matrix with `l = k + oversampling` columns
+/// (followed by a few power iterations); afterwards, the problem is projected onto the resulting
+/// orthonormal basis and solved by a small dense SVD (see [mat_svd]). Thus, `a` is only accessed
+/// by `2 + 2 ⋅ POWER_ITERATIONS` blocks of `l` products with `a` or `aᵀ`, which is well suited to
+/// the construction of reduced-order models (e.g., by proper orthogonal decomposition).
+///
+/// # Input
+///
+/// * `a` -- the (m,n) dense or sparse matrix or the matrix-free operator implementing
+///   [LinearOperator::apply_transpose]
+/// * `rank` -- the number `k` of singular triplets (`1 ≤ k ≤ min(m,n)`)
+/// * `oversampling` -- the number of extra samples (e.g., 5 or 10) improving the accuracy
+///
+/// # Output
+///
+/// * `s` -- (k) singular values
+/// * `u` -- (m,k) left singular vectors
+/// * `vt` -- (k,n) transposed right singular vectors
+///
+/// **Note:** The random test matrix is generated with a fixed seed; thus, the results are reproducible.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_sparse::{randomized_svd, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // rank-1 matrix a = x ⋅ yᵀ with ‖x‖ = 3 and ‖y‖ = 2
+///     let a = Matrix::from(&[
+///         [2.0, 0.0],
+///         [4.0, 0.0],
+///         [4.0, 0.0],
+///     ]);
+///     let mut s = Vector::new(1);
+///     let mut u = Matrix::new(3, 1);
+///     let mut vt = Matrix::new(1, 2);
+///     randomized_svd(&mut s, &mut u, &mut vt, &a, 1, 1)?;
+///     assert_eq!(format!("{:.6}", s[0]), "6.000000");
+///     Ok(())
+/// }
+/// ```
+pub fn randomized_svd(
+    s: &mut Vector,
+    u: &mut Matrix,
+    vt: &mut Matrix,
+    a: &dyn LinearOperator,
+    rank: usize,
+    oversampling: usize,
+) -> Result<(), StrError> {
+    let (m, n) = a.dims();
+    let min_mn = usize::min(m, n);
+    if rank < 1 || rank > min_mn {
+        return Err("rank must satisfy 1 ≤ rank ≤ min(m,n)");
+    }
+    if s.dim() != rank {
+        return Err("s vector must have dimension equal to rank");
+    }
+    if u.dims() != (m, rank) {
+        return Err("u matrix must have dimensions (m,rank)");
+    }
+    if vt.dims() != (rank, n) {
+        return Err("vt matrix must have dimensions (rank,n)");
+    }
+    let l = usize::min(rank + oversampling, min_mn);
+
+    // sample the range of a: y = a ⋅ ω
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut omega = vec![Vector::new(n); l];
+    for col in omega.iter_mut() {
+        for i in 0..n {
+            col[i] = StandardNormal.sample(&mut rng);
+        }
+    }
+    let mut q = vec![Vector::new(m); l];
+    apply_block(&mut q, a, &omega, false)?;
+    orthonormalize(&mut q);
+
+    // power iterations: q ← orth(a ⋅ orth(aᵀ ⋅ q))
+    let mut z = omega;
+    for _ in 0..POWER_ITERATIONS {
+        apply_block(&mut z, a, &q, true)?;
+        orthonormalize(&mut z);
+        apply_block(&mut q, a, &z, false)?;
+        orthonormalize(&mut q);
+    }
+
+    // project: bᵀ = aᵀ ⋅ q = q₂ ⋅ r (thin QR)
+    apply_block(&mut z, a, &q, true)?;
+    let r = orthonormalize(&mut z);
+
+    // small SVD: r = uᵣ ⋅ Σ ⋅ vᵣᵀ; thus, a ≈ q ⋅ bᵀᵀ = (q ⋅ vᵣ) ⋅ Σ ⋅ (q₂ ⋅ uᵣ)ᵀ
+    let mut rr = r;
+    let mut sr = Vector::new(l);
+    let mut ur = Matrix::new(l, l);
+    let mut vtr = Matrix::new(l, l);
+    mat_svd(&mut sr, &mut ur, &mut vtr, &mut rr)?;
+    for c in 0..rank {
+        s[c] = sr[c];
+        for i in 0..m {
+            let sum: f64 = q.iter().enumerate().map(|(j, qj)| qj[i] * vtr.get(c, j)).sum();
+            u.set(i, c, sum);
+        }
+        for i in 0..n {
+            let sum: f64 = z.iter().enumerate().map(|(j, zj)| zj[i] * ur.get(j, c)).sum();
+            vt.set(c, i, sum);
+        }
+    }
+    Ok(())
+}
+
+/// Computes the columns `y[j] = a ⋅ x[j]` (or `aᵀ ⋅ x[j]` if `transpose`)
+fn apply_block(y: &mut [Vector], a: &dyn LinearOperator, x: &[Vector], transpose: bool) -> Result<(), StrError> {
+    for (yj, xj) in y.iter_mut().zip(x) {
+        if transpose {
+            a.apply_transpose(yj, xj)?;
+        } else {
+            a.apply(yj, xj)?;
+        }
+    }
+    Ok(())
+}
+
+/// Orthonormalizes the columns by the modified Gram-Schmidt method with re-orthogonalization
+///
+/// Returns the (l,l) upper triangular matrix `r` such that `x_original = x_orthonormal ⋅ r`.
+/// Linearly dependent columns are replaced by zero (with a zero diagonal entry in `r`).
+fn orthonormalize(x: &mut [Vector]) -> Matrix {
+    let l = x.len();
+    let mut r = Matrix::new(l, l);
+    for j in 0..l {
+        let (done, rest) = x.split_at_mut(j);
+        let xj = &mut rest[0];
+        let norm_initial = vec_norm(xj, Norm::Euc);
+        for _ in 0..2 {
+            for (i, qi) in done.iter().enumerate() {
+                let rij = vec_inner(qi, xj);
+                r.add(i, j, rij);
+                for k in 0..xj.dim() {
+                    xj[k] -= rij * qi[k];
+                }
+            }
+        }
+        let norm = vec_norm(xj, Norm::Euc);
+        if norm <= 1e-14 * norm_initial || norm == 0.0 {
+            xj.fill(0.0);
+        } else {
+            r.set(j, j, norm);
+            for k in 0..xj.dim() {
+                xj[k] /= norm;
+            }
+        }
+    }
+    r
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{orthonormalize, randomized_svd};
+    use crate::{SparseTriplet, TripletSymmetry};
+    use russell_chk::vec_approx_eq;
+    use russell_lab::{vec_inner, Matrix, Vector};
+
+    #[test]
+    fn orthonormalize_works() {
+        let mut x = vec![
+            Vector::from(&[1.0, 1.0, 0.0]),
+            Vector::from(&[2.0, 2.0, 0.0]), // dependent
+            Vector::from(&[1.0, 0.0, 1.0]),
+        ];
+        let original = x.clone();
+        let r = orthonormalize(&mut x);
+        assert_eq!(x[1].as_data(), &[0.0, 0.0, 0.0]);
+        for i in [0, 2] {
+            for j in [0, 2] {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!(f64::abs(vec_inner(&x[i], &x[j]) - expected) < 1e-15);
+            }
+        }
+        // x_original = x ⋅ r
+        for (j, xj_original) in original.iter().enumerate() {
+            let mut col = Vector::new(3);
+            for (i, xi) in x.iter().enumerate() {
+                for k in 0..3 {
+                    col[k] += xi[k] * r.get(i, j);
+                }
+            }
+            vec_approx_eq(col.as_data(), xj_original.as_data(), 1e-15);
+        }
+        assert_eq!(r.get(1, 0), 0.0);
+        assert_eq!(r.get(1, 1), 0.0);
+    }
+
+    #[test]
+    fn randomized_svd_captures_errors() {
+        let a = Matrix::new(3, 2);
+        let mut s = Vector::new(1);
+        let mut u = Matrix::new(3, 1);
+        let mut vt = Matrix::new(1, 2);
+        assert_eq!(
+            randomized_svd(&mut s, &mut u, &mut vt, &a, 0, 2).err(),
+            Some("rank must satisfy 1 ≤ rank ≤ min(m,n)")
+        );
+        assert_eq!(
+            randomized_svd(&mut s, &mut u, &mut vt, &a, 3, 2).err(),
+            Some("rank must satisfy 1 ≤ rank ≤ min(m,n)")
+        );
+        assert_eq!(
+            randomized_svd(&mut Vector::new(2), &mut u, &mut vt, &a, 1, 2).err(),
+            Some("s vector must have dimension equal to rank")
+        );
+        assert_eq!(
+            randomized_svd(&mut s, &mut Matrix::new(2, 1), &mut vt, &a, 1, 2).err(),
+            Some("u matrix must have dimensions (m,rank)")
+        );
+        assert_eq!(
+            randomized_svd(&mut s, &mut u, &mut Matrix::new(1, 3), &a, 1, 2).err(),
+            Some("vt matrix must have dimensions (rank,n)")
+        );
+    }
+
+    #[test]
+    fn randomized_svd_works() {
+        // exact rank-2 matrix a = 5 ⋅ x₁ ⋅ y₁ᵀ + 2 ⋅ x₂ ⋅ y₂ᵀ (orthonormal x and y)
+        let (m, n) = (6, 5);
+        let x1 = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0].map(|v| v / f64::sqrt(6.0));
+        let x2 = [1.0, -1.0, 1.0, -1.0, 1.0, -1.0].map(|v| v / f64::sqrt(6.0));
+        let y1 = [1.0, 0.0, 0.0, 0.0, 0.0];
+        let y2 = [0.0, 0.6, 0.8, 0.0, 0.0];
+        let mut a = Matrix::new(m, n);
+        for i in 0..m {
+            for j in 0..n {
+                a.set(i, j, 5.0 * x1[i] * y1[j] + 2.0 * x2[i] * y2[j]);
+            }
+        }
+        let mut s = Vector::new(2);
+        let mut u = Matrix::new(m, 2);
+        let mut vt = Matrix::new(2, n);
+        randomized_svd(&mut s, &mut u, &mut vt, &a, 2, 2).unwrap();
+        vec_approx_eq(s.as_data(), &[5.0, 2.0], 1e-13);
+        for i in 0..m {
+            for j in 0..n {
+                let mut aij = 0.0;
+                for k in 0..2 {
+                    aij += u.get(i, k) * s[k] * vt.get(k, j);
+                }
+                assert!(f64::abs(aij - a.get(i, j)) < 1e-13);
+            }
+        }
+    }
+
+    #[test]
+    fn randomized_svd_works_with_sparse_matrix() {
+        // diagonal matrix with singular values 10, 9, ..., 1
+        let n = 10;
+        let mut trip = SparseTriplet::new(n, n, TripletSymmetry::None).unwrap();
+        for i in 0..n {
+            trip.put(i, i, (n - i) as f64).unwrap();
+        }
+        let mut s = Vector::new(3);
+        let mut u = Matrix::new(n, 3);
+        let mut vt = Matrix::new(3, n);
+        randomized_svd(&mut s, &mut u, &mut vt, &trip, 3, 7).unwrap();
+        vec_approx_eq(s.as_data(), &[10.0, 9.0, 8.0], 1e-12);
+    }
+}
@@ -1,10 +1,36 @@
 use super::SparseTriplet;
-use crate::StrError;
+use crate::{ComplexSparseTriplet, StrError, TripletSymmetry};
+use num_complex::Complex64;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader};
+
+/// Holds the metadata given in the header and dimensions lines of a MatrixMarket file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MatrixMarketHeader {
+    /// The kind (format) of the data; e.g., "coordinate"
+    pub kind: &'static str,
+
+    /// The type of the values; i.e., "real" or "complex"
+    pub field: &'static str,
+
+    /// The symmetry of the matrix
+    ///
+    /// [TripletSymmetry::LowerTriangular] if the `symmetric` keyword is present; otherwise [TripletSymmetry::None]
+    pub symmetry: TripletSymmetry,
+
+    /// The number of rows
+    pub nrow: usize,
+
+    /// The number of columns
+    pub ncol: usize,
+
+    /// The number of non-zero values (the number of (i,j,aij) lines in the file)
+    pub nnz: usize,
+}
 
 struct MatrixMarketData {
     // header
+    complex: bool,
     symmetric: bool,
 
     // dimensions
@@ -13,15 +39,17 @@ struct MatrixMarketData {
     nnz: i32, // number of non-zeros
 
     // current triple
-    i: i32,   // current i-index
-    j: i32,   // current j-index
-    aij: f64, // current aij-value
-    pos: i32, // current position in the list of triples
+    i: i32,        // current i-index
+    j: i32,        // current j-index
+    aij: f64,      // current aij-value (real part if complex)
+    aij_imag: f64, // current imaginary part of aij (if complex)
+    pos: i32,      // current position in the list of triples
 }
 
 impl MatrixMarketData {
     fn new() -> Self {
         MatrixMarketData {
+            complex: false,
             symmetric: false,
             m: 0,
             n: 0,
@@ -29,6 +57,7 @@ impl MatrixMarketData {
             i: 0,
             j: 0,
             aij: 0.0,
+            aij_imag: 0.0,
             pos: 0,
         }
     }
@@ -65,11 +94,11 @@ impl MatrixMarketData {
         }
 
         match data.next() {
-            Some(v) => {
-                if v != "real" {
-                    return Err("after %%MatrixMarket, the third option must be \"real\"");
-                }
-            }
+            Some(v) => match v {
+                "real" => self.complex = false,
+                "complex" => self.complex = true,
+                _ => return Err("after %%MatrixMarket, the third option must be either \"real\" or \"complex\""),
+            },
             None => return Err("cannot find the third option in the header line"),
         }
 
@@ -146,6 +175,13 @@ impl MatrixMarketData {
             None => return Err("cannot read value aij"),
         };
 
+        if self.complex {
+            match data.next() {
+                Some(v) => self.aij_imag = v.parse().map_err(|_| "cannot parse imaginary part of aij")?,
+                None => return Err("cannot read imaginary part of aij"),
+            };
+        }
+
         self.i -= 1; // MatrixMarket is one-based
         self.j -= 1;
 
@@ -166,31 +202,22 @@ impl MatrixMarketData {
 /// # Input
 ///
 /// * `filepath` -- The full file path with filename
-/// * `sym_mirror` -- Tells the reader to mirror the **off diagonal** entries,
-///                   if the symmetric option is found in the header.
 ///
-/// ## Remarks on sym_mirror
-///
-/// ```text
-/// if i != j, read line and set a(i,j) = a(j,i) = line data
-/// ```
+/// ## Remarks on symmetric matrices
 ///
 /// If the matrix is symmetric, only entries in the **lower triangular** portion
-/// are present in the MatrixMarket file (see reference). However, some solvers
-/// (e.g., UMFPACK) require the complete sparse dataset (both off-diagonals),
-/// even if the matrix is symmetric. Other solvers (e.g. Mu-M-P-S) must **not**
-/// receive both off-diagonal sides when working with symmetric matrices.
-/// Therefore, the user has to decide when to use the `sym_mirror` flag.
-///
-/// If `sym_mirror` is true, the reader will set `nnz` (number of non-zero values)
-/// with twice the specified `nnz` value because we cannot know how many entries
-/// are on the diagonal until the whole file is read. Nonetheless, the `SparseTriplet`
-/// can be used normally by the user, since this information is internal to `SparseTriplet`.
+/// are present in the MatrixMarket file (see reference). Thus, the resulting triplet
+/// is created with the [TripletSymmetry::LowerTriangular] option. Note that some solvers
+/// (e.g., UMFPACK) require the complete sparse dataset (both off-diagonals), even if the
+/// matrix is symmetric, whereas other solvers (e.g. Mu-M-P-S) must **not** receive both
+/// off-diagonal sides. The Solver takes care of mirroring the entries when needed.
 ///
 /// # Output
 ///
-/// * A SparseTriplet or an error message
-/// * Returns true if the `symmetric` keyword is present in the header
+/// * A SparseTriplet (sized with the nnz given in the file) or an error message
+/// * The metadata given in the header and dimensions lines (see [MatrixMarketHeader])
+/// * The symmetry of the triplet is [TripletSymmetry::LowerTriangular] if the `symmetric`
+///   keyword is present in the header; otherwise, it is [TripletSymmetry::None]
 ///
 /// # Panics
 ///
@@ -238,7 +265,8 @@ impl MatrixMarketData {
 ///
 /// * The first line is the **header line**
 /// * The header must contain `%%MatrixMarket matrix coordinate real` followed by `general` or `symmetric` (separated by spaces)
-/// * Thus, this function can only read the `coordinate` and `real` combination for now
+/// * Thus, this function can only read the `coordinate` and `real` combination
+///   (see [read_matrix_market_complex] for the `complex` field)
 /// * After the header line, the percentage character marks a comment line
 /// * After the header line, a line with dimensions `m n nnz` must follow
 /// * `m`, `n`, and `nnz` are the number of columns, rows, and non-zero values
@@ -269,11 +297,11 @@ impl MatrixMarketData {
 ///
 /// ```
 /// use russell_lab::Matrix;
-/// use russell_sparse::{read_matrix_market, StrError};
+/// use russell_sparse::{read_matrix_market, StrError, TripletSymmetry};
 ///
 /// fn main() -> Result<(), StrError> {
 ///     let filepath = "./data/matrix_market/simple_gen.mtx".to_string();
-///     let (trip, symmetric) = read_matrix_market(&filepath, false)?;
+///     let (trip, header) = read_matrix_market(&filepath)?;
 ///     let neq = trip.neq();
 ///     let mut a = Matrix::new(neq, neq);
 ///     trip.to_matrix(&mut a)?;
@@ -282,7 +310,8 @@ impl MatrixMarketData {
 ///                    │ 3 4 0 │\n\
 ///                    │ 0 0 5 │\n\
 ///                    └       ┘";
-///     assert_eq!(symmetric, false);
+///     assert_eq!(trip.symmetry(), TripletSymmetry::None);
+///     assert_eq!((header.nrow, header.ncol, header.nnz), (3, 3, 5));
 ///     assert_eq!(format!("{}", a), correct);
 ///     Ok(())
 /// }
@@ -305,11 +334,11 @@ impl MatrixMarketData {
 ///
 /// ```
 /// use russell_lab::Matrix;
-/// use russell_sparse::{read_matrix_market, StrError};
+/// use russell_sparse::{read_matrix_market, StrError, TripletSymmetry};
 ///
 /// fn main() -> Result<(), StrError> {
 ///     let filepath = "./data/matrix_market/simple_sym.mtx".to_string();
-///     let (trip, symmetric) = read_matrix_market(&filepath, true)?;
+///     let (trip, header) = read_matrix_market(&filepath)?;
 ///     let neq = trip.neq();
 ///     let mut a = Matrix::new(neq, neq);
 ///     trip.to_matrix(&mut a)?;
@@ -318,12 +347,13 @@ impl MatrixMarketData {
 ///                    │ 2 3 4 │\n\
 ///                    │ 0 4 0 │\n\
 ///                    └       ┘";
-///     assert_eq!(symmetric, true);
+///     assert_eq!(trip.symmetry(), TripletSymmetry::LowerTriangular);
+///     assert_eq!(header.symmetry, TripletSymmetry::LowerTriangular);
 ///     assert_eq!(format!("{}", a), correct);
 ///     Ok(())
 /// }
 /// ```
-pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(SparseTriplet, bool), StrError> {
+pub fn read_matrix_market(filepath: &String) -> Result<(SparseTriplet, MatrixMarketHeader), StrError> {
     let input = File::open(filepath).map_err(|_| "cannot open file")?;
     let buffered = BufReader::new(input);
     let mut lines_iter = buffered.lines();
@@ -331,36 +361,19 @@ pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(Sparse
     // auxiliary data structure
     let mut data = MatrixMarketData::new();
 
-    // read first line
-    let header = match lines_iter.next() {
-        Some(v) => v.unwrap(), // must panic because no error expected here
-        None => return Err("file is empty"),
-    };
-
-    // parse header
-    data.parse_header(&header)?;
+    // read header and dimensions
+    let header = read_header_and_dimensions(&mut lines_iter, &mut data)?;
 
-    // read and parse dimensions
-    loop {
-        let line = lines_iter.next().unwrap().unwrap(); // must panic because no error expected here
-        if data.parse_dimensions(&line)? {
-            break;
-        }
+    // check field and dimensions
+    if data.complex {
+        return Err("cannot read complex matrix; use read_matrix_market_complex instead");
     }
-
-    // check dimensions
     if data.m != data.n {
         return Err("cannot read non-square matrix");
     }
 
-    // set max number of entries
-    let mut max = data.nnz;
-    if data.symmetric && sym_mirror {
-        max = 2 * data.nnz;
-    }
-
-    // allocate triplet
-    let mut trip = SparseTriplet::new(data.m as usize, max as usize)?;
+    // allocate triplet (sized with the nnz given in the file)
+    let mut trip = SparseTriplet::new(header.nrow, header.nnz, header.symmetry)?;
 
     // read and parse triples
     loop {
@@ -369,9 +382,6 @@ pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(Sparse
                 let line = v.unwrap(); // must panic because no error expected here
                 if data.parse_triple(&line)? {
                     trip.put(data.i as usize, data.j as usize, data.aij)?;
-                    if data.symmetric && sym_mirror && data.i != data.j {
-                        trip.put(data.j as usize, data.i as usize, data.aij)?;
-                    }
                 }
             }
             None => break,
@@ -383,14 +393,168 @@ pub fn read_matrix_market(filepath: &String, sym_mirror: bool) -> Result<(Sparse
         return Err("not all triples (i,j,aij) have been found");
     }
 
-    Ok((trip, data.symmetric))
+    Ok((trip, header))
+}
+
+/// Reads a MatrixMarket file with real or complex values into a ComplexSparseTriplet
+///
+/// **Note:** This function works only with square matrices.
+///
+/// The header must contain `%%MatrixMarket matrix coordinate` followed by `real` or `complex`
+/// and then `general` or `symmetric`. With the `complex` field, each data line holds the
+/// real and imaginary parts of aij, i.e., `i j re(aij) im(aij)`. With the `real` field,
+/// the imaginary parts are set to zero. See [read_matrix_market] for further remarks.
+///
+/// **Note:** `symmetric` means complex symmetric (`a = aᵀ`); the `hermitian` keyword is not supported.
+///
+/// # Input
+///
+/// * `filepath` -- The full file path with filename
+///
+/// # Output
+///
+/// * A ComplexSparseTriplet (sized with the nnz given in the file) or an error message
+/// * The metadata given in the header and dimensions lines (see [MatrixMarketHeader])
+///
+/// # Example
+///
+/// Given the following `simple_complex.mtx` file:
+///
+/// ```text
+/// %%MatrixMarket matrix coordinate complex general
+/// 3 3  4
+///  1 1  1.0  1.0
+///  1 2  2.0 -1.0
+///  2 2  0.0  3.0
+///  3 3  5.0  0.0
+/// ```
+///
+/// Read the data:
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_sparse::{read_matrix_market_complex, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let filepath = "./data/matrix_market/simple_complex.mtx".to_string();
+///     let (trip, header) = read_matrix_market_complex(&filepath)?;
+///     assert_eq!(header.field, "complex");
+///     assert_eq!((header.nrow, header.ncol, header.nnz), (3, 3, 4));
+///     assert_eq!(trip.get(0, 1), Complex64::new(2.0, -1.0));
+///     assert_eq!(trip.get(1, 1), Complex64::new(0.0, 3.0));
+///     Ok(())
+/// }
+/// ```
+pub fn read_matrix_market_complex(filepath: &String) -> Result<(ComplexSparseTriplet, MatrixMarketHeader), StrError> {
+    let input = File::open(filepath).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(input);
+    let mut lines_iter = buffered.lines();
+
+    // auxiliary data structure
+    let mut data = MatrixMarketData::new();
+
+    // read header and dimensions
+    let header = read_header_and_dimensions(&mut lines_iter, &mut data)?;
+
+    // check dimensions
+    if data.m != data.n {
+        return Err("cannot read non-square matrix");
+    }
+
+    // allocate triplet (sized with the nnz given in the file)
+    let mut trip = ComplexSparseTriplet::new(header.nrow, header.nnz, header.symmetry)?;
+
+    // read and parse triples
+    for v in lines_iter {
+        let line = v.unwrap(); // must panic because no error expected here
+        if data.parse_triple(&line)? {
+            let aij = Complex64::new(data.aij, if data.complex { data.aij_imag } else { 0.0 });
+            trip.put(data.i as usize, data.j as usize, aij)?;
+        }
+    }
+
+    // check data
+    if data.pos != data.nnz {
+        return Err("not all triples (i,j,aij) have been found");
+    }
+
+    Ok((trip, header))
+}
+
+/// Reads only the header and dimensions of a MatrixMarket file
+///
+/// This function is useful to estimate the memory required by a matrix (e.g., using the nnz value)
+/// before reading the whole file; the data lines are not read. See also [read_matrix_market].
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::{read_matrix_market_header, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     let filepath = "./data/matrix_market/simple_sym.mtx".to_string();
+///     let header = read_matrix_market_header(&filepath)?;
+///     assert_eq!(header.kind, "coordinate");
+///     assert_eq!(header.field, "real");
+///     assert_eq!(header.symmetry, TripletSymmetry::LowerTriangular);
+///     assert_eq!((header.nrow, header.ncol, header.nnz), (3, 3, 4));
+///     Ok(())
+/// }
+/// ```
+pub fn read_matrix_market_header(filepath: &String) -> Result<MatrixMarketHeader, StrError> {
+    let input = File::open(filepath).map_err(|_| "cannot open file")?;
+    let buffered = BufReader::new(input);
+    let mut lines_iter = buffered.lines();
+    let mut data = MatrixMarketData::new();
+    read_header_and_dimensions(&mut lines_iter, &mut data)
+}
+
+/// Reads and parses the header and dimensions lines (first pass)
+fn read_header_and_dimensions<B: BufRead>(
+    lines_iter: &mut io::Lines<B>,
+    data: &mut MatrixMarketData,
+) -> Result<MatrixMarketHeader, StrError> {
+    // read first line
+    let header = match lines_iter.next() {
+        Some(v) => v.unwrap(), // must panic because no error expected here
+        None => return Err("file is empty"),
+    };
+
+    // parse header
+    data.parse_header(&header)?;
+
+    // read and parse dimensions
+    loop {
+        let line = lines_iter.next().unwrap().unwrap(); // must panic because no error expected here
+        if data.parse_dimensions(&line)? {
+            break;
+        }
+    }
+
+    // results
+    Ok(MatrixMarketHeader {
+        kind: "coordinate",
+        field: if data.complex { "complex" } else { "real" },
+        symmetry: if data.symmetric {
+            TripletSymmetry::LowerTriangular
+        } else {
+            TripletSymmetry::None
+        },
+        nrow: data.m as usize,
+        ncol: data.n as usize,
+        nnz: data.nnz as usize,
+    })
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{read_matrix_market, MatrixMarketData};
+    use super::{
+        read_matrix_market, read_matrix_market_complex, read_matrix_market_header, MatrixMarketData, MatrixMarketHeader,
+    };
+    use crate::TripletSymmetry;
+    use num_complex::Complex64;
     use russell_lab::Matrix;
 
     #[test]
@@ -430,7 +594,7 @@ mod tests {
         );
         assert_eq!(
             data.parse_header(&String::from("%%MatrixMarket matrix    coordinate  wrong")),
-            Err("after %%MatrixMarket, the third option must be \"real\""),
+            Err("after %%MatrixMarket, the third option must be either \"real\" or \"complex\""),
         );
 
         assert_eq!(
@@ -535,40 +699,75 @@ mod tests {
     #[test]
     fn read_matrix_market_handle_wrong_files() {
         assert_eq!(
-            read_matrix_market(&String::from("__wrong__"), false).err(),
+            read_matrix_market(&String::from("__wrong__")).err(),
             Some("cannot open file")
         );
         assert_eq!(
-            read_matrix_market(&String::from("./data/matrix_market/bad_empty_file.mtx"), false).err(),
+            read_matrix_market(&String::from("./data/matrix_market/bad_empty_file.mtx")).err(),
             Some("file is empty")
         );
         assert_eq!(
-            read_matrix_market(&String::from("./data/matrix_market/bad_wrong_header.mtx"), false).err(),
+            read_matrix_market(&String::from("./data/matrix_market/bad_wrong_header.mtx")).err(),
             Some("after %%MatrixMarket, the first option must be \"matrix\"")
         );
         assert_eq!(
-            read_matrix_market(&String::from("./data/matrix_market/bad_wrong_dims.mtx"), false).err(),
+            read_matrix_market(&String::from("./data/matrix_market/bad_wrong_dims.mtx")).err(),
             Some("found invalid (zero or negative) dimensions")
         );
         assert_eq!(
-            read_matrix_market(&String::from("./data/matrix_market/bad_rectangular.mtx"), false).err(),
+            read_matrix_market(&String::from("./data/matrix_market/bad_rectangular.mtx")).err(),
             Some("cannot read non-square matrix")
         );
         assert_eq!(
-            read_matrix_market(&String::from("./data/matrix_market/bad_missing_data.mtx"), false).err(),
+            read_matrix_market(&String::from("./data/matrix_market/bad_missing_data.mtx")).err(),
             Some("not all triples (i,j,aij) have been found")
         );
         assert_eq!(
-            read_matrix_market(&String::from("./data/matrix_market/bad_many_lines.mtx"), false).err(),
+            read_matrix_market(&String::from("./data/matrix_market/bad_many_lines.mtx")).err(),
             Some("there are more (i,j,aij) triples than specified")
         );
     }
 
+    #[test]
+    fn read_matrix_market_header_works() {
+        assert_eq!(
+            read_matrix_market_header(&String::from("__wrong__")).err(),
+            Some("cannot open file")
+        );
+        assert_eq!(
+            read_matrix_market_header(&String::from("./data/matrix_market/bad_empty_file.mtx")).err(),
+            Some("file is empty")
+        );
+        let header = read_matrix_market_header(&String::from("./data/matrix_market/bad_rectangular.mtx")).unwrap();
+        assert_eq!((header.nrow, header.ncol, header.nnz), (1, 2, 3));
+        let header = read_matrix_market_header(&String::from("./data/matrix_market/ok1.mtx")).unwrap();
+        assert_eq!(
+            header,
+            MatrixMarketHeader {
+                kind: "coordinate",
+                field: "real",
+                symmetry: TripletSymmetry::None,
+                nrow: 5,
+                ncol: 5,
+                nnz: 12,
+            }
+        );
+    }
+
+    #[test]
+    fn read_matrix_market_returns_header() {
+        let filepath = "./data/matrix_market/ok3.mtx".to_string();
+        let (trip, header) = read_matrix_market(&filepath).unwrap();
+        assert_eq!(header.symmetry, TripletSymmetry::LowerTriangular);
+        assert_eq!((header.nrow, header.ncol, header.nnz), (5, 5, 7));
+        assert_eq!(trip.max, header.nnz);
+    }
+
     #[test]
     fn read_matrix_market_works() {
         let filepath = "./data/matrix_market/ok1.mtx".to_string();
-        let (trip, sym) = read_matrix_market(&filepath, false).unwrap();
-        assert_eq!(sym, false);
+        let (trip, _) = read_matrix_market(&filepath).unwrap();
+        assert_eq!(trip.symmetry, TripletSymmetry::None);
         assert_eq!((trip.neq, trip.pos, trip.max), (5, 12, 12));
         assert_eq!(trip.indices_i, &[0, 1, 0, 2, 4, 1, 2, 3, 4, 2, 1, 4]);
         assert_eq!(trip.indices_j, &[0, 0, 1, 1, 1, 2, 2, 2, 2, 3, 4, 4]);
@@ -581,11 +780,12 @@ mod tests {
     #[test]
     fn read_matrix_market_sym_triangle_works() {
         let filepath = "./data/matrix_market/ok2.mtx".to_string();
-        let (trip, sym) = read_matrix_market(&filepath, false).unwrap();
-        assert_eq!(sym, true);
+        let (trip, _) = read_matrix_market(&filepath).unwrap();
+        assert_eq!(trip.symmetry, TripletSymmetry::LowerTriangular);
         assert_eq!((trip.neq, trip.pos, trip.max), (5, 15, 15));
-        assert_eq!(trip.indices_i, &[0, 1, 2, 3, 4, 0, 0, 0, 0, 1, 1, 1, 2, 2, 3]);
-        assert_eq!(trip.indices_j, &[0, 1, 2, 3, 4, 1, 2, 3, 4, 2, 3, 4, 3, 4, 4]);
+        // the upper triangular entries in the file are moved to the lower triangle
+        assert_eq!(trip.indices_i, &[0, 1, 2, 3, 4, 1, 2, 3, 4, 2, 3, 4, 3, 4, 4]);
+        assert_eq!(trip.indices_j, &[0, 1, 2, 3, 4, 0, 0, 0, 0, 1, 1, 1, 2, 2, 3]);
         assert_eq!(
             trip.values_aij,
             &[2.0, 2.0, 9.0, 7.0, 8.0, 1.0, 1.0, 3.0, 2.0, 2.0, 1.0, 1.0, 1.0, 5.0, 1.0],
@@ -593,17 +793,14 @@ mod tests {
     }
 
     #[test]
-    fn read_matrix_market_sym_mirror_works() {
+    fn read_matrix_market_sym_works() {
         let filepath = "./data/matrix_market/ok3.mtx".to_string();
-        let (trip, sym) = read_matrix_market(&filepath, true).unwrap();
-        assert_eq!(sym, true);
-        assert_eq!((trip.neq, trip.pos, trip.max), (5, 11, 14));
-        assert_eq!(trip.indices_i, &[0, 1, 0, 2, 1, 3, 2, 3, 4, 1, 4, 0, 0, 0]);
-        assert_eq!(trip.indices_j, &[0, 0, 1, 1, 2, 2, 3, 3, 1, 4, 4, 0, 0, 0]);
-        assert_eq!(
-            trip.values_aij,
-            &[2.0, 3.0, 3.0, -1.0, -1.0, 2.0, 2.0, 3.0, 6.0, 6.0, 1.0, 0.0, 0.0, 0.0]
-        );
+        let (trip, _) = read_matrix_market(&filepath).unwrap();
+        assert_eq!(trip.symmetry, TripletSymmetry::LowerTriangular);
+        assert_eq!((trip.neq, trip.pos, trip.max), (5, 7, 7));
+        assert_eq!(trip.indices_i, &[0, 1, 2, 3, 3, 4, 4]);
+        assert_eq!(trip.indices_j, &[0, 0, 1, 2, 3, 1, 4]);
+        assert_eq!(trip.values_aij, &[2.0, 3.0, -1.0, 2.0, 3.0, 6.0, 1.0]);
         let mut a = Matrix::new(5, 5);
         trip.to_matrix(&mut a).unwrap();
         let correct = "┌                ┐\n\
@@ -615,4 +812,60 @@ mod tests {
                        └                ┘";
         assert_eq!(format!("{}", a), correct);
     }
+
+    #[test]
+    fn parse_triple_complex_captures_errors() {
+        let mut data = MatrixMarketData::new();
+        data.complex = true;
+        data.m = 2;
+        data.n = 2;
+        data.nnz = 1;
+        assert_eq!(
+            data.parse_triple(&String::from(" 1 1  1.0 \n")).err(),
+            Some("cannot read imaginary part of aij")
+        );
+        assert_eq!(
+            data.parse_triple(&String::from(" 1 1  1.0 wrong")).err(),
+            Some("cannot parse imaginary part of aij")
+        );
+        assert_eq!(data.parse_triple(&String::from(" 2 1  1.0 -2.0")), Ok(true));
+        assert_eq!((data.i, data.j, data.aij, data.aij_imag), (1, 0, 1.0, -2.0));
+    }
+
+    #[test]
+    fn read_matrix_market_complex_works() {
+        let filepath = "./data/matrix_market/simple_complex.mtx".to_string();
+        assert_eq!(
+            read_matrix_market(&filepath).err(),
+            Some("cannot read complex matrix; use read_matrix_market_complex instead")
+        );
+        let header = read_matrix_market_header(&filepath).unwrap();
+        assert_eq!(header.field, "complex");
+        let (trip, _) = read_matrix_market_complex(&filepath).unwrap();
+        assert_eq!(trip.symmetry, TripletSymmetry::None);
+        assert_eq!((trip.neq, trip.pos, trip.max), (3, 4, 4));
+        assert_eq!(trip.indices_i, &[0, 0, 1, 2]);
+        assert_eq!(trip.indices_j, &[0, 1, 1, 2]);
+        assert_eq!(
+            trip.values_aij,
+            &[
+                Complex64::new(1.0, 1.0),
+                Complex64::new(2.0, -1.0),
+                Complex64::new(0.0, 3.0),
+                Complex64::new(5.0, 0.0)
+            ]
+        );
+
+        // real files are read with zero imaginary parts
+        let filepath = "./data/matrix_market/ok3.mtx".to_string();
+        let (trip, header) = read_matrix_market_complex(&filepath).unwrap();
+        assert_eq!(header.field, "real");
+        assert_eq!(trip.symmetry, TripletSymmetry::LowerTriangular);
+        assert_eq!(trip.get(0, 1), Complex64::new(3.0, 0.0));
+        assert_eq!(trip.get(4, 4), Complex64::new(1.0, 0.0));
+        assert_eq!(
+            read_matrix_market_complex(&String::from("./data/matrix_market/bad_rectangular.mtx")).err(),
+            Some("cannot read non-square matrix")
+        );
+    }
 }
@@ -0,0 +1,238 @@
+use crate::StrError;
+use russell_lab::{Matrix, Vector};
+use russell_openblas::to_i32;
+
+/// Holds triples (i,j,aij) representing a rectangular sparse matrix
+///
+/// # Remarks
+///
+/// - This structure mirrors [crate::SparseTriplet] (see its remarks), but the number of rows may differ from the number of columns
+/// - Entries with repeated (i,j) indices are allowed and are summed up (e.g., by `get`, `to_matrix`, and `mat_vec_mul`)
+/// - The rectangular system `a ⋅ x = b` may be solved in the least-squares sense with [crate::solve_lsqr]
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_sparse::{RectSparseTriplet, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut trip = RectSparseTriplet::new(2, 3, 4)?;
+///     trip.put(0, 0, 1.0)?;
+///     trip.put(0, 2, 2.0)?;
+///     trip.put(1, 1, 3.0)?;
+///     trip.put(1, 2, 4.0)?;
+///     let mut a = Matrix::new(2, 3);
+///     trip.to_matrix(&mut a)?;
+///     assert_eq!(
+///         format!("{}", a),
+///         "┌       ┐\n\
+///          │ 1 0 2 │\n\
+///          │ 0 3 4 │\n\
+///          └       ┘"
+///     );
+///     let v = trip.mat_vec_mul(&Vector::from(&[1.0, 1.0, 1.0]))?;
+///     assert_eq!(v.as_data(), &[3.0, 7.0]);
+///     Ok(())
+/// }
+/// ```
+pub struct RectSparseTriplet {
+    pub(crate) nrow: usize,          // [i32] number of rows
+    pub(crate) ncol: usize,          // [i32] number of columns
+    pub(crate) pos: usize,           // [i32] current index => nnz in the end
+    pub(crate) max: usize,           // [i32] max allowed number of entries (may be > nnz)
+    pub(crate) indices_i: Vec<i32>,  // [nnz] indices i
+    pub(crate) indices_j: Vec<i32>,  // [nnz] indices j
+    pub(crate) values_aij: Vec<f64>, // [nnz] values aij
+}
+
+impl RectSparseTriplet {
+    /// Creates a new RectSparseTriplet representing a rectangular sparse matrix
+    ///
+    /// # Input
+    ///
+    /// * `nrow` -- The number of rows of the sparse matrix
+    /// * `ncol` -- The number of columns of the sparse matrix
+    /// * `max` -- The maximum number fo non-zero (nnz) values in the sparse matrix,
+    ///   including entries with repeated indices
+    pub fn new(nrow: usize, ncol: usize, max: usize) -> Result<Self, StrError> {
+        if nrow == 0 || ncol == 0 || max == 0 {
+            return Err("nrow, ncol, and max must be greater than zero");
+        }
+        Ok(RectSparseTriplet {
+            nrow,
+            ncol,
+            pos: 0,
+            max,
+            indices_i: vec![0; max],
+            indices_j: vec![0; max],
+            values_aij: vec![0.0; max],
+        })
+    }
+
+    /// Puts the next triple (i,j,aij) into the Triplet
+    pub fn put(&mut self, i: usize, j: usize, aij: f64) -> Result<(), StrError> {
+        if i >= self.nrow {
+            return Err("sparse matrix row index is out of bounds");
+        }
+        if j >= self.ncol {
+            return Err("sparse matrix column index is out of bounds");
+        }
+        if self.pos >= self.max {
+            return Err("current nnz (number of non-zeros) reached maximum limit");
+        }
+        self.indices_i[self.pos] = to_i32(i);
+        self.indices_j[self.pos] = to_i32(j);
+        self.values_aij[self.pos] = aij;
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Returns the (i,j) component of the represented matrix (repeated entries are summed up)
+    ///
+    /// **Note:** This function loops over all entries; thus, it is not efficient for large matrices.
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        let (i, j) = (to_i32(i), to_i32(j));
+        let mut sum = 0.0;
+        for p in 0..self.pos {
+            if self.indices_i[p] == i && self.indices_j[p] == j {
+                sum += self.values_aij[p];
+            }
+        }
+        sum
+    }
+
+    /// Returns the (nrow, ncol) dimensions of the matrix represented by this Triplet
+    pub fn dims(&self) -> (usize, usize) {
+        (self.nrow, self.ncol)
+    }
+
+    /// Returns the current number of non-zero values (nnz), including entries with repeated indices
+    pub fn nnz_current(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the maximum number of non-zero values (allocated size)
+    pub fn nnz_maximum(&self) -> usize {
+        self.max
+    }
+
+    /// Resets the position of the current non-zero value, allowing using "put" from scratch
+    pub fn reset(&mut self) {
+        self.pos = 0;
+    }
+
+    /// Converts the triplet data to a matrix, up to a limit
+    ///
+    /// # Input
+    ///
+    /// `a` -- (nrow_max, ncol_max) matrix to hold the triplet data.
+    ///  The output matrix may have fewer rows or fewer columns than the triplet data.
+    pub fn to_matrix(&self, a: &mut Matrix) -> Result<(), StrError> {
+        let (m, n) = a.dims();
+        if m > self.nrow || n > self.ncol {
+            return Err("wrong matrix dimensions");
+        }
+        a.fill(0.0);
+        for p in 0..self.pos {
+            let (i, j) = (self.indices_i[p] as usize, self.indices_j[p] as usize);
+            if i < m && j < n {
+                a.add(i, j, self.values_aij[p]);
+            }
+        }
+        Ok(())
+    }
+
+    /// Performs the matrix-vector multiplication
+    ///
+    /// ```text
+    ///  v  :=  a   ⋅  u
+    /// (m)   (m,n)   (n)
+    /// ```
+    pub fn mat_vec_mul(&self, u: &Vector) -> Result<Vector, StrError> {
+        if u.dim() != self.ncol {
+            return Err("u.ndim must equal ncol");
+        }
+        let mut v = Vector::new(self.nrow);
+        for p in 0..self.pos {
+            v[self.indices_i[p] as usize] += self.values_aij[p] * u[self.indices_j[p] as usize];
+        }
+        Ok(v)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::RectSparseTriplet;
+    use russell_lab::{Matrix, Vector};
+
+    #[test]
+    fn new_and_put_capture_errors() {
+        assert_eq!(
+            RectSparseTriplet::new(0, 3, 3).err(),
+            Some("nrow, ncol, and max must be greater than zero")
+        );
+        assert_eq!(
+            RectSparseTriplet::new(3, 0, 3).err(),
+            Some("nrow, ncol, and max must be greater than zero")
+        );
+        let mut trip = RectSparseTriplet::new(2, 3, 1).unwrap();
+        assert_eq!(
+            trip.put(2, 0, 1.0).err(),
+            Some("sparse matrix row index is out of bounds")
+        );
+        assert_eq!(
+            trip.put(0, 3, 1.0).err(),
+            Some("sparse matrix column index is out of bounds")
+        );
+        trip.put(1, 2, 1.0).unwrap();
+        assert_eq!(
+            trip.put(0, 0, 1.0).err(),
+            Some("current nnz (number of non-zeros) reached maximum limit")
+        );
+        let mut a = Matrix::new(3, 3);
+        assert_eq!(trip.to_matrix(&mut a).err(), Some("wrong matrix dimensions"));
+        assert_eq!(trip.mat_vec_mul(&Vector::new(2)).err(), Some("u.ndim must equal ncol"));
+    }
+
+    #[test]
+    fn getters_and_reset_work() {
+        let mut trip = RectSparseTriplet::new(3, 2, 4).unwrap();
+        assert_eq!(trip.dims(), (3, 2));
+        assert_eq!(trip.nnz_maximum(), 4);
+        trip.put(2, 1, 1.0).unwrap();
+        trip.put(2, 1, 0.5).unwrap();
+        assert_eq!(trip.nnz_current(), 2);
+        assert_eq!(trip.get(2, 1), 1.5);
+        assert_eq!(trip.get(1, 2), 0.0);
+        trip.reset();
+        assert_eq!(trip.nnz_current(), 0);
+        assert_eq!(trip.get(2, 1), 0.0);
+    }
+
+    #[test]
+    fn to_matrix_and_mat_vec_mul_work() {
+        let mut trip = RectSparseTriplet::new(3, 2, 4).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, 2.0).unwrap();
+        trip.put(2, 0, 3.0).unwrap();
+        trip.put(2, 1, 4.0).unwrap();
+        let mut a = Matrix::new(3, 2);
+        trip.to_matrix(&mut a).unwrap();
+        assert_eq!(
+            format!("{}", a),
+            "┌     ┐\n\
+             │ 1 0 │\n\
+             │ 0 2 │\n\
+             │ 3 4 │\n\
+             └     ┘"
+        );
+        let mut b = Matrix::new(2, 1);
+        trip.to_matrix(&mut b).unwrap();
+        assert_eq!(b.as_data(), &[1.0, 0.0]);
+        let v = trip.mat_vec_mul(&Vector::from(&[1.0, -1.0])).unwrap();
+        assert_eq!(v.as_data(), &[1.0, -2.0, -1.0]);
+    }
+}
@@ -0,0 +1,467 @@
+use super::{solve_pcg_jacobi, ConfigSolver, Solver, SparseCsr, SparseTriplet, TripletSymmetry};
+use crate::StrError;
+use russell_lab::{solve_lin_sys, Matrix, Vector};
+
+/// Defines the solution path selected by [solve_auto]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolutionPath {
+    /// Dense LU factorization with LAPACK (dgesv)
+    DenseLapack,
+
+    /// Sparse direct factorization with the [Solver] (UMFPACK or Mu-M-P-S)
+    SparseDirect,
+
+    /// Jacobi-preconditioned conjugate gradient (see [solve_pcg_jacobi])
+    Iterative,
+}
+
+/// Holds the coefficient matrix given to [solve_auto] (dense or sparse)
+#[derive(Clone, Copy)]
+pub enum AutoMatrix<'a> {
+    /// Dense matrix
+    Dense(&'a Matrix),
+
+    /// Sparse matrix in triplet format
+    Triplet(&'a SparseTriplet),
+}
+
+impl<'a> From<&'a Matrix> for AutoMatrix<'a> {
+    fn from(a: &'a Matrix) -> Self {
+        AutoMatrix::Dense(a)
+    }
+}
+
+impl<'a> From<&'a SparseTriplet> for AutoMatrix<'a> {
+    fn from(trip: &'a SparseTriplet) -> Self {
+        AutoMatrix::Triplet(trip)
+    }
+}
+
+/// Holds the thresholds used by [solve_auto] to select the solution path
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigAuto {
+    pub(crate) max_neq_dense: usize,        // use LAPACK if neq ≤ max_neq_dense
+    pub(crate) density_dense: f64,          // use LAPACK if nnz/neq² ≥ density_dense
+    pub(crate) min_neq_iterative: usize,    // try PCG if neq ≥ min_neq_iterative
+    pub(crate) tolerance: f64,              // relative tolerance of PCG
+    pub(crate) max_iterations: usize,       // max number of PCG iterations (0 => neq)
+    pub(crate) config_solver: ConfigSolver, // configuration of the sparse direct solver
+}
+
+impl ConfigAuto {
+    /// Returns a default configuration
+    pub fn new() -> Self {
+        ConfigAuto {
+            max_neq_dense: 200,
+            density_dense: 0.25,
+            min_neq_iterative: 50_000,
+            tolerance: 1e-10,
+            max_iterations: 0,
+            config_solver: ConfigSolver::new(),
+        }
+    }
+
+    /// Sets the maximum number of equations for which the dense LAPACK solver is always used
+    pub fn max_neq_dense(&mut self, value: usize) -> &mut Self {
+        self.max_neq_dense = value;
+        self
+    }
+
+    /// Sets the density (nnz / neq²) above which the dense LAPACK solver is used
+    pub fn density_dense(&mut self, value: f64) -> &mut Self {
+        self.density_dense = value;
+        self
+    }
+
+    /// Sets the minimum number of equations for which the iterative solver is tried
+    ///
+    /// The iterative solver is only tried on symmetric matrices with positive diagonal entries.
+    pub fn min_neq_iterative(&mut self, value: usize) -> &mut Self {
+        self.min_neq_iterative = value;
+        self
+    }
+
+    /// Sets the relative tolerance of the iterative solver
+    pub fn tolerance(&mut self, value: f64) -> &mut Self {
+        self.tolerance = value;
+        self
+    }
+
+    /// Sets the maximum number of iterations of the iterative solver (0 means neq)
+    pub fn max_iterations(&mut self, value: usize) -> &mut Self {
+        self.max_iterations = value;
+        self
+    }
+
+    /// Sets the configuration of the sparse direct solver
+    pub fn config_solver(&mut self, config: ConfigSolver) -> &mut Self {
+        self.config_solver = config;
+        self
+    }
+}
+
+impl Default for ConfigAuto {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Holds a report of the solution performed by [solve_auto]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AutoReport {
+    /// The solution path that produced the solution
+    pub path: SolutionPath,
+
+    /// The number of equations
+    pub neq: usize,
+
+    /// The number of non-zero entries of the full matrix (after summing up duplicates)
+    pub nnz: usize,
+
+    /// The density nnz / neq²
+    pub density: f64,
+
+    /// Indicates whether the matrix is symmetric or not
+    pub symmetric: bool,
+
+    /// The number of iterations of the iterative solver (zero for direct solvers)
+    pub iterations: usize,
+
+    /// Indicates that the iterative solver did not converge and the sparse direct solver was used instead
+    pub fallback: bool,
+}
+
+/// Solves a linear system selecting the solver automatically
+///
+/// Finds `x` such that `a ⋅ x = b`, where `a` is either a dense [Matrix] or a [SparseTriplet].
+/// The solution path is selected as follows:
+///
+/// 1. [SolutionPath::DenseLapack] if `neq ≤ max_neq_dense` or the density `nnz/neq² ≥ density_dense`
+/// 2. [SolutionPath::Iterative] if `neq ≥ min_neq_iterative` and the matrix is symmetric (by value) with positive
+///    diagonal entries (a necessary condition for positive-definiteness). If the conjugate gradient
+///    method does not converge (e.g., the matrix is ill-conditioned or indefinite), the sparse direct
+///    solver is used instead and [AutoReport::fallback] is set.
+/// 3. [SolutionPath::SparseDirect] otherwise
+///
+/// Returns the solution and a report describing the selected path (see [ConfigAuto] for the thresholds).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_sparse::{solve_auto, SolutionPath, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = Matrix::from(&[[2.0, 0.0], [0.0, 4.0]]);
+///     let b = Vector::from(&[2.0, 4.0]);
+///     let (x, report) = solve_auto(&a, &b)?;
+///     assert_eq!(x.as_data(), &[1.0, 1.0]);
+///     assert_eq!(report.path, SolutionPath::DenseLapack);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_auto<'a, A>(a: A, b: &Vector) -> Result<(Vector, AutoReport), StrError>
+where
+    A: Into<AutoMatrix<'a>>,
+{
+    solve_auto_config(&ConfigAuto::new(), a, b)
+}
+
+/// Solves a linear system selecting the solver automatically with the given thresholds
+///
+/// See [solve_auto].
+pub fn solve_auto_config<'a, A>(config: &ConfigAuto, a: A, b: &Vector) -> Result<(Vector, AutoReport), StrError>
+where
+    A: Into<AutoMatrix<'a>>,
+{
+    let a = a.into();
+    let neq = match a {
+        AutoMatrix::Dense(mat) => {
+            let (m, n) = mat.dims();
+            if m != n {
+                return Err("matrix must be square");
+            }
+            m
+        }
+        AutoMatrix::Triplet(trip) => trip.neq(),
+    };
+    if b.dim() != neq {
+        return Err("vector has wrong dimension");
+    }
+
+    // analyze the matrix (the CSR matrix of a triplet is kept for the iterative path)
+    let (nnz, symmetric, csr) = match a {
+        AutoMatrix::Dense(mat) => {
+            let (nnz, symmetric) = dense_nnz_and_symmetry(mat);
+            (nnz, symmetric, None)
+        }
+        AutoMatrix::Triplet(trip) => {
+            let csr = SparseCsr::from_triplet(trip);
+            (csr.nnz(), csr_is_symmetric(&csr), Some(csr))
+        }
+    };
+    let density = if neq == 0 {
+        1.0
+    } else {
+        (nnz as f64) / ((neq * neq) as f64)
+    };
+    let mut report = AutoReport {
+        path: SolutionPath::DenseLapack,
+        neq,
+        nnz,
+        density,
+        symmetric,
+        iterations: 0,
+        fallback: false,
+    };
+
+    // dense path
+    if neq <= config.max_neq_dense || density >= config.density_dense {
+        let mut aa = Matrix::new(neq, neq);
+        match a {
+            AutoMatrix::Dense(mat) => aa.as_mut_data().copy_from_slice(mat.as_data()),
+            AutoMatrix::Triplet(trip) => trip.to_matrix(&mut aa)?,
+        }
+        let mut x = b.clone();
+        solve_lin_sys(&mut x, &mut aa)?;
+        return Ok((x, report));
+    }
+
+    // sparse paths
+    let converted;
+    let trip = match a {
+        AutoMatrix::Dense(mat) => {
+            converted = dense_to_triplet(mat, nnz, symmetric)?;
+            &converted
+        }
+        AutoMatrix::Triplet(trip) => trip,
+    };
+    if symmetric && neq >= config.min_neq_iterative {
+        let csr = csr.unwrap_or_else(|| SparseCsr::from_triplet(trip));
+        if csr.diagonal().as_data().iter().all(|&d| d > 0.0) {
+            let max_iterations = if config.max_iterations == 0 {
+                neq
+            } else {
+                config.max_iterations
+            };
+            let mut x = Vector::new(neq);
+            let stats = solve_pcg_jacobi(&mut x, &csr, b, config.tolerance, max_iterations);
+            match stats {
+                Ok(stats) if stats.converged => {
+                    report.path = SolutionPath::Iterative;
+                    report.iterations = stats.iterations;
+                    return Ok((x, report));
+                }
+                Ok(stats) => {
+                    report.iterations = stats.iterations;
+                    report.fallback = true;
+                }
+                Err(_) => report.fallback = true,
+            }
+        }
+    }
+    let (_, x) = Solver::compute(config.config_solver, trip, b)?;
+    report.path = SolutionPath::SparseDirect;
+    Ok((x, report))
+}
+
+/// Counts the non-zero entries of a dense matrix and checks whether it is symmetric or not
+fn dense_nnz_and_symmetry(a: &Matrix) -> (usize, bool) {
+    let n = a.nrow();
+    let mut nnz = 0;
+    let mut symmetric = true;
+    for i in 0..n {
+        for j in 0..n {
+            let aij = a.get(i, j);
+            if aij != 0.0 {
+                nnz += 1;
+            }
+            if j < i && aij != a.get(j, i) {
+                symmetric = false;
+            }
+        }
+    }
+    (nnz, symmetric)
+}
+
+/// Checks whether a CSR matrix is symmetric or not (by comparing the values aᵢⱼ and aⱼᵢ)
+fn csr_is_symmetric(csr: &SparseCsr) -> bool {
+    let get = |i: usize, j: usize| {
+        let (start, end) = (csr.row_pointers[i], csr.row_pointers[i + 1]);
+        match csr.col_indices[start..end].binary_search(&j) {
+            Ok(k) => csr.values[start + k],
+            Err(_) => 0.0,
+        }
+    };
+    for i in 0..csr.neq {
+        for k in csr.row_pointers[i]..csr.row_pointers[i + 1] {
+            let j = csr.col_indices[k];
+            if j != i && csr.values[k] != get(j, i) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Converts a dense matrix into a triplet (lower triangle only if symmetric)
+fn dense_to_triplet(a: &Matrix, nnz: usize, symmetric: bool) -> Result<SparseTriplet, StrError> {
+    let n = a.nrow();
+    let symmetry = if symmetric {
+        TripletSymmetry::LowerTriangular
+    } else {
+        TripletSymmetry::None
+    };
+    let mut trip = SparseTriplet::new(n, usize::max(1, nnz), symmetry)?;
+    for j in 0..n {
+        let i_start = if symmetric { j } else { 0 };
+        for i in i_start..n {
+            let aij = a.get(i, j);
+            if aij != 0.0 {
+                trip.put(i, j, aij)?;
+            }
+        }
+    }
+    Ok(trip)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        csr_is_symmetric, dense_nnz_and_symmetry, dense_to_triplet, solve_auto, solve_auto_config, ConfigAuto,
+        SolutionPath,
+    };
+    use crate::{SparseCsr, SparseTriplet, TripletSymmetry};
+    use russell_chk::vec_approx_eq;
+    use russell_lab::{Matrix, Vector};
+
+    fn laplacian_1d(n: usize) -> SparseTriplet {
+        let mut trip = SparseTriplet::new(n, 2 * n, TripletSymmetry::LowerTriangular).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 2.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -1.0).unwrap();
+            }
+        }
+        trip
+    }
+
+    #[test]
+    fn solve_auto_fails_on_wrong_dims() {
+        let a = Matrix::new(2, 3);
+        assert_eq!(solve_auto(&a, &Vector::new(2)).err(), Some("matrix must be square"));
+        let trip = laplacian_1d(3);
+        assert_eq!(
+            solve_auto(&trip, &Vector::new(2)).err(),
+            Some("vector has wrong dimension")
+        );
+    }
+
+    #[test]
+    fn dense_helpers_work() {
+        let a = Matrix::from(&[[2.0, 1.0, 0.0], [1.0, 3.0, 0.0], [0.0, 0.0, 4.0]]);
+        assert_eq!(dense_nnz_and_symmetry(&a), (5, true));
+        let trip = dense_to_triplet(&a, 5, true).unwrap();
+        assert_eq!(trip.symmetry(), TripletSymmetry::LowerTriangular);
+        assert_eq!(trip.nnz_current(), 4);
+        let mut b = Matrix::new(3, 3);
+        trip.to_matrix(&mut b).unwrap();
+        assert_eq!(b.as_data(), a.as_data());
+        let a = Matrix::from(&[[2.0, 1.0], [0.0, 3.0]]);
+        assert_eq!(dense_nnz_and_symmetry(&a), (3, false));
+        let trip = dense_to_triplet(&a, 3, false).unwrap();
+        assert_eq!(trip.symmetry(), TripletSymmetry::None);
+        assert_eq!(trip.nnz_current(), 3);
+    }
+
+    #[test]
+    fn csr_is_symmetric_works() {
+        assert!(csr_is_symmetric(&SparseCsr::from_triplet(&laplacian_1d(3))));
+        let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(0, 2, 1.0).unwrap();
+        trip.put(2, 0, 1.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        assert!(csr_is_symmetric(&SparseCsr::from_triplet(&trip)));
+        trip.put(2, 0, 0.5).unwrap(); // a20 = 1.5
+        assert!(!csr_is_symmetric(&SparseCsr::from_triplet(&trip)));
+        let mut trip = SparseTriplet::new(2, 2, TripletSymmetry::None).unwrap();
+        trip.put(0, 1, 1.0).unwrap(); // a10 is missing
+        trip.put(1, 1, 1.0).unwrap();
+        assert!(!csr_is_symmetric(&SparseCsr::from_triplet(&trip)));
+    }
+
+    #[test]
+    fn solve_auto_dense_path_works() {
+        let trip = laplacian_1d(5);
+        let mut b = Vector::new(5);
+        b[0] = 1.0;
+        b[4] = 1.0;
+        let (x, report) = solve_auto(&trip, &b).unwrap();
+        assert_eq!(report.path, SolutionPath::DenseLapack);
+        assert_eq!(report.nnz, 13);
+        assert!(report.symmetric);
+        vec_approx_eq(x.as_data(), &[1.0, 1.0, 1.0, 1.0, 1.0], 1e-14);
+    }
+
+    #[test]
+    fn solve_auto_iterative_path_works() {
+        let n = 100;
+        let trip = laplacian_1d(n);
+        let mut b = Vector::new(n);
+        b[0] = 1.0;
+        b[n - 1] = 1.0;
+        let config = *ConfigAuto::new()
+            .max_neq_dense(10)
+            .min_neq_iterative(50)
+            .tolerance(1e-12);
+        let (x, report) = solve_auto_config(&config, &trip, &b).unwrap();
+        assert_eq!(report.path, SolutionPath::Iterative);
+        assert!(!report.fallback);
+        assert!(report.iterations > 0);
+        vec_approx_eq(x.as_data(), &vec![1.0; n], 1e-9);
+
+        // numerically symmetric matrix given by a triplet without the symmetry flag
+        let mut trip = SparseTriplet::new(n, 3 * n, TripletSymmetry::None).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 2.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -1.0).unwrap();
+                trip.put(i - 1, i, -1.0).unwrap();
+            }
+        }
+        let (x, report) = solve_auto_config(&config, &trip, &b).unwrap();
+        assert_eq!(report.path, SolutionPath::Iterative);
+        assert!(report.symmetric);
+        vec_approx_eq(x.as_data(), &vec![1.0; n], 1e-9);
+    }
+
+    #[test]
+    fn solve_auto_sparse_direct_path_works() {
+        let n = 100;
+        let trip = laplacian_1d(n);
+        let mut b = Vector::new(n);
+        b[0] = 1.0;
+        b[n - 1] = 1.0;
+
+        // not large enough for the iterative solver
+        let config = *ConfigAuto::new().max_neq_dense(10);
+        let (x, report) = solve_auto_config(&config, &trip, &b).unwrap();
+        assert_eq!(report.path, SolutionPath::SparseDirect);
+        assert!(!report.fallback);
+        vec_approx_eq(x.as_data(), &vec![1.0; n], 1e-12);
+
+        // the iterative solver does not converge
+        let config = *ConfigAuto::new()
+            .max_neq_dense(10)
+            .min_neq_iterative(50)
+            .max_iterations(2);
+        let (x, report) = solve_auto_config(&config, &trip, &b).unwrap();
+        assert_eq!(report.path, SolutionPath::SparseDirect);
+        assert!(report.fallback);
+        assert_eq!(report.iterations, 2);
+        vec_approx_eq(x.as_data(), &vec![1.0; n], 1e-12);
+    }
+}
@@ -1,12 +1,16 @@
 use super::{
     code_symmetry_mmp, code_symmetry_umf, str_enum_ordering, str_enum_scaling, str_mmp_ordering, str_mmp_scaling,
-    str_umf_ordering, str_umf_scaling, ConfigSolver, LinSolKind, SparseTriplet,
+    str_umf_ordering, str_umf_scaling, CancelToken, ConfigSolver, LinSolKind, SolverEvent, SolverPhase, SparseCsr,
+    SparseTriplet,
 };
-use crate::{StrError, Symmetry};
-use russell_lab::{format_nanoseconds, vec_copy, Stopwatch, Vector};
-use russell_openblas::to_i32;
+use crate::{RussellError, StrError, Symmetry};
+use russell_lab::{format_nanoseconds, vec_copy, vec_norm, Norm, Stopwatch, Vector};
+use russell_openblas::{set_num_threads, to_i32};
 use std::fmt;
 
+/// Maximum number of iterative refinement steps with mixed precision (as in Lapack dsgesv)
+const MAX_REFINE_ITERATIONS: usize = 30;
+
 #[repr(C)]
 pub(crate) struct ExtSolver {
     data: [u8; 0],
@@ -35,6 +39,7 @@ extern "C" {
         values_aij: *const f64,
         verbose: i32,
     ) -> i32;
+    fn solver_mmp_refactorize(solver: *mut ExtSolver, values_aij: *const f64, verbose: i32) -> i32;
     fn solver_mmp_solve(solver: *mut ExtSolver, rhs: *mut f64, verbose: i32) -> i32;
     fn solver_mmp_used_ordering(solver: *const ExtSolver) -> i32;
     fn solver_mmp_used_scaling(solver: *const ExtSolver) -> i32;
@@ -58,11 +63,21 @@ extern "C" {
         values_aij: *const f64,
         verbose: i32,
     ) -> i32;
+    fn solver_umf_refactorize(
+        solver: *mut ExtSolver,
+        indices_i: *const i32,
+        indices_j: *const i32,
+        values_aij: *const f64,
+        verbose: i32,
+    ) -> i32;
     fn solver_umf_solve(solver: *mut ExtSolver, x: *mut f64, rhs: *const f64, verbose: i32) -> i32;
     fn solver_umf_used_ordering(solver: *const ExtSolver) -> i32;
     fn solver_umf_used_scaling(solver: *const ExtSolver) -> i32;
 }
 
+/// Defines the type of the progress callback (see [Solver::set_progress_callback])
+type ProgressCallback = Box<dyn FnMut(SolverEvent)>;
+
 /// Implements a sparse linear solver
 ///
 /// For a general sparse and square matrix `a` (symmetric, non-symmetric)
@@ -73,30 +88,97 @@ extern "C" {
 /// (m,m)   (m)    (m)
 /// ```
 pub struct Solver {
-    kind: LinSolKind,            // solver kind
-    verbose: i32,                // verbose mode
-    done_factorize: bool,        // factorization completed
-    neq: usize,                  // number of equations == nrow(a) where a*x=rhs
-    solver: *mut ExtSolver,      // data allocated by the c-code
-    stopwatch: Stopwatch,        // stopwatch to measure elapsed time
-    time_fact: u128,             // elapsed time during factorize
-    time_solve: u128,            // elapsed time during solve
-    used_ordering: &'static str, // used ordering strategy
-    used_scaling: &'static str,  // used scaling strategy
+    kind: LinSolKind,                      // solver kind
+    verbose: i32,                          // verbose mode
+    done_factorize: bool,                  // factorization completed
+    neq: usize,                            // number of equations == nrow(a) where a*x=rhs
+    nnz: usize,                            // number of entries passed to the c-code
+    config: ConfigSolver,                  // configuration (to re-initialize the c-code)
+    symmetry: Option<Symmetry>,            // symmetry option
+    jacobi: Option<Vec<f64>>,              // Jacobi scaling factors (if equilibrate is on)
+    pattern: Option<(Vec<i32>, Vec<i32>)>, // indices passed to the c-code by the last successful factorize
+    refine: Option<SparseCsr>,             // original matrix for the iterative refinement (if mixed_precision is on)
+    refine_iterations: usize,              // number of refinement iterations performed by the last solve
+    work: Vec<f64>,                        // copy of the rhs for UMF (solve_inplace)
+    solver: *mut ExtSolver,                // data allocated by the c-code
+    stopwatch: Stopwatch,                  // stopwatch to measure elapsed time
+    time_fact: u128,                       // elapsed time during factorize
+    time_solve: u128,                      // elapsed time during solve
+    used_ordering: &'static str,           // used ordering strategy
+    used_scaling: &'static str,            // used scaling strategy
+    progress: Option<ProgressCallback>,    // progress callback
+    cancel: Option<CancelToken>,           // cancellation token
+    last_error: Option<RussellError>,      // typed error of the last failed phase
 }
 
 impl Solver {
     /// Creates a new solver
-    pub fn new(config: ConfigSolver, neq: usize, nnz: usize, symmetry: Option<Symmetry>) -> Result<Self, StrError> {
+    ///
+    /// # Input
+    ///
+    /// * `config` -- The configuration parameters
+    /// * `neq` -- The number of equations
+    /// * `nnz` -- The number of non-zero values (usually `trip.nnz_current()`)
+    /// * `symmetry` -- The symmetry option (if any)
+    ///
+    /// **Note:** The number of OpenBLAS threads is set here if `blas_num_threads` is given in [ConfigSolver]
+    /// or if the `deterministic` option is enabled (one thread).
+    ///
+    /// **Note:** The backend is re-initialized by `factorize` if the number of entries
+    /// required by the backend differs from `nnz` after mirroring or restricting the
+    /// entries of the triplet (see [crate::TripletSymmetry]).
+    pub fn new(config: ConfigSolver, neq: usize, nnz: usize, symmetry: Option<Symmetry>) -> Result<Self, RussellError> {
+        let blas_num_threads = config.actual_blas_num_threads();
+        if blas_num_threads > 0 {
+            set_num_threads(i32::try_from(blas_num_threads).map_err(|_| "blas_num_threads is too large")?);
+        }
+        let solver = Solver::allocate(&config, neq, nnz, symmetry)?;
+        Ok(Solver {
+            kind: config.lin_sol_kind,
+            verbose: if config.verbose { 1 } else { 0 },
+            done_factorize: false,
+            neq,
+            nnz,
+            config,
+            symmetry,
+            jacobi: None,
+            pattern: None,
+            refine: None,
+            refine_iterations: 0,
+            work: Vec::new(),
+            solver,
+            stopwatch: Stopwatch::new(""),
+            time_fact: 0,
+            time_solve: 0,
+            used_ordering: str_enum_ordering(config.ordering as i32),
+            used_scaling: str_enum_scaling(config.scaling as i32),
+            progress: None,
+            cancel: None,
+            last_error: None,
+        })
+    }
+
+    /// Allocates and initializes the data in the c-code
+    fn allocate(
+        config: &ConfigSolver,
+        neq: usize,
+        nnz: usize,
+        symmetry: Option<Symmetry>,
+    ) -> Result<*mut ExtSolver, RussellError> {
         let n = to_i32(neq);
         let nnz = to_i32(nnz);
+        let pct_inc_workspace =
+            i32::try_from(config.pct_inc_workspace).map_err(|_| "pct_inc_workspace is too large")?;
+        let max_work_memory = i32::try_from(config.max_work_memory).map_err(|_| "max_work_memory is too large")?;
+        let openmp_num_threads =
+            i32::try_from(config.actual_openmp_num_threads()).map_err(|_| "openmp_num_threads is too large")?;
         unsafe {
             let solver = match config.lin_sol_kind {
                 LinSolKind::Mmp => new_solver_mmp(),
                 LinSolKind::Umf => new_solver_umf(),
             };
             if solver.is_null() {
-                return Err("c-code failed to allocate solver");
+                return Err("c-code failed to allocate solver".into());
             }
             match config.lin_sol_kind {
                 LinSolKind::Mmp => {
@@ -105,15 +187,15 @@ impl Solver {
                         n,
                         nnz,
                         code_symmetry_mmp(symmetry)?,
-                        config.ordering,
-                        config.scaling,
-                        config.pct_inc_workspace,
-                        config.max_work_memory,
-                        config.openmp_num_threads,
+                        config.ordering as i32,
+                        config.scaling as i32,
+                        pct_inc_workspace,
+                        max_work_memory,
+                        openmp_num_threads,
                     );
                     if res != 0 {
                         drop_solver_mmp(solver);
-                        return Err(Solver::handle_mmp_error_code(res));
+                        return Err(Solver::error_from_code(LinSolKind::Mmp, res));
                     }
                 }
                 LinSolKind::Umf => {
@@ -122,49 +204,217 @@ impl Solver {
                         n,
                         nnz,
                         code_symmetry_umf(symmetry)?,
-                        config.ordering,
-                        config.scaling,
-                        config.verbose,
+                        config.ordering as i32,
+                        config.scaling as i32,
+                        if config.verbose { 1 } else { 0 },
                     );
                     if res != 0 {
                         drop_solver_umf(solver);
-                        return Err(Solver::handle_umf_error_code(res));
+                        return Err(Solver::error_from_code(LinSolKind::Umf, res));
                     }
                 }
             }
-            Ok(Solver {
-                kind: config.lin_sol_kind,
-                verbose: config.verbose,
-                done_factorize: false,
-                neq,
-                solver,
-                stopwatch: Stopwatch::new(""),
-                time_fact: 0,
-                time_solve: 0,
-                used_ordering: str_enum_ordering(config.ordering),
-                used_scaling: str_enum_scaling(config.scaling),
-            })
+            Ok(solver)
         }
     }
 
     /// Performs the factorization
-    pub fn factorize(&mut self, trip: &SparseTriplet) -> Result<(), StrError> {
+    ///
+    /// **Note:** The entries of the triplet are mirrored or restricted as required by the backend:
+    ///
+    /// * MMP with symmetry: only the lower triangle is used (e.g., of a [crate::TripletSymmetry::GeneralFull] triplet)
+    /// * MMP without symmetry or UMF: the full matrix is used (e.g., of a [crate::TripletSymmetry::LowerTriangular] triplet)
+    ///
+    /// If `equilibrate` is set in [ConfigSolver], the matrix `D⋅A⋅D` with `D = diag(1/√|aᵢᵢ|)`
+    /// is factorized instead of `A`; then, `solve` scales the right-hand side and the solution accordingly.
+    ///
+    /// If `mixed_precision` is set in [ConfigSolver], the values are rounded to single precision
+    /// before the factorization and a copy of the matrix is kept for the iterative refinement
+    /// performed by `solve`.
+    ///
+    /// The factorization is aborted if the token given to [Solver::set_cancel_token] is cancelled or
+    /// if the time limit given by `max_time_factorize` in [ConfigSolver] is exceeded. These conditions
+    /// are checked before and after calling the backend (which cannot be interrupted).
+    /// See [Solver::get_last_error] to obtain the typed error (e.g., [RussellError::Timeout]).
+    pub fn factorize(&mut self, trip: &SparseTriplet) -> Result<(), RussellError> {
+        self.factorize_or_update(trip, false)
+    }
+
+    /// Performs a new numeric factorization reusing the analysis of the previous factorization
+    ///
+    /// This function is useful when a sequence of matrices with the same sparsity pattern
+    /// must be factorized; e.g., the Jacobian matrices in Newton iterations. The entries of
+    /// `trip` must have the same indices (in the same order) as the triplet given to the
+    /// previous call to [Solver::factorize]; only the values may change.
+    ///
+    /// **Note:** The ordering (and the symbolic analysis) of the previous factorization is reused.
+    /// UMF still performs the numerical pivoting; thus, the factorization remains stable as long as
+    /// the values do not change drastically. MMP skips the analysis phase and only redoes the
+    /// numeric factorization.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_chk::vec_approx_eq;
+    /// use russell_lab::Vector;
+    /// use russell_sparse::{ConfigSolver, Solver, SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::None)?;
+    ///     trip.put(0, 0, 2.0)?;
+    ///     trip.put(0, 1, 1.0)?;
+    ///     trip.put(1, 1, 4.0)?;
+    ///     let mut solver = Solver::new(ConfigSolver::new(), 2, 3, None)?;
+    ///     solver.factorize(&trip)?;
+    ///
+    ///     // same pattern, new values
+    ///     trip.reset();
+    ///     trip.put(0, 0, 1.0)?;
+    ///     trip.put(0, 1, 2.0)?;
+    ///     trip.put(1, 1, 2.0)?;
+    ///     solver.update_values(&trip)?;
+    ///     let mut x = Vector::new(2);
+    ///     solver.solve(&mut x, &Vector::from(&[5.0, 4.0]))?;
+    ///     vec_approx_eq(x.as_data(), &[1.0, 2.0], 1e-15);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn update_values(&mut self, trip: &SparseTriplet) -> Result<(), RussellError> {
+        if self.pattern.is_none() {
+            return Err("update_values requires a previous call to factorize".into());
+        }
+        self.factorize_or_update(trip, true)
+    }
+
+    /// Performs the factorization (or the numeric factorization only if `reuse` is true)
+    fn factorize_or_update(&mut self, trip: &SparseTriplet, reuse: bool) -> Result<(), RussellError> {
         if trip.neq != self.neq {
-            return Err("cannot factorize because the triplet has incompatible number of equations");
+            return Err(RussellError::DimensionMismatch {
+                expected: self.neq,
+                found: trip.neq,
+            });
         }
         self.stopwatch.reset();
+        self.last_error = None;
+        self.notify(SolverEvent::Started(SolverPhase::Factorize));
+        self.check_interrupt(SolverPhase::Factorize)?;
+
+        // mirror or restrict the entries as required by the backend
+        let lower_only = match self.kind {
+            LinSolKind::Mmp => self.symmetry.is_some(),
+            LinSolKind::Umf => false,
+        };
+        let entries = trip.entries_for_solver(lower_only);
+        let (indices_i, indices_j, values_aij) = match &entries {
+            Some((ii, jj, aa)) => (ii.as_slice(), jj.as_slice(), aa.as_slice()),
+            None => (
+                trip.indices_i.as_slice(),
+                trip.indices_j.as_slice(),
+                trip.values_aij.as_slice(),
+            ),
+        };
+
+        // equilibrate the matrix (the triplet is not modified)
+        let scaled_aij = if self.config.equilibrate {
+            let d = trip.jacobi_scaling();
+            let scaled: Vec<f64> = (0..values_aij.len())
+                .map(|p| d[indices_i[p] as usize] * values_aij[p] * d[indices_j[p] as usize])
+                .collect();
+            self.jacobi = Some(d);
+            self.notify(SolverEvent::Diagnostic(
+                SolverPhase::Factorize,
+                "equilibrating with Jacobi scaling",
+            ));
+            Some(scaled)
+        } else {
+            self.jacobi = None;
+            None
+        };
+        let values_aij = match &scaled_aij {
+            Some(aa) => aa.as_slice(),
+            None => values_aij,
+        };
+
+        // round the values to single precision (the original matrix is kept for the refinement)
+        let rounded_aij = if self.config.mixed_precision {
+            self.refine = Some(SparseCsr::from_triplet(trip));
+            self.notify(SolverEvent::Diagnostic(
+                SolverPhase::Factorize,
+                "rounding the values to single precision",
+            ));
+            Some(values_aij.iter().map(|&aij| aij as f32 as f64).collect::<Vec<_>>())
+        } else {
+            self.refine = None;
+            None
+        };
+        let values_aij = match &rounded_aij {
+            Some(aa) => aa.as_slice(),
+            None => values_aij,
+        };
+
+        // check the pattern (the c-code reads the first nnz entries only)
+        if reuse {
+            let n = usize::min(self.nnz, values_aij.len());
+            let same = match &self.pattern {
+                Some((ii, jj)) => {
+                    (entries.is_none() || values_aij.len() == self.nnz)
+                        && indices_i[..n] == ii[..]
+                        && indices_j[..n] == jj[..]
+                }
+                None => false,
+            };
+            if !same {
+                return Err("update_values requires the same sparsity pattern as in factorize".into());
+            }
+            self.notify(SolverEvent::Diagnostic(
+                SolverPhase::Factorize,
+                "reusing the ordering of the previous factorization",
+            ));
+        }
+
+        // re-initialize the backend if the number of entries has changed
+        if entries.is_some() && values_aij.len() != self.nnz {
+            self.notify(SolverEvent::Diagnostic(
+                SolverPhase::Factorize,
+                "re-initializing the backend because the number of entries has changed",
+            ));
+            let solver = match Solver::allocate(&self.config, self.neq, values_aij.len(), self.symmetry) {
+                Ok(solver) => solver,
+                Err(e) => return Err(self.fail_with(SolverPhase::Factorize, e)),
+            };
+            unsafe {
+                match self.kind {
+                    LinSolKind::Mmp => drop_solver_mmp(self.solver),
+                    LinSolKind::Umf => drop_solver_umf(self.solver),
+                }
+            }
+            self.solver = solver;
+            self.nnz = values_aij.len();
+            self.done_factorize = false;
+        }
+
+        self.done_factorize = false;
+        if !reuse {
+            self.pattern = None;
+        }
+        self.check_interrupt(SolverPhase::Factorize)?;
         unsafe {
             match self.kind {
                 LinSolKind::Mmp => {
-                    let res = solver_mmp_factorize(
-                        self.solver,
-                        trip.indices_i.as_ptr(),
-                        trip.indices_j.as_ptr(),
-                        trip.values_aij.as_ptr(),
-                        self.verbose,
-                    );
+                    let res = if reuse {
+                        solver_mmp_refactorize(self.solver, values_aij.as_ptr(), self.verbose)
+                    } else {
+                        solver_mmp_factorize(
+                            self.solver,
+                            indices_i.as_ptr(),
+                            indices_j.as_ptr(),
+                            values_aij.as_ptr(),
+                            self.verbose,
+                        )
+                    };
                     if res != 0 {
-                        return Err(Solver::handle_mmp_error_code(res));
+                        let err = Solver::error_from_code(LinSolKind::Mmp, res);
+                        return Err(self.fail_with(SolverPhase::Factorize, err));
                     }
                     let ord = solver_mmp_used_ordering(self.solver);
                     let sca = solver_mmp_used_scaling(self.solver);
@@ -172,15 +422,26 @@ impl Solver {
                     self.used_scaling = str_mmp_scaling(sca);
                 }
                 LinSolKind::Umf => {
-                    let res = solver_umf_factorize(
-                        self.solver,
-                        trip.indices_i.as_ptr(),
-                        trip.indices_j.as_ptr(),
-                        trip.values_aij.as_ptr(),
-                        self.verbose,
-                    );
+                    let res = if reuse {
+                        solver_umf_refactorize(
+                            self.solver,
+                            indices_i.as_ptr(),
+                            indices_j.as_ptr(),
+                            values_aij.as_ptr(),
+                            self.verbose,
+                        )
+                    } else {
+                        solver_umf_factorize(
+                            self.solver,
+                            indices_i.as_ptr(),
+                            indices_j.as_ptr(),
+                            values_aij.as_ptr(),
+                            self.verbose,
+                        )
+                    };
                     if res != 0 {
-                        return Err(Solver::handle_umf_error_code(res));
+                        let err = Solver::error_from_code(LinSolKind::Umf, res);
+                        return Err(self.fail_with(SolverPhase::Factorize, err));
                     }
                     let ord = solver_umf_used_ordering(self.solver);
                     let sca = solver_umf_used_scaling(self.solver);
@@ -189,8 +450,16 @@ impl Solver {
                 }
             }
         }
+        self.check_interrupt(SolverPhase::Factorize)?;
+        if !reuse {
+            let n = usize::min(self.nnz, values_aij.len());
+            self.pattern = Some((indices_i[..n].to_vec(), indices_j[..n].to_vec()));
+        }
         self.done_factorize = true;
         self.time_fact = self.stopwatch.stop();
+        self.notify(SolverEvent::Diagnostic(SolverPhase::Factorize, self.used_ordering));
+        self.notify(SolverEvent::Diagnostic(SolverPhase::Factorize, self.used_scaling));
+        self.notify(SolverEvent::Finished(SolverPhase::Factorize, self.time_fact));
         Ok(())
     }
 
@@ -200,12 +469,12 @@ impl Solver {
     ///
     /// ```
     /// use russell_lab::{Matrix, Vector};
-    /// use russell_sparse::{ConfigSolver, SparseTriplet, Solver, StrError};
+    /// use russell_sparse::{ConfigSolver, SparseTriplet, Solver, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     // allocate a square matrix
     ///     let (neq, nnz) = (5, 13);
-    ///     let mut trip = SparseTriplet::new(neq, nnz)?;
+    ///     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     trip.put(0, 0, 1.0)?; // << (0, 0, a00/2)
     ///     trip.put(0, 0, 1.0)?; // << (0, 0, a00/2)
     ///     trip.put(1, 0, 3.0)?;
@@ -252,40 +521,289 @@ impl Solver {
     ///     Ok(())
     /// }
     /// ```
-    pub fn solve(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), StrError> {
+    pub fn solve(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), RussellError> {
         if !self.done_factorize {
-            return Err("factorization must be done before calling solve");
+            return Err("factorization must be done before calling solve".into());
         }
-        if x.dim() != self.neq || rhs.dim() != self.neq {
-            return Err("x.ndim() and rhs.ndim() must equal the number of equations");
+        for dim in [x.dim(), rhs.dim()] {
+            if dim != self.neq {
+                return Err(RussellError::DimensionMismatch {
+                    expected: self.neq,
+                    found: dim,
+                });
+            }
         }
         self.stopwatch.reset();
+        self.last_error = None;
+        self.notify(SolverEvent::Started(SolverPhase::Solve));
+        self.check_interrupt(SolverPhase::Solve)?;
+        self.solve_direct(x, rhs)?;
+        self.check_interrupt(SolverPhase::Solve)?;
+        if self.refine.is_some() {
+            self.refine_solution(x, rhs)?;
+        }
+        self.time_solve = self.stopwatch.stop();
+        self.notify(SolverEvent::Finished(SolverPhase::Solve, self.time_solve));
+        Ok(())
+    }
+
+    /// Computes the solution with the factorization (without refinement)
+    fn solve_direct(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), RussellError> {
         unsafe {
             match self.kind {
                 LinSolKind::Mmp => {
                     vec_copy(x, rhs)?;
+                    if let Some(d) = &self.jacobi {
+                        for i in 0..self.neq {
+                            x[i] *= d[i];
+                        }
+                    }
                     let res = solver_mmp_solve(self.solver, x.as_mut_data().as_mut_ptr(), self.verbose);
                     if res != 0 {
-                        return Err(Solver::handle_mmp_error_code(res));
+                        let err = Solver::error_from_code(LinSolKind::Mmp, res);
+                        return Err(self.fail_with(SolverPhase::Solve, err));
                     }
                 }
                 LinSolKind::Umf => {
+                    let scaled_rhs: Vec<f64>;
+                    let rhs_data = match &self.jacobi {
+                        Some(d) => {
+                            scaled_rhs = rhs.as_data().iter().zip(d).map(|(b, di)| b * di).collect();
+                            scaled_rhs.as_slice()
+                        }
+                        None => rhs.as_data().as_slice(),
+                    };
                     let res = solver_umf_solve(
                         self.solver,
                         x.as_mut_data().as_mut_ptr(),
-                        rhs.as_data().as_ptr(),
+                        rhs_data.as_ptr(),
                         self.verbose,
                     );
                     if res != 0 {
-                        return Err(Solver::handle_umf_error_code(res));
+                        let err = Solver::error_from_code(LinSolKind::Umf, res);
+                        return Err(self.fail_with(SolverPhase::Solve, err));
                     }
                 }
             }
         }
+        if let Some(d) = &self.jacobi {
+            for i in 0..self.neq {
+                x[i] *= d[i];
+            }
+        }
+        Ok(())
+    }
+
+    /// Performs the iterative refinement of the solution in double precision (similar to Lapack dsgesv)
+    ///
+    /// The refinement stops when `‖r‖∞ ≤ ‖x‖∞ ⋅ ‖a‖∞ ⋅ ε ⋅ √neq`, where `r = rhs - a ⋅ x`.
+    fn refine_solution(&mut self, x: &mut Vector, rhs: &Vector) -> Result<(), RussellError> {
+        let a = match self.refine.take() {
+            Some(a) => a,
+            None => return Ok(()),
+        };
+        let result = self.refine_with(&a, x, rhs);
+        self.refine = Some(a);
+        result
+    }
+
+    /// Performs the iterative refinement using the (double precision) matrix `a`
+    fn refine_with(&mut self, a: &SparseCsr, x: &mut Vector, rhs: &Vector) -> Result<(), RussellError> {
+        let mut norm_a = 0.0;
+        for i in 0..self.neq {
+            let row_sum: f64 = a.values[a.row_pointers[i]..a.row_pointers[i + 1]]
+                .iter()
+                .map(|aij| f64::abs(*aij))
+                .sum();
+            norm_a = f64::max(norm_a, row_sum);
+        }
+        let factor = norm_a * f64::EPSILON * f64::sqrt(self.neq as f64);
+        let mut dx = Vector::new(self.neq);
+        for iter in 0..=MAX_REFINE_ITERATIONS {
+            let mut r = a.mat_vec_mul(x)?;
+            for i in 0..self.neq {
+                r[i] = rhs[i] - r[i];
+            }
+            if vec_norm(&r, Norm::Max) <= vec_norm(x, Norm::Max) * factor {
+                self.refine_iterations = iter;
+                return Ok(());
+            }
+            if iter == MAX_REFINE_ITERATIONS {
+                break;
+            }
+            self.solve_direct(&mut dx, &r)?;
+            for i in 0..self.neq {
+                x[i] += dx[i];
+            }
+            self.check_interrupt(SolverPhase::Solve)?;
+        }
+        self.refine_iterations = MAX_REFINE_ITERATIONS;
+        Err(self.fail(
+            SolverPhase::Solve,
+            "the mixed precision iterative refinement did not converge",
+        ))
+    }
+
+    /// Computes the solution in-place (the right-hand side is overwritten by the solution)
+    ///
+    /// ```text
+    /// x_rhs := a⁻¹ ⋅ x_rhs
+    /// ```
+    ///
+    /// This function avoids allocating `x` and copying `rhs` at every call of [Solver::solve]
+    /// (e.g., in time-stepping loops). MMP solves directly on `x_rhs`; UMF requires distinct
+    /// arrays; thus, `x_rhs` is copied into a work array that is allocated once and then reused.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Vector;
+    /// use russell_sparse::{ConfigSolver, Solver, SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::None)?;
+    ///     trip.put(0, 0, 2.0)?;
+    ///     trip.put(0, 1, 1.0)?;
+    ///     trip.put(1, 1, 4.0)?;
+    ///
+    ///     let mut solver = Solver::new(ConfigSolver::new(), 2, 3, None)?;
+    ///     solver.factorize(&trip)?;
+    ///     let mut x_rhs = Vector::from(&[3.0, 4.0]);
+    ///     solver.solve_inplace(&mut x_rhs)?;
+    ///     assert_eq!(x_rhs.as_data(), &[1.0, 1.0]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn solve_inplace(&mut self, x_rhs: &mut Vector) -> Result<(), RussellError> {
+        if !self.done_factorize {
+            return Err("factorization must be done before calling solve".into());
+        }
+        if x_rhs.dim() != self.neq {
+            return Err(RussellError::DimensionMismatch {
+                expected: self.neq,
+                found: x_rhs.dim(),
+            });
+        }
+        if self.refine.is_some() {
+            // the right-hand side is required by the refinement
+            let rhs = x_rhs.clone();
+            return self.solve(x_rhs, &rhs);
+        }
+        self.stopwatch.reset();
+        self.last_error = None;
+        self.notify(SolverEvent::Started(SolverPhase::Solve));
+        self.check_interrupt(SolverPhase::Solve)?;
+        if let Some(d) = &self.jacobi {
+            for i in 0..self.neq {
+                x_rhs[i] *= d[i];
+            }
+        }
+        unsafe {
+            match self.kind {
+                LinSolKind::Mmp => {
+                    let res = solver_mmp_solve(self.solver, x_rhs.as_mut_data().as_mut_ptr(), self.verbose);
+                    if res != 0 {
+                        let err = Solver::error_from_code(LinSolKind::Mmp, res);
+                        return Err(self.fail_with(SolverPhase::Solve, err));
+                    }
+                }
+                LinSolKind::Umf => {
+                    self.work.clear();
+                    self.work.extend_from_slice(x_rhs.as_data());
+                    let res = solver_umf_solve(
+                        self.solver,
+                        x_rhs.as_mut_data().as_mut_ptr(),
+                        self.work.as_ptr(),
+                        self.verbose,
+                    );
+                    if res != 0 {
+                        let err = Solver::error_from_code(LinSolKind::Umf, res);
+                        return Err(self.fail_with(SolverPhase::Solve, err));
+                    }
+                }
+            }
+        }
+        if let Some(d) = &self.jacobi {
+            for i in 0..self.neq {
+                x_rhs[i] *= d[i];
+            }
+        }
+        self.check_interrupt(SolverPhase::Solve)?;
         self.time_solve = self.stopwatch.stop();
+        self.notify(SolverEvent::Finished(SolverPhase::Solve, self.time_solve));
         Ok(())
     }
 
+    /// Computes selected entries of the inverse matrix
+    ///
+    /// Returns the values `a⁻¹ᵢⱼ` corresponding to the `(i, j)` pairs in `indices` (in the same order).
+    ///
+    /// The entries are obtained column by column by solving `a ⋅ x = eⱼ`, where `eⱼ` is the j-th unit vector;
+    /// thus, one solution (forward and backward substitutions) is performed for each distinct column `j`.
+    /// For instance, the diagonal entries of the inverse yield the variances of the parameters of
+    /// least-squares problems or Gaussian models (with `a` being the normal or precision matrix).
+    ///
+    /// **Note:** The options of the factorization (e.g., `equilibrate` or `mixed_precision`) are taken into account.
+    /// The elapsed time returned by [Solver::get_elapsed_times] corresponds to the last solution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_chk::vec_approx_eq;
+    /// use russell_sparse::{ConfigSolver, Solver, SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     //     ┌       ┐              ┌          ┐
+    ///     //     │ 2 1 0 │              │  3 -2  1 │
+    ///     // a = │ 1 2 1 │  ⇒  a⁻¹ = ¼ ⋅ │ -2  4 -2 │
+    ///     //     │ 0 1 2 │              │  1 -2  3 │
+    ///     //     └       ┘              └          ┘
+    ///     let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular)?;
+    ///     trip.put(0, 0, 2.0)?;
+    ///     trip.put(1, 0, 1.0)?;
+    ///     trip.put(1, 1, 2.0)?;
+    ///     trip.put(2, 1, 1.0)?;
+    ///     trip.put(2, 2, 2.0)?;
+    ///     let mut solver = Solver::new(ConfigSolver::new(), 3, 5, None)?;
+    ///     solver.factorize(&trip)?;
+    ///
+    ///     // diagonal entries
+    ///     let diag = solver.inverse_entries(&[(0, 0), (1, 1), (2, 2)])?;
+    ///     vec_approx_eq(&diag, &[0.75, 1.0, 0.75], 1e-15);
+    ///
+    ///     // any entries
+    ///     let entries = solver.inverse_entries(&[(2, 0), (0, 1)])?;
+    ///     vec_approx_eq(&entries, &[0.25, -0.5], 1e-15);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn inverse_entries(&mut self, indices: &[(usize, usize)]) -> Result<Vec<f64>, RussellError> {
+        if !self.done_factorize {
+            return Err("factorization must be done before calling inverse_entries".into());
+        }
+        if let Some(index) = indices.iter().flat_map(|&(i, j)| [i, j]).find(|&k| k >= self.neq) {
+            return Err(RussellError::IndexOutOfBounds { index, dim: self.neq });
+        }
+        // group the entries by column (one solution per distinct column)
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_by_key(|&k| indices[k].1);
+        let mut values = vec![0.0; indices.len()];
+        let mut x = Vector::new(self.neq);
+        let mut e = Vector::new(self.neq);
+        let mut column = None;
+        for k in order {
+            let (i, j) = indices[k];
+            if column != Some(j) {
+                e.fill(0.0);
+                e[j] = 1.0;
+                self.solve(&mut x, &e)?;
+                column = Some(j);
+            }
+            values[k] = x[i];
+        }
+        Ok(values)
+    }
+
     /// Computes a new solution
     ///
     /// ```text
@@ -305,16 +823,18 @@ impl Solver {
     /// you may re-compute solutions with the already factorized matrix
     /// by calling `solve` again.
     ///
+    /// **Note:** The [Symmetry::General] option is used if the triplet is symmetric.
+    ///
     /// # Example
     ///
     /// ```
     /// use russell_lab::{Matrix, Vector};
-    /// use russell_sparse::{ConfigSolver, Solver, SparseTriplet, StrError};
+    /// use russell_sparse::{ConfigSolver, Solver, SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     // allocate a square matrix
     ///     let (neq, nnz) = (3, 5);
-    ///     let mut trip = SparseTriplet::new(neq, nnz)?;
+    ///     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     trip.put(0, 0, 0.2)?;
     ///     trip.put(0, 1, 0.2)?;
     ///     trip.put(1, 0, 0.5)?;
@@ -357,14 +877,161 @@ impl Solver {
     ///     Ok(())
     /// }
     /// ```
-    pub fn compute(config: ConfigSolver, trip: &SparseTriplet, rhs: &Vector) -> Result<(Self, Vector), StrError> {
-        let mut solver = Solver::new(config, trip.neq, trip.pos, None)?;
+    pub fn compute(config: ConfigSolver, trip: &SparseTriplet, rhs: &Vector) -> Result<(Self, Vector), RussellError> {
+        let symmetry = if trip.symmetry.is_symmetric() {
+            Some(Symmetry::General)
+        } else {
+            None
+        };
+        let mut solver = Solver::new(config, trip.neq, trip.pos, symmetry)?;
         let mut x = Vector::new(trip.neq());
         solver.factorize(&trip)?;
         solver.solve(&mut x, &rhs)?;
         Ok((solver, x))
     }
 
+    /// Sets a callback to report the progress of the factorization and solution phases
+    ///
+    /// The callback receives [SolverEvent]s when a phase starts or finishes, and when
+    /// diagnostic messages are issued (e.g., the used ordering and scaling, or error messages).
+    /// For instance, the callback may forward the events to a logging library or to a progress bar,
+    /// instead of (or in addition to) the lower-level messages printed by the backend with `verbose`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Vector;
+    /// use russell_sparse::{ConfigSolver, Solver, SolverEvent, SparseTriplet, StrError, TripletSymmetry};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let mut trip = SparseTriplet::new(2, 2, TripletSymmetry::None)?;
+    ///     trip.put(0, 0, 2.0)?;
+    ///     trip.put(1, 1, 4.0)?;
+    ///
+    ///     let log = Arc::new(Mutex::new(Vec::new()));
+    ///     let log_clone = Arc::clone(&log);
+    ///     let mut solver = Solver::new(ConfigSolver::new(), 2, 2, None)?;
+    ///     solver.set_progress_callback(move |event| {
+    ///         if let SolverEvent::Finished(phase, _) = event {
+    ///             log_clone.lock().unwrap().push(format!("{:?} finished", phase));
+    ///         }
+    ///     });
+    ///
+    ///     let mut x = Vector::new(2);
+    ///     solver.factorize(&trip)?;
+    ///     solver.solve(&mut x, &Vector::from(&[2.0, 4.0]))?;
+    ///     assert_eq!(x.as_data(), &[1.0, 1.0]);
+    ///     assert_eq!(*log.lock().unwrap(), &["Factorize finished", "Solve finished"]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_progress_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(SolverEvent) + 'static,
+    {
+        self.progress = Some(Box::new(callback));
+    }
+
+    /// Removes the progress callback
+    pub fn clear_progress_callback(&mut self) {
+        self.progress = None;
+    }
+
+    /// Calls the progress callback, if any
+    fn notify(&mut self, event: SolverEvent) {
+        if let Some(callback) = self.progress.as_mut() {
+            callback(event);
+        }
+    }
+
+    /// Reports an error message to the progress callback and returns it
+    fn fail(&mut self, phase: SolverPhase, message: StrError) -> RussellError {
+        self.fail_with(phase, RussellError::Message(message))
+    }
+
+    /// Reports a typed error to the progress callback, records it, and returns it
+    fn fail_with(&mut self, phase: SolverPhase, err: RussellError) -> RussellError {
+        self.notify(SolverEvent::Diagnostic(phase, err.as_str()));
+        self.last_error = Some(err);
+        err
+    }
+
+    /// Returns an error if the computation has been cancelled or if the time limit has been exceeded
+    fn check_interrupt(&mut self, phase: SolverPhase) -> Result<(), RussellError> {
+        let (name, max_time) = match phase {
+            SolverPhase::Factorize => ("Factorize", self.config.max_time_factorize),
+            SolverPhase::Solve => ("Solve", self.config.max_time_solve),
+        };
+        if self.cancel.as_ref().is_some_and(|token| token.is_cancelled()) {
+            return Err(self.fail_with(phase, RussellError::Cancelled { phase: name }));
+        }
+        if max_time > 0 {
+            let elapsed = self.stopwatch.stop();
+            let limit = (max_time as u128) * 1_000_000;
+            if elapsed > limit {
+                return Err(self.fail_with(
+                    phase,
+                    RussellError::Timeout {
+                        phase: name,
+                        elapsed,
+                        limit,
+                    },
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets a token to cancel the factorization or the solution from another thread
+    ///
+    /// The token is checked before and after each call to the backend (which cannot be interrupted).
+    /// A cancelled computation returns an error and [Solver::get_last_error] returns [RussellError::Cancelled].
+    /// Call [CancelToken::reset] before re-using the token.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{CancelToken, ConfigSolver, RussellError, Solver, SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let mut trip = SparseTriplet::new(2, 2, TripletSymmetry::None)?;
+    ///     trip.put(0, 0, 2.0)?;
+    ///     trip.put(1, 1, 4.0)?;
+    ///     let mut solver = Solver::new(ConfigSolver::new(), 2, 2, None)?;
+    ///
+    ///     // the token would be cancelled by another thread (e.g., a job scheduler)
+    ///     let token = CancelToken::new();
+    ///     solver.set_cancel_token(token.clone());
+    ///     token.cancel();
+    ///     assert_eq!(solver.factorize(&trip).err(), Some("the computation has been cancelled"));
+    ///     assert_eq!(solver.get_last_error(), Some(RussellError::Cancelled { phase: "Factorize" }));
+    ///
+    ///     token.reset();
+    ///     solver.factorize(&trip)?;
+    ///     assert_eq!(solver.get_last_error(), None);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_cancel_token(&mut self, token: CancelToken) {
+        self.cancel = Some(token);
+    }
+
+    /// Removes the cancellation token
+    pub fn clear_cancel_token(&mut self) {
+        self.cancel = None;
+    }
+
+    /// Returns the typed error of the last failed factorize or solve (if any)
+    ///
+    /// The functions of the Solver return [RussellError] with context data; e.g.,
+    /// [RussellError::Backend] with the error code of the backend, [RussellError::Timeout]
+    /// with the elapsed time, or [RussellError::Cancelled]. This function returns a copy of the
+    /// last one, which is cleared when factorize or solve starts.
+    pub fn get_last_error(&self) -> Option<RussellError> {
+        self.last_error
+    }
+
     /// Returns the elapsed times
     ///
     /// # Output
@@ -374,6 +1041,42 @@ impl Solver {
         (self.time_fact, self.time_solve)
     }
 
+    /// Returns the number of iterative refinement steps performed by the last call to solve
+    ///
+    /// **Note:** The refinement is only performed if `mixed_precision` is set in [ConfigSolver].
+    pub fn get_refinement_iterations(&self) -> usize {
+        self.refine_iterations
+    }
+
+    /// Returns a typed error corresponding to a code returned by the c-code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{LinSolKind, RussellError, Solver};
+    ///
+    /// let err = Solver::error_from_code(LinSolKind::Umf, 1);
+    /// match err {
+    ///     RussellError::Backend { backend, code, .. } => assert_eq!((backend, code), ("UMF", 1)),
+    ///     _ => panic!("should be a backend error"),
+    /// }
+    /// assert_eq!(err.as_str(), "Error(1): Matrix is singular");
+    /// ```
+    pub fn error_from_code(kind: LinSolKind, code: i32) -> RussellError {
+        match kind {
+            LinSolKind::Mmp => RussellError::Backend {
+                backend: "MMP",
+                code,
+                message: Solver::handle_mmp_error_code(code),
+            },
+            LinSolKind::Umf => RussellError::Backend {
+                backend: "UMF",
+                code,
+                message: Solver::handle_umf_error_code(code),
+            },
+        }
+    }
+
     /// Handles error code
     fn handle_mmp_error_code(err: i32) -> StrError {
         match err {
@@ -526,9 +1229,14 @@ impl fmt::Display for Solver {
 
 #[cfg(test)]
 mod tests {
-    use super::{ConfigSolver, LinSolKind, Solver, SparseTriplet};
+    use super::{ConfigSolver, LinSolKind, Solver, SolverPhase, SparseTriplet};
+    use crate::{CancelToken, RussellError, StrError, TripletSymmetry};
     use russell_chk::vec_approx_eq;
     use russell_lab::Vector;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::thread::sleep;
+    use std::time::Duration;
 
     #[test]
     fn new_works() {
@@ -539,14 +1247,42 @@ mod tests {
         assert_eq!(solver.neq, 2);
     }
 
+    #[test]
+    fn new_captures_errors() {
+        let mut config = ConfigSolver::new();
+        config.max_work_memory(usize::MAX);
+        assert_eq!(
+            Solver::new(config, 2, 2, None).err(),
+            Some(RussellError::Message("max_work_memory is too large"))
+        );
+        let mut config = ConfigSolver::new();
+        config.pct_inc_workspace(usize::MAX);
+        assert_eq!(
+            Solver::new(config, 2, 2, None).err(),
+            Some(RussellError::Message("pct_inc_workspace is too large"))
+        );
+        let mut config = ConfigSolver::new();
+        config.openmp_num_threads(usize::MAX);
+        assert_eq!(
+            Solver::new(config, 2, 2, None).err(),
+            Some(RussellError::Message("openmp_num_threads is too large"))
+        );
+        let mut config = ConfigSolver::new();
+        config.blas_num_threads(usize::MAX);
+        assert_eq!(
+            Solver::new(config, 2, 2, None).err(),
+            Some(RussellError::Message("blas_num_threads is too large"))
+        );
+    }
+
     #[test]
     fn factorize_fails_on_incompatible_triplet() {
         let config = ConfigSolver::new();
         let mut solver = Solver::new(config, 1, 1, None).unwrap();
-        let trip = SparseTriplet::new(2, 2).unwrap();
+        let trip = SparseTriplet::new(2, 2, TripletSymmetry::None).unwrap();
         assert_eq!(
             solver.factorize(&trip).err(),
-            Some("cannot factorize because the triplet has incompatible number of equations")
+            Some(RussellError::DimensionMismatch { expected: 1, found: 2 })
         );
     }
 
@@ -555,10 +1291,163 @@ mod tests {
         let config = ConfigSolver::new();
         let (neq, nnz) = (2, 2);
         let mut solver = Solver::new(config, neq, nnz, None).unwrap();
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, 0.0).unwrap();
+        assert_eq!(
+            solver.factorize(&trip),
+            Err(Solver::error_from_code(LinSolKind::Umf, 1))
+        );
+    }
+
+    #[test]
+    fn progress_callback_works() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = Rc::clone(&events);
+        let mut config = ConfigSolver::new();
+        config.equilibrate(true);
+        let (neq, nnz) = (2, 2);
+        let mut solver = Solver::new(config, neq, nnz, None).unwrap();
+        solver.set_progress_callback(move |event| events_clone.borrow_mut().push(format!("{:?}", event)));
+
+        // singular matrix
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, 0.0).unwrap();
+        assert_eq!(
+            solver.factorize(&trip),
+            Err(Solver::error_from_code(LinSolKind::Umf, 1))
+        );
+        assert_eq!(
+            *events.borrow(),
+            &[
+                "Started(Factorize)",
+                "Diagnostic(Factorize, \"equilibrating with Jacobi scaling\")",
+                "Diagnostic(Factorize, \"Error(1): Matrix is singular\")",
+            ]
+        );
+
+        // regular matrix
+        events.borrow_mut().clear();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 4.0).unwrap();
+        trip.put(1, 1, 2.0).unwrap();
+        solver.factorize(&trip).unwrap();
+        let mut x = Vector::new(neq);
+        solver.solve(&mut x, &Vector::from(&[4.0, 4.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[1.0, 2.0], 1e-15);
+        let recorded = events.borrow();
+        assert_eq!(recorded.len(), 7);
+        assert_eq!(recorded[0], "Started(Factorize)");
+        assert!(recorded[4].starts_with("Finished(Factorize, "));
+        assert_eq!(recorded[5], "Started(Solve)");
+        assert!(recorded[6].starts_with("Finished(Solve, "));
+        drop(recorded);
+
+        // no more events after clearing the callback
+        events.borrow_mut().clear();
+        solver.clear_progress_callback();
+        solver.solve(&mut x, &Vector::from(&[4.0, 4.0])).unwrap();
+        assert_eq!(events.borrow().len(), 0);
+    }
+
+    #[test]
+    fn cancel_token_and_last_error_work() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = Rc::clone(&events);
+        let (neq, nnz) = (2, 2);
+        let mut solver = Solver::new(ConfigSolver::new(), neq, nnz, None).unwrap();
+        solver.set_progress_callback(move |event| events_clone.borrow_mut().push(format!("{:?}", event)));
+        assert_eq!(solver.get_last_error(), None);
+
+        // backend error
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(1, 1, 0.0).unwrap();
-        assert_eq!(solver.factorize(&trip), Err("Error(1): Matrix is singular"));
+        assert_eq!(
+            solver.factorize(&trip),
+            Err(Solver::error_from_code(LinSolKind::Umf, 1))
+        );
+        assert_eq!(
+            solver.get_last_error(),
+            Some(Solver::error_from_code(LinSolKind::Umf, 1))
+        );
+
+        // cancelled factorization
+        let token = CancelToken::new();
+        solver.set_cancel_token(token.clone());
+        token.cancel();
+        events.borrow_mut().clear();
+        trip.reset();
+        trip.put(0, 0, 4.0).unwrap();
+        trip.put(1, 1, 2.0).unwrap();
+        assert_eq!(
+            solver.factorize(&trip).err(),
+            Some(RussellError::Cancelled { phase: "Factorize" })
+        );
+        assert_eq!(
+            solver.get_last_error(),
+            Some(RussellError::Cancelled { phase: "Factorize" })
+        );
+        assert!(!solver.done_factorize);
+        assert_eq!(
+            *events.borrow(),
+            &[
+                "Started(Factorize)",
+                "Diagnostic(Factorize, \"the computation has been cancelled\")",
+            ]
+        );
+
+        // the error is cleared by a successful factorization
+        token.reset();
+        solver.factorize(&trip).unwrap();
+        assert_eq!(solver.get_last_error(), None);
+
+        // cancelled solution
+        let mut x = Vector::new(neq);
+        let rhs = Vector::from(&[4.0, 4.0]);
+        token.cancel();
+        assert_eq!(
+            solver.solve(&mut x, &rhs).err(),
+            Some(RussellError::Cancelled { phase: "Solve" })
+        );
+        assert_eq!(
+            solver.get_last_error(),
+            Some(RussellError::Cancelled { phase: "Solve" })
+        );
+        let mut x_rhs = rhs.clone();
+        assert_eq!(
+            solver.solve_inplace(&mut x_rhs).err(),
+            Some(RussellError::Cancelled { phase: "Solve" })
+        );
+
+        // the token is ignored after clearing it
+        solver.clear_cancel_token();
+        solver.solve(&mut x, &rhs).unwrap();
+        vec_approx_eq(x.as_data(), &[1.0, 2.0], 1e-15);
+        assert_eq!(solver.get_last_error(), None);
+    }
+
+    #[test]
+    fn check_interrupt_captures_timeout() {
+        let mut config = ConfigSolver::new();
+        config.max_time_factorize(1);
+        let mut solver = Solver::new(config, 1, 1, None).unwrap();
+        solver.stopwatch.reset();
+        assert_eq!(solver.check_interrupt(SolverPhase::Solve), Ok(())); // unlimited
+        sleep(Duration::from_millis(2));
+        assert!(matches!(
+            solver.check_interrupt(SolverPhase::Factorize).err(),
+            Some(RussellError::Timeout { phase: "Factorize", .. })
+        ));
+        match solver.get_last_error() {
+            Some(RussellError::Timeout { phase, elapsed, limit }) => {
+                assert_eq!(phase, "Factorize");
+                assert_eq!(limit, 1_000_000);
+                assert!(elapsed > limit);
+            }
+            _ => panic!("should be a timeout error"),
+        }
     }
 
     #[test]
@@ -566,26 +1455,96 @@ mod tests {
         let config = ConfigSolver::new();
         let (neq, nnz) = (2, 2);
         let mut solver = Solver::new(config, neq, nnz, None).unwrap();
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(1, 1, 1.0).unwrap();
         solver.factorize(&trip).unwrap();
         assert!(solver.done_factorize);
     }
 
+    #[test]
+    fn update_values_captures_errors() {
+        let config = ConfigSolver::new();
+        let (neq, nnz) = (2, 2);
+        let mut solver = Solver::new(config, neq, nnz, None).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, 1.0).unwrap();
+        assert_eq!(
+            solver.update_values(&trip).err(),
+            Some(RussellError::Message(
+                "update_values requires a previous call to factorize"
+            ))
+        );
+        solver.factorize(&trip).unwrap();
+        let mut trip_other = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip_other.put(0, 1, 1.0).unwrap();
+        trip_other.put(1, 0, 1.0).unwrap();
+        assert_eq!(
+            solver.update_values(&trip_other).err(),
+            Some(RussellError::Message(
+                "update_values requires the same sparsity pattern as in factorize"
+            ))
+        );
+        let trip_wrong = SparseTriplet::new(1, 1, TripletSymmetry::None).unwrap();
+        assert_eq!(
+            solver.update_values(&trip_wrong).err(),
+            Some(RussellError::DimensionMismatch { expected: 2, found: 1 })
+        );
+    }
+
+    #[test]
+    fn update_values_works() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = Rc::clone(&events);
+        let config = ConfigSolver::new();
+        let (neq, nnz) = (3, 5);
+        let mut solver = Solver::new(config, neq, nnz, None).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(0, 1, 1.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        trip.put(2, 1, 1.0).unwrap();
+        trip.put(2, 2, 4.0).unwrap();
+        solver.factorize(&trip).unwrap();
+        let mut x = Vector::new(neq);
+        solver.solve(&mut x, &Vector::from(&[4.0, 6.0, 14.0])).unwrap();
+        vec_approx_eq(x.as_data(), &[1.0, 2.0, 3.0], 1e-15);
+
+        // same pattern with new values (Newton-like sequence)
+        solver.set_progress_callback(move |event| events_clone.borrow_mut().push(format!("{:?}", event)));
+        for k in 1..4 {
+            let c = 1.0 + k as f64;
+            trip.reset();
+            trip.put(0, 0, 2.0 * c).unwrap();
+            trip.put(0, 1, 1.0).unwrap();
+            trip.put(1, 1, 3.0 * c).unwrap();
+            trip.put(2, 1, 1.0).unwrap();
+            trip.put(2, 2, 4.0 * c).unwrap();
+            solver.update_values(&trip).unwrap();
+            let rhs = trip.mat_vec_mul(&Vector::from(&[1.0, 2.0, 3.0])).unwrap();
+            solver.solve(&mut x, &rhs).unwrap();
+            vec_approx_eq(x.as_data(), &[1.0, 2.0, 3.0], 1e-14);
+        }
+        assert_eq!(
+            events.borrow()[1],
+            "Diagnostic(Factorize, \"reusing the ordering of the previous factorization\")"
+        );
+    }
+
     #[test]
     fn solve_fails_on_non_factorized() {
         let config = ConfigSolver::new();
         let (neq, nnz) = (2, 2);
         let mut solver = Solver::new(config, neq, nnz, None).unwrap();
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(1, 1, 1.0).unwrap();
         let mut x = Vector::new(neq);
         let rhs = Vector::from(&[1.0, 1.0]);
         assert_eq!(
             solver.solve(&mut x, &rhs),
-            Err("factorization must be done before calling solve")
+            Err(RussellError::Message("factorization must be done before calling solve"))
         );
     }
 
@@ -594,7 +1553,7 @@ mod tests {
         let config = ConfigSolver::new();
         let (neq, nnz) = (2, 2);
         let mut solver = Solver::new(config, neq, nnz, None).unwrap();
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(1, 1, 1.0).unwrap();
         solver.factorize(&trip).unwrap();
@@ -604,11 +1563,11 @@ mod tests {
         let rhs_wrong = Vector::from(&[1.0]);
         assert_eq!(
             solver.solve(&mut x_wrong, &rhs),
-            Err("x.ndim() and rhs.ndim() must equal the number of equations")
+            Err(RussellError::DimensionMismatch { expected: 2, found: 1 })
         );
         assert_eq!(
             solver.solve(&mut x, &rhs_wrong),
-            Err("x.ndim() and rhs.ndim() must equal the number of equations")
+            Err(RussellError::DimensionMismatch { expected: 2, found: 1 })
         );
     }
 
@@ -619,7 +1578,7 @@ mod tests {
         let mut solver = Solver::new(config, neq, nnz, None).unwrap();
 
         // allocate a square matrix
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
         trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
         trip.put(1, 0, 3.0).unwrap();
@@ -647,6 +1606,187 @@ mod tests {
         vec_approx_eq(x.as_data(), x_correct, 1e-14);
     }
 
+    #[test]
+    fn solve_inplace_captures_errors() {
+        let (neq, nnz) = (2, 2);
+        let mut solver = Solver::new(ConfigSolver::new(), neq, nnz, None).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, 1.0).unwrap();
+        let mut x_rhs = Vector::from(&[1.0, 1.0]);
+        assert_eq!(
+            solver.solve_inplace(&mut x_rhs),
+            Err(RussellError::Message("factorization must be done before calling solve"))
+        );
+        solver.factorize(&trip).unwrap();
+        let mut x_rhs_wrong = Vector::new(1);
+        assert_eq!(
+            solver.solve_inplace(&mut x_rhs_wrong),
+            Err(RussellError::DimensionMismatch { expected: 2, found: 1 })
+        );
+    }
+
+    #[test]
+    fn solve_inplace_works() {
+        let (neq, nnz) = (3, 7);
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1e8).unwrap();
+        trip.put(0, 1, 1e4).unwrap();
+        trip.put(1, 0, 1e4).unwrap();
+        trip.put(1, 1, 2.0).unwrap();
+        trip.put(1, 2, 1e-4).unwrap();
+        trip.put(2, 1, 1e-4).unwrap();
+        trip.put(2, 2, 3e-8).unwrap();
+        for equilibrate in [false, true] {
+            let mut config = ConfigSolver::new();
+            config.equilibrate(equilibrate);
+            let mut solver = Solver::new(config, neq, nnz, None).unwrap();
+            solver.factorize(&trip).unwrap();
+            for x_correct in [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]] {
+                let mut x_rhs = trip.mat_vec_mul(&Vector::from(&x_correct)).unwrap();
+                solver.solve_inplace(&mut x_rhs).unwrap();
+                vec_approx_eq(x_rhs.as_data(), &x_correct, 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_entries_captures_errors() {
+        let mut trip = SparseTriplet::new(2, 2, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, 1.0).unwrap();
+        let mut solver = Solver::new(ConfigSolver::new(), 2, 2, None).unwrap();
+        assert_eq!(
+            solver.inverse_entries(&[(0, 0)]).err(),
+            Some(RussellError::Message(
+                "factorization must be done before calling inverse_entries"
+            ))
+        );
+        solver.factorize(&trip).unwrap();
+        assert_eq!(
+            solver.inverse_entries(&[(0, 2)]).err(),
+            Some(RussellError::IndexOutOfBounds { index: 2, dim: 2 })
+        );
+        assert_eq!(
+            solver.inverse_entries(&[(3, 0)]).err(),
+            Some(RussellError::IndexOutOfBounds { index: 3, dim: 2 })
+        );
+    }
+
+    #[test]
+    fn inverse_entries_works() {
+        #[rustfmt::skip]
+        let data = [
+            [4.0, 1.0, 0.0],
+            [2.0, 5.0, 1.0],
+            [0.0, 1.0, 3.0],
+        ];
+        let mut trip = SparseTriplet::new(3, 7, TripletSymmetry::None).unwrap();
+        for (i, row) in data.iter().enumerate() {
+            for (j, &aij) in row.iter().enumerate() {
+                if aij != 0.0 {
+                    trip.put(i, j, aij).unwrap();
+                }
+            }
+        }
+        // a⁻¹ = adj(a) / det(a) with det(a) = 50
+        #[rustfmt::skip]
+        let ai = [
+            [14.0 / 50.0, -3.0 / 50.0,  1.0 / 50.0],
+            [-6.0 / 50.0, 12.0 / 50.0, -4.0 / 50.0],
+            [ 2.0 / 50.0, -4.0 / 50.0, 18.0 / 50.0],
+        ];
+        let mut indices = Vec::new();
+        let mut correct = Vec::new();
+        for (i, row) in ai.iter().enumerate() {
+            for (j, &aij) in row.iter().enumerate() {
+                indices.push((i, j));
+                correct.push(aij);
+            }
+        }
+        for equilibrate in [false, true] {
+            let mut config = ConfigSolver::new();
+            config.equilibrate(equilibrate);
+            let mut solver = Solver::new(config, 3, 7, None).unwrap();
+            solver.factorize(&trip).unwrap();
+            let values = solver.inverse_entries(&indices).unwrap();
+            vec_approx_eq(&values, &correct, 1e-15);
+            let values = solver.inverse_entries(&[(2, 2), (0, 0), (1, 1), (0, 0)]).unwrap();
+            vec_approx_eq(&values, &[ai[2][2], ai[0][0], ai[1][1], ai[0][0]], 1e-15);
+            assert_eq!(solver.inverse_entries(&[]).unwrap().len(), 0);
+        }
+    }
+
+    #[test]
+    fn solve_with_equilibrate_works() {
+        // badly scaled symmetric matrix
+        let (neq, nnz) = (3, 7);
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1e8).unwrap();
+        trip.put(0, 1, 1e4).unwrap();
+        trip.put(1, 0, 1e4).unwrap();
+        trip.put(1, 1, 2.0).unwrap();
+        trip.put(1, 2, 1e-4).unwrap();
+        trip.put(2, 1, 1e-4).unwrap();
+        trip.put(2, 2, 3e-8).unwrap();
+        let x_correct = Vector::from(&[1.0, 2.0, 3.0]);
+        let rhs = trip.mat_vec_mul(&x_correct).unwrap();
+
+        // factorize and solve with equilibration
+        let mut config = ConfigSolver::new();
+        config.equilibrate(true);
+        let mut solver = Solver::new(config, neq, nnz, None).unwrap();
+        solver.factorize(&trip).unwrap();
+        let mut x = Vector::new(neq);
+        solver.solve(&mut x, &rhs).unwrap();
+        vec_approx_eq(x.as_data(), x_correct.as_data(), 1e-12);
+
+        // solve again (the scaling factors are kept)
+        let rhs = trip.mat_vec_mul(&Vector::from(&[2.0, 4.0, 6.0])).unwrap();
+        solver.solve(&mut x, &rhs).unwrap();
+        vec_approx_eq(x.as_data(), &[2.0, 4.0, 6.0], 1e-12);
+    }
+
+    #[test]
+    fn solve_with_mixed_precision_works() {
+        // the values are not representable in single precision
+        let d = 1.0 + 1e-10;
+        let (neq, nnz) = (3, 7);
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 4.0 * d).unwrap();
+        trip.put(0, 1, d).unwrap();
+        trip.put(1, 0, d).unwrap();
+        trip.put(1, 1, 3.0 * d).unwrap();
+        trip.put(1, 2, 1.0 / 3.0).unwrap();
+        trip.put(2, 1, 1.0 / 3.0).unwrap();
+        trip.put(2, 2, 2.0 * d).unwrap();
+        let x_correct = Vector::from(&[1.0, 2.0, 3.0]);
+        let rhs = trip.mat_vec_mul(&x_correct).unwrap();
+
+        // the single precision factorization alone is inaccurate; thus, refinement is required
+        let mut config = ConfigSolver::new();
+        config.mixed_precision(true);
+        let mut solver = Solver::new(config, neq, nnz, None).unwrap();
+        assert_eq!(solver.get_refinement_iterations(), 0);
+        solver.factorize(&trip).unwrap();
+        let mut x = Vector::new(neq);
+        solver.solve(&mut x, &rhs).unwrap();
+        vec_approx_eq(x.as_data(), x_correct.as_data(), 1e-14);
+        assert!(solver.get_refinement_iterations() > 0);
+
+        // solve in-place
+        let mut x_rhs = rhs.clone();
+        solver.solve_inplace(&mut x_rhs).unwrap();
+        vec_approx_eq(x_rhs.as_data(), x_correct.as_data(), 1e-14);
+
+        // with equilibration
+        config.equilibrate(true);
+        let mut solver = Solver::new(config, neq, nnz, None).unwrap();
+        solver.factorize(&trip).unwrap();
+        solver.solve(&mut x, &rhs).unwrap();
+        vec_approx_eq(x.as_data(), x_correct.as_data(), 1e-14);
+    }
+
     // This function tests many behaviors of the MMP solver.
     // All of these calls must be in a single function because the
     // MMP solver is NOT thread-safe.
@@ -659,15 +1799,15 @@ mod tests {
         let mut solver = Solver::new(config, neq, nnz, None).unwrap();
 
         // factorize fails on incompatible triplet
-        let mut trip_wrong = SparseTriplet::new(1, 1).unwrap();
+        let mut trip_wrong = SparseTriplet::new(1, 1, TripletSymmetry::None).unwrap();
         trip_wrong.put(0, 0, 1.0).unwrap();
         assert_eq!(
             solver.factorize(&trip_wrong).err(),
-            Some("cannot factorize because the triplet has incompatible number of equations")
+            Some(RussellError::DimensionMismatch { expected: 5, found: 1 })
         );
 
         // allocate a square matrix
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
         trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
         trip.put(1, 0, 3.0).unwrap();
@@ -690,7 +1830,7 @@ mod tests {
         // solve fails on non-factorized system
         assert_eq!(
             solver.solve(&mut x, &rhs),
-            Err("factorization must be done before calling solve")
+            Err(RussellError::Message("factorization must be done before calling solve"))
         );
 
         // factorize works
@@ -701,14 +1841,14 @@ mod tests {
         let mut x_wrong = Vector::new(3);
         assert_eq!(
             solver.solve(&mut x_wrong, &rhs),
-            Err("x.ndim() and rhs.ndim() must equal the number of equations")
+            Err(RussellError::DimensionMismatch { expected: 5, found: 3 })
         );
 
         // solve fails on wrong rhs vector
         let rhs_wrong = Vector::from(&[1.0]);
         assert_eq!(
             solver.solve(&mut x, &rhs_wrong),
-            Err("x.ndim() and rhs.ndim() must equal the number of equations")
+            Err(RussellError::DimensionMismatch { expected: 5, found: 1 })
         );
 
         // solve works
@@ -720,21 +1860,77 @@ mod tests {
         solver.solve(&mut x_again, &rhs).unwrap();
         vec_approx_eq(x_again.as_data(), x_correct, 1e-14);
 
+        // solve in-place works
+        let mut x_rhs = rhs.clone();
+        solver.solve_inplace(&mut x_rhs).unwrap();
+        vec_approx_eq(x_rhs.as_data(), x_correct, 1e-14);
+
+        // update_values works (same pattern, doubled values)
+        for v in trip.values_aij.iter_mut() {
+            *v *= 2.0;
+        }
+        solver.update_values(&trip).unwrap();
+        solver.solve(&mut x, &rhs).unwrap();
+        vec_approx_eq(x.as_data(), &[0.5, 1.0, 1.5, 2.0, 2.5], 1e-14);
+
+        // update_values fails on a different pattern
+        trip.indices_j.swap(0, 3);
+        assert_eq!(
+            solver.update_values(&trip).err(),
+            Some(RussellError::Message(
+                "update_values requires the same sparsity pattern as in factorize"
+            ))
+        );
+
         // factorize fails on singular matrix
-        let mut trip_singular = SparseTriplet::new(5, 2).unwrap();
+        let mut trip_singular = SparseTriplet::new(5, 2, TripletSymmetry::None).unwrap();
         trip_singular.put(0, 0, 1.0).unwrap();
         trip_singular.put(4, 4, 1.0).unwrap();
         let mut solver = Solver::new(config, 5, 2, None).unwrap();
         assert_eq!(
             solver.factorize(&trip_singular),
-            Err("Error(-10): numerically singular matrix")
+            Err(Solver::error_from_code(LinSolKind::Mmp, -10))
         );
     }
 
+    #[test]
+    fn factorize_mirrors_or_restricts_symmetric_triplets() {
+        //  2  -1              2     ...
+        // -1   2  -1    =>   -1   2
+        //     -1   2             -1   2
+        let x_correct = &[5.0, 8.0, 7.0];
+        let rhs = Vector::from(&[2.0, 4.0, 6.0]);
+
+        // lower triangular triplet is mirrored for UMF
+        let mut lower = SparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular).unwrap();
+        lower.put(0, 0, 2.0).unwrap();
+        lower.put(1, 1, 2.0).unwrap();
+        lower.put(2, 2, 2.0).unwrap();
+        lower.put(1, 0, -1.0).unwrap();
+        lower.put(2, 1, -1.0).unwrap();
+        let config = ConfigSolver::new();
+        let (_, x) = Solver::compute(config, &lower, &rhs).unwrap();
+        vec_approx_eq(x.as_data(), x_correct, 1e-14);
+
+        // full symmetric triplet is restricted to the lower triangle for MMP
+        let mut full = SparseTriplet::new(3, 7, TripletSymmetry::GeneralFull).unwrap();
+        full.put(0, 0, 2.0).unwrap();
+        full.put(1, 1, 2.0).unwrap();
+        full.put(2, 2, 2.0).unwrap();
+        full.put(1, 0, -1.0).unwrap();
+        full.put(0, 1, -1.0).unwrap();
+        full.put(2, 1, -1.0).unwrap();
+        full.put(1, 2, -1.0).unwrap();
+        let mut config = ConfigSolver::new();
+        config.lin_sol_kind(LinSolKind::Mmp);
+        let (_, x) = Solver::compute(config, &full, &rhs).unwrap();
+        vec_approx_eq(x.as_data(), x_correct, 1e-14);
+    }
+
     #[test]
     fn compute_works() {
         let (neq, nnz) = (3, 6);
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(0, 1, 1.0).unwrap();
         trip.put(1, 0, 2.0).unwrap();
@@ -790,6 +1986,20 @@ mod tests {
         assert_eq!(Solver::handle_mmp_error_code(123), default);
     }
 
+    #[test]
+    fn error_from_code_works() {
+        assert_eq!(
+            Solver::error_from_code(LinSolKind::Mmp, -10),
+            RussellError::Backend {
+                backend: "MMP",
+                code: -10,
+                message: "Error(-10): numerically singular matrix",
+            }
+        );
+        let err: StrError = Solver::error_from_code(LinSolKind::Umf, 1).into();
+        assert_eq!(err, "Error(1): Matrix is singular");
+    }
+
     #[test]
     fn handle_umf_error_code_works() {
         let default = "Error: unknown error returned by c-code (UMF)";
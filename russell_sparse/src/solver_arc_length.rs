@@ -0,0 +1,534 @@
+use super::{ConfigSolver, Solver, SparseTriplet, TripletSymmetry};
+use crate::StrError;
+use russell_lab::{vec_inner, vec_norm, Norm, Vector};
+
+/// Holds configuration options for the arc-length continuation driver
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigArcLength {
+    pub(crate) arc_length: f64,             // initial arc-length increment Δs
+    pub(crate) arc_length_min: f64,         // minimum Δs (the continuation fails below this value)
+    pub(crate) arc_length_max: f64,         // maximum Δs
+    pub(crate) psi: f64,                    // scaling factor ψ of the load parameter in the constraint
+    pub(crate) tolerance: f64,              // tolerance on the norm of the augmented residual
+    pub(crate) max_iterations: usize,       // max number of corrector iterations
+    pub(crate) desired_iterations: usize,   // desired number of corrector iterations (to adapt Δs)
+    pub(crate) config_solver: ConfigSolver, // configuration of the linear solver
+}
+
+impl ConfigArcLength {
+    /// Returns a default configuration
+    pub fn new() -> Self {
+        ConfigArcLength {
+            arc_length: 0.1,
+            arc_length_min: 1e-8,
+            arc_length_max: 1.0,
+            psi: 1.0,
+            tolerance: 1e-10,
+            max_iterations: 15,
+            desired_iterations: 4,
+            config_solver: ConfigSolver::new(),
+        }
+    }
+
+    /// Sets the initial, minimum and maximum arc-length increments
+    pub fn arc_length(&mut self, init: f64, min: f64, max: f64) -> &mut Self {
+        self.arc_length = init;
+        self.arc_length_min = min;
+        self.arc_length_max = max;
+        self
+    }
+
+    /// Sets the scaling factor ψ of the load parameter in the arc-length constraint
+    ///
+    /// With `ψ = 1`, the constraint is the spherical (Riks) arc-length; with `ψ = 0`, it is the cylindrical one.
+    pub fn psi(&mut self, value: f64) -> &mut Self {
+        self.psi = value;
+        self
+    }
+
+    /// Sets the tolerance on the Euclidean norm of the augmented residual
+    pub fn tolerance(&mut self, value: f64) -> &mut Self {
+        self.tolerance = value;
+        self
+    }
+
+    /// Sets the maximum number of corrector iterations
+    pub fn max_iterations(&mut self, value: usize) -> &mut Self {
+        self.max_iterations = value;
+        self
+    }
+
+    /// Sets the desired number of corrector iterations used to adapt the arc-length increment
+    pub fn desired_iterations(&mut self, value: usize) -> &mut Self {
+        self.desired_iterations = value;
+        self
+    }
+
+    /// Sets the configuration of the linear solver
+    pub fn config_solver(&mut self, config: ConfigSolver) -> &mut Self {
+        self.config_solver = config;
+        self
+    }
+}
+
+impl Default for ConfigArcLength {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Holds the results of a converged continuation step
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ArcLengthStats {
+    /// The load parameter λ at the new equilibrium point
+    pub lambda: f64,
+
+    /// The arc-length increment Δs used by the step
+    pub arc_length: f64,
+
+    /// Number of corrector iterations
+    pub iterations: usize,
+
+    /// Number of times the step was restarted with a halved arc-length increment
+    pub cutbacks: usize,
+
+    /// Euclidean norm of the final augmented residual
+    pub residual_norm: f64,
+}
+
+/// Implements an arc-length (Riks) continuation driver
+///
+/// Traces the equilibrium path of the parametric system:
+///
+/// ```text
+/// R(u, λ) = 0
+/// ```
+///
+/// by adding the arc-length constraint (with `Δu = u - uₙ` and `Δλ = λ - λₙ`):
+///
+/// ```text
+/// g(u, λ) = ½ (Δuᵀ Δu + ψ² Δλ² - Δs²) = 0
+/// ```
+///
+/// Each step consists of a tangent predictor and a Newton corrector on the augmented system:
+///
+/// ```text
+/// ┌              ┐ ┌    ┐     ┌   ┐
+/// │ ∂R/∂u  ∂R/∂λ │ │ δu │     │ R │
+/// │              │ │    │ = - │   │
+/// │  Δuᵀ   ψ² Δλ │ │ δλ │     │ g │
+/// └              ┘ └    ┘     └   ┘
+/// ```
+///
+/// which is solved by the sparse [Solver]. The augmented matrix remains non-singular at limit
+/// points; thus, snap-through (and snap-back) paths can be followed. The tangent is computed with
+/// the previous tangent in the last row, so the path is never traversed backwards.
+///
+/// The arc-length increment is adapted according to the number of corrector iterations and
+/// halved (and the step restarted) if the corrector fails.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{ConfigArcLength, SolverArcLength, SparseTriplet, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // R(u, λ) = u³ - 3u² + 2.5u - λ has two limit points
+///     let mut config = ConfigArcLength::new();
+///     config.arc_length(0.1, 1e-6, 0.2);
+///     let mut driver = SolverArcLength::new(config, 1, 1)?;
+///     let mut u = Vector::new(1);
+///     let mut lambda = 0.0;
+///     let mut lambdas = Vec::new();
+///     driver.trace(
+///         &mut u,
+///         &mut lambda,
+///         40,
+///         |r: &mut Vector, u: &Vector, l: f64| {
+///             r[0] = u[0] * u[0] * u[0] - 3.0 * u[0] * u[0] + 2.5 * u[0] - l;
+///             Ok(())
+///         },
+///         |jj: &mut SparseTriplet, dr_dl: &mut Vector, u: &Vector, _: f64| {
+///             jj.reset();
+///             jj.put(0, 0, 3.0 * u[0] * u[0] - 6.0 * u[0] + 2.5)?;
+///             dr_dl[0] = -1.0;
+///             Ok(())
+///         },
+///         |_: &Vector, stats| {
+///             lambdas.push(stats.lambda);
+///             true
+///         },
+///     )?;
+///     // the load parameter decreases between the limit points
+///     assert!(lambdas.windows(2).any(|w| w[1] < w[0]));
+///     assert!(u[0] > 2.0);
+///     Ok(())
+/// }
+/// ```
+pub struct SolverArcLength {
+    config: ConfigArcLength, // configuration
+    neq: usize,              // number of equations (without the constraint)
+    arc_length: f64,         // current arc-length increment
+    tangent: Vector,         // (neq + 1) current tangent (the last entry corresponds to λ)
+    solver: Solver,          // linear solver for the augmented system
+    jj: SparseTriplet,       // Jacobian matrix ∂R/∂u
+    dr_dl: Vector,           // derivative ∂R/∂λ
+    aug: SparseTriplet,      // augmented matrix
+}
+
+impl SolverArcLength {
+    /// Creates a new continuation driver
+    ///
+    /// # Input
+    ///
+    /// * `config` -- The configuration parameters
+    /// * `neq` -- The number of equations (dimension of `u`)
+    /// * `nnz` -- The maximum number of non-zero values of the Jacobian matrix `∂R/∂u`
+    ///
+    /// **Note:** The Jacobian triplet given to the `calc_jacobian` functions has [TripletSymmetry::None]
+    /// because the augmented matrix is not symmetric.
+    pub fn new(config: ConfigArcLength, neq: usize, nnz: usize) -> Result<Self, StrError> {
+        if neq == 0 {
+            return Err("neq must be greater than zero");
+        }
+        let valid = config.arc_length_min > 0.0
+            && config.arc_length_min <= config.arc_length
+            && config.arc_length <= config.arc_length_max;
+        if !valid {
+            return Err("arc-lengths must satisfy 0 < min ≤ init ≤ max");
+        }
+        if config.psi.is_nan() || config.psi < 0.0 {
+            return Err("psi must be non-negative");
+        }
+        if config.max_iterations == 0 || config.desired_iterations == 0 {
+            return Err("the numbers of iterations must be greater than zero");
+        }
+        let nnz_aug = nnz + 2 * neq + 1;
+        let mut tangent = Vector::new(neq + 1);
+        tangent[neq] = 1.0;
+        Ok(SolverArcLength {
+            config,
+            neq,
+            arc_length: config.arc_length,
+            tangent,
+            solver: Solver::new(config.config_solver, neq + 1, nnz_aug, None)?,
+            jj: SparseTriplet::new(neq, nnz, TripletSymmetry::None)?,
+            dr_dl: Vector::new(neq),
+            aug: SparseTriplet::new(neq + 1, nnz_aug, TripletSymmetry::None)?,
+        })
+    }
+
+    /// Returns the current arc-length increment (to be used by the next step)
+    pub fn get_arc_length(&self) -> f64 {
+        self.arc_length
+    }
+
+    /// Sets the initial direction of the path (increasing or decreasing load parameter)
+    ///
+    /// **Note:** This resets the tangent; thus, it should be called before the first step only.
+    pub fn set_increasing_lambda(&mut self, increasing: bool) {
+        self.tangent.fill(0.0);
+        self.tangent[self.neq] = if increasing { 1.0 } else { -1.0 };
+    }
+
+    /// Performs one continuation step
+    ///
+    /// # Input
+    ///
+    /// * `u` -- on input, the current equilibrium state; on output, the next one
+    /// * `lambda` -- on input, the current load parameter; on output, the next one
+    /// * `calc_residual` -- computes `r = R(u, λ)`
+    /// * `calc_jacobian` -- computes `jj = ∂R/∂u` (the triplet must be reset first) and `dr_dl = ∂R/∂λ`
+    pub fn step<F, G>(
+        &mut self,
+        u: &mut Vector,
+        lambda: &mut f64,
+        calc_residual: &mut F,
+        calc_jacobian: &mut G,
+    ) -> Result<ArcLengthStats, StrError>
+    where
+        F: FnMut(&mut Vector, &Vector, f64) -> Result<(), StrError>,
+        G: FnMut(&mut SparseTriplet, &mut Vector, &Vector, f64) -> Result<(), StrError>,
+    {
+        let n = self.neq;
+        if u.dim() != n {
+            return Err("u vector must have dimension equal to neq");
+        }
+        let psi2 = self.config.psi * self.config.psi;
+        let mut rhs = Vector::new(n + 1);
+        let mut x = Vector::new(n + 1);
+        let mut r = Vector::new(n);
+        let mut du = Vector::new(n);
+
+        // tangent: [∂R/∂u ∂R/∂λ; tₙᵀ (with ψ²)] t = [0; 1]
+        calc_jacobian(&mut self.jj, &mut self.dr_dl, u, *lambda)?;
+        let t_l = self.tangent[n];
+        let t_u = Vector::from(&&self.tangent.as_data()[..n]);
+        assemble(&mut self.aug, &self.jj, &self.dr_dl, &t_u, psi2 * t_l)?;
+        rhs[n] = 1.0;
+        self.solver.factorize(&self.aug)?;
+        self.solver.solve(&mut x, &rhs)?;
+        let x_u = Vector::from(&&x.as_data()[..n]);
+        let norm = f64::sqrt(vec_inner(&x_u, &x_u) + psi2 * x[n] * x[n]);
+        if norm == 0.0 || !norm.is_finite() {
+            return Err("cannot compute the tangent of the equilibrium path");
+        }
+        let mut tangent = Vector::new(n + 1);
+        for i in 0..=n {
+            tangent[i] = x[i] / norm;
+        }
+
+        let mut cutbacks = 0;
+        loop {
+            let ds = self.arc_length;
+
+            // predictor
+            let mut u_new = u.clone();
+            for i in 0..n {
+                u_new[i] += ds * tangent[i];
+            }
+            let mut l_new = *lambda + ds * tangent[n];
+
+            // corrector
+            let mut converged = false;
+            let mut residual_norm = 0.0;
+            let mut iterations = 0;
+            while iterations <= self.config.max_iterations {
+                calc_residual(&mut r, &u_new, l_new)?;
+                for i in 0..n {
+                    du[i] = u_new[i] - u[i];
+                }
+                let dl = l_new - *lambda;
+                let g = 0.5 * (vec_inner(&du, &du) + psi2 * dl * dl - ds * ds);
+                let norm_r = vec_norm(&r, Norm::Euc);
+                residual_norm = f64::sqrt(norm_r * norm_r + g * g);
+                if !residual_norm.is_finite() {
+                    break;
+                }
+                if residual_norm <= self.config.tolerance {
+                    converged = true;
+                    break;
+                }
+                if iterations == self.config.max_iterations {
+                    break;
+                }
+                calc_jacobian(&mut self.jj, &mut self.dr_dl, &u_new, l_new)?;
+                assemble(&mut self.aug, &self.jj, &self.dr_dl, &du, psi2 * dl)?;
+                for i in 0..n {
+                    rhs[i] = r[i];
+                }
+                rhs[n] = g;
+                if self.solver.factorize(&self.aug).is_err() || self.solver.solve(&mut x, &rhs).is_err() {
+                    break;
+                }
+                for i in 0..n {
+                    u_new[i] -= x[i];
+                }
+                l_new -= x[n];
+                iterations += 1;
+            }
+
+            if converged {
+                u.as_mut_data().copy_from_slice(u_new.as_data());
+                *lambda = l_new;
+                self.tangent = tangent;
+                let factor = f64::sqrt(self.config.desired_iterations as f64 / usize::max(iterations, 1) as f64);
+                self.arc_length =
+                    (ds * factor.clamp(0.5, 2.0)).clamp(self.config.arc_length_min, self.config.arc_length_max);
+                return Ok(ArcLengthStats {
+                    lambda: l_new,
+                    arc_length: ds,
+                    iterations,
+                    cutbacks,
+                    residual_norm,
+                });
+            }
+
+            // cutback
+            if ds <= self.config.arc_length_min {
+                return Err("arc-length increment became smaller than the minimum");
+            }
+            self.arc_length = f64::max(0.5 * ds, self.config.arc_length_min);
+            cutbacks += 1;
+        }
+    }
+
+    /// Traces the equilibrium path with a number of continuation steps
+    ///
+    /// # Input
+    ///
+    /// * `u` -- on input, the initial equilibrium state; on output, the last one
+    /// * `lambda` -- on input, the initial load parameter; on output, the last one
+    /// * `n_steps` -- the maximum number of steps
+    /// * `calc_residual` -- computes `r = R(u, λ)`
+    /// * `calc_jacobian` -- computes `jj = ∂R/∂u` (the triplet must be reset first) and `dr_dl = ∂R/∂λ`
+    /// * `output` -- is called after each converged step; returning `false` stops the continuation
+    ///
+    /// Returns the number of converged steps.
+    pub fn trace<F, G, H>(
+        &mut self,
+        u: &mut Vector,
+        lambda: &mut f64,
+        n_steps: usize,
+        mut calc_residual: F,
+        mut calc_jacobian: G,
+        mut output: H,
+    ) -> Result<usize, StrError>
+    where
+        F: FnMut(&mut Vector, &Vector, f64) -> Result<(), StrError>,
+        G: FnMut(&mut SparseTriplet, &mut Vector, &Vector, f64) -> Result<(), StrError>,
+        H: FnMut(&Vector, &ArcLengthStats) -> bool,
+    {
+        for step in 0..n_steps {
+            let stats = self.step(u, lambda, &mut calc_residual, &mut calc_jacobian)?;
+            if !output(u, &stats) {
+                return Ok(step + 1);
+            }
+        }
+        Ok(n_steps)
+    }
+}
+
+/// Assembles the augmented matrix [jj dr_dl; aᵀ b]
+fn assemble(aug: &mut SparseTriplet, jj: &SparseTriplet, dr_dl: &Vector, a: &Vector, b: f64) -> Result<(), StrError> {
+    let n = jj.neq;
+    aug.reset();
+    for p in 0..jj.pos {
+        aug.put(jj.indices_i[p] as usize, jj.indices_j[p] as usize, jj.values_aij[p])?;
+    }
+    for i in 0..n {
+        aug.put(i, n, dr_dl[i])?;
+        aug.put(n, i, a[i])?;
+    }
+    aug.put(n, n, b)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{assemble, ConfigArcLength, SolverArcLength};
+    use crate::{SparseTriplet, StrError, TripletSymmetry};
+    use russell_lab::{Matrix, Vector};
+
+    #[test]
+    fn config_arc_length_works() {
+        let mut config = ConfigArcLength::new();
+        config
+            .arc_length(0.5, 0.01, 2.0)
+            .psi(0.0)
+            .tolerance(1e-8)
+            .max_iterations(10)
+            .desired_iterations(3);
+        assert_eq!(config.arc_length, 0.5);
+        assert_eq!(config.arc_length_min, 0.01);
+        assert_eq!(config.arc_length_max, 2.0);
+        assert_eq!(config.psi, 0.0);
+        assert_eq!(config.tolerance, 1e-8);
+        assert_eq!(config.max_iterations, 10);
+        assert_eq!(config.desired_iterations, 3);
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            SolverArcLength::new(ConfigArcLength::new(), 0, 1).err(),
+            Some("neq must be greater than zero")
+        );
+        let mut config = ConfigArcLength::new();
+        config.arc_length(1.0, 0.1, 0.5);
+        assert_eq!(
+            SolverArcLength::new(config, 1, 1).err(),
+            Some("arc-lengths must satisfy 0 < min ≤ init ≤ max")
+        );
+        let mut config = ConfigArcLength::new();
+        config.psi(-1.0);
+        assert_eq!(
+            SolverArcLength::new(config, 1, 1).err(),
+            Some("psi must be non-negative")
+        );
+        let mut config = ConfigArcLength::new();
+        config.desired_iterations(0);
+        assert_eq!(
+            SolverArcLength::new(config, 1, 1).err(),
+            Some("the numbers of iterations must be greater than zero")
+        );
+    }
+
+    #[test]
+    fn assemble_works() {
+        let mut jj = SparseTriplet::new(2, 3, TripletSymmetry::None).unwrap();
+        jj.put(0, 0, 1.0).unwrap();
+        jj.put(0, 1, 2.0).unwrap();
+        jj.put(1, 1, 3.0).unwrap();
+        let mut aug = SparseTriplet::new(3, 8, TripletSymmetry::None).unwrap();
+        assemble(
+            &mut aug,
+            &jj,
+            &Vector::from(&[4.0, 5.0]),
+            &Vector::from(&[6.0, 7.0]),
+            8.0,
+        )
+        .unwrap();
+        let mut a = Matrix::new(3, 3);
+        aug.to_matrix(&mut a).unwrap();
+        assert_eq!(
+            format!("{}", a),
+            "┌       ┐\n\
+             │ 1 2 4 │\n\
+             │ 0 3 5 │\n\
+             │ 6 7 8 │\n\
+             └       ┘"
+        );
+    }
+
+    // two-bar (von Mises) truss: R(u, λ) = u (u - 1) (u - 2) - λ (snap-through)
+    fn calc_residual(r: &mut Vector, u: &Vector, l: f64) -> Result<(), StrError> {
+        r[0] = u[0] * (u[0] - 1.0) * (u[0] - 2.0) - l;
+        Ok(())
+    }
+
+    fn calc_jacobian(jj: &mut SparseTriplet, dr_dl: &mut Vector, u: &Vector, _: f64) -> Result<(), StrError> {
+        jj.reset();
+        jj.put(0, 0, 3.0 * u[0] * u[0] - 6.0 * u[0] + 2.0)?;
+        dr_dl[0] = -1.0;
+        Ok(())
+    }
+
+    #[test]
+    fn trace_follows_snap_through() {
+        let mut config = ConfigArcLength::new();
+        config.arc_length(0.05, 1e-6, 0.1);
+        let mut driver = SolverArcLength::new(config, 1, 1).unwrap();
+        let mut u = Vector::new(1);
+        let mut lambda = 0.0;
+        let mut points = Vec::new();
+        let n = driver
+            .trace(&mut u, &mut lambda, 200, calc_residual, calc_jacobian, |u, stats| {
+                points.push((u[0], stats.lambda));
+                u[0] < 2.5
+            })
+            .unwrap();
+        assert!(n < 200);
+        let mut r = Vector::new(1);
+        for (u0, l) in &points {
+            calc_residual(&mut r, &Vector::from(&[*u0]), *l).unwrap();
+            assert!(f64::abs(r[0]) < 1e-9);
+        }
+        // u increases monotonically while λ goes up, down (between the limit points), and up again
+        assert!(points.windows(2).all(|w| w[1].0 > w[0].0));
+        let l_max = points
+            .iter()
+            .filter(|p| p.0 < 1.0)
+            .map(|p| p.1)
+            .fold(f64::MIN, f64::max);
+        let l_min = points
+            .iter()
+            .filter(|p| p.0 > 1.0 && p.0 < 2.0)
+            .map(|p| p.1)
+            .fold(f64::MAX, f64::min);
+        assert!(l_max > 0.38 && l_max < 0.385); // λ at u = 1 - 1/√3 is 2/(3√3) ≈ 0.3849
+        assert!(l_min < -0.38 && l_min > -0.385);
+    }
+}
@@ -0,0 +1,271 @@
+use super::{ConfigIterative, IterativeMonitor, IterativeStats, LinearOperator, Preconditioner};
+use crate::StrError;
+use russell_lab::{vec_inner, vec_norm, Norm, Vector};
+
+/// Solves a general (non-symmetric) system with the biconjugate gradient stabilized method (BiCGStab)
+///
+/// Finds `x` such that `a ⋅ x = b` with:
+///
+/// ```text
+/// ‖b - a⋅x‖₂ ≤ tolerance ⋅ ‖b‖₂
+/// ```
+///
+/// # Input
+///
+/// * `x` -- on input, the initial guess (e.g., zero or a previous solution); on output, the solution
+/// * `a` -- the coefficient matrix or matrix-free operator (see [LinearOperator])
+/// * `b` -- the right-hand side
+/// * `precond` -- the (right) preconditioner (see [Preconditioner])
+/// * `config` -- the tolerance and maximum number of iterations (see [ConfigIterative])
+/// * `monitor` -- receives the residual norm of each iteration and may stop the iterations (see [IterativeMonitor])
+///
+/// **Note:** No error is returned if the tolerance is not reached; check [IterativeStats::converged] instead.
+/// However, an error is returned if the method breaks down (e.g., with a singular matrix).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{solve_bicgstab, ConfigIterative, IterativeMonitor, PrecondIdentity};
+/// use russell_sparse::{SparseCsr, SparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut trip = SparseTriplet::new(3, 6, TripletSymmetry::None)?;
+///     trip.put(0, 0, 4.0)?;
+///     trip.put(0, 1, -1.0)?;
+///     trip.put(1, 0, 2.0)?;
+///     trip.put(1, 1, 5.0)?;
+///     trip.put(1, 2, 1.0)?;
+///     trip.put(2, 2, 3.0)?;
+///     let csr = SparseCsr::from_triplet(&trip);
+///     let b = Vector::from(&[3.0, 8.0, 3.0]);
+///     let mut x = Vector::new(3);
+///     let config = ConfigIterative::new();
+///     let mut precond = PrecondIdentity;
+///     let stats = solve_bicgstab(&mut x, &csr, &b, &mut precond, &config, &mut IterativeMonitor::new())?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.6}", x), "┌          ┐\n\
+///                                      │ 1.000000 │\n\
+///                                      │ 1.000000 │\n\
+///                                      │ 1.000000 │\n\
+///                                      └          ┘");
+///     Ok(())
+/// }
+/// ```
+pub fn solve_bicgstab(
+    x: &mut Vector,
+    a: &dyn LinearOperator,
+    b: &Vector,
+    precond: &mut dyn Preconditioner,
+    config: &ConfigIterative,
+    monitor: &mut IterativeMonitor,
+) -> Result<IterativeStats, StrError> {
+    let (n, ncol) = a.dims();
+    if ncol != n {
+        return Err("the operator must be square");
+    }
+    if x.dim() != n || b.dim() != n {
+        return Err("vectors are incompatible with the matrix");
+    }
+
+    // r = b - a⋅x
+    let mut r = Vector::new(n);
+    a.apply(&mut r, x)?;
+    for i in 0..n {
+        r[i] = b[i] - r[i];
+    }
+    let norm_b = vec_norm(b, Norm::Euc);
+    let target = if norm_b > 0.0 {
+        config.tolerance * norm_b
+    } else {
+        config.tolerance
+    };
+    let mut residual_norm = vec_norm(&r, Norm::Euc);
+    monitor.reset();
+    let proceed = monitor.update(0, residual_norm);
+    if residual_norm <= target || !proceed {
+        return Ok(IterativeStats {
+            iterations: 0,
+            residual_norm,
+            converged: residual_norm <= target,
+        });
+    }
+
+    // shadow residual and auxiliary vectors
+    let r_hat = r.clone();
+    let mut p = Vector::new(n);
+    let mut p_hat = Vector::new(n);
+    let mut v = Vector::new(n);
+    let mut s = Vector::new(n);
+    let mut s_hat = Vector::new(n);
+    let mut t = Vector::new(n);
+    let (mut rho, mut alpha, mut omega) = (1.0, 1.0, 1.0);
+
+    for iteration in 1..=config.max_iterations {
+        let rho_new = vec_inner(&r_hat, &r);
+        if rho_new == 0.0 {
+            return Err("BiCGStab broke down because (r̂⋅r) is zero");
+        }
+        let beta = (rho_new / rho) * (alpha / omega);
+        rho = rho_new;
+        for i in 0..n {
+            p[i] = r[i] + beta * (p[i] - omega * v[i]);
+        }
+        precond.apply(&mut p_hat, &p)?;
+        a.apply(&mut v, &p_hat)?;
+        let r_hat_v = vec_inner(&r_hat, &v);
+        if r_hat_v == 0.0 {
+            return Err("BiCGStab broke down because (r̂⋅v) is zero");
+        }
+        alpha = rho / r_hat_v;
+        for i in 0..n {
+            s[i] = r[i] - alpha * v[i];
+        }
+
+        // early exit with the half step
+        let norm_s = vec_norm(&s, Norm::Euc);
+        if norm_s <= target {
+            for i in 0..n {
+                x[i] += alpha * p_hat[i];
+            }
+            monitor.update(iteration, norm_s);
+            return Ok(IterativeStats {
+                iterations: iteration,
+                residual_norm: norm_s,
+                converged: true,
+            });
+        }
+
+        // stabilization step
+        precond.apply(&mut s_hat, &s)?;
+        a.apply(&mut t, &s_hat)?;
+        let tt = vec_inner(&t, &t);
+        omega = if tt > 0.0 { vec_inner(&t, &s) / tt } else { 0.0 };
+        if omega == 0.0 {
+            return Err("BiCGStab broke down because ω is zero");
+        }
+        for i in 0..n {
+            x[i] += alpha * p_hat[i] + omega * s_hat[i];
+            r[i] = s[i] - omega * t[i];
+        }
+        residual_norm = vec_norm(&r, Norm::Euc);
+        let proceed = monitor.update(iteration, residual_norm);
+        if residual_norm <= target || !proceed {
+            return Ok(IterativeStats {
+                iterations: iteration,
+                residual_norm,
+                converged: residual_norm <= target,
+            });
+        }
+    }
+    Ok(IterativeStats {
+        iterations: config.max_iterations,
+        residual_norm,
+        converged: false,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::solve_bicgstab;
+    use crate::{
+        ConfigIterative, IterativeMonitor, PrecondIdentity, PrecondJacobi, SparseCsr, SparseTriplet, TripletSymmetry,
+    };
+    use russell_lab::Vector;
+
+    // convection-diffusion operator (non-symmetric)
+    fn convection_diffusion_1d(n: usize) -> SparseCsr {
+        let mut trip = SparseTriplet::new(n, 3 * n, TripletSymmetry::None).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 2.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -1.5).unwrap();
+            }
+            if i + 1 < n {
+                trip.put(i, i + 1, -0.5).unwrap();
+            }
+        }
+        SparseCsr::from_triplet(&trip)
+    }
+
+    #[test]
+    fn solve_bicgstab_captures_errors() {
+        let a = convection_diffusion_1d(3);
+        let mut x = Vector::new(3);
+        let mut monitor = IterativeMonitor::new();
+        let config = ConfigIterative::new();
+        assert_eq!(
+            solve_bicgstab(&mut x, &a, &Vector::new(2), &mut PrecondIdentity, &config, &mut monitor).err(),
+            Some("vectors are incompatible with the matrix")
+        );
+        let mut trip = SparseTriplet::new(2, 2, TripletSymmetry::None).unwrap();
+        trip.put(0, 1, 1.0).unwrap();
+        trip.put(1, 0, 1.0).unwrap();
+        let a = SparseCsr::from_triplet(&trip);
+        let mut x = Vector::new(2);
+        assert_eq!(
+            solve_bicgstab(
+                &mut x,
+                &a,
+                &Vector::from(&[1.0, 0.0]),
+                &mut PrecondIdentity,
+                &config,
+                &mut monitor
+            )
+            .err(),
+            Some("BiCGStab broke down because (r̂⋅v) is zero")
+        );
+    }
+
+    #[test]
+    fn solve_bicgstab_works() {
+        // the solution is x = (1, 1, ..., 1)
+        let n = 40;
+        let a = convection_diffusion_1d(n);
+        let mut b = Vector::new(n);
+        b[0] = 1.5;
+        b[n - 1] = 0.5;
+        let mut x = Vector::new(n);
+        let mut monitor = IterativeMonitor::new();
+        monitor.record_history(true);
+        let config = *ConfigIterative::new().tolerance(1e-12).max_iterations(200);
+        let stats = solve_bicgstab(&mut x, &a, &b, &mut PrecondIdentity, &config, &mut monitor).unwrap();
+        assert!(stats.converged);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - 1.0) < 1e-9);
+        }
+        let history = monitor.get_history();
+        assert_eq!(history.dim(), stats.iterations + 1);
+        assert_eq!(history[stats.iterations], stats.residual_norm);
+
+        // warm start with the solution
+        let stats = solve_bicgstab(&mut x, &a, &b, &mut PrecondIdentity, &config, &mut monitor).unwrap();
+        assert_eq!(stats.iterations, 0);
+        assert_eq!(monitor.get_history().dim(), 1);
+
+        // with preconditioner
+        let mut x = Vector::new(n);
+        let mut precond = PrecondJacobi::new(&a).unwrap();
+        let stats = solve_bicgstab(&mut x, &a, &b, &mut precond, &config, &mut monitor).unwrap();
+        assert!(stats.converged);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - 1.0) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn solve_bicgstab_stops_on_request() {
+        let n = 40;
+        let a = convection_diffusion_1d(n);
+        let b = Vector::filled(n, 1.0);
+        let mut x = Vector::new(n);
+        let mut monitor = IterativeMonitor::new();
+        monitor.set_observer(|iteration, _| iteration < 2);
+        let config = *ConfigIterative::new().tolerance(1e-14);
+        let stats = solve_bicgstab(&mut x, &a, &b, &mut PrecondIdentity, &config, &mut monitor).unwrap();
+        assert!(!stats.converged);
+        assert_eq!(stats.iterations, 2);
+    }
+}
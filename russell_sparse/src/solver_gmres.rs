@@ -0,0 +1,386 @@
+use super::{ConfigIterative, IterativeMonitor, IterativeStats, LinearOperator, Preconditioner};
+use crate::StrError;
+use russell_lab::{vec_inner, vec_norm, Matrix, Norm, Vector};
+
+/// Solves a general (non-symmetric) system with the restarted generalized minimal residual method GMRES(m)
+///
+/// Finds `x` such that `a ⋅ x = b` with:
+///
+/// ```text
+/// ‖b - a⋅x‖₂ ≤ tolerance ⋅ ‖b‖₂
+/// ```
+///
+/// # Input
+///
+/// * `x` -- on input, the initial guess (e.g., zero or a previous solution); on output, the solution
+/// * `a` -- the coefficient matrix or matrix-free operator (see [LinearOperator])
+/// * `b` -- the right-hand side
+/// * `precond` -- the (right) preconditioner (see [Preconditioner])
+/// * `config` -- the tolerance, the maximum number of (inner) iterations including all restarts,
+///   and the dimension `m` of the Krylov subspace before restarting (see [ConfigIterative])
+/// * `monitor` -- receives the residual norm of each iteration and may stop the iterations (see [IterativeMonitor])
+///
+/// **Note:** Within a cycle, the residual norm given to the monitor is the (cheap) estimate from the
+/// least-squares problem. The residual norm in [IterativeStats] is computed explicitly at the end.
+///
+/// **Note:** No error is returned if the tolerance is not reached; check [IterativeStats::converged] instead.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{solve_gmres, ConfigIterative, IterativeMonitor, PrecondIdentity};
+/// use russell_sparse::{SparseCsr, SparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut trip = SparseTriplet::new(3, 6, TripletSymmetry::None)?;
+///     trip.put(0, 0, 4.0)?;
+///     trip.put(0, 1, -1.0)?;
+///     trip.put(1, 0, 2.0)?;
+///     trip.put(1, 1, 5.0)?;
+///     trip.put(1, 2, 1.0)?;
+///     trip.put(2, 2, 3.0)?;
+///     let csr = SparseCsr::from_triplet(&trip);
+///     let b = Vector::from(&[3.0, 8.0, 3.0]);
+///     let mut x = Vector::new(3);
+///     let config = *ConfigIterative::new().restart(3);
+///     let mut precond = PrecondIdentity;
+///     let stats = solve_gmres(&mut x, &csr, &b, &mut precond, &config, &mut IterativeMonitor::new())?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.6}", x), "┌          ┐\n\
+///                                      │ 1.000000 │\n\
+///                                      │ 1.000000 │\n\
+///                                      │ 1.000000 │\n\
+///                                      └          ┘");
+///     Ok(())
+/// }
+/// ```
+pub fn solve_gmres(
+    x: &mut Vector,
+    a: &dyn LinearOperator,
+    b: &Vector,
+    precond: &mut dyn Preconditioner,
+    config: &ConfigIterative,
+    monitor: &mut IterativeMonitor,
+) -> Result<IterativeStats, StrError> {
+    let (n, ncol) = a.dims();
+    if ncol != n {
+        return Err("the operator must be square");
+    }
+    if x.dim() != n || b.dim() != n {
+        return Err("vectors are incompatible with the matrix");
+    }
+    if config.restart < 1 {
+        return Err("restart must be at least 1");
+    }
+    let m = usize::min(config.restart, n);
+    let max_iterations = config.max_iterations;
+
+    // r = b - a⋅x
+    let mut r = residual(x, a, b)?;
+    let norm_b = vec_norm(b, Norm::Euc);
+    let target = if norm_b > 0.0 {
+        config.tolerance * norm_b
+    } else {
+        config.tolerance
+    };
+    let mut residual_norm = vec_norm(&r, Norm::Euc);
+    monitor.reset();
+    let mut proceed = monitor.update(0, residual_norm);
+    if residual_norm <= target || !proceed {
+        return Ok(IterativeStats {
+            iterations: 0,
+            residual_norm,
+            converged: residual_norm <= target,
+        });
+    }
+
+    // Krylov basis, Hessenberg matrix, Givens rotations, and right-hand side of the least-squares problem
+    let mut basis = vec![Vector::new(n); m + 1];
+    let mut h = Matrix::new(m + 1, m);
+    let mut cs = vec![0.0; m];
+    let mut sn = vec![0.0; m];
+    let mut g = vec![0.0; m + 1];
+    let mut z = Vector::new(n);
+    let mut w = Vector::new(n);
+
+    let mut iteration = 0;
+    while iteration < max_iterations {
+        // start cycle
+        for i in 0..n {
+            basis[0][i] = r[i] / residual_norm;
+        }
+        g.fill(0.0);
+        g[0] = residual_norm;
+        let mut k_used = 0;
+
+        for k in 0..m {
+            iteration += 1;
+
+            // Arnoldi process with modified Gram-Schmidt (w = a⋅M⁻¹⋅vₖ)
+            precond.apply(&mut z, &basis[k])?;
+            a.apply(&mut w, &z)?;
+            for (i, v) in basis.iter().enumerate().take(k + 1) {
+                let hik = vec_inner(&w, v);
+                h.set(i, k, hik);
+                for l in 0..n {
+                    w[l] -= hik * v[l];
+                }
+            }
+            let norm_w = vec_norm(&w, Norm::Euc);
+            h.set(k + 1, k, norm_w);
+            if norm_w > 0.0 {
+                for l in 0..n {
+                    basis[k + 1][l] = w[l] / norm_w;
+                }
+            }
+
+            // apply the previous rotations to the new column
+            for i in 0..k {
+                let (hik, hjk) = (h.get(i, k), h.get(i + 1, k));
+                h.set(i, k, cs[i] * hik + sn[i] * hjk);
+                h.set(i + 1, k, -sn[i] * hik + cs[i] * hjk);
+            }
+
+            // compute and apply the new rotation
+            let (hkk, hjk) = (h.get(k, k), h.get(k + 1, k));
+            let denom = f64::hypot(hkk, hjk);
+            if denom == 0.0 {
+                return Err("GMRES broke down because the matrix is singular");
+            }
+            cs[k] = hkk / denom;
+            sn[k] = hjk / denom;
+            h.set(k, k, denom);
+            h.set(k + 1, k, 0.0);
+            g[k + 1] = -sn[k] * g[k];
+            g[k] *= cs[k];
+
+            k_used = k + 1;
+            let estimate = f64::abs(g[k + 1]);
+            proceed = monitor.update(iteration, estimate);
+            if estimate <= target || !proceed || norm_w == 0.0 || iteration == max_iterations {
+                break;
+            }
+        }
+
+        // solve the upper triangular system and update x (x += M⁻¹⋅V⋅y)
+        let mut y = vec![0.0; k_used];
+        for i in (0..k_used).rev() {
+            let mut sum = g[i];
+            for (j, yj) in y.iter().enumerate().skip(i + 1) {
+                sum -= h.get(i, j) * yj;
+            }
+            y[i] = sum / h.get(i, i);
+        }
+        let mut u = Vector::new(n);
+        for j in 0..k_used {
+            for l in 0..n {
+                u[l] += y[j] * basis[j][l];
+            }
+        }
+        precond.apply(&mut z, &u)?;
+        for l in 0..n {
+            x[l] += z[l];
+        }
+
+        // compute the true residual
+        r = residual(x, a, b)?;
+        residual_norm = vec_norm(&r, Norm::Euc);
+        if residual_norm <= target || !proceed {
+            break;
+        }
+    }
+    Ok(IterativeStats {
+        iterations: iteration,
+        residual_norm,
+        converged: residual_norm <= target,
+    })
+}
+
+/// Computes r = b - a⋅x
+fn residual(x: &Vector, a: &dyn LinearOperator, b: &Vector) -> Result<Vector, StrError> {
+    let mut r = Vector::new(b.dim());
+    a.apply(&mut r, x)?;
+    for i in 0..r.dim() {
+        r[i] = b[i] - r[i];
+    }
+    Ok(r)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::solve_gmres;
+    use crate::{
+        ConfigIterative, IterativeMonitor, LinearOperatorFn, PrecondIdentity, PrecondJacobi, SparseCsr, SparseTriplet,
+        TripletSymmetry,
+    };
+    use russell_lab::Vector;
+
+    // convection-diffusion operator (non-symmetric)
+    fn convection_diffusion_1d(n: usize) -> SparseCsr {
+        let mut trip = SparseTriplet::new(n, 3 * n, TripletSymmetry::None).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 2.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -1.5).unwrap();
+            }
+            if i + 1 < n {
+                trip.put(i, i + 1, -0.5).unwrap();
+            }
+        }
+        SparseCsr::from_triplet(&trip)
+    }
+
+    #[test]
+    fn solve_gmres_captures_errors() {
+        let a = convection_diffusion_1d(3);
+        let mut x = Vector::new(3);
+        let mut monitor = IterativeMonitor::new();
+        let config = *ConfigIterative::new().restart(2);
+        assert_eq!(
+            solve_gmres(&mut x, &a, &Vector::new(2), &mut PrecondIdentity, &config, &mut monitor).err(),
+            Some("vectors are incompatible with the matrix")
+        );
+        let b = Vector::filled(3, 1.0);
+        assert_eq!(
+            solve_gmres(
+                &mut x,
+                &a,
+                &b,
+                &mut PrecondIdentity,
+                ConfigIterative::new().restart(0),
+                &mut monitor
+            )
+            .err(),
+            Some("restart must be at least 1")
+        );
+        let mut trip = SparseTriplet::new(2, 1, TripletSymmetry::None).unwrap();
+        trip.put(0, 1, 1.0).unwrap();
+        let a = SparseCsr::from_triplet(&trip);
+        let mut x = Vector::new(2);
+        assert_eq!(
+            solve_gmres(
+                &mut x,
+                &a,
+                &Vector::from(&[0.0, 1.0]),
+                &mut PrecondIdentity,
+                &config,
+                &mut monitor
+            )
+            .err(),
+            Some("GMRES broke down because the matrix is singular")
+        );
+    }
+
+    #[test]
+    fn solve_gmres_works() {
+        // the solution is x = (1, 1, ..., 1)
+        let n = 40;
+        let a = convection_diffusion_1d(n);
+        let mut b = Vector::new(n);
+        b[0] = 1.5;
+        b[n - 1] = 0.5;
+
+        // full GMRES
+        let mut x = Vector::new(n);
+        let mut monitor = IterativeMonitor::new();
+        monitor.record_history(true);
+        let config = *ConfigIterative::new().tolerance(1e-12).max_iterations(100).restart(n);
+        let stats = solve_gmres(&mut x, &a, &b, &mut PrecondIdentity, &config, &mut monitor).unwrap();
+        assert!(stats.converged);
+        assert!(stats.iterations <= n);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - 1.0) < 1e-9);
+        }
+        let history = monitor.get_history();
+        assert_eq!(history.dim(), stats.iterations + 1);
+        for k in 1..history.dim() {
+            assert!(history[k] <= history[k - 1] * (1.0 + 1e-12)); // monotonic within a cycle
+        }
+
+        // restarted GMRES
+        let mut x = Vector::new(n);
+        let config = *ConfigIterative::new().restart(5);
+        let stats = solve_gmres(&mut x, &a, &b, &mut PrecondIdentity, &config, &mut monitor).unwrap();
+        assert!(stats.converged);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - 1.0) < 1e-8);
+        }
+
+        // with preconditioner
+        let mut x = Vector::new(n);
+        let mut precond = PrecondJacobi::new(&a).unwrap();
+        let stats = solve_gmres(&mut x, &a, &b, &mut precond, &config, &mut monitor).unwrap();
+        assert!(stats.converged);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - 1.0) < 1e-8);
+        }
+    }
+
+    #[test]
+    fn solve_gmres_stops_on_request() {
+        let n = 40;
+        let a = convection_diffusion_1d(n);
+        let b = Vector::filled(n, 1.0);
+        let mut x = Vector::new(n);
+        let mut monitor = IterativeMonitor::new();
+        monitor.set_observer(|iteration, _| iteration < 3);
+        let config = *ConfigIterative::new().tolerance(1e-14).restart(10);
+        let stats = solve_gmres(&mut x, &a, &b, &mut PrecondIdentity, &config, &mut monitor).unwrap();
+        assert!(!stats.converged);
+        assert_eq!(stats.iterations, 3);
+
+        // no convergence within the maximum number of iterations
+        let mut x = Vector::new(n);
+        let config = *ConfigIterative::new().tolerance(1e-14).max_iterations(7).restart(2);
+        let stats = solve_gmres(
+            &mut x,
+            &a,
+            &b,
+            &mut PrecondIdentity,
+            &config,
+            &mut IterativeMonitor::new(),
+        )
+        .unwrap();
+        assert!(!stats.converged);
+        assert_eq!(stats.iterations, 7);
+    }
+
+    #[test]
+    fn solve_gmres_works_with_matrix_free_operator() {
+        // same as convection_diffusion_1d, defined by a closure
+        let n = 40;
+        let op = LinearOperatorFn::new(n, n, |y, x| {
+            for i in 0..n {
+                y[i] = 2.0 * x[i];
+                if i > 0 {
+                    y[i] -= 1.5 * x[i - 1];
+                }
+                if i + 1 < n {
+                    y[i] -= 0.5 * x[i + 1];
+                }
+            }
+            Ok(())
+        });
+        let mut b = Vector::new(n);
+        b[0] = 1.5;
+        b[n - 1] = 0.5;
+        let mut x = Vector::new(n);
+        let config = *ConfigIterative::new().tolerance(1e-12).max_iterations(100).restart(n);
+        let stats = solve_gmres(
+            &mut x,
+            &op,
+            &b,
+            &mut PrecondIdentity,
+            &config,
+            &mut IterativeMonitor::new(),
+        )
+        .unwrap();
+        assert!(stats.converged);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - 1.0) < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,444 @@
+use super::{solve_gmres, ConfigIterative, IterativeMonitor, LinearOperator, Preconditioner};
+use crate::StrError;
+use russell_lab::{vec_norm, Norm, Vector};
+use std::cell::RefCell;
+
+/// Holds configuration options for the Jacobian-free Newton-Krylov solver
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigJfnk {
+    pub(crate) tol_abs: f64,                      // absolute tolerance on the residual norm
+    pub(crate) tol_rel: f64,                      // relative tolerance on the residual norm
+    pub(crate) max_iterations: usize,             // max number of (nonlinear) iterations
+    pub(crate) forcing_max: f64,                  // maximum forcing term (relative tolerance of GMRES)
+    pub(crate) perturbation: f64,                 // relative perturbation of the finite differences
+    pub(crate) config_iterative: ConfigIterative, // configuration of GMRES
+}
+
+impl ConfigJfnk {
+    /// Returns a default configuration
+    pub fn new() -> Self {
+        ConfigJfnk {
+            tol_abs: 1e-12,
+            tol_rel: 1e-10,
+            max_iterations: 50,
+            forcing_max: 0.9,
+            perturbation: f64::sqrt(f64::EPSILON),
+            config_iterative: ConfigIterative::new(),
+        }
+    }
+
+    /// Sets the absolute and relative tolerances
+    ///
+    /// The iterations stop when `‖R‖₂ ≤ tol_abs + tol_rel ⋅ ‖R₀‖₂`.
+    pub fn tolerances(&mut self, tol_abs: f64, tol_rel: f64) -> &mut Self {
+        self.tol_abs = tol_abs;
+        self.tol_rel = tol_rel;
+        self
+    }
+
+    /// Sets the maximum number of (nonlinear) iterations
+    pub fn max_iterations(&mut self, value: usize) -> &mut Self {
+        self.max_iterations = value;
+        self
+    }
+
+    /// Sets the maximum forcing term `η_max` (the largest relative tolerance given to GMRES)
+    pub fn forcing_max(&mut self, value: f64) -> &mut Self {
+        self.forcing_max = value;
+        self
+    }
+
+    /// Sets the relative perturbation `δ` of the finite-difference directional derivatives
+    pub fn perturbation(&mut self, value: f64) -> &mut Self {
+        self.perturbation = value;
+        self
+    }
+
+    /// Sets the configuration of GMRES (the tolerance is replaced by the forcing term)
+    pub fn config_iterative(&mut self, config: ConfigIterative) -> &mut Self {
+        self.config_iterative = config;
+        self
+    }
+}
+
+impl Default for ConfigJfnk {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Holds the results of the Jacobian-free Newton-Krylov iterations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JfnkStats {
+    /// Number of (nonlinear) iterations performed
+    pub iterations: usize,
+
+    /// Total number of GMRES iterations (each one requires one residual evaluation)
+    pub linear_iterations: usize,
+
+    /// Euclidean norm of the final residual
+    pub residual_norm: f64,
+
+    /// Indicates whether the tolerance was reached or not
+    pub converged: bool,
+}
+
+/// Implements the Jacobian-free Newton-Krylov (JFNK) solver for nonlinear systems
+///
+/// Finds `u` such that `R(u) = 0` by the inexact Newton method. The Newton step is computed by
+/// GMRES (see [crate::solve_gmres]) without forming the Jacobian matrix; instead, the
+/// Jacobian-vector products are approximated by finite-difference directional derivatives:
+///
+/// ```text
+///          R(u + ε v) - R(u)                δ (1 + ‖u‖₂)
+/// J ⋅ v ≈ ───────────────────    with   ε = ────────────
+///                  ε                           ‖v‖₂
+/// ```
+///
+/// Thus, only the residual function is required, and very large systems can be solved.
+///
+/// The linear systems are solved inexactly, i.e., `‖R + J ⋅ Δu‖₂ ≤ η ⋅ ‖R‖₂`, where the
+/// forcing term `η ≤ η_max` is selected by the second choice of Eisenstat and Walker;
+/// thus, the linear tolerance is loose far from the solution and tight near it.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{ConfigJfnk, PrecondIdentity, SolverJfnk, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // R(u) = (u₀² + u₁² - 4, u₀ - u₁)
+///     let mut solver = SolverJfnk::new(ConfigJfnk::new(), 2)?;
+///     let mut u = Vector::from(&[1.0, 2.0]);
+///     let stats = solver.solve(
+///         &mut u,
+///         |r: &mut Vector, u: &Vector| {
+///             r[0] = u[0] * u[0] + u[1] * u[1] - 4.0;
+///             r[1] = u[0] - u[1];
+///             Ok(())
+///         },
+///         &mut PrecondIdentity,
+///     )?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.6}", u[0]), "1.414214");
+///     assert_eq!(format!("{:.6}", u[1]), "1.414214");
+///     Ok(())
+/// }
+/// ```
+pub struct SolverJfnk {
+    config: ConfigJfnk, // configuration
+    neq: usize,         // number of equations
+}
+
+impl SolverJfnk {
+    /// Creates a new Jacobian-free Newton-Krylov solver
+    ///
+    /// # Input
+    ///
+    /// * `config` -- The configuration parameters
+    /// * `neq` -- The number of equations
+    pub fn new(config: ConfigJfnk, neq: usize) -> Result<Self, StrError> {
+        if neq == 0 {
+            return Err("neq must be greater than zero");
+        }
+        if config.max_iterations == 0 {
+            return Err("max_iterations must be greater than zero");
+        }
+        if config.forcing_max <= 0.0 || config.forcing_max >= 1.0 {
+            return Err("forcing_max must satisfy 0 < forcing_max < 1");
+        }
+        if config.perturbation <= 0.0 {
+            return Err("perturbation must be greater than zero");
+        }
+        Ok(SolverJfnk { config, neq })
+    }
+
+    /// Solves the nonlinear system
+    ///
+    /// # Input
+    ///
+    /// * `u` -- on input, the initial guess; on output, the solution
+    /// * `calc_residual` -- computes the residual vector `r = R(u)`
+    /// * `precond` -- the (right) preconditioner of GMRES (e.g., based on an approximate Jacobian; see [Preconditioner])
+    ///
+    /// **Note:** No error is returned if the tolerance is not reached; check [JfnkStats::converged] instead.
+    pub fn solve<F>(
+        &mut self,
+        u: &mut Vector,
+        calc_residual: F,
+        precond: &mut dyn Preconditioner,
+    ) -> Result<JfnkStats, StrError>
+    where
+        F: FnMut(&mut Vector, &Vector) -> Result<(), StrError>,
+    {
+        let n = self.neq;
+        if u.dim() != n {
+            return Err("u vector must have dimension equal to neq");
+        }
+        let calc_residual = RefCell::new(calc_residual);
+        let mut r = Vector::new(n);
+        let mut mr = Vector::new(n);
+        let mut du = Vector::new(n);
+        let mut monitor = IterativeMonitor::new();
+        let mut config_gmres = self.config.config_iterative;
+        let mut linear_iterations = 0;
+
+        // initial residual
+        (calc_residual.borrow_mut())(&mut r, u)?;
+        let mut norm_r = vec_norm(&r, Norm::Euc);
+        let target = self.config.tol_abs + self.config.tol_rel * norm_r;
+        let mut eta = self.config.forcing_max;
+
+        for iteration in 0..self.config.max_iterations {
+            if norm_r <= target {
+                return Ok(JfnkStats {
+                    iterations: iteration,
+                    linear_iterations,
+                    residual_norm: norm_r,
+                    converged: true,
+                });
+            }
+
+            // inexact Newton step: J ⋅ Δu = -R
+            for i in 0..n {
+                mr[i] = -r[i];
+            }
+            du.fill(0.0);
+            config_gmres.tolerance(eta);
+            let jacobian = JacobianFree {
+                calc_residual: &calc_residual,
+                u,
+                r: &r,
+                u_perturbed: RefCell::new(Vector::new(n)),
+                epsilon_factor: self.config.perturbation * (1.0 + vec_norm(u, Norm::Euc)),
+            };
+            let stats = solve_gmres(&mut du, &jacobian, &mr, precond, &config_gmres, &mut monitor)?;
+            linear_iterations += stats.iterations;
+
+            // update
+            for i in 0..n {
+                u[i] += du[i];
+            }
+            (calc_residual.borrow_mut())(&mut r, u)?;
+            let norm_r_old = norm_r;
+            norm_r = vec_norm(&r, Norm::Euc);
+            eta = forcing_term(eta, norm_r, norm_r_old, target, self.config.forcing_max);
+        }
+        Ok(JfnkStats {
+            iterations: self.config.max_iterations,
+            linear_iterations,
+            residual_norm: norm_r,
+            converged: norm_r <= target,
+        })
+    }
+}
+
+/// Approximates the Jacobian-vector product by finite differences
+struct JacobianFree<'a, F> {
+    calc_residual: &'a RefCell<F>, // computes R(u)
+    u: &'a Vector,                 // current state
+    r: &'a Vector,                 // R(u)
+    u_perturbed: RefCell<Vector>,  // u + ε v
+    epsilon_factor: f64,           // δ (1 + ‖u‖₂)
+}
+
+impl<'a, F> LinearOperator for JacobianFree<'a, F>
+where
+    F: FnMut(&mut Vector, &Vector) -> Result<(), StrError>,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.u.dim(), self.u.dim())
+    }
+
+    fn apply(&self, y: &mut Vector, v: &Vector) -> Result<(), StrError> {
+        let n = self.u.dim();
+        if y.dim() != n || v.dim() != n {
+            return Err("vectors are incompatible with the operator");
+        }
+        let norm_v = vec_norm(v, Norm::Euc);
+        if norm_v == 0.0 {
+            y.fill(0.0);
+            return Ok(());
+        }
+        let epsilon = self.epsilon_factor / norm_v;
+        let mut u_perturbed = self.u_perturbed.borrow_mut();
+        for i in 0..n {
+            u_perturbed[i] = self.u[i] + epsilon * v[i];
+        }
+        (self.calc_residual.borrow_mut())(y, &u_perturbed)?;
+        for i in 0..n {
+            y[i] = (y[i] - self.r[i]) / epsilon;
+        }
+        Ok(())
+    }
+}
+
+/// Computes the forcing term by the second choice of Eisenstat and Walker (with safeguards)
+///
+/// ```text
+/// η = γ (‖R‖ / ‖R_old‖)²    with γ = 0.9
+/// ```
+///
+/// The term is not allowed to decrease too fast (when `γ η_old² > 0.1`) and to become
+/// smaller than what is needed to reach the nonlinear tolerance (to avoid over-solving).
+fn forcing_term(eta_old: f64, norm_r: f64, norm_r_old: f64, target: f64, forcing_max: f64) -> f64 {
+    const GAMMA: f64 = 0.9;
+    let ratio = norm_r / norm_r_old;
+    let mut eta = GAMMA * ratio * ratio;
+    let safeguard = GAMMA * eta_old * eta_old;
+    if safeguard > 0.1 {
+        eta = f64::max(eta, safeguard);
+    }
+    if norm_r > 0.0 {
+        eta = f64::max(eta, 0.5 * target / norm_r);
+    }
+    f64::min(eta, forcing_max)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{forcing_term, ConfigJfnk, JacobianFree, SolverJfnk};
+    use crate::{ConfigIterative, LinearOperator, PrecondIdentity, StrError};
+    use russell_chk::vec_approx_eq;
+    use russell_lab::Vector;
+    use std::cell::RefCell;
+
+    #[test]
+    fn config_jfnk_works() {
+        let mut config = ConfigJfnk::new();
+        config
+            .tolerances(1e-8, 1e-6)
+            .max_iterations(5)
+            .forcing_max(0.5)
+            .perturbation(1e-6)
+            .config_iterative(*ConfigIterative::new().restart(10));
+        assert_eq!(config.tol_abs, 1e-8);
+        assert_eq!(config.tol_rel, 1e-6);
+        assert_eq!(config.max_iterations, 5);
+        assert_eq!(config.forcing_max, 0.5);
+        assert_eq!(config.perturbation, 1e-6);
+        assert_eq!(config.config_iterative.restart, 10);
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            SolverJfnk::new(ConfigJfnk::new(), 0).err(),
+            Some("neq must be greater than zero")
+        );
+        let mut config = ConfigJfnk::new();
+        config.max_iterations(0);
+        assert_eq!(
+            SolverJfnk::new(config, 1).err(),
+            Some("max_iterations must be greater than zero")
+        );
+        let mut config = ConfigJfnk::new();
+        config.forcing_max(1.0);
+        assert_eq!(
+            SolverJfnk::new(config, 1).err(),
+            Some("forcing_max must satisfy 0 < forcing_max < 1")
+        );
+        let mut config = ConfigJfnk::new();
+        config.perturbation(0.0);
+        assert_eq!(
+            SolverJfnk::new(config, 1).err(),
+            Some("perturbation must be greater than zero")
+        );
+        let mut solver = SolverJfnk::new(ConfigJfnk::new(), 2).unwrap();
+        assert_eq!(
+            solver
+                .solve(&mut Vector::new(1), |_, _| Ok(()), &mut PrecondIdentity)
+                .err(),
+            Some("u vector must have dimension equal to neq")
+        );
+    }
+
+    #[test]
+    fn forcing_term_works() {
+        // quadratic decrease
+        assert_eq!(forcing_term(0.1, 1.0, 10.0, 0.0, 0.9), 0.9 * 0.01);
+        // safeguard: not decreasing too fast
+        assert_eq!(forcing_term(0.9, 1.0, 10.0, 0.0, 0.9), 0.9 * 0.81);
+        // capped by forcing_max
+        assert_eq!(forcing_term(0.1, 10.0, 1.0, 0.0, 0.5), 0.5);
+        // not over-solving near the solution
+        assert_eq!(forcing_term(0.1, 1e-6, 1.0, 1e-6, 0.9), 0.5);
+    }
+
+    fn calc_residual(r: &mut Vector, u: &Vector) -> Result<(), StrError> {
+        // same as in solver_newton: R = ∇f with f = (u0 - 2)² + (u1 + 1)² + u0⁴/4 + u0 u1
+        r[0] = 2.0 * (u[0] - 2.0) + u[0] * u[0] * u[0] + u[1];
+        r[1] = 2.0 * (u[1] + 1.0) + u[0];
+        Ok(())
+    }
+
+    #[test]
+    fn jacobian_free_operator_works() {
+        let calc = RefCell::new(calc_residual);
+        let u = Vector::from(&[1.0, 2.0]);
+        let mut r = Vector::new(2);
+        calc_residual(&mut r, &u).unwrap();
+        let jacobian = JacobianFree {
+            calc_residual: &calc,
+            u: &u,
+            r: &r,
+            u_perturbed: RefCell::new(Vector::new(2)),
+            epsilon_factor: f64::sqrt(f64::EPSILON) * 3.0,
+        };
+        assert_eq!(jacobian.dims(), (2, 2));
+        let mut y = Vector::new(2);
+        assert_eq!(
+            jacobian.apply(&mut y, &Vector::new(3)).err(),
+            Some("vectors are incompatible with the operator")
+        );
+        jacobian.apply(&mut y, &Vector::new(2)).unwrap();
+        assert_eq!(y.as_data(), &[0.0, 0.0]);
+        // J = [[2 + 3 u0², 1], [1, 2]] = [[5, 1], [1, 2]]
+        jacobian.apply(&mut y, &Vector::from(&[1.0, -1.0])).unwrap();
+        vec_approx_eq(y.as_data(), &[4.0, -1.0], 1e-6);
+    }
+
+    #[test]
+    fn solve_works() {
+        let mut solver = SolverJfnk::new(ConfigJfnk::new(), 2).unwrap();
+        let mut u = Vector::from(&[5.0, 5.0]);
+        let stats = solver.solve(&mut u, calc_residual, &mut PrecondIdentity).unwrap();
+        assert!(stats.converged);
+        assert!(stats.linear_iterations >= stats.iterations);
+        let mut r = Vector::new(2);
+        calc_residual(&mut r, &u).unwrap();
+        vec_approx_eq(r.as_data(), &[0.0, 0.0], 1e-10);
+    }
+
+    #[test]
+    fn solve_works_with_large_system() {
+        // Bratu problem: -u'' - λ exp(u) = 0 in (0,1) with u(0) = u(1) = 0 (finite differences)
+        let n = 200;
+        let h = 1.0 / ((n + 1) as f64);
+        let lambda = 1.0;
+        let bratu = |r: &mut Vector, u: &Vector| {
+            for i in 0..n {
+                let left = if i > 0 { u[i - 1] } else { 0.0 };
+                let right = if i + 1 < n { u[i + 1] } else { 0.0 };
+                r[i] = (2.0 * u[i] - left - right) / (h * h) - lambda * f64::exp(u[i]);
+            }
+            Ok(())
+        };
+        let mut config = ConfigJfnk::new();
+        config
+            .tolerances(1e-8, 1e-8)
+            .config_iterative(*ConfigIterative::new().restart(n));
+        let mut solver = SolverJfnk::new(config, n).unwrap();
+        let mut u = Vector::new(n);
+        let stats = solver.solve(&mut u, bratu, &mut PrecondIdentity).unwrap();
+        assert!(stats.converged);
+        assert!(stats.iterations < 10);
+        // the solution is symmetric with maximum ≈ 0.1405 at x = 0.5 (lower branch)
+        let mid = u[n / 2];
+        assert!(f64::abs(mid - 0.1405) < 1e-3);
+        assert!(f64::abs(u[0] - u[n - 1]) < 1e-6);
+    }
+}
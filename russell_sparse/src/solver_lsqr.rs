@@ -0,0 +1,327 @@
+use super::{ConfigIterative, IterativeMonitor, IterativeStats, LinearOperator};
+use crate::StrError;
+use russell_lab::{vec_norm, Norm, Vector};
+
+/// Solves a rectangular (or square) system in the least-squares sense with the LSQR method
+///
+/// Finds `x` minimizing `‖b - a⋅x‖₂` where `a` is an `(m,n)` operator with `m ≥ n` or `m < n`.
+/// If the initial guess is zero, the iterations converge to the minimum-norm solution
+/// (i.e., the solution with the smallest `‖x‖₂`), even if `a` is rank-deficient.
+///
+/// The iterations stop when one of the criteria of Paige and Saunders (1982) is satisfied:
+///
+/// ```text
+/// (consistent system)       ‖r‖₂ ≤ tolerance ⋅ ‖b‖₂
+/// (least-squares problem)   ‖aᵀ⋅r‖₂ ≤ tolerance ⋅ ‖a‖_F ⋅ ‖r‖₂
+/// ```
+///
+/// where `r = b - a⋅x` and `‖a‖_F` is estimated during the iterations. The norms of `r` are the
+/// estimates computed by the bidiagonalization (no extra matrix-vector products are performed);
+/// these are reported to the `monitor` and returned in [IterativeStats::residual_norm].
+///
+/// # Input
+///
+/// * `x` -- (n) on input, the initial guess (usually zero); on output, the solution
+/// * `a` -- (m,n) the coefficient matrix or matrix-free operator; [LinearOperator::apply_transpose] is required
+/// * `b` -- (m) the right-hand side
+/// * `config` -- the tolerance and maximum number of iterations (see [ConfigIterative])
+/// * `monitor` -- receives the residual norm of each iteration and may stop the iterations (see [IterativeMonitor])
+///
+/// **Note:** No error is returned if the tolerance is not reached; check [IterativeStats::converged] instead.
+///
+/// # Reference
+///
+/// Paige CC and Saunders MA (1982) LSQR: An algorithm for sparse linear equations and sparse
+/// least squares, ACM Transactions on Mathematical Software, 8(1):43-71
+///
+/// # Example
+///
+/// ```
+/// use russell_chk::vec_approx_eq;
+/// use russell_lab::Vector;
+/// use russell_sparse::{solve_lsqr, ConfigIterative, IterativeMonitor, RectSparseTriplet, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // overdetermined system: fit a line y = c0 + c1 t through (0,1), (1,3), (2,4)
+///     let mut trip = RectSparseTriplet::new(3, 2, 6)?;
+///     for (i, t) in [0.0, 1.0, 2.0].iter().enumerate() {
+///         trip.put(i, 0, 1.0)?;
+///         trip.put(i, 1, *t)?;
+///     }
+///     let b = Vector::from(&[1.0, 3.0, 4.0]);
+///     let mut x = Vector::new(2);
+///     let config = ConfigIterative::new();
+///     let stats = solve_lsqr(&mut x, &trip, &b, &config, &mut IterativeMonitor::new())?;
+///     assert!(stats.converged);
+///     vec_approx_eq(x.as_data(), &[7.0 / 6.0, 1.5], 1e-12);
+///     Ok(())
+/// }
+/// ```
+pub fn solve_lsqr(
+    x: &mut Vector,
+    a: &dyn LinearOperator,
+    b: &Vector,
+    config: &ConfigIterative,
+    monitor: &mut IterativeMonitor,
+) -> Result<IterativeStats, StrError> {
+    let (m, n) = a.dims();
+    if x.dim() != n || b.dim() != m {
+        return Err("vectors are incompatible with the matrix");
+    }
+
+    // β⋅u = b - a⋅x
+    let mut u = Vector::new(m);
+    a.apply(&mut u, x)?;
+    for i in 0..m {
+        u[i] = b[i] - u[i];
+    }
+    let mut beta = vec_norm(&u, Norm::Euc);
+    let norm_b = vec_norm(b, Norm::Euc);
+    let target = if norm_b > 0.0 {
+        config.tolerance * norm_b
+    } else {
+        config.tolerance
+    };
+    monitor.reset();
+    let proceed = monitor.update(0, beta);
+    if beta <= target || !proceed {
+        return Ok(IterativeStats {
+            iterations: 0,
+            residual_norm: beta,
+            converged: beta <= target,
+        });
+    }
+    scale(&mut u, 1.0 / beta);
+
+    // α⋅v = aᵀ⋅u
+    let mut v = Vector::new(n);
+    a.apply_transpose(&mut v, &u)?;
+    let mut alpha = vec_norm(&v, Norm::Euc);
+    if alpha == 0.0 {
+        // aᵀ⋅r = 0; thus, x is already a least-squares solution
+        return Ok(IterativeStats {
+            iterations: 0,
+            residual_norm: beta,
+            converged: true,
+        });
+    }
+    scale(&mut v, 1.0 / alpha);
+
+    let mut w = v.clone();
+    let mut av = Vector::new(m);
+    let mut atu = Vector::new(n);
+    let mut phi_bar = beta;
+    let mut rho_bar = alpha;
+    let mut norm_a_sq = 0.0;
+
+    for iteration in 1..=config.max_iterations {
+        // continue the bidiagonalization: β⋅u = a⋅v - α⋅u
+        a.apply(&mut av, &v)?;
+        for i in 0..m {
+            u[i] = av[i] - alpha * u[i];
+        }
+        beta = vec_norm(&u, Norm::Euc);
+        norm_a_sq += alpha * alpha + beta * beta;
+        if beta > 0.0 {
+            scale(&mut u, 1.0 / beta);
+        }
+
+        // α⋅v = aᵀ⋅u - β⋅v
+        a.apply_transpose(&mut atu, &u)?;
+        for j in 0..n {
+            v[j] = atu[j] - beta * v[j];
+        }
+        alpha = vec_norm(&v, Norm::Euc);
+        if alpha > 0.0 {
+            scale(&mut v, 1.0 / alpha);
+        }
+
+        // plane rotation to eliminate β (from the lower bidiagonal matrix)
+        let rho = f64::hypot(rho_bar, beta);
+        let c = rho_bar / rho;
+        let s = beta / rho;
+        let theta = s * alpha;
+        rho_bar = -c * alpha;
+        let phi = c * phi_bar;
+        phi_bar *= s;
+
+        // update x and w
+        let t1 = phi / rho;
+        let t2 = -theta / rho;
+        for j in 0..n {
+            x[j] += t1 * w[j];
+            w[j] = v[j] + t2 * w[j];
+        }
+
+        // check the stopping criteria
+        let residual_norm = phi_bar;
+        let norm_at_r = phi_bar * alpha * f64::abs(c);
+        let target_at_r = config.tolerance * f64::sqrt(norm_a_sq) * residual_norm;
+        let converged = residual_norm <= target || norm_at_r <= target_at_r;
+        let proceed = monitor.update(iteration, residual_norm);
+        if converged || !proceed {
+            return Ok(IterativeStats {
+                iterations: iteration,
+                residual_norm,
+                converged,
+            });
+        }
+    }
+    Ok(IterativeStats {
+        iterations: config.max_iterations,
+        residual_norm: phi_bar,
+        converged: false,
+    })
+}
+
+/// Scales a vector in place
+fn scale(v: &mut Vector, alpha: f64) {
+    for value in v.as_mut_data() {
+        *value *= alpha;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::solve_lsqr;
+    use crate::{
+        ConfigIterative, IterativeMonitor, LinearOperatorFn, RectSparseTriplet, SparseTriplet, TripletSymmetry,
+    };
+    use russell_chk::{approx_eq, vec_approx_eq};
+    use russell_lab::{Matrix, Vector};
+
+    #[test]
+    fn solve_lsqr_captures_errors() {
+        let trip = RectSparseTriplet::new(3, 2, 1).unwrap();
+        let config = ConfigIterative::new();
+        let mut x = Vector::new(3);
+        let b = Vector::new(3);
+        assert_eq!(
+            solve_lsqr(&mut x, &trip, &b, &config, &mut IterativeMonitor::new()).err(),
+            Some("vectors are incompatible with the matrix")
+        );
+        let op = LinearOperatorFn::new(1, 2, |y, x| {
+            y[0] = x[0] + x[1];
+            Ok(())
+        });
+        let mut x = Vector::new(2);
+        assert_eq!(
+            solve_lsqr(
+                &mut x,
+                &op,
+                &Vector::from(&[1.0]),
+                &config,
+                &mut IterativeMonitor::new()
+            )
+            .err(),
+            Some("the transpose of the operator is not available")
+        );
+    }
+
+    #[test]
+    fn solve_lsqr_overdetermined_works() {
+        // ┌     ┐       ┌   ┐
+        // │ 1 0 │       │ 1 │
+        // │ 0 1 │ ⋅ x ≈ │ 2 │  ⇒  x = (4/3, 7/3)
+        // │ 1 1 │       │ 4 │
+        // └     ┘       └   ┘
+        let mut trip = RectSparseTriplet::new(3, 2, 4).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, 1.0).unwrap();
+        trip.put(2, 0, 1.0).unwrap();
+        trip.put(2, 1, 1.0).unwrap();
+        let b = Vector::from(&[1.0, 2.0, 4.0]);
+        let mut x = Vector::new(2);
+        let config = ConfigIterative::new();
+        let mut monitor = IterativeMonitor::new();
+        monitor.record_history(true);
+        let stats = solve_lsqr(&mut x, &trip, &b, &config, &mut monitor).unwrap();
+        assert!(stats.converged);
+        assert!(stats.iterations <= 2);
+        vec_approx_eq(x.as_data(), &[4.0 / 3.0, 7.0 / 3.0], 1e-14);
+        // the residual is orthogonal to the columns: r = (-1/3, -1/3, 1/3)
+        approx_eq(stats.residual_norm, f64::sqrt(3.0) / 3.0, 1e-14);
+        assert_eq!(monitor.get_history().dim(), stats.iterations + 1);
+    }
+
+    #[test]
+    fn solve_lsqr_underdetermined_gives_minimum_norm() {
+        // a = [1 2 3] and b = [14]  ⇒  x = aᵀ⋅(a⋅aᵀ)⁻¹⋅b = (1, 2, 3)
+        let mut trip = RectSparseTriplet::new(1, 3, 3).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 1, 2.0).unwrap();
+        trip.put(0, 2, 3.0).unwrap();
+        let mut x = Vector::new(3);
+        let config = ConfigIterative::new();
+        let stats = solve_lsqr(
+            &mut x,
+            &trip,
+            &Vector::from(&[14.0]),
+            &config,
+            &mut IterativeMonitor::new(),
+        )
+        .unwrap();
+        assert!(stats.converged);
+        vec_approx_eq(x.as_data(), &[1.0, 2.0, 3.0], 1e-14);
+
+        // rank-deficient matrix (same as a dense pseudo-inverse)
+        #[rustfmt::skip]
+        let a = Matrix::from(&[
+            [1.0, 1.0, 0.0],
+            [1.0, 1.0, 0.0],
+            [0.0, 0.0, 2.0],
+            [0.0, 0.0, 0.0],
+        ]);
+        let b = Vector::from(&[1.0, 3.0, 4.0, 5.0]);
+        let mut x = Vector::new(3);
+        let stats = solve_lsqr(&mut x, &a, &b, &config, &mut IterativeMonitor::new()).unwrap();
+        assert!(stats.converged);
+        vec_approx_eq(x.as_data(), &[1.0, 1.0, 2.0], 1e-13);
+        approx_eq(stats.residual_norm, f64::sqrt(27.0), 1e-13);
+    }
+
+    #[test]
+    fn solve_lsqr_square_system_works() {
+        let n = 10;
+        let mut trip = SparseTriplet::new(n, 3 * n, TripletSymmetry::None).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 4.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -1.0).unwrap();
+            }
+            if i + 1 < n {
+                trip.put(i, i + 1, -2.0).unwrap();
+            }
+        }
+        let x_correct = Vector::linspace(1.0, 2.0, n).unwrap();
+        let b = trip.mat_vec_mul(&x_correct).unwrap();
+        let mut x = Vector::new(n);
+        let config = *ConfigIterative::new().tolerance(1e-12);
+        let stats = solve_lsqr(&mut x, &trip, &b, &config, &mut IterativeMonitor::new()).unwrap();
+        assert!(stats.converged);
+        vec_approx_eq(x.as_data(), x_correct.as_data(), 1e-10);
+
+        // initial guess equal to the solution
+        let stats = solve_lsqr(&mut x, &trip, &b, &config, &mut IterativeMonitor::new()).unwrap();
+        assert!(stats.converged);
+        assert_eq!(stats.iterations, 0);
+    }
+
+    #[test]
+    fn solve_lsqr_stops_on_request() {
+        let mut trip = RectSparseTriplet::new(3, 2, 4).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, 1.0).unwrap();
+        trip.put(2, 0, 1.0).unwrap();
+        trip.put(2, 1, 1.0).unwrap();
+        let b = Vector::from(&[1.0, 2.0, 4.0]);
+        let mut x = Vector::new(2);
+        let mut monitor = IterativeMonitor::new();
+        monitor.set_observer(|iteration, _| iteration < 1);
+        let stats = solve_lsqr(&mut x, &trip, &b, &ConfigIterative::new(), &mut monitor).unwrap();
+        assert_eq!(stats.iterations, 1);
+        assert!(!stats.converged);
+    }
+}
@@ -0,0 +1,625 @@
+use super::{ConfigSolver, Globalization, Solver, SparseTriplet, TripletSymmetry};
+use crate::StrError;
+use russell_lab::{vec_norm, Norm, Vector};
+
+/// Holds configuration options for the Newton solver
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigNewton {
+    pub(crate) tol_abs: f64,                 // absolute tolerance on the (natural) residual norm
+    pub(crate) tol_rel: f64,                 // relative tolerance on the (natural) residual norm
+    pub(crate) max_iterations: usize,        // max number of iterations
+    pub(crate) globalization: Globalization, // globalization strategy
+    pub(crate) trust_radius_init: f64,       // initial trust-region radius
+    pub(crate) trust_radius_max: f64,        // maximum trust-region radius
+    pub(crate) config_solver: ConfigSolver,  // configuration of the linear solver
+}
+
+impl ConfigNewton {
+    /// Returns a default configuration
+    pub fn new() -> Self {
+        ConfigNewton {
+            tol_abs: 1e-12,
+            tol_rel: 1e-10,
+            max_iterations: 20,
+            globalization: Globalization::None,
+            trust_radius_init: 1.0,
+            trust_radius_max: 1e3,
+            config_solver: ConfigSolver::new(),
+        }
+    }
+
+    /// Sets the absolute and relative tolerances
+    ///
+    /// The iterations stop when `‖ρ‖₂ ≤ tol_abs + tol_rel ⋅ ‖ρ₀‖₂`, where `ρ` is the natural residual.
+    pub fn tolerances(&mut self, tol_abs: f64, tol_rel: f64) -> &mut Self {
+        self.tol_abs = tol_abs;
+        self.tol_rel = tol_rel;
+        self
+    }
+
+    /// Sets the maximum number of iterations
+    pub fn max_iterations(&mut self, value: usize) -> &mut Self {
+        self.max_iterations = value;
+        self
+    }
+
+    /// Sets the globalization strategy
+    pub fn globalization(&mut self, value: Globalization) -> &mut Self {
+        self.globalization = value;
+        self
+    }
+
+    /// Sets the initial and maximum trust-region radii (used with [Globalization::TrustRegion])
+    pub fn trust_radius(&mut self, init: f64, max: f64) -> &mut Self {
+        self.trust_radius_init = init;
+        self.trust_radius_max = max;
+        self
+    }
+
+    /// Sets the configuration of the linear solver
+    pub fn config_solver(&mut self, config: ConfigSolver) -> &mut Self {
+        self.config_solver = config;
+        self
+    }
+}
+
+impl Default for ConfigNewton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Holds the results of the Newton iterations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NewtonStats {
+    /// Number of iterations performed (number of Jacobian factorizations)
+    pub iterations: usize,
+
+    /// Euclidean norm of the final natural residual
+    pub residual_norm: f64,
+
+    /// Indicates whether the tolerance was reached or not
+    pub converged: bool,
+
+    /// Number of active bound constraints at the last iteration
+    pub n_active: usize,
+}
+
+/// Implements a Newton solver for nonlinear systems with simple bound constraints
+///
+/// Finds `u` such that:
+///
+/// ```text
+/// R(u) = 0    subject to    lower ≤ u ≤ upper
+/// ```
+///
+/// If the bounds are active at the solution, the complementarity conditions are solved instead:
+///
+/// ```text
+/// uᵢ = lowerᵢ  and  Rᵢ(u) ≥ 0,   or
+/// uᵢ = upperᵢ  and  Rᵢ(u) ≤ 0,   or
+/// lowerᵢ < uᵢ < upperᵢ  and  Rᵢ(u) = 0
+/// ```
+///
+/// which are measured by the natural residual `ρ = u - Π(u - R(u))`, where `Π` is the projection
+/// onto the box `[lower, upper]`.
+///
+/// At each iteration, the components at a bound with the residual pointing outwards form the active set.
+/// The active rows and columns of the Jacobian are replaced by the identity; thus, the linear solver
+/// computes a Newton step restricted to the free components, which is then projected onto the box.
+/// With [Globalization::TrustRegion], the step is computed by the dogleg method and accepted
+/// according to the ratio between the actual and the predicted reduction of `‖ρ‖₂²`.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{ConfigNewton, SolverNewton, SparseTriplet, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // R(u) = u² - 4 with u ≤ 1 (the unconstrained root u = 2 is not admissible)
+///     let mut solver = SolverNewton::new(ConfigNewton::new(), 1, 1)?;
+///     solver.set_bounds(&Vector::from(&[f64::NEG_INFINITY]), &Vector::from(&[1.0]))?;
+///     let mut u = Vector::from(&[0.5]);
+///     let stats = solver.solve(
+///         &mut u,
+///         |r: &mut Vector, u: &Vector| {
+///             r[0] = u[0] * u[0] - 4.0;
+///             Ok(())
+///         },
+///         |jj: &mut SparseTriplet, u: &Vector| {
+///             jj.reset();
+///             jj.put(0, 0, 2.0 * u[0])
+///         },
+///     )?;
+///     assert!(stats.converged);
+///     assert_eq!(stats.n_active, 1);
+///     assert_eq!(u[0], 1.0);
+///     Ok(())
+/// }
+/// ```
+pub struct SolverNewton {
+    config: ConfigNewton,      // configuration
+    neq: usize,                // number of equations
+    lower: Vector,             // lower bounds
+    upper: Vector,             // upper bounds
+    solver: Solver,            // linear solver
+    jj: SparseTriplet,         // Jacobian matrix
+    jj_reduced: SparseTriplet, // Jacobian matrix with the active rows and columns replaced by the identity
+}
+
+impl SolverNewton {
+    /// Creates a new Newton solver without bounds
+    ///
+    /// # Input
+    ///
+    /// * `config` -- The configuration parameters
+    /// * `neq` -- The number of equations
+    /// * `nnz` -- The maximum number of non-zero values of the Jacobian matrix
+    pub fn new(config: ConfigNewton, neq: usize, nnz: usize) -> Result<Self, StrError> {
+        SolverNewton::new_with_symmetry(config, neq, nnz, TripletSymmetry::None)
+    }
+
+    /// Creates a new Newton solver without bounds and with a symmetric Jacobian matrix
+    ///
+    /// The `calc_jacobian` function given to [SolverNewton::solve] receives a triplet
+    /// with the given symmetry (storage scheme).
+    pub fn new_with_symmetry(
+        config: ConfigNewton,
+        neq: usize,
+        nnz: usize,
+        symmetry: TripletSymmetry,
+    ) -> Result<Self, StrError> {
+        if neq == 0 {
+            return Err("neq must be greater than zero");
+        }
+        if config.max_iterations == 0 {
+            return Err("max_iterations must be greater than zero");
+        }
+        let valid = config.trust_radius_init > 0.0 && config.trust_radius_max >= config.trust_radius_init;
+        if !valid {
+            return Err("trust-region radii must satisfy 0 < init ≤ max");
+        }
+        Ok(SolverNewton {
+            config,
+            neq,
+            lower: Vector::filled(neq, f64::NEG_INFINITY),
+            upper: Vector::filled(neq, f64::INFINITY),
+            solver: Solver::new(config.config_solver, neq, nnz, None)?,
+            jj: SparseTriplet::new(neq, nnz, symmetry)?,
+            jj_reduced: SparseTriplet::new(neq, nnz + neq, symmetry)?,
+        })
+    }
+
+    /// Sets the bounds `lower ≤ u ≤ upper`
+    ///
+    /// Use `f64::NEG_INFINITY` and `f64::INFINITY` for unbounded components.
+    pub fn set_bounds(&mut self, lower: &Vector, upper: &Vector) -> Result<(), StrError> {
+        if lower.dim() != self.neq || upper.dim() != self.neq {
+            return Err("bounds vectors must have dimension equal to neq");
+        }
+        for i in 0..self.neq {
+            if lower[i].is_nan() || upper[i].is_nan() || lower[i] > upper[i] {
+                return Err("lower bounds must be smaller than or equal to the upper bounds");
+            }
+        }
+        self.lower = lower.clone();
+        self.upper = upper.clone();
+        Ok(())
+    }
+
+    /// Solves the nonlinear system
+    ///
+    /// # Input
+    ///
+    /// * `u` -- on input, the initial guess (it is projected onto the bounds); on output, the solution
+    /// * `calc_residual` -- computes the residual vector `r = R(u)`
+    /// * `calc_jacobian` -- computes the Jacobian matrix `jj = ∂R/∂u` (the triplet must be reset first)
+    ///
+    /// **Note:** No error is returned if the tolerance is not reached; check [NewtonStats::converged] instead.
+    pub fn solve<F, G>(
+        &mut self,
+        u: &mut Vector,
+        mut calc_residual: F,
+        mut calc_jacobian: G,
+    ) -> Result<NewtonStats, StrError>
+    where
+        F: FnMut(&mut Vector, &Vector) -> Result<(), StrError>,
+        G: FnMut(&mut SparseTriplet, &Vector) -> Result<(), StrError>,
+    {
+        let n = self.neq;
+        if u.dim() != n {
+            return Err("u vector must have dimension equal to neq");
+        }
+        let mut r = Vector::new(n);
+        let mut rho = Vector::new(n);
+        let mut rhs = Vector::new(n);
+        let mut mdu = Vector::new(n);
+        let mut active = vec![false; n];
+
+        // initial residual
+        project(u, &self.lower, &self.upper);
+        calc_residual(&mut r, u)?;
+        natural_residual(&mut rho, u, &r, &self.lower, &self.upper);
+        let mut norm_rho = vec_norm(&rho, Norm::Euc);
+        let target = self.config.tol_abs + self.config.tol_rel * norm_rho;
+        let mut delta = self.config.trust_radius_init;
+
+        for iteration in 0..self.config.max_iterations {
+            let n_active = find_active_set(&mut active, u, &r, &self.lower, &self.upper);
+            if norm_rho <= target {
+                return Ok(NewtonStats {
+                    iterations: iteration,
+                    residual_norm: norm_rho,
+                    converged: true,
+                    n_active,
+                });
+            }
+
+            // reduced Newton step
+            calc_jacobian(&mut self.jj, u)?;
+            reduce_jacobian(&mut self.jj_reduced, &self.jj, &active)?;
+            for i in 0..n {
+                rhs[i] = if active[i] { 0.0 } else { r[i] };
+            }
+            self.solver.factorize(&self.jj_reduced)?;
+            self.solver.solve(&mut mdu, &rhs)?;
+
+            match self.config.globalization {
+                Globalization::None => {
+                    for i in 0..n {
+                        u[i] -= mdu[i];
+                    }
+                    project(u, &self.lower, &self.upper);
+                    calc_residual(&mut r, u)?;
+                }
+                Globalization::TrustRegion => {
+                    // steepest descent direction of ½‖r̃‖² and its image
+                    let g = mat_t_vec_mul(&self.jj_reduced, &rhs);
+                    let jg = self.jj_reduced.mat_vec_mul(&g)?;
+                    let norm_rhs_sq = dot(&rhs, &rhs);
+                    let mut u_trial = Vector::new(n);
+                    let mut r_trial = Vector::new(n);
+                    let mut rho_trial = Vector::new(n);
+                    loop {
+                        let p = dogleg(&mdu, &g, &jg, delta);
+                        let jp = self.jj_reduced.mat_vec_mul(&p)?;
+                        let mut model_sq = 0.0;
+                        for i in 0..n {
+                            model_sq += (rhs[i] + jp[i]) * (rhs[i] + jp[i]);
+                            u_trial[i] = u[i] + p[i];
+                        }
+                        project(&mut u_trial, &self.lower, &self.upper);
+                        calc_residual(&mut r_trial, &u_trial)?;
+                        natural_residual(&mut rho_trial, &u_trial, &r_trial, &self.lower, &self.upper);
+                        let predicted = norm_rhs_sq - model_sq;
+                        let actual = norm_rho * norm_rho - dot(&rho_trial, &rho_trial);
+                        let ratio = if predicted > 0.0 { actual / predicted } else { -1.0 };
+                        let norm_p = f64::sqrt(dot(&p, &p));
+                        if ratio < 0.25 {
+                            delta = 0.25 * norm_p;
+                        } else if ratio > 0.75 && norm_p >= 0.99 * delta {
+                            delta = f64::min(2.0 * delta, self.config.trust_radius_max);
+                        }
+                        if ratio > 1e-4 {
+                            u.as_mut_data().copy_from_slice(u_trial.as_data());
+                            r.as_mut_data().copy_from_slice(r_trial.as_data());
+                            break;
+                        }
+                        if delta <= f64::EPSILON * (1.0 + vec_norm(u, Norm::Euc)) {
+                            return Err("trust-region radius became too small");
+                        }
+                    }
+                }
+            }
+            natural_residual(&mut rho, u, &r, &self.lower, &self.upper);
+            norm_rho = vec_norm(&rho, Norm::Euc);
+        }
+        Ok(NewtonStats {
+            iterations: self.config.max_iterations,
+            residual_norm: norm_rho,
+            converged: norm_rho <= target,
+            n_active: find_active_set(&mut active, u, &r, &self.lower, &self.upper),
+        })
+    }
+}
+
+/// Projects u onto the box [lower, upper]
+fn project(u: &mut Vector, lower: &Vector, upper: &Vector) {
+    for i in 0..u.dim() {
+        u[i] = f64::max(lower[i], f64::min(upper[i], u[i]));
+    }
+}
+
+/// Computes the natural residual ρ = u - Π(u - r)
+fn natural_residual(rho: &mut Vector, u: &Vector, r: &Vector, lower: &Vector, upper: &Vector) {
+    for i in 0..u.dim() {
+        rho[i] = u[i] - f64::max(lower[i], f64::min(upper[i], u[i] - r[i]));
+    }
+}
+
+/// Marks the components at a bound whose residual points outwards; returns the number of active components
+fn find_active_set(active: &mut [bool], u: &Vector, r: &Vector, lower: &Vector, upper: &Vector) -> usize {
+    let mut count = 0;
+    for i in 0..u.dim() {
+        active[i] = (u[i] <= lower[i] && r[i] > 0.0) || (u[i] >= upper[i] && r[i] < 0.0);
+        if active[i] {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Copies the Jacobian replacing the active rows and columns by the identity
+fn reduce_jacobian(reduced: &mut SparseTriplet, jj: &SparseTriplet, active: &[bool]) -> Result<(), StrError> {
+    reduced.reset();
+    for p in 0..jj.pos {
+        let i = jj.indices_i[p] as usize;
+        let j = jj.indices_j[p] as usize;
+        if !active[i] && !active[j] {
+            reduced.put(i, j, jj.values_aij[p])?;
+        }
+    }
+    for (i, &a) in active.iter().enumerate() {
+        if a {
+            reduced.put(i, i, 1.0)?;
+        }
+    }
+    Ok(())
+}
+
+/// Computes v = aᵀ ⋅ u
+fn mat_t_vec_mul(trip: &SparseTriplet, u: &Vector) -> Vector {
+    let triangular = trip.symmetry == TripletSymmetry::LowerTriangular;
+    let mut v = Vector::new(trip.neq);
+    for p in 0..trip.pos {
+        let i = trip.indices_i[p] as usize;
+        let j = trip.indices_j[p] as usize;
+        let aij = trip.values_aij[p];
+        v[j] += aij * u[i];
+        if triangular && i != j {
+            v[i] += aij * u[j];
+        }
+    }
+    v
+}
+
+/// Computes the dot product (without BLAS; vectors are small compared with the factorization)
+fn dot(u: &Vector, v: &Vector) -> f64 {
+    u.as_data().iter().zip(v.as_data()).map(|(a, b)| a * b).sum()
+}
+
+/// Computes the dogleg step
+///
+/// * `mdu` -- minus the Newton step
+/// * `g` -- gradient of ½‖r‖²
+/// * `jg` -- Jacobian times the gradient
+fn dogleg(mdu: &Vector, g: &Vector, jg: &Vector, delta: f64) -> Vector {
+    let n = mdu.dim();
+    let norm_newton = f64::sqrt(dot(mdu, mdu));
+    let mut p = Vector::new(n);
+    if norm_newton <= delta {
+        for i in 0..n {
+            p[i] = -mdu[i];
+        }
+        return p;
+    }
+    let gg = dot(g, g);
+    let jgjg = dot(jg, jg);
+    if gg == 0.0 || jgjg == 0.0 {
+        for i in 0..n {
+            p[i] = -mdu[i] * delta / norm_newton;
+        }
+        return p;
+    }
+    let tau = gg / jgjg;
+    let norm_cauchy = tau * f64::sqrt(gg);
+    if norm_cauchy >= delta {
+        let s = delta / f64::sqrt(gg);
+        for i in 0..n {
+            p[i] = -s * g[i];
+        }
+        return p;
+    }
+    // p = pc + β (pn - pc) with ‖p‖ = Δ
+    let mut d = Vector::new(n);
+    for i in 0..n {
+        p[i] = -tau * g[i];
+        d[i] = -mdu[i] - p[i];
+    }
+    let a = dot(&d, &d);
+    let b = 2.0 * dot(&p, &d);
+    let c = norm_cauchy * norm_cauchy - delta * delta;
+    let beta = (-b + f64::sqrt(b * b - 4.0 * a * c)) / (2.0 * a);
+    for i in 0..n {
+        p[i] += beta * d[i];
+    }
+    p
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        dogleg, find_active_set, mat_t_vec_mul, natural_residual, project, reduce_jacobian, ConfigNewton, SolverNewton,
+    };
+    use crate::{Globalization, SparseTriplet, StrError, TripletSymmetry};
+    use russell_chk::vec_approx_eq;
+    use russell_lab::{Matrix, Vector};
+
+    #[test]
+    fn config_newton_works() {
+        let mut config = ConfigNewton::new();
+        config
+            .tolerances(1e-8, 1e-6)
+            .max_iterations(5)
+            .globalization(Globalization::TrustRegion)
+            .trust_radius(0.5, 10.0);
+        assert_eq!(config.tol_abs, 1e-8);
+        assert_eq!(config.tol_rel, 1e-6);
+        assert_eq!(config.max_iterations, 5);
+        assert_eq!(config.globalization, Globalization::TrustRegion);
+        assert_eq!(config.trust_radius_init, 0.5);
+        assert_eq!(config.trust_radius_max, 10.0);
+    }
+
+    #[test]
+    fn projection_and_natural_residual_work() {
+        let lower = Vector::from(&[0.0, 0.0, f64::NEG_INFINITY]);
+        let upper = Vector::from(&[1.0, 1.0, f64::INFINITY]);
+        let mut u = Vector::from(&[-1.0, 2.0, 3.0]);
+        project(&mut u, &lower, &upper);
+        assert_eq!(u.as_data(), &[0.0, 1.0, 3.0]);
+
+        // at the lower bound with r > 0 (satisfied); at the upper bound with r > 0 (violated)
+        let r = Vector::from(&[2.0, 0.5, -0.25]);
+        let mut rho = Vector::new(3);
+        natural_residual(&mut rho, &u, &r, &lower, &upper);
+        assert_eq!(rho.as_data(), &[0.0, 0.5, -0.25]);
+
+        let mut active = vec![false; 3];
+        assert_eq!(find_active_set(&mut active, &u, &r, &lower, &upper), 1);
+        assert_eq!(active, &[true, false, false]);
+    }
+
+    #[test]
+    fn reduce_jacobian_and_mat_t_vec_mul_work() {
+        let mut jj = SparseTriplet::new(3, 9, TripletSymmetry::None).unwrap();
+        jj.put(0, 0, 1.0).unwrap();
+        jj.put(0, 1, 2.0).unwrap();
+        jj.put(1, 0, 3.0).unwrap();
+        jj.put(1, 1, 4.0).unwrap();
+        jj.put(1, 2, 5.0).unwrap();
+        jj.put(2, 2, 6.0).unwrap();
+        let mut reduced = SparseTriplet::new(3, 12, TripletSymmetry::None).unwrap();
+        reduce_jacobian(&mut reduced, &jj, &[false, false, true]).unwrap();
+        let mut a = Matrix::new(3, 3);
+        reduced.to_matrix(&mut a).unwrap();
+        assert_eq!(
+            format!("{}", a),
+            "┌       ┐\n\
+             │ 1 2 0 │\n\
+             │ 3 4 0 │\n\
+             │ 0 0 1 │\n\
+             └       ┘"
+        );
+        let v = mat_t_vec_mul(&jj, &Vector::from(&[1.0, 1.0, 1.0]));
+        assert_eq!(v.as_data(), &[4.0, 6.0, 11.0]);
+
+        let mut sym = SparseTriplet::new(2, 3, TripletSymmetry::LowerTriangular).unwrap();
+        sym.put(0, 0, 1.0).unwrap();
+        sym.put(1, 0, 2.0).unwrap();
+        sym.put(1, 1, 3.0).unwrap();
+        let v = mat_t_vec_mul(&sym, &Vector::from(&[1.0, 2.0]));
+        assert_eq!(v.as_data(), &[5.0, 8.0]);
+    }
+
+    #[test]
+    fn dogleg_works() {
+        // identity Jacobian: the Newton and the Cauchy steps coincide
+        let mdu = Vector::from(&[3.0, 4.0]);
+        let g = Vector::from(&[3.0, 4.0]);
+        let jg = Vector::from(&[3.0, 4.0]);
+        vec_approx_eq(dogleg(&mdu, &g, &jg, 10.0).as_data(), &[-3.0, -4.0], 1e-15);
+        vec_approx_eq(dogleg(&mdu, &g, &jg, 1.0).as_data(), &[-0.6, -0.8], 1e-15);
+
+        // J = diag(1, 2), r = (1, 1) ⇒ pn = (-1, -0.5), g = (1, 2), τ = 5/17
+        let mdu = Vector::from(&[1.0, 0.5]);
+        let g = Vector::from(&[1.0, 2.0]);
+        let jg = Vector::from(&[1.0, 4.0]);
+        let p = dogleg(&mdu, &g, &jg, 0.8);
+        let norm_p = f64::sqrt(p[0] * p[0] + p[1] * p[1]);
+        assert!(f64::abs(norm_p - 0.8) < 1e-14);
+        assert!(p[0] < 0.0 && p[1] < 0.0);
+    }
+
+    #[test]
+    fn new_and_set_bounds_capture_errors() {
+        assert_eq!(
+            SolverNewton::new(ConfigNewton::new(), 0, 1).err(),
+            Some("neq must be greater than zero")
+        );
+        let mut config = ConfigNewton::new();
+        config.max_iterations(0);
+        assert_eq!(
+            SolverNewton::new(config, 1, 1).err(),
+            Some("max_iterations must be greater than zero")
+        );
+        let mut config = ConfigNewton::new();
+        config.trust_radius(2.0, 1.0);
+        assert_eq!(
+            SolverNewton::new(config, 1, 1).err(),
+            Some("trust-region radii must satisfy 0 < init ≤ max")
+        );
+        let mut solver = SolverNewton::new(ConfigNewton::new(), 2, 4).unwrap();
+        assert_eq!(
+            solver.set_bounds(&Vector::new(1), &Vector::new(2)).err(),
+            Some("bounds vectors must have dimension equal to neq")
+        );
+        assert_eq!(
+            solver
+                .set_bounds(&Vector::from(&[0.0, 1.0]), &Vector::from(&[1.0, 0.0]))
+                .err(),
+            Some("lower bounds must be smaller than or equal to the upper bounds")
+        );
+    }
+
+    fn calc_residual(r: &mut Vector, u: &Vector) -> Result<(), StrError> {
+        // R = ∇f with f = (u0 - 2)² + (u1 + 1)² + u0⁴/4 + u0 u1
+        r[0] = 2.0 * (u[0] - 2.0) + u[0] * u[0] * u[0] + u[1];
+        r[1] = 2.0 * (u[1] + 1.0) + u[0];
+        Ok(())
+    }
+
+    fn calc_jacobian(jj: &mut SparseTriplet, u: &Vector) -> Result<(), StrError> {
+        jj.reset();
+        jj.put(0, 0, 2.0 + 3.0 * u[0] * u[0])?;
+        jj.put(0, 1, 1.0)?;
+        jj.put(1, 0, 1.0)?;
+        jj.put(1, 1, 2.0)?;
+        Ok(())
+    }
+
+    #[test]
+    fn solve_works_without_bounds() {
+        for globalization in [Globalization::None, Globalization::TrustRegion] {
+            let mut config = ConfigNewton::new();
+            config.globalization(globalization);
+            let mut solver = SolverNewton::new(config, 2, 4).unwrap();
+            let mut u = Vector::from(&[5.0, 5.0]);
+            let stats = solver.solve(&mut u, calc_residual, calc_jacobian).unwrap();
+            assert!(stats.converged);
+            assert_eq!(stats.n_active, 0);
+            let mut r = Vector::new(2);
+            calc_residual(&mut r, &u).unwrap();
+            vec_approx_eq(r.as_data(), &[0.0, 0.0], 1e-10);
+        }
+    }
+
+    #[test]
+    fn solve_works_with_active_bounds() {
+        // the unconstrained minimizer has u1 < -1; with u1 ≥ -1 the bound becomes active
+        for globalization in [Globalization::None, Globalization::TrustRegion] {
+            let mut config = ConfigNewton::new();
+            config.globalization(globalization);
+            let mut solver = SolverNewton::new(config, 2, 4).unwrap();
+            solver
+                .set_bounds(
+                    &Vector::from(&[f64::NEG_INFINITY, -1.0]),
+                    &Vector::from(&[f64::INFINITY, 0.0]),
+                )
+                .unwrap();
+            let mut u = Vector::from(&[0.0, 0.0]);
+            let stats = solver.solve(&mut u, calc_residual, calc_jacobian).unwrap();
+            assert!(stats.converged);
+            assert_eq!(stats.n_active, 1);
+            assert_eq!(u[1], -1.0);
+            // 2 (u0 - 2) + u0³ - 1 = 0
+            let res = 2.0 * (u[0] - 2.0) + u[0] * u[0] * u[0] - 1.0;
+            assert!(f64::abs(res) < 1e-10);
+            let r1 = 2.0 * (u[1] + 1.0) + u[0];
+            assert!(r1 > 0.0);
+        }
+    }
+}
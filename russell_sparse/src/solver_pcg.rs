@@ -0,0 +1,386 @@
+use super::{ConfigIterative, IterativeMonitor, LinearOperator, PrecondJacobi, Preconditioner, SparseCsr};
+use crate::StrError;
+use russell_lab::{vec_inner, vec_norm, Norm, Vector};
+
+/// Holds the results of an iterative solution
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IterativeStats {
+    /// Number of iterations performed
+    pub iterations: usize,
+
+    /// Euclidean norm of the final residual `‖b - a⋅x‖₂`
+    pub residual_norm: f64,
+
+    /// Indicates whether the relative residual reached the tolerance or not
+    pub converged: bool,
+}
+
+/// Solves a symmetric positive-definite system with the Jacobi-preconditioned conjugate gradient method
+///
+/// Finds `x` such that `a ⋅ x = b` with:
+///
+/// ```text
+/// ‖b - a⋅x‖₂ ≤ tolerance ⋅ ‖b‖₂
+/// ```
+///
+/// The preconditioner is `M = diag(a)`; thus, all diagonal entries must be positive.
+///
+/// # Input
+///
+/// * `x` -- on input, the initial guess (e.g., zero or a previous solution); on output, the solution
+/// * `a` -- the coefficient matrix (symmetric positive-definite)
+/// * `b` -- the right-hand side
+/// * `tolerance` -- the relative tolerance on the residual
+/// * `max_iterations` -- the maximum number of iterations
+///
+/// **Note:** No error is returned if the tolerance is not reached; check [IterativeStats::converged] instead.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{solve_pcg_jacobi, SparseCsr, SparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular)?;
+///     trip.put(0, 0, 4.0)?;
+///     trip.put(1, 0, 1.0)?;
+///     trip.put(1, 1, 3.0)?;
+///     trip.put(2, 1, 1.0)?;
+///     trip.put(2, 2, 2.0)?;
+///     let csr = SparseCsr::from_triplet(&trip);
+///     let b = Vector::from(&[5.0, 5.0, 3.0]);
+///     let mut x = Vector::new(3);
+///     let stats = solve_pcg_jacobi(&mut x, &csr, &b, 1e-12, 10)?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.6}", x), "┌          ┐\n\
+///                                      │ 1.000000 │\n\
+///                                      │ 1.000000 │\n\
+///                                      │ 1.000000 │\n\
+///                                      └          ┘");
+///     Ok(())
+/// }
+/// ```
+pub fn solve_pcg_jacobi(
+    x: &mut Vector,
+    a: &SparseCsr,
+    b: &Vector,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<IterativeStats, StrError> {
+    solve_pcg_jacobi_monitored(x, a, b, tolerance, max_iterations, &mut IterativeMonitor::new())
+}
+
+/// Solves a symmetric positive-definite system with the Jacobi-preconditioned conjugate gradient method (monitored)
+///
+/// This function is equivalent to [solve_pcg_jacobi], but it reports the residual norm of
+/// each iteration to the `monitor` (see [IterativeMonitor]), which may also stop the iterations.
+/// In this case, [IterativeStats::converged] is `false` unless the tolerance has been reached.
+pub fn solve_pcg_jacobi_monitored(
+    x: &mut Vector,
+    a: &SparseCsr,
+    b: &Vector,
+    tolerance: f64,
+    max_iterations: usize,
+    monitor: &mut IterativeMonitor,
+) -> Result<IterativeStats, StrError> {
+    let n = a.neq();
+    if x.dim() != n || b.dim() != n {
+        return Err("vectors are incompatible with the matrix");
+    }
+    let mut precond = PrecondJacobi::new(a)?;
+    let config = *ConfigIterative::new()
+        .tolerance(tolerance)
+        .max_iterations(max_iterations);
+    solve_pcg(x, a, b, &mut precond, &config, monitor)
+}
+
+/// Solves a symmetric positive-definite system with the preconditioned conjugate gradient method
+///
+/// Finds `x` such that `a ⋅ x = b` with:
+///
+/// ```text
+/// ‖b - a⋅x‖₂ ≤ tolerance ⋅ ‖b‖₂
+/// ```
+///
+/// # Input
+///
+/// * `x` -- on input, the initial guess (e.g., zero or a previous solution); on output, the solution
+/// * `a` -- the coefficient matrix or matrix-free operator (symmetric positive-definite; see [LinearOperator])
+/// * `b` -- the right-hand side
+/// * `precond` -- the preconditioner (symmetric positive-definite; see [Preconditioner])
+/// * `config` -- the tolerance and maximum number of iterations (see [ConfigIterative])
+/// * `monitor` -- receives the residual norm of each iteration and may stop the iterations (see [IterativeMonitor])
+///
+/// **Note:** No error is returned if the tolerance is not reached; check [IterativeStats::converged] instead.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Vector;
+/// use russell_sparse::{solve_pcg, ConfigIterative, IterativeMonitor, PrecondIdentity};
+/// use russell_sparse::{SparseCsr, SparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular)?;
+///     trip.put(0, 0, 4.0)?;
+///     trip.put(1, 0, 1.0)?;
+///     trip.put(1, 1, 3.0)?;
+///     trip.put(2, 1, 1.0)?;
+///     trip.put(2, 2, 2.0)?;
+///     let csr = SparseCsr::from_triplet(&trip);
+///     let b = Vector::from(&[5.0, 5.0, 3.0]);
+///     let mut x = Vector::new(3);
+///     let config = ConfigIterative::new();
+///     let stats = solve_pcg(&mut x, &csr, &b, &mut PrecondIdentity, &config, &mut IterativeMonitor::new())?;
+///     assert!(stats.converged);
+///     assert_eq!(format!("{:.6}", x), "┌          ┐\n\
+///                                      │ 1.000000 │\n\
+///                                      │ 1.000000 │\n\
+///                                      │ 1.000000 │\n\
+///                                      └          ┘");
+///     Ok(())
+/// }
+/// ```
+pub fn solve_pcg(
+    x: &mut Vector,
+    a: &dyn LinearOperator,
+    b: &Vector,
+    precond: &mut dyn Preconditioner,
+    config: &ConfigIterative,
+    monitor: &mut IterativeMonitor,
+) -> Result<IterativeStats, StrError> {
+    let (n, ncol) = a.dims();
+    if ncol != n {
+        return Err("the operator must be square");
+    }
+    if x.dim() != n || b.dim() != n {
+        return Err("vectors are incompatible with the matrix");
+    }
+
+    // r = b - a⋅x
+    let mut r = Vector::new(n);
+    a.apply(&mut r, x)?;
+    for i in 0..n {
+        r[i] = b[i] - r[i];
+    }
+    let norm_b = vec_norm(b, Norm::Euc);
+    let target = if norm_b > 0.0 {
+        config.tolerance * norm_b
+    } else {
+        config.tolerance
+    };
+    let mut residual_norm = vec_norm(&r, Norm::Euc);
+    monitor.reset();
+    let proceed = monitor.update(0, residual_norm);
+    if residual_norm <= target || !proceed {
+        return Ok(IterativeStats {
+            iterations: 0,
+            residual_norm,
+            converged: residual_norm <= target,
+        });
+    }
+
+    // z = M⁻¹⋅r and p = z
+    let mut z = Vector::new(n);
+    precond.apply(&mut z, &r)?;
+    let mut p = z.clone();
+    let mut rz = vec_inner(&r, &z);
+    let mut q = Vector::new(n);
+
+    for iteration in 1..=config.max_iterations {
+        a.apply(&mut q, &p)?;
+        let pq = vec_inner(&p, &q);
+        if pq <= 0.0 {
+            return Err("the matrix is not positive-definite");
+        }
+        let alpha = rz / pq;
+        for i in 0..n {
+            x[i] += alpha * p[i];
+            r[i] -= alpha * q[i];
+        }
+        residual_norm = vec_norm(&r, Norm::Euc);
+        let proceed = monitor.update(iteration, residual_norm);
+        if residual_norm <= target || !proceed {
+            return Ok(IterativeStats {
+                iterations: iteration,
+                residual_norm,
+                converged: residual_norm <= target,
+            });
+        }
+        precond.apply(&mut z, &r)?;
+        let rz_new = vec_inner(&r, &z);
+        let beta = rz_new / rz;
+        rz = rz_new;
+        for i in 0..n {
+            p[i] = z[i] + beta * p[i];
+        }
+    }
+    Ok(IterativeStats {
+        iterations: config.max_iterations,
+        residual_norm,
+        converged: false,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{solve_pcg, solve_pcg_jacobi, solve_pcg_jacobi_monitored};
+    use crate::{
+        ConfigIterative, IterativeMonitor, LinearOperatorFn, PrecondIdentity, SparseCsr, SparseTriplet, TripletSymmetry,
+    };
+    use russell_lab::Vector;
+
+    fn laplacian_1d(n: usize) -> SparseCsr {
+        let mut trip = SparseTriplet::new(n, 2 * n, TripletSymmetry::LowerTriangular).unwrap();
+        for i in 0..n {
+            trip.put(i, i, 2.0).unwrap();
+            if i > 0 {
+                trip.put(i, i - 1, -1.0).unwrap();
+            }
+        }
+        SparseCsr::from_triplet(&trip)
+    }
+
+    #[test]
+    fn solve_pcg_jacobi_fails_on_wrong_input() {
+        let a = laplacian_1d(3);
+        let mut x = Vector::new(3);
+        assert_eq!(
+            solve_pcg_jacobi(&mut x, &a, &Vector::new(2), 1e-10, 10).err(),
+            Some("vectors are incompatible with the matrix")
+        );
+        let mut trip = SparseTriplet::new(2, 2, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, -1.0).unwrap();
+        let a = SparseCsr::from_triplet(&trip);
+        let mut x = Vector::new(2);
+        assert_eq!(
+            solve_pcg_jacobi(&mut x, &a, &Vector::from(&[1.0, 1.0]), 1e-10, 10).err(),
+            Some("the Jacobi preconditioner requires positive diagonal entries")
+        );
+    }
+
+    #[test]
+    fn solve_pcg_jacobi_works() {
+        // the solution is x = (1, 1, ..., 1)
+        let n = 50;
+        let a = laplacian_1d(n);
+        let mut b = Vector::new(n);
+        b[0] = 1.0;
+        b[n - 1] = 1.0;
+        let mut x = Vector::new(n);
+        let stats = solve_pcg_jacobi(&mut x, &a, &b, 1e-12, 100).unwrap();
+        assert!(stats.converged);
+        assert!(stats.iterations <= n);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - 1.0) < 1e-10);
+        }
+
+        // warm start with the solution
+        let stats = solve_pcg_jacobi(&mut x, &a, &b, 1e-8, 100).unwrap();
+        assert_eq!(stats.iterations, 0);
+        assert!(stats.converged);
+    }
+
+    #[test]
+    fn solve_pcg_jacobi_reports_no_convergence() {
+        let n = 50;
+        let a = laplacian_1d(n);
+        let b = Vector::filled(n, 1.0);
+        let mut x = Vector::new(n);
+        let stats = solve_pcg_jacobi(&mut x, &a, &b, 1e-14, 3).unwrap();
+        assert!(!stats.converged);
+        assert_eq!(stats.iterations, 3);
+        assert!(stats.residual_norm > 0.0);
+    }
+
+    #[test]
+    fn solve_pcg_jacobi_monitored_works() {
+        let n = 50;
+        let a = laplacian_1d(n);
+        let b = Vector::filled(n, 1.0);
+        let mut x = Vector::new(n);
+        let mut monitor = IterativeMonitor::new();
+        monitor.record_history(true);
+        let stats = solve_pcg_jacobi_monitored(&mut x, &a, &b, 1e-12, 100, &mut monitor).unwrap();
+        assert!(stats.converged);
+        let history = monitor.get_history();
+        assert_eq!(history.dim(), stats.iterations + 1);
+        assert_eq!(history[0], f64::sqrt(n as f64));
+        assert_eq!(history[stats.iterations], stats.residual_norm);
+
+        // custom stopping logic
+        let mut x = Vector::new(n);
+        let mut monitor = IterativeMonitor::new();
+        monitor.record_history(true).set_observer(|iteration, _| iteration < 4);
+        let stats = solve_pcg_jacobi_monitored(&mut x, &a, &b, 1e-12, 100, &mut monitor).unwrap();
+        assert!(!stats.converged);
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(monitor.get_history().dim(), 5);
+    }
+
+    #[test]
+    fn solve_pcg_works() {
+        let n = 50;
+        let a = laplacian_1d(n);
+        let mut b = Vector::new(n);
+        b[0] = 1.0;
+        b[n - 1] = 1.0;
+        let mut x = Vector::new(n);
+        let mut monitor = IterativeMonitor::new();
+        assert_eq!(
+            solve_pcg(
+                &mut x,
+                &a,
+                &Vector::new(2),
+                &mut PrecondIdentity,
+                &ConfigIterative::new(),
+                &mut monitor
+            )
+            .err(),
+            Some("vectors are incompatible with the matrix")
+        );
+        let config = *ConfigIterative::new().tolerance(1e-12).max_iterations(100);
+        let stats = solve_pcg(&mut x, &a, &b, &mut PrecondIdentity, &config, &mut monitor).unwrap();
+        assert!(stats.converged);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - 1.0) < 1e-10);
+        }
+    }
+
+    #[test]
+    fn solve_pcg_works_with_matrix_free_operator() {
+        let n = 50;
+        let laplacian = LinearOperatorFn::new(n, n, |y, x| {
+            for i in 0..n {
+                y[i] = 2.0 * x[i];
+                if i > 0 {
+                    y[i] -= x[i - 1];
+                }
+                if i + 1 < n {
+                    y[i] -= x[i + 1];
+                }
+            }
+            Ok(())
+        });
+        let mut b = Vector::new(n);
+        b[0] = 1.0;
+        b[n - 1] = 1.0;
+        let mut x = Vector::new(n);
+        let mut monitor = IterativeMonitor::new();
+        let config = *ConfigIterative::new().tolerance(1e-12).max_iterations(100);
+        let stats = solve_pcg(&mut x, &laplacian, &b, &mut PrecondIdentity, &config, &mut monitor).unwrap();
+        assert!(stats.converged);
+        for i in 0..n {
+            assert!(f64::abs(x[i] - 1.0) < 1e-10);
+        }
+        let rectangular = LinearOperatorFn::new(n, n + 1, |_, _| Ok(()));
+        assert_eq!(
+            solve_pcg(&mut x, &rectangular, &b, &mut PrecondIdentity, &config, &mut monitor).err(),
+            Some("the operator must be square")
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use super::SparseCsr;
+
+/// Panics if two sparse matrices are not approximately equal to each other
+///
+/// The patterns are aligned row by row and a missing entry is treated as zero; thus, matrices
+/// with different patterns (e.g., with stored zeros) may still be approximately equal.
+///
+/// The location of the **worst** (largest) difference is reported in the panic message.
+///
+/// Panics also if the matrix dimensions differ
+///
+/// **Note:** A triplet may be compared after the conversion with [SparseCsr::from_triplet];
+/// thus, repeated entries are summed up and lower triangular triplets represent the full matrix.
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::{sparse_approx_eq, SparseCsr, SparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     // | 2  1 |
+///     // | 1  3 |
+///     let mut trip_lower = SparseTriplet::new(2, 3, TripletSymmetry::LowerTriangular)?;
+///     trip_lower.put(0, 0, 2.0)?;
+///     trip_lower.put(1, 0, 1.0)?;
+///     trip_lower.put(1, 1, 3.0)?;
+///
+///     // the same matrix assembled with repeated entries and a stored zero
+///     let mut trip_full = SparseTriplet::new(2, 6, TripletSymmetry::None)?;
+///     trip_full.put(0, 0, 1.0)?;
+///     trip_full.put(0, 0, 1.0)?;
+///     trip_full.put(0, 1, 1.0)?;
+///     trip_full.put(1, 0, 1.0)?;
+///     trip_full.put(1, 1, 3.0 + 1e-15)?;
+///     trip_full.put(1, 1, 0.0)?;
+///
+///     let a = SparseCsr::from_triplet(&trip_lower);
+///     let b = SparseCsr::from_triplet(&trip_full);
+///     sparse_approx_eq(&a, &b, 1e-14);
+///     Ok(())
+/// }
+/// ```
+pub fn sparse_approx_eq(a: &SparseCsr, b: &SparseCsr, tol: f64) {
+    if a.neq != b.neq {
+        panic!("sparse matrix dimensions differ. {} != {}", a.neq, b.neq);
+    }
+    let mut worst: Option<(usize, usize, f64)> = None;
+    let mut check = |i: usize, j: usize, diff: f64| {
+        if diff > tol && worst.is_none_or(|(_, _, d)| diff > d) {
+            worst = Some((i, j, diff));
+        }
+    };
+    for i in 0..a.neq {
+        // merge the (sorted) column indices of both rows
+        let (mut p, end_p) = (a.row_pointers[i], a.row_pointers[i + 1]);
+        let (mut q, end_q) = (b.row_pointers[i], b.row_pointers[i + 1]);
+        while p < end_p || q < end_q {
+            let ja = if p < end_p { a.col_indices[p] } else { usize::MAX };
+            let jb = if q < end_q { b.col_indices[q] } else { usize::MAX };
+            if ja == jb {
+                check(i, ja, f64::abs(a.values[p] - b.values[q]));
+                p += 1;
+                q += 1;
+            } else if ja < jb {
+                check(i, ja, f64::abs(a.values[p]));
+                p += 1;
+            } else {
+                check(i, jb, f64::abs(b.values[q]));
+                q += 1;
+            }
+        }
+    }
+    if let Some((i, j, diff)) = worst {
+        panic!(
+            "sparse matrices are not approximately equal. worst @ ({},{}) diff = {:?}",
+            i, j, diff
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::sparse_approx_eq;
+    use crate::{SparseCsr, SparseTriplet, TripletSymmetry};
+
+    fn csr(neq: usize, entries: &[(usize, usize, f64)]) -> SparseCsr {
+        let mut trip = SparseTriplet::new(neq, usize::max(1, entries.len()), TripletSymmetry::None).unwrap();
+        for &(i, j, aij) in entries {
+            trip.put(i, j, aij).unwrap();
+        }
+        SparseCsr::from_triplet(&trip)
+    }
+
+    #[test]
+    #[should_panic(expected = "sparse matrix dimensions differ. 2 != 3")]
+    fn sparse_approx_eq_panics_on_wrong_dims() {
+        let a = csr(2, &[(0, 0, 1.0)]);
+        let b = csr(3, &[(0, 0, 1.0)]);
+        sparse_approx_eq(&a, &b, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "sparse matrices are not approximately equal. worst @ (1,0) diff = 2.0")]
+    fn sparse_approx_eq_reports_the_worst_difference() {
+        let a = csr(2, &[(0, 0, 1.0), (0, 1, 1.0), (1, 1, 4.0)]);
+        let b = csr(2, &[(0, 0, 1.5), (1, 0, 2.0), (1, 1, 4.0)]);
+        sparse_approx_eq(&a, &b, 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "sparse matrices are not approximately equal. worst @ (2,2) diff = 3.0")]
+    fn sparse_approx_eq_catches_entries_missing_in_a() {
+        let a = csr(3, &[(0, 0, 1.0)]);
+        let b = csr(3, &[(0, 0, 1.0), (2, 2, -3.0)]);
+        sparse_approx_eq(&a, &b, 1e-15);
+    }
+
+    #[test]
+    fn sparse_approx_eq_works() {
+        // stored zeros and different patterns
+        let a = csr(3, &[(0, 0, 1.0), (1, 2, 0.0), (2, 1, 1e-16)]);
+        let b = csr(3, &[(0, 0, 1.0), (2, 0, 0.0)]);
+        sparse_approx_eq(&a, &b, 1e-15);
+        sparse_approx_eq(&b, &a, 1e-15);
+
+        // tolerance
+        let a = csr(2, &[(0, 0, 1.0), (1, 1, 2.0)]);
+        let b = csr(2, &[(0, 0, 1.0 + 1e-10), (1, 1, 2.0 - 1e-10)]);
+        sparse_approx_eq(&a, &b, 1e-9);
+    }
+}
@@ -0,0 +1,312 @@
+use super::SparseTriplet;
+use crate::{StrError, TripletSymmetry};
+use russell_lab::{Matrix, Vector};
+
+/// Holds a sparse matrix in the compressed sparse row (CSR) format
+///
+/// # Remarks
+///
+/// - The CSR matrix is obtained from a [SparseTriplet] (see [SparseCsr::from_triplet])
+/// - Entries with repeated (i,j) indices are summed up
+/// - The column indices of each row are sorted in ascending order
+/// - If the triplet symmetry is [TripletSymmetry::LowerTriangular], the off-diagonal entries are mirrored;
+///   thus, the CSR matrix always represents the full matrix
+/// - The matrix-vector multiplication is faster than the one performed with the triplet
+///   because the entries of each row are contiguous in memory; thus, this structure is
+///   advisable when the product is computed many times (e.g., large matrices or many vectors)
+pub struct SparseCsr {
+    pub(crate) neq: usize,               // number of rows = number of columns
+    pub(crate) row_pointers: Vec<usize>, // [neq + 1] start of each row in col_indices and values
+    pub(crate) col_indices: Vec<usize>,  // [nnz] column indices
+    pub(crate) values: Vec<f64>,         // [nnz] values
+}
+
+impl SparseCsr {
+    /// Creates a new CSR matrix from a triplet
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{SparseCsr, SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // | 2  0  1 |
+    ///     // | 0  3  0 |
+    ///     // | 1  0  4 |
+    ///     let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular)?;
+    ///     trip.put(0, 0, 1.0)?;
+    ///     trip.put(0, 0, 1.0)?;
+    ///     trip.put(1, 1, 3.0)?;
+    ///     trip.put(2, 0, 1.0)?;
+    ///     trip.put(2, 2, 4.0)?;
+    ///     let csr = SparseCsr::from_triplet(&trip);
+    ///     assert_eq!(csr.row_pointers(), &[0, 2, 3, 5]);
+    ///     assert_eq!(csr.col_indices(), &[0, 2, 1, 0, 2]);
+    ///     assert_eq!(csr.values(), &[2.0, 1.0, 3.0, 1.0, 4.0]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_triplet(trip: &SparseTriplet) -> Self {
+        let neq = trip.neq;
+        let mirror = trip.symmetry == TripletSymmetry::LowerTriangular;
+
+        // count the number of entries in each row (including repeated entries)
+        let mut row_pointers = vec![0; neq + 1];
+        for p in 0..trip.pos {
+            let (i, j) = (trip.indices_i[p] as usize, trip.indices_j[p] as usize);
+            row_pointers[i + 1] += 1;
+            if mirror && i != j {
+                row_pointers[j + 1] += 1;
+            }
+        }
+        for i in 0..neq {
+            row_pointers[i + 1] += row_pointers[i];
+        }
+
+        // scatter the entries into the rows
+        let total = row_pointers[neq];
+        let mut next = row_pointers.clone();
+        let mut col_indices = vec![0; total];
+        let mut values = vec![0.0; total];
+        for p in 0..trip.pos {
+            let (i, j, aij) = (
+                trip.indices_i[p] as usize,
+                trip.indices_j[p] as usize,
+                trip.values_aij[p],
+            );
+            col_indices[next[i]] = j;
+            values[next[i]] = aij;
+            next[i] += 1;
+            if mirror && i != j {
+                col_indices[next[j]] = i;
+                values[next[j]] = aij;
+                next[j] += 1;
+            }
+        }
+
+        // sort the columns of each row and sum up the repeated entries
+        let mut row: Vec<(usize, f64)> = Vec::new();
+        let mut nnz = 0;
+        let mut start = 0;
+        for i in 0..neq {
+            let end = row_pointers[i + 1];
+            row.clear();
+            row.extend(
+                col_indices[start..end]
+                    .iter()
+                    .copied()
+                    .zip(values[start..end].iter().copied()),
+            );
+            row.sort_by_key(|(j, _)| *j);
+            row_pointers[i] = nnz;
+            for (k, &(j, aij)) in row.iter().enumerate() {
+                if k > 0 && row[k - 1].0 == j {
+                    values[nnz - 1] += aij;
+                } else {
+                    col_indices[nnz] = j;
+                    values[nnz] = aij;
+                    nnz += 1;
+                }
+            }
+            start = end;
+        }
+        row_pointers[neq] = nnz;
+        col_indices.truncate(nnz);
+        values.truncate(nnz);
+        SparseCsr {
+            neq,
+            row_pointers,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Returns the (nrow = ncol) dimensions of the matrix
+    pub fn neq(&self) -> usize {
+        self.neq
+    }
+
+    /// Returns the number of stored entries (after summing up repeated entries)
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the row pointers (dim = neq + 1)
+    pub fn row_pointers(&self) -> &[usize] {
+        &self.row_pointers
+    }
+
+    /// Returns the column indices (dim = nnz)
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    /// Returns the values (dim = nnz)
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns the diagonal entries (zero if not stored)
+    pub fn diagonal(&self) -> Vector {
+        let mut d = Vector::new(self.neq);
+        for i in 0..self.neq {
+            for p in self.row_pointers[i]..self.row_pointers[i + 1] {
+                if self.col_indices[p] == i {
+                    d[i] = self.values[p];
+                }
+            }
+        }
+        d
+    }
+
+    /// Converts the CSR matrix to a dense matrix
+    ///
+    /// **Note:** The dense matrix must have dimensions (neq, neq).
+    pub fn to_matrix(&self, a: &mut Matrix) -> Result<(), StrError> {
+        if a.dims() != (self.neq, self.neq) {
+            return Err("wrong matrix dimensions");
+        }
+        a.fill(0.0);
+        for i in 0..self.neq {
+            for p in self.row_pointers[i]..self.row_pointers[i + 1] {
+                a.set(i, self.col_indices[p], self.values[p]);
+            }
+        }
+        Ok(())
+    }
+
+    /// Performs the matrix-vector multiplication
+    ///
+    /// ```text
+    ///  v  :=   a   ⋅  u
+    /// (m)    (m,m)   (m)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Vector;
+    /// use russell_sparse::{SparseCsr, SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // | 1  2 |
+    ///     // | 0  3 |
+    ///     let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::None)?;
+    ///     trip.put(0, 0, 1.0)?;
+    ///     trip.put(0, 1, 2.0)?;
+    ///     trip.put(1, 1, 3.0)?;
+    ///     let csr = SparseCsr::from_triplet(&trip);
+    ///     let v = csr.mat_vec_mul(&Vector::from(&[1.0, 1.0]))?;
+    ///     assert_eq!(v.as_data(), &[3.0, 3.0]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn mat_vec_mul(&self, u: &Vector) -> Result<Vector, StrError> {
+        if u.dim() != self.neq {
+            return Err("u.ndim must equal neq");
+        }
+        let mut v = Vector::new(self.neq);
+        for i in 0..self.neq {
+            let mut sum = 0.0;
+            for p in self.row_pointers[i]..self.row_pointers[i + 1] {
+                sum += self.values[p] * u[self.col_indices[p]];
+            }
+            v[i] = sum;
+        }
+        Ok(v)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SparseCsr;
+    use crate::{SparseTriplet, TripletSymmetry};
+    use russell_lab::{Matrix, Vector};
+
+    #[test]
+    fn from_triplet_works() {
+        //  1  -1   .  -3   .
+        // -2   5   .   .   .
+        //  .   .   4   6   4
+        // -4   .   2   7   .
+        //  .   8   .   .  -5
+        let mut trip = SparseTriplet::new(5, 14, TripletSymmetry::None).unwrap();
+        trip.put(4, 4, -5.0).unwrap();
+        trip.put(0, 0, 0.5).unwrap();
+        trip.put(0, 3, -3.0).unwrap();
+        trip.put(0, 1, -1.0).unwrap();
+        trip.put(1, 0, -2.0).unwrap();
+        trip.put(1, 1, 5.0).unwrap();
+        trip.put(2, 4, 4.0).unwrap();
+        trip.put(2, 2, 4.0).unwrap();
+        trip.put(2, 3, 6.0).unwrap();
+        trip.put(3, 0, -4.0).unwrap();
+        trip.put(3, 2, 2.0).unwrap();
+        trip.put(3, 3, 7.0).unwrap();
+        trip.put(4, 1, 8.0).unwrap();
+        trip.put(0, 0, 0.5).unwrap();
+        let csr = SparseCsr::from_triplet(&trip);
+        assert_eq!(csr.neq(), 5);
+        assert_eq!(csr.nnz(), 13);
+        assert_eq!(csr.row_pointers(), &[0, 3, 5, 8, 11, 13]);
+        assert_eq!(csr.col_indices(), &[0, 1, 3, 0, 1, 2, 3, 4, 0, 2, 3, 1, 4]);
+        assert_eq!(
+            csr.values(),
+            &[1.0, -1.0, -3.0, -2.0, 5.0, 4.0, 6.0, 4.0, -4.0, 2.0, 7.0, 8.0, -5.0]
+        );
+        let mut a = Matrix::new(5, 5);
+        let mut a_trip = Matrix::new(5, 5);
+        csr.to_matrix(&mut a).unwrap();
+        trip.to_matrix(&mut a_trip).unwrap();
+        assert_eq!(a.as_data(), a_trip.as_data());
+    }
+
+    #[test]
+    fn from_triplet_handles_symmetry_and_empty_rows() {
+        let mut trip = SparseTriplet::new(3, 3, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 2, 2.0).unwrap(); // stored as (2, 0)
+        trip.put(2, 0, 3.0).unwrap();
+        let csr = SparseCsr::from_triplet(&trip);
+        assert_eq!(csr.row_pointers(), &[0, 2, 2, 3]);
+        assert_eq!(csr.col_indices(), &[0, 2, 0]);
+        assert_eq!(csr.values(), &[1.0, 5.0, 5.0]);
+    }
+
+    #[test]
+    fn diagonal_works() {
+        let mut trip = SparseTriplet::new(3, 4, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(0, 1, 1.0).unwrap();
+        trip.put(2, 2, 4.0).unwrap();
+        trip.put(2, 2, 1.0).unwrap();
+        let csr = SparseCsr::from_triplet(&trip);
+        assert_eq!(csr.diagonal().as_data(), &[2.0, 0.0, 5.0]);
+    }
+
+    #[test]
+    fn to_matrix_fails_on_wrong_dims() {
+        let trip = SparseTriplet::new(2, 1, TripletSymmetry::None).unwrap();
+        let csr = SparseCsr::from_triplet(&trip);
+        let mut a = Matrix::new(2, 3);
+        assert_eq!(csr.to_matrix(&mut a).err(), Some("wrong matrix dimensions"));
+    }
+
+    #[test]
+    fn mat_vec_mul_works() {
+        let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(1, 0, 1.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        trip.put(2, 1, -1.0).unwrap();
+        trip.put(2, 2, 4.0).unwrap();
+        let csr = SparseCsr::from_triplet(&trip);
+        let u = Vector::from(&[1.0, 2.0, 3.0]);
+        assert_eq!(csr.mat_vec_mul(&Vector::new(2)).err(), Some("u.ndim must equal neq"));
+        let v = csr.mat_vec_mul(&u).unwrap();
+        assert_eq!(v.as_data(), trip.mat_vec_mul(&u).unwrap().as_data());
+        assert_eq!(v.as_data(), &[4.0, 4.0, 10.0]);
+    }
+}
@@ -0,0 +1,418 @@
+use super::{SparseCsr, SparseTriplet};
+use crate::StrError;
+use std::collections::VecDeque;
+
+/// Holds the adjacency graph of the sparsity pattern of a square sparse matrix
+///
+/// The vertices are the equations (rows) and there is an edge between `i` and `j` (i ≠ j)
+/// if `aij` or `aji` is stored in the matrix (i.e., the graph of the pattern of `a + aᵀ`).
+///
+/// # Remarks
+///
+/// - The diagonal entries are ignored (no self-loops)
+/// - Stored zero values are part of the pattern (only the structure is considered)
+/// - The neighbors of each vertex are sorted in ascending order
+/// - The algorithms are implemented natively; thus, they are independent of the external solvers
+///   and may be used, e.g., to detect disconnected substructures or to renumber the equations
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::{SparseGraph, SparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     // | 1  .  2  . |
+///     // | .  3  .  . |
+///     // | .  .  4  . |
+///     // | .  5  .  6 |
+///     let mut trip = SparseTriplet::new(4, 6, TripletSymmetry::None)?;
+///     trip.put(0, 0, 1.0)?;
+///     trip.put(0, 2, 2.0)?;
+///     trip.put(1, 1, 3.0)?;
+///     trip.put(2, 2, 4.0)?;
+///     trip.put(3, 1, 5.0)?;
+///     trip.put(3, 3, 6.0)?;
+///     let graph = SparseGraph::from_triplet(&trip);
+///     assert_eq!(graph.neighbors(2), &[0]);
+///     assert_eq!(graph.neighbors(1), &[3]);
+///     assert_eq!(graph.connected_components(), vec![vec![0, 2], vec![1, 3]]);
+///     Ok(())
+/// }
+/// ```
+pub struct SparseGraph {
+    neq: usize,               // number of vertices
+    adj_pointers: Vec<usize>, // [neq + 1] start of the neighbors of each vertex in adj_indices
+    adj_indices: Vec<usize>,  // [2 ⋅ n_edge] neighbors
+}
+
+impl SparseGraph {
+    /// Creates the adjacency graph of the sparsity pattern of a CSR matrix
+    pub fn from_csr(a: &SparseCsr) -> Self {
+        let neq = a.neq;
+
+        // collect the neighbors of each vertex (pattern of a + aᵀ)
+        let mut lists: Vec<Vec<usize>> = vec![Vec::new(); neq];
+        for i in 0..neq {
+            for p in a.row_pointers[i]..a.row_pointers[i + 1] {
+                let j = a.col_indices[p];
+                if i != j {
+                    lists[i].push(j);
+                    lists[j].push(i);
+                }
+            }
+        }
+
+        // sort, remove duplicates, and compress
+        let mut adj_pointers = vec![0; neq + 1];
+        let mut adj_indices = Vec::new();
+        for i in 0..neq {
+            lists[i].sort_unstable();
+            lists[i].dedup();
+            adj_indices.extend_from_slice(&lists[i]);
+            adj_pointers[i + 1] = adj_indices.len();
+        }
+        SparseGraph {
+            neq,
+            adj_pointers,
+            adj_indices,
+        }
+    }
+
+    /// Creates the adjacency graph of the sparsity pattern of a triplet
+    ///
+    /// **Note:** The symmetry of the triplet does not matter because the graph is always symmetric.
+    pub fn from_triplet(trip: &SparseTriplet) -> Self {
+        SparseGraph::from_csr(&SparseCsr::from_triplet(trip))
+    }
+
+    /// Returns the number of vertices (equations)
+    pub fn neq(&self) -> usize {
+        self.neq
+    }
+
+    /// Returns the number of edges (each edge i–j is counted once)
+    pub fn n_edge(&self) -> usize {
+        self.adj_indices.len() / 2
+    }
+
+    /// Returns the (sorted) neighbors of vertex `i`
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i ≥ neq`.
+    pub fn neighbors(&self, i: usize) -> &[usize] {
+        &self.adj_indices[self.adj_pointers[i]..self.adj_pointers[i + 1]]
+    }
+
+    /// Returns the degree (number of neighbors) of vertex `i`
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i ≥ neq`.
+    pub fn degree(&self, i: usize) -> usize {
+        self.adj_pointers[i + 1] - self.adj_pointers[i]
+    }
+
+    /// Finds the connected components of the graph
+    ///
+    /// Returns the lists of vertices of each component. The vertices of each component
+    /// are sorted in ascending order and the components are sorted by their first vertex.
+    ///
+    /// **Note:** More than one component means that the matrix is reducible; e.g., in
+    /// structural analyses, the model has disconnected (floating) substructures.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.neq];
+        let mut components = Vec::new();
+        for root in 0..self.neq {
+            if !visited[root] {
+                let mut component = self.breadth_first(root, &mut visited, false);
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+        components
+    }
+
+    /// Computes the reverse Cuthill-McKee (RCM) ordering
+    ///
+    /// The RCM ordering reduces the bandwidth (and profile) of the matrix. Each connected component
+    /// is numbered by a breadth-first search starting at a pseudo-peripheral vertex (George-Liu
+    /// algorithm), visiting the neighbors in ascending order of degree; the final sequence is reversed.
+    ///
+    /// Returns the permutation `perm` such that `perm[new] = old`; i.e., the equation `perm[k]`
+    /// of the original matrix becomes the k-th equation of the reordered matrix. This is the
+    /// convention of [russell_lab::Permutation]; thus, the ordering may be wrapped with
+    /// [russell_lab::Permutation::from_vec] to be inverted, composed, or applied to vectors and matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Permutation;
+    /// use russell_sparse::{SparseGraph, SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // chain 0–4–1–3–2 numbered badly
+    ///     let mut trip = SparseTriplet::new(5, 9, TripletSymmetry::LowerTriangular)?;
+    ///     for i in 0..5 {
+    ///         trip.put(i, i, 2.0)?;
+    ///     }
+    ///     trip.put(4, 0, -1.0)?;
+    ///     trip.put(4, 1, -1.0)?;
+    ///     trip.put(3, 1, -1.0)?;
+    ///     trip.put(3, 2, -1.0)?;
+    ///     let graph = SparseGraph::from_triplet(&trip);
+    ///     assert_eq!(graph.bandwidth(None)?, 4);
+    ///
+    ///     let perm = graph.rcm_ordering();
+    ///     assert_eq!(perm, &[2, 3, 1, 4, 0]);
+    ///     assert_eq!(graph.bandwidth(Some(&perm))?, 1);
+    ///
+    ///     // new equation number of each original equation
+    ///     let p = Permutation::from_vec(perm)?;
+    ///     assert_eq!(p.inverse().as_slice(), &[4, 2, 0, 1, 3]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn rcm_ordering(&self) -> Vec<usize> {
+        let mut perm = Vec::with_capacity(self.neq);
+        let mut visited = vec![false; self.neq];
+        for component in self.connected_components() {
+            let start = self.pseudo_peripheral_vertex(component[0]);
+            let order = self.breadth_first(start, &mut visited, true);
+            perm.extend_from_slice(&order);
+        }
+        perm.reverse();
+        perm
+    }
+
+    /// Returns the bandwidth of the matrix, i.e., the maximum |i - j| among the edges i–j
+    ///
+    /// # Input
+    ///
+    /// * `perm` -- the ordering `perm[new] = old` (e.g., from [SparseGraph::rcm_ordering]);
+    ///   None means the original ordering
+    pub fn bandwidth(&self, perm: Option<&[usize]>) -> Result<usize, StrError> {
+        let mut new_index: Vec<usize> = (0..self.neq).collect();
+        if let Some(p) = perm {
+            if p.len() != self.neq {
+                return Err("perm.len() must equal neq");
+            }
+            let mut found = vec![false; self.neq];
+            for (k, &old) in p.iter().enumerate() {
+                if old >= self.neq || found[old] {
+                    return Err("perm must be a permutation of 0..neq");
+                }
+                found[old] = true;
+                new_index[old] = k;
+            }
+        }
+        let mut bandwidth = 0;
+        for i in 0..self.neq {
+            for &j in self.neighbors(i) {
+                bandwidth = usize::max(bandwidth, new_index[i].abs_diff(new_index[j]));
+            }
+        }
+        Ok(bandwidth)
+    }
+
+    /// Performs a breadth-first search starting at `root` and returns the visited vertices
+    ///
+    /// If `by_degree` is true, the unvisited neighbors are queued in ascending order of degree.
+    fn breadth_first(&self, root: usize, visited: &mut [bool], by_degree: bool) -> Vec<usize> {
+        let mut order = Vec::new();
+        let mut queue = VecDeque::new();
+        let mut next: Vec<usize> = Vec::new();
+        visited[root] = true;
+        queue.push_back(root);
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+            next.clear();
+            next.extend(self.neighbors(i).iter().copied().filter(|&j| !visited[j]));
+            if by_degree {
+                // stable sort: ties are broken by the vertex number
+                next.sort_by_key(|&j| self.degree(j));
+            }
+            for &j in &next {
+                visited[j] = true;
+                queue.push_back(j);
+            }
+        }
+        order
+    }
+
+    /// Computes the level structure rooted at `root` (within its connected component)
+    fn level_structure(&self, root: usize, level: &mut [usize]) -> Vec<Vec<usize>> {
+        level.fill(usize::MAX);
+        level[root] = 0;
+        let mut levels = vec![vec![root]];
+        loop {
+            let mut next = Vec::new();
+            for &i in levels.last().unwrap() {
+                for &j in self.neighbors(i) {
+                    if level[j] == usize::MAX {
+                        level[j] = levels.len();
+                        next.push(j);
+                    }
+                }
+            }
+            if next.is_empty() {
+                return levels;
+            }
+            levels.push(next);
+        }
+    }
+
+    /// Finds a pseudo-peripheral vertex (approximately maximum eccentricity) in the component of `seed`
+    fn pseudo_peripheral_vertex(&self, seed: usize) -> usize {
+        let mut level = vec![usize::MAX; self.neq];
+        let mut root = seed;
+        let mut levels = self.level_structure(root, &mut level);
+
+        // start from a vertex of minimum degree
+        let mut min_degree = self.degree(root);
+        for list in &levels {
+            for &i in list {
+                if self.degree(i) < min_degree {
+                    min_degree = self.degree(i);
+                    root = i;
+                }
+            }
+        }
+        if root != seed {
+            levels = self.level_structure(root, &mut level);
+        }
+
+        // move to a vertex of minimum degree in the last level while the eccentricity increases
+        loop {
+            let last = levels.last().unwrap();
+            let candidate = *last.iter().min_by_key(|&&i| (self.degree(i), i)).unwrap();
+            let candidate_levels = self.level_structure(candidate, &mut level);
+            if candidate_levels.len() > levels.len() {
+                root = candidate;
+                levels = candidate_levels;
+            } else {
+                return root;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::SparseGraph;
+    use crate::{SparseCsr, SparseTriplet, TripletSymmetry};
+
+    /// Returns the triplet of the 2D Laplacian on a (nx,ny) grid numbered row by row
+    fn laplacian_2d(nx: usize, ny: usize) -> SparseTriplet {
+        let n = nx * ny;
+        let mut trip = SparseTriplet::new(n, 3 * n, TripletSymmetry::LowerTriangular).unwrap();
+        for r in 0..ny {
+            for c in 0..nx {
+                let i = r * nx + c;
+                trip.put(i, i, 4.0).unwrap();
+                if c > 0 {
+                    trip.put(i, i - 1, -1.0).unwrap();
+                }
+                if r > 0 {
+                    trip.put(i, i - nx, -1.0).unwrap();
+                }
+            }
+        }
+        trip
+    }
+
+    #[test]
+    fn from_csr_works() {
+        // non-symmetric pattern with repeated and zero entries
+        let mut trip = SparseTriplet::new(3, 6, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 2, 1.0).unwrap();
+        trip.put(0, 2, 1.0).unwrap();
+        trip.put(2, 0, 1.0).unwrap();
+        trip.put(1, 2, 0.0).unwrap();
+        let csr = SparseCsr::from_triplet(&trip);
+        let graph = SparseGraph::from_csr(&csr);
+        assert_eq!(graph.neq(), 3);
+        assert_eq!(graph.n_edge(), 2);
+        assert_eq!(graph.neighbors(0), &[2]);
+        assert_eq!(graph.neighbors(1), &[2]);
+        assert_eq!(graph.neighbors(2), &[0, 1]);
+        assert_eq!(graph.degree(0), 1);
+        assert_eq!(graph.degree(2), 2);
+
+        // lower triangular storage gives the same graph
+        let mut trip = SparseTriplet::new(3, 3, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(2, 0, 1.0).unwrap();
+        trip.put(2, 1, 1.0).unwrap();
+        trip.put(1, 1, 1.0).unwrap();
+        let graph = SparseGraph::from_triplet(&trip);
+        assert_eq!(graph.neighbors(0), &[2]);
+        assert_eq!(graph.neighbors(1), &[2]);
+        assert_eq!(graph.neighbors(2), &[0, 1]);
+    }
+
+    #[test]
+    fn connected_components_works() {
+        // two bars (0)--(3) and (1)--(4)--(2) and the isolated vertex (5)
+        let mut trip = SparseTriplet::new(6, 9, TripletSymmetry::LowerTriangular).unwrap();
+        for i in 0..6 {
+            trip.put(i, i, 1.0).unwrap();
+        }
+        trip.put(3, 0, -1.0).unwrap();
+        trip.put(4, 1, -1.0).unwrap();
+        trip.put(4, 2, -1.0).unwrap();
+        let graph = SparseGraph::from_triplet(&trip);
+        assert_eq!(graph.connected_components(), vec![vec![0, 3], vec![1, 2, 4], vec![5]]);
+
+        // connected
+        let graph = SparseGraph::from_triplet(&laplacian_2d(3, 2));
+        assert_eq!(graph.connected_components(), vec![vec![0, 1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    fn bandwidth_captures_errors() {
+        let graph = SparseGraph::from_triplet(&laplacian_2d(2, 2));
+        assert_eq!(
+            graph.bandwidth(Some(&[0, 1, 2])).err(),
+            Some("perm.len() must equal neq")
+        );
+        assert_eq!(
+            graph.bandwidth(Some(&[0, 1, 1, 3])).err(),
+            Some("perm must be a permutation of 0..neq")
+        );
+        assert_eq!(
+            graph.bandwidth(Some(&[0, 1, 2, 4])).err(),
+            Some("perm must be a permutation of 0..neq")
+        );
+        assert_eq!(graph.bandwidth(None), Ok(2));
+        assert_eq!(graph.bandwidth(Some(&[0, 1, 3, 2])), Ok(3));
+    }
+
+    #[test]
+    fn rcm_ordering_works() {
+        // wide grid numbered along the long direction: bandwidth = nx
+        let (nx, ny) = (8, 3);
+        let graph = SparseGraph::from_triplet(&laplacian_2d(nx, ny));
+        assert_eq!(graph.bandwidth(None), Ok(nx));
+        let perm = graph.rcm_ordering();
+        let mut sorted = perm.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..nx * ny).collect::<Vec<_>>());
+        assert!(graph.bandwidth(Some(&perm)).unwrap() <= ny + 1);
+
+        // disconnected components are numbered separately and isolated vertices are kept
+        let mut trip = SparseTriplet::new(5, 7, TripletSymmetry::LowerTriangular).unwrap();
+        for i in 0..5 {
+            trip.put(i, i, 1.0).unwrap();
+        }
+        trip.put(4, 0, -1.0).unwrap();
+        trip.put(3, 1, -1.0).unwrap();
+        let graph = SparseGraph::from_triplet(&trip);
+        assert_eq!(graph.bandwidth(None), Ok(4));
+        let perm = graph.rcm_ordering();
+        assert_eq!(perm.len(), 5);
+        assert_eq!(graph.bandwidth(Some(&perm)), Ok(1));
+    }
+}
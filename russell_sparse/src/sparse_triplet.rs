@@ -1,8 +1,12 @@
-use crate::StrError;
-use russell_lab::{Matrix, Vector};
+use crate::{StrError, TripletSymmetry};
+use russell_lab::{vec_norm, Matrix, Norm, Vector};
 use russell_openblas::to_i32;
+use std::collections::BTreeMap;
 use std::fmt;
 
+/// Default maximum number of entries listed by the alternate format of Display (`{:#}`)
+pub const DISPLAY_MAX_ENTRIES: usize = 10;
+
 /// Holds triples (i,j,aij) representing a sparse matrix
 ///
 /// # Remarks
@@ -13,14 +17,16 @@ use std::fmt;
 /// - The repeated (i,j) capability is of great convenience for Finite Element solvers
 /// - A maximum number of entries must be decided prior to allocating a new Triplet
 /// - The maximum number of entries includes possible entries with repeated indices
+/// - The symmetry option tells the Solver how to mirror or restrict the entries (see [TripletSymmetry])
 /// - See the `to_matrix` method for an example
 pub struct SparseTriplet {
-    pub(crate) neq: usize,           // [i32] number of rows = number of columns = n_equation
-    pub(crate) pos: usize,           // [i32] current index => nnz in the end
-    pub(crate) max: usize,           // [i32] max allowed number of entries (may be > nnz)
-    pub(crate) indices_i: Vec<i32>,  // [nnz] indices i
-    pub(crate) indices_j: Vec<i32>,  // [nnz] indices j
-    pub(crate) values_aij: Vec<f64>, // [nnz] values aij
+    pub(crate) neq: usize,                // [i32] number of rows = number of columns = n_equation
+    pub(crate) pos: usize,                // [i32] current index => nnz in the end
+    pub(crate) max: usize,                // [i32] max allowed number of entries (may be > nnz)
+    pub(crate) symmetry: TripletSymmetry, // symmetry and storage scheme
+    pub(crate) indices_i: Vec<i32>,       // [nnz] indices i
+    pub(crate) indices_j: Vec<i32>,       // [nnz] indices j
+    pub(crate) values_aij: Vec<f64>,      // [nnz] values aij
 }
 
 impl SparseTriplet {
@@ -37,19 +43,20 @@ impl SparseTriplet {
     /// * `max` -- The maximum number fo non-zero (nnz) values in the sparse matrix,
     ///            including entries with repeated indices
     ///   **Note:** This value must be greater than or equal to the actual nnz.
+    /// * `symmetry` -- The symmetry and storage scheme (see [TripletSymmetry])
     ///
     /// # Example
     ///
     /// ```
-    /// use russell_sparse::{SparseTriplet, StrError};
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     let (neq, nnz) = (3, 4);
-    ///     let trip = SparseTriplet::new(neq, nnz)?;
+    ///     let trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     Ok(())
     /// }
     /// ```
-    pub fn new(neq: usize, max: usize) -> Result<Self, StrError> {
+    pub fn new(neq: usize, max: usize, symmetry: TripletSymmetry) -> Result<Self, StrError> {
         if neq == 0 || max == 0 {
             return Err("neq and max must be greater than zero");
         }
@@ -57,6 +64,7 @@ impl SparseTriplet {
             neq,
             pos: 0,
             max,
+            symmetry,
             indices_i: vec![0; max],
             indices_j: vec![0; max],
             values_aij: vec![0.0; max],
@@ -65,14 +73,17 @@ impl SparseTriplet {
 
     /// Puts the next triple (i,j,aij) into the Triplet
     ///
+    /// **Note:** If the symmetry is [TripletSymmetry::LowerTriangular], an upper triangular
+    /// entry (i < j) is stored as (j,i), i.e., in the lower triangle.
+    ///
     /// # Example
     ///
     /// ```
-    /// use russell_sparse::{SparseTriplet, StrError};
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     let (neq, nnz) = (3, 4);
-    ///     let mut trip = SparseTriplet::new(neq, nnz)?;
+    ///     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     trip.put(0, 0, 1.0)?;
     ///     trip.put(1, 1, 2.0)?;
     ///     trip.put(2, 2, 3.0)?;
@@ -90,6 +101,10 @@ impl SparseTriplet {
         if self.pos >= self.max {
             return Err("current nnz (number of non-zeros) reached maximum limit");
         }
+        let (i, j) = match self.symmetry {
+            TripletSymmetry::LowerTriangular if i < j => (j, i),
+            _ => (i, j),
+        };
         let i_i32 = to_i32(i);
         let j_i32 = to_i32(j);
         self.indices_i[self.pos] = i_i32;
@@ -99,16 +114,241 @@ impl SparseTriplet {
         Ok(())
     }
 
+    /// Puts the entries of a dense block into the Triplet, skipping the (nearly) zero entries
+    ///
+    /// Scatters the dense (m,n) block `a_dense` such that:
+    ///
+    /// ```text
+    /// trip[row_map[i], col_map[j]] += a_dense[i, j]    if |a_dense[i, j]| > tol
+    /// ```
+    ///
+    /// as required by the assembly of finite element matrices. Skipping the entries below the tolerance
+    /// reduces the number of non-zeros of smoothed or regularized operators (e.g., `tol = 0` skips exact zeros).
+    ///
+    /// **Note:** If the symmetry is [TripletSymmetry::LowerTriangular], `a_dense` must hold a full block
+    /// of the symmetric matrix and the entries mapped to the upper triangle (`row_map[i] < col_map[j]`)
+    /// are skipped; otherwise, the off-diagonal entries would be put twice.
+    ///
+    /// # Input
+    ///
+    /// * `a_dense` -- the (m,n) dense block
+    /// * `row_map` -- the (m) global row indices of the rows of the block
+    /// * `col_map` -- the (n) global column indices of the columns of the block
+    /// * `tol` -- the absolute tolerance (≥ 0) below which (or at which) the entries are skipped
+    ///
+    /// # Output
+    ///
+    /// Returns the number of entries put into the Triplet. Nothing is put if an error occurs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Matrix;
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let mut trip = SparseTriplet::new(3, 6, TripletSymmetry::None)?;
+    ///     let block = Matrix::from(&[
+    ///         [1.0, 1e-17],
+    ///         [0.0, 2.0],
+    ///     ]);
+    ///     let count = trip.put_block(&block, &[0, 2], &[0, 2], 1e-15)?;
+    ///     assert_eq!(count, 2);
+    ///     assert_eq!(
+    ///         trip.to_string_full(),
+    ///         "┌       ┐\n\
+    ///          │ 1 0 0 │\n\
+    ///          │ 0 0 0 │\n\
+    ///          │ 0 0 2 │\n\
+    ///          └       ┘"
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn put_block(
+        &mut self,
+        a_dense: &Matrix,
+        row_map: &[usize],
+        col_map: &[usize],
+        tol: f64,
+    ) -> Result<usize, StrError> {
+        let (m, n) = a_dense.dims();
+        if row_map.len() != m || col_map.len() != n {
+            return Err("the lengths of row_map and col_map must equal the dimensions of the block");
+        }
+        if tol.is_nan() || tol < 0.0 {
+            return Err("tol must be non-negative");
+        }
+        if row_map.iter().any(|&i| i >= self.neq) {
+            return Err("sparse matrix row index is out of bounds");
+        }
+        if col_map.iter().any(|&j| j >= self.neq) {
+            return Err("sparse matrix column index is out of bounds");
+        }
+        let lower = self.symmetry == TripletSymmetry::LowerTriangular;
+        let selected = || {
+            row_map.iter().enumerate().flat_map(move |(i, &gi)| {
+                col_map.iter().enumerate().filter_map(move |(j, &gj)| {
+                    let aij = a_dense.get(i, j);
+                    if f64::abs(aij) <= tol || (lower && gi < gj) {
+                        None
+                    } else {
+                        Some((gi, gj, aij))
+                    }
+                })
+            })
+        };
+        let count = selected().count();
+        if self.pos + count > self.max {
+            return Err("current nnz (number of non-zeros) reached maximum limit");
+        }
+        for (gi, gj, aij) in selected() {
+            self.put(gi, gj, aij)?;
+        }
+        Ok(count)
+    }
+
+    /// Creates a new SparseTriplet from the arrays of a compressed sparse row (CSR) matrix
+    ///
+    /// The arrays follow the standard 0-based CSR convention (e.g., `indptr`, `indices`, and `data`
+    /// of scipy.sparse.csr_matrix): the column indices and values of row `i` are stored in
+    /// `indices[indptr[i]..indptr[i+1]]` and `values[indptr[i]..indptr[i+1]]`.
+    ///
+    /// # Input
+    ///
+    /// * `indptr` -- (neq + 1) row pointers; `indptr[0] = 0` and `indptr[neq] = nnz`
+    /// * `indices` -- (nnz) column indices
+    /// * `values` -- (nnz) values
+    /// * `symmetry` -- The symmetry and storage scheme (see [TripletSymmetry]).
+    ///   **Note:** With a symmetric storage, only one triangle must be given.
+    ///
+    /// **Note:** Unsorted column indices and repeated entries are allowed (see the remarks of [SparseTriplet]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // | 1  .  2 |
+    ///     // | .  3  . |
+    ///     // | 4  .  5 |
+    ///     let indptr = &[0, 2, 3, 5];
+    ///     let indices = &[0, 2, 1, 0, 2];
+    ///     let values = &[1.0, 2.0, 3.0, 4.0, 5.0];
+    ///     let trip = SparseTriplet::from_csr_parts(indptr, indices, values, TripletSymmetry::None)?;
+    ///     assert_eq!(
+    ///         trip.to_string_full(),
+    ///         "┌       ┐\n\
+    ///          │ 1 0 2 │\n\
+    ///          │ 0 3 0 │\n\
+    ///          │ 4 0 5 │\n\
+    ///          └       ┘"
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_csr_parts(
+        indptr: &[usize],
+        indices: &[usize],
+        values: &[f64],
+        symmetry: TripletSymmetry,
+    ) -> Result<Self, StrError> {
+        SparseTriplet::from_compressed_parts(indptr, indices, values, symmetry, false)
+    }
+
+    /// Creates a new SparseTriplet from the arrays of a compressed sparse column (CSC) matrix
+    ///
+    /// The arrays follow the standard 0-based CSC convention (e.g., `indptr`, `indices`, and `data`
+    /// of scipy.sparse.csc_matrix): the row indices and values of column `j` are stored in
+    /// `indices[indptr[j]..indptr[j+1]]` and `values[indptr[j]..indptr[j+1]]`.
+    ///
+    /// # Input
+    ///
+    /// * `indptr` -- (neq + 1) column pointers; `indptr[0] = 0` and `indptr[neq] = nnz`
+    /// * `indices` -- (nnz) row indices
+    /// * `values` -- (nnz) values
+    /// * `symmetry` -- The symmetry and storage scheme (see [TripletSymmetry]).
+    ///   **Note:** With a symmetric storage, only one triangle must be given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // | 1  .  2 |
+    ///     // | .  3  . |
+    ///     // | 4  .  5 |
+    ///     let indptr = &[0, 2, 3, 5];
+    ///     let indices = &[0, 2, 1, 0, 2];
+    ///     let values = &[1.0, 4.0, 3.0, 2.0, 5.0];
+    ///     let trip = SparseTriplet::from_csc_parts(indptr, indices, values, TripletSymmetry::None)?;
+    ///     assert_eq!(
+    ///         trip.to_string_full(),
+    ///         "┌       ┐\n\
+    ///          │ 1 0 2 │\n\
+    ///          │ 0 3 0 │\n\
+    ///          │ 4 0 5 │\n\
+    ///          └       ┘"
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_csc_parts(
+        indptr: &[usize],
+        indices: &[usize],
+        values: &[f64],
+        symmetry: TripletSymmetry,
+    ) -> Result<Self, StrError> {
+        SparseTriplet::from_compressed_parts(indptr, indices, values, symmetry, true)
+    }
+
+    /// Creates a new SparseTriplet from CSR arrays (or CSC arrays if `by_column`)
+    fn from_compressed_parts(
+        indptr: &[usize],
+        indices: &[usize],
+        values: &[f64],
+        symmetry: TripletSymmetry,
+        by_column: bool,
+    ) -> Result<Self, StrError> {
+        if indptr.len() < 2 {
+            return Err("indptr must have at least two entries");
+        }
+        let neq = indptr.len() - 1;
+        let nnz = indices.len();
+        if values.len() != nnz {
+            return Err("indices and values must have the same length");
+        }
+        if indptr[0] != 0 || indptr[neq] != nnz {
+            return Err("indptr must start at zero and end at nnz");
+        }
+        if indptr.windows(2).any(|w| w[0] > w[1]) {
+            return Err("indptr must be non-decreasing");
+        }
+        let mut trip = SparseTriplet::new(neq, usize::max(1, nnz), symmetry)?;
+        for k in 0..neq {
+            for p in indptr[k]..indptr[k + 1] {
+                if by_column {
+                    trip.put(indices[p], k, values[p])?;
+                } else {
+                    trip.put(k, indices[p], values[p])?;
+                }
+            }
+        }
+        Ok(trip)
+    }
+
     /// Returns the (nrow = ncol) dimensions of the matrix represented by this Triplet
     ///
     /// # Example
     ///
     /// ```
-    /// use russell_sparse::{SparseTriplet, StrError};
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     let (neq, nnz) = (2, 1);
-    ///     let trip = SparseTriplet::new(neq, nnz)?;
+    ///     let trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     assert_eq!(trip.neq(), 2);
     ///     Ok(())
     /// }
@@ -122,11 +362,11 @@ impl SparseTriplet {
     /// # Example
     ///
     /// ```
-    /// use russell_sparse::{SparseTriplet, StrError};
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     let (neq, nnz) = (2, 1);
-    ///     let mut trip = SparseTriplet::new(neq, neq)?;
+    ///     let mut trip = SparseTriplet::new(neq, neq, TripletSymmetry::None)?;
     ///     assert_eq!(trip.nnz_current(), 0);
     ///     trip.put(0, 0, 1.0);
     ///     assert_eq!(trip.nnz_current(), 1);
@@ -137,16 +377,33 @@ impl SparseTriplet {
         self.pos
     }
 
+    /// Returns the symmetry and storage scheme
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let trip = SparseTriplet::new(2, 2, TripletSymmetry::LowerTriangular)?;
+    ///     assert_eq!(trip.symmetry(), TripletSymmetry::LowerTriangular);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn symmetry(&self) -> TripletSymmetry {
+        self.symmetry
+    }
+
     /// Returns the maximum allowed number of non-zero values (max)
     ///
     /// # Example
     ///
     /// ```
-    /// use russell_sparse::{SparseTriplet, StrError};
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     let (neq, nnz) = (2, 1);
-    ///     let trip = SparseTriplet::new(neq, nnz)?;
+    ///     let trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     assert_eq!(trip.nnz_maximum(), 1);
     ///     Ok(())
     /// }
@@ -160,11 +417,11 @@ impl SparseTriplet {
     /// # Example
     ///
     /// ```
-    /// use russell_sparse::{SparseTriplet, StrError};
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     let (neq, nnz) = (3, 4);
-    ///     let mut trip = SparseTriplet::new(neq, nnz)?;
+    ///     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     trip.put(0, 0, 1.0)?;
     ///     trip.put(1, 1, 2.0)?;
     ///     trip.put(2, 2, 3.0)?;
@@ -184,13 +441,13 @@ impl SparseTriplet {
     /// Note: this function calls [SparseTriplet::to_matrix].
     ///
     /// ```
-    /// use russell_sparse::{SparseTriplet, StrError};
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     // define (4 x 4) sparse matrix with 6+1 non-zero values
     ///     // (with an extra ij-repeated entry)
     ///     let (neq, nnz) = (4, 7);
-    ///     let mut trip = SparseTriplet::new(neq, nnz)?;
+    ///     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     trip.put(0, 0, 0.5)?; // (0, 0, a00/2)
     ///     trip.put(0, 0, 0.5)?; // (0, 0, a00/2)
     ///     trip.put(0, 1, 2.0)?;
@@ -217,6 +474,36 @@ impl SparseTriplet {
         a
     }
 
+    /// Returns a string with the dense representation of this Triplet
+    ///
+    /// Note: this function allocates a (neq x neq) dense matrix; thus, it is
+    /// only suitable for small matrices (e.g., when debugging the assembly process).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     let (neq, nnz) = (3, 4);
+    ///     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
+    ///     trip.put(0, 0, 1.0)?;
+    ///     trip.put(1, 1, 2.0)?;
+    ///     trip.put(2, 2, 3.0)?;
+    ///     trip.put(0, 1, 4.0)?;
+    ///     let correct = "┌       ┐\n\
+    ///                    │ 1 4 0 │\n\
+    ///                    │ 0 2 0 │\n\
+    ///                    │ 0 0 3 │\n\
+    ///                    └       ┘";
+    ///     assert_eq!(trip.to_string_full(), correct);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_string_full(&self) -> String {
+        format!("{}", self.as_matrix())
+    }
+
     /// Converts the triplet data to a matrix, up to a limit
     ///
     /// Note: see the function [SparseTriplet::as_matrix] that returns the Matrix already.
@@ -226,17 +513,20 @@ impl SparseTriplet {
     /// `a` -- (nrow_max, ncol_max) matrix to hold the triplet data.
     ///  The output matrix may have fewer rows or fewer columns than the triplet data.
     ///
+    /// **Note:** If the symmetry is [TripletSymmetry::LowerTriangular], the off-diagonal
+    /// entries are mirrored; i.e., the output is the full matrix.
+    ///
     /// # Example
     ///
     /// ```
     /// use russell_lab::{Matrix};
-    /// use russell_sparse::{SparseTriplet, StrError};
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     // define (4 x 4) sparse matrix with 6+1 non-zero values
     ///     // (with an extra ij-repeated entry)
     ///     let (neq, nnz) = (4, 7);
-    ///     let mut trip = SparseTriplet::new(neq, nnz)?;
+    ///     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     trip.put(0, 0, 0.5)?; // (0, 0, a00/2)
     ///     trip.put(0, 0, 0.5)?; // (0, 0, a00/2)
     ///     trip.put(0, 1, 2.0)?;
@@ -275,12 +565,21 @@ impl SparseTriplet {
         }
         let m_i32 = to_i32(m);
         let n_i32 = to_i32(n);
+        let mirror = self.symmetry == TripletSymmetry::LowerTriangular;
         a.fill(0.0);
         for p in 0..self.pos {
             if self.indices_i[p] < m_i32 && self.indices_j[p] < n_i32 {
                 let (i, j) = (self.indices_i[p] as usize, self.indices_j[p] as usize);
                 a.add(i, j, self.values_aij[p]);
             }
+            if mirror
+                && self.indices_i[p] != self.indices_j[p]
+                && self.indices_j[p] < m_i32
+                && self.indices_i[p] < n_i32
+            {
+                let (i, j) = (self.indices_i[p] as usize, self.indices_j[p] as usize);
+                a.add(j, i, self.values_aij[p]);
+            }
         }
         Ok(())
     }
@@ -292,11 +591,8 @@ impl SparseTriplet {
     /// (m)    (m,n)   (n)
     /// ```
     ///
-    /// # Input
-    ///
-    /// * `triangular` -- must be set to true if the triplet stores
-    ///   the components of the matrix in triangular format, i.e.,
-    ///   only the upper/lower diagonal values and the diagonal are stored
+    /// **Note:** If the symmetry is [TripletSymmetry::LowerTriangular], the off-diagonal
+    /// entries are accounted for on both sides of the diagonal.
     ///
     /// # Note
     ///
@@ -306,12 +602,12 @@ impl SparseTriplet {
     ///
     /// ```
     /// use russell_lab::{Matrix, Vector};
-    /// use russell_sparse::{SparseTriplet, StrError};
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     // set sparse matrix (3 x 3) with 6 non-zeros
     ///     let (neq, nnz) = (3, 6);
-    ///     let mut trip = SparseTriplet::new(neq, nnz)?;
+    ///     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     trip.put(0, 0, 1.0)?;
     ///     trip.put(1, 0, 2.0)?;
     ///     trip.put(1, 1, 3.0)?;
@@ -331,7 +627,7 @@ impl SparseTriplet {
     ///
     ///     // perform mat-vec-mul
     ///     let u = Vector::from(&[1.0, 1.0, 1.0]);
-    ///     let v = trip.mat_vec_mul(&u, false)?;
+    ///     let v = trip.mat_vec_mul(&u)?;
     ///
     ///     // check vector
     ///     let correct_v = "┌    ┐\n\
@@ -343,10 +639,11 @@ impl SparseTriplet {
     ///     Ok(())
     /// }
     /// ```
-    pub fn mat_vec_mul(&self, u: &Vector, triangular: bool) -> Result<Vector, StrError> {
+    pub fn mat_vec_mul(&self, u: &Vector) -> Result<Vector, StrError> {
         if u.dim() != self.neq {
             return Err("u.ndim must equal neq");
         }
+        let triangular = self.symmetry == TripletSymmetry::LowerTriangular;
         let mut v = Vector::new(self.neq);
         for p in 0..self.pos {
             let i = self.indices_i[p] as usize;
@@ -359,9 +656,156 @@ impl SparseTriplet {
         }
         Ok(v)
     }
+
+    /// Computes the norm of the matrix represented by this Triplet
+    ///
+    /// **Note:** Entries with repeated (i,j) indices are summed first. If the symmetry
+    /// is [TripletSymmetry::LowerTriangular], the off-diagonal entries are mirrored.
+    /// See [Norm] for the definitions (e.g., [Norm::P] is an entry-wise norm for matrices).
+    /// The entries are visited in (i,j) order; thus, the result is reproducible.
+    ///
+    /// An error is returned if [Norm::P] is given with p < 1 (or NaN).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Norm;
+    /// use russell_sparse::{SparseTriplet, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // ┌       ┐
+    ///     // │ 1 2 0 │
+    ///     // │ 2 3 4 │
+    ///     // │ 0 4 0 │
+    ///     // └       ┘
+    ///     let mut trip = SparseTriplet::new(3, 4, TripletSymmetry::LowerTriangular)?;
+    ///     trip.put(0, 0, 1.0)?;
+    ///     trip.put(1, 0, 2.0)?;
+    ///     trip.put(1, 1, 3.0)?;
+    ///     trip.put(2, 1, 4.0)?;
+    ///     assert_eq!(trip.norm(Norm::One)?, 9.0);
+    ///     assert_eq!(trip.norm(Norm::Inf)?, 9.0);
+    ///     assert_eq!(trip.norm(Norm::Max)?, 4.0);
+    ///     assert_eq!(trip.norm(Norm::Fro)?, f64::sqrt(50.0));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn norm(&self, kind: Norm) -> Result<f64, StrError> {
+        if let Norm::P(p) = kind {
+            if p.is_nan() || p < 1.0 {
+                return Err("the p-norm requires p ≥ 1");
+            }
+        }
+        // sum the entries with repeated indices
+        let mirror = self.symmetry == TripletSymmetry::LowerTriangular;
+        let mut entries: BTreeMap<(usize, usize), f64> = BTreeMap::new();
+        for p in 0..self.pos {
+            let (i, j) = (self.indices_i[p] as usize, self.indices_j[p] as usize);
+            *entries.entry((i, j)).or_insert(0.0) += self.values_aij[p];
+            if mirror && i != j {
+                *entries.entry((j, i)).or_insert(0.0) += self.values_aij[p];
+            }
+        }
+        match kind {
+            Norm::Inf | Norm::One => {
+                let mut sums = vec![0.0; self.neq];
+                for ((i, j), aij) in &entries {
+                    let k = if kind == Norm::Inf { *i } else { *j };
+                    sums[k] += f64::abs(*aij);
+                }
+                Ok(sums.iter().fold(0.0, |acc, &x| f64::max(acc, x)))
+            }
+            _ => {
+                let values: Vec<f64> = entries.values().copied().collect();
+                Ok(vec_norm(&Vector::from(&values), kind))
+            }
+        }
+    }
+
+    /// Returns the entries mirrored or restricted as required by a solver backend
+    ///
+    /// # Input
+    ///
+    /// * `lower_only` -- the backend requires only the lower triangle (including the diagonal);
+    ///   otherwise, the backend requires the full matrix
+    ///
+    /// # Output
+    ///
+    /// Returns `None` if the entries can be used as they are; otherwise, returns
+    /// the new `(indices_i, indices_j, values_aij)` arrays with the required entries only.
+    pub(crate) fn entries_for_solver(&self, lower_only: bool) -> Option<(Vec<i32>, Vec<i32>, Vec<f64>)> {
+        match (self.symmetry, lower_only) {
+            (TripletSymmetry::GeneralFull, true) => {
+                let mut indices_i = Vec::with_capacity(self.pos);
+                let mut indices_j = Vec::with_capacity(self.pos);
+                let mut values_aij = Vec::with_capacity(self.pos);
+                for p in 0..self.pos {
+                    if self.indices_j[p] <= self.indices_i[p] {
+                        indices_i.push(self.indices_i[p]);
+                        indices_j.push(self.indices_j[p]);
+                        values_aij.push(self.values_aij[p]);
+                    }
+                }
+                Some((indices_i, indices_j, values_aij))
+            }
+            (TripletSymmetry::LowerTriangular, false) => {
+                let mut indices_i = Vec::with_capacity(2 * self.pos);
+                let mut indices_j = Vec::with_capacity(2 * self.pos);
+                let mut values_aij = Vec::with_capacity(2 * self.pos);
+                for p in 0..self.pos {
+                    indices_i.push(self.indices_i[p]);
+                    indices_j.push(self.indices_j[p]);
+                    values_aij.push(self.values_aij[p]);
+                    if self.indices_i[p] != self.indices_j[p] {
+                        indices_i.push(self.indices_j[p]);
+                        indices_j.push(self.indices_i[p]);
+                        values_aij.push(self.values_aij[p]);
+                    }
+                }
+                Some((indices_i, indices_j, values_aij))
+            }
+            _ => None,
+        }
+    }
+
+    /// Computes the Jacobi (diagonal) scaling factors used to equilibrate the matrix
+    ///
+    /// ```text
+    /// dᵢ = 1 / √|aᵢᵢ|
+    /// ```
+    ///
+    /// The scaled matrix `D⋅A⋅D` (with `D = diag(d)`) has unit diagonal (in absolute value) and
+    /// keeps the symmetry of `A`. Duplicate diagonal entries are summed up first.
+    ///
+    /// **Note:** The factor is set to one if the diagonal entry is zero (or not finite).
+    pub(crate) fn jacobi_scaling(&self) -> Vec<f64> {
+        let mut diagonal = vec![0.0; self.neq];
+        for p in 0..self.pos {
+            if self.indices_i[p] == self.indices_j[p] {
+                diagonal[self.indices_i[p] as usize] += self.values_aij[p];
+            }
+        }
+        diagonal
+            .iter()
+            .map(|aii| {
+                let value = f64::abs(*aii);
+                if value > 0.0 && value.is_finite() {
+                    1.0 / f64::sqrt(value)
+                } else {
+                    1.0
+                }
+            })
+            .collect()
+    }
 }
 
 impl fmt::Display for SparseTriplet {
+    /// Prints the metadata and, optionally, some statistics and the first entries
+    ///
+    /// The default format prints only the metadata (neq, nnz_current, nnz_maximum) as JSON-like lines.
+    /// The alternate format (`{:#}`) also prints the minimum, maximum and maximum absolute value
+    /// of the aij entries, followed by the (i,j,aij) triples. The precision, e.g., `{:#.5}`,
+    /// sets the maximum number of listed entries (default is [DISPLAY_MAX_ENTRIES]).
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -371,6 +815,26 @@ impl fmt::Display for SparseTriplet {
             self.neq, self.pos, self.max,
         )
         .unwrap();
+        if !f.alternate() || self.pos == 0 {
+            return Ok(());
+        }
+        let values = &self.values_aij[0..self.pos];
+        let min = values.iter().fold(f64::INFINITY, |acc, &x| f64::min(acc, x));
+        let max = values.iter().fold(f64::NEG_INFINITY, |acc, &x| f64::max(acc, x));
+        let max_abs = values.iter().fold(0.0, |acc, &x| f64::max(acc, f64::abs(x)));
+        writeln!(f, "min(aij) = {}, max(aij) = {}, max(|aij|) = {}", min, max, max_abs).unwrap();
+        let n_entries = usize::min(f.precision().unwrap_or(DISPLAY_MAX_ENTRIES), self.pos);
+        for p in 0..n_entries {
+            writeln!(
+                f,
+                "({}, {}) = {}",
+                self.indices_i[p], self.indices_j[p], self.values_aij[p]
+            )
+            .unwrap();
+        }
+        if n_entries < self.pos {
+            writeln!(f, "... ({} more entries)", self.pos - n_entries).unwrap();
+        }
         Ok(())
     }
 }
@@ -380,24 +844,25 @@ impl fmt::Display for SparseTriplet {
 #[cfg(test)]
 mod tests {
     use super::SparseTriplet;
-    use russell_chk::vec_approx_eq;
-    use russell_lab::{Matrix, Vector};
+    use crate::TripletSymmetry;
+    use russell_chk::{approx_eq, vec_approx_eq};
+    use russell_lab::{mat_norm, Matrix, Norm, Vector};
 
     #[test]
     fn new_fails_on_wrong_input() {
         assert_eq!(
-            SparseTriplet::new(0, 3).err(),
+            SparseTriplet::new(0, 3, TripletSymmetry::None).err(),
             Some("neq and max must be greater than zero")
         );
         assert_eq!(
-            SparseTriplet::new(3, 0).err(),
+            SparseTriplet::new(3, 0, TripletSymmetry::None).err(),
             Some("neq and max must be greater than zero")
         );
     }
 
     #[test]
     fn new_works() {
-        let trip = SparseTriplet::new(3, 5).unwrap();
+        let trip = SparseTriplet::new(3, 5, TripletSymmetry::None).unwrap();
         assert_eq!(trip.neq, 3);
         assert_eq!(trip.pos, 0);
         assert_eq!(trip.max, 5);
@@ -405,7 +870,7 @@ mod tests {
 
     #[test]
     fn put_fails_on_wrong_values() {
-        let mut trip = SparseTriplet::new(1, 1).unwrap();
+        let mut trip = SparseTriplet::new(1, 1, TripletSymmetry::None).unwrap();
         assert_eq!(
             trip.put(1, 0, 0.0).err(),
             Some("sparse matrix row index is out of bounds")
@@ -423,7 +888,7 @@ mod tests {
 
     #[test]
     fn put_works() {
-        let mut trip = SparseTriplet::new(3, 5).unwrap();
+        let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         assert_eq!(trip.pos, 1);
         trip.put(0, 1, 2.0).unwrap();
@@ -438,7 +903,7 @@ mod tests {
 
     #[test]
     fn getters_and_reset_work() {
-        let mut trip = SparseTriplet::new(2, 4).unwrap();
+        let mut trip = SparseTriplet::new(2, 4, TripletSymmetry::None).unwrap();
         assert_eq!(trip.nnz_current(), 0);
         trip.put(0, 0, 1.0).unwrap();
         trip.put(0, 1, 4.0).unwrap();
@@ -453,7 +918,7 @@ mod tests {
 
     #[test]
     fn to_matrix_fails_on_wrong_dims() {
-        let trip = SparseTriplet::new(1, 1).unwrap();
+        let trip = SparseTriplet::new(1, 1, TripletSymmetry::None).unwrap();
         let mut a_2x1 = Matrix::new(2, 1);
         let mut a_1x2 = Matrix::new(1, 2);
         assert_eq!(trip.to_matrix(&mut a_2x1), Err("wrong matrix dimensions"));
@@ -462,7 +927,7 @@ mod tests {
 
     #[test]
     fn to_matrix_works() {
-        let mut trip = SparseTriplet::new(3, 5).unwrap();
+        let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(0, 1, 2.0).unwrap();
         trip.put(1, 0, 3.0).unwrap();
@@ -489,7 +954,7 @@ mod tests {
     fn to_matrix_with_duplicates_works() {
         // allocate a square matrix
         let (neq, nnz) = (5, 13);
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
         trip.put(0, 0, 1.0).unwrap(); // << (0, 0, a00/2)
         trip.put(1, 0, 3.0).unwrap();
@@ -517,11 +982,113 @@ mod tests {
         assert_eq!(format!("{}", a), correct);
     }
 
+    #[test]
+    fn put_lower_triangular_works() {
+        let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 1, 2.0).unwrap(); // stored as (1, 0)
+        trip.put(1, 1, 3.0).unwrap();
+        assert_eq!(trip.symmetry(), TripletSymmetry::LowerTriangular);
+        assert_eq!(trip.indices_i, &[0, 1, 1]);
+        assert_eq!(trip.indices_j, &[0, 0, 1]);
+        let correct = "┌     ┐\n\
+                       │ 1 2 │\n\
+                       │ 2 3 │\n\
+                       └     ┘";
+        assert_eq!(format!("{}", trip.as_matrix()), correct);
+        let mut a = Matrix::new(1, 2);
+        trip.to_matrix(&mut a).unwrap();
+        assert_eq!(a.as_data(), &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn norm_captures_errors() {
+        let trip = SparseTriplet::new(2, 1, TripletSymmetry::None).unwrap();
+        assert_eq!(trip.norm(Norm::P(0.5)).err(), Some("the p-norm requires p ≥ 1"));
+        assert_eq!(trip.norm(Norm::P(f64::NAN)).err(), Some("the p-norm requires p ≥ 1"));
+    }
+
+    #[test]
+    fn norm_works() {
+        let trip = SparseTriplet::new(2, 1, TripletSymmetry::None).unwrap();
+        assert_eq!(trip.norm(Norm::One), Ok(0.0));
+        assert_eq!(trip.norm(Norm::Fro), Ok(0.0));
+
+        // with repeated entries
+        let mut trip = SparseTriplet::new(3, 7, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 2.5).unwrap();
+        trip.put(0, 0, 2.5).unwrap();
+        trip.put(0, 1, -4.0).unwrap();
+        trip.put(1, 0, -1.0).unwrap();
+        trip.put(1, 2, 3.0).unwrap();
+        trip.put(2, 0, -2.0).unwrap();
+        trip.put(2, 1, 1.0).unwrap();
+        let a = trip.as_matrix();
+        for kind in [Norm::One, Norm::Inf, Norm::Euc, Norm::Fro, Norm::Max] {
+            approx_eq(trip.norm(kind).unwrap(), mat_norm(&a, kind), 1e-15);
+        }
+        approx_eq(trip.norm(Norm::P(1.0)).unwrap(), 16.0, 1e-15);
+        approx_eq(trip.norm(Norm::P(2.0)).unwrap(), f64::sqrt(56.0), 1e-15);
+
+        // symmetric (lower triangular)
+        let mut trip = SparseTriplet::new(3, 4, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 0, -2.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        trip.put(2, 1, 4.0).unwrap();
+        let a = trip.as_matrix();
+        for kind in [Norm::One, Norm::Inf, Norm::Euc, Norm::Fro, Norm::Max] {
+            approx_eq(trip.norm(kind).unwrap(), mat_norm(&a, kind), 1e-15);
+        }
+    }
+
+    #[test]
+    fn entries_for_solver_works() {
+        let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 1, 2.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        assert_eq!(trip.entries_for_solver(true), None);
+        assert_eq!(trip.entries_for_solver(false), None);
+
+        let mut trip = SparseTriplet::new(2, 4, TripletSymmetry::GeneralFull).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 1, 2.0).unwrap();
+        trip.put(1, 0, 2.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        assert_eq!(trip.entries_for_solver(false), None);
+        let (ii, jj, aa) = trip.entries_for_solver(true).unwrap();
+        assert_eq!(ii, &[0, 1, 1]);
+        assert_eq!(jj, &[0, 0, 1]);
+        assert_eq!(aa, &[1.0, 2.0, 3.0]);
+
+        let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 0, 2.0).unwrap();
+        trip.put(1, 1, 3.0).unwrap();
+        assert_eq!(trip.entries_for_solver(true), None);
+        let (ii, jj, aa) = trip.entries_for_solver(false).unwrap();
+        assert_eq!(ii, &[0, 1, 0, 1]);
+        assert_eq!(jj, &[0, 0, 1, 1]);
+        assert_eq!(aa, &[1.0, 2.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn jacobi_scaling_works() {
+        let mut trip = SparseTriplet::new(3, 5, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(0, 0, 2.0).unwrap();
+        trip.put(0, 1, 5.0).unwrap();
+        trip.put(1, 1, -0.25).unwrap();
+        trip.put(2, 1, 7.0).unwrap();
+        assert_eq!(trip.jacobi_scaling(), &[0.5, 2.0, 1.0]);
+    }
+
     #[test]
     fn mat_vec_mul_fails_on_wrong_input() {
-        let trip = SparseTriplet::new(2, 1).unwrap();
+        let trip = SparseTriplet::new(2, 1, TripletSymmetry::None).unwrap();
         let u = Vector::new(3);
-        assert_eq!(trip.mat_vec_mul(&u, false).err(), Some("u.ndim must equal neq"));
+        assert_eq!(trip.mat_vec_mul(&u).err(), Some("u.ndim must equal neq"));
     }
 
     #[test]
@@ -529,7 +1096,7 @@ mod tests {
         //  1.0  2.0  3.0
         //  0.1  0.2  0.3
         // 10.0 20.0 30.0
-        let mut trip = SparseTriplet::new(3, 9).unwrap();
+        let mut trip = SparseTriplet::new(3, 9, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(0, 1, 2.0).unwrap();
         trip.put(0, 2, 3.0).unwrap();
@@ -541,7 +1108,7 @@ mod tests {
         trip.put(2, 2, 30.0).unwrap();
         let u = Vector::from(&[0.1, 0.2, 0.3]);
         let correct_v = &[1.4, 0.14, 14.0];
-        let v = trip.mat_vec_mul(&u, false).unwrap();
+        let v = trip.mat_vec_mul(&u).unwrap();
         vec_approx_eq(v.as_data(), correct_v, 1e-15);
     }
 
@@ -553,7 +1120,7 @@ mod tests {
         // 3  1  1  7
         // 2  1  5  1  8
         let (neq, nnz) = (5, 15);
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::LowerTriangular).unwrap();
         trip.put(0, 0, 2.0).unwrap();
         trip.put(1, 1, 2.0).unwrap();
         trip.put(2, 2, 9.0).unwrap();
@@ -575,7 +1142,7 @@ mod tests {
         trip.put(4, 3, 1.0).unwrap();
         let u = Vector::from(&[-629.0 / 98.0, 237.0 / 49.0, -53.0 / 49.0, 62.0 / 49.0, 23.0 / 14.0]);
         let correct_v = &[-2.0, 4.0, 3.0, -5.0, 1.0];
-        let v = trip.mat_vec_mul(&u, true).unwrap();
+        let v = trip.mat_vec_mul(&u).unwrap();
         vec_approx_eq(v.as_data(), correct_v, 1e-14);
     }
 
@@ -587,7 +1154,7 @@ mod tests {
         // 3  1  1  7  1
         // 2  1  5  1  8
         let (neq, nnz) = (5, 25);
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 2.0).unwrap();
         trip.put(1, 1, 2.0).unwrap();
         trip.put(2, 2, 9.0).unwrap();
@@ -619,7 +1186,7 @@ mod tests {
         trip.put(3, 4, 1.0).unwrap();
         let u = Vector::from(&[-629.0 / 98.0, 237.0 / 49.0, -53.0 / 49.0, 62.0 / 49.0, 23.0 / 14.0]);
         let correct_v = &[-2.0, 4.0, 3.0, -5.0, 1.0];
-        let v = trip.mat_vec_mul(&u, false).unwrap();
+        let v = trip.mat_vec_mul(&u).unwrap();
         vec_approx_eq(v.as_data(), correct_v, 1e-14);
     }
 
@@ -629,7 +1196,7 @@ mod tests {
         // -1   2  -1    =>   -1   2
         //     -1   2             -1   2
         let (neq, nnz) = (3, 5);
-        let mut trip = SparseTriplet::new(neq, nnz).unwrap();
+        let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::LowerTriangular).unwrap();
         trip.put(0, 0, 2.0).unwrap();
         trip.put(1, 1, 2.0).unwrap();
         trip.put(2, 2, 2.0).unwrap();
@@ -637,16 +1204,216 @@ mod tests {
         trip.put(2, 1, -1.0).unwrap();
         let u = Vector::from(&[5.0, 8.0, 7.0]);
         let correct_v = &[2.0, 4.0, 6.0];
-        let v = trip.mat_vec_mul(&u, true).unwrap();
+        let v = trip.mat_vec_mul(&u).unwrap();
         vec_approx_eq(v.as_data(), correct_v, 1e-15);
     }
 
     #[test]
     fn display_trait_works() {
-        let trip = SparseTriplet::new(3, 1).unwrap();
+        let trip = SparseTriplet::new(3, 1, TripletSymmetry::None).unwrap();
         let correct: &str = "\x20\x20\x20\x20\"neq\": 3,\n\
                              \x20\x20\x20\x20\"nnz_current\": 0,\n\
                              \x20\x20\x20\x20\"nnz_maximum\": 1,\n";
         assert_eq!(format!("{}", trip), correct);
     }
+
+    #[test]
+    fn display_trait_alternate_works() {
+        let mut trip = SparseTriplet::new(3, 4, TripletSymmetry::None).unwrap();
+        let correct: &str = "\x20\x20\x20\x20\"neq\": 3,\n\
+                             \x20\x20\x20\x20\"nnz_current\": 0,\n\
+                             \x20\x20\x20\x20\"nnz_maximum\": 4,\n";
+        assert_eq!(format!("{:#}", trip), correct);
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 1, -5.0).unwrap();
+        trip.put(2, 2, 3.0).unwrap();
+        let correct: &str = "\x20\x20\x20\x20\"neq\": 3,\n\
+                             \x20\x20\x20\x20\"nnz_current\": 3,\n\
+                             \x20\x20\x20\x20\"nnz_maximum\": 4,\n\
+                             min(aij) = -5, max(aij) = 3, max(|aij|) = 5\n\
+                             (0, 0) = 1\n\
+                             (1, 1) = -5\n\
+                             (2, 2) = 3\n";
+        assert_eq!(format!("{:#}", trip), correct);
+        let correct: &str = "\x20\x20\x20\x20\"neq\": 3,\n\
+                             \x20\x20\x20\x20\"nnz_current\": 3,\n\
+                             \x20\x20\x20\x20\"nnz_maximum\": 4,\n\
+                             min(aij) = -5, max(aij) = 3, max(|aij|) = 5\n\
+                             (0, 0) = 1\n\
+                             ... (2 more entries)\n";
+        assert_eq!(format!("{:#.1}", trip), correct);
+    }
+
+    #[test]
+    fn to_string_full_works() {
+        let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::None).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 0, 3.0).unwrap();
+        let correct = "┌     ┐\n\
+                       │ 2 0 │\n\
+                       │ 3 0 │\n\
+                       └     ┘";
+        assert_eq!(trip.to_string_full(), correct);
+    }
+
+    #[test]
+    fn put_block_captures_errors() {
+        let mut trip = SparseTriplet::new(2, 2, TripletSymmetry::None).unwrap();
+        let block = Matrix::from(&[[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(
+            trip.put_block(&block, &[0], &[0, 1], 0.0).err(),
+            Some("the lengths of row_map and col_map must equal the dimensions of the block")
+        );
+        assert_eq!(
+            trip.put_block(&block, &[0, 1], &[0, 1, 1], 0.0).err(),
+            Some("the lengths of row_map and col_map must equal the dimensions of the block")
+        );
+        assert_eq!(
+            trip.put_block(&block, &[0, 1], &[0, 1], -1.0).err(),
+            Some("tol must be non-negative")
+        );
+        assert_eq!(
+            trip.put_block(&block, &[0, 1], &[0, 1], f64::NAN).err(),
+            Some("tol must be non-negative")
+        );
+        assert_eq!(
+            trip.put_block(&block, &[0, 2], &[0, 1], 0.0).err(),
+            Some("sparse matrix row index is out of bounds")
+        );
+        assert_eq!(
+            trip.put_block(&block, &[0, 1], &[2, 1], 0.0).err(),
+            Some("sparse matrix column index is out of bounds")
+        );
+        assert_eq!(
+            trip.put_block(&block, &[0, 1], &[0, 1], 0.0).err(),
+            Some("current nnz (number of non-zeros) reached maximum limit")
+        );
+        assert_eq!(trip.pos, 0); // nothing has been put
+        assert_eq!(trip.put_block(&block, &[0, 1], &[0, 1], 2.5), Ok(2));
+    }
+
+    #[test]
+    fn put_block_works() {
+        // two overlapping 1D elements with a tiny (smoothed) coupling
+        let mut trip = SparseTriplet::new(3, 8, TripletSymmetry::None).unwrap();
+        let ke = Matrix::from(&[[1.0, -1.0], [-1.0, 1.0]]);
+        assert_eq!(trip.put_block(&ke, &[0, 1], &[0, 1], 0.0), Ok(4));
+        assert_eq!(trip.put_block(&ke, &[1, 2], &[1, 2], 0.0), Ok(4));
+        let correct = "┌          ┐\n\
+                       │  1 -1  0 │\n\
+                       │ -1  2 -1 │\n\
+                       │  0 -1  1 │\n\
+                       └          ┘";
+        assert_eq!(format!("{}", trip.as_matrix()), correct);
+
+        // the tiny entries are skipped
+        let mut trip = SparseTriplet::new(3, 9, TripletSymmetry::None).unwrap();
+        #[rustfmt::skip]
+        let block = Matrix::from(&[
+            [4.0,   1e-14, 0.0],
+            [1e-14, 5.0,   0.5],
+            [0.0,   0.5,   6.0],
+        ]);
+        assert_eq!(trip.put_block(&block, &[2, 1, 0], &[2, 1, 0], 1e-12), Ok(5));
+        assert_eq!(trip.nnz_current(), 5);
+        let correct = "┌             ┐\n\
+                       │ 6.0 0.5 0.0 │\n\
+                       │ 0.5 5.0 0.0 │\n\
+                       │ 0.0 0.0 4.0 │\n\
+                       └             ┘";
+        assert_eq!(format!("{:.1}", trip.as_matrix()), correct);
+
+        // lower triangular storage: the upper entries are skipped
+        let mut trip = SparseTriplet::new(3, 6, TripletSymmetry::LowerTriangular).unwrap();
+        assert_eq!(trip.put_block(&block, &[2, 1, 0], &[2, 1, 0], 1e-12), Ok(4));
+        assert_eq!(format!("{:.1}", trip.as_matrix()), correct);
+    }
+
+    #[test]
+    fn from_csr_and_csc_parts_capture_errors() {
+        let sym = TripletSymmetry::None;
+        assert_eq!(
+            SparseTriplet::from_csr_parts(&[0], &[], &[], sym).err(),
+            Some("indptr must have at least two entries")
+        );
+        assert_eq!(
+            SparseTriplet::from_csr_parts(&[0, 1], &[0], &[], sym).err(),
+            Some("indices and values must have the same length")
+        );
+        assert_eq!(
+            SparseTriplet::from_csr_parts(&[1, 1], &[0], &[1.0], sym).err(),
+            Some("indptr must start at zero and end at nnz")
+        );
+        assert_eq!(
+            SparseTriplet::from_csr_parts(&[0, 2], &[0], &[1.0], sym).err(),
+            Some("indptr must start at zero and end at nnz")
+        );
+        assert_eq!(
+            SparseTriplet::from_csr_parts(&[0, 2, 1], &[0], &[1.0], sym).err(),
+            Some("indptr must be non-decreasing")
+        );
+        assert_eq!(
+            SparseTriplet::from_csr_parts(&[0, 1, 1], &[2], &[1.0], sym).err(),
+            Some("sparse matrix column index is out of bounds")
+        );
+        assert_eq!(
+            SparseTriplet::from_csc_parts(&[0, 1, 1], &[2], &[1.0], sym).err(),
+            Some("sparse matrix row index is out of bounds")
+        );
+    }
+
+    #[test]
+    fn from_csr_and_csc_parts_work() {
+        //  1  -1   .  -3
+        // -2   5   .   .
+        //  .   .   4   6
+        // -4   .   2   7
+        let mut a = Matrix::new(4, 4);
+        let correct = &[
+            [1.0, -1.0, 0.0, -3.0],
+            [-2.0, 5.0, 0.0, 0.0],
+            [0.0, 0.0, 4.0, 6.0],
+            [-4.0, 0.0, 2.0, 7.0],
+        ];
+
+        // CSR with unsorted and repeated entries
+        let indptr = &[0, 4, 6, 8, 11];
+        let indices = &[3, 0, 1, 0, 0, 1, 2, 3, 3, 2, 0];
+        let values = &[-3.0, 0.5, -1.0, 0.5, -2.0, 5.0, 4.0, 6.0, 7.0, 2.0, -4.0];
+        let trip = SparseTriplet::from_csr_parts(indptr, indices, values, TripletSymmetry::None).unwrap();
+        assert_eq!(trip.neq(), 4);
+        assert_eq!(trip.nnz_current(), 11);
+        trip.to_matrix(&mut a).unwrap();
+        assert_eq!(a.as_data(), Matrix::from(correct).as_data());
+
+        // CSC
+        let indptr = &[0, 3, 5, 7, 10];
+        let indices = &[0, 1, 3, 0, 1, 2, 3, 0, 2, 3];
+        let values = &[1.0, -2.0, -4.0, -1.0, 5.0, 4.0, 2.0, -3.0, 6.0, 7.0];
+        let trip = SparseTriplet::from_csc_parts(indptr, indices, values, TripletSymmetry::None).unwrap();
+        trip.to_matrix(&mut a).unwrap();
+        assert_eq!(a.as_data(), Matrix::from(correct).as_data());
+
+        // empty matrix
+        let trip = SparseTriplet::from_csr_parts(&[0, 0, 0], &[], &[], TripletSymmetry::None).unwrap();
+        assert_eq!(trip.neq(), 2);
+        assert_eq!(trip.nnz_current(), 0);
+
+        // upper triangle in CSR equals the lower triangle in CSC (mirrored into the lower triangle)
+        // |  2  -1 |
+        // | -1   3 |
+        let trip = SparseTriplet::from_csr_parts(
+            &[0, 2, 3],
+            &[0, 1, 1],
+            &[2.0, -1.0, 3.0],
+            TripletSymmetry::LowerTriangular,
+        )
+        .unwrap();
+        assert_eq!(trip.indices_i, &[0, 1, 1]);
+        assert_eq!(trip.indices_j, &[0, 0, 1]);
+        let mut a = Matrix::new(2, 2);
+        trip.to_matrix(&mut a).unwrap();
+        assert_eq!(a.as_data(), &[2.0, -1.0, -1.0, 3.0]);
+    }
 }
@@ -0,0 +1,280 @@
+use crate::{SparseTriplet, StrError, TripletSymmetry};
+use russell_openblas::to_i32;
+
+/// Holds a portion of the triples (i,j,aij) of a sparse matrix assembled by one thread
+///
+/// # Remarks
+///
+/// - Each thread owns its shard; thus, no locks are needed during the assembly
+/// - Unlike [SparseTriplet], the shard grows as needed (no maximum number of entries)
+/// - Entries with repeated (i,j) indices are allowed (they are summed by the solver)
+/// - The shards are combined by [SparseTriplet::merge] in the order they are given; thus,
+///   the resulting triplet does not depend on the order in which the threads finish
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::{SparseTriplet, StrError, TripletShard, TripletSymmetry};
+/// use std::thread;
+///
+/// fn main() -> Result<(), StrError> {
+///     // the "elements" assembled by each thread
+///     let neq = 4;
+///     let chunks = vec![vec![0, 1], vec![2]];
+///
+///     // assemble the shards in parallel
+///     let shards: Vec<TripletShard> = thread::scope(|scope| {
+///         let handles: Vec<_> = chunks
+///             .iter()
+///             .map(|elements| {
+///                 scope.spawn(move || {
+///                     let mut shard = TripletShard::new(neq, TripletSymmetry::None).unwrap();
+///                     for &e in elements {
+///                         // 1D bar element connecting nodes e and e+1
+///                         shard.put(e, e, 1.0).unwrap();
+///                         shard.put(e, e + 1, -1.0).unwrap();
+///                         shard.put(e + 1, e, -1.0).unwrap();
+///                         shard.put(e + 1, e + 1, 1.0).unwrap();
+///                     }
+///                     shard
+///                 })
+///             })
+///             .collect();
+///         handles.into_iter().map(|h| h.join().unwrap()).collect()
+///     });
+///
+///     // merge into a single triplet
+///     let trip = SparseTriplet::merge(&shards)?;
+///     assert_eq!(trip.nnz_current(), 12);
+///     let correct = "┌             ┐\n\
+///                    │  1 -1  0  0 │\n\
+///                    │ -1  2 -1  0 │\n\
+///                    │  0 -1  2 -1 │\n\
+///                    │  0  0 -1  1 │\n\
+///                    └             ┘";
+///     assert_eq!(trip.to_string_full(), correct);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TripletShard {
+    neq: usize,                // number of rows = number of columns = n_equation
+    symmetry: TripletSymmetry, // symmetry and storage scheme
+    indices_i: Vec<i32>,       // indices i
+    indices_j: Vec<i32>,       // indices j
+    values_aij: Vec<f64>,      // values aij
+}
+
+impl TripletShard {
+    /// Creates a new (empty) shard
+    ///
+    /// # Input
+    ///
+    /// * `neq` -- The number of rows (= ncol) of the whole sparse matrix
+    /// * `symmetry` -- The symmetry and storage scheme (see [TripletSymmetry]);
+    ///   it must be the same for all shards
+    pub fn new(neq: usize, symmetry: TripletSymmetry) -> Result<Self, StrError> {
+        if neq == 0 {
+            return Err("neq must be greater than zero");
+        }
+        Ok(TripletShard {
+            neq,
+            symmetry,
+            indices_i: Vec::new(),
+            indices_j: Vec::new(),
+            values_aij: Vec::new(),
+        })
+    }
+
+    /// Puts the next triple (i,j,aij) into the shard
+    ///
+    /// **Note:** If the symmetry is [TripletSymmetry::LowerTriangular], an upper triangular
+    /// entry (i < j) is stored as (j,i), i.e., in the lower triangle.
+    pub fn put(&mut self, i: usize, j: usize, aij: f64) -> Result<(), StrError> {
+        if i >= self.neq {
+            return Err("sparse matrix row index is out of bounds");
+        }
+        if j >= self.neq {
+            return Err("sparse matrix column index is out of bounds");
+        }
+        let (i, j) = match self.symmetry {
+            TripletSymmetry::LowerTriangular if i < j => (j, i),
+            _ => (i, j),
+        };
+        self.indices_i.push(to_i32(i));
+        self.indices_j.push(to_i32(j));
+        self.values_aij.push(aij);
+        Ok(())
+    }
+
+    /// Returns the (nrow = ncol) dimensions of the whole matrix
+    pub fn neq(&self) -> usize {
+        self.neq
+    }
+
+    /// Returns the number of entries in this shard
+    pub fn nnz_current(&self) -> usize {
+        self.values_aij.len()
+    }
+
+    /// Returns the symmetry and storage scheme
+    pub fn symmetry(&self) -> TripletSymmetry {
+        self.symmetry
+    }
+
+    /// Removes all entries, allowing using "put" from scratch (the memory is kept)
+    pub fn reset(&mut self) {
+        self.indices_i.clear();
+        self.indices_j.clear();
+        self.values_aij.clear();
+    }
+}
+
+impl SparseTriplet {
+    /// Merges shards assembled (e.g., by several threads) into a single triplet
+    ///
+    /// The entries are copied shard-by-shard, in the given order, and keep the order
+    /// in which they were put into each shard. Thus, the result is reproducible.
+    ///
+    /// **Note:** The maximum number of entries of the resulting triplet equals the
+    /// total number of entries in the shards (or one if all shards are empty).
+    ///
+    /// See [TripletShard] for an example.
+    pub fn merge(shards: &[TripletShard]) -> Result<Self, StrError> {
+        if shards.is_empty() {
+            return Err("at least one shard is required");
+        }
+        let (neq, symmetry) = (shards[0].neq, shards[0].symmetry);
+        if shards.iter().any(|s| s.neq != neq || s.symmetry != symmetry) {
+            return Err("all shards must have the same neq and symmetry");
+        }
+        let total: usize = shards.iter().map(|s| s.nnz_current()).sum();
+        let mut trip = SparseTriplet::new(neq, usize::max(total, 1), symmetry)?;
+        for shard in shards {
+            let (start, end) = (trip.pos, trip.pos + shard.nnz_current());
+            trip.indices_i[start..end].copy_from_slice(&shard.indices_i);
+            trip.indices_j[start..end].copy_from_slice(&shard.indices_j);
+            trip.values_aij[start..end].copy_from_slice(&shard.values_aij);
+            trip.pos = end;
+        }
+        Ok(trip)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::TripletShard;
+    use crate::{SparseTriplet, TripletSymmetry};
+    use std::thread;
+
+    #[test]
+    fn new_and_put_capture_errors() {
+        assert_eq!(
+            TripletShard::new(0, TripletSymmetry::None).err(),
+            Some("neq must be greater than zero")
+        );
+        let mut shard = TripletShard::new(2, TripletSymmetry::None).unwrap();
+        assert_eq!(
+            shard.put(2, 0, 1.0).err(),
+            Some("sparse matrix row index is out of bounds")
+        );
+        assert_eq!(
+            shard.put(0, 2, 1.0).err(),
+            Some("sparse matrix column index is out of bounds")
+        );
+    }
+
+    #[test]
+    fn put_and_reset_work() {
+        let mut shard = TripletShard::new(3, TripletSymmetry::LowerTriangular).unwrap();
+        assert_eq!(shard.neq(), 3);
+        assert_eq!(shard.symmetry(), TripletSymmetry::LowerTriangular);
+        shard.put(0, 2, 1.0).unwrap();
+        shard.put(1, 1, 2.0).unwrap();
+        assert_eq!(shard.nnz_current(), 2);
+        assert_eq!(shard.indices_i, &[2, 1]);
+        assert_eq!(shard.indices_j, &[0, 1]);
+        assert_eq!(shard.values_aij, &[1.0, 2.0]);
+        shard.reset();
+        assert_eq!(shard.nnz_current(), 0);
+    }
+
+    #[test]
+    fn merge_captures_errors() {
+        assert_eq!(SparseTriplet::merge(&[]).err(), Some("at least one shard is required"));
+        let a = TripletShard::new(2, TripletSymmetry::None).unwrap();
+        let b = TripletShard::new(3, TripletSymmetry::None).unwrap();
+        let c = TripletShard::new(2, TripletSymmetry::LowerTriangular).unwrap();
+        assert_eq!(
+            SparseTriplet::merge(&[a.clone(), b]).err(),
+            Some("all shards must have the same neq and symmetry")
+        );
+        assert_eq!(
+            SparseTriplet::merge(&[a, c]).err(),
+            Some("all shards must have the same neq and symmetry")
+        );
+    }
+
+    #[test]
+    fn merge_works() {
+        let empty = TripletShard::new(2, TripletSymmetry::None).unwrap();
+        let trip = SparseTriplet::merge(std::slice::from_ref(&empty)).unwrap();
+        assert_eq!(trip.nnz_current(), 0);
+        assert_eq!(trip.nnz_maximum(), 1);
+
+        let mut a = TripletShard::new(2, TripletSymmetry::None).unwrap();
+        a.put(0, 0, 1.0).unwrap();
+        a.put(1, 0, 2.0).unwrap();
+        let mut b = TripletShard::new(2, TripletSymmetry::None).unwrap();
+        b.put(0, 0, 3.0).unwrap();
+        let trip = SparseTriplet::merge(&[a, empty, b]).unwrap();
+        assert_eq!(trip.nnz_current(), 3);
+        assert_eq!(trip.nnz_maximum(), 3);
+        assert_eq!(trip.indices_i, &[0, 1, 0]);
+        assert_eq!(trip.indices_j, &[0, 0, 0]);
+        assert_eq!(trip.values_aij, &[1.0, 2.0, 3.0]);
+        assert_eq!(
+            trip.to_string_full(),
+            "┌     ┐\n\
+             │ 4 0 │\n\
+             │ 2 0 │\n\
+             └     ┘"
+        );
+    }
+
+    #[test]
+    fn merge_is_reproducible_with_threads() {
+        let neq = 50;
+        let n_thread = 4;
+        let run = || {
+            let shards: Vec<TripletShard> = thread::scope(|scope| {
+                let handles: Vec<_> = (0..n_thread)
+                    .map(|t| {
+                        scope.spawn(move || {
+                            let mut shard = TripletShard::new(neq, TripletSymmetry::None).unwrap();
+                            for e in (t..(neq - 1)).step_by(n_thread) {
+                                shard.put(e, e, 1.0 / (e + 1) as f64).unwrap();
+                                shard.put(e, e + 1, -0.1).unwrap();
+                                shard.put(e + 1, e, -0.2).unwrap();
+                                shard.put(e + 1, e + 1, 1.0 / (e + 2) as f64).unwrap();
+                            }
+                            shard
+                        })
+                    })
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect()
+            });
+            SparseTriplet::merge(&shards).unwrap()
+        };
+        let first = run();
+        assert_eq!(first.nnz_current(), 4 * (neq - 1));
+        for _ in 0..5 {
+            let other = run();
+            assert_eq!(other.indices_i, first.indices_i);
+            assert_eq!(other.indices_j, first.indices_j);
+            assert_eq!(other.values_aij, first.values_aij);
+        }
+    }
+}
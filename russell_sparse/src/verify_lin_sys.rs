@@ -1,4 +1,4 @@
-use super::SparseTriplet;
+use super::{SparseCsr, SparseTriplet};
 use crate::StrError;
 use russell_lab::{format_nanoseconds, vec_norm, vec_update, Norm, Stopwatch, Vector};
 use russell_openblas::{idamax, to_i32};
@@ -20,16 +20,18 @@ impl VerifyLinSys {
     /// diff : = |a ⋅ x - rhs|
     /// ```
     ///
+    /// **Note:** The symmetry of the triplet is taken into account (see [SparseTriplet::mat_vec_mul]).
+    ///
     /// # Example
     ///
     /// ```
     /// use russell_lab::{Matrix, Vector};
-    /// use russell_sparse::{SparseTriplet, VerifyLinSys, StrError};
+    /// use russell_sparse::{SparseTriplet, VerifyLinSys, StrError, TripletSymmetry};
     ///
     /// fn main() -> Result<(), StrError> {
     ///     // set sparse matrix (3 x 3) with 4 non-zeros
     ///     let (neq, nnz) = (3, 4);
-    ///     let mut trip = SparseTriplet::new(neq, nnz)?;
+    ///     let mut trip = SparseTriplet::new(neq, nnz, TripletSymmetry::None)?;
     ///     trip.put(0, 0, 1.0)?;
     ///     trip.put(0, 2, 4.0)?;
     ///     trip.put(1, 1, 2.0)?;
@@ -48,7 +50,7 @@ impl VerifyLinSys {
     ///     // verify lin-sys
     ///     let x = Vector::from(&[1.0, 1.0, 1.0]);
     ///     let rhs = Vector::from(&[5.0, 2.0, 3.0]);
-    ///     let verify = VerifyLinSys::new(&trip, &x, &rhs, false)?;
+    ///     let verify = VerifyLinSys::new(&trip, &x, &rhs)?;
     ///     assert_eq!(verify.max_abs_a, 4.0);
     ///     assert_eq!(verify.max_abs_ax, 5.0);
     ///     assert_eq!(verify.max_abs_diff, 0.0);
@@ -57,7 +59,7 @@ impl VerifyLinSys {
     ///     Ok(())
     /// }
     /// ```
-    pub fn new(trip: &SparseTriplet, x: &Vector, rhs: &Vector, triangular: bool) -> Result<Self, StrError> {
+    pub fn new(trip: &SparseTriplet, x: &Vector, rhs: &Vector) -> Result<Self, StrError> {
         if x.dim() != trip.neq || rhs.dim() != trip.neq {
             return Err("vector dimensions are incompatible");
         }
@@ -70,7 +72,7 @@ impl VerifyLinSys {
         let max_abs_a = f64::abs(trip.values_aij[idx as usize]);
 
         // compute max_abs_ax
-        let mut ax = trip.mat_vec_mul(&x, triangular).unwrap(); // already checked
+        let mut ax = trip.mat_vec_mul(x).unwrap(); // already checked
         let max_abs_ax = vec_norm(&ax, Norm::Max);
 
         // compute max_abs_diff
@@ -92,6 +94,67 @@ impl VerifyLinSys {
             time_check,
         })
     }
+
+    /// Creates a new verification dataset using a CSR matrix
+    ///
+    /// ```text
+    /// diff : = |a ⋅ x - rhs|
+    /// ```
+    ///
+    /// This function is advisable for large matrices (or when verifying many solutions) because
+    /// the matrix-vector product with the [SparseCsr] format is faster than with the triplet.
+    ///
+    /// **Note:** Since the CSR matrix holds the full matrix with the repeated entries summed up,
+    /// `max_abs_a` is computed from the actual components of `a` (which may differ from the
+    /// value computed by [VerifyLinSys::new] if the triplet has repeated entries).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use russell_lab::Vector;
+    /// use russell_sparse::{SparseCsr, SparseTriplet, VerifyLinSys, StrError, TripletSymmetry};
+    ///
+    /// fn main() -> Result<(), StrError> {
+    ///     // | 2  1 |
+    ///     // | 1  3 |
+    ///     let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::LowerTriangular)?;
+    ///     trip.put(0, 0, 2.0)?;
+    ///     trip.put(1, 0, 1.0)?;
+    ///     trip.put(1, 1, 3.0)?;
+    ///     let csr = SparseCsr::from_triplet(&trip);
+    ///
+    ///     let x = Vector::from(&[1.0, 1.0]);
+    ///     let rhs = Vector::from(&[3.0, 4.0]);
+    ///     let verify = VerifyLinSys::from_csr(&csr, &x, &rhs)?;
+    ///     assert_eq!(verify.max_abs_a, 3.0);
+    ///     assert_eq!(verify.max_abs_ax, 4.0);
+    ///     assert_eq!(verify.max_abs_diff, 0.0);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_csr(csr: &SparseCsr, x: &Vector, rhs: &Vector) -> Result<Self, StrError> {
+        if x.dim() != csr.neq || rhs.dim() != csr.neq {
+            return Err("vector dimensions are incompatible");
+        }
+        let mut sw = Stopwatch::new("");
+        let max_abs_a = csr.values.iter().fold(0.0, |acc, aij| f64::max(acc, f64::abs(*aij)));
+        let ax = csr.mat_vec_mul(x).unwrap(); // already checked
+        let mut max_abs_ax: f64 = 0.0;
+        let mut max_abs_diff: f64 = 0.0;
+        for i in 0..csr.neq {
+            max_abs_ax = f64::max(max_abs_ax, f64::abs(ax[i]));
+            max_abs_diff = f64::max(max_abs_diff, f64::abs(ax[i] - rhs[i]));
+        }
+        let relative_error = max_abs_diff / (max_abs_a + 1.0);
+        let time_check = sw.stop();
+        Ok(VerifyLinSys {
+            max_abs_a,
+            max_abs_ax,
+            max_abs_diff,
+            relative_error,
+            time_check,
+        })
+    }
 }
 
 impl fmt::Display for VerifyLinSys {
@@ -120,22 +183,23 @@ impl fmt::Display for VerifyLinSys {
 
 #[cfg(test)]
 mod tests {
-    use super::{SparseTriplet, VerifyLinSys};
+    use super::{SparseCsr, SparseTriplet, VerifyLinSys};
+    use crate::TripletSymmetry;
     use russell_lab::Vector;
 
     #[test]
     fn new_fails_on_wrong_vectors() {
-        let trip = SparseTriplet::new(1, 1).unwrap();
+        let trip = SparseTriplet::new(1, 1, TripletSymmetry::None).unwrap();
         let x = Vector::new(2);
         let rhs = Vector::new(3);
         let x_wrong = Vector::new(3);
         let rhs_wrong = Vector::new(2);
         assert_eq!(
-            VerifyLinSys::new(&trip, &x_wrong, &rhs, false).err(),
+            VerifyLinSys::new(&trip, &x_wrong, &rhs).err(),
             Some("vector dimensions are incompatible")
         );
         assert_eq!(
-            VerifyLinSys::new(&trip, &x, &rhs_wrong, false).err(),
+            VerifyLinSys::new(&trip, &x, &rhs_wrong).err(),
             Some("vector dimensions are incompatible")
         );
     }
@@ -145,7 +209,7 @@ mod tests {
         // | 1  3 -2 |
         // | 3  5  6 |
         // | 2  4  3 |
-        let mut trip = SparseTriplet::new(3, 9).unwrap();
+        let mut trip = SparseTriplet::new(3, 9, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(0, 1, 3.0).unwrap();
         trip.put(0, 2, -2.0).unwrap();
@@ -157,7 +221,7 @@ mod tests {
         trip.put(2, 2, 3.0).unwrap();
         let x = Vector::from(&[-15.0, 8.0, 2.0]);
         let rhs = Vector::from(&[5.0, 7.0, 8.0]);
-        let verify = VerifyLinSys::new(&trip, &x, &rhs, false).unwrap();
+        let verify = VerifyLinSys::new(&trip, &x, &rhs).unwrap();
         assert_eq!(verify.max_abs_a, 6.0);
         assert_eq!(verify.max_abs_ax, 8.0);
         assert_eq!(verify.max_abs_diff, 0.0);
@@ -165,14 +229,49 @@ mod tests {
         assert!(verify.time_check > 0);
     }
 
+    #[test]
+    fn from_csr_fails_on_wrong_vectors() {
+        let trip = SparseTriplet::new(1, 1, TripletSymmetry::None).unwrap();
+        let csr = SparseCsr::from_triplet(&trip);
+        let x = Vector::new(1);
+        let rhs_wrong = Vector::new(2);
+        assert_eq!(
+            VerifyLinSys::from_csr(&csr, &x, &rhs_wrong).err(),
+            Some("vector dimensions are incompatible")
+        );
+    }
+
+    #[test]
+    fn from_csr_works() {
+        // | 1  3 -2 |
+        // | 3  5  6 |
+        // |-2  6  3 |
+        let mut trip = SparseTriplet::new(3, 7, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(1, 0, 3.0).unwrap();
+        trip.put(2, 0, -2.0).unwrap();
+        trip.put(1, 1, 2.5).unwrap();
+        trip.put(1, 1, 2.5).unwrap();
+        trip.put(2, 1, 6.0).unwrap();
+        trip.put(2, 2, 3.0).unwrap();
+        let csr = SparseCsr::from_triplet(&trip);
+        let x = Vector::from(&[1.0, 1.0, 1.0]);
+        let rhs = Vector::from(&[2.0, 14.0, 6.0]);
+        let verify = VerifyLinSys::from_csr(&csr, &x, &rhs).unwrap();
+        assert_eq!(verify.max_abs_a, 6.0);
+        assert_eq!(verify.max_abs_ax, 14.0);
+        assert_eq!(verify.max_abs_diff, 1.0);
+        assert_eq!(verify.relative_error, 1.0 / 7.0);
+    }
+
     #[test]
     fn display_trait_works() {
-        let mut trip = SparseTriplet::new(2, 2).unwrap();
+        let mut trip = SparseTriplet::new(2, 2, TripletSymmetry::None).unwrap();
         trip.put(0, 0, 1.0).unwrap();
         trip.put(1, 1, 1.0).unwrap();
         let x = Vector::from(&[1.0, 1.0]);
         let rhs = Vector::from(&[1.0, 1.0]);
-        let mut verify = VerifyLinSys::new(&trip, &x, &rhs, false).unwrap();
+        let mut verify = VerifyLinSys::new(&trip, &x, &rhs).unwrap();
         verify.time_check = 0;
         let correct: &str = "\x20\x20\x20\x20\"maxAbsA\": 1,\n\
                              \x20\x20\x20\x20\"maxAbsAx\": 1,\n\
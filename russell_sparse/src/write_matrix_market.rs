@@ -0,0 +1,203 @@
+use crate::{ComplexSparseTriplet, SparseTriplet, StrError, TripletSymmetry};
+use std::ffi::OsStr;
+use std::fmt::Write as FmtWrite;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+
+/// Writes a SparseTriplet to a MatrixMarket file
+///
+/// The file can be read back with [crate::read_matrix_market].
+///
+/// # Input
+///
+/// * `full_path` -- may be a String, &str, or Path
+/// * `trip` -- the triplet
+///
+/// # Remarks
+///
+/// * The `symmetric` keyword is written if the symmetry is [TripletSymmetry::LowerTriangular]
+///   (all entries are in the lower triangle); otherwise, the `general` keyword is written
+/// * Entries with repeated indices are written as they are (they are summed up when reading)
+/// * The indices are written as 1-based indices
+///
+/// # Example
+///
+/// ```
+/// use russell_sparse::{read_matrix_market, write_matrix_market, SparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut trip = SparseTriplet::new(2, 3, TripletSymmetry::None)?;
+///     trip.put(0, 0, 1.0)?;
+///     trip.put(0, 1, 2.0)?;
+///     trip.put(1, 1, 3.0)?;
+///     let full_path = "/tmp/russell_sparse/doc_write_matrix_market.mtx";
+///     write_matrix_market(full_path, &trip)?;
+///     let (read, _) = read_matrix_market(&full_path.to_string())?;
+///     assert_eq!(read.nnz_current(), 3);
+///     assert_eq!(read.as_matrix().get(0, 1), 2.0);
+///     Ok(())
+/// }
+/// ```
+pub fn write_matrix_market<P>(full_path: &P, trip: &SparseTriplet) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let mut buffer = String::new();
+    write_header(&mut buffer, "real", trip.symmetry, trip.neq, trip.pos);
+    for p in 0..trip.pos {
+        writeln!(
+            &mut buffer,
+            "{} {} {:?}",
+            trip.indices_i[p] + 1,
+            trip.indices_j[p] + 1,
+            trip.values_aij[p]
+        )
+        .unwrap();
+    }
+    write_file(full_path, &buffer)
+}
+
+/// Writes a ComplexSparseTriplet to a MatrixMarket file
+///
+/// The file can be read back with [crate::read_matrix_market_complex].
+///
+/// # Input
+///
+/// * `full_path` -- may be a String, &str, or Path
+/// * `trip` -- the triplet
+///
+/// # Remarks
+///
+/// * The `complex` field is written and each data line holds `i j re(aij) im(aij)`
+/// * See [write_matrix_market] for the remarks on symmetry and repeated entries
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use russell_sparse::{read_matrix_market_complex, write_matrix_market_complex};
+/// use russell_sparse::{ComplexSparseTriplet, StrError, TripletSymmetry};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut trip = ComplexSparseTriplet::new(2, 2, TripletSymmetry::None)?;
+///     trip.put(0, 0, Complex64::new(1.0, -1.0))?;
+///     trip.put(1, 0, Complex64::new(0.0, 2.0))?;
+///     let full_path = "/tmp/russell_sparse/doc_write_matrix_market_complex.mtx";
+///     write_matrix_market_complex(full_path, &trip)?;
+///     let (read, header) = read_matrix_market_complex(&full_path.to_string())?;
+///     assert_eq!(header.field, "complex");
+///     assert_eq!(read.get(1, 0), Complex64::new(0.0, 2.0));
+///     Ok(())
+/// }
+/// ```
+pub fn write_matrix_market_complex<P>(full_path: &P, trip: &ComplexSparseTriplet) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    let mut buffer = String::new();
+    write_header(&mut buffer, "complex", trip.symmetry, trip.neq, trip.pos);
+    for p in 0..trip.pos {
+        writeln!(
+            &mut buffer,
+            "{} {} {:?} {:?}",
+            trip.indices_i[p] + 1,
+            trip.indices_j[p] + 1,
+            trip.values_aij[p].re,
+            trip.values_aij[p].im
+        )
+        .unwrap();
+    }
+    write_file(full_path, &buffer)
+}
+
+/// Writes the header and dimensions lines to the buffer
+fn write_header(buffer: &mut String, field: &str, symmetry: TripletSymmetry, neq: usize, nnz: usize) {
+    let keyword = match symmetry {
+        TripletSymmetry::LowerTriangular => "symmetric",
+        _ => "general",
+    };
+    writeln!(buffer, "%%MatrixMarket matrix coordinate {} {}", field, keyword).unwrap();
+    writeln!(buffer, "{} {} {}", neq, neq, nnz).unwrap();
+}
+
+/// Writes the buffer to a file, creating the directory if needed
+fn write_file<P>(full_path: &P, buffer: &str) -> Result<(), StrError>
+where
+    P: AsRef<OsStr> + ?Sized,
+{
+    // create directory
+    let path = Path::new(full_path);
+    if let Some(p) = path.parent() {
+        fs::create_dir_all(p).map_err(|_| "cannot create directory")?;
+    }
+
+    // write data to file
+    let mut file = File::create(path).map_err(|_| "cannot create file")?;
+    file.write_all(buffer.as_bytes()).map_err(|_| "cannot write file")?;
+
+    // force sync
+    file.sync_all().map_err(|_| "cannot sync file")?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{write_matrix_market, write_matrix_market_complex};
+    use crate::{read_matrix_market, read_matrix_market_complex, ComplexSparseTriplet, SparseTriplet, TripletSymmetry};
+    use num_complex::Complex64;
+    use std::fs;
+
+    #[test]
+    fn write_matrix_market_works() {
+        let mut trip = SparseTriplet::new(3, 4, TripletSymmetry::LowerTriangular).unwrap();
+        trip.put(0, 0, 1.0).unwrap();
+        trip.put(0, 1, 2.5).unwrap(); // moved to (1, 0)
+        trip.put(1, 1, -3.0).unwrap();
+        trip.put(2, 2, 1e-20).unwrap();
+        let full_path = "/tmp/russell_sparse/test_write_matrix_market.mtx";
+        write_matrix_market(full_path, &trip).unwrap();
+        let contents = fs::read_to_string(full_path).unwrap();
+        assert_eq!(
+            contents,
+            "%%MatrixMarket matrix coordinate real symmetric\n\
+             3 3 4\n\
+             1 1 1.0\n\
+             2 1 2.5\n\
+             2 2 -3.0\n\
+             3 3 1e-20\n"
+        );
+        let (read, _) = read_matrix_market(&full_path.to_string()).unwrap();
+        assert_eq!(read.symmetry, TripletSymmetry::LowerTriangular);
+        assert_eq!(read.indices_i, trip.indices_i);
+        assert_eq!(read.indices_j, trip.indices_j);
+        assert_eq!(read.values_aij, trip.values_aij);
+    }
+
+    #[test]
+    fn write_matrix_market_complex_works() {
+        let mut trip = ComplexSparseTriplet::new(2, 3, TripletSymmetry::None).unwrap();
+        trip.put(0, 1, Complex64::new(1.0, -2.0)).unwrap();
+        trip.put(1, 0, Complex64::new(0.1, 0.0)).unwrap();
+        trip.put(1, 1, Complex64::new(-3.0, 4.0)).unwrap();
+        let full_path = "/tmp/russell_sparse/test_write_matrix_market_complex.mtx";
+        write_matrix_market_complex(full_path, &trip).unwrap();
+        let contents = fs::read_to_string(full_path).unwrap();
+        assert_eq!(
+            contents,
+            "%%MatrixMarket matrix coordinate complex general\n\
+             2 2 3\n\
+             1 2 1.0 -2.0\n\
+             2 1 0.1 0.0\n\
+             2 2 -3.0 4.0\n"
+        );
+        let (read, header) = read_matrix_market_complex(&full_path.to_string()).unwrap();
+        assert_eq!(header.field, "complex");
+        assert_eq!(read.symmetry, TripletSymmetry::None);
+        assert_eq!(read.indices_i, trip.indices_i);
+        assert_eq!(read.indices_j, trip.indices_j);
+        assert_eq!(read.values_aij, trip.values_aij);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{DistributionFisherF, ProbabilityDistribution, StrError};
+
+/// Holds the results of the one-way analysis of variance (ANOVA table)
+#[derive(Clone, Copy, Debug)]
+pub struct AnovaOneWay {
+    /// Sum of squares between the groups (treatment)
+    pub ss_between: f64,
+
+    /// Sum of squares within the groups (error)
+    pub ss_within: f64,
+
+    /// Degrees of freedom between the groups (k - 1)
+    pub dof_between: usize,
+
+    /// Degrees of freedom within the groups (N - k)
+    pub dof_within: usize,
+
+    /// The F statistic (ratio of the mean squares)
+    pub f_statistic: f64,
+
+    /// The p-value; i.e., the probability of a larger F if all groups have the same mean
+    pub p_value: f64,
+}
+
+/// Performs the one-way analysis of variance (ANOVA)
+///
+/// Tests whether the means of `k` groups are equal (assuming normal populations with equal
+/// variances), for instance, to screen the effect of the levels of one factor in a parameter study:
+///
+/// ```text
+///      SSB / (k - 1)
+/// F = ———————————————     SSB = Σ nᵢ (x̄ᵢ - x̄)²     SSW = Σ Σ (xᵢⱼ - x̄ᵢ)²
+///      SSW / (N - k)                i                   i j
+/// ```
+///
+/// The p-value is computed from the F distribution with `(k - 1, N - k)` degrees of freedom
+/// (see [DistributionFisherF]).
+///
+/// # Input
+///
+/// * `groups` -- the samples of each group (at least two groups and N > k)
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{anova_oneway, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let a = [6.0, 8.0, 4.0, 5.0, 3.0, 4.0];
+///     let b = [8.0, 12.0, 9.0, 11.0, 6.0, 8.0];
+///     let c = [13.0, 9.0, 11.0, 8.0, 7.0, 12.0];
+///     let res = anova_oneway(&[&a, &b, &c])?;
+///     assert_eq!(res.ss_between, 84.0);
+///     assert_eq!(res.ss_within, 68.0);
+///     assert_eq!(format!("{:.4}", res.f_statistic), "9.2647");
+///     assert_eq!(format!("{:.4}", res.p_value), "0.0024");
+///     Ok(())
+/// }
+/// ```
+pub fn anova_oneway(groups: &[&[f64]]) -> Result<AnovaOneWay, StrError> {
+    let k = groups.len();
+    if k < 2 {
+        return Err("at least two groups are required");
+    }
+    if groups.iter().any(|g| g.is_empty()) {
+        return Err("groups must not be empty");
+    }
+    let n: usize = groups.iter().map(|g| g.len()).sum();
+    if n <= k {
+        return Err("the total number of values must be greater than the number of groups");
+    }
+    let grand_mean = groups.iter().flat_map(|g| g.iter()).sum::<f64>() / (n as f64);
+    let mut ss_between = 0.0;
+    let mut ss_within = 0.0;
+    for group in groups {
+        let mean = group.iter().sum::<f64>() / (group.len() as f64);
+        ss_between += (group.len() as f64) * (mean - grand_mean) * (mean - grand_mean);
+        ss_within += group.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>();
+    }
+    let (dof_between, dof_within) = (k - 1, n - k);
+    if ss_within == 0.0 {
+        return Err("the variance within the groups is zero");
+    }
+    let f_statistic = (ss_between / (dof_between as f64)) / (ss_within / (dof_within as f64));
+    let dist = DistributionFisherF::new(dof_between as f64, dof_within as f64)?;
+    Ok(AnovaOneWay {
+        ss_between,
+        ss_within,
+        dof_between,
+        dof_within,
+        f_statistic,
+        p_value: 1.0 - dist.cdf(f_statistic),
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::anova_oneway;
+    use crate::welch_t_test;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn anova_oneway_captures_errors() {
+        assert_eq!(
+            anova_oneway(&[&[1.0, 2.0]]).err(),
+            Some("at least two groups are required")
+        );
+        assert_eq!(
+            anova_oneway(&[&[1.0, 2.0], &[]]).err(),
+            Some("groups must not be empty")
+        );
+        assert_eq!(
+            anova_oneway(&[&[1.0], &[2.0]]).err(),
+            Some("the total number of values must be greater than the number of groups")
+        );
+        assert_eq!(
+            anova_oneway(&[&[1.0, 1.0], &[2.0, 2.0]]).err(),
+            Some("the variance within the groups is zero")
+        );
+    }
+
+    #[test]
+    fn anova_oneway_works() {
+        let a = [6.0, 8.0, 4.0, 5.0, 3.0, 4.0];
+        let b = [8.0, 12.0, 9.0, 11.0, 6.0, 8.0];
+        let c = [13.0, 9.0, 11.0, 8.0, 7.0, 12.0];
+        let res = anova_oneway(&[&a, &b, &c]).unwrap();
+        assert_eq!(res.dof_between, 2);
+        assert_eq!(res.dof_within, 15);
+        approx_eq(res.ss_between, 84.0, 1e-13);
+        approx_eq(res.ss_within, 68.0, 1e-13);
+        approx_eq(res.f_statistic, (84.0 / 2.0) / (68.0 / 15.0), 1e-14);
+        // F(2, d₂): p = (1 + 2F/d₂)^(-d₂/2)
+        approx_eq(res.p_value, f64::powf(1.0 + 2.0 * res.f_statistic / 15.0, -7.5), 1e-14);
+
+        // equal means
+        let res = anova_oneway(&[&[1.0, 2.0, 3.0], &[3.0, 1.0, 2.0]]).unwrap();
+        assert_eq!(res.f_statistic, 0.0);
+        assert_eq!(res.p_value, 1.0);
+    }
+
+    #[test]
+    fn anova_oneway_two_groups_matches_t_test() {
+        // with two groups of equal size, F = t² and the p-values of ANOVA and t-test coincide
+        let a = [5.1, 4.8, 6.0, 5.5, 5.9, 4.7];
+        let b = [6.2, 6.8, 5.9, 7.1, 6.5, 6.0];
+        let res = anova_oneway(&[&a, &b]).unwrap();
+        let t = welch_t_test(&a, &b).unwrap();
+        approx_eq(res.f_statistic, t.statistic * t.statistic, 1e-13);
+        // the Welch's ν differs from N - 2 if the variances differ; thus, compare loosely
+        approx_eq(res.p_value, t.p_value, 1e-3);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::StrError;
+use russell_lab::Matrix;
+
+/// Computes the sample covariance matrix
+///
+/// ```text
+///        1     n
+/// Sᵢⱼ = ———    Σ  (xₖᵢ - x̄ᵢ) (xₖⱼ - x̄ⱼ)
+///       n-1   k=1
+/// ```
+///
+/// # Input
+///
+/// * `samples` -- (nsample,ndim) matrix with one observation per row (nsample ≥ 2)
+///
+/// # Output
+///
+/// Returns the (ndim,ndim) symmetric covariance matrix
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::Matrix;
+/// use russell_stat::{covariance_matrix, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let samples = Matrix::from(&[
+///         [1.0, 2.0],
+///         [2.0, 4.5],
+///         [3.0, 5.5],
+///         [4.0, 8.0],
+///     ]);
+///     let cov = covariance_matrix(&samples)?;
+///     assert_eq!(
+///         format!("{:.4}", cov),
+///         "┌               ┐\n\
+///          │ 1.6667 3.1667 │\n\
+///          │ 3.1667 6.1667 │\n\
+///          └               ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub fn covariance_matrix(samples: &Matrix) -> Result<Matrix, StrError> {
+    let (n, p) = samples.dims();
+    if n < 2 {
+        return Err("at least two samples are required");
+    }
+    if p < 1 {
+        return Err("samples must have at least one column");
+    }
+    let mut cov = scatter(&centered(samples));
+    for i in 0..p {
+        for j in 0..p {
+            cov.set(i, j, cov.get(i, j) / ((n - 1) as f64));
+        }
+    }
+    Ok(cov)
+}
+
+/// Computes the covariance matrix with the Ledoit-Wolf shrinkage
+///
+/// The sample covariance matrix is a poor (and possibly singular) estimate when the number of
+/// samples is not much larger than the number of dimensions. The Ledoit-Wolf estimator shrinks
+/// the (maximum likelihood) sample covariance `S` towards a scaled identity matrix:
+///
+/// ```text
+/// Σ* = δ μ I + (1 - δ) S     with  μ = tr(S) / p
+/// ```
+///
+/// where the shrinkage intensity `δ ∈ [0, 1]` minimizes the expected quadratic loss
+/// (Ledoit and Wolf, 2004). The resulting matrix is always positive definite (if `δ > 0`),
+/// and can thus be factorized by the Cholesky decomposition.
+///
+/// # Input
+///
+/// * `samples` -- (nsample,ndim) matrix with one observation per row (nsample ≥ 2)
+///
+/// # Output
+///
+/// Returns the (ndim,ndim) shrunk covariance matrix and the shrinkage intensity `δ`
+///
+/// # Reference
+///
+/// * Ledoit O and Wolf M (2004) A well-conditioned estimator for large-dimensional covariance
+///   matrices, Journal of Multivariate Analysis, 88(2):365-411
+pub fn covariance_matrix_ledoit_wolf(samples: &Matrix) -> Result<(Matrix, f64), StrError> {
+    let (n, p) = samples.dims();
+    if n < 2 {
+        return Err("at least two samples are required");
+    }
+    if p < 1 {
+        return Err("samples must have at least one column");
+    }
+    let x = centered(samples);
+    let mut s = scatter(&x);
+    for i in 0..p {
+        for j in 0..p {
+            s.set(i, j, s.get(i, j) / (n as f64));
+        }
+    }
+
+    // squared (normalized) Frobenius distance to the target: d² = ‖S - μ I‖² / p
+    let mu = (0..p).map(|i| s.get(i, i)).sum::<f64>() / (p as f64);
+    let mut d2 = 0.0;
+    for i in 0..p {
+        for j in 0..p {
+            let target = if i == j { mu } else { 0.0 };
+            d2 += (s.get(i, j) - target) * (s.get(i, j) - target);
+        }
+    }
+    d2 /= p as f64;
+
+    // estimated variance of the entries of S: b² = (1/n²) Σₖ ‖xₖ xₖᵀ - S‖² / p
+    let mut b2 = 0.0;
+    for k in 0..n {
+        for i in 0..p {
+            for j in 0..p {
+                let diff = x.get(k, i) * x.get(k, j) - s.get(i, j);
+                b2 += diff * diff;
+            }
+        }
+    }
+    b2 /= (n * n * p) as f64;
+
+    let shrinkage = if d2 > 0.0 { f64::min(b2, d2) / d2 } else { 0.0 };
+    for i in 0..p {
+        for j in 0..p {
+            let target = if i == j { mu } else { 0.0 };
+            s.set(i, j, shrinkage * target + (1.0 - shrinkage) * s.get(i, j));
+        }
+    }
+    Ok((s, shrinkage))
+}
+
+/// Returns the samples minus the mean of each column
+fn centered(samples: &Matrix) -> Matrix {
+    let (n, p) = samples.dims();
+    let mut x = samples.clone();
+    for j in 0..p {
+        let mean = (0..n).map(|k| samples.get(k, j)).sum::<f64>() / (n as f64);
+        for k in 0..n {
+            x.set(k, j, samples.get(k, j) - mean);
+        }
+    }
+    x
+}
+
+/// Returns the scatter matrix XᵀX
+fn scatter(x: &Matrix) -> Matrix {
+    let (n, p) = x.dims();
+    let mut s = Matrix::new(p, p);
+    for i in 0..p {
+        for j in i..p {
+            let value = (0..n).map(|k| x.get(k, i) * x.get(k, j)).sum::<f64>();
+            s.set(i, j, value);
+            s.set(j, i, value);
+        }
+    }
+    s
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{covariance_matrix, covariance_matrix_ledoit_wolf};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rand_distr::{Distribution, StandardNormal};
+    use russell_chk::{approx_eq, vec_approx_eq};
+    use russell_lab::Matrix;
+
+    #[test]
+    fn functions_capture_errors() {
+        assert_eq!(
+            covariance_matrix(&Matrix::new(1, 2)).err(),
+            Some("at least two samples are required")
+        );
+        assert_eq!(
+            covariance_matrix(&Matrix::new(2, 0)).err(),
+            Some("samples must have at least one column")
+        );
+        assert_eq!(
+            covariance_matrix_ledoit_wolf(&Matrix::new(1, 2)).err(),
+            Some("at least two samples are required")
+        );
+        assert_eq!(
+            covariance_matrix_ledoit_wolf(&Matrix::new(2, 0)).err(),
+            Some("samples must have at least one column")
+        );
+    }
+
+    #[test]
+    fn covariance_matrix_works() {
+        let samples = Matrix::from(&[[1.0, 2.0, -1.0], [2.0, 4.5, 0.0], [3.0, 5.5, 1.0], [4.0, 8.0, 0.0]]);
+        let cov = covariance_matrix(&samples).unwrap();
+        #[rustfmt::skip]
+        let correct = [
+            5.0 / 3.0, 9.5 / 3.0,  2.0 / 3.0,   // column 0
+            9.5 / 3.0, 18.5 / 3.0, 3.5 / 3.0,   // column 1
+            2.0 / 3.0, 3.5 / 3.0,  2.0 / 3.0,   // column 2
+        ];
+        vec_approx_eq(cov.as_data(), &correct, 1e-14);
+    }
+
+    #[test]
+    fn covariance_matrix_ledoit_wolf_works() {
+        // reference values computed independently (same data as above)
+        let samples = Matrix::from(&[[1.0, 2.0, -1.0], [2.0, 4.5, 0.0], [3.0, 5.5, 1.0], [4.0, 8.0, 0.0]]);
+        let (cov, shrinkage) = covariance_matrix_ledoit_wolf(&samples).unwrap();
+        approx_eq(shrinkage, 0.362074829932, 1e-11);
+        let s = covariance_matrix(&samples).unwrap();
+        let mu = (s.get(0, 0) + s.get(1, 1) + s.get(2, 2)) * 0.75 / 3.0;
+        for i in 0..3 {
+            for j in 0..3 {
+                let target = if i == j { mu } else { 0.0 };
+                approx_eq(
+                    cov.get(i, j),
+                    shrinkage * target + (1.0 - shrinkage) * 0.75 * s.get(i, j),
+                    1e-14,
+                );
+            }
+        }
+
+        // one dimension: nothing to shrink
+        let (cov, shrinkage) = covariance_matrix_ledoit_wolf(&Matrix::from(&[[1.0], [3.0]])).unwrap();
+        assert_eq!(shrinkage, 0.0);
+        assert_eq!(cov.get(0, 0), 1.0);
+    }
+
+    #[test]
+    fn ledoit_wolf_improves_the_estimate_with_few_samples() {
+        // 10 samples of a 20-dimensional standard normal variable: S is singular
+        let (n, p) = (10, 20);
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut samples = Matrix::new(n, p);
+        for k in 0..n {
+            for j in 0..p {
+                samples.set(k, j, StandardNormal.sample(&mut rng));
+            }
+        }
+        let s = covariance_matrix(&samples).unwrap();
+        let (cov, shrinkage) = covariance_matrix_ledoit_wolf(&samples).unwrap();
+        assert!(shrinkage > 0.5 && shrinkage <= 1.0);
+        // distance to the true covariance (identity)
+        let error = |a: &Matrix| {
+            let mut sum = 0.0;
+            for i in 0..p {
+                for j in 0..p {
+                    let target = if i == j { 1.0 } else { 0.0 };
+                    sum += (a.get(i, j) - target) * (a.get(i, j) - target);
+                }
+            }
+            sum
+        };
+        assert!(error(&cov) < 0.2 * error(&s));
+    }
+}
@@ -0,0 +1,302 @@
+use crate::StrError;
+use russell_lab::Matrix;
+
+/// Specifies the distance of the axial (star) points of a central composite design
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CcdAlpha {
+    /// Places the axial points at the centers of the faces of the cube (`α = 1`; three levels only)
+    FaceCentered,
+
+    /// Makes the variance of the prediction depend only on the distance to the center (`α = (2ᵏ)^¼`)
+    Rotatable,
+
+    /// Places the axial points at `±α` (α > 0)
+    Custom(f64),
+}
+
+/// Generates a full factorial design with any number of levels per factor
+///
+/// The levels of each factor are evenly spaced in the normalized interval `[-1, 1]`
+/// (e.g., `-1, 1` for two levels and `-1, 0, 1` for three levels). The runs are given in the
+/// standard (Yates) order; i.e., the first factor varies the fastest.
+///
+/// # Input
+///
+/// * `levels` -- (nfactor) the number of levels of each factor (≥ 2)
+///
+/// # Output
+///
+/// Returns the (nrun,nfactor) design matrix with `nrun = Π levels[j]`
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{doe_full_factorial, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = doe_full_factorial(&[2, 3])?;
+///     assert_eq!(
+///         format!("{}", x),
+///         "┌       ┐\n\
+///          │ -1 -1 │\n\
+///          │  1 -1 │\n\
+///          │ -1  0 │\n\
+///          │  1  0 │\n\
+///          │ -1  1 │\n\
+///          │  1  1 │\n\
+///          └       ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub fn doe_full_factorial(levels: &[usize]) -> Result<Matrix, StrError> {
+    if levels.is_empty() {
+        return Err("at least one factor is required");
+    }
+    if levels.iter().any(|&l| l < 2) {
+        return Err("the number of levels of each factor must be at least 2");
+    }
+    let nrun: usize = levels.iter().product();
+    let mut x = Matrix::new(nrun, levels.len());
+    let mut period = 1;
+    for (j, &l) in levels.iter().enumerate() {
+        let step = 2.0 / ((l - 1) as f64);
+        for i in 0..nrun {
+            let level = (i / period) % l;
+            x.set(i, j, -1.0 + (level as f64) * step);
+        }
+        period *= l;
+    }
+    Ok(x)
+}
+
+/// Generates a two-level fractional factorial design
+///
+/// The first `nbase` columns form the full two-level factorial design (standard order) and each
+/// additional column is the product of the base columns listed in its generator. For instance,
+/// the 2⁴⁻¹ design with `D = ABC` is given by `nbase = 3` and `generators = &[&[0, 1, 2]]`.
+///
+/// # Input
+///
+/// * `nbase` -- the number of base factors (≥ 1)
+/// * `generators` -- the indices (< nbase) of the base factors defining each additional factor
+///
+/// # Output
+///
+/// Returns the (2ⁿᵇᵃˢᵉ, nbase + generators.len()) design matrix with entries `±1`
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{doe_fractional_factorial, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // 2³⁻¹ design with C = AB
+///     let x = doe_fractional_factorial(2, &[&[0, 1]])?;
+///     assert_eq!(
+///         format!("{}", x),
+///         "┌          ┐\n\
+///          │ -1 -1  1 │\n\
+///          │  1 -1 -1 │\n\
+///          │ -1  1 -1 │\n\
+///          │  1  1  1 │\n\
+///          └          ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub fn doe_fractional_factorial(nbase: usize, generators: &[&[usize]]) -> Result<Matrix, StrError> {
+    if nbase < 1 {
+        return Err("nbase must be at least 1");
+    }
+    for generator in generators {
+        if generator.is_empty() {
+            return Err("generators must not be empty");
+        }
+        if generator.iter().any(|&k| k >= nbase) {
+            return Err("generators must refer to base factors (index < nbase)");
+        }
+    }
+    let base = doe_full_factorial(&vec![2; nbase])?;
+    let nrun = base.nrow();
+    let mut x = Matrix::new(nrun, nbase + generators.len());
+    for i in 0..nrun {
+        for j in 0..nbase {
+            x.set(i, j, base.get(i, j));
+        }
+        for (g, generator) in generators.iter().enumerate() {
+            let value = generator.iter().fold(1.0, |acc, &k| acc * base.get(i, k));
+            x.set(i, nbase + g, value);
+        }
+    }
+    Ok(x)
+}
+
+/// Generates a central composite design (CCD) for fitting second-order response surfaces
+///
+/// The design consists of the two-level full factorial points (`±1`), followed by the axial points
+/// `±α` along each axis, and by the center points:
+///
+/// ```text
+/// nrun = 2ᵏ + 2 k + ncenter
+/// ```
+///
+/// # Input
+///
+/// * `nfactor` -- the number of factors `k` (≥ 2)
+/// * `alpha` -- the distance of the axial points
+/// * `ncenter` -- the number of (repeated) center points
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{doe_central_composite, CcdAlpha, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = doe_central_composite(2, CcdAlpha::FaceCentered, 1)?;
+///     assert_eq!(
+///         format!("{}", x),
+///         "┌       ┐\n\
+///          │ -1 -1 │\n\
+///          │  1 -1 │\n\
+///          │ -1  1 │\n\
+///          │  1  1 │\n\
+///          │ -1  0 │\n\
+///          │  1  0 │\n\
+///          │  0 -1 │\n\
+///          │  0  1 │\n\
+///          │  0  0 │\n\
+///          └       ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub fn doe_central_composite(nfactor: usize, alpha: CcdAlpha, ncenter: usize) -> Result<Matrix, StrError> {
+    if nfactor < 2 {
+        return Err("nfactor must be at least 2");
+    }
+    let nfactorial = 1 << nfactor;
+    let a = match alpha {
+        CcdAlpha::FaceCentered => 1.0,
+        CcdAlpha::Rotatable => f64::powf(nfactorial as f64, 0.25),
+        CcdAlpha::Custom(value) => {
+            if value <= 0.0 {
+                return Err("alpha must be greater than zero");
+            }
+            value
+        }
+    };
+    let factorial = doe_full_factorial(&vec![2; nfactor])?;
+    let mut x = Matrix::new(nfactorial + 2 * nfactor + ncenter, nfactor);
+    for i in 0..nfactorial {
+        for j in 0..nfactor {
+            x.set(i, j, factorial.get(i, j));
+        }
+    }
+    for j in 0..nfactor {
+        x.set(nfactorial + 2 * j, j, -a);
+        x.set(nfactorial + 2 * j + 1, j, a);
+    }
+    Ok(x) // the center points remain zero
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{doe_central_composite, doe_fractional_factorial, doe_full_factorial, CcdAlpha};
+
+    #[test]
+    fn doe_functions_capture_errors() {
+        assert_eq!(doe_full_factorial(&[]).err(), Some("at least one factor is required"));
+        assert_eq!(
+            doe_full_factorial(&[2, 1]).err(),
+            Some("the number of levels of each factor must be at least 2")
+        );
+        assert_eq!(doe_fractional_factorial(0, &[]).err(), Some("nbase must be at least 1"));
+        assert_eq!(
+            doe_fractional_factorial(2, &[&[]]).err(),
+            Some("generators must not be empty")
+        );
+        assert_eq!(
+            doe_fractional_factorial(2, &[&[0, 2]]).err(),
+            Some("generators must refer to base factors (index < nbase)")
+        );
+        assert_eq!(
+            doe_central_composite(1, CcdAlpha::Rotatable, 0).err(),
+            Some("nfactor must be at least 2")
+        );
+        assert_eq!(
+            doe_central_composite(2, CcdAlpha::Custom(0.0), 0).err(),
+            Some("alpha must be greater than zero")
+        );
+    }
+
+    #[test]
+    fn doe_full_factorial_works() {
+        let x = doe_full_factorial(&[3]).unwrap();
+        assert_eq!(x.as_data(), &[-1.0, 0.0, 1.0]);
+
+        let x = doe_full_factorial(&[2, 2, 2]).unwrap();
+        assert_eq!(x.dims(), (8, 3));
+        // columns are orthogonal and balanced
+        for j in 0..3 {
+            let sum: f64 = (0..8).map(|i| x.get(i, j)).sum();
+            assert_eq!(sum, 0.0);
+            for k in (j + 1)..3 {
+                let dot: f64 = (0..8).map(|i| x.get(i, j) * x.get(i, k)).sum();
+                assert_eq!(dot, 0.0);
+            }
+        }
+
+        let x = doe_full_factorial(&[5, 2]).unwrap();
+        let col: Vec<f64> = (0..5).map(|i| x.get(i, 0)).collect();
+        assert_eq!(col, &[-1.0, -0.5, 0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn doe_fractional_factorial_works() {
+        // 2⁵⁻² design with D = AB and E = AC
+        let x = doe_fractional_factorial(3, &[&[0, 1], &[0, 2]]).unwrap();
+        assert_eq!(x.dims(), (8, 5));
+        for i in 0..8 {
+            assert_eq!(x.get(i, 3), x.get(i, 0) * x.get(i, 1));
+            assert_eq!(x.get(i, 4), x.get(i, 0) * x.get(i, 2));
+        }
+        // resolution III: the main effects are mutually orthogonal
+        for j in 0..5 {
+            for k in (j + 1)..5 {
+                let dot: f64 = (0..8).map(|i| x.get(i, j) * x.get(i, k)).sum();
+                assert_eq!(dot, 0.0);
+            }
+        }
+
+        // without generators, it is the full factorial design
+        assert_eq!(
+            doe_fractional_factorial(3, &[]).unwrap().as_data(),
+            doe_full_factorial(&[2, 2, 2]).unwrap().as_data()
+        );
+    }
+
+    #[test]
+    fn doe_central_composite_works() {
+        let x = doe_central_composite(3, CcdAlpha::Rotatable, 2).unwrap();
+        assert_eq!(x.dims(), (8 + 6 + 2, 3));
+        let alpha = f64::powf(8.0, 0.25);
+        for j in 0..3 {
+            for i in 0..8 {
+                assert_eq!(f64::abs(x.get(i, j)), 1.0);
+            }
+            for a in 0..3 {
+                let expected = if a == j { alpha } else { 0.0 };
+                assert_eq!(x.get(8 + 2 * a, j), -expected);
+                assert_eq!(x.get(8 + 2 * a + 1, j), expected);
+            }
+            assert_eq!(x.get(14, j), 0.0);
+            assert_eq!(x.get(15, j), 0.0);
+        }
+        let x = doe_central_composite(2, CcdAlpha::Custom(1.5), 0).unwrap();
+        assert_eq!(x.get(4, 0), -1.5);
+        assert_eq!(x.get(7, 1), 1.5);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{ProbabilityDistribution, StrError};
+use rand::Rng;
+use rand_distr::{Distribution, FisherF};
+use russell_lab::math::{beta_inc, ln_gamma};
+
+/// Defines the Fisher-Snedecor's F distribution (ratio of scaled chi-squared variables)
+///
+/// ```text
+///      U₁ / d₁
+/// X = —————————     with  U₁ ~ χ²(d₁)  and  U₂ ~ χ²(d₂)
+///      U₂ / d₂
+///
+/// F(x) = I_w(d₁/2, d₂/2)     with  w = d₁ x / (d₁ x + d₂)
+/// ```
+///
+/// where `I` is the regularized incomplete beta function. This distribution gives the p-values
+/// of the analysis of variance (ANOVA).
+pub struct DistributionFisherF {
+    d1: f64, // degrees of freedom of the numerator
+    d2: f64, // degrees of freedom of the denominator
+
+    sampler: FisherF<f64>, // sampler
+}
+
+impl DistributionFisherF {
+    /// Creates a new F distribution
+    ///
+    /// # Input
+    ///
+    /// * `d1` -- degrees of freedom of the numerator (> 0)
+    /// * `d2` -- degrees of freedom of the denominator (> 0)
+    pub fn new(d1: f64, d2: f64) -> Result<Self, StrError> {
+        if !d1.is_finite() || !d2.is_finite() || d1 <= 0.0 || d2 <= 0.0 {
+            return Err("invalid parameters");
+        }
+        Ok(DistributionFisherF {
+            d1,
+            d2,
+            sampler: FisherF::new(d1, d2).map_err(|_| "invalid parameters")?,
+        })
+    }
+}
+
+impl ProbabilityDistribution for DistributionFisherF {
+    /// Implements the Probability Density Function (CDF)
+    fn pdf(&self, x: f64) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+        if x < 0.0 {
+            return 0.0;
+        }
+        if x == 0.0 {
+            return if d1 < 2.0 {
+                f64::INFINITY
+            } else if d1 == 2.0 {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        let ln_beta = ln_gamma(0.5 * d1) + ln_gamma(0.5 * d2) - ln_gamma(0.5 * (d1 + d2));
+        let ln_f =
+            0.5 * (d1 * f64::ln(d1 * x) + d2 * f64::ln(d2) - (d1 + d2) * f64::ln(d1 * x + d2)) - f64::ln(x) - ln_beta;
+        f64::exp(ln_f)
+    }
+
+    /// Implements the Cumulative Density Function (CDF)
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x == f64::INFINITY {
+            return 1.0;
+        }
+        let w = self.d1 * x / (self.d1 * x + self.d2);
+        beta_inc(0.5 * self.d1, 0.5 * self.d2, w)
+    }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    ///
+    /// The quantile is computed by bisection on the argument of the incomplete beta function.
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        if p == 0.0 {
+            return 0.0;
+        }
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+        let (a, b) = (0.5 * self.d1, 0.5 * self.d2);
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..200 {
+            let mid = 0.5 * (lo + hi);
+            if mid == lo || mid == hi {
+                break;
+            }
+            if beta_inc(a, b, mid) < p {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let w = 0.5 * (lo + hi);
+        self.d2 * w / (self.d1 * (1.0 - w))
+    }
+
+    /// Returns the Mean
+    fn mean(&self) -> f64 {
+        if self.d2 > 2.0 {
+            return self.d2 / (self.d2 - 2.0);
+        }
+        f64::INFINITY
+    }
+
+    /// Returns the Variance
+    fn variance(&self) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+        if d2 > 4.0 {
+            return 2.0 * d2 * d2 * (d1 + d2 - 2.0) / (d1 * (d2 - 2.0) * (d2 - 2.0) * (d2 - 4.0));
+        }
+        f64::INFINITY
+    }
+
+    /// Generates a pseudo-random number belonging to this probability distribution
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.sampler.sample(rng)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{DistributionFisherF, ProbabilityDistribution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn fisher_f_handles_errors() {
+        assert_eq!(DistributionFisherF::new(0.0, 1.0).err(), Some("invalid parameters"));
+        assert_eq!(
+            DistributionFisherF::new(1.0, f64::INFINITY).err(),
+            Some("invalid parameters")
+        );
+    }
+
+    #[test]
+    fn fisher_f_works() {
+        // F(2, 2): f(x) = 1/(1 + x)² and F(x) = x/(1 + x)
+        let d = DistributionFisherF::new(2.0, 2.0).unwrap();
+        for x in [0.0, 0.1, 0.5, 1.0, 3.0, 20.0] {
+            approx_eq(d.pdf(x), 1.0 / ((1.0 + x) * (1.0 + x)), 1e-14);
+            approx_eq(d.cdf(x), x / (1.0 + x), 1e-14);
+        }
+        assert_eq!(d.pdf(-1.0), 0.0);
+        assert_eq!(d.cdf(-1.0), 0.0);
+        assert_eq!(d.cdf(f64::INFINITY), 1.0);
+
+        // F(2, d₂): F(x) = 1 - (1 + 2x/d₂)^(-d₂/2)
+        let d = DistributionFisherF::new(2.0, 7.0).unwrap();
+        for x in [0.2, 1.0, 4.0] {
+            approx_eq(d.cdf(x), 1.0 - f64::powf(1.0 + 2.0 * x / 7.0, -3.5), 1e-14);
+        }
+
+        // F(1, 2) is the square of the Student's t(2): F(x) = √x / √(2 + x)
+        let d = DistributionFisherF::new(1.0, 2.0).unwrap();
+        for x in [0.01, 0.5, 2.0, 9.0] {
+            approx_eq(d.cdf(x), f64::sqrt(x / (2.0 + x)), 1e-14);
+            // f(x) = dF/dx = 1 / (√x (2 + x)^(3/2))
+            approx_eq(d.pdf(x), 1.0 / (f64::sqrt(x) * f64::powf(2.0 + x, 1.5)), 1e-14);
+        }
+        assert_eq!(d.pdf(0.0), f64::INFINITY);
+        let d = DistributionFisherF::new(3.0, 2.0).unwrap();
+        assert_eq!(d.pdf(0.0), 0.0);
+    }
+
+    #[test]
+    fn mean_and_variance_work() {
+        let d = DistributionFisherF::new(3.0, 10.0).unwrap();
+        approx_eq(d.mean(), 1.25, 1e-15);
+        approx_eq(d.variance(), 2.0 * 100.0 * 11.0 / (3.0 * 64.0 * 6.0), 1e-15);
+        let d = DistributionFisherF::new(3.0, 4.0).unwrap();
+        assert_eq!(d.mean(), 2.0);
+        assert_eq!(d.variance(), f64::INFINITY);
+        let d = DistributionFisherF::new(3.0, 2.0).unwrap();
+        assert_eq!(d.mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn sample_works() {
+        let d = DistributionFisherF::new(5.0, 10.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        let n = 20_000;
+        let mean = (0..n).map(|_| d.sample(&mut rng)).sum::<f64>() / (n as f64);
+        approx_eq(mean, d.mean(), 0.03);
+    }
+
+    #[test]
+    fn inv_cdf_works() {
+        for (d1, d2) in [(1.0, 2.0), (2.0, 7.0), (5.0, 30.0)] {
+            let d = DistributionFisherF::new(d1, d2).unwrap();
+            for p in [1e-6, 0.01, 0.25, 0.5, 0.75, 0.95, 0.999] {
+                approx_eq(d.cdf(d.inv_cdf(p)), p, 1e-13);
+            }
+            assert_eq!(d.inv_cdf(0.0), 0.0);
+            assert_eq!(d.inv_cdf(1.0), f64::INFINITY);
+            assert!(d.inv_cdf(-0.1).is_nan());
+        }
+        // F(2, 2): x = p / (1 - p)
+        let d = DistributionFisherF::new(2.0, 2.0).unwrap();
+        approx_eq(d.inv_cdf(0.75), 3.0, 1e-13);
+    }
+}
@@ -50,6 +50,14 @@ impl ProbabilityDistribution for DistributionFrechet {
         f64::exp(-f64::powf(z, -self.shape))
     }
 
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        self.location + self.scale * f64::powf(-f64::ln(p), -1.0 / self.shape)
+    }
+
     /// Returns the Mean
     fn mean(&self) -> f64 {
         if self.shape > 1.0 {
@@ -261,4 +269,15 @@ mod tests {
         let mut rng = rand::thread_rng();
         d.sample(&mut rng);
     }
+
+    #[test]
+    fn inv_cdf_works() {
+        let d = DistributionFrechet::new(1.0, 2.0, 3.0).unwrap();
+        for p in [1e-10, 0.01, 0.25, 0.5, 0.75, 0.99] {
+            approx_eq(d.cdf(d.inv_cdf(p)), p, 1e-14);
+        }
+        assert_eq!(d.inv_cdf(0.0), 1.0);
+        assert_eq!(d.inv_cdf(1.0), f64::INFINITY);
+        assert!(d.inv_cdf(-0.1).is_nan());
+    }
 }
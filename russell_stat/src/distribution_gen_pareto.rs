@@ -0,0 +1,200 @@
+use crate::{ProbabilityDistribution, StrError};
+use rand::Rng;
+
+/// Defines the Generalized Pareto Distribution (excesses over a threshold)
+///
+/// ```text
+///                         -1/ξ
+/// F(x) = 1 - (1 + ξ z)          with  z = (x - location) / scale
+///
+/// F(x) = 1 - exp(-z)            if ξ = 0
+/// ```
+///
+/// The support is `z ≥ 0` if `ξ ≥ 0` and `0 ≤ z ≤ -1/ξ` if `ξ < 0`. This distribution models the
+/// values of a random variable exceeding a large threshold (peaks-over-threshold method).
+pub struct DistributionGenPareto {
+    location: f64, // location parameter (threshold)
+    scale: f64,    // scale parameter
+    shape: f64,    // shape parameter ξ
+}
+
+impl DistributionGenPareto {
+    /// Creates a new Generalized Pareto distribution
+    ///
+    /// # Input
+    ///
+    /// * `location` -- location parameter (threshold)
+    /// * `scale` -- scale parameter (> 0)
+    /// * `shape` -- shape parameter ξ (ξ > 0 gives a heavy tail; ξ < 0 gives a bounded tail)
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Self, StrError> {
+        if !location.is_finite() || !scale.is_finite() || !shape.is_finite() || scale <= 0.0 {
+            return Err("invalid parameters");
+        }
+        Ok(DistributionGenPareto { location, scale, shape })
+    }
+
+    /// Returns the scale parameter
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape parameter
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+}
+
+impl ProbabilityDistribution for DistributionGenPareto {
+    /// Implements the Probability Density Function (CDF)
+    fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z < 0.0 || (self.shape < 0.0 && z > -1.0 / self.shape) {
+            return 0.0;
+        }
+        if self.shape == 0.0 {
+            return f64::exp(-z) / self.scale;
+        }
+        f64::exp(-(1.0 / self.shape + 1.0) * f64::ln_1p(self.shape * z)) / self.scale
+    }
+
+    /// Implements the Cumulative Density Function (CDF)
+    fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z <= 0.0 {
+            return 0.0;
+        }
+        if self.shape < 0.0 && z >= -1.0 / self.shape {
+            return 1.0;
+        }
+        if self.shape == 0.0 {
+            return -f64::exp_m1(-z);
+        }
+        -f64::exp_m1(-f64::ln_1p(self.shape * z) / self.shape)
+    }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        let ln_q = f64::ln_1p(-p); // ln(1 - p)
+        if self.shape == 0.0 {
+            return self.location - self.scale * ln_q;
+        }
+        self.location + self.scale * f64::exp_m1(-self.shape * ln_q) / self.shape
+    }
+
+    /// Returns the Mean
+    fn mean(&self) -> f64 {
+        if self.shape < 1.0 {
+            return self.location + self.scale / (1.0 - self.shape);
+        }
+        f64::INFINITY
+    }
+
+    /// Returns the Variance
+    fn variance(&self) -> f64 {
+        if self.shape < 0.5 {
+            let a = 1.0 - self.shape;
+            return self.scale * self.scale / (a * a * (1.0 - 2.0 * self.shape));
+        }
+        f64::INFINITY
+    }
+
+    /// Generates a pseudo-random number belonging to this probability distribution
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.inv_cdf(rng.gen::<f64>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{DistributionGenPareto, ProbabilityDistribution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn gen_pareto_handles_errors() {
+        assert_eq!(
+            DistributionGenPareto::new(0.0, 0.0, 0.1).err(),
+            Some("invalid parameters")
+        );
+        assert_eq!(
+            DistributionGenPareto::new(0.0, 1.0, f64::NAN).err(),
+            Some("invalid parameters")
+        );
+    }
+
+    #[test]
+    fn gen_pareto_works() {
+        // ξ = 0.5: F(x) = 1 - (1 + x/2)⁻²
+        let d = DistributionGenPareto::new(0.0, 1.0, 0.5).unwrap();
+        approx_eq(d.cdf(2.0), 0.75, 1e-15);
+        approx_eq(d.pdf(2.0), 0.125, 1e-15);
+        assert_eq!(d.cdf(-1.0), 0.0);
+        assert_eq!(d.pdf(-1.0), 0.0);
+        assert_eq!(d.scale(), 1.0);
+        assert_eq!(d.shape(), 0.5);
+
+        // ξ = -0.5: F(x) = 1 - (1 - x/2)², bounded by x = 2
+        let d = DistributionGenPareto::new(0.0, 1.0, -0.5).unwrap();
+        approx_eq(d.cdf(1.0), 0.75, 1e-15);
+        approx_eq(d.pdf(1.0), 0.5, 1e-15);
+        assert_eq!(d.cdf(2.5), 1.0);
+        assert_eq!(d.pdf(2.5), 0.0);
+
+        // ξ = 0 is the (shifted) exponential distribution
+        let d = DistributionGenPareto::new(1.0, 2.0, 0.0).unwrap();
+        let e = DistributionGenPareto::new(1.0, 2.0, 1e-12).unwrap();
+        for x in [1.0, 1.5, 3.0, 10.0] {
+            approx_eq(d.cdf(x), -f64::exp_m1(-(x - 1.0) / 2.0), 1e-15);
+            approx_eq(d.pdf(x), f64::exp(-(x - 1.0) / 2.0) / 2.0, 1e-15);
+            approx_eq(e.cdf(x), d.cdf(x), 1e-10);
+            approx_eq(e.pdf(x), d.pdf(x), 1e-10);
+        }
+    }
+
+    #[test]
+    fn mean_and_variance_work() {
+        let d = DistributionGenPareto::new(1.0, 1.0, 0.25).unwrap();
+        approx_eq(d.mean(), 1.0 + 4.0 / 3.0, 1e-15);
+        approx_eq(d.variance(), 1.0 / (0.5625 * 0.5), 1e-15);
+
+        let d = DistributionGenPareto::new(0.0, 1.0, 0.5).unwrap();
+        assert_eq!(d.mean(), 2.0);
+        assert_eq!(d.variance(), f64::INFINITY);
+
+        let d = DistributionGenPareto::new(0.0, 1.0, 1.0).unwrap();
+        assert_eq!(d.mean(), f64::INFINITY);
+    }
+
+    #[test]
+    fn sample_works() {
+        let d = DistributionGenPareto::new(1.0, 2.0, -0.2).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        let n = 20_000;
+        let samples: Vec<_> = (0..n).map(|_| d.sample(&mut rng)).collect();
+        assert!(samples.iter().all(|x| (1.0..=11.0).contains(x)));
+        let mean = samples.iter().sum::<f64>() / (n as f64);
+        approx_eq(mean, d.mean(), 0.05);
+    }
+
+    #[test]
+    fn inv_cdf_works() {
+        for shape in [-0.3, 0.0, 0.4] {
+            let d = DistributionGenPareto::new(1.0, 2.0, shape).unwrap();
+            for p in [1e-10, 0.01, 0.25, 0.5, 0.75, 0.99] {
+                approx_eq(d.cdf(d.inv_cdf(p)), p, 1e-14);
+            }
+            assert_eq!(d.inv_cdf(0.0), 1.0);
+            assert!(d.inv_cdf(1.1).is_nan());
+        }
+        let d = DistributionGenPareto::new(1.0, 2.0, -0.5).unwrap();
+        assert_eq!(d.inv_cdf(1.0), 5.0);
+        let d = DistributionGenPareto::new(1.0, 2.0, 0.5).unwrap();
+        assert_eq!(d.inv_cdf(1.0), f64::INFINITY);
+    }
+}
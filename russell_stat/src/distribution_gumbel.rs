@@ -56,6 +56,14 @@ impl ProbabilityDistribution for DistributionGumbel {
         f64::exp(-f64::exp(mz))
     }
 
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        self.location - self.scale * f64::ln(-f64::ln(p))
+    }
+
     /// Returns the Mean
     fn mean(&self) -> f64 {
         self.location + EULER * self.scale
@@ -76,7 +84,7 @@ impl ProbabilityDistribution for DistributionGumbel {
 
 #[cfg(test)]
 mod tests {
-    use crate::{DistributionGumbel, ProbabilityDistribution};
+    use crate::{DistributionGumbel, DistributionNormal, ProbabilityDistribution};
     use russell_chk::approx_eq;
 
     // Data from the following R-code (run with Rscript gumbel.R):
@@ -271,4 +279,29 @@ mod tests {
         let mut rng = rand::thread_rng();
         d.sample(&mut rng);
     }
+
+    #[test]
+    fn inv_cdf_works() {
+        let d = DistributionGumbel::new(1.0, 2.0).unwrap();
+        approx_eq(d.inv_cdf(0.5), 1.0 - 2.0 * f64::ln(f64::ln(2.0)), 1e-15);
+        for p in [1e-10, 0.01, 0.25, 0.5, 0.75, 0.99] {
+            approx_eq(d.cdf(d.inv_cdf(p)), p, 1e-14);
+        }
+        assert_eq!(d.inv_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(d.inv_cdf(1.0), f64::INFINITY);
+        assert!(d.inv_cdf(1.1).is_nan());
+    }
+
+    #[test]
+    fn x_to_u_and_u_to_x_work() {
+        // default implementation: Φ(u) = F(x)
+        let d = DistributionGumbel::new(1.0, 2.0).unwrap();
+        let normal = DistributionNormal::new(0.0, 1.0).unwrap();
+        for x in [-3.0, 0.0, 1.0, 4.0, 10.0] {
+            let u = d.x_to_u(x);
+            approx_eq(normal.cdf(u), d.cdf(x), 1e-14);
+            approx_eq(d.u_to_x(u), x, 1e-12);
+        }
+        approx_eq(d.u_to_x(0.0), d.inv_cdf(0.5), 1e-15);
+    }
 }
@@ -1,7 +1,7 @@
-use crate::{ProbabilityDistribution, StrError};
+use crate::{inv_cdf_standard_normal, ProbabilityDistribution, StrError};
 use rand::Rng;
 use rand_distr::{Distribution, LogNormal};
-use russell_lab::math::{erf, SQRT_2, SQRT_PI};
+use russell_lab::math::{erf, SQRT_2, SQRT_2_PI};
 
 const LOGNORMAL_MIN_X: f64 = 1e-15;
 
@@ -26,7 +26,7 @@ impl DistributionLognormal {
         Ok(DistributionLognormal {
             mu_logx,
             sig_logx,
-            a: 1.0 / (sig_logx * SQRT_2 * SQRT_PI),
+            a: 1.0 / (sig_logx * SQRT_2_PI),
             b: -1.0 / (2.0 * sig_logx * sig_logx),
             sampler: LogNormal::new(mu_logx, sig_logx).map_err(|_| "invalid parameters")?,
         })
@@ -48,7 +48,7 @@ impl DistributionLognormal {
         Ok(DistributionLognormal {
             mu_logx,
             sig_logx,
-            a: 1.0 / (sig_logx * SQRT_2 * SQRT_PI),
+            a: 1.0 / (sig_logx * SQRT_2_PI),
             b: -1.0 / (2.0 * sig_logx * sig_logx),
             sampler: LogNormal::new(mu_logx, sig_logx).map_err(|_| "invalid parameters")?,
         })
@@ -72,6 +72,24 @@ impl ProbabilityDistribution for DistributionLognormal {
         (1.0 + erf((f64::ln(x) - self.mu_logx) / (self.sig_logx * SQRT_2))) / 2.0
     }
 
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inv_cdf(&self, p: f64) -> f64 {
+        f64::exp(self.mu_logx + self.sig_logx * inv_cdf_standard_normal(p))
+    }
+
+    /// Maps `x` to the standard normal space (exact transformation of log(x))
+    fn x_to_u(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        (f64::ln(x) - self.mu_logx) / self.sig_logx
+    }
+
+    /// Maps `u` from the standard normal space (exact transformation of log(x))
+    fn u_to_x(&self, u: f64) -> f64 {
+        f64::exp(self.mu_logx + self.sig_logx * u)
+    }
+
     /// Returns the Mean
     fn mean(&self) -> f64 {
         f64::exp(self.mu_logx + self.sig_logx * self.sig_logx / 2.0)
@@ -93,7 +111,7 @@ impl ProbabilityDistribution for DistributionLognormal {
 
 #[cfg(test)]
 mod tests {
-    use crate::{DistributionLognormal, ProbabilityDistribution};
+    use crate::{inv_cdf_standard_normal, DistributionLognormal, ProbabilityDistribution};
     use russell_chk::approx_eq;
 
     // Data from the following R-code (run with Rscript lognormal.R):
@@ -289,4 +307,28 @@ mod tests {
         let mut rng = rand::thread_rng();
         d.sample(&mut rng);
     }
+
+    #[test]
+    fn inv_cdf_works() {
+        let d = DistributionLognormal::new(1.0, 0.5).unwrap();
+        approx_eq(d.inv_cdf(0.5), f64::exp(1.0), 1e-15);
+        for p in [1e-10, 0.01, 0.25, 0.5, 0.75, 0.99] {
+            approx_eq(d.cdf(d.inv_cdf(p)), p, 1e-14);
+        }
+        assert_eq!(d.inv_cdf(0.0), 0.0);
+        assert_eq!(d.inv_cdf(1.0), f64::INFINITY);
+        assert!(d.inv_cdf(1.1).is_nan());
+    }
+
+    #[test]
+    fn x_to_u_and_u_to_x_work() {
+        let d = DistributionLognormal::new(1.0, 0.5).unwrap();
+        approx_eq(d.x_to_u(f64::exp(1.0)), 0.0, 1e-15);
+        approx_eq(d.u_to_x(2.0), f64::exp(2.0), 1e-14);
+        assert_eq!(d.x_to_u(0.0), f64::NEG_INFINITY);
+        for x in [0.5, 1.0, 3.0, 8.0] {
+            approx_eq(d.x_to_u(x), inv_cdf_standard_normal(d.cdf(x)), 1e-12);
+            approx_eq(d.u_to_x(d.x_to_u(x)), x, 1e-14);
+        }
+    }
 }
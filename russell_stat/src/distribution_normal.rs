@@ -1,7 +1,7 @@
 use crate::{ProbabilityDistribution, StrError};
 use rand::Rng;
 use rand_distr::{Distribution, Normal};
-use russell_lab::math::{erf, SQRT_2, SQRT_PI};
+use russell_lab::math::{erf, erfc, SQRT_2, SQRT_2_PI};
 
 /// Defines the Normal distribution
 pub struct DistributionNormal {
@@ -24,7 +24,7 @@ impl DistributionNormal {
         Ok(DistributionNormal {
             mu,
             sig,
-            a: 1.0 / (sig * SQRT_2 * SQRT_PI),
+            a: 1.0 / (sig * SQRT_2_PI),
             b: -1.0 / (2.0 * sig * sig),
             sampler: Normal::new(mu, sig).map_err(|_| "invalid parameters")?,
         })
@@ -42,6 +42,21 @@ impl ProbabilityDistribution for DistributionNormal {
         (1.0 + erf((x - self.mu) / (self.sig * SQRT_2))) / 2.0
     }
 
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inv_cdf(&self, p: f64) -> f64 {
+        self.mu + self.sig * inv_cdf_standard_normal(p)
+    }
+
+    /// Maps `x` to the standard normal space (exact linear transformation)
+    fn x_to_u(&self, x: f64) -> f64 {
+        (x - self.mu) / self.sig
+    }
+
+    /// Maps `u` from the standard normal space (exact linear transformation)
+    fn u_to_x(&self, u: f64) -> f64 {
+        self.mu + self.sig * u
+    }
+
     /// Returns the Mean
     fn mean(&self) -> f64 {
         self.mu
@@ -58,10 +73,78 @@ impl ProbabilityDistribution for DistributionNormal {
     }
 }
 
+/// Computes the inverse of the standard normal Cumulative Density Function (probit function)
+///
+/// Uses the rational approximations by P. J. Acklam (relative error of about 1.15e-9) followed by
+/// one step of Halley's method, resulting in (nearly) full double precision.
+///
+/// Returns `-∞` for `p = 0`, `+∞` for `p = 1`, and NaN if `p` is outside `[0, 1]`.
+pub(crate) fn inv_cdf_standard_normal(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.38357751867269e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+    const P_LOW: f64 = 0.02425;
+    if !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p == 1.0 {
+        return f64::INFINITY;
+    }
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    let x = if p < P_LOW {
+        tail(f64::sqrt(-2.0 * f64::ln(p)))
+    } else if p > 1.0 - P_LOW {
+        -tail(f64::sqrt(-2.0 * f64::ln(1.0 - p)))
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    };
+
+    // refinement (Halley)
+    let e = 0.5 * erfc(-x / SQRT_2) - p;
+    let u = e * SQRT_2_PI * f64::exp(x * x / 2.0);
+    x - u / (1.0 + x * u / 2.0)
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
+    use super::inv_cdf_standard_normal;
     use crate::{DistributionNormal, ProbabilityDistribution};
     use russell_chk::approx_eq;
 
@@ -279,4 +362,37 @@ mod tests {
         let mut rng = rand::thread_rng();
         d.sample(&mut rng);
     }
+
+    #[test]
+    fn inv_cdf_works() {
+        // reference values from R: qnorm(p)
+        let d = DistributionNormal::new(0.0, 1.0).unwrap();
+        approx_eq(d.inv_cdf(0.5), 0.0, 1e-15);
+        approx_eq(d.inv_cdf(0.975), 1.959963984540054, 1e-14);
+        approx_eq(d.inv_cdf(0.001), -3.090232306167814, 1e-14);
+        approx_eq(d.inv_cdf(1e-10), -6.361340902404056, 1e-13);
+        for p in [1e-300, 1e-10, 0.02, 0.02425, 0.3, 0.5, 0.7, 0.97575, 0.99] {
+            approx_eq(d.cdf(d.inv_cdf(p)), p, 1e-15);
+        }
+        assert_eq!(d.inv_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(d.inv_cdf(1.0), f64::INFINITY);
+        assert!(d.inv_cdf(-0.1).is_nan());
+        assert!(d.inv_cdf(f64::NAN).is_nan());
+
+        // shifted and scaled
+        let d = DistributionNormal::new(-1.0, 0.5).unwrap();
+        approx_eq(d.inv_cdf(0.975), -1.0 + 0.5 * 1.959963984540054, 1e-14);
+    }
+
+    #[test]
+    fn x_to_u_and_u_to_x_work() {
+        let d = DistributionNormal::new(-1.0, 0.5).unwrap();
+        approx_eq(d.x_to_u(-1.0), 0.0, 1e-15);
+        approx_eq(d.x_to_u(0.0), 2.0, 1e-15);
+        approx_eq(d.u_to_x(-2.0), -2.0, 1e-15);
+        for x in [-3.0, -1.5, 0.0, 0.2] {
+            approx_eq(d.x_to_u(x), inv_cdf_standard_normal(d.cdf(x)), 1e-12);
+            approx_eq(d.u_to_x(d.x_to_u(x)), x, 1e-15);
+        }
+    }
 }
@@ -0,0 +1,215 @@
+use crate::{
+    DistributionFisherF, DistributionFrechet, DistributionGenPareto, DistributionGumbel, DistributionLognormal,
+    DistributionNormal, DistributionStable, DistributionStudentT, DistributionUniform, ProbabilityDistribution,
+    StrError,
+};
+use serde::{Deserialize, Serialize};
+
+/// Holds the parameters of a probability distribution (serializable description)
+///
+/// The name of the distribution is given by the `type` field and the remaining fields are the
+/// arguments of the corresponding `new` function. For example, in JSON:
+///
+/// ```text
+/// {"type": "gumbel", "location": 1.0, "scale": 2.0}
+/// {"type": "normal", "mu": 0.0, "sig": 1.0}
+/// {"type": "student_t", "dof": 5.0, "location": 0.0, "scale": 1.0}
+/// ```
+///
+/// Thus, the random variables of a stochastic model may be defined in an input file.
+/// Unknown fields are rejected to catch misspelled parameters.
+///
+/// See [distribution_from_params] to allocate the distribution.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum DistributionParams {
+    /// Fisher-Snedecor F distribution (see [DistributionFisherF])
+    FisherF { d1: f64, d2: f64 },
+
+    /// Frechet distribution (see [DistributionFrechet])
+    Frechet { location: f64, scale: f64, shape: f64 },
+
+    /// Generalized Pareto distribution (see [DistributionGenPareto])
+    GenPareto { location: f64, scale: f64, shape: f64 },
+
+    /// Gumbel distribution (see [DistributionGumbel])
+    Gumbel { location: f64, scale: f64 },
+
+    /// Lognormal distribution (see [DistributionLognormal])
+    Lognormal { mu_logx: f64, sig_logx: f64 },
+
+    /// Normal distribution (see [DistributionNormal])
+    Normal { mu: f64, sig: f64 },
+
+    /// Stable distribution (see [DistributionStable])
+    Stable {
+        alpha: f64,
+        beta: f64,
+        scale: f64,
+        location: f64,
+    },
+
+    /// Student's t distribution (see [DistributionStudentT])
+    StudentT { dof: f64, location: f64, scale: f64 },
+
+    /// Uniform distribution (see [DistributionUniform])
+    Uniform { xmin: f64, xmax: f64 },
+}
+
+/// Allocates a probability distribution from its parameters
+///
+/// **Note:** The trait object does not give access to [ProbabilityDistribution::sample];
+/// use [ProbabilityDistribution::inv_cdf] with a uniform number instead.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{distribution_from_params, DistributionParams, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let params = DistributionParams::Gumbel {
+///         location: 0.0,
+///         scale: 1.0,
+///     };
+///     let dist = distribution_from_params(&params)?;
+///     assert_eq!(dist.cdf(0.0), f64::exp(-1.0));
+///     Ok(())
+/// }
+/// ```
+pub fn distribution_from_params(
+    params: &DistributionParams,
+) -> Result<Box<dyn ProbabilityDistribution + Send + Sync>, StrError> {
+    let dist: Box<dyn ProbabilityDistribution + Send + Sync> = match *params {
+        DistributionParams::FisherF { d1, d2 } => Box::new(DistributionFisherF::new(d1, d2)?),
+        DistributionParams::Frechet { location, scale, shape } => {
+            Box::new(DistributionFrechet::new(location, scale, shape)?)
+        }
+        DistributionParams::GenPareto { location, scale, shape } => {
+            Box::new(DistributionGenPareto::new(location, scale, shape)?)
+        }
+        DistributionParams::Gumbel { location, scale } => Box::new(DistributionGumbel::new(location, scale)?),
+        DistributionParams::Lognormal { mu_logx, sig_logx } => Box::new(DistributionLognormal::new(mu_logx, sig_logx)?),
+        DistributionParams::Normal { mu, sig } => Box::new(DistributionNormal::new(mu, sig)?),
+        DistributionParams::Stable {
+            alpha,
+            beta,
+            scale,
+            location,
+        } => Box::new(DistributionStable::new(alpha, beta, scale, location)?),
+        DistributionParams::StudentT { dof, location, scale } => {
+            Box::new(DistributionStudentT::new(dof, location, scale)?)
+        }
+        DistributionParams::Uniform { xmin, xmax } => Box::new(DistributionUniform::new(xmin, xmax)?),
+    };
+    Ok(dist)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{distribution_from_params, DistributionParams};
+    use crate::{DistributionGumbel, DistributionLognormal, DistributionStudentT, ProbabilityDistribution};
+
+    #[test]
+    fn distribution_from_params_handles_errors() {
+        let params = DistributionParams::StudentT {
+            dof: -1.0,
+            location: 0.0,
+            scale: 1.0,
+        };
+        assert_eq!(distribution_from_params(&params).err(), Some("invalid parameters"));
+        let params = DistributionParams::Uniform { xmin: 1.0, xmax: 0.0 };
+        assert_eq!(distribution_from_params(&params).err(), Some("invalid parameters"));
+    }
+
+    #[test]
+    fn distribution_from_params_works() {
+        let all = [
+            DistributionParams::FisherF { d1: 5.0, d2: 10.0 },
+            DistributionParams::Frechet {
+                location: 0.0,
+                scale: 1.0,
+                shape: 3.0,
+            },
+            DistributionParams::GenPareto {
+                location: 0.0,
+                scale: 1.0,
+                shape: 0.2,
+            },
+            DistributionParams::Gumbel {
+                location: 1.0,
+                scale: 2.0,
+            },
+            DistributionParams::Lognormal {
+                mu_logx: 0.0,
+                sig_logx: 0.25,
+            },
+            DistributionParams::Normal { mu: 1.0, sig: 0.5 },
+            DistributionParams::Stable {
+                alpha: 1.5,
+                beta: 0.0,
+                scale: 1.0,
+                location: 0.0,
+            },
+            DistributionParams::StudentT {
+                dof: 5.0,
+                location: 0.0,
+                scale: 1.0,
+            },
+            DistributionParams::Uniform { xmin: 0.0, xmax: 2.0 },
+        ];
+        for params in &all {
+            let dist = distribution_from_params(params).unwrap();
+            let x = dist.inv_cdf(0.3);
+            assert!(f64::abs(dist.cdf(x) - 0.3) < 1e-8);
+        }
+        let dist = distribution_from_params(&all[3]).unwrap();
+        let gumbel = DistributionGumbel::new(1.0, 2.0).unwrap();
+        assert_eq!(dist.mean(), gumbel.mean());
+        assert_eq!(dist.pdf(1.5), gumbel.pdf(1.5));
+        let dist = distribution_from_params(&all[4]).unwrap();
+        let lognormal = DistributionLognormal::new(0.0, 0.25).unwrap();
+        assert_eq!(dist.variance(), lognormal.variance());
+        let dist = distribution_from_params(&all[7]).unwrap();
+        let student = DistributionStudentT::new(5.0, 0.0, 1.0).unwrap();
+        assert_eq!(dist.cdf(0.7), student.cdf(0.7));
+    }
+
+    #[test]
+    fn serialize_and_deserialize_work() {
+        let params = DistributionParams::Gumbel {
+            location: 1.0,
+            scale: 2.0,
+        };
+        let json = serde_json::to_string(&params).unwrap();
+        assert_eq!(json, r#"{"type":"gumbel","location":1.0,"scale":2.0}"#);
+        let input = r#"[
+            {"type": "gumbel", "location": 1.0, "scale": 2.0},
+            {"type": "student_t", "dof": 5.0, "location": 0.0, "scale": 1.0},
+            {"type": "gen_pareto", "location": 0.0, "scale": 1.0, "shape": 0.2}
+        ]"#;
+        let all: Vec<DistributionParams> = serde_json::from_str(input).unwrap();
+        assert_eq!(all[0], params);
+        assert_eq!(
+            all[1],
+            DistributionParams::StudentT {
+                dof: 5.0,
+                location: 0.0,
+                scale: 1.0
+            }
+        );
+        assert_eq!(
+            all[2],
+            DistributionParams::GenPareto {
+                location: 0.0,
+                scale: 1.0,
+                shape: 0.2
+            }
+        );
+        let misspelled = r#"{"type": "normal", "mu": 0.0, "sigma": 1.0}"#;
+        assert!(serde_json::from_str::<DistributionParams>(misspelled).is_err());
+        let unknown = r#"{"type": "weibull", "scale": 1.0}"#;
+        assert!(serde_json::from_str::<DistributionParams>(unknown).is_err());
+    }
+}
@@ -0,0 +1,447 @@
+use crate::{ProbabilityDistribution, StrError};
+use rand::Rng;
+use russell_lab::math::{gamma, PI, PI_BY_2};
+
+/// Defines the (Lévy alpha-) stable distribution
+///
+/// The distribution is defined by its characteristic function (parameterization S1 by Nolan):
+///
+/// ```text
+/// E[exp(i t X)] = exp(-σ^α |t|^α [1 - i β sign(t) tan(πα/2)] + i μ t)          if α ≠ 1
+///
+/// E[exp(i t X)] = exp(-σ |t| [1 + i β (2/π) sign(t) ln|t|] + i μ t)             if α = 1
+/// ```
+///
+/// where `α ∈ (0, 2]` is the stability index, `β ∈ [-1, 1]` is the skewness, `σ > 0` is the scale,
+/// and `μ` is the location. The tails decay as `|x|^(-1-α)`; thus, the variance is infinite for
+/// `α < 2` and the mean is undefined for `α ≤ 1`. Special cases are the normal distribution
+/// (`α = 2` with standard deviation `σ √2`), the Cauchy distribution (`α = 1, β = 0`), and
+/// the Lévy distribution (`α = ½, β = 1`).
+///
+/// The PDF and CDF are computed by numerical integration of the integral representations
+/// by Nolan (1997) and the samples are generated by the method of Chambers, Mallows, and Stuck (1976).
+///
+/// # References
+///
+/// 1. Nolan JP (1997) Numerical calculation of stable densities and distribution functions,
+///    Communications in Statistics -- Stochastic Models, 13(4):759-774
+/// 2. Chambers JM, Mallows CL, Stuck BW (1976) A method for simulating stable random variables,
+///    Journal of the American Statistical Association, 71(354):340-344
+pub struct DistributionStable {
+    alpha: f64,    // α: stability index
+    beta: f64,     // β: skewness
+    scale: f64,    // σ: scale parameter
+    location: f64, // μ: location parameter
+}
+
+impl DistributionStable {
+    /// Creates a new stable distribution
+    ///
+    /// # Input
+    ///
+    /// * `alpha` -- stability index α (0 < α ≤ 2)
+    /// * `beta` -- skewness β (-1 ≤ β ≤ 1)
+    /// * `scale` -- scale parameter σ (> 0)
+    /// * `location` -- location parameter μ
+    pub fn new(alpha: f64, beta: f64, scale: f64, location: f64) -> Result<Self, StrError> {
+        if !alpha.is_finite() || !beta.is_finite() || !scale.is_finite() || !location.is_finite() {
+            return Err("invalid parameters");
+        }
+        if alpha <= 0.0 || alpha > 2.0 || !(-1.0..=1.0).contains(&beta) || scale <= 0.0 {
+            return Err("invalid parameters");
+        }
+        Ok(DistributionStable {
+            alpha,
+            beta,
+            scale,
+            location,
+        })
+    }
+
+    /// Returns the stability index α
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the skewness β
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Returns the standardized variable in the S0 parameterization (where the PDF is continuous in α)
+    fn standardize(&self, x: f64) -> f64 {
+        if self.alpha == 1.0 {
+            (x - self.location) / self.scale - self.beta * f64::ln(self.scale) / PI_BY_2
+        } else {
+            (x - self.location) / self.scale - self.beta * f64::tan(PI_BY_2 * self.alpha)
+        }
+    }
+}
+
+impl ProbabilityDistribution for DistributionStable {
+    /// Implements the Probability Density Function (CDF)
+    fn pdf(&self, x: f64) -> f64 {
+        let z = self.standardize(x);
+        if z.is_infinite() {
+            return 0.0;
+        }
+        standard_pdf(z, self.alpha, self.beta) / self.scale
+    }
+
+    /// Implements the Cumulative Density Function (CDF)
+    fn cdf(&self, x: f64) -> f64 {
+        let z = self.standardize(x);
+        if z.is_infinite() {
+            return if z > 0.0 { 1.0 } else { 0.0 };
+        }
+        standard_cdf(z, self.alpha, self.beta).clamp(0.0, 1.0)
+    }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    ///
+    /// The quantile is computed by bracketing followed by bisection on the CDF.
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        if p == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+        let (mut lo, mut hi) = (self.location - self.scale, self.location + self.scale);
+        while self.cdf(lo) > p && lo.is_finite() {
+            lo = self.location - 2.0 * (self.location - lo);
+        }
+        while self.cdf(hi) < p && hi.is_finite() {
+            hi = self.location + 2.0 * (hi - self.location);
+        }
+        for _ in 0..200 {
+            let mid = 0.5 * (lo + hi);
+            if mid == lo || mid == hi {
+                break;
+            }
+            if self.cdf(mid) < p {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        0.5 * (lo + hi)
+    }
+
+    /// Returns the Mean
+    ///
+    /// **Note:** The mean is undefined (NaN) if `α ≤ 1`.
+    fn mean(&self) -> f64 {
+        if self.alpha > 1.0 {
+            return self.location;
+        }
+        f64::NAN
+    }
+
+    /// Returns the Variance
+    ///
+    /// **Note:** The variance is infinite if `α < 2`.
+    fn variance(&self) -> f64 {
+        if self.alpha == 2.0 {
+            return 2.0 * self.scale * self.scale;
+        }
+        f64::INFINITY
+    }
+
+    /// Generates a pseudo-random number belonging to this probability distribution
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let (a, b) = (self.alpha, self.beta);
+        let v = PI * (rng.gen::<f64>() - 0.5); // uniform in (-π/2, π/2)
+        let w = -f64::ln(1.0 - rng.gen::<f64>()); // standard exponential
+        if a == 1.0 {
+            let c = PI_BY_2 + b * v;
+            let z = (c * f64::tan(v) - b * f64::ln(PI_BY_2 * w * f64::cos(v) / c)) / PI_BY_2;
+            return self.location + self.scale * z + b * self.scale * f64::ln(self.scale) / PI_BY_2;
+        }
+        let t = b * f64::tan(PI_BY_2 * a);
+        let b0 = f64::atan(t) / a;
+        let s0 = f64::powf(1.0 + t * t, 0.5 / a);
+        let z = s0 * f64::sin(a * (v + b0)) / f64::powf(f64::cos(v), 1.0 / a)
+            * f64::powf(f64::cos(v - a * (v + b0)) / w, (1.0 - a) / a);
+        self.location + self.scale * z
+    }
+}
+
+/// Computes the PDF of the standard stable distribution (S0 parameterization)
+fn standard_pdf(z: f64, alpha: f64, beta: f64) -> f64 {
+    if alpha == 1.0 {
+        if beta == 0.0 {
+            return 1.0 / (PI * (1.0 + z * z));
+        }
+        if beta < 0.0 {
+            return standard_pdf(-z, alpha, -beta);
+        }
+        let k = f64::exp(-z / (2.0 * beta / PI));
+        let integral = integrate(
+            -PI_BY_2,
+            PI_BY_2,
+            |theta| k * v_alpha_one(theta, beta),
+            |g| g * f64::exp(-g),
+        );
+        return integral / (2.0 * beta);
+    }
+    let zeta = -beta * f64::tan(PI_BY_2 * alpha);
+    if z < zeta {
+        return standard_pdf(-z, alpha, -beta);
+    }
+    let theta0 = f64::atan(beta * f64::tan(PI_BY_2 * alpha)) / alpha;
+    if z - zeta < 1e-12 * f64::max(1.0, zeta.abs()) {
+        return gamma(1.0 + 1.0 / alpha) * f64::cos(theta0) / (PI * f64::powf(1.0 + zeta * zeta, 0.5 / alpha));
+    }
+    let k = f64::powf(z - zeta, alpha / (alpha - 1.0));
+    let integral = integrate(
+        -theta0,
+        PI_BY_2,
+        |theta| k * v_alpha(theta, theta0, alpha),
+        |g| g * f64::exp(-g),
+    );
+    alpha * integral / (PI * f64::abs(alpha - 1.0) * (z - zeta))
+}
+
+/// Computes the CDF of the standard stable distribution (S0 parameterization)
+fn standard_cdf(z: f64, alpha: f64, beta: f64) -> f64 {
+    if alpha == 1.0 {
+        if beta == 0.0 {
+            return 0.5 + f64::atan(z) / PI;
+        }
+        if beta < 0.0 {
+            return 1.0 - standard_cdf(-z, alpha, -beta);
+        }
+        let k = f64::exp(-z / (2.0 * beta / PI));
+        return integrate(
+            -PI_BY_2,
+            PI_BY_2,
+            |theta| k * v_alpha_one(theta, beta),
+            |g| f64::exp(-g),
+        ) / PI;
+    }
+    let zeta = -beta * f64::tan(PI_BY_2 * alpha);
+    if z < zeta {
+        return 1.0 - standard_cdf(-z, alpha, -beta);
+    }
+    let theta0 = f64::atan(beta * f64::tan(PI_BY_2 * alpha)) / alpha;
+    let c0 = (PI_BY_2 - theta0) / PI;
+    if z - zeta < 1e-12 * f64::max(1.0, zeta.abs()) {
+        return c0;
+    }
+    let k = f64::powf(z - zeta, alpha / (alpha - 1.0));
+    let integral = integrate(
+        -theta0,
+        PI_BY_2,
+        |theta| k * v_alpha(theta, theta0, alpha),
+        |g| f64::exp(-g),
+    );
+    if alpha < 1.0 {
+        c0 + integral / PI
+    } else {
+        1.0 - integral / PI
+    }
+}
+
+/// Computes the auxiliary function V(θ) of the integral representation with α ≠ 1
+fn v_alpha(theta: f64, theta0: f64, alpha: f64) -> f64 {
+    let c = f64::powf(f64::cos(alpha * theta0), 1.0 / (alpha - 1.0));
+    let r = f64::cos(theta) / f64::sin(alpha * (theta0 + theta));
+    c * f64::powf(r, alpha / (alpha - 1.0)) * f64::cos(alpha * theta0 + (alpha - 1.0) * theta) / f64::cos(theta)
+}
+
+/// Computes the auxiliary function V(θ) of the integral representation with α = 1 and β > 0
+fn v_alpha_one(theta: f64, beta: f64) -> f64 {
+    let c = PI_BY_2 + beta * theta;
+    c / f64::cos(theta) * f64::exp(c * f64::tan(theta) / beta) / PI_BY_2
+}
+
+/// Integrates h(g(θ)) over [a, b] using adaptive Simpson's rule, where g(θ) = k V(θ) is monotonic
+///
+/// The integrands `g exp(-g)` and `exp(-g)` vary rapidly around `g = 1`; thus, the range is split
+/// at this point (found by bisection) and each part is further subdivided, making sure that narrow
+/// peaks are captured. Non-finite values of the integrand (at the endpoints) are replaced by zero.
+fn integrate<G, H>(a: f64, b: f64, g: G, h: H) -> f64
+where
+    G: Fn(f64) -> f64,
+    H: Fn(f64) -> f64,
+{
+    const NSUB: usize = 32;
+    let f = |theta: f64| {
+        let y = h(g(theta));
+        if y.is_finite() {
+            y
+        } else {
+            0.0
+        }
+    };
+    let (mut lo, mut hi) = (a, b);
+    let increasing = g(a) < 1.0;
+    if increasing != (g(b) < 1.0) {
+        for _ in 0..100 {
+            let mid = 0.5 * (lo + hi);
+            if mid == lo || mid == hi {
+                break;
+            }
+            if (g(mid) < 1.0) == increasing {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+    }
+    let peak = 0.5 * (lo + hi);
+    let mut sum = 0.0;
+    for (start, end) in [(a, peak), (peak, b)] {
+        let dx = (end - start) / (NSUB as f64);
+        for i in 0..NSUB {
+            let (x0, x1) = (start + (i as f64) * dx, start + ((i + 1) as f64) * dx);
+            let xm = 0.5 * (x0 + x1);
+            let (y0, ym, y1) = (f(x0), f(xm), f(x1));
+            let whole = (x1 - x0) * (y0 + 4.0 * ym + y1) / 6.0;
+            sum += adaptive_simpson(&f, (x0, x1), [y0, ym, y1], whole, 1e-14, 30);
+        }
+    }
+    sum
+}
+
+/// Implements the recursive step of the adaptive Simpson's rule
+fn adaptive_simpson<F>(f: &F, (a, b): (f64, f64), [fa, fm, fb]: [f64; 3], whole: f64, tol: f64, depth: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let m = 0.5 * (a + b);
+    let (lm, rm) = (0.5 * (a + m), 0.5 * (m + b));
+    let (flm, frm) = (f(lm), f(rm));
+    let left = (m - a) * (fa + 4.0 * flm + fm) / 6.0;
+    let right = (b - m) * (fm + 4.0 * frm + fb) / 6.0;
+    let delta = left + right - whole;
+    if depth == 0 || f64::abs(delta) <= 15.0 * tol {
+        return left + right + delta / 15.0;
+    }
+    adaptive_simpson(f, (a, m), [fa, flm, fm], left, 0.5 * tol, depth - 1)
+        + adaptive_simpson(f, (m, b), [fm, frm, fb], right, 0.5 * tol, depth - 1)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{DistributionNormal, DistributionStable, ProbabilityDistribution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+    use russell_lab::math::{erfc, PI, SQRT_2};
+
+    #[test]
+    fn stable_handles_errors() {
+        assert_eq!(
+            DistributionStable::new(0.0, 0.0, 1.0, 0.0).err(),
+            Some("invalid parameters")
+        );
+        assert_eq!(
+            DistributionStable::new(2.1, 0.0, 1.0, 0.0).err(),
+            Some("invalid parameters")
+        );
+        assert_eq!(
+            DistributionStable::new(1.5, 1.1, 1.0, 0.0).err(),
+            Some("invalid parameters")
+        );
+        assert_eq!(
+            DistributionStable::new(1.5, 0.0, 0.0, 0.0).err(),
+            Some("invalid parameters")
+        );
+        assert_eq!(
+            DistributionStable::new(1.5, 0.0, 1.0, f64::NAN).err(),
+            Some("invalid parameters")
+        );
+    }
+
+    #[test]
+    fn stable_works_normal() {
+        let d = DistributionStable::new(2.0, 0.5, 1.5, 3.0).unwrap();
+        let n = DistributionNormal::new(3.0, 1.5 * SQRT_2).unwrap();
+        assert_eq!(d.alpha(), 2.0);
+        assert_eq!(d.beta(), 0.5);
+        for x in [-4.0, 0.0, 2.5, 3.0, 3.1, 6.0, 10.0] {
+            approx_eq(d.pdf(x), n.pdf(x), 1e-10);
+            approx_eq(d.cdf(x), n.cdf(x), 1e-10);
+        }
+        assert_eq!(d.mean(), 3.0);
+        approx_eq(d.variance(), n.variance(), 1e-15);
+    }
+
+    #[test]
+    fn stable_works_cauchy() {
+        let (scale, location) = (0.5, 2.0);
+        let d = DistributionStable::new(1.0, 0.0, scale, location).unwrap();
+        for x in [-3.0, 0.0, 1.9, 2.0, 2.6, 10.0] {
+            let z = (x - location) / scale;
+            approx_eq(d.pdf(x), 1.0 / (PI * scale * (1.0 + z * z)), 1e-15);
+            approx_eq(d.cdf(x), 0.5 + f64::atan(z) / PI, 1e-15);
+        }
+        assert!(d.mean().is_nan());
+        assert_eq!(d.variance(), f64::INFINITY);
+    }
+
+    #[test]
+    fn stable_works_levy() {
+        let (scale, location) = (2.0, 1.0);
+        let d = DistributionStable::new(0.5, 1.0, scale, location).unwrap();
+        for x in [1.2, 2.0, 3.0, 5.0, 20.0] {
+            let r = x - location;
+            let pdf = f64::sqrt(scale / (2.0 * PI)) * f64::exp(-scale / (2.0 * r)) / f64::powf(r, 1.5);
+            approx_eq(d.pdf(x), pdf, 1e-10);
+            approx_eq(d.cdf(x), erfc(f64::sqrt(scale / (2.0 * r))), 1e-10);
+        }
+        assert_eq!(d.pdf(0.5), 0.0);
+        approx_eq(d.cdf(0.5), 0.0, 1e-15);
+        // reflected Lévy
+        let d = DistributionStable::new(0.5, -1.0, scale, location).unwrap();
+        let r = 4.0;
+        approx_eq(d.cdf(location - r), 1.0 - erfc(f64::sqrt(scale / (2.0 * r))), 1e-10);
+    }
+
+    #[test]
+    fn pdf_integrates_to_cdf() {
+        for (alpha, beta) in [(1.5, 0.7), (0.8, -0.3), (1.0, 0.6), (1.0, -0.9)] {
+            let d = DistributionStable::new(alpha, beta, 1.2, 0.4).unwrap();
+            // Simpson's rule between a and b compared with the difference of the CDF values
+            let (a, b, n) = (-1.0, 2.0, 600);
+            let h = (b - a) / (n as f64);
+            let mut sum = d.pdf(a) + d.pdf(b);
+            for i in 1..n {
+                let w = if i % 2 == 1 { 4.0 } else { 2.0 };
+                sum += w * d.pdf(a + (i as f64) * h);
+            }
+            approx_eq(sum * h / 3.0, d.cdf(b) - d.cdf(a), 1e-8);
+        }
+    }
+
+    #[test]
+    fn inv_cdf_works() {
+        for (alpha, beta) in [(1.7, 0.2), (0.6, 0.9), (1.0, -0.5)] {
+            let d = DistributionStable::new(alpha, beta, 2.0, -1.0).unwrap();
+            for p in [0.01, 0.3, 0.5, 0.9] {
+                approx_eq(d.cdf(d.inv_cdf(p)), p, 1e-10);
+            }
+            assert_eq!(d.inv_cdf(0.0), f64::NEG_INFINITY);
+            assert_eq!(d.inv_cdf(1.0), f64::INFINITY);
+            assert!(d.inv_cdf(-0.1).is_nan());
+        }
+    }
+
+    #[test]
+    fn sample_works() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let n = 20_000;
+        for (alpha, beta, x) in [(1.5, 0.5, 0.0), (0.7, -0.4, 1.0), (1.0, 0.8, 2.0)] {
+            let d = DistributionStable::new(alpha, beta, 1.5, 0.5).unwrap();
+            let below = (0..n).filter(|_| d.sample(&mut rng) <= x).count() as f64 / (n as f64);
+            approx_eq(below, d.cdf(x), 0.01);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{ProbabilityDistribution, StrError};
+use rand::Rng;
+use rand_distr::{Distribution, StudentT};
+use russell_lab::math::{beta_inc, ln_gamma, PI};
+
+/// Defines the Student's t distribution with location and scale parameters
+///
+/// ```text
+///                    Γ((ν+1)/2)           z²  -(ν+1)/2
+/// f(x) = ——————————————————————— (1 + ———)              with  z = (x - location) / scale
+///         σ √(ν π) Γ(ν/2)                ν
+///
+/// F(x) = 1 - ½ I_w(ν/2, ½)     with  w = ν / (ν + z²)  and  z ≥ 0
+/// ```
+///
+/// where `I` is the regularized incomplete beta function. The tails decay as a power law; thus,
+/// this distribution is an alternative to the normal distribution when the data shows outliers.
+/// The Cauchy distribution corresponds to `ν = 1` and the normal distribution is recovered as `ν → ∞`.
+pub struct DistributionStudentT {
+    dof: f64,      // degrees of freedom ν
+    location: f64, // location parameter
+    scale: f64,    // scale parameter
+
+    sampler: StudentT<f64>, // sampler of the standard variable
+}
+
+impl DistributionStudentT {
+    /// Creates a new Student's t distribution
+    ///
+    /// # Input
+    ///
+    /// * `dof` -- degrees of freedom ν (> 0)
+    /// * `location` -- location parameter (median)
+    /// * `scale` -- scale parameter (> 0)
+    pub fn new(dof: f64, location: f64, scale: f64) -> Result<Self, StrError> {
+        if !dof.is_finite() || !location.is_finite() || !scale.is_finite() || dof <= 0.0 || scale <= 0.0 {
+            return Err("invalid parameters");
+        }
+        Ok(DistributionStudentT {
+            dof,
+            location,
+            scale,
+            sampler: StudentT::new(dof).map_err(|_| "invalid parameters")?,
+        })
+    }
+
+    /// Returns the degrees of freedom
+    pub fn dof(&self) -> f64 {
+        self.dof
+    }
+}
+
+impl ProbabilityDistribution for DistributionStudentT {
+    /// Implements the Probability Density Function (CDF)
+    fn pdf(&self, x: f64) -> f64 {
+        let nu = self.dof;
+        let z = (x - self.location) / self.scale;
+        let ln_c = ln_gamma(0.5 * (nu + 1.0)) - ln_gamma(0.5 * nu) - 0.5 * f64::ln(nu * PI);
+        f64::exp(ln_c - 0.5 * (nu + 1.0) * f64::ln_1p(z * z / nu)) / self.scale
+    }
+
+    /// Implements the Cumulative Density Function (CDF)
+    fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z.is_infinite() {
+            return if z > 0.0 { 1.0 } else { 0.0 };
+        }
+        let tail = 0.5 * beta_inc(0.5 * self.dof, 0.5, self.dof / (self.dof + z * z));
+        if z > 0.0 {
+            1.0 - tail
+        } else {
+            tail
+        }
+    }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    ///
+    /// The quantile is computed by bisection on the argument of the incomplete beta function.
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        if p == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+        if p == 0.5 {
+            return self.location;
+        }
+        let tail = if p < 0.5 { p } else { 1.0 - p };
+        let a = 0.5 * self.dof;
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..200 {
+            let mid = 0.5 * (lo + hi);
+            if mid == lo || mid == hi {
+                break;
+            }
+            if 0.5 * beta_inc(a, 0.5, mid) < tail {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let w = 0.5 * (lo + hi);
+        let z = f64::sqrt(self.dof * (1.0 - w) / w);
+        if p < 0.5 {
+            self.location - self.scale * z
+        } else {
+            self.location + self.scale * z
+        }
+    }
+
+    /// Returns the Mean
+    ///
+    /// **Note:** The mean is undefined (NaN) if `ν ≤ 1`.
+    fn mean(&self) -> f64 {
+        if self.dof > 1.0 {
+            return self.location;
+        }
+        f64::NAN
+    }
+
+    /// Returns the Variance
+    ///
+    /// **Note:** The variance is infinite if `1 < ν ≤ 2` and undefined (NaN) if `ν ≤ 1`.
+    fn variance(&self) -> f64 {
+        if self.dof > 2.0 {
+            return self.scale * self.scale * self.dof / (self.dof - 2.0);
+        }
+        if self.dof > 1.0 {
+            return f64::INFINITY;
+        }
+        f64::NAN
+    }
+
+    /// Generates a pseudo-random number belonging to this probability distribution
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.location + self.scale * self.sampler.sample(rng)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{DistributionStudentT, ProbabilityDistribution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+    use russell_lab::math::PI;
+
+    #[test]
+    fn student_t_handles_errors() {
+        assert_eq!(
+            DistributionStudentT::new(0.0, 0.0, 1.0).err(),
+            Some("invalid parameters")
+        );
+        assert_eq!(
+            DistributionStudentT::new(1.0, 0.0, -1.0).err(),
+            Some("invalid parameters")
+        );
+        assert_eq!(
+            DistributionStudentT::new(1.0, f64::NAN, 1.0).err(),
+            Some("invalid parameters")
+        );
+    }
+
+    #[test]
+    fn student_t_works() {
+        // ν = 1: Cauchy distribution
+        let (location, scale) = (2.0, 0.5);
+        let d = DistributionStudentT::new(1.0, location, scale).unwrap();
+        assert_eq!(d.dof(), 1.0);
+        for x in [-3.0, 0.0, 1.9, 2.0, 2.6, 10.0] {
+            let z = (x - location) / scale;
+            approx_eq(d.pdf(x), 1.0 / (PI * scale * (1.0 + z * z)), 1e-15);
+            approx_eq(d.cdf(x), 0.5 + f64::atan(z) / PI, 1e-14);
+        }
+
+        // ν = 2: F(z) = ½ + z / (2 √(2 + z²))
+        let d = DistributionStudentT::new(2.0, 0.0, 1.0).unwrap();
+        for z in [-4.0, -1.0, -0.1, 0.0, 0.3, 2.0, 50.0] {
+            approx_eq(d.cdf(z), 0.5 + z / (2.0 * f64::sqrt(2.0 + z * z)), 1e-14);
+            approx_eq(d.pdf(z), f64::powf(2.0 + z * z, -1.5), 1e-15);
+        }
+        assert_eq!(d.cdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(d.cdf(f64::INFINITY), 1.0);
+
+        // ν = 3: F(z) = ½ + (1/π) [z / (√3 (1 + z²/3)) + atan(z/√3)]
+        let d = DistributionStudentT::new(3.0, -1.0, 2.0).unwrap();
+        for x in [-9.0, -2.0, 0.0, 4.0] {
+            let z = (x + 1.0) / 2.0;
+            let s = f64::sqrt(3.0);
+            approx_eq(
+                d.cdf(x),
+                0.5 + (z / (s * (1.0 + z * z / 3.0)) + f64::atan(z / s)) / PI,
+                1e-14,
+            );
+        }
+    }
+
+    #[test]
+    fn inv_cdf_works() {
+        // ν = 2: z = (2p - 1) / √(2p (1 - p))
+        let d = DistributionStudentT::new(2.0, 1.0, 3.0).unwrap();
+        for p in [0.001, 0.2, 0.5, 0.7, 0.99] {
+            let z = (2.0 * p - 1.0) / f64::sqrt(2.0 * p * (1.0 - p));
+            approx_eq(d.inv_cdf(p), 1.0 + 3.0 * z, 1e-12);
+        }
+        for nu in [0.5, 4.0, 30.0] {
+            let d = DistributionStudentT::new(nu, 0.0, 1.0).unwrap();
+            for p in [1e-6, 0.05, 0.3, 0.5, 0.8, 0.975] {
+                approx_eq(d.cdf(d.inv_cdf(p)), p, 1e-13);
+            }
+        }
+        assert_eq!(d.inv_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(d.inv_cdf(1.0), f64::INFINITY);
+        assert!(d.inv_cdf(1.1).is_nan());
+    }
+
+    #[test]
+    fn mean_and_variance_work() {
+        let d = DistributionStudentT::new(5.0, 3.0, 2.0).unwrap();
+        assert_eq!(d.mean(), 3.0);
+        approx_eq(d.variance(), 4.0 * 5.0 / 3.0, 1e-15);
+        let d = DistributionStudentT::new(2.0, 3.0, 2.0).unwrap();
+        assert_eq!(d.variance(), f64::INFINITY);
+        let d = DistributionStudentT::new(1.0, 3.0, 2.0).unwrap();
+        assert!(d.mean().is_nan());
+        assert!(d.variance().is_nan());
+    }
+
+    #[test]
+    fn sample_works() {
+        let d = DistributionStudentT::new(6.0, 10.0, 2.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        let n = 20_000;
+        let values: Vec<_> = (0..n).map(|_| d.sample(&mut rng)).collect();
+        let mean = values.iter().sum::<f64>() / (n as f64);
+        let below = values.iter().filter(|&&x| x <= 9.0).count() as f64 / (n as f64);
+        approx_eq(mean, 10.0, 0.05);
+        approx_eq(below, d.cdf(9.0), 0.01);
+    }
+}
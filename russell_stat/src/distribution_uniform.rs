@@ -52,6 +52,14 @@ impl ProbabilityDistribution for DistributionUniform {
         (x - self.xmin) / (self.xmax - self.xmin)
     }
 
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        self.xmin + p * (self.xmax - self.xmin)
+    }
+
     /// Returns the Mean
     fn mean(&self) -> f64 {
         (self.xmin + self.xmax) / 2.0
@@ -140,4 +148,14 @@ mod tests {
         approx_eq(x, 0.23691851694908816, 1e-15);
         approx_eq(y, 0.16964948689475423, 1e-15);
     }
+
+    #[test]
+    fn inv_cdf_works() {
+        let d = DistributionUniform::new(1.5, 2.5).unwrap();
+        assert_eq!(d.inv_cdf(0.0), 1.5);
+        assert_eq!(d.inv_cdf(0.5), 2.0);
+        assert_eq!(d.inv_cdf(1.0), 2.5);
+        assert!(d.inv_cdf(-0.1).is_nan());
+        assert!(d.inv_cdf(1.1).is_nan());
+    }
 }
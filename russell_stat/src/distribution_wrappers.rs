@@ -0,0 +1,330 @@
+use crate::{ProbabilityDistribution, StrError};
+use rand::Rng;
+
+/// Defines the distribution of `Y = X + c` (shifted random variable)
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{DistributionNormal, ProbabilityDistribution, Shifted, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let dist = Shifted::new(DistributionNormal::new(0.0, 1.0)?, 10.0);
+///     assert_eq!(dist.mean(), 10.0);
+///     assert_eq!(dist.cdf(10.0), 0.5);
+///     Ok(())
+/// }
+/// ```
+pub struct Shifted<D: ProbabilityDistribution> {
+    dist: D, // distribution of X
+    c: f64,  // shift
+}
+
+/// Defines the distribution of `Y = k X` (scaled random variable with k ≠ 0)
+///
+/// A negative factor also reflects the distribution (e.g., `k = -1` is the same as [Reflected]).
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{DistributionUniform, ProbabilityDistribution, Scaled, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let dist = Scaled::new(DistributionUniform::new(0.0, 1.0)?, 4.0)?;
+///     assert_eq!(dist.mean(), 2.0);
+///     assert_eq!(dist.pdf(1.0), 0.25);
+///     Ok(())
+/// }
+/// ```
+pub struct Scaled<D: ProbabilityDistribution> {
+    dist: D, // distribution of X
+    k: f64,  // scale factor
+}
+
+/// Defines the distribution of `Y = -X` (reflected random variable)
+///
+/// For instance, the minimum of a set of variables is the negative of the maximum of their negatives;
+/// thus, the (Type I) distribution of the smallest value is the reflected Gumbel distribution.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{DistributionGumbel, ProbabilityDistribution, Reflected, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let gumbel = DistributionGumbel::new(1.0, 2.0)?;
+///     let gumbel_min = Reflected::new(DistributionGumbel::new(1.0, 2.0)?);
+///     assert_eq!(gumbel_min.mean(), -gumbel.mean());
+///     assert_eq!(gumbel_min.cdf(-3.0), 1.0 - gumbel.cdf(3.0));
+///     Ok(())
+/// }
+/// ```
+pub struct Reflected<D: ProbabilityDistribution> {
+    dist: D, // distribution of X
+}
+
+impl<D: ProbabilityDistribution> Shifted<D> {
+    /// Creates a new shifted distribution
+    ///
+    /// # Input
+    ///
+    /// * `dist` -- distribution of X
+    /// * `c` -- shift
+    pub fn new(dist: D, c: f64) -> Self {
+        Shifted { dist, c }
+    }
+}
+
+impl<D: ProbabilityDistribution> Scaled<D> {
+    /// Creates a new scaled distribution
+    ///
+    /// # Input
+    ///
+    /// * `dist` -- distribution of X
+    /// * `k` -- scale factor (k ≠ 0)
+    pub fn new(dist: D, k: f64) -> Result<Self, StrError> {
+        if k == 0.0 || !k.is_finite() {
+            return Err("the scale factor must be finite and non-zero");
+        }
+        Ok(Scaled { dist, k })
+    }
+}
+
+impl<D: ProbabilityDistribution> Reflected<D> {
+    /// Creates a new reflected distribution
+    ///
+    /// # Input
+    ///
+    /// * `dist` -- distribution of X
+    pub fn new(dist: D) -> Self {
+        Reflected { dist }
+    }
+}
+
+impl<D: ProbabilityDistribution> ProbabilityDistribution for Shifted<D> {
+    /// Implements the Probability Density Function (CDF)
+    fn pdf(&self, x: f64) -> f64 {
+        self.dist.pdf(x - self.c)
+    }
+
+    /// Implements the Cumulative Density Function (CDF)
+    fn cdf(&self, x: f64) -> f64 {
+        self.dist.cdf(x - self.c)
+    }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inv_cdf(&self, p: f64) -> f64 {
+        self.dist.inv_cdf(p) + self.c
+    }
+
+    /// Maps `x` to the standard normal space
+    fn x_to_u(&self, x: f64) -> f64 {
+        self.dist.x_to_u(x - self.c)
+    }
+
+    /// Maps `u` from the standard normal space
+    fn u_to_x(&self, u: f64) -> f64 {
+        self.dist.u_to_x(u) + self.c
+    }
+
+    /// Returns the Mean
+    fn mean(&self) -> f64 {
+        self.dist.mean() + self.c
+    }
+
+    /// Returns the Variance
+    fn variance(&self) -> f64 {
+        self.dist.variance()
+    }
+
+    /// Generates a pseudo-random number belonging to this probability distribution
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.dist.sample(rng) + self.c
+    }
+}
+
+impl<D: ProbabilityDistribution> ProbabilityDistribution for Scaled<D> {
+    /// Implements the Probability Density Function (CDF)
+    fn pdf(&self, x: f64) -> f64 {
+        self.dist.pdf(x / self.k) / f64::abs(self.k)
+    }
+
+    /// Implements the Cumulative Density Function (CDF)
+    fn cdf(&self, x: f64) -> f64 {
+        if self.k > 0.0 {
+            self.dist.cdf(x / self.k)
+        } else {
+            1.0 - self.dist.cdf(x / self.k)
+        }
+    }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if self.k > 0.0 {
+            self.k * self.dist.inv_cdf(p)
+        } else {
+            self.k * self.dist.inv_cdf(1.0 - p)
+        }
+    }
+
+    /// Maps `x` to the standard normal space
+    fn x_to_u(&self, x: f64) -> f64 {
+        if self.k > 0.0 {
+            self.dist.x_to_u(x / self.k)
+        } else {
+            -self.dist.x_to_u(x / self.k)
+        }
+    }
+
+    /// Maps `u` from the standard normal space
+    fn u_to_x(&self, u: f64) -> f64 {
+        if self.k > 0.0 {
+            self.k * self.dist.u_to_x(u)
+        } else {
+            self.k * self.dist.u_to_x(-u)
+        }
+    }
+
+    /// Returns the Mean
+    fn mean(&self) -> f64 {
+        self.k * self.dist.mean()
+    }
+
+    /// Returns the Variance
+    fn variance(&self) -> f64 {
+        self.k * self.k * self.dist.variance()
+    }
+
+    /// Generates a pseudo-random number belonging to this probability distribution
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.k * self.dist.sample(rng)
+    }
+}
+
+impl<D: ProbabilityDistribution> ProbabilityDistribution for Reflected<D> {
+    /// Implements the Probability Density Function (CDF)
+    fn pdf(&self, x: f64) -> f64 {
+        self.dist.pdf(-x)
+    }
+
+    /// Implements the Cumulative Density Function (CDF)
+    fn cdf(&self, x: f64) -> f64 {
+        1.0 - self.dist.cdf(-x)
+    }
+
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    fn inv_cdf(&self, p: f64) -> f64 {
+        -self.dist.inv_cdf(1.0 - p)
+    }
+
+    /// Maps `x` to the standard normal space
+    fn x_to_u(&self, x: f64) -> f64 {
+        -self.dist.x_to_u(-x)
+    }
+
+    /// Maps `u` from the standard normal space
+    fn u_to_x(&self, u: f64) -> f64 {
+        -self.dist.u_to_x(-u)
+    }
+
+    /// Returns the Mean
+    fn mean(&self) -> f64 {
+        -self.dist.mean()
+    }
+
+    /// Returns the Variance
+    fn variance(&self) -> f64 {
+        self.dist.variance()
+    }
+
+    /// Generates a pseudo-random number belonging to this probability distribution
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        -self.dist.sample(rng)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{DistributionGumbel, DistributionNormal, ProbabilityDistribution, Reflected, Scaled, Shifted};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn scaled_handles_errors() {
+        let dist = DistributionNormal::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            Scaled::new(dist, 0.0).err(),
+            Some("the scale factor must be finite and non-zero")
+        );
+        let dist = DistributionNormal::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            Scaled::new(dist, f64::INFINITY).err(),
+            Some("the scale factor must be finite and non-zero")
+        );
+    }
+
+    #[test]
+    fn wrappers_match_normal_distribution() {
+        // N(μ, σ) shifted by c is N(μ + c, σ); scaled by k is N(k μ, |k| σ); reflected is N(-μ, σ)
+        let (mu, sig) = (1.0, 0.5);
+        let normal = || DistributionNormal::new(mu, sig).unwrap();
+        let shifted = Shifted::new(normal(), 2.0);
+        let scaled_pos = Scaled::new(normal(), 3.0).unwrap();
+        let scaled_neg = Scaled::new(normal(), -3.0).unwrap();
+        let reflected = Reflected::new(normal());
+        let cases: [(&dyn ProbabilityDistribution, DistributionNormal); 4] = [
+            (&shifted, DistributionNormal::new(mu + 2.0, sig).unwrap()),
+            (&scaled_pos, DistributionNormal::new(3.0 * mu, 3.0 * sig).unwrap()),
+            (&scaled_neg, DistributionNormal::new(-3.0 * mu, 3.0 * sig).unwrap()),
+            (&reflected, DistributionNormal::new(-mu, sig).unwrap()),
+        ];
+        for (dist, correct) in cases {
+            approx_eq(dist.mean(), correct.mean(), 1e-15);
+            approx_eq(dist.variance(), correct.variance(), 1e-15);
+            for x in [-5.0, -1.0, 0.0, 0.5, 2.0, 4.0] {
+                approx_eq(dist.pdf(x), correct.pdf(x), 1e-14);
+                approx_eq(dist.cdf(x), correct.cdf(x), 1e-14);
+            }
+            for p in [0.01, 0.3, 0.5, 0.9] {
+                approx_eq(dist.inv_cdf(p), correct.inv_cdf(p), 1e-13);
+            }
+            for x in [-5.0, 0.0, 2.0] {
+                approx_eq(dist.x_to_u(x), correct.x_to_u(x), 1e-14);
+            }
+            for u in [-2.0, 0.0, 1.5] {
+                approx_eq(dist.u_to_x(u), correct.u_to_x(u), 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn reflected_gumbel_works() {
+        // smallest value distribution: F(x) = 1 - exp(-exp((x - u) / β)) with u = -location
+        let (location, scale) = (1.0, 2.0);
+        let dist = Reflected::new(DistributionGumbel::new(location, scale).unwrap());
+        for x in [-6.0, -2.0, 0.0, 3.0] {
+            let z = (x + location) / scale;
+            approx_eq(dist.cdf(x), 1.0 - f64::exp(-f64::exp(z)), 1e-14);
+            approx_eq(dist.pdf(x), f64::exp(z) * f64::exp(-f64::exp(z)) / scale, 1e-14);
+            approx_eq(dist.inv_cdf(dist.cdf(x)), x, 1e-12);
+        }
+    }
+
+    #[test]
+    fn sample_works() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let x = DistributionNormal::new(1.0, 0.5).unwrap().sample(&mut rng);
+        let mut rng = StdRng::seed_from_u64(1234);
+        let dist = Shifted::new(
+            Scaled::new(DistributionNormal::new(1.0, 0.5).unwrap(), -2.0).unwrap(),
+            3.0,
+        );
+        approx_eq(dist.sample(&mut rng), 3.0 - 2.0 * x, 1e-15);
+        let mut rng = StdRng::seed_from_u64(1234);
+        let dist = Reflected::new(DistributionNormal::new(1.0, 0.5).unwrap());
+        approx_eq(dist.sample(&mut rng), -x, 1e-15);
+    }
+}
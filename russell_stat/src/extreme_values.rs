@@ -0,0 +1,152 @@
+use crate::{ProbabilityDistribution, StrError};
+
+/// Computes the return level (value exceeded, on average, once every `period` blocks)
+///
+/// For a distribution of block maxima (e.g., annual maxima), the return level `x_T` of the
+/// return period `T` (e.g., in years) is the value with probability `1/T` of being exceeded
+/// within one block:
+///
+/// ```text
+/// F(x_T) = 1 - 1/T
+/// ```
+///
+/// # Input
+///
+/// * `dist` -- the distribution of the block maxima (e.g., Gumbel or Frechet)
+/// * `period` -- the return period `T` in number of blocks (> 1)
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{return_level, return_period, DistributionGumbel, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // annual maximum wind speed (m/s)
+///     let dist = DistributionGumbel::new_from_mu_sig(25.0, 3.0)?;
+///     let x50 = return_level(&dist, 50.0)?;
+///     assert_eq!(format!("{:.2}", x50), "32.78");
+///     assert_eq!(format!("{:.1}", return_period(&dist, x50)), "50.0");
+///     Ok(())
+/// }
+/// ```
+pub fn return_level(dist: &dyn ProbabilityDistribution, period: f64) -> Result<f64, StrError> {
+    if period.is_nan() || period <= 1.0 {
+        return Err("period must be greater than one");
+    }
+    Ok(dist.inv_cdf(1.0 - 1.0 / period))
+}
+
+/// Computes the return period (mean number of blocks between exceedances) of a given value
+///
+/// ```text
+/// T(x) = 1 / (1 - F(x))
+/// ```
+///
+/// Returns infinity if the value cannot be exceeded.
+pub fn return_period(dist: &dyn ProbabilityDistribution, x: f64) -> f64 {
+    1.0 / (1.0 - dist.cdf(x))
+}
+
+/// Computes the characteristic value corresponding to a fractile
+///
+/// The characteristic value is the value with probability `fractile` of not being exceeded;
+/// e.g., the 98% fractile of the annual maxima for variable actions (50-year return period)
+/// and the 5% fractile for resistances:
+///
+/// ```text
+/// F(x_k) = fractile
+/// ```
+///
+/// # Input
+///
+/// * `dist` -- the distribution of the variable
+/// * `fractile` -- the probability of non-exceedance (0 < fractile < 1)
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{characteristic_value, DistributionNormal, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // concrete compressive strength (MPa)
+///     let dist = DistributionNormal::new(38.0, 5.0)?;
+///     let fck = characteristic_value(&dist, 0.05)?;
+///     assert_eq!(format!("{:.2}", fck), "29.78");
+///     Ok(())
+/// }
+/// ```
+pub fn characteristic_value(dist: &dyn ProbabilityDistribution, fractile: f64) -> Result<f64, StrError> {
+    if fractile.is_nan() || fractile <= 0.0 || fractile >= 1.0 {
+        return Err("fractile must satisfy 0 < fractile < 1");
+    }
+    Ok(dist.inv_cdf(fractile))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{characteristic_value, return_level, return_period};
+    use crate::{DistributionFrechet, DistributionGumbel, ProbabilityDistribution};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn functions_capture_errors() {
+        let dist = DistributionGumbel::new(0.0, 1.0).unwrap();
+        assert_eq!(return_level(&dist, 1.0).err(), Some("period must be greater than one"));
+        assert_eq!(
+            return_level(&dist, f64::NAN).err(),
+            Some("period must be greater than one")
+        );
+        assert_eq!(
+            characteristic_value(&dist, 1.0).err(),
+            Some("fractile must satisfy 0 < fractile < 1")
+        );
+        assert_eq!(
+            characteristic_value(&dist, 0.0).err(),
+            Some("fractile must satisfy 0 < fractile < 1")
+        );
+    }
+
+    #[test]
+    fn return_level_works() {
+        // Gumbel: x_T = u - β ln(-ln(1 - 1/T))
+        let (location, scale) = (10.0, 2.0);
+        let dist = DistributionGumbel::new(location, scale).unwrap();
+        for period in [2.0, 10.0, 50.0, 100.0, 1000.0] {
+            let correct = location - scale * f64::ln(-f64::ln(1.0 - 1.0 / period));
+            approx_eq(return_level(&dist, period).unwrap(), correct, 1e-12);
+        }
+
+        // Frechet
+        let dist = DistributionFrechet::new(0.0, 1.0, 3.0).unwrap();
+        for period in [2.0, 10.0, 100.0] {
+            let x = return_level(&dist, period).unwrap();
+            approx_eq(return_period(&dist, x), period, 1e-10);
+        }
+    }
+
+    #[test]
+    fn return_period_works() {
+        let dist = DistributionGumbel::new(0.0, 1.0).unwrap();
+        approx_eq(return_period(&dist, 0.0), 1.0 / (1.0 - f64::exp(-1.0)), 1e-15);
+        assert!(return_period(&dist, -10.0) < 1.0 + 1e-10);
+        let dist = DistributionFrechet::new(1.0, 1.0, 3.0).unwrap();
+        assert_eq!(return_period(&dist, 0.0), 1.0);
+    }
+
+    #[test]
+    fn characteristic_value_works() {
+        let dist = DistributionGumbel::new_from_mu_sig(10.0, 2.0).unwrap();
+        for fractile in [0.05, 0.5, 0.95, 0.98] {
+            let x = characteristic_value(&dist, fractile).unwrap();
+            approx_eq(dist.cdf(x), fractile, 1e-14);
+        }
+        // the 98% fractile of the annual maxima is the 50-year return level
+        approx_eq(
+            characteristic_value(&dist, 0.98).unwrap(),
+            return_level(&dist, 50.0).unwrap(),
+            1e-14,
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{ProbabilityDistribution, StrError};
+use russell_lab::{SolverNelderMead, Vector};
+
+/// Defines an observation, possibly censored, for the fitting of probability distributions
+///
+/// Censored observations are known only to be above (right-censored) or below (left-censored)
+/// a given value; e.g., the number of cycles of a fatigue test stopped before failure (run-out).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Observation {
+    /// Exactly observed value `x` (contributes with `ln f(x)` to the log-likelihood)
+    Exact(f64),
+
+    /// The true value is greater than `x` (contributes with `ln(1 - F(x))` to the log-likelihood)
+    RightCensored(f64),
+
+    /// The true value is smaller than `x` (contributes with `ln F(x)` to the log-likelihood)
+    LeftCensored(f64),
+}
+
+/// Holds the results of the maximum likelihood fitting
+pub struct MaximumLikelihoodFit<D: ProbabilityDistribution> {
+    /// The fitted distribution
+    pub distribution: D,
+
+    /// The parameters of the fitted distribution (as passed to the constructor)
+    pub parameters: Vec<f64>,
+
+    /// The maximum log-likelihood
+    pub log_likelihood: f64,
+}
+
+/// Computes the log-likelihood of a set of (possibly censored) observations
+///
+/// ```text
+/// ln L = Σ ln f(xᵢ) + Σ ln(1 - F(xⱼ)) + Σ ln F(xₖ)
+///      exact        right-censored    left-censored
+/// ```
+///
+/// Returns `-∞` if any observation is impossible under the given distribution.
+pub fn log_likelihood(dist: &dyn ProbabilityDistribution, data: &[Observation]) -> f64 {
+    data.iter()
+        .map(|obs| match *obs {
+            Observation::Exact(x) => f64::ln(dist.pdf(x)),
+            Observation::RightCensored(x) => f64::ln(1.0 - dist.cdf(x)),
+            Observation::LeftCensored(x) => f64::ln(dist.cdf(x)),
+        })
+        .sum()
+}
+
+/// Fits a probability distribution to (possibly censored) data by maximum likelihood
+///
+/// The log-likelihood (see [log_likelihood]) is maximized with respect to the parameters of the
+/// distribution using the Nelder-Mead method (see [SolverNelderMead]). The `make` function creates
+/// the distribution from a set of parameters; an error returned by `make` (e.g., a negative scale)
+/// marks the parameters as infeasible.
+///
+/// # Input
+///
+/// * `data` -- the observations
+/// * `initial` -- initial guess of the parameters (e.g., from the method of moments)
+/// * `make` -- function to create the distribution from the parameters
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{fit_maximum_likelihood, DistributionNormal, Observation, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // fatigue tests stopped at 4.0 (run-outs) are right-censored
+///     let data = [
+///         Observation::Exact(2.1),
+///         Observation::Exact(2.9),
+///         Observation::Exact(3.4),
+///         Observation::Exact(3.8),
+///         Observation::RightCensored(4.0),
+///         Observation::RightCensored(4.0),
+///     ];
+///     let fit = fit_maximum_likelihood(&data, &[3.0, 1.0], |p| DistributionNormal::new(p[0], p[1]))?;
+///     let mu = fit.parameters[0];
+///     assert!(mu > 3.55); // taking the run-outs as failures would give 3.37
+///     Ok(())
+/// }
+/// ```
+pub fn fit_maximum_likelihood<D, F>(
+    data: &[Observation],
+    initial: &[f64],
+    make: F,
+) -> Result<MaximumLikelihoodFit<D>, StrError>
+where
+    D: ProbabilityDistribution,
+    F: Fn(&[f64]) -> Result<D, StrError>,
+{
+    if data.is_empty() {
+        return Err("data must not be empty");
+    }
+    if initial.is_empty() {
+        return Err("at least one parameter is required");
+    }
+    let objective = |p: &Vector| match make(p.as_data()) {
+        Ok(dist) => {
+            let value = -log_likelihood(&dist, data);
+            Ok(if value.is_nan() { f64::INFINITY } else { value })
+        }
+        Err(_) => Ok(f64::INFINITY),
+    };
+    let mut p = Vector::from(&initial);
+    if !objective(&p)?.is_finite() {
+        return Err("the log-likelihood must be finite at the initial parameters");
+    }
+    let stats = SolverNelderMead::new()
+        .tolerances(1e-10, 1e-12)
+        .solve(&mut p, objective)?;
+    Ok(MaximumLikelihoodFit {
+        distribution: make(p.as_data())?,
+        parameters: p.as_data().clone(),
+        log_likelihood: -stats.f_min,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{fit_maximum_likelihood, log_likelihood, Observation};
+    use crate::{DistributionNormal, DistributionUniform, ProbabilityDistribution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn fit_maximum_likelihood_captures_errors() {
+        let make = |p: &[f64]| DistributionNormal::new(p[0], p[1]);
+        assert_eq!(
+            fit_maximum_likelihood(&[], &[0.0, 1.0], make).err(),
+            Some("data must not be empty")
+        );
+        let data = [Observation::Exact(1.0)];
+        assert_eq!(
+            fit_maximum_likelihood(&data, &[], make).err(),
+            Some("at least one parameter is required")
+        );
+        let make = |p: &[f64]| DistributionUniform::new(p[0], p[1]);
+        assert_eq!(
+            fit_maximum_likelihood(&data, &[2.0, 3.0], make).err(),
+            Some("the log-likelihood must be finite at the initial parameters")
+        );
+    }
+
+    #[test]
+    fn log_likelihood_works() {
+        let dist = DistributionUniform::new(0.0, 4.0).unwrap();
+        let data = [
+            Observation::Exact(1.0),
+            Observation::RightCensored(3.0),
+            Observation::LeftCensored(2.0),
+        ];
+        // ln(1/4) + ln(1/4) + ln(1/2)
+        approx_eq(log_likelihood(&dist, &data), f64::ln(1.0 / 32.0), 1e-15);
+        assert_eq!(log_likelihood(&dist, &[Observation::Exact(5.0)]), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn fit_maximum_likelihood_works_exact_data() {
+        // the maximum likelihood estimates of the normal distribution are known in closed form
+        let x = [9.1, 11.4, 10.2, 8.7, 12.3, 10.9, 9.8, 10.5];
+        let n = x.len() as f64;
+        let mu = x.iter().sum::<f64>() / n;
+        let sig = f64::sqrt(x.iter().map(|v| (v - mu) * (v - mu)).sum::<f64>() / n);
+        let data: Vec<_> = x.iter().map(|&v| Observation::Exact(v)).collect();
+        let fit = fit_maximum_likelihood(&data, &[10.0, 1.0], |p| DistributionNormal::new(p[0], p[1])).unwrap();
+        approx_eq(fit.parameters[0], mu, 1e-7);
+        approx_eq(fit.parameters[1], sig, 1e-7);
+        approx_eq(fit.distribution.mean(), mu, 1e-7);
+        approx_eq(
+            fit.log_likelihood,
+            log_likelihood(&DistributionNormal::new(mu, sig).unwrap(), &data),
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn fit_maximum_likelihood_works_censored_data() {
+        // samples of N(10, 2) with run-outs at 11 and detection limit at 7
+        let (mu, sig) = (10.0, 2.0);
+        let dist = DistributionNormal::new(mu, sig).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        let data: Vec<_> = (0..2000)
+            .map(|_| {
+                let x = dist.sample(&mut rng);
+                if x > 11.0 {
+                    Observation::RightCensored(11.0)
+                } else if x < 7.0 {
+                    Observation::LeftCensored(7.0)
+                } else {
+                    Observation::Exact(x)
+                }
+            })
+            .collect();
+        let make = |p: &[f64]| DistributionNormal::new(p[0], p[1]);
+        let fit = fit_maximum_likelihood(&data, &[9.0, 1.0], make).unwrap();
+        assert!(f64::abs(fit.parameters[0] - mu) < 0.1);
+        assert!(f64::abs(fit.parameters[1] - sig) < 0.1);
+
+        // treating the censored values as exact underestimates the dispersion
+        let naive: Vec<_> = data
+            .iter()
+            .map(|obs| match *obs {
+                Observation::RightCensored(x) | Observation::LeftCensored(x) => Observation::Exact(x),
+                other => other,
+            })
+            .collect();
+        let fit = fit_maximum_likelihood(&naive, &[9.0, 1.0], make).unwrap();
+        assert!(fit.parameters[1] < 1.6);
+    }
+}
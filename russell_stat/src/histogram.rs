@@ -88,6 +88,16 @@ where
         })
     }
 
+    /// Creates a new Histogram with given counts (frequencies)
+    pub(crate) fn from_counts(stations: &[T], counts: Vec<usize>) -> Result<Self, StrError> {
+        let mut hist = Histogram::new(stations)?;
+        if counts.len() != hist.counts.len() {
+            return Err("the number of counts must equal the number of bins");
+        }
+        hist.counts = counts;
+        Ok(hist)
+    }
+
     /// Counts how many items fall within each bin
     pub fn count(&mut self, data: &[T]) {
         for x in data {
@@ -123,29 +133,37 @@ where
 
     /// Finds which bin contains x
     fn find_bin(&self, x: T) -> Option<usize> {
-        // handle values outside range
-        let nstation = self.stations.len();
-        if x < self.stations[0] {
-            return None;
-        }
-        if x >= self.stations[nstation - 1] {
-            return None;
-        }
+        find_bin(&self.stations, x)
+    }
+}
 
-        // perform binary search
-        let mut upper = nstation;
-        let mut lower = 0;
-        let mut mid;
-        while upper - lower > 1 {
-            mid = (upper + lower) / 2;
-            if x >= self.stations[mid] {
-                lower = mid
-            } else {
-                upper = mid
-            }
+/// Finds which bin contains x (bin_i corresponds to station_i <= x < station_(i+1))
+pub(crate) fn find_bin<T>(stations: &[T], x: T) -> Option<usize>
+where
+    T: PartialOrd + Copy,
+{
+    // handle values outside range
+    let nstation = stations.len();
+    if x < stations[0] {
+        return None;
+    }
+    if x >= stations[nstation - 1] {
+        return None;
+    }
+
+    // perform binary search
+    let mut upper = nstation;
+    let mut lower = 0;
+    let mut mid;
+    while upper - lower > 1 {
+        mid = (upper + lower) / 2;
+        if x >= stations[mid] {
+            lower = mid
+        } else {
+            upper = mid
         }
-        Some(lower)
     }
+    Some(lower)
 }
 
 impl<T> fmt::Display for Histogram<T>
@@ -0,0 +1,206 @@
+use crate::histogram::find_bin;
+use crate::{Histogram, StrError};
+use num_traits::Num;
+
+/// Implements a two-dimensional Histogram to count the joint frequencies of pairs (x, y)
+///
+/// The bins are defined by the stations along x and y as in [Histogram]. The counts are
+/// stored such that `counts[i][j]` corresponds to
+///
+/// ```text
+/// station_x_i <= x < station_x_(i+1)  and  station_y_j <= y < station_y_(j+1)
+/// ```
+///
+/// Pairs with any coordinate outside the range of the stations are not counted. This structure
+/// is useful to inspect the dependence between two (simulated) random variables.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{Histogram2D, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = [0.5, 0.5, 1.5, 1.5, 1.5, 2.5];
+///     let y = [0.1, 0.2, 0.3, 1.4, 1.6, 1.9];
+///     let mut hist = Histogram2D::new(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 2.0])?;
+///     hist.count(&x, &y)?;
+///     assert_eq!(hist.get_counts(), &[vec![2, 0], vec![1, 2], vec![0, 1]]);
+///
+///     // distribution of x regardless of y
+///     assert_eq!(hist.marginal_x()?.get_counts(), &[2, 3, 1]);
+///
+///     // distribution of y given that 1 ≤ x < 2
+///     assert_eq!(hist.conditional_y(1)?.get_counts(), &[1, 2]);
+///     Ok(())
+/// }
+/// ```
+pub struct Histogram2D<T>
+where
+    T: Num + Copy,
+{
+    stations_x: Vec<T>,      // stations along x
+    stations_y: Vec<T>,      // stations along y
+    counts: Vec<Vec<usize>>, // (nbin_x, nbin_y) joint counts
+}
+
+impl<T> Histogram2D<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Creates a new two-dimensional Histogram
+    ///
+    /// # Input
+    ///
+    /// * `stations_x` -- stations along x (at least 2)
+    /// * `stations_y` -- stations along y (at least 2)
+    pub fn new(stations_x: &[T], stations_y: &[T]) -> Result<Self, StrError> {
+        if stations_x.len() < 2 || stations_y.len() < 2 {
+            return Err("histogram must have at least 2 stations along each direction");
+        }
+        Ok(Histogram2D {
+            stations_x: Vec::from(stations_x),
+            stations_y: Vec::from(stations_y),
+            counts: vec![vec![0; stations_y.len() - 1]; stations_x.len() - 1],
+        })
+    }
+
+    /// Counts how many pairs (x, y) fall within each bin
+    pub fn count(&mut self, x: &[T], y: &[T]) -> Result<(), StrError> {
+        if x.len() != y.len() {
+            return Err("x and y arrays must have the same length");
+        }
+        for k in 0..x.len() {
+            if let (Some(i), Some(j)) = (find_bin(&self.stations_x, x[k]), find_bin(&self.stations_y, y[k])) {
+                self.counts[i][j] += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Erase all counts
+    pub fn reset(&mut self) {
+        for row in &mut self.counts {
+            row.fill(0);
+        }
+    }
+
+    /// Returns a read-only access to the joint counts (frequencies)
+    pub fn get_counts(&self) -> &Vec<Vec<usize>> {
+        &self.counts
+    }
+
+    /// Returns the total number of counted pairs
+    pub fn total(&self) -> usize {
+        self.counts.iter().map(|row| row.iter().sum::<usize>()).sum()
+    }
+
+    /// Returns the joint relative frequencies (empirical joint probability mass of each bin)
+    ///
+    /// **Note:** All frequencies are zero if no pair has been counted.
+    pub fn get_frequencies(&self) -> Vec<Vec<f64>> {
+        let total = self.total();
+        let den = if total > 0 { total as f64 } else { 1.0 };
+        self.counts
+            .iter()
+            .map(|row| row.iter().map(|&c| (c as f64) / den).collect())
+            .collect()
+    }
+
+    /// Returns the marginal Histogram along x (the counts are summed over all y bins)
+    pub fn marginal_x(&self) -> Result<Histogram<T>, StrError> {
+        let counts = self.counts.iter().map(|row| row.iter().sum()).collect();
+        Histogram::from_counts(&self.stations_x, counts)
+    }
+
+    /// Returns the marginal Histogram along y (the counts are summed over all x bins)
+    pub fn marginal_y(&self) -> Result<Histogram<T>, StrError> {
+        let nbin_y = self.stations_y.len() - 1;
+        let counts = (0..nbin_y)
+            .map(|j| self.counts.iter().map(|row| row[j]).sum())
+            .collect();
+        Histogram::from_counts(&self.stations_y, counts)
+    }
+
+    /// Returns the Histogram of x conditioned on y being within the j-th bin along y
+    pub fn conditional_x(&self, j: usize) -> Result<Histogram<T>, StrError> {
+        if j >= self.stations_y.len() - 1 {
+            return Err("index of bin along y is out of range");
+        }
+        let counts = self.counts.iter().map(|row| row[j]).collect();
+        Histogram::from_counts(&self.stations_x, counts)
+    }
+
+    /// Returns the Histogram of y conditioned on x being within the i-th bin along x
+    pub fn conditional_y(&self, i: usize) -> Result<Histogram<T>, StrError> {
+        if i >= self.stations_x.len() - 1 {
+            return Err("index of bin along x is out of range");
+        }
+        Histogram::from_counts(&self.stations_y, self.counts[i].clone())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Histogram2D;
+
+    #[test]
+    fn new_fails_on_wrong_input() {
+        assert_eq!(
+            Histogram2D::<i32>::new(&[0], &[0, 1]).err(),
+            Some("histogram must have at least 2 stations along each direction")
+        );
+        assert_eq!(
+            Histogram2D::<i32>::new(&[0, 1], &[]).err(),
+            Some("histogram must have at least 2 stations along each direction")
+        );
+    }
+
+    #[test]
+    fn count_works() {
+        let mut hist = Histogram2D::new(&[0, 2, 4], &[0, 1, 2, 3]).unwrap();
+        assert_eq!(
+            hist.count(&[1], &[]).err(),
+            Some("x and y arrays must have the same length")
+        );
+        let x = [0, 1, 1, 3, 3, 3, 4, -1, 2];
+        let y = [0, 2, 2, 1, 2, 2, 0, 1, 3];
+        hist.count(&x, &y).unwrap();
+        assert_eq!(hist.get_counts(), &[vec![1, 0, 2], vec![0, 1, 2]]);
+        assert_eq!(hist.total(), 6);
+        hist.reset();
+        assert_eq!(hist.get_counts(), &[vec![0, 0, 0], vec![0, 0, 0]]);
+        assert_eq!(hist.total(), 0);
+        assert_eq!(hist.get_frequencies(), &[vec![0.0, 0.0, 0.0], vec![0.0, 0.0, 0.0]]);
+    }
+
+    #[test]
+    fn frequencies_marginals_and_conditionals_work() {
+        let mut hist = Histogram2D::new(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0, 3.0]).unwrap();
+        let x = [0.5, 0.5, 0.5, 1.5, 1.5, 1.5, 1.5, 1.5];
+        let y = [0.5, 1.5, 1.5, 0.5, 2.5, 2.5, 2.5, 2.5];
+        hist.count(&x, &y).unwrap();
+        assert_eq!(hist.get_frequencies(), &[vec![0.125, 0.25, 0.0], vec![0.125, 0.0, 0.5]]);
+        assert_eq!(hist.marginal_x().unwrap().get_counts(), &[3, 5]);
+        assert_eq!(hist.marginal_y().unwrap().get_counts(), &[2, 2, 4]);
+        assert_eq!(hist.conditional_x(0).unwrap().get_counts(), &[1, 1]);
+        assert_eq!(hist.conditional_x(2).unwrap().get_counts(), &[0, 4]);
+        assert_eq!(hist.conditional_y(0).unwrap().get_counts(), &[1, 2, 0]);
+        assert_eq!(hist.conditional_y(1).unwrap().get_counts(), &[1, 0, 4]);
+        assert_eq!(
+            hist.conditional_x(3).err(),
+            Some("index of bin along y is out of range")
+        );
+        assert_eq!(
+            hist.conditional_y(2).err(),
+            Some("index of bin along x is out of range")
+        );
+        assert_eq!(
+            format!("{}", hist.marginal_x().unwrap()),
+            "[0,1) | 3 🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦\n\
+             [1,2) | 5 🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦🟦\n\
+             \x20\x20sum = 8\n"
+        );
+    }
+}
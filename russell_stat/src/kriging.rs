@@ -0,0 +1,436 @@
+use crate::StrError;
+use russell_lab::{mat_cholesky, Matrix, SolverNelderMead, Vector};
+
+/// Specifies the correlation function (kernel) of the Gaussian process
+///
+/// The kernels depend on the scaled distance `h = √(Σ ((xₖ - x'ₖ) / θₖ)²)`, where `θₖ` are the length scales.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KrigingKernel {
+    /// Squared exponential (Gaussian) kernel `exp(-h²/2)` (infinitely differentiable)
+    SquaredExponential,
+
+    /// Exponential kernel `exp(-h)` (Matérn ν = ½; continuous but not differentiable)
+    Exponential,
+
+    /// Matérn ν = 3/2 kernel `(1 + √3 h) exp(-√3 h)` (once differentiable)
+    Matern32,
+
+    /// Matérn ν = 5/2 kernel `(1 + √5 h + 5h²/3) exp(-√5 h)` (twice differentiable)
+    Matern52,
+}
+
+impl KrigingKernel {
+    /// Computes the correlation given the scaled distance h
+    pub fn correlation(&self, h: f64) -> f64 {
+        match self {
+            KrigingKernel::SquaredExponential => f64::exp(-h * h / 2.0),
+            KrigingKernel::Exponential => f64::exp(-h),
+            KrigingKernel::Matern32 => {
+                let s = f64::sqrt(3.0) * h;
+                (1.0 + s) * f64::exp(-s)
+            }
+            KrigingKernel::Matern52 => {
+                let s = f64::sqrt(5.0) * h;
+                (1.0 + s + s * s / 3.0) * f64::exp(-s)
+            }
+        }
+    }
+}
+
+/// Implements Gaussian process regression (ordinary kriging) for building surrogate models
+///
+/// The response is modeled as `y(x) = μ + Z(x)`, where `μ` is an unknown constant and `Z` is a
+/// zero-mean Gaussian process with covariance `σ² R(x, x')`. Given the correlation matrix
+/// `R` of the training points (plus a small nugget on the diagonal), the generalized least squares
+/// estimates and the predictor are:
+///
+/// ```text
+///      1ᵀ R⁻¹ y             (y - μ 1)ᵀ R⁻¹ (y - μ 1)
+/// μ = ──────────      σ² = ──────────────────────────
+///     1ᵀ R⁻¹ 1                         n
+///
+/// ŷ(x) = μ + rᵀ R⁻¹ (y - μ 1)
+///
+///                                 (1 - 1ᵀ R⁻¹ r)²
+/// s²(x) = σ² ⋅ ( 1 - rᵀ R⁻¹ r + ───────────────── )
+///                                    1ᵀ R⁻¹ 1
+/// ```
+///
+/// where `r` holds the correlations between `x` and the training points. The length scales can be
+/// estimated by maximizing the concentrated log-likelihood `-n/2 ⋅ ln σ² - ½ ln |R|` (see [Kriging::optimize]).
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_stat::{Kriging, KrigingKernel, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // training data: y = sin(x)
+///     let x = Matrix::from(&[[0.0], [1.0], [2.0], [3.0], [4.0], [5.0]]);
+///     let y = Vector::from(&[0.0, 0.8415, 0.9093, 0.1411, -0.7568, -0.9589]);
+///     let mut kriging = Kriging::new(&x, &y, KrigingKernel::SquaredExponential)?;
+///     kriging.fit(&[1.5])?;
+///
+///     // the predictor interpolates the training data
+///     let (mean, variance) = kriging.predict(&[2.0])?;
+///     assert_eq!(format!("{:.4}", mean), "0.9093");
+///     assert!(variance < 1e-8);
+///     Ok(())
+/// }
+/// ```
+pub struct Kriging {
+    kernel: KrigingKernel,   // correlation function
+    points: Vec<Vec<f64>>,   // (n) training points (ndim each)
+    y: Vector,               // (n) training responses
+    nugget: f64,             // regularization of the correlation matrix
+    length_scales: Vec<f64>, // (ndim) length scales θ
+    l: Matrix,               // (n,n) Cholesky factor of R
+    r_inv_one: Vector,       // (n) R⁻¹ ⋅ 1
+    r_inv_residual: Vector,  // (n) R⁻¹ ⋅ (y - μ 1)
+    one_r_inv_one: f64,      // 1ᵀ ⋅ R⁻¹ ⋅ 1
+    mean: f64,               // μ
+    variance: f64,           // σ²
+    log_likelihood: f64,     // concentrated log-likelihood
+    fitted: bool,            // the hyperparameters have been set
+}
+
+impl Kriging {
+    /// Allocates a new instance
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (n,ndim) the training points (one per row; e.g., a design of experiments)
+    /// * `y` -- (n) the training responses
+    /// * `kernel` -- the correlation function
+    pub fn new(x: &Matrix, y: &Vector, kernel: KrigingKernel) -> Result<Self, StrError> {
+        let (n, ndim) = x.dims();
+        if n < 2 || ndim < 1 {
+            return Err("at least two training points with one or more dimensions are required");
+        }
+        if y.dim() != n {
+            return Err("y vector must have dimension equal to the number of training points");
+        }
+        Ok(Kriging {
+            kernel,
+            points: (0..n).map(|i| (0..ndim).map(|k| x.get(i, k)).collect()).collect(),
+            y: y.clone(),
+            nugget: 1e-10,
+            length_scales: vec![1.0; ndim],
+            l: Matrix::new(n, n),
+            r_inv_one: Vector::new(n),
+            r_inv_residual: Vector::new(n),
+            one_r_inv_one: 0.0,
+            mean: 0.0,
+            variance: 0.0,
+            log_likelihood: f64::NEG_INFINITY,
+            fitted: false,
+        })
+    }
+
+    /// Sets the nugget (relative regularization added to the diagonal of R; default 1e-10)
+    ///
+    /// A larger nugget turns the interpolation into a smoothing regression of noisy data.
+    ///
+    /// **Note:** The change is effective in the next call to [Kriging::fit] or [Kriging::optimize].
+    pub fn nugget(&mut self, value: f64) -> &mut Self {
+        self.nugget = value;
+        self
+    }
+
+    /// Computes the (generalized least squares) estimates with given length scales
+    ///
+    /// # Input
+    ///
+    /// * `length_scales` -- (ndim) the length scales θₖ > 0
+    pub fn fit(&mut self, length_scales: &[f64]) -> Result<(), StrError> {
+        if length_scales.len() != self.length_scales.len() {
+            return Err("length_scales must have length equal to the number of dimensions");
+        }
+        if length_scales.iter().any(|&t| t <= 0.0) {
+            return Err("length scales must be greater than zero");
+        }
+        if self.nugget < 0.0 {
+            return Err("the nugget must not be negative");
+        }
+        self.length_scales = length_scales.to_vec();
+        self.fitted = false;
+
+        // correlation matrix and Cholesky factorization
+        let n = self.y.dim();
+        let mut rr = Matrix::new(n, n);
+        for i in 0..n {
+            for j in 0..i {
+                let rij = self.correlation(&self.points[i], &self.points[j]);
+                rr.set(i, j, rij);
+                rr.set(j, i, rij);
+            }
+            rr.set(i, i, 1.0 + self.nugget);
+        }
+        mat_cholesky(&mut self.l, &rr)?;
+
+        // estimates
+        let one = Vector::filled(n, 1.0);
+        cholesky_solve(&mut self.r_inv_one, &self.l, &one);
+        self.one_r_inv_one = self.r_inv_one.as_data().iter().sum();
+        let y_r_inv_one: f64 = (0..n).map(|i| self.y[i] * self.r_inv_one[i]).sum();
+        self.mean = y_r_inv_one / self.one_r_inv_one;
+        let mut residual = self.y.clone();
+        for i in 0..n {
+            residual[i] -= self.mean;
+        }
+        cholesky_solve(&mut self.r_inv_residual, &self.l, &residual);
+        let quadratic: f64 = (0..n).map(|i| residual[i] * self.r_inv_residual[i]).sum();
+        self.variance = quadratic / (n as f64);
+        let log_det: f64 = (0..n).map(|i| 2.0 * f64::ln(self.l.get(i, i))).sum();
+        self.log_likelihood = -0.5 * (n as f64) * f64::ln(f64::max(self.variance, f64::MIN_POSITIVE)) - 0.5 * log_det;
+        self.fitted = true;
+        Ok(())
+    }
+
+    /// Estimates the length scales by maximizing the concentrated log-likelihood
+    ///
+    /// The search is performed on the logarithm of the length scales by the Nelder-Mead method
+    /// (see [SolverNelderMead]), starting at half of the range of each coordinate and restricted to
+    /// `[10⁻³, 10³]` times this range. Afterwards, the estimates are computed with the best length scales.
+    ///
+    /// Returns the maximum log-likelihood
+    pub fn optimize(&mut self) -> Result<f64, StrError> {
+        let ndim = self.length_scales.len();
+        let mut ranges = vec![0.0; ndim];
+        for (k, range) in ranges.iter_mut().enumerate() {
+            let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+            for point in &self.points {
+                min = f64::min(min, point[k]);
+                max = f64::max(max, point[k]);
+            }
+            *range = if max > min { max - min } else { 1.0 };
+        }
+        let mut log_theta = Vector::from(&ranges.iter().map(|r| f64::ln(r / 2.0)).collect::<Vec<_>>());
+        let mut theta = vec![0.0; ndim];
+        let mut solver = SolverNelderMead::new();
+        solver.initial_step(0.5).tolerances(1e-4, 1e-8).max_iterations(500);
+        solver.solve(&mut log_theta, |log_theta| {
+            for k in 0..ndim {
+                let ratio = f64::exp(log_theta[k]) / ranges[k];
+                if !(1e-3..=1e3).contains(&ratio) {
+                    return Ok(f64::INFINITY);
+                }
+                theta[k] = f64::exp(log_theta[k]);
+            }
+            match self.fit(&theta) {
+                Ok(()) => Ok(-self.log_likelihood),
+                Err(_) => Ok(f64::INFINITY), // R is numerically singular
+            }
+        })?;
+        for k in 0..ndim {
+            theta[k] = f64::exp(log_theta[k]);
+        }
+        self.fit(&theta)?;
+        Ok(self.log_likelihood)
+    }
+
+    /// Predicts the response at a point x
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (ndim) the point
+    ///
+    /// # Output
+    ///
+    /// Returns the predictive `(mean, variance)`
+    pub fn predict(&self, x: &[f64]) -> Result<(f64, f64), StrError> {
+        if !self.fitted {
+            return Err("fit or optimize must be called first");
+        }
+        if x.len() != self.length_scales.len() {
+            return Err("x must have length equal to the number of dimensions");
+        }
+        let n = self.y.dim();
+        let r = Vector::from(&self.points.iter().map(|p| self.correlation(x, p)).collect::<Vec<_>>());
+        let mut r_inv_r = Vector::new(n);
+        cholesky_solve(&mut r_inv_r, &self.l, &r);
+        let mut mean = self.mean;
+        let mut r_r_inv_r = 0.0;
+        let mut one_r_inv_r = 0.0;
+        for i in 0..n {
+            mean += r[i] * self.r_inv_residual[i];
+            r_r_inv_r += r[i] * r_inv_r[i];
+            one_r_inv_r += r_inv_r[i];
+        }
+        let u = 1.0 - one_r_inv_r;
+        let variance = self.variance * (1.0 + self.nugget - r_r_inv_r + u * u / self.one_r_inv_one);
+        Ok((mean, f64::max(variance, 0.0)))
+    }
+
+    /// Returns the length scales θ
+    pub fn length_scales(&self) -> &[f64] {
+        &self.length_scales
+    }
+
+    /// Returns the estimated constant mean μ
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the estimated process variance σ²
+    pub fn variance(&self) -> f64 {
+        self.variance
+    }
+
+    /// Returns the concentrated log-likelihood of the last fit
+    pub fn log_likelihood(&self) -> f64 {
+        self.log_likelihood
+    }
+
+    /// Computes the correlation between two points
+    fn correlation(&self, a: &[f64], b: &[f64]) -> f64 {
+        let h2: f64 = a
+            .iter()
+            .zip(b)
+            .zip(&self.length_scales)
+            .map(|((ak, bk), t)| f64::powi((ak - bk) / t, 2))
+            .sum();
+        self.kernel.correlation(f64::sqrt(h2))
+    }
+}
+
+/// Solves `l ⋅ lᵀ ⋅ x = b` given the lower triangular Cholesky factor `l`
+fn cholesky_solve(x: &mut Vector, l: &Matrix, b: &Vector) {
+    let n = b.dim();
+    for i in 0..n {
+        let mut sum = b[i];
+        for k in 0..i {
+            sum -= l.get(i, k) * x[k];
+        }
+        x[i] = sum / l.get(i, i);
+    }
+    for i in (0..n).rev() {
+        let mut sum = x[i];
+        for k in (i + 1)..n {
+            sum -= l.get(k, i) * x[k];
+        }
+        x[i] = sum / l.get(i, i);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Kriging, KrigingKernel};
+    use crate::doe_full_factorial;
+    use russell_chk::approx_eq;
+    use russell_lab::{Matrix, Vector};
+
+    #[test]
+    fn kernels_work() {
+        for kernel in [
+            KrigingKernel::SquaredExponential,
+            KrigingKernel::Exponential,
+            KrigingKernel::Matern32,
+            KrigingKernel::Matern52,
+        ] {
+            assert_eq!(kernel.correlation(0.0), 1.0);
+            assert!(kernel.correlation(1.0) < 1.0);
+            assert!(kernel.correlation(1.0) > kernel.correlation(2.0));
+        }
+        approx_eq(
+            KrigingKernel::SquaredExponential.correlation(2.0),
+            f64::exp(-2.0),
+            1e-15,
+        );
+        approx_eq(KrigingKernel::Exponential.correlation(2.0), f64::exp(-2.0), 1e-15);
+    }
+
+    #[test]
+    fn kriging_captures_errors() {
+        let x = Matrix::from(&[[0.0], [1.0]]);
+        let y = Vector::from(&[0.0, 1.0]);
+        let kernel = KrigingKernel::Matern52;
+        assert_eq!(
+            Kriging::new(&Matrix::new(1, 1), &Vector::new(1), kernel).err(),
+            Some("at least two training points with one or more dimensions are required")
+        );
+        assert_eq!(
+            Kriging::new(&x, &Vector::new(3), kernel).err(),
+            Some("y vector must have dimension equal to the number of training points")
+        );
+        let mut kriging = Kriging::new(&x, &y, kernel).unwrap();
+        assert_eq!(
+            kriging.predict(&[0.5]).err(),
+            Some("fit or optimize must be called first")
+        );
+        assert_eq!(
+            kriging.fit(&[1.0, 1.0]).err(),
+            Some("length_scales must have length equal to the number of dimensions")
+        );
+        assert_eq!(
+            kriging.fit(&[0.0]).err(),
+            Some("length scales must be greater than zero")
+        );
+        assert_eq!(
+            kriging.nugget(-1.0).fit(&[1.0]).err(),
+            Some("the nugget must not be negative")
+        );
+        kriging.nugget(0.0).fit(&[1.0]).unwrap();
+        assert_eq!(
+            kriging.predict(&[0.5, 0.5]).err(),
+            Some("x must have length equal to the number of dimensions")
+        );
+    }
+
+    #[test]
+    fn fit_and_predict_work() {
+        // two points: closed-form expressions
+        let x = Matrix::from(&[[0.0], [1.0]]);
+        let y = Vector::from(&[1.0, 3.0]);
+        let mut kriging = Kriging::new(&x, &y, KrigingKernel::Exponential).unwrap();
+        kriging.nugget(0.0).fit(&[1.0]).unwrap();
+        let rho = f64::exp(-1.0);
+        approx_eq(kriging.mean(), 2.0, 1e-14);
+        approx_eq(kriging.variance(), 1.0 / (1.0 - rho), 1e-14);
+        approx_eq(
+            kriging.log_likelihood(),
+            f64::ln(1.0 - rho) - 0.5 * f64::ln(1.0 - rho * rho),
+            1e-14,
+        );
+        for (xi, yi) in [(0.0, 1.0), (1.0, 3.0)] {
+            let (mean, variance) = kriging.predict(&[xi]).unwrap();
+            approx_eq(mean, yi, 1e-14);
+            approx_eq(variance, 0.0, 1e-14);
+        }
+        // far away, the prediction reverts to the mean and the variance grows
+        let (mean, variance) = kriging.predict(&[100.0]).unwrap();
+        approx_eq(mean, 2.0, 1e-14);
+        assert!(variance > kriging.variance());
+    }
+
+    #[test]
+    fn optimize_works() {
+        // y = sin(x₀) + cos(x₁) on a 5 × 5 grid on [-2, 2]²
+        let mut x = doe_full_factorial(&[5, 5]).unwrap();
+        let n = x.nrow();
+        for i in 0..n {
+            for k in 0..2 {
+                x.set(i, k, 2.0 * x.get(i, k));
+            }
+        }
+        let model = |p: [f64; 2]| f64::sin(p[0]) + f64::cos(p[1]);
+        let y = Vector::from(&(0..n).map(|i| model([x.get(i, 0), x.get(i, 1)])).collect::<Vec<_>>());
+        let mut kriging = Kriging::new(&x, &y, KrigingKernel::SquaredExponential).unwrap();
+        kriging.fit(&[2.0, 2.0]).unwrap();
+        let initial_likelihood = kriging.log_likelihood();
+        let max_likelihood = kriging.optimize().unwrap();
+        assert!(max_likelihood >= initial_likelihood);
+        assert_eq!(max_likelihood, kriging.log_likelihood());
+        assert!(kriging.length_scales().iter().all(|&t| t > 0.1 && t < 10.0));
+
+        // accurate surrogate between the training points
+        for p in [[0.5, 0.5], [-1.5, 0.3], [1.2, -1.7]] {
+            let (mean, variance) = kriging.predict(&p).unwrap();
+            assert!(f64::abs(mean - model(p)) < 0.02);
+            assert!(variance > 0.0 && f64::sqrt(variance) < 0.05);
+        }
+    }
+}
@@ -5,22 +5,73 @@
 /// Defines a type alias for the error type as a static string
 pub type StrError = &'static str;
 
+/// Defines a typed error with context data (convertible from/into StrError)
+pub use russell_lab::RussellError;
+
+mod anova;
+mod covariance;
+mod design_of_experiments;
+mod distribution_fisher_f;
 mod distribution_frechet;
+mod distribution_gen_pareto;
 mod distribution_gumbel;
 mod distribution_lognormal;
 mod distribution_normal;
+mod distribution_params;
+mod distribution_stable;
+mod distribution_student_t;
 mod distribution_uniform;
+mod distribution_wrappers;
+mod extreme_values;
+mod fitting;
 mod histogram;
+mod histogram_2d;
+mod kriging;
+mod markov_chain;
+mod monte_carlo;
+mod online_quantile;
+mod online_statistics;
+mod peaks_over_threshold;
+mod polynomial_chaos;
 mod probability_distribution;
+#[cfg(feature = "python")]
+pub mod python;
+mod quasi_random;
+mod sensitivity;
 mod statistics;
+mod two_sample_tests;
+mod weighted_statistics;
+pub use crate::anova::*;
+pub use crate::covariance::*;
+pub use crate::design_of_experiments::*;
+pub use crate::distribution_fisher_f::*;
 pub use crate::distribution_frechet::*;
+pub use crate::distribution_gen_pareto::*;
 pub use crate::distribution_gumbel::*;
 pub use crate::distribution_lognormal::*;
 pub use crate::distribution_normal::*;
+pub use crate::distribution_params::*;
+pub use crate::distribution_stable::*;
+pub use crate::distribution_student_t::*;
 pub use crate::distribution_uniform::*;
+pub use crate::distribution_wrappers::*;
+pub use crate::extreme_values::*;
+pub use crate::fitting::*;
 pub use crate::histogram::*;
+pub use crate::histogram_2d::*;
+pub use crate::kriging::*;
+pub use crate::markov_chain::*;
+pub use crate::monte_carlo::*;
+pub use crate::online_quantile::*;
+pub use crate::online_statistics::*;
+pub use crate::peaks_over_threshold::*;
+pub use crate::polynomial_chaos::*;
 pub use crate::probability_distribution::*;
+pub use crate::quasi_random::*;
+pub use crate::sensitivity::*;
 pub use crate::statistics::*;
+pub use crate::two_sample_tests::*;
+pub use crate::weighted_statistics::*;
 
 // run code from README file
 #[cfg(doctest)]
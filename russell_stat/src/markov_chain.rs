@@ -0,0 +1,317 @@
+use crate::StrError;
+use rand::Rng;
+use russell_lab::{mat_eigen, mat_mat_mul, vec_mat_mul, Matrix, Vector};
+
+/// Defines the tolerance to check that the rows of the transition matrix sum to one
+const MARKOV_CHAIN_TOL_ROW_SUM: f64 = 1e-10;
+
+/// Implements a discrete-time Markov chain with a finite number of states
+///
+/// The transition matrix holds the probability of moving from state `i` to state `j` in one step:
+///
+/// ```text
+/// Pᵢⱼ = P(Xₖ₊₁ = j | Xₖ = i)    with    Σⱼ Pᵢⱼ = 1
+/// ```
+///
+/// For instance, the states may represent the condition ratings of a structural component
+/// subject to degradation, with one step per inspection interval.
+///
+/// # Example
+///
+/// ```
+/// use russell_lab::{Matrix, Vector};
+/// use russell_stat::{MarkovChain, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // condition states: good, fair, poor (absorbing)
+///     let chain = MarkovChain::new(Matrix::from(&[
+///         [0.9, 0.1, 0.0],
+///         [0.0, 0.8, 0.2],
+///         [0.0, 0.0, 1.0],
+///     ]))?;
+///     let p = chain.distribution_after(&Vector::from(&[1.0, 0.0, 0.0]), 2)?;
+///     assert_eq!(
+///         format!("{:.2}", p),
+///         "┌      ┐\n\
+///          │ 0.81 │\n\
+///          │ 0.17 │\n\
+///          │ 0.02 │\n\
+///          └      ┘"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct MarkovChain {
+    transition: Matrix, // (nstate,nstate) transition matrix
+}
+
+impl MarkovChain {
+    /// Creates a new Markov chain
+    ///
+    /// # Input
+    ///
+    /// * `transition` -- (nstate,nstate) transition matrix with non-negative entries and rows summing to one
+    pub fn new(transition: Matrix) -> Result<Self, StrError> {
+        let (m, n) = transition.dims();
+        if m != n || m < 1 {
+            return Err("transition matrix must be square and non-empty");
+        }
+        for i in 0..n {
+            let mut sum = 0.0;
+            for j in 0..n {
+                let p = transition.get(i, j);
+                if !(0.0..=1.0).contains(&p) {
+                    return Err("transition probabilities must be in [0, 1]");
+                }
+                sum += p;
+            }
+            if f64::abs(sum - 1.0) > MARKOV_CHAIN_TOL_ROW_SUM {
+                return Err("the rows of the transition matrix must sum to one");
+            }
+        }
+        Ok(MarkovChain { transition })
+    }
+
+    /// Returns the number of states
+    pub fn nstate(&self) -> usize {
+        self.transition.nrow()
+    }
+
+    /// Returns the transition matrix
+    pub fn transition(&self) -> &Matrix {
+        &self.transition
+    }
+
+    /// Computes the n-step transition matrix `Pⁿ`
+    ///
+    /// The entry `(i, j)` of the result is the probability of reaching state `j` after `n` steps
+    /// starting from state `i`. The power is computed by repeated squaring.
+    pub fn n_step(&self, n: usize) -> Result<Matrix, StrError> {
+        let m = self.nstate();
+        let mut result = Matrix::identity(m);
+        let mut power = self.transition.clone();
+        let mut temp = Matrix::new(m, m);
+        let mut k = n;
+        while k > 0 {
+            if k % 2 == 1 {
+                mat_mat_mul(&mut temp, 1.0, &result, &power)?;
+                std::mem::swap(&mut result, &mut temp);
+            }
+            k /= 2;
+            if k > 0 {
+                mat_mat_mul(&mut temp, 1.0, &power, &power)?;
+                std::mem::swap(&mut power, &mut temp);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Computes the probabilities of the states after n steps
+    ///
+    /// ```text
+    /// pₙ = p₀ Pⁿ
+    /// ```
+    ///
+    /// # Input
+    ///
+    /// * `initial` -- (nstate) probabilities of the states at the initial step
+    /// * `n` -- number of steps
+    pub fn distribution_after(&self, initial: &Vector, n: usize) -> Result<Vector, StrError> {
+        if initial.dim() != self.nstate() {
+            return Err("initial vector must have length equal to nstate");
+        }
+        let mut p = initial.clone();
+        let mut next = Vector::new(self.nstate());
+        for _ in 0..n {
+            vec_mat_mul(&mut next, 1.0, &p, &self.transition)?;
+            std::mem::swap(&mut p, &mut next);
+        }
+        Ok(p)
+    }
+
+    /// Computes the stationary distribution
+    ///
+    /// The stationary distribution `π` satisfies `π P = π` with `Σ πᵢ = 1`; i.e., it is the left
+    /// eigenvector of `P` corresponding to the unit eigenvalue (computed by [mat_eigen]).
+    ///
+    /// **Note:** The stationary distribution is unique if the chain is irreducible. Otherwise, e.g.,
+    /// with more than one absorbing state, one of the stationary distributions is returned.
+    pub fn stationary(&self) -> Result<Vector, StrError> {
+        let n = self.nstate();
+        let mut a = Matrix::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                a.set(i, j, self.transition.get(j, i));
+            }
+        }
+        let mut l_real = Vector::new(n);
+        let mut l_imag = Vector::new(n);
+        let mut v_real = Matrix::new(n, n);
+        let mut v_imag = Matrix::new(n, n);
+        mat_eigen(&mut l_real, &mut l_imag, &mut v_real, &mut v_imag, &mut a)?;
+        let k = (0..n)
+            .min_by(|&a, &b| {
+                let da = f64::hypot(l_real[a] - 1.0, l_imag[a]);
+                let db = f64::hypot(l_real[b] - 1.0, l_imag[b]);
+                da.total_cmp(&db)
+            })
+            .unwrap(); // n ≥ 1
+        let sum: f64 = (0..n).map(|i| v_real.get(i, k)).sum();
+        if sum == 0.0 {
+            return Err("cannot normalize the stationary distribution");
+        }
+        Ok(Vector::from(
+            &(0..n).map(|i| v_real.get(i, k) / sum).collect::<Vec<_>>(),
+        ))
+    }
+
+    /// Generates a sequence of states by Monte Carlo simulation
+    ///
+    /// # Input
+    ///
+    /// * `initial_state` -- the state at the initial step (< nstate)
+    /// * `nstep` -- number of steps
+    /// * `rng` -- random number generator
+    ///
+    /// # Output
+    ///
+    /// Returns the (nstep + 1) states, including the initial state
+    pub fn sample_path<R: Rng + ?Sized>(
+        &self,
+        initial_state: usize,
+        nstep: usize,
+        rng: &mut R,
+    ) -> Result<Vec<usize>, StrError> {
+        let n = self.nstate();
+        if initial_state >= n {
+            return Err("initial_state must be smaller than nstate");
+        }
+        let mut path = Vec::with_capacity(nstep + 1);
+        let mut state = initial_state;
+        path.push(state);
+        for _ in 0..nstep {
+            let u: f64 = rng.gen();
+            let mut cumulative = 0.0;
+            let mut next = n - 1; // fallback due to round-off
+            for j in 0..n {
+                cumulative += self.transition.get(state, j);
+                if u < cumulative {
+                    next = j;
+                    break;
+                }
+            }
+            state = next;
+            path.push(state);
+        }
+        Ok(path)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::MarkovChain;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::{approx_eq, vec_approx_eq};
+    use russell_lab::{Matrix, Vector};
+
+    fn two_state(a: f64, b: f64) -> MarkovChain {
+        MarkovChain::new(Matrix::from(&[[1.0 - a, a], [b, 1.0 - b]])).unwrap()
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            MarkovChain::new(Matrix::new(2, 3)).err(),
+            Some("transition matrix must be square and non-empty")
+        );
+        assert_eq!(
+            MarkovChain::new(Matrix::from(&[[1.5, -0.5], [0.5, 0.5]])).err(),
+            Some("transition probabilities must be in [0, 1]")
+        );
+        assert_eq!(
+            MarkovChain::new(Matrix::from(&[[0.5, 0.4], [0.5, 0.5]])).err(),
+            Some("the rows of the transition matrix must sum to one")
+        );
+        let chain = two_state(0.1, 0.2);
+        assert_eq!(
+            chain.distribution_after(&Vector::new(3), 1).err(),
+            Some("initial vector must have length equal to nstate")
+        );
+        let mut rng = StdRng::seed_from_u64(1234);
+        assert_eq!(
+            chain.sample_path(2, 1, &mut rng).err(),
+            Some("initial_state must be smaller than nstate")
+        );
+    }
+
+    #[test]
+    fn n_step_works() {
+        // Pⁿ = (1/(a+b)) [[b, a], [b, a]] + ((1-a-b)ⁿ/(a+b)) [[a, -a], [-b, b]]
+        let (a, b) = (0.3, 0.1);
+        let chain = two_state(a, b);
+        assert_eq!(chain.nstate(), 2);
+        assert_eq!(chain.transition().get(0, 1), a);
+        assert_eq!(chain.n_step(0).unwrap().as_data(), Matrix::identity(2).as_data());
+        for n in [1, 2, 5, 16, 33] {
+            let r = f64::powi(1.0 - a - b, n as i32);
+            let correct = [
+                (b + r * a) / (a + b),
+                (b - r * b) / (a + b),
+                (a - r * a) / (a + b),
+                (a + r * b) / (a + b),
+            ];
+            vec_approx_eq(chain.n_step(n).unwrap().as_data(), &correct, 1e-14);
+        }
+    }
+
+    #[test]
+    fn distribution_after_works() {
+        let chain = two_state(0.3, 0.1);
+        let initial = Vector::from(&[0.2, 0.8]);
+        let p = chain.distribution_after(&initial, 7).unwrap();
+        let pn = chain.n_step(7).unwrap();
+        approx_eq(p[0], 0.2 * pn.get(0, 0) + 0.8 * pn.get(1, 0), 1e-15);
+        approx_eq(p[1], 0.2 * pn.get(0, 1) + 0.8 * pn.get(1, 1), 1e-15);
+        assert_eq!(
+            chain.distribution_after(&initial, 0).unwrap().as_data(),
+            initial.as_data()
+        );
+    }
+
+    #[test]
+    fn stationary_works() {
+        let (a, b) = (0.3, 0.1);
+        let pi = two_state(a, b).stationary().unwrap();
+        vec_approx_eq(pi.as_data(), &[b / (a + b), a / (a + b)], 1e-14);
+
+        // weather example: sunny, cloudy, rainy
+        let chain = MarkovChain::new(Matrix::from(&[[0.6, 0.3, 0.1], [0.3, 0.4, 0.3], [0.2, 0.3, 0.5]])).unwrap();
+        let pi = chain.stationary().unwrap();
+        let next = chain.distribution_after(&pi, 1).unwrap();
+        vec_approx_eq(next.as_data(), pi.as_data(), 1e-14);
+        approx_eq(pi.as_data().iter().sum(), 1.0, 1e-15);
+
+        // absorbing state
+        let chain = MarkovChain::new(Matrix::from(&[[0.9, 0.1], [0.0, 1.0]])).unwrap();
+        vec_approx_eq(chain.stationary().unwrap().as_data(), &[0.0, 1.0], 1e-14);
+    }
+
+    #[test]
+    fn sample_path_works() {
+        let (a, b) = (0.3, 0.1);
+        let chain = two_state(a, b);
+        let mut rng = StdRng::seed_from_u64(1234);
+        let path = chain.sample_path(0, 20_000, &mut rng).unwrap();
+        assert_eq!(path.len(), 20_001);
+        assert_eq!(path[0], 0);
+        let frequency = path.iter().filter(|&&s| s == 1).count() as f64 / (path.len() as f64);
+        approx_eq(frequency, a / (a + b), 0.02);
+
+        // absorbing state
+        let chain = MarkovChain::new(Matrix::from(&[[0.0, 1.0], [0.0, 1.0]])).unwrap();
+        assert_eq!(chain.sample_path(0, 3, &mut rng).unwrap(), &[0, 1, 1, 1]);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{OnlineStats, StrError};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Number of samples generated with the same stream of pseudo-random numbers
+///
+/// **Note:** The blocks of samples do not depend on the number of threads; thus, the results are reproducible.
+const SAMPLES_PER_STREAM: usize = 1024;
+
+/// Returns an independent stream of pseudo-random numbers
+///
+/// The seed of the stream is obtained by mixing `seed` and `index` with the SplitMix64 function.
+/// Thus, streams with different indices are (statistically) independent and each stream is
+/// fully determined by `(seed, index)`, regardless of which thread uses it.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use russell_stat::rng_stream;
+///
+/// let mut rng_0 = rng_stream(1234, 0);
+/// let mut rng_1 = rng_stream(1234, 1);
+/// let x: f64 = rng_0.gen();
+/// let y: f64 = rng_1.gen();
+/// assert_ne!(x, y);
+/// assert_eq!(rng_stream(1234, 1).gen::<f64>(), y);
+/// ```
+pub fn rng_stream(seed: u64, index: u64) -> StdRng {
+    let mut z = seed ^ index.wrapping_add(1).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    StdRng::seed_from_u64(z ^ (z >> 31))
+}
+
+/// Runs a Monte Carlo simulation and accumulates the statistics of the outputs
+///
+/// The samples are split into blocks of fixed size and each block uses its own stream of
+/// pseudo-random numbers given by [rng_stream] with the block index. The statistics of each
+/// block are accumulated in an [OnlineStats] and the blocks are merged in the order of their
+/// indices. Therefore, the results are identical for any number of threads.
+///
+/// With the `rayon` feature, the blocks are computed in parallel.
+///
+/// # Input
+///
+/// * `ndim` -- the number of outputs of the model (≥ 1)
+/// * `nsample` -- the number of samples (≥ 1)
+/// * `seed` -- the seed of the pseudo-random numbers
+/// * `model` -- the function `model(y, rng)` that computes the `ndim` outputs `y` of one sample
+///   using the generator `rng` to draw the random inputs
+///
+/// **Note:** If the model fails, the error of the first failing block (by index) is returned.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use russell_stat::{monte_carlo, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // estimate π with the fraction of points of the unit square within the quarter circle
+///     let stats = monte_carlo(1, 100_000, 1234, |y, rng| {
+///         let (u, v): (f64, f64) = (rng.gen(), rng.gen());
+///         y[0] = if u * u + v * v < 1.0 { 4.0 } else { 0.0 };
+///         Ok(())
+///     })?;
+///     assert_eq!(stats.count(), 100_000);
+///     assert!(f64::abs(stats.mean()[0] - std::f64::consts::PI) < 0.05);
+///     Ok(())
+/// }
+/// ```
+pub fn monte_carlo<F>(ndim: usize, nsample: usize, seed: u64, model: F) -> Result<OnlineStats, StrError>
+where
+    F: Fn(&mut [f64], &mut StdRng) -> Result<(), StrError> + Sync,
+{
+    if ndim < 1 {
+        return Err("ndim must be at least 1");
+    }
+    if nsample < 1 {
+        return Err("nsample must be at least 1");
+    }
+
+    // runs the samples of one block
+    let run_block = |block: usize| -> Result<OnlineStats, StrError> {
+        let start = block * SAMPLES_PER_STREAM;
+        let end = usize::min(start + SAMPLES_PER_STREAM, nsample);
+        let mut rng = rng_stream(seed, block as u64);
+        let mut stats = OnlineStats::new(ndim)?;
+        let mut y = vec![0.0; ndim];
+        for _ in start..end {
+            model(&mut y, &mut rng)?;
+            stats.push(&y)?;
+        }
+        Ok(stats)
+    };
+
+    // compute all blocks
+    let nblock = nsample.div_ceil(SAMPLES_PER_STREAM);
+    #[cfg(feature = "rayon")]
+    let blocks: Vec<_> = (0..nblock).into_par_iter().map(run_block).collect();
+    #[cfg(not(feature = "rayon"))]
+    let blocks: Vec<_> = (0..nblock).map(run_block).collect();
+
+    // merge the results in the order of the blocks
+    let mut stats = OnlineStats::new(ndim)?;
+    for block in blocks {
+        stats.merge(&block?)?;
+    }
+    Ok(stats)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{monte_carlo, rng_stream, SAMPLES_PER_STREAM};
+    use crate::OnlineStats;
+    use rand::Rng;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn monte_carlo_handles_errors() {
+        let model = |y: &mut [f64], _: &mut _| {
+            y[0] = 1.0;
+            Ok(())
+        };
+        assert_eq!(monte_carlo(0, 10, 1234, model).err(), Some("ndim must be at least 1"));
+        assert_eq!(monte_carlo(1, 0, 1234, model).err(), Some("nsample must be at least 1"));
+        let failing = |_: &mut [f64], _: &mut _| Err("stop");
+        assert_eq!(monte_carlo(1, 10, 1234, failing).err(), Some("stop"));
+    }
+
+    #[test]
+    fn rng_stream_is_reproducible() {
+        let a: Vec<u64> = (0..4).map(|i| rng_stream(1234, i).gen()).collect();
+        let b: Vec<u64> = (0..4).map(|i| rng_stream(1234, i).gen()).collect();
+        let c: Vec<u64> = (0..4).map(|i| rng_stream(4321, i).gen()).collect();
+        assert_eq!(a, b);
+        for i in 0..4 {
+            assert_ne!(a[i], c[i]);
+            for j in (i + 1)..4 {
+                assert_ne!(a[i], a[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn monte_carlo_matches_sequential_blocks() {
+        // the result must be identical to the sequential run over the blocks
+        let nsample = 2 * SAMPLES_PER_STREAM + 100;
+        let model = |y: &mut [f64], rng: &mut rand::rngs::StdRng| {
+            y[0] = rng.gen();
+            y[1] = y[0] + rng.gen::<f64>();
+            Ok(())
+        };
+        let stats = monte_carlo(2, nsample, 1234, model).unwrap();
+        let mut correct = OnlineStats::new(2).unwrap();
+        for block in 0..3 {
+            let mut rng = rng_stream(1234, block as u64);
+            let mut part = OnlineStats::new(2).unwrap();
+            let n = if block < 2 { SAMPLES_PER_STREAM } else { 100 };
+            let mut y = vec![0.0; 2];
+            for _ in 0..n {
+                model(&mut y, &mut rng).unwrap();
+                part.push(&y).unwrap();
+            }
+            correct.merge(&part).unwrap();
+        }
+        assert_eq!(stats.count(), nsample);
+        assert_eq!(stats.mean(), correct.mean());
+        assert_eq!(stats.covariance(0, 1), correct.covariance(0, 1));
+        let again = monte_carlo(2, nsample, 1234, model).unwrap();
+        assert_eq!(stats.mean(), again.mean());
+        assert_eq!(stats.variance(1), again.variance(1));
+    }
+
+    #[test]
+    fn monte_carlo_of_uniform_works() {
+        // y₀ = u, y₁ = u + v with u, v ~ U(0, 1): var(y₀) = 1/12, var(y₁) = 1/6, cov = 1/12
+        let stats = monte_carlo(2, 50_000, 4321, |y, rng| {
+            y[0] = rng.gen();
+            y[1] = y[0] + rng.gen::<f64>();
+            Ok(())
+        })
+        .unwrap();
+        approx_eq(stats.mean()[0], 0.5, 0.01);
+        approx_eq(stats.mean()[1], 1.0, 0.01);
+        approx_eq(stats.variance(0), 1.0 / 12.0, 0.002);
+        approx_eq(stats.variance(1), 1.0 / 6.0, 0.004);
+        approx_eq(stats.covariance(0, 1), 1.0 / 12.0, 0.002);
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{weighted_quantile, StrError};
+
+/// Estimates a quantile of a stream of samples without storing them (P² algorithm)
+///
+/// The P² algorithm by Jain and Chlamtac (1985) keeps five markers: the minimum, the maximum,
+/// the desired quantile, and two intermediate quantiles (at `p/2` and `(1+p)/2`). The heights of
+/// the markers are adjusted by piecewise-parabolic (P²) interpolation as the samples arrive.
+/// Thus, the memory requirement and the cost of each update are constant; e.g., many percentiles
+/// of a long Monte Carlo simulation may be monitored by a set of estimators.
+///
+/// While fewer than five samples have been pushed, the quantile is computed exactly as in
+/// [weighted_quantile] with equal weights.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{OnlineQuantile, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut median = OnlineQuantile::new(0.5)?;
+///     for i in 0..1001 {
+///         median.push(((i * 37) % 1001) as f64); // 0, 37, 74, ... (permutation of 0..1001)
+///     }
+///     assert_eq!(median.count(), 1001);
+///     assert!(f64::abs(median.quantile() - 500.0) < 2.0);
+///     Ok(())
+/// }
+/// ```
+///
+/// # Reference
+///
+/// * Jain R and Chlamtac I (1985) The P² algorithm for dynamic calculation of quantiles and
+///   histograms without storing observations, Communications of the ACM, 28(10):1076-1085
+pub struct OnlineQuantile {
+    p: f64,               // probability level
+    count: usize,         // number of samples
+    heights: [f64; 5],    // heights of the markers (the first samples while count < 5)
+    positions: [f64; 5],  // actual positions of the markers
+    desired: [f64; 5],    // desired positions of the markers
+    increments: [f64; 5], // increments of the desired positions
+}
+
+impl OnlineQuantile {
+    /// Allocates a new (empty) estimator
+    ///
+    /// # Input
+    ///
+    /// * `p` -- the probability level in `(0, 1)` (e.g., `0.5` for the median)
+    pub fn new(p: f64) -> Result<Self, StrError> {
+        if !(p > 0.0 && p < 1.0) {
+            return Err("p must be in (0, 1)");
+        }
+        Ok(OnlineQuantile {
+            p,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+            desired: [0.0, 2.0 * p, 4.0 * p, 2.0 + 2.0 * p, 4.0],
+            increments: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        })
+    }
+
+    /// Adds a sample to the estimator
+    ///
+    /// **Note:** NaN values are ignored.
+    pub fn push(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        if self.count < 5 {
+            self.heights[self.count] = x;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort_by(|a, b| a.total_cmp(b));
+            }
+            return;
+        }
+        self.count += 1;
+
+        // find the cell k such that q[k] ≤ x < q[k+1] and update the extreme markers
+        let q = &mut self.heights;
+        let k = if x < q[0] {
+            q[0] = x;
+            0
+        } else if x >= q[4] {
+            q[4] = x;
+            3
+        } else {
+            (0..4).find(|&i| x < q[i + 1]).unwrap_or(3)
+        };
+
+        // increment the positions of the markers above k and all desired positions
+        for i in (k + 1)..5 {
+            self.positions[i] += 1.0;
+        }
+        for i in 0..5 {
+            self.desired[i] += self.increments[i];
+        }
+
+        // adjust the heights of the intermediate markers if necessary
+        for i in 1..4 {
+            let n = &mut self.positions;
+            let d = self.desired[i] - n[i];
+            if (d >= 1.0 && n[i + 1] - n[i] > 1.0) || (d <= -1.0 && n[i - 1] - n[i] < -1.0) {
+                let s = f64::signum(d);
+                let parabolic = q[i]
+                    + s / (n[i + 1] - n[i - 1])
+                        * ((n[i] - n[i - 1] + s) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                            + (n[i + 1] - n[i] - s) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]));
+                if q[i - 1] < parabolic && parabolic < q[i + 1] {
+                    q[i] = parabolic;
+                } else {
+                    let j = if s > 0.0 { i + 1 } else { i - 1 };
+                    q[i] += s * (q[j] - q[i]) / (n[j] - n[i]);
+                }
+                n[i] += s;
+            }
+        }
+    }
+
+    /// Returns the probability level
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Returns the number of samples
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the (estimated) quantile
+    ///
+    /// **Note:** Returns NaN if no sample has been pushed.
+    pub fn quantile(&self) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        if self.count < 5 {
+            let x = &self.heights[..self.count];
+            return weighted_quantile(x, &vec![1.0; self.count], self.p).unwrap_or(f64::NAN);
+        }
+        self.heights[2]
+    }
+
+    /// Returns the smallest sample
+    ///
+    /// **Note:** Returns NaN if no sample has been pushed.
+    pub fn min(&self) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        self.heights[..usize::min(self.count, 5)]
+            .iter()
+            .copied()
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns the largest sample
+    ///
+    /// **Note:** Returns NaN if no sample has been pushed.
+    pub fn max(&self) -> f64 {
+        if self.count == 0 {
+            return f64::NAN;
+        }
+        self.heights[..usize::min(self.count, 5)]
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::OnlineQuantile;
+    use crate::weighted_quantile;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn new_handles_errors() {
+        assert_eq!(OnlineQuantile::new(0.0).err(), Some("p must be in (0, 1)"));
+        assert_eq!(OnlineQuantile::new(1.0).err(), Some("p must be in (0, 1)"));
+        assert_eq!(OnlineQuantile::new(f64::NAN).err(), Some("p must be in (0, 1)"));
+    }
+
+    #[test]
+    fn small_counts_are_exact() {
+        let mut est = OnlineQuantile::new(0.5).unwrap();
+        assert_eq!(est.p(), 0.5);
+        assert!(est.quantile().is_nan());
+        assert!(est.min().is_nan());
+        assert!(est.max().is_nan());
+        est.push(3.0);
+        assert_eq!(est.quantile(), 3.0);
+        est.push(f64::NAN);
+        est.push(1.0);
+        assert_eq!(est.count(), 2);
+        assert_eq!(est.quantile(), 2.0);
+        est.push(4.0);
+        est.push(2.0);
+        assert_eq!(est.quantile(), 2.5);
+        assert_eq!(est.min(), 1.0);
+        assert_eq!(est.max(), 4.0);
+        est.push(5.0);
+        assert_eq!(est.count(), 5);
+        assert_eq!(est.quantile(), 3.0);
+        est.push(-1.0);
+        est.push(10.0);
+        assert_eq!(est.min(), -1.0);
+        assert_eq!(est.max(), 10.0);
+    }
+
+    #[test]
+    fn quantile_of_uniform_stream_works() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let mut estimators: Vec<_> = [0.01, 0.1, 0.5, 0.9, 0.99]
+            .iter()
+            .map(|&p| OnlineQuantile::new(p).unwrap())
+            .collect();
+        for _ in 0..20_000 {
+            let x: f64 = rng.gen();
+            for est in &mut estimators {
+                est.push(x);
+            }
+        }
+        for est in &estimators {
+            approx_eq(est.quantile(), est.p(), 0.01);
+        }
+    }
+
+    #[test]
+    fn quantile_of_skewed_stream_works() {
+        // exponential samples: compare with the quantile of all (stored) samples
+        let mut rng = StdRng::seed_from_u64(4321);
+        let samples: Vec<f64> = (0..10_000).map(|_| -f64::ln(1.0 - rng.gen::<f64>())).collect();
+        let w = vec![1.0; samples.len()];
+        for p in [0.05, 0.5, 0.95] {
+            let mut est = OnlineQuantile::new(p).unwrap();
+            for x in &samples {
+                est.push(*x);
+            }
+            let exact = weighted_quantile(&samples, &w, p).unwrap();
+            approx_eq(est.quantile(), exact, 0.02 * f64::max(1.0, exact));
+        }
+    }
+}
@@ -0,0 +1,376 @@
+use crate::{RussellError, Statistics, StrError};
+use russell_lab::Matrix;
+
+/// Accumulates statistics of a stream of (multivariate) samples without storing them
+///
+/// The mean and the co-moments are updated sample-by-sample using Welford's algorithm:
+///
+/// ```text
+/// dᵢ = xᵢ - x̄ᵢ
+/// x̄ᵢ ← x̄ᵢ + dᵢ / n
+/// Cᵢⱼ ← Cᵢⱼ + dᵢ (xⱼ - x̄ⱼ)
+/// ```
+///
+/// which is numerically stable, unlike the accumulation of `Σ x` and `Σ x²`. The (sample)
+/// covariance is `Cᵢⱼ / (n - 1)`. Two accumulators (e.g., from different threads) can be
+/// combined by [OnlineStats::merge] (Chan et al., 1979).
+///
+/// **Note:** The memory requirement is proportional to `ndim²` because of the co-moments.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{OnlineStats, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut stats = OnlineStats::new(2)?;
+///     for x in [[2.0, 1.0], [4.0, 3.0], [4.0, 2.0], [6.0, 6.0]] {
+///         stats.push(&x)?;
+///     }
+///     assert_eq!(stats.count(), 4);
+///     assert_eq!(stats.mean(), &[4.0, 3.0]);
+///     assert_eq!(stats.variance(0), 8.0 / 3.0);
+///     assert_eq!(stats.covariance(0, 1), 10.0 / 3.0);
+///     assert_eq!(stats.statistics(1).max, 6.0);
+///     Ok(())
+/// }
+/// ```
+///
+/// # Reference
+///
+/// * Chan TF, Golub GH, LeVeque RJ (1979) Updating formulae and a pairwise algorithm for computing
+///   sample variances, Technical Report STAN-CS-79-773, Stanford University
+pub struct OnlineStats {
+    count: usize,     // number of samples
+    mean: Vec<f64>,   // (ndim) mean of each component
+    min: Vec<f64>,    // (ndim) minimum of each component
+    max: Vec<f64>,    // (ndim) maximum of each component
+    comoment: Matrix, // (ndim,ndim) co-moments Cᵢⱼ = Σ (xᵢ - x̄ᵢ) (xⱼ - x̄ⱼ)
+    delta: Vec<f64>,  // (ndim) auxiliary vector
+}
+
+impl OnlineStats {
+    /// Allocates a new (empty) accumulator
+    ///
+    /// # Input
+    ///
+    /// * `ndim` -- number of components of each sample (≥ 1)
+    pub fn new(ndim: usize) -> Result<Self, StrError> {
+        if ndim < 1 {
+            return Err("ndim must be at least 1");
+        }
+        Ok(OnlineStats {
+            count: 0,
+            mean: vec![0.0; ndim],
+            min: vec![f64::INFINITY; ndim],
+            max: vec![f64::NEG_INFINITY; ndim],
+            comoment: Matrix::new(ndim, ndim),
+            delta: vec![0.0; ndim],
+        })
+    }
+
+    /// Adds a sample to the accumulator
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (ndim) sample
+    pub fn push(&mut self, x: &[f64]) -> Result<(), RussellError> {
+        let ndim = self.mean.len();
+        if x.len() != ndim {
+            return Err(RussellError::DimensionMismatch {
+                expected: ndim,
+                found: x.len(),
+            });
+        }
+        self.count += 1;
+        let n = self.count as f64;
+        for (i, xi) in x.iter().enumerate() {
+            self.delta[i] = xi - self.mean[i];
+            self.mean[i] += self.delta[i] / n;
+            self.min[i] = f64::min(self.min[i], *xi);
+            self.max[i] = f64::max(self.max[i], *xi);
+        }
+        for (i, di) in self.delta.iter().enumerate() {
+            for (j, (xj, mj)) in x.iter().zip(&self.mean).enumerate() {
+                let c = self.comoment.get(i, j) + di * (xj - mj);
+                self.comoment.set(i, j, c);
+            }
+        }
+        Ok(())
+    }
+
+    /// Combines the statistics of another accumulator into this one
+    ///
+    /// The result is the same (except for round-off errors) as if all samples pushed into `other`
+    /// had been pushed into this accumulator.
+    pub fn merge(&mut self, other: &OnlineStats) -> Result<(), RussellError> {
+        let ndim = self.mean.len();
+        if other.mean.len() != ndim {
+            return Err(RussellError::DimensionMismatch {
+                expected: ndim,
+                found: other.mean.len(),
+            });
+        }
+        if other.count == 0 {
+            return Ok(());
+        }
+        let (na, nb) = (self.count as f64, other.count as f64);
+        let n = na + nb;
+        for i in 0..ndim {
+            self.delta[i] = other.mean[i] - self.mean[i];
+        }
+        for i in 0..ndim {
+            for j in 0..ndim {
+                let c =
+                    self.comoment.get(i, j) + other.comoment.get(i, j) + self.delta[i] * self.delta[j] * na * nb / n;
+                self.comoment.set(i, j, c);
+            }
+        }
+        for i in 0..ndim {
+            self.mean[i] += self.delta[i] * nb / n;
+            self.min[i] = f64::min(self.min[i], other.min[i]);
+            self.max[i] = f64::max(self.max[i], other.max[i]);
+        }
+        self.count += other.count;
+        Ok(())
+    }
+
+    /// Erases all accumulated data
+    pub fn reset(&mut self) {
+        self.count = 0;
+        self.mean.fill(0.0);
+        self.min.fill(f64::INFINITY);
+        self.max.fill(f64::NEG_INFINITY);
+        self.comoment.fill(0.0);
+    }
+
+    /// Returns the number of components of each sample
+    pub fn ndim(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Returns the number of samples
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of each component
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    /// Returns the minimum of each component (infinity if empty)
+    pub fn min(&self) -> &[f64] {
+        &self.min
+    }
+
+    /// Returns the maximum of each component (negative infinity if empty)
+    pub fn max(&self) -> &[f64] {
+        &self.max
+    }
+
+    /// Returns the (sample) variance of the i-th component (applying Bessel's correction)
+    ///
+    /// **Note:** Returns zero if the number of samples is smaller than 2.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i` is out of range.
+    pub fn variance(&self, i: usize) -> f64 {
+        self.covariance(i, i)
+    }
+
+    /// Returns the (sample) covariance of the i-th and j-th components (applying Bessel's correction)
+    ///
+    /// **Note:** Returns zero if the number of samples is smaller than 2.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i` or `j` is out of range.
+    pub fn covariance(&self, i: usize, j: usize) -> f64 {
+        if self.count < 2 {
+            return 0.0;
+        }
+        self.comoment.get(i, j) / ((self.count - 1) as f64)
+    }
+
+    /// Returns the (Pearson) correlation coefficient of the i-th and j-th components
+    ///
+    /// **Note:** Returns NaN if any of the variances is zero.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i` or `j` is out of range.
+    pub fn correlation(&self, i: usize, j: usize) -> f64 {
+        let den = f64::sqrt(self.comoment.get(i, i) * self.comoment.get(j, j));
+        if den > 0.0 {
+            self.comoment.get(i, j) / den
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Returns the (ndim,ndim) sample covariance matrix
+    ///
+    /// **Note:** Returns a zero matrix if the number of samples is smaller than 2.
+    pub fn covariance_matrix(&self) -> Matrix {
+        let ndim = self.mean.len();
+        let mut cov = Matrix::new(ndim, ndim);
+        for i in 0..ndim {
+            for j in 0..ndim {
+                cov.set(i, j, self.covariance(i, j));
+            }
+        }
+        cov
+    }
+
+    /// Returns the basic statistics of the i-th component (the same as [crate::statistics])
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i` is out of range.
+    pub fn statistics(&self, i: usize) -> Statistics {
+        if self.count == 0 {
+            return Statistics {
+                min: 0.0,
+                max: 0.0,
+                mean: 0.0,
+                std_dev: 0.0,
+            };
+        }
+        Statistics {
+            min: self.min[i],
+            max: self.max[i],
+            mean: self.mean[i],
+            std_dev: f64::sqrt(self.variance(i)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::OnlineStats;
+    use crate::{covariance_matrix, statistics, RussellError};
+    use russell_chk::{approx_eq, vec_approx_eq};
+    use russell_lab::Matrix;
+
+    #[test]
+    fn new_and_push_handle_errors() {
+        assert_eq!(OnlineStats::new(0).err(), Some("ndim must be at least 1"));
+        let mut stats = OnlineStats::new(2).unwrap();
+        assert_eq!(
+            stats.push(&[1.0]).err(),
+            Some(RussellError::DimensionMismatch { expected: 2, found: 1 })
+        );
+        let other = OnlineStats::new(3).unwrap();
+        assert_eq!(
+            stats.merge(&other).err(),
+            Some(RussellError::DimensionMismatch { expected: 2, found: 3 })
+        );
+    }
+
+    #[test]
+    fn small_counts_work() {
+        let mut stats = OnlineStats::new(1).unwrap();
+        assert_eq!(stats.ndim(), 1);
+        let res = stats.statistics(0);
+        assert_eq!((res.min, res.max, res.mean, res.std_dev), (0.0, 0.0, 0.0, 0.0));
+        assert_eq!(stats.min(), &[f64::INFINITY]);
+        assert_eq!(stats.max(), &[f64::NEG_INFINITY]);
+        stats.push(&[1.23]).unwrap();
+        let res = stats.statistics(0);
+        assert_eq!((res.min, res.max, res.mean, res.std_dev), (1.23, 1.23, 1.23, 0.0));
+        assert!(stats.correlation(0, 0).is_nan());
+    }
+
+    #[test]
+    fn push_matches_statistics() {
+        let x = [100, 100, 102, 98, 77, 99, 70, 105, 98];
+        let mut stats = OnlineStats::new(1).unwrap();
+        for v in x {
+            stats.push(&[v as f64]).unwrap();
+        }
+        let reference = statistics(&x);
+        let res = stats.statistics(0);
+        assert_eq!(stats.count(), 9);
+        assert_eq!(res.min, 70.0);
+        assert_eq!(res.max, 105.0);
+        approx_eq(res.mean, reference.mean, 1e-13);
+        approx_eq(res.std_dev, reference.std_dev, 1e-13);
+    }
+
+    #[test]
+    fn push_matches_covariance_matrix() {
+        let samples = Matrix::from(&[
+            [1.0, 2.0, -1.0],
+            [2.0, 4.5, 0.0],
+            [3.0, 5.5, 2.5],
+            [4.0, 8.0, 1.0],
+            [0.5, 1.0, 3.0],
+        ]);
+        let mut stats = OnlineStats::new(3).unwrap();
+        for k in 0..5 {
+            stats
+                .push(&[samples.get(k, 0), samples.get(k, 1), samples.get(k, 2)])
+                .unwrap();
+        }
+        let reference = covariance_matrix(&samples).unwrap();
+        vec_approx_eq(stats.covariance_matrix().as_data(), reference.as_data(), 1e-14);
+        vec_approx_eq(stats.mean(), &[2.1, 4.2, 1.1], 1e-14);
+        assert_eq!(stats.min(), &[0.5, 1.0, -1.0]);
+        assert_eq!(stats.max(), &[4.0, 8.0, 3.0]);
+        let rho = reference.get(0, 1) / f64::sqrt(reference.get(0, 0) * reference.get(1, 1));
+        approx_eq(stats.correlation(0, 1), rho, 1e-14);
+        approx_eq(stats.correlation(1, 0), rho, 1e-14);
+        approx_eq(stats.correlation(2, 2), 1.0, 1e-15);
+
+        stats.reset();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.variance(1), 0.0);
+        assert_eq!(stats.covariance_matrix().as_data(), &[0.0; 9]);
+    }
+
+    #[test]
+    fn merge_works() {
+        let data = [
+            [1.0, -2.0],
+            [4.0, 0.5],
+            [2.5, 2.0],
+            [-1.0, 3.0],
+            [7.0, 1.0],
+            [3.0, -4.0],
+        ];
+        let mut all = OnlineStats::new(2).unwrap();
+        let mut a = OnlineStats::new(2).unwrap();
+        let mut b = OnlineStats::new(2).unwrap();
+        for (k, x) in data.iter().enumerate() {
+            all.push(x).unwrap();
+            if k < 2 {
+                a.push(x).unwrap();
+            } else {
+                b.push(x).unwrap();
+            }
+        }
+        let empty = OnlineStats::new(2).unwrap();
+        a.merge(&empty).unwrap();
+        assert_eq!(a.count(), 2);
+        a.merge(&b).unwrap();
+        assert_eq!(a.count(), 6);
+        vec_approx_eq(a.mean(), all.mean(), 1e-15);
+        assert_eq!(a.min(), all.min());
+        assert_eq!(a.max(), all.max());
+        vec_approx_eq(
+            a.covariance_matrix().as_data(),
+            all.covariance_matrix().as_data(),
+            1e-14,
+        );
+
+        // merging into an empty accumulator
+        let mut c = OnlineStats::new(2).unwrap();
+        c.merge(&all).unwrap();
+        vec_approx_eq(c.mean(), all.mean(), 1e-15);
+        approx_eq(c.covariance(0, 1), all.covariance(0, 1), 1e-15);
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{fit_maximum_likelihood, DistributionGenPareto, Observation, StrError};
+
+/// Holds the diagnostics of a candidate threshold of the peaks-over-threshold method
+///
+/// Above a suitable threshold `u`, the mean excess is linear in `u` and the shape and modified
+/// scale of the fitted Generalized Pareto distribution are approximately constant:
+///
+/// ```text
+/// e(u) = E[X - u | X > u] = (σᵤ₀ + ξ (u - u₀)) / (1 - ξ)
+///
+/// σ* = σᵤ - ξ u
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ThresholdDiagnostic {
+    /// The candidate threshold
+    pub threshold: f64,
+
+    /// The number of values exceeding the threshold
+    pub nexceed: usize,
+
+    /// The mean of the excesses over the threshold (mean residual life)
+    pub mean_excess: f64,
+
+    /// The shape parameter ξ of the fitted distribution
+    pub shape: f64,
+
+    /// The modified scale `σᵤ - ξ u` of the fitted distribution
+    pub modified_scale: f64,
+}
+
+/// Implements the peaks-over-threshold (POT) method for the analysis of extremes
+///
+/// The values exceeding a (large) threshold `u` are fitted to a Generalized Pareto distribution
+/// by maximum likelihood (see [fit_maximum_likelihood]). Then, with an exceedance rate `λ`
+/// (number of exceedances per unit of time), the level exceeded once, on average, every `T` units
+/// of time (return level) is given by:
+///
+/// ```text
+/// x_T = u + σ ((λ T)^ξ - 1) / ξ
+///
+/// x_T = u + σ ln(λ T)          if ξ = 0
+/// ```
+///
+/// This approach complements the block-maxima approach (e.g., fitting the annual maxima to a
+/// [crate::DistributionGumbel]) by using all large values instead of one value per block.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{PeaksOverThreshold, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // daily rainfall (mm) recorded over 2 years; only the values above 30 mm are used
+///     let data = [
+///         31.2, 5.0, 44.1, 12.3, 35.8, 0.0, 52.6, 33.9, 8.1, 38.4, 30.5, 61.7, 3.3, 36.2, 41.0,
+///     ];
+///     let pot = PeaksOverThreshold::fit(&data, 30.0, 2.0)?;
+///     assert_eq!(pot.nexceed(), 10);
+///     assert_eq!(pot.rate(), 5.0);
+///     // 100-year return level
+///     let x100 = pot.return_level(100.0);
+///     assert!(x100 > 61.7);
+///     Ok(())
+/// }
+/// ```
+pub struct PeaksOverThreshold {
+    threshold: f64,                      // threshold u
+    nexceed: usize,                      // number of exceedances
+    rate: f64,                           // number of exceedances per unit of time
+    distribution: DistributionGenPareto, // fitted distribution of the exceedances
+    log_likelihood: f64,                 // maximum log-likelihood
+}
+
+impl PeaksOverThreshold {
+    /// Fits the Generalized Pareto distribution to the values exceeding a threshold
+    ///
+    /// # Input
+    ///
+    /// * `data` -- the observed values (e.g., declustered daily maxima)
+    /// * `threshold` -- the threshold `u`
+    /// * `duration` -- the observation period, in the units of the return periods (e.g., years)
+    pub fn fit(data: &[f64], threshold: f64, duration: f64) -> Result<Self, StrError> {
+        if duration <= 0.0 {
+            return Err("duration must be greater than zero");
+        }
+        let excesses: Vec<f64> = data
+            .iter()
+            .filter(|&&x| x > threshold)
+            .map(|&x| x - threshold)
+            .collect();
+        let n = excesses.len();
+        if n < 2 {
+            return Err("at least two values must exceed the threshold");
+        }
+
+        // initial guess by the method of moments (exponential if not valid; it requires ξ > -½)
+        let mean = excesses.iter().sum::<f64>() / (n as f64);
+        let var = excesses.iter().map(|e| (e - mean) * (e - mean)).sum::<f64>() / ((n - 1) as f64);
+        let max = excesses.iter().fold(0.0, |acc: f64, &e| acc.max(e));
+        let mut shape = 0.5 * (1.0 - mean * mean / var);
+        let mut scale = 0.5 * mean * (mean * mean / var + 1.0);
+        if shape.is_nan() || shape <= -0.5 || !scale.is_finite() || (shape < 0.0 && max >= -scale / shape) {
+            shape = 0.0;
+            scale = mean;
+        }
+
+        // maximum likelihood (ξ ≤ -1 is excluded because the likelihood is unbounded)
+        let observations: Vec<_> = excesses.iter().map(|&e| Observation::Exact(threshold + e)).collect();
+        let fit = fit_maximum_likelihood(&observations, &[scale, shape], |p| {
+            if p[1] <= -1.0 {
+                return Err("shape must be greater than -1");
+            }
+            DistributionGenPareto::new(threshold, p[0], p[1])
+        })?;
+        Ok(PeaksOverThreshold {
+            threshold,
+            nexceed: n,
+            rate: (n as f64) / duration,
+            distribution: fit.distribution,
+            log_likelihood: fit.log_likelihood,
+        })
+    }
+
+    /// Computes the diagnostics for the selection of the threshold
+    ///
+    /// The mean excess and the parameters of the distribution fitted above each candidate threshold
+    /// (see [ThresholdDiagnostic]) should be plotted against the threshold; a suitable threshold is
+    /// the lowest one above which the mean excess is linear and the parameters are stable.
+    ///
+    /// # Input
+    ///
+    /// * `data` -- the observed values
+    /// * `thresholds` -- the candidate thresholds (each one must be exceeded by at least two values)
+    pub fn threshold_diagnostics(data: &[f64], thresholds: &[f64]) -> Result<Vec<ThresholdDiagnostic>, StrError> {
+        thresholds
+            .iter()
+            .map(|&u| {
+                let pot = PeaksOverThreshold::fit(data, u, 1.0)?;
+                let excess_sum: f64 = data.iter().filter(|&&x| x > u).map(|&x| x - u).sum();
+                let shape = pot.distribution.shape();
+                Ok(ThresholdDiagnostic {
+                    threshold: u,
+                    nexceed: pot.nexceed,
+                    mean_excess: excess_sum / (pot.nexceed as f64),
+                    shape,
+                    modified_scale: pot.distribution.scale() - shape * u,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the return level corresponding to a return period
+    ///
+    /// The return period `T` is given in the units of the observation period (the same units of
+    /// `duration` in [PeaksOverThreshold::fit]). Returns the threshold if `λ T ≤ 1`, i.e., if the
+    /// threshold is exceeded more often than once every `T` units of time.
+    pub fn return_level(&self, period: f64) -> f64 {
+        let m = self.rate * period;
+        if m <= 1.0 {
+            return self.threshold;
+        }
+        let (scale, shape) = (self.distribution.scale(), self.distribution.shape());
+        if shape == 0.0 {
+            return self.threshold + scale * f64::ln(m);
+        }
+        self.threshold + scale * f64::exp_m1(shape * f64::ln(m)) / shape
+    }
+
+    /// Returns the threshold
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Returns the number of values exceeding the threshold
+    pub fn nexceed(&self) -> usize {
+        self.nexceed
+    }
+
+    /// Returns the exceedance rate (number of exceedances per unit of time)
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Returns the fitted distribution of the values exceeding the threshold
+    pub fn distribution(&self) -> &DistributionGenPareto {
+        &self.distribution
+    }
+
+    /// Returns the maximum log-likelihood
+    pub fn log_likelihood(&self) -> f64 {
+        self.log_likelihood
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::PeaksOverThreshold;
+    use crate::{DistributionGenPareto, ProbabilityDistribution};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use russell_chk::approx_eq;
+
+    fn samples(n: usize, scale: f64, shape: f64) -> Vec<f64> {
+        let dist = DistributionGenPareto::new(0.0, scale, shape).unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+        (0..n).map(|_| dist.sample(&mut rng)).collect()
+    }
+
+    #[test]
+    fn fit_captures_errors() {
+        assert_eq!(
+            PeaksOverThreshold::fit(&[1.0, 2.0, 3.0], 0.0, 0.0).err(),
+            Some("duration must be greater than zero")
+        );
+        assert_eq!(
+            PeaksOverThreshold::fit(&[1.0, 2.0, 3.0], 2.0, 1.0).err(),
+            Some("at least two values must exceed the threshold")
+        );
+        assert_eq!(
+            PeaksOverThreshold::threshold_diagnostics(&[1.0, 2.0, 3.0], &[0.0, 2.5]).err(),
+            Some("at least two values must exceed the threshold")
+        );
+    }
+
+    #[test]
+    fn fit_works() {
+        // the excesses of a GPD over u follow a GPD with the same shape and scale σ + ξ u
+        let (scale, shape) = (1.0, 0.2);
+        let data = samples(5000, scale, shape);
+        let u = 0.5;
+        let pot = PeaksOverThreshold::fit(&data, u, 50.0).unwrap();
+        let nexceed = data.iter().filter(|&&x| x > u).count();
+        assert_eq!(pot.threshold(), u);
+        assert_eq!(pot.nexceed(), nexceed);
+        assert_eq!(pot.rate(), (nexceed as f64) / 50.0);
+        assert!(f64::abs(pot.distribution().shape() - shape) < 0.05);
+        assert!(f64::abs(pot.distribution().scale() - (scale + shape * u)) < 0.1);
+        assert!(pot.log_likelihood().is_finite());
+
+        // bounded tail
+        let data = samples(5000, 2.0, -0.3);
+        let pot = PeaksOverThreshold::fit(&data, 1.0, 1.0).unwrap();
+        assert!(f64::abs(pot.distribution().shape() + 0.3) < 0.05);
+        assert!(f64::abs(pot.distribution().scale() - 1.7) < 0.1);
+    }
+
+    #[test]
+    fn return_level_works() {
+        let data = samples(2000, 1.0, 0.1);
+        let pot = PeaksOverThreshold::fit(&data, 1.0, 20.0).unwrap();
+        // the return level is exceeded, on average, once in T units of time: λ T (1 - F(x_T)) = 1
+        for period in [1.0, 10.0, 100.0, 1000.0] {
+            let x = pot.return_level(period);
+            approx_eq(pot.rate() * period * (1.0 - pot.distribution().cdf(x)), 1.0, 1e-12);
+        }
+        assert_eq!(pot.return_level(1.0 / pot.rate()), 1.0);
+        assert!(pot.return_level(1000.0) > pot.return_level(100.0));
+    }
+
+    #[test]
+    fn threshold_diagnostics_works() {
+        let (scale, shape) = (1.0, 0.2);
+        let data = samples(10000, scale, shape);
+        let thresholds = [0.0, 0.5, 1.0, 1.5];
+        let res = PeaksOverThreshold::threshold_diagnostics(&data, &thresholds).unwrap();
+        assert_eq!(res.len(), 4);
+        for (diag, u) in res.iter().zip(thresholds) {
+            assert_eq!(diag.threshold, u);
+            assert_eq!(diag.nexceed, data.iter().filter(|&&x| x > u).count());
+            // linear mean excess and stable parameters
+            let correct = (scale + shape * u) / (1.0 - shape);
+            assert!(f64::abs(diag.mean_excess - correct) < 0.1 * correct);
+            assert!(f64::abs(diag.shape - shape) < 0.1);
+            assert!(f64::abs(diag.modified_scale - scale) < 0.15);
+        }
+    }
+}
@@ -0,0 +1,549 @@
+use crate::{inv_cdf_standard_normal, ProbabilityDistribution, StrError};
+use rand::distributions::Open01;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use russell_lab::math::{erfc, SQRT_2};
+use russell_lab::{mat_eigen_sym_jacobi, solve_lsq, Matrix, Vector};
+
+/// Seed of the pseudo-random samples of the least-squares fitting (thus, the results are reproducible)
+const SEED: u64 = 161803;
+
+/// Specifies the family of orthonormal polynomials (and the underlying standard random variable ξ)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PceBasis {
+    /// (Probabilists') Hermite polynomials with the standard normal variable `ξ ~ N(0, 1)`
+    Hermite,
+
+    /// Legendre polynomials with the uniform variable `ξ ~ U(-1, 1)`
+    Legendre,
+}
+
+/// Computes the Gauss quadrature rule associated with the probability measure of a polynomial basis
+///
+/// The nodes and weights are such that (exactly for polynomials of degree up to `2 npoint - 1`):
+///
+/// ```text
+/// E[g(ξ)] ≈ Σ wₖ g(ξₖ)    with   Σ wₖ = 1
+/// ```
+///
+/// The rule is obtained by the Golub–Welsch algorithm; i.e., the nodes are the eigenvalues of the
+/// (symmetric tridiagonal) Jacobi matrix of the three-term recurrence and the weights are the squared
+/// first components of the normalized eigenvectors (see [mat_eigen_sym_jacobi]).
+///
+/// # Input
+///
+/// * `basis` -- the polynomial basis (i.e., the probability measure)
+/// * `npoint` -- the number of points (`1 ≤ npoint ≤ 32`)
+///
+/// # Output
+///
+/// Returns `(nodes, weights)` with the nodes sorted in ascending order
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{gauss_quadrature, PceBasis, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // E[ξ⁴] = 3 with ξ ~ N(0, 1)
+///     let (nodes, weights) = gauss_quadrature(PceBasis::Hermite, 3)?;
+///     let moment: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * f64::powi(*x, 4)).sum();
+///     assert_eq!(format!("{:.12}", moment), "3.000000000000");
+///     Ok(())
+/// }
+/// ```
+pub fn gauss_quadrature(basis: PceBasis, npoint: usize) -> Result<(Vec<f64>, Vec<f64>), StrError> {
+    if !(1..=32).contains(&npoint) {
+        return Err("npoint must satisfy 1 ≤ npoint ≤ 32");
+    }
+    let mut jacobi = Matrix::new(npoint, npoint);
+    for k in 1..npoint {
+        let kf = k as f64;
+        let beta = match basis {
+            PceBasis::Hermite => f64::sqrt(kf),
+            PceBasis::Legendre => kf / f64::sqrt(4.0 * kf * kf - 1.0),
+        };
+        jacobi.set(k - 1, k, beta);
+        jacobi.set(k, k - 1, beta);
+    }
+    let mut l = Vector::new(npoint);
+    let mut v = Matrix::new(npoint, npoint);
+    mat_eigen_sym_jacobi(&mut l, &mut v, &mut jacobi)?;
+    let mut pairs: Vec<(f64, f64)> = (0..npoint).map(|k| (l[k], v.get(0, k) * v.get(0, k))).collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+    Ok(pairs.into_iter().unzip())
+}
+
+/// Implements a (total-degree) polynomial chaos expansion (PCE) of a model with independent random inputs
+///
+/// The model output is approximated by:
+///
+/// ```text
+/// y = f(x) ≈ Σ cₐ Ψₐ(ξ)    with   Ψₐ(ξ) = ψ_α₀(ξ₀) ⋅ ψ_α₁(ξ₁) ⋯   and   |α| ≤ degree
+///            α
+/// ```
+///
+/// where `ψₖ` are the orthonormal (Hermite or Legendre) polynomials and each input is mapped onto the
+/// standard variable by the isoprobabilistic transformation `xᵢ = Fᵢ⁻¹(G(ξᵢ))`, with `Fᵢ` the CDF of
+/// the input and `G` the CDF of ξ (see [ProbabilityDistribution::inv_cdf]). Owing to orthonormality,
+/// the statistics of the output follow directly from the coefficients:
+///
+/// ```text
+/// E[y] = c₀     Var[y] = Σ cₐ²   (α ≠ 0)
+/// ```
+///
+/// The coefficients are computed by (tensor-grid) Gauss quadrature projection or by least-squares
+/// regression on random samples (see [solve_lsq]).
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{DistributionNormal, PceBasis, PolynomialChaos, ProbabilityDistribution, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // y = x₀ x₁ + x₁ with x₀ ~ N(1, 0.5) and x₁ ~ N(2, 1)
+///     let d0 = DistributionNormal::new(1.0, 0.5)?;
+///     let d1 = DistributionNormal::new(2.0, 1.0)?;
+///     let distributions: [&dyn ProbabilityDistribution; 2] = [&d0, &d1];
+///     let mut pce = PolynomialChaos::new(&distributions, PceBasis::Hermite, 2)?;
+///     pce.fit_quadrature(|x| Ok(x[0] * x[1] + x[1]), 3)?;
+///
+///     // E[y] = E[x₀] E[x₁] + E[x₁] = 4
+///     assert_eq!(format!("{:.10}", pce.mean()), "4.0000000000");
+///     Ok(())
+/// }
+/// ```
+pub struct PolynomialChaos<'a> {
+    distributions: Vec<&'a dyn ProbabilityDistribution>, // (ndim) input distributions
+    basis: PceBasis,                                     // polynomial basis
+    degree: usize,                                       // maximum total degree
+    multi_indices: Vec<Vec<usize>>,                      // (nterm) multi-indices α (graded order)
+    coefficients: Vec<f64>,                              // (nterm) coefficients cₐ
+}
+
+impl<'a> PolynomialChaos<'a> {
+    /// Allocates a new instance
+    ///
+    /// **Note:** The coefficients are zero until one of the fitting functions is called.
+    ///
+    /// # Input
+    ///
+    /// * `distributions` -- (ndim) the probability distributions of the inputs
+    /// * `basis` -- the polynomial basis
+    /// * `degree` -- the maximum total degree of the expansion
+    pub fn new(
+        distributions: &[&'a dyn ProbabilityDistribution],
+        basis: PceBasis,
+        degree: usize,
+    ) -> Result<Self, StrError> {
+        if distributions.is_empty() {
+            return Err("at least one distribution is required");
+        }
+        let mut multi_indices = Vec::new();
+        let mut alpha = vec![0; distributions.len()];
+        for total in 0..=degree {
+            compositions(&mut multi_indices, &mut alpha, 0, total);
+        }
+        let nterm = multi_indices.len();
+        Ok(PolynomialChaos {
+            distributions: distributions.to_vec(),
+            basis,
+            degree,
+            multi_indices,
+            coefficients: vec![0.0; nterm],
+        })
+    }
+
+    /// Returns the number of terms of the expansion
+    pub fn nterm(&self) -> usize {
+        self.multi_indices.len()
+    }
+
+    /// Returns the (nterm) multi-indices α (in graded order, with the constant term first)
+    pub fn multi_indices(&self) -> &[Vec<usize>] {
+        &self.multi_indices
+    }
+
+    /// Returns the (nterm) coefficients cₐ
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Computes the coefficients by Gauss quadrature projection on a tensor grid
+    ///
+    /// ```text
+    /// cₐ = E[f Ψₐ] ≈ Σ w_q f(x(ξ_q)) Ψₐ(ξ_q)
+    /// ```
+    ///
+    /// # Input
+    ///
+    /// * `model` -- the function `y = f(x)` with `x.len() = ndim`
+    /// * `npoint` -- the number of points per dimension (e.g., `degree + 1`); the model is called `npointⁿᵈⁱᵐ` times
+    pub fn fit_quadrature<F>(&mut self, mut model: F, npoint: usize) -> Result<(), StrError>
+    where
+        F: FnMut(&[f64]) -> Result<f64, StrError>,
+    {
+        let (nodes, weights) = gauss_quadrature(self.basis, npoint)?;
+        let ndim = self.distributions.len();
+        let mut counter = vec![0; ndim];
+        let mut xi = vec![0.0; ndim];
+        let mut x = vec![0.0; ndim];
+        let mut psi = vec![vec![0.0; self.degree + 1]; ndim];
+        self.coefficients.fill(0.0);
+        loop {
+            let mut weight = 1.0;
+            for (i, &k) in counter.iter().enumerate() {
+                xi[i] = nodes[k];
+                weight *= weights[k];
+            }
+            self.to_input(&mut x, &xi);
+            let y = model(&x)?;
+            self.eval_basis(&mut psi, &xi);
+            for (c, alpha) in self.coefficients.iter_mut().zip(&self.multi_indices) {
+                *c += weight * y * product(&psi, alpha);
+            }
+            // next point of the tensor grid
+            let mut i = 0;
+            while i < ndim {
+                counter[i] += 1;
+                if counter[i] < npoint {
+                    break;
+                }
+                counter[i] = 0;
+                i += 1;
+            }
+            if i == ndim {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Computes the coefficients by least-squares regression on pseudo-random samples
+    ///
+    /// # Input
+    ///
+    /// * `model` -- the function `y = f(x)` with `x.len() = ndim`
+    /// * `nsample` -- the number of samples (≥ nterm; e.g., `2 ⋅ nterm` or more)
+    ///
+    /// **Note:** The samples are generated with a fixed seed; thus, the results are reproducible.
+    pub fn fit_least_squares<F>(&mut self, mut model: F, nsample: usize) -> Result<(), StrError>
+    where
+        F: FnMut(&[f64]) -> Result<f64, StrError>,
+    {
+        let nterm = self.nterm();
+        if nsample < nterm {
+            return Err("nsample must be greater than or equal to the number of terms");
+        }
+        let ndim = self.distributions.len();
+        let mut rng = StdRng::seed_from_u64(SEED);
+        let mut xi = vec![0.0; ndim];
+        let mut x = vec![0.0; ndim];
+        let mut psi = vec![vec![0.0; self.degree + 1]; ndim];
+        let mut a = Matrix::new(nsample, nterm);
+        let mut b = Vector::new(nsample);
+        for i in 0..nsample {
+            for v in xi.iter_mut() {
+                let u: f64 = rng.sample(Open01);
+                *v = match self.basis {
+                    PceBasis::Hermite => inv_cdf_standard_normal(u),
+                    PceBasis::Legendre => 2.0 * u - 1.0,
+                };
+            }
+            self.to_input(&mut x, &xi);
+            b[i] = model(&x)?;
+            self.eval_basis(&mut psi, &xi);
+            for (j, alpha) in self.multi_indices.iter().enumerate() {
+                a.set(i, j, product(&psi, alpha));
+            }
+        }
+        let mut c = Vector::new(nterm);
+        solve_lsq(&mut c, &a, &b)?;
+        self.coefficients.copy_from_slice(c.as_data());
+        Ok(())
+    }
+
+    /// Evaluates the expansion at the input point x
+    ///
+    /// # Input
+    ///
+    /// * `x` -- (ndim) the input values (in the support of the distributions)
+    pub fn eval(&self, x: &[f64]) -> Result<f64, StrError> {
+        let ndim = self.distributions.len();
+        if x.len() != ndim {
+            return Err("x must have length equal to the number of distributions");
+        }
+        let mut xi = vec![0.0; ndim];
+        for ((v, &xx), dist) in xi.iter_mut().zip(x).zip(&self.distributions) {
+            let u = dist.cdf(xx);
+            *v = match self.basis {
+                PceBasis::Hermite => inv_cdf_standard_normal(u),
+                PceBasis::Legendre => 2.0 * u - 1.0,
+            };
+        }
+        let mut psi = vec![vec![0.0; self.degree + 1]; ndim];
+        self.eval_basis(&mut psi, &xi);
+        Ok(self
+            .coefficients
+            .iter()
+            .zip(&self.multi_indices)
+            .map(|(c, alpha)| c * product(&psi, alpha))
+            .sum())
+    }
+
+    /// Returns the mean of the output
+    pub fn mean(&self) -> f64 {
+        self.coefficients[0]
+    }
+
+    /// Returns the variance of the output
+    pub fn variance(&self) -> f64 {
+        self.coefficients[1..].iter().map(|c| c * c).sum()
+    }
+
+    /// Returns the (ndim) first-order Sobol indices (terms depending on xᵢ only)
+    pub fn sobol_first_order(&self) -> Vec<f64> {
+        self.sobol_indices(|alpha, i| alpha[i] > 0 && alpha.iter().filter(|&&a| a > 0).count() == 1)
+    }
+
+    /// Returns the (ndim) total-effect Sobol indices (terms depending on xᵢ)
+    pub fn sobol_total_effect(&self) -> Vec<f64> {
+        self.sobol_indices(|alpha, i| alpha[i] > 0)
+    }
+
+    /// Sums the squared coefficients of the selected terms divided by the variance
+    fn sobol_indices<S>(&self, selected: S) -> Vec<f64>
+    where
+        S: Fn(&[usize], usize) -> bool,
+    {
+        let variance = self.variance();
+        (0..self.distributions.len())
+            .map(|i| {
+                let sum: f64 = self
+                    .coefficients
+                    .iter()
+                    .zip(&self.multi_indices)
+                    .filter(|(_, alpha)| selected(alpha, i))
+                    .map(|(c, _)| c * c)
+                    .sum();
+                if variance > 0.0 {
+                    sum / variance
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Maps the standard variables ξ onto the input values x
+    fn to_input(&self, x: &mut [f64], xi: &[f64]) {
+        for ((xx, &v), dist) in x.iter_mut().zip(xi).zip(&self.distributions) {
+            let u = match self.basis {
+                PceBasis::Hermite => 0.5 * erfc(-v / SQRT_2),
+                PceBasis::Legendre => (v + 1.0) / 2.0,
+            };
+            *xx = dist.inv_cdf(u);
+        }
+    }
+
+    /// Computes the orthonormal polynomials `psi[i][k] = ψₖ(ξᵢ)` for `k ≤ degree`
+    fn eval_basis(&self, psi: &mut [Vec<f64>], xi: &[f64]) {
+        for (p, &v) in psi.iter_mut().zip(xi) {
+            // three-term recurrence of the (non-normalized) polynomials
+            p[0] = 1.0;
+            if self.degree > 0 {
+                p[1] = v;
+            }
+            for k in 1..self.degree {
+                let kf = k as f64;
+                p[k + 1] = match self.basis {
+                    PceBasis::Hermite => v * p[k] - kf * p[k - 1],
+                    PceBasis::Legendre => ((2.0 * kf + 1.0) * v * p[k] - kf * p[k - 1]) / (kf + 1.0),
+                };
+            }
+            // normalization
+            let mut factorial = 1.0;
+            for (k, pk) in p.iter_mut().enumerate().skip(1) {
+                factorial *= k as f64;
+                *pk *= match self.basis {
+                    PceBasis::Hermite => 1.0 / f64::sqrt(factorial),
+                    PceBasis::Legendre => f64::sqrt((2 * k + 1) as f64),
+                };
+            }
+        }
+    }
+}
+
+/// Appends all multi-indices with `alpha[start..]` summing up to `remaining` (the first index varies the slowest)
+fn compositions(list: &mut Vec<Vec<usize>>, alpha: &mut [usize], start: usize, remaining: usize) {
+    if start == alpha.len() - 1 {
+        alpha[start] = remaining;
+        list.push(alpha.to_vec());
+        return;
+    }
+    for a in (0..=remaining).rev() {
+        alpha[start] = a;
+        compositions(list, alpha, start + 1, remaining - a);
+    }
+}
+
+/// Computes `Ψₐ(ξ) = Π ψ_αᵢ(ξᵢ)` from the tabulated univariate polynomials
+fn product(psi: &[Vec<f64>], alpha: &[usize]) -> f64 {
+    psi.iter().zip(alpha).fold(1.0, |acc, (p, &a)| acc * p[a])
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{gauss_quadrature, PceBasis, PolynomialChaos};
+    use crate::{DistributionLognormal, DistributionNormal, DistributionUniform, ProbabilityDistribution};
+    use russell_chk::{approx_eq, vec_approx_eq};
+    use russell_lab::math::PI;
+
+    #[test]
+    fn gauss_quadrature_works() {
+        assert_eq!(
+            gauss_quadrature(PceBasis::Hermite, 0).err(),
+            Some("npoint must satisfy 1 ≤ npoint ≤ 32")
+        );
+        let (x, w) = gauss_quadrature(PceBasis::Hermite, 3).unwrap();
+        vec_approx_eq(&x, &[-f64::sqrt(3.0), 0.0, f64::sqrt(3.0)], 1e-14);
+        vec_approx_eq(&w, &[1.0 / 6.0, 2.0 / 3.0, 1.0 / 6.0], 1e-14);
+        let (x, w) = gauss_quadrature(PceBasis::Legendre, 2).unwrap();
+        vec_approx_eq(&x, &[-1.0 / f64::sqrt(3.0), 1.0 / f64::sqrt(3.0)], 1e-15);
+        vec_approx_eq(&w, &[0.5, 0.5], 1e-15);
+
+        // moments: E[ξ²ᵏ] = (2k - 1)!! (normal) and 1 / (2k + 1) (uniform)
+        let (x, w) = gauss_quadrature(PceBasis::Hermite, 6).unwrap();
+        let (y, v) = gauss_quadrature(PceBasis::Legendre, 6).unwrap();
+        for (k, normal) in [1.0, 1.0, 3.0, 15.0, 105.0, 945.0].iter().enumerate() {
+            let m: f64 = x.iter().zip(&w).map(|(x, w)| w * f64::powi(*x, 2 * k as i32)).sum();
+            approx_eq(m, *normal, 1e-10 * normal);
+            let m: f64 = y.iter().zip(&v).map(|(y, v)| v * f64::powi(*y, 2 * k as i32)).sum();
+            approx_eq(m, 1.0 / ((2 * k + 1) as f64), 1e-14);
+        }
+    }
+
+    #[test]
+    fn new_works() {
+        assert_eq!(
+            PolynomialChaos::new(&[], PceBasis::Hermite, 2).err(),
+            Some("at least one distribution is required")
+        );
+        let dist = DistributionNormal::new(0.0, 1.0).unwrap();
+        let pce = PolynomialChaos::new(&[&dist, &dist, &dist], PceBasis::Hermite, 2).unwrap();
+        assert_eq!(pce.nterm(), 10); // (ndim + degree)! / (ndim! degree!)
+        assert_eq!(pce.multi_indices()[0], &[0, 0, 0]);
+        assert_eq!(pce.multi_indices()[1], &[1, 0, 0]);
+        assert_eq!(pce.multi_indices()[3], &[0, 0, 1]);
+        assert_eq!(pce.multi_indices()[4], &[2, 0, 0]);
+        assert_eq!(pce.multi_indices()[9], &[0, 0, 2]);
+        assert_eq!(pce.coefficients(), &[0.0; 10]);
+        assert_eq!(
+            pce.eval(&[0.0]).err(),
+            Some("x must have length equal to the number of distributions")
+        );
+    }
+
+    #[test]
+    fn basis_is_orthonormal() {
+        for basis in [PceBasis::Hermite, PceBasis::Legendre] {
+            let dist = DistributionNormal::new(0.0, 1.0).unwrap();
+            let pce = PolynomialChaos::new(&[&dist], basis, 5).unwrap();
+            let (x, w) = gauss_quadrature(basis, 8).unwrap();
+            let mut psi = vec![vec![0.0; 6]];
+            let mut gram = [[0.0; 6]; 6];
+            for (xk, wk) in x.iter().zip(&w) {
+                pce.eval_basis(&mut psi, &[*xk]);
+                for i in 0..6 {
+                    for j in 0..6 {
+                        gram[i][j] += wk * psi[0][i] * psi[0][j];
+                    }
+                }
+            }
+            for (i, row) in gram.iter().enumerate() {
+                for (j, &value) in row.iter().enumerate() {
+                    approx_eq(value, if i == j { 1.0 } else { 0.0 }, 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fit_quadrature_works_polynomial_model() {
+        // y = x₀² + 3 x₁ with x₀ ~ N(1, 0.5) and x₁ ~ N(-2, 2)
+        let d0 = DistributionNormal::new(1.0, 0.5).unwrap();
+        let d1 = DistributionNormal::new(-2.0, 2.0).unwrap();
+        let distributions: [&dyn ProbabilityDistribution; 2] = [&d0, &d1];
+        let mut pce = PolynomialChaos::new(&distributions, PceBasis::Hermite, 2).unwrap();
+        let model = |x: &[f64]| Ok(x[0] * x[0] + 3.0 * x[1]);
+        pce.fit_quadrature(model, 3).unwrap();
+
+        // E[x₀²] = σ² + μ² and Var[x₀²] = 4 μ² σ² + 2 σ⁴
+        let var0 = 4.0 * 1.0 * 0.25 + 2.0 * 0.0625;
+        let var1 = 9.0 * 4.0;
+        approx_eq(pce.mean(), 1.25 - 6.0, 1e-13);
+        approx_eq(pce.variance(), var0 + var1, 1e-12);
+        vec_approx_eq(
+            &pce.sobol_first_order(),
+            &[var0 / (var0 + var1), var1 / (var0 + var1)],
+            1e-13,
+        );
+        vec_approx_eq(&pce.sobol_first_order(), &pce.sobol_total_effect(), 1e-15);
+
+        // the expansion reproduces the (polynomial) model
+        for x in [[1.0, -2.0], [0.3, 1.5], [2.0, -4.0]] {
+            approx_eq(pce.eval(&x).unwrap(), model(&x).unwrap(), 1e-12);
+        }
+    }
+
+    #[test]
+    fn fit_quadrature_works_ishigami() {
+        // y = sin(x₀) + a sin²(x₁) + b x₂⁴ sin(x₀) with xᵢ ~ U(-π, π)
+        let (a, b) = (7.0, 0.1);
+        let dist = DistributionUniform::new(-PI, PI).unwrap();
+        let distributions: [&dyn ProbabilityDistribution; 3] = [&dist, &dist, &dist];
+        let mut pce = PolynomialChaos::new(&distributions, PceBasis::Legendre, 14).unwrap();
+        pce.fit_quadrature(
+            |x| {
+                let s = f64::sin(x[1]);
+                Ok(f64::sin(x[0]) + a * s * s + b * f64::powi(x[2], 4) * f64::sin(x[0]))
+            },
+            15,
+        )
+        .unwrap();
+        let pi4 = f64::powi(PI, 4);
+        let v1 = 0.5 * f64::powi(1.0 + b * pi4 / 5.0, 2);
+        let v2 = a * a / 8.0;
+        let v13 = b * b * pi4 * pi4 * (1.0 / 18.0 - 1.0 / 50.0);
+        let v = v1 + v2 + v13;
+        approx_eq(pce.mean(), a / 2.0, 1e-10);
+        approx_eq(pce.variance(), v, 1e-3);
+        vec_approx_eq(&pce.sobol_first_order(), &[v1 / v, v2 / v, 0.0], 1e-3);
+        vec_approx_eq(&pce.sobol_total_effect(), &[(v1 + v13) / v, v2 / v, v13 / v], 1e-3);
+    }
+
+    #[test]
+    fn fit_least_squares_works() {
+        // y = exp(x) with x ~ Lognormal; thus, y is not polynomial in ξ
+        let d0 = DistributionNormal::new(0.0, 0.2).unwrap();
+        let d1 = DistributionLognormal::new(0.0, 0.25).unwrap();
+        let distributions: [&dyn ProbabilityDistribution; 2] = [&d0, &d1];
+        let mut pce = PolynomialChaos::new(&distributions, PceBasis::Hermite, 4).unwrap();
+        assert_eq!(
+            pce.fit_least_squares(|_| Ok(0.0), 14).err(),
+            Some("nsample must be greater than or equal to the number of terms")
+        );
+        let model = |x: &[f64]| Ok(f64::exp(x[0]) + x[1]);
+        pce.fit_least_squares(model, 200).unwrap();
+        // E[exp(x₀)] = exp(σ²/2) and E[x₁] = exp(σ²/2)
+        approx_eq(pce.mean(), f64::exp(0.02) + f64::exp(0.03125), 1e-5);
+        approx_eq(pce.eval(&[0.1, 1.2]).unwrap(), model(&[0.1, 1.2]).unwrap(), 1e-4);
+
+        // same as quadrature
+        let mut quad = PolynomialChaos::new(&distributions, PceBasis::Hermite, 4).unwrap();
+        quad.fit_quadrature(model, 6).unwrap();
+        vec_approx_eq(pce.coefficients(), quad.coefficients(), 1e-4);
+    }
+}
@@ -1,4 +1,6 @@
+use crate::inv_cdf_standard_normal;
 use rand::Rng;
+use russell_lab::math::{erfc, SQRT_2};
 
 /// Defines the Probability Distribution trait
 pub trait ProbabilityDistribution {
@@ -8,6 +10,37 @@ pub trait ProbabilityDistribution {
     /// Implements the Cumulative Density Function (CDF)
     fn cdf(&self, x: f64) -> f64;
 
+    /// Implements the inverse of the Cumulative Density Function (quantile function)
+    ///
+    /// Returns `x` such that `cdf(x) = p` with `p` in `[0, 1]` (NaN otherwise). Thus, a sample
+    /// of this distribution is obtained from a uniform (e.g., quasi-random) number `p`.
+    fn inv_cdf(&self, p: f64) -> f64;
+
+    /// Maps `x` to the standard normal space (isoprobabilistic transformation)
+    ///
+    /// ```text
+    /// u = Φ⁻¹(F(x))
+    /// ```
+    ///
+    /// where `F` is the CDF of this distribution and `Φ` is the standard normal CDF. Thus, `u`
+    /// is a sample of `N(0, 1)` if `x` is a sample of this distribution (e.g., as in FORM).
+    ///
+    /// **Note:** The default implementation loses accuracy far in the upper tail where `F(x) ≈ 1`.
+    fn x_to_u(&self, x: f64) -> f64 {
+        inv_cdf_standard_normal(self.cdf(x))
+    }
+
+    /// Maps `u` from the standard normal space to this distribution (inverse of [ProbabilityDistribution::x_to_u])
+    ///
+    /// ```text
+    /// x = F⁻¹(Φ(u))
+    /// ```
+    ///
+    /// For example, a (Latin hypercube) sample of `N(0, 1)` is mapped to a sample of this distribution.
+    fn u_to_x(&self, u: f64) -> f64 {
+        self.inv_cdf(0.5 * erfc(-u / SQRT_2))
+    }
+
     /// Returns the Mean
     fn mean(&self) -> f64;
 
@@ -15,5 +48,10 @@ pub trait ProbabilityDistribution {
     fn variance(&self) -> f64;
 
     /// Generates a pseudo-random number belonging to this probability distribution
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64;
+    ///
+    /// **Note:** This function is not available for trait objects (`&dyn ProbabilityDistribution`);
+    /// use [ProbabilityDistribution::inv_cdf] with a uniform number instead.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64
+    where
+        Self: Sized;
 }
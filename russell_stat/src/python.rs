@@ -0,0 +1,261 @@
+//! Python bindings (requires the `python` feature)
+//!
+//! This module defines the Python classes of the probability distributions. The classes are
+//! registered into a Python module by [register]; for instance, the `russell` extension module
+//! of russell_sparse calls this function.
+//!
+//! ```text
+//! from russell import DistributionNormal
+//! dist = DistributionNormal(0.0, 1.0)
+//! print(dist.cdf(0.0)) # 0.5
+//! samples = dist.sample(1000, seed=42)
+//! ```
+
+use crate::{
+    DistributionFisherF, DistributionFrechet, DistributionGenPareto, DistributionGumbel, DistributionLognormal,
+    DistributionNormal, DistributionStable, DistributionStudentT, DistributionUniform, ProbabilityDistribution,
+    StrError,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Converts an error into a Python ValueError
+fn to_py_err(err: StrError) -> PyErr {
+    PyValueError::new_err(err)
+}
+
+/// Generates n samples using a seeded (reproducible) or an unseeded generator
+fn generate_samples<D: ProbabilityDistribution>(dist: &D, n: usize, seed: Option<u64>) -> Vec<f64> {
+    let mut rng = match seed {
+        Some(s) => StdRng::seed_from_u64(s),
+        None => StdRng::from_entropy(),
+    };
+    (0..n).map(|_| dist.sample(&mut rng)).collect()
+}
+
+/// Defines a Python class wrapping a probability distribution
+macro_rules! py_distribution {
+    ($py_type:ident, $name:literal, $dist:ident, ($($arg:ident),*) $(, $extra:item)*) => {
+        #[doc = concat!("Holds a ", stringify!($dist), " (Python class `", $name, "`)")]
+        #[pyclass(name = $name)]
+        pub struct $py_type {
+            dist: $dist,
+        }
+
+        #[pymethods]
+        impl $py_type {
+            #[new]
+            pub fn new($($arg: f64),*) -> PyResult<Self> {
+                Ok($py_type {
+                    dist: $dist::new($($arg),*).map_err(to_py_err)?,
+                })
+            }
+
+            /// Computes the probability density function
+            pub fn pdf(&self, x: f64) -> f64 {
+                self.dist.pdf(x)
+            }
+
+            /// Computes the cumulative density function
+            pub fn cdf(&self, x: f64) -> f64 {
+                self.dist.cdf(x)
+            }
+
+            /// Computes the inverse of the cumulative density function
+            pub fn inv_cdf(&self, p: f64) -> f64 {
+                self.dist.inv_cdf(p)
+            }
+
+            /// Maps x to the standard normal space
+            pub fn x_to_u(&self, x: f64) -> f64 {
+                self.dist.x_to_u(x)
+            }
+
+            /// Maps u from the standard normal space
+            pub fn u_to_x(&self, u: f64) -> f64 {
+                self.dist.u_to_x(u)
+            }
+
+            /// Returns the mean
+            pub fn mean(&self) -> f64 {
+                self.dist.mean()
+            }
+
+            /// Returns the variance
+            pub fn variance(&self) -> f64 {
+                self.dist.variance()
+            }
+
+            /// Generates n pseudo-random numbers (reproducible if the seed is given)
+            #[pyo3(signature = (n, seed = None))]
+            pub fn sample(&self, n: usize, seed: Option<u64>) -> Vec<f64> {
+                generate_samples(&self.dist, n, seed)
+            }
+
+            $($extra)*
+        }
+    };
+}
+
+py_distribution!(
+    PyDistributionFisherF,
+    "DistributionFisherF",
+    DistributionFisherF,
+    (d1, d2)
+);
+
+py_distribution!(
+    PyDistributionFrechet,
+    "DistributionFrechet",
+    DistributionFrechet,
+    (location, scale, shape)
+);
+
+py_distribution!(
+    PyDistributionGenPareto,
+    "DistributionGenPareto",
+    DistributionGenPareto,
+    (location, scale, shape)
+);
+
+py_distribution!(
+    PyDistributionGumbel,
+    "DistributionGumbel",
+    DistributionGumbel,
+    (location, scale),
+    /// Creates a new distribution given the mean and standard deviation
+    #[staticmethod]
+    pub fn from_mu_sig(mu: f64, sig: f64) -> PyResult<Self> {
+        Ok(PyDistributionGumbel {
+            dist: DistributionGumbel::new_from_mu_sig(mu, sig).map_err(to_py_err)?,
+        })
+    }
+);
+
+py_distribution!(
+    PyDistributionLognormal,
+    "DistributionLognormal",
+    DistributionLognormal,
+    (mu_logx, sig_logx),
+    /// Creates a new distribution given the mean and standard deviation
+    #[staticmethod]
+    pub fn from_mu_sig(mu: f64, sig: f64) -> PyResult<Self> {
+        Ok(PyDistributionLognormal {
+            dist: DistributionLognormal::new_from_mu_sig(mu, sig).map_err(to_py_err)?,
+        })
+    }
+);
+
+py_distribution!(
+    PyDistributionNormal,
+    "DistributionNormal",
+    DistributionNormal,
+    (mu, sig)
+);
+
+py_distribution!(
+    PyDistributionStable,
+    "DistributionStable",
+    DistributionStable,
+    (alpha, beta, scale, location)
+);
+
+py_distribution!(
+    PyDistributionStudentT,
+    "DistributionStudentT",
+    DistributionStudentT,
+    (dof, location, scale)
+);
+
+py_distribution!(
+    PyDistributionUniform,
+    "DistributionUniform",
+    DistributionUniform,
+    (xmin, xmax)
+);
+
+/// Registers the classes of this module into a Python module
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyDistributionFisherF>()?;
+    m.add_class::<PyDistributionFrechet>()?;
+    m.add_class::<PyDistributionGenPareto>()?;
+    m.add_class::<PyDistributionGumbel>()?;
+    m.add_class::<PyDistributionLognormal>()?;
+    m.add_class::<PyDistributionNormal>()?;
+    m.add_class::<PyDistributionStable>()?;
+    m.add_class::<PyDistributionStudentT>()?;
+    m.add_class::<PyDistributionUniform>()?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        PyDistributionFisherF, PyDistributionFrechet, PyDistributionGenPareto, PyDistributionGumbel,
+        PyDistributionLognormal, PyDistributionNormal, PyDistributionStable, PyDistributionStudentT,
+        PyDistributionUniform,
+    };
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn distributions_work() {
+        let normal = PyDistributionNormal::new(1.0, 2.0).unwrap();
+        approx_eq(normal.cdf(1.0), 0.5, 1e-15);
+        assert_eq!(normal.mean(), 1.0);
+        assert_eq!(normal.variance(), 4.0);
+        approx_eq(normal.x_to_u(3.0), 1.0, 1e-15);
+        approx_eq(normal.u_to_x(-1.0), -1.0, 1e-15);
+        approx_eq(
+            normal.pdf(1.0),
+            1.0 / (2.0 * f64::sqrt(2.0 * std::f64::consts::PI)),
+            1e-15,
+        );
+        assert!(PyDistributionNormal::new(0.0, f64::NAN).is_err());
+
+        let uniform = PyDistributionUniform::new(0.0, 2.0).unwrap();
+        assert_eq!(uniform.mean(), 1.0);
+        assert!(PyDistributionUniform::new(2.0, 1.0).is_err());
+
+        let gumbel = PyDistributionGumbel::from_mu_sig(10.0, 2.0).unwrap();
+        approx_eq(gumbel.mean(), 10.0, 1e-14);
+        approx_eq(gumbel.variance(), 4.0, 1e-14);
+        assert!(PyDistributionGumbel::new(0.0, 1.0).is_ok());
+
+        let lognormal = PyDistributionLognormal::from_mu_sig(10.0, 2.0).unwrap();
+        approx_eq(lognormal.mean(), 10.0, 1e-14);
+        assert!(PyDistributionLognormal::new(0.0, 1.0).is_ok());
+
+        let frechet = PyDistributionFrechet::new(0.0, 1.0, 3.0).unwrap();
+        assert!(frechet.mean() > 0.0);
+
+        let gen_pareto = PyDistributionGenPareto::new(0.0, 1.0, 0.5).unwrap();
+        assert_eq!(gen_pareto.mean(), 2.0);
+        assert!(PyDistributionGenPareto::new(0.0, -1.0, 0.5).is_err());
+
+        let fisher_f = PyDistributionFisherF::new(2.0, 2.0).unwrap();
+        approx_eq(fisher_f.cdf(1.0), 0.5, 1e-15);
+        assert!(PyDistributionFisherF::new(0.0, 1.0).is_err());
+
+        let stable = PyDistributionStable::new(1.0, 0.0, 1.0, 2.0).unwrap();
+        approx_eq(stable.cdf(2.0), 0.5, 1e-15);
+        assert!(PyDistributionStable::new(2.5, 0.0, 1.0, 0.0).is_err());
+
+        let student_t = PyDistributionStudentT::new(5.0, 1.0, 2.0).unwrap();
+        assert_eq!(student_t.mean(), 1.0);
+        assert!(PyDistributionStudentT::new(0.0, 0.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn sample_is_reproducible_with_seed() {
+        let normal = PyDistributionNormal::new(0.0, 1.0).unwrap();
+        let a = normal.sample(10, Some(123));
+        let b = normal.sample(10, Some(123));
+        assert_eq!(a.len(), 10);
+        assert_eq!(a, b);
+        assert_eq!(normal.sample(5, None).len(), 5);
+    }
+}
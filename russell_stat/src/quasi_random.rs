@@ -0,0 +1,452 @@
+use crate::StrError;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Number of bits of the Sobol points
+const SOBOL_BITS: usize = 32;
+
+/// Primitive polynomials and initial direction numbers of the Sobol sequence (dimensions 2, 3, …)
+///
+/// Each row holds the degree `s`, the coefficients `a` and the initial direction numbers `m₁, …, mₛ`.
+/// The values are the first rows of the `new-joe-kuo-6.21201` table by S. Joe and F. Y. Kuo (2008).
+#[rustfmt::skip]
+const SOBOL_DIRECTIONS: [(usize, u32, &[u32]); 20] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+/// Maximum number of dimensions of the Sobol sequence
+pub const SOBOL_MAX_NDIM: usize = SOBOL_DIRECTIONS.len() + 1;
+
+/// Defines a low-discrepancy (quasi-random) sequence of points in the unit hypercube `[0, 1)ᵈ`
+///
+/// The points fill the hypercube more evenly than pseudo-random numbers; thus, quasi–Monte Carlo
+/// estimates converge faster (nearly `O(1/n)` instead of `O(1/√n)`). Each coordinate may be mapped
+/// onto a probability distribution by [crate::ProbabilityDistribution::inv_cdf].
+pub trait QuasiRandomSequence {
+    /// Returns the number of dimensions
+    fn ndim(&self) -> usize;
+
+    /// Computes the next point of the sequence
+    ///
+    /// # Output
+    ///
+    /// * `point` -- (ndim) the coordinates in `[0, 1)`
+    fn next_point(&mut self, point: &mut [f64]) -> Result<(), StrError>;
+}
+
+/// Implements the Sobol sequence (base-2 digital net) with optional scrambling
+///
+/// The points are generated in Gray-code order from the direction numbers of Joe and Kuo.
+/// The scrambled version applies a random linear matrix scrambling followed by a random
+/// digital shift, which preserves the net properties (e.g., each block of `2ᵏ` points is
+/// stratified along each coordinate) and removes the deterministic artifacts of the sequence.
+///
+/// **Note:** The first point of the non-scrambled sequence is the origin; thus, it maps to `-∞`
+/// for unbounded distributions. Use the scrambled sequence or skip this point in such cases.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{DistributionNormal, ProbabilityDistribution, QuasiRandomSequence, SequenceSobol, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // estimate the mean of x² + y² with x, y ~ N(0, 1)
+///     let dist = DistributionNormal::new(0.0, 1.0)?;
+///     let mut sobol = SequenceSobol::new_scrambled(2, 1234)?;
+///     let mut u = vec![0.0; 2];
+///     let n = 1024;
+///     let mut sum = 0.0;
+///     for _ in 0..n {
+///         sobol.next_point(&mut u)?;
+///         let (x, y) = (dist.inv_cdf(u[0]), dist.inv_cdf(u[1]));
+///         sum += x * x + y * y;
+///     }
+///     let mean = sum / (n as f64);
+///     assert!(f64::abs(mean - 2.0) < 0.02);
+///     Ok(())
+/// }
+/// ```
+pub struct SequenceSobol {
+    ndim: usize,                        // number of dimensions
+    directions: Vec<[u32; SOBOL_BITS]>, // (ndim) direction numbers (scrambled, if requested)
+    shift: Vec<u32>,                    // (ndim) digital shift (zero if not scrambled)
+    state: Vec<u32>,                    // (ndim) current point (integer representation)
+    index: u64,                         // index of the next point
+}
+
+impl SequenceSobol {
+    /// Allocates a new (non-scrambled) instance
+    ///
+    /// # Input
+    ///
+    /// * `ndim` -- the number of dimensions (`1 ≤ ndim ≤ SOBOL_MAX_NDIM`)
+    pub fn new(ndim: usize) -> Result<Self, StrError> {
+        if !(1..=SOBOL_MAX_NDIM).contains(&ndim) {
+            return Err("ndim must satisfy 1 ≤ ndim ≤ SOBOL_MAX_NDIM");
+        }
+        let mut directions = vec![[0; SOBOL_BITS]; ndim];
+        for (k, v) in directions[0].iter_mut().enumerate() {
+            *v = 1 << (SOBOL_BITS - 1 - k);
+        }
+        for (v, &(s, a, m)) in directions.iter_mut().skip(1).zip(SOBOL_DIRECTIONS.iter()) {
+            for k in 0..s {
+                v[k] = m[k] << (SOBOL_BITS - 1 - k);
+            }
+            for k in s..SOBOL_BITS {
+                v[k] = v[k - s] ^ (v[k - s] >> s);
+                for i in 1..s {
+                    if (a >> (s - 1 - i)) & 1 == 1 {
+                        v[k] ^= v[k - i];
+                    }
+                }
+            }
+        }
+        Ok(SequenceSobol {
+            ndim,
+            directions,
+            shift: vec![0; ndim],
+            state: vec![0; ndim],
+            index: 0,
+        })
+    }
+
+    /// Allocates a new scrambled instance
+    ///
+    /// # Input
+    ///
+    /// * `ndim` -- the number of dimensions (`1 ≤ ndim ≤ SOBOL_MAX_NDIM`)
+    /// * `seed` -- the seed of the random scrambling (thus, the points are reproducible)
+    pub fn new_scrambled(ndim: usize, seed: u64) -> Result<Self, StrError> {
+        let mut sobol = SequenceSobol::new(ndim)?;
+        let mut rng = StdRng::seed_from_u64(seed);
+        for (v, shift) in sobol.directions.iter_mut().zip(sobol.shift.iter_mut()) {
+            // random lower triangular matrix with unit diagonal (row b holds the bits influencing bit b)
+            let mut rows = [0_u32; SOBOL_BITS];
+            for (b, row) in rows.iter_mut().enumerate() {
+                let higher = if b == SOBOL_BITS - 1 { 0 } else { u32::MAX << (b + 1) };
+                *row = (rng.gen::<u32>() & higher) | (1 << b);
+            }
+            for vk in v.iter_mut() {
+                let mut scrambled = 0;
+                for (b, row) in rows.iter().enumerate() {
+                    scrambled |= ((row & *vk).count_ones() & 1) << b;
+                }
+                *vk = scrambled;
+            }
+            *shift = rng.gen();
+        }
+        sobol.state.copy_from_slice(&sobol.shift);
+        Ok(sobol)
+    }
+}
+
+impl QuasiRandomSequence for SequenceSobol {
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    fn next_point(&mut self, point: &mut [f64]) -> Result<(), StrError> {
+        if point.len() != self.ndim {
+            return Err("point must have length equal to ndim");
+        }
+        if self.index >= (1 << SOBOL_BITS) {
+            return Err("the maximum number of points (2³²) has been reached");
+        }
+        let scale = 1.0 / ((1_u64 << SOBOL_BITS) as f64);
+        for (x, &s) in point.iter_mut().zip(&self.state) {
+            *x = (s as f64) * scale;
+        }
+        let c = self.index.trailing_ones() as usize; // Gray code: bit flipping from index to index + 1
+        if c < SOBOL_BITS {
+            for (s, v) in self.state.iter_mut().zip(&self.directions) {
+                *s ^= v[c];
+            }
+        }
+        self.index += 1;
+        Ok(())
+    }
+}
+
+/// Implements the Halton sequence (radical inverse in prime bases) with optional scrambling
+///
+/// The coordinate `j` of the point `k` is the radical inverse of `k` in the base `bⱼ` given by the
+/// j-th prime number. The sequence starts at `k = 1`; thus, the origin is never generated.
+/// The scrambled version permutes the digits `1, …, bⱼ - 1` randomly (one permutation per
+/// coordinate), which removes the correlations between higher dimensions.
+///
+/// **Note:** The quality of the Halton sequence deteriorates for large dimensions (e.g., above 10
+/// for the non-scrambled version); prefer [SequenceSobol] in such cases.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{QuasiRandomSequence, SequenceHalton, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mut halton = SequenceHalton::new(2)?;
+///     let mut x = vec![0.0; 2];
+///     halton.next_point(&mut x)?;
+///     assert_eq!(x, &[1.0 / 2.0, 1.0 / 3.0]);
+///     halton.next_point(&mut x)?;
+///     assert_eq!(x, &[1.0 / 4.0, 2.0 / 3.0]);
+///     Ok(())
+/// }
+/// ```
+pub struct SequenceHalton {
+    bases: Vec<u64>,                     // (ndim) prime bases
+    permutations: Option<Vec<Vec<u64>>>, // (ndim) digit permutations (if scrambled)
+    index: u64,                          // index of the next point
+}
+
+impl SequenceHalton {
+    /// Allocates a new (non-scrambled) instance
+    ///
+    /// # Input
+    ///
+    /// * `ndim` -- the number of dimensions (≥ 1)
+    pub fn new(ndim: usize) -> Result<Self, StrError> {
+        if ndim < 1 {
+            return Err("ndim must be greater than zero");
+        }
+        let mut bases = Vec::with_capacity(ndim);
+        let mut candidate = 2;
+        while bases.len() < ndim {
+            if bases
+                .iter()
+                .take_while(|&&p| p * p <= candidate)
+                .all(|&p| candidate % p != 0)
+            {
+                bases.push(candidate);
+            }
+            candidate += 1;
+        }
+        Ok(SequenceHalton {
+            bases,
+            permutations: None,
+            index: 1,
+        })
+    }
+
+    /// Allocates a new scrambled instance
+    ///
+    /// # Input
+    ///
+    /// * `ndim` -- the number of dimensions (≥ 1)
+    /// * `seed` -- the seed of the random permutations (thus, the points are reproducible)
+    pub fn new_scrambled(ndim: usize, seed: u64) -> Result<Self, StrError> {
+        let mut halton = SequenceHalton::new(ndim)?;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let permutations = halton
+            .bases
+            .iter()
+            .map(|&b| {
+                let mut perm: Vec<u64> = (0..b).collect();
+                perm[1..].shuffle(&mut rng); // zero is kept fixed (finite expansions remain finite)
+                perm
+            })
+            .collect();
+        halton.permutations = Some(permutations);
+        Ok(halton)
+    }
+}
+
+impl QuasiRandomSequence for SequenceHalton {
+    fn ndim(&self) -> usize {
+        self.bases.len()
+    }
+
+    fn next_point(&mut self, point: &mut [f64]) -> Result<(), StrError> {
+        if point.len() != self.bases.len() {
+            return Err("point must have length equal to ndim");
+        }
+        for (j, (x, &b)) in point.iter_mut().zip(&self.bases).enumerate() {
+            let inv_b = 1.0 / (b as f64);
+            let mut factor = inv_b;
+            let mut k = self.index;
+            let mut sum = 0.0;
+            while k > 0 {
+                let digit = match &self.permutations {
+                    Some(perms) => perms[j][(k % b) as usize],
+                    None => k % b,
+                };
+                sum += (digit as f64) * factor;
+                factor *= inv_b;
+                k /= b;
+            }
+            *x = sum;
+        }
+        self.index += 1;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{QuasiRandomSequence, SequenceHalton, SequenceSobol, SOBOL_MAX_NDIM};
+    use crate::{DistributionNormal, ProbabilityDistribution};
+
+    /// Checks that each coordinate of the first `n = bᵐ` points has exactly one point in each interval `[i/n, (i+1)/n)`
+    fn check_stratification(seq: &mut dyn QuasiRandomSequence, n: usize) {
+        let ndim = seq.ndim();
+        let mut counts = vec![vec![0; n]; ndim];
+        let mut x = vec![0.0; ndim];
+        for _ in 0..n {
+            seq.next_point(&mut x).unwrap();
+            for (count, &xj) in counts.iter_mut().zip(&x) {
+                assert!((0.0..1.0).contains(&xj));
+                count[(xj * (n as f64)) as usize] += 1;
+            }
+        }
+        for count in &counts {
+            assert!(count.iter().all(|&c| c == 1));
+        }
+    }
+
+    #[test]
+    fn new_captures_errors() {
+        assert_eq!(
+            SequenceSobol::new(0).err(),
+            Some("ndim must satisfy 1 ≤ ndim ≤ SOBOL_MAX_NDIM")
+        );
+        assert_eq!(
+            SequenceSobol::new_scrambled(SOBOL_MAX_NDIM + 1, 0).err(),
+            Some("ndim must satisfy 1 ≤ ndim ≤ SOBOL_MAX_NDIM")
+        );
+        assert_eq!(SequenceHalton::new(0).err(), Some("ndim must be greater than zero"));
+        let mut sobol = SequenceSobol::new(2).unwrap();
+        let mut halton = SequenceHalton::new(2).unwrap();
+        let mut x = vec![0.0; 3];
+        assert_eq!(
+            sobol.next_point(&mut x).err(),
+            Some("point must have length equal to ndim")
+        );
+        assert_eq!(
+            halton.next_point(&mut x).err(),
+            Some("point must have length equal to ndim")
+        );
+    }
+
+    #[test]
+    fn sobol_works() {
+        // reference values from scipy.stats.qmc.Sobol(3, scramble=False)
+        #[rustfmt::skip]
+        let correct = [
+            [0.0,   0.0,   0.0  ],
+            [0.5,   0.5,   0.5  ],
+            [0.75,  0.25,  0.25 ],
+            [0.25,  0.75,  0.75 ],
+            [0.375, 0.375, 0.625],
+            [0.875, 0.875, 0.125],
+            [0.625, 0.125, 0.875],
+            [0.125, 0.625, 0.375],
+        ];
+        let mut sobol = SequenceSobol::new(3).unwrap();
+        assert_eq!(sobol.ndim(), 3);
+        let mut x = vec![0.0; 3];
+        for row in &correct {
+            sobol.next_point(&mut x).unwrap();
+            assert_eq!(x, row);
+        }
+        check_stratification(&mut SequenceSobol::new(SOBOL_MAX_NDIM).unwrap(), 1024);
+    }
+
+    #[test]
+    fn sobol_scrambled_works() {
+        check_stratification(&mut SequenceSobol::new_scrambled(SOBOL_MAX_NDIM, 1234).unwrap(), 1024);
+
+        // reproducible and different from the non-scrambled sequence
+        let mut a = SequenceSobol::new_scrambled(2, 7).unwrap();
+        let mut b = SequenceSobol::new_scrambled(2, 7).unwrap();
+        let mut xa = vec![0.0; 2];
+        let mut xb = vec![0.0; 2];
+        for _ in 0..10 {
+            a.next_point(&mut xa).unwrap();
+            b.next_point(&mut xb).unwrap();
+            assert_eq!(xa, xb);
+        }
+        assert!(xa[0] != 0.0 && xa[1] != 0.0);
+    }
+
+    #[test]
+    fn halton_works() {
+        let mut halton = SequenceHalton::new(3).unwrap();
+        assert_eq!(halton.ndim(), 3);
+        let mut x = vec![0.0; 3];
+        let correct = [
+            [1.0 / 2.0, 1.0 / 3.0, 1.0 / 5.0],
+            [1.0 / 4.0, 2.0 / 3.0, 2.0 / 5.0],
+            [3.0 / 4.0, 1.0 / 9.0, 3.0 / 5.0],
+            [1.0 / 8.0, 4.0 / 9.0, 4.0 / 5.0],
+            [5.0 / 8.0, 7.0 / 9.0, 1.0 / 25.0],
+        ];
+        for row in &correct {
+            halton.next_point(&mut x).unwrap();
+            for j in 0..3 {
+                assert!(f64::abs(x[j] - row[j]) < 1e-15);
+            }
+        }
+        let bases = SequenceHalton::new(10).unwrap().bases;
+        assert_eq!(bases, &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn halton_scrambled_works() {
+        // the first b-1 points (starting at k = 1) are the digits 1/b, …, (b-1)/b (permuted)
+        let mut halton = SequenceHalton::new_scrambled(3, 1234).unwrap();
+        let mut x = vec![0.0; 3];
+        let mut coords = [0.0; 4];
+        for c in coords.iter_mut() {
+            halton.next_point(&mut x).unwrap();
+            *c = x[2];
+        }
+        coords.sort_by(|a, b| a.total_cmp(b));
+        for (i, c) in coords.iter().enumerate() {
+            assert!(f64::abs(c - ((i + 1) as f64) / 5.0) < 1e-15);
+        }
+    }
+
+    #[test]
+    fn quasi_monte_carlo_beats_monte_carlo() {
+        // E[x] = 0 and E[x²] = 1 with x ~ N(0, 1)
+        let dist = DistributionNormal::new(0.0, 1.0).unwrap();
+        let n = 4096;
+        let mut x = vec![0.0; 1];
+        let mut sobol = SequenceSobol::new_scrambled(1, 42).unwrap();
+        let mut halton = SequenceHalton::new(1).unwrap();
+        let seqs: [&mut dyn QuasiRandomSequence; 2] = [&mut sobol, &mut halton];
+        for seq in seqs {
+            let (mut sum, mut sum_sq) = (0.0, 0.0);
+            for _ in 0..n {
+                seq.next_point(&mut x).unwrap();
+                let v = dist.inv_cdf(x[0]);
+                sum += v;
+                sum_sq += v * v;
+            }
+            // the standard error of plain Monte Carlo would be about 1/√n ≈ 0.016
+            assert!(f64::abs(sum / (n as f64)) < 1e-3);
+            assert!(f64::abs(sum_sq / (n as f64) - 1.0) < 1e-2);
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{ProbabilityDistribution, StrError};
+use rand::distributions::Open01;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Seed of the pseudo-random samples (thus, the results are reproducible)
+const SEED: u64 = 314159;
+
+/// Number of bootstrap resamples used to compute the confidence intervals
+const BOOTSTRAP_RESAMPLES: usize = 200;
+
+/// Confidence level of the bootstrap intervals
+const CONFIDENCE_LEVEL: f64 = 0.95;
+
+/// Holds the results of the global sensitivity analysis
+#[derive(Clone, Debug)]
+pub struct SobolIndices {
+    /// (ndim) First-order indices `Sᵢ` (fraction of the variance explained by `xᵢ` alone)
+    pub first_order: Vec<f64>,
+
+    /// (ndim) Total-effect indices `STᵢ` (fraction of the variance involving `xᵢ`, including interactions)
+    pub total_effect: Vec<f64>,
+
+    /// (ndim) Bootstrap confidence intervals `(lower, upper)` of the first-order indices
+    pub first_order_interval: Vec<(f64, f64)>,
+
+    /// (ndim) Bootstrap confidence intervals `(lower, upper)` of the total-effect indices
+    pub total_effect_interval: Vec<(f64, f64)>,
+
+    /// Estimated mean of the model output
+    pub mean: f64,
+
+    /// Estimated variance of the model output
+    pub variance: f64,
+}
+
+/// Computes the first-order and total-effect Sobol indices of a model with independent random inputs
+///
+/// Two independent (n,ndim) sample matrices `A` and `B` are generated and the model is evaluated at
+/// `A`, `B`, and `A_B⁽ⁱ⁾` (the matrix `A` with the column `i` taken from `B`); thus, the model is called
+/// `n ⋅ (ndim + 2)` times. The indices are given by the pick–freeze estimators of Saltelli (2010)
+/// and Jansen (1999):
+///
+/// ```text
+///        1    n
+/// Sᵢ  = ─── ⋅ Σ  f(B)ⱼ ⋅ (f(A_B⁽ⁱ⁾)ⱼ - f(A)ⱼ)
+///       n V  j=1
+///
+///        1     n
+/// STᵢ = ──── ⋅ Σ  (f(A)ⱼ - f(A_B⁽ⁱ⁾)ⱼ)²
+///       2n V  j=1
+/// ```
+///
+/// where `V` is the variance of the output. The 95% confidence intervals are obtained by resampling
+/// the n rows (with replacement) and computing the percentiles of the bootstrap estimates.
+///
+/// # Input
+///
+/// * `model` -- the function `y = f(x)` with `x.len() = ndim`
+/// * `distributions` -- (ndim) the probability distributions of the inputs
+/// * `n` -- the number of base samples (≥ 2; e.g., 1000 or more)
+///
+/// **Note:** The samples are generated with a fixed seed; thus, the results are reproducible.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{sobol_indices, DistributionNormal, ProbabilityDistribution, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // y = x₀ + 2 x₁ with x₀, x₁ ~ N(0, 1); thus, V = 5, S₀ = 1/5 and S₁ = 4/5
+///     let dist = DistributionNormal::new(0.0, 1.0)?;
+///     let distributions: [&dyn ProbabilityDistribution; 2] = [&dist, &dist];
+///     let res = sobol_indices(|x| Ok(x[0] + 2.0 * x[1]), &distributions, 2000)?;
+///     assert!(f64::abs(res.first_order[0] - 0.2) < 0.05);
+///     assert!(f64::abs(res.total_effect[1] - 0.8) < 0.05);
+///     Ok(())
+/// }
+/// ```
+pub fn sobol_indices<F>(
+    mut model: F,
+    distributions: &[&dyn ProbabilityDistribution],
+    n: usize,
+) -> Result<SobolIndices, StrError>
+where
+    F: FnMut(&[f64]) -> Result<f64, StrError>,
+{
+    let ndim = distributions.len();
+    if ndim < 1 {
+        return Err("at least one distribution is required");
+    }
+    if n < 2 {
+        return Err("n must be at least 2");
+    }
+
+    // sample matrices (row-major)
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let sample = |rng: &mut StdRng| -> Vec<f64> {
+        let mut m = vec![0.0; n * ndim];
+        for row in m.chunks_exact_mut(ndim) {
+            for (x, dist) in row.iter_mut().zip(distributions) {
+                *x = dist.inv_cdf(rng.sample(Open01));
+            }
+        }
+        m
+    };
+    let a = sample(&mut rng);
+    let b = sample(&mut rng);
+
+    // model evaluations
+    let mut fa = vec![0.0; n];
+    let mut fb = vec![0.0; n];
+    let mut fab = vec![vec![0.0; n]; ndim];
+    let mut x = vec![0.0; ndim];
+    for j in 0..n {
+        let (a_row, b_row) = (&a[j * ndim..(j + 1) * ndim], &b[j * ndim..(j + 1) * ndim]);
+        fa[j] = model(a_row)?;
+        fb[j] = model(b_row)?;
+        for (i, fab_i) in fab.iter_mut().enumerate() {
+            x.copy_from_slice(a_row);
+            x[i] = b_row[i];
+            fab_i[j] = model(&x)?;
+        }
+    }
+
+    // estimates
+    let rows: Vec<usize> = (0..n).collect();
+    let (mean, variance) = output_mean_and_variance(&fa, &fb, &rows);
+    if variance <= 0.0 {
+        return Err("the variance of the model output is zero");
+    }
+    let mut first_order = vec![0.0; ndim];
+    let mut total_effect = vec![0.0; ndim];
+    for i in 0..ndim {
+        (first_order[i], total_effect[i]) = estimate_indices(&fa, &fb, &fab[i], &rows, variance);
+    }
+
+    // bootstrap confidence intervals
+    let mut boot_first = vec![vec![0.0; BOOTSTRAP_RESAMPLES]; ndim];
+    let mut boot_total = vec![vec![0.0; BOOTSTRAP_RESAMPLES]; ndim];
+    let mut rows = vec![0; n];
+    for r in 0..BOOTSTRAP_RESAMPLES {
+        for k in rows.iter_mut() {
+            *k = rng.gen_range(0..n);
+        }
+        let (_, var) = output_mean_and_variance(&fa, &fb, &rows);
+        for i in 0..ndim {
+            (boot_first[i][r], boot_total[i][r]) = if var > 0.0 {
+                estimate_indices(&fa, &fb, &fab[i], &rows, var)
+            } else {
+                (0.0, 0.0)
+            };
+        }
+    }
+    Ok(SobolIndices {
+        first_order,
+        total_effect,
+        first_order_interval: boot_first.iter_mut().map(|s| percentile_interval(s)).collect(),
+        total_effect_interval: boot_total.iter_mut().map(|s| percentile_interval(s)).collect(),
+        mean,
+        variance,
+    })
+}
+
+/// Computes the mean and variance of the outputs `f(A)` and `f(B)` (selected rows)
+fn output_mean_and_variance(fa: &[f64], fb: &[f64], rows: &[usize]) -> (f64, f64) {
+    let count = (2 * rows.len()) as f64;
+    let mean = rows.iter().map(|&j| fa[j] + fb[j]).sum::<f64>() / count;
+    let sum_sq: f64 = rows
+        .iter()
+        .map(|&j| (fa[j] - mean) * (fa[j] - mean) + (fb[j] - mean) * (fb[j] - mean))
+        .sum();
+    (mean, sum_sq / (count - 1.0))
+}
+
+/// Computes the first-order and total-effect estimators (selected rows)
+fn estimate_indices(fa: &[f64], fb: &[f64], fab_i: &[f64], rows: &[usize], variance: f64) -> (f64, f64) {
+    let nf = rows.len() as f64;
+    let mut sum_first = 0.0;
+    let mut sum_total = 0.0;
+    for &j in rows {
+        sum_first += fb[j] * (fab_i[j] - fa[j]);
+        sum_total += (fa[j] - fab_i[j]) * (fa[j] - fab_i[j]);
+    }
+    (sum_first / (nf * variance), sum_total / (2.0 * nf * variance))
+}
+
+/// Returns the percentile interval of the bootstrap estimates (sorts the values)
+fn percentile_interval(values: &mut [f64]) -> (f64, f64) {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let last = (values.len() - 1) as f64;
+    let alpha = (1.0 - CONFIDENCE_LEVEL) / 2.0;
+    let lower = values[f64::round(alpha * last) as usize];
+    let upper = values[f64::round((1.0 - alpha) * last) as usize];
+    (lower, upper)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{percentile_interval, sobol_indices};
+    use crate::{DistributionNormal, DistributionUniform, ProbabilityDistribution};
+    use russell_lab::math::PI;
+
+    #[test]
+    fn sobol_indices_captures_errors() {
+        let dist = DistributionNormal::new(0.0, 1.0).unwrap();
+        assert_eq!(
+            sobol_indices(|_| Ok(0.0), &[], 10).err(),
+            Some("at least one distribution is required")
+        );
+        assert_eq!(
+            sobol_indices(|_| Ok(0.0), &[&dist], 1).err(),
+            Some("n must be at least 2")
+        );
+        assert_eq!(
+            sobol_indices(|_| Ok(1.0), &[&dist], 10).err(),
+            Some("the variance of the model output is zero")
+        );
+        assert_eq!(sobol_indices(|_| Err("stop"), &[&dist], 10).err(), Some("stop"));
+    }
+
+    #[test]
+    fn percentile_interval_works() {
+        let mut values: Vec<f64> = (0..101).rev().map(|i| i as f64).collect();
+        assert_eq!(percentile_interval(&mut values), (3.0, 98.0));
+    }
+
+    #[test]
+    fn sobol_indices_works_linear_model() {
+        // y = x₀ + 2 x₁ + 0 x₂ with xᵢ ~ N(0, 1)
+        let dist = DistributionNormal::new(0.0, 1.0).unwrap();
+        let distributions: [&dyn ProbabilityDistribution; 3] = [&dist, &dist, &dist];
+        let mut count = 0;
+        let res = sobol_indices(
+            |x| {
+                count += 1;
+                Ok(x[0] + 2.0 * x[1] + 0.0 * x[2])
+            },
+            &distributions,
+            5000,
+        )
+        .unwrap();
+        assert_eq!(count, 5000 * 5);
+        assert!(f64::abs(res.mean) < 0.1);
+        assert!(f64::abs(res.variance - 5.0) < 0.2);
+        let correct = [0.2, 0.8, 0.0];
+        for (i, &c) in correct.iter().enumerate() {
+            assert!(f64::abs(res.first_order[i] - c) < 0.03);
+            assert!(f64::abs(res.total_effect[i] - c) < 0.03);
+            let (lower, upper) = res.first_order_interval[i];
+            assert!(lower < upper || c == 0.0);
+            assert!(lower <= res.first_order[i] + 1e-15 && res.first_order[i] <= upper + 1e-15);
+        }
+        // the non-influential variable has no total effect at all
+        assert_eq!(res.total_effect[2], 0.0);
+        assert_eq!(res.total_effect_interval[2], (0.0, 0.0));
+    }
+
+    #[test]
+    fn sobol_indices_works_ishigami() {
+        // Ishigami function: y = sin(x₀) + a sin²(x₁) + b x₂⁴ sin(x₀) with xᵢ ~ U(-π, π)
+        let (a, b) = (7.0, 0.1);
+        let dist = DistributionUniform::new(-PI, PI).unwrap();
+        let distributions: [&dyn ProbabilityDistribution; 3] = [&dist, &dist, &dist];
+        let res = sobol_indices(
+            |x| {
+                let s = f64::sin(x[1]);
+                Ok(f64::sin(x[0]) + a * s * s + b * f64::powi(x[2], 4) * f64::sin(x[0]))
+            },
+            &distributions,
+            10000,
+        )
+        .unwrap();
+
+        // analytical values
+        let pi4 = f64::powi(PI, 4);
+        let v1 = 0.5 * f64::powi(1.0 + b * pi4 / 5.0, 2);
+        let v2 = a * a / 8.0;
+        let v13 = b * b * pi4 * pi4 * (1.0 / 18.0 - 1.0 / 50.0);
+        let v = v1 + v2 + v13;
+        let first_order = [v1 / v, v2 / v, 0.0];
+        let total_effect = [(v1 + v13) / v, v2 / v, v13 / v];
+        for i in 0..3 {
+            assert!(f64::abs(res.first_order[i] - first_order[i]) < 0.04);
+            assert!(f64::abs(res.total_effect[i] - total_effect[i]) < 0.04);
+            let (lower, upper) = res.total_effect_interval[i];
+            assert!(lower < total_effect[i] && total_effect[i] < upper);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use crate::StrError;
+use russell_lab::math::{beta_inc, erfc, SQRT_2};
+
+/// Holds the results of a statistical (hypothesis) test
+#[derive(Clone, Copy, Debug)]
+pub struct TestResult {
+    /// The test statistic
+    pub statistic: f64,
+
+    /// The (two-sided) p-value; i.e., the probability of a statistic at least as extreme as the
+    /// observed one under the null hypothesis
+    pub p_value: f64,
+}
+
+/// Performs the Welch's t-test for the equality of the means of two samples
+///
+/// The samples are assumed to come from normal populations with possibly different variances:
+///
+/// ```text
+///           x̄ - ȳ                    (s²ₓ/nₓ + s²ᵧ/nᵧ)²
+/// t = ———————————————     ν = ———————————————————————————————
+///     √(s²ₓ/nₓ + s²ᵧ/nᵧ)        (s²ₓ/nₓ)²/(nₓ-1) + (s²ᵧ/nᵧ)²/(nᵧ-1)
+/// ```
+///
+/// The p-value is computed from the Student's t distribution with `ν` degrees of freedom.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{welch_t_test, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+///     let y = [6.0, 7.0, 8.0, 9.0, 10.0];
+///     let res = welch_t_test(&x, &y)?;
+///     assert_eq!(res.statistic, -5.0);
+///     assert_eq!(format!("{:.6}", res.p_value), "0.001053");
+///     Ok(())
+/// }
+/// ```
+pub fn welch_t_test(x: &[f64], y: &[f64]) -> Result<TestResult, StrError> {
+    if x.len() < 2 || y.len() < 2 {
+        return Err("samples must have at least two values each");
+    }
+    let (mx, vx) = mean_and_variance(x);
+    let (my, vy) = mean_and_variance(y);
+    let (ax, ay) = (vx / (x.len() as f64), vy / (y.len() as f64));
+    let se2 = ax + ay;
+    if se2 == 0.0 {
+        return Err("the variance of both samples is zero");
+    }
+    let t = (mx - my) / f64::sqrt(se2);
+    let dof = se2 * se2 / (ax * ax / ((x.len() - 1) as f64) + ay * ay / ((y.len() - 1) as f64));
+    let p_value = beta_inc(0.5 * dof, 0.5, dof / (dof + t * t));
+    Ok(TestResult { statistic: t, p_value })
+}
+
+/// Performs the Mann-Whitney U test (Wilcoxon rank-sum test)
+///
+/// This non-parametric test checks whether the values of one sample tend to be larger than the
+/// values of the other sample. The statistic is the U of the first sample:
+///
+/// ```text
+/// U = Rₓ - nₓ (nₓ + 1) / 2
+/// ```
+///
+/// where `Rₓ` is the sum of the ranks of `x` in the combined sample (ties get the average rank).
+/// The p-value is computed by the normal approximation with continuity and tie corrections;
+/// thus, it is accurate for samples with more than about 10 values each.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{mann_whitney_u_test, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = [1.1, 2.3, 2.9, 4.0, 5.2, 3.3, 1.8, 2.2, 4.1, 3.0];
+///     let y = [3.5, 4.4, 5.0, 6.1, 4.8, 5.9, 3.9, 6.6, 5.3, 4.2];
+///     let res = mann_whitney_u_test(&x, &y)?;
+///     assert_eq!(res.statistic, 10.0);
+///     assert!(res.p_value < 0.01);
+///     Ok(())
+/// }
+/// ```
+pub fn mann_whitney_u_test(x: &[f64], y: &[f64]) -> Result<TestResult, StrError> {
+    if x.is_empty() || y.is_empty() {
+        return Err("samples must not be empty");
+    }
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let n = nx + ny;
+
+    // ranks of the combined sample (average ranks for ties)
+    let mut combined: Vec<(f64, bool)> = x
+        .iter()
+        .map(|&v| (v, true))
+        .chain(y.iter().map(|&v| (v, false)))
+        .collect();
+    combined.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut rank_sum_x = 0.0;
+    let mut tie_sum = 0.0; // Σ (t³ - t)
+    let mut i = 0;
+    while i < combined.len() {
+        let mut j = i + 1;
+        while j < combined.len() && combined[j].0 == combined[i].0 {
+            j += 1;
+        }
+        let rank = 0.5 * ((i + 1 + j) as f64); // average of the ranks i+1..=j
+        rank_sum_x += rank * (combined[i..j].iter().filter(|c| c.1).count() as f64);
+        let t = (j - i) as f64;
+        tie_sum += t * t * t - t;
+        i = j;
+    }
+    let u = rank_sum_x - nx * (nx + 1.0) / 2.0;
+
+    // normal approximation
+    let mean = nx * ny / 2.0;
+    let variance = nx * ny / 12.0 * ((n + 1.0) - tie_sum / (n * (n - 1.0)));
+    let p_value = if variance > 0.0 {
+        let z = f64::max(f64::abs(u - mean) - 0.5, 0.0) / f64::sqrt(variance);
+        erfc(z / SQRT_2)
+    } else {
+        1.0
+    };
+    Ok(TestResult { statistic: u, p_value })
+}
+
+/// Performs the two-sample Kolmogorov-Smirnov test
+///
+/// This non-parametric test checks whether two samples come from the same (continuous)
+/// distribution. The statistic is the maximum distance between the empirical CDFs:
+///
+/// ```text
+/// D = max |Fₓ(v) - Fᵧ(v)|
+///      v
+/// ```
+///
+/// The p-value is computed from the asymptotic Kolmogorov distribution with Stephens' correction
+/// for small samples:
+///
+/// ```text
+///                  ∞
+/// p = Q(λ) = 2 Σ (-1)ᵏ⁻¹ exp(-2 k² λ²)     with   λ = (√nₑ + 0.12 + 0.11/√nₑ) D
+///                 k=1
+/// ```
+///
+/// where `nₑ = nₓ nᵧ / (nₓ + nᵧ)`.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{ks_two_sample_test, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = [0.61, 0.29, 0.06, 0.59, -1.73, -0.74, 0.51, -0.56, 0.39, 1.64];
+///     let y = [2.20, 1.66, 1.38, 0.20, 0.36, 0.00, 0.96, 1.56, 0.44, 1.50];
+///     let res = ks_two_sample_test(&x, &y)?;
+///     assert_eq!(res.statistic, 0.5);
+///     assert!(res.p_value > 0.05); // not enough evidence (at 5%) of different distributions
+///     Ok(())
+/// }
+/// ```
+pub fn ks_two_sample_test(x: &[f64], y: &[f64]) -> Result<TestResult, StrError> {
+    if x.is_empty() || y.is_empty() {
+        return Err("samples must not be empty");
+    }
+    let mut xs = x.to_vec();
+    let mut ys = y.to_vec();
+    xs.sort_by(|a, b| a.total_cmp(b));
+    ys.sort_by(|a, b| a.total_cmp(b));
+    let (nx, ny) = (xs.len() as f64, ys.len() as f64);
+    let (mut i, mut j) = (0, 0);
+    let mut d: f64 = 0.0;
+    while i < xs.len() && j < ys.len() {
+        // advance past all values equal to v on both sides (ties) before updating d
+        let v = f64::min(xs[i], ys[j]);
+        while i < xs.len() && xs[i] == v {
+            i += 1;
+        }
+        while j < ys.len() && ys[j] == v {
+            j += 1;
+        }
+        d = f64::max(d, f64::abs((i as f64) / nx - (j as f64) / ny));
+    }
+    let sqrt_ne = f64::sqrt(nx * ny / (nx + ny));
+    let lambda = (sqrt_ne + 0.12 + 0.11 / sqrt_ne) * d;
+    Ok(TestResult {
+        statistic: d,
+        p_value: kolmogorov_q(lambda),
+    })
+}
+
+/// Computes the mean and the (unbiased) variance of a sample
+fn mean_and_variance(x: &[f64]) -> (f64, f64) {
+    let n = x.len() as f64;
+    let mean = x.iter().sum::<f64>() / n;
+    let variance = x.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+/// Computes the complementary CDF of the Kolmogorov distribution
+fn kolmogorov_q(lambda: f64) -> f64 {
+    if lambda < 0.2 {
+        return 1.0; // the series converges slowly here, where Q(λ) ≈ 1
+    }
+    let a = -2.0 * lambda * lambda;
+    let mut sum = 0.0;
+    let mut sign = 1.0;
+    for k in 1..=100 {
+        let kf = k as f64;
+        let term = sign * f64::exp(a * kf * kf);
+        sum += term;
+        if f64::abs(term) <= 1e-16 * f64::abs(sum) {
+            break;
+        }
+        sign = -sign;
+    }
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{kolmogorov_q, ks_two_sample_test, mann_whitney_u_test, welch_t_test};
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn tests_capture_errors() {
+        assert_eq!(
+            welch_t_test(&[1.0], &[1.0, 2.0]).err(),
+            Some("samples must have at least two values each")
+        );
+        assert_eq!(
+            welch_t_test(&[1.0, 1.0], &[2.0, 2.0]).err(),
+            Some("the variance of both samples is zero")
+        );
+        assert_eq!(
+            mann_whitney_u_test(&[], &[1.0]).err(),
+            Some("samples must not be empty")
+        );
+        assert_eq!(ks_two_sample_test(&[1.0], &[]).err(), Some("samples must not be empty"));
+    }
+
+    #[test]
+    fn welch_t_test_works() {
+        // equal variances and sizes: t = -5 and ν = 8
+        let res = welch_t_test(&[1.0, 2.0, 3.0, 4.0, 5.0], &[6.0, 7.0, 8.0, 9.0, 10.0]).unwrap();
+        assert_eq!(res.statistic, -5.0);
+        approx_eq(res.p_value, 0.00105282579337, 1e-12);
+
+        // y with zero variance and s²ₓ = 4, nₓ = 3: ν = 2 and p = 1 - |t|/√(2 + t²)
+        let res = welch_t_test(&[-1.0, 1.0, 3.0], &[0.0, 0.0]).unwrap();
+        let t = 1.0 / f64::sqrt(4.0 / 3.0);
+        approx_eq(res.statistic, t, 1e-15);
+        approx_eq(res.p_value, 1.0 - t / f64::sqrt(2.0 + t * t), 1e-14);
+
+        // identical samples
+        let res = welch_t_test(&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0]).unwrap();
+        assert_eq!(res.statistic, 0.0);
+        assert_eq!(res.p_value, 1.0);
+    }
+
+    #[test]
+    fn mann_whitney_u_test_works() {
+        // complete separation: U = 0, z = (12.5 - 0.5) / √(25⋅11/12)
+        let res = mann_whitney_u_test(&[1.0, 2.0, 3.0, 4.0, 5.0], &[6.0, 7.0, 8.0, 9.0, 10.0]).unwrap();
+        assert_eq!(res.statistic, 0.0);
+        approx_eq(res.p_value, 0.0121857803553, 1e-12);
+        let res = mann_whitney_u_test(&[6.0, 7.0, 8.0, 9.0, 10.0], &[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert_eq!(res.statistic, 25.0);
+        approx_eq(res.p_value, 0.0121857803553, 1e-12);
+
+        // ties: ranks of [1, 2, 2, 3, 3, 3] are [1, 2.5, 2.5, 5, 5, 5]
+        let res = mann_whitney_u_test(&[1.0, 2.0, 3.0], &[2.0, 3.0, 3.0]).unwrap();
+        assert_eq!(res.statistic, (1.0 + 2.5 + 5.0) - 6.0);
+        let variance: f64 = 9.0 / 12.0 * (7.0 - (6.0 + 24.0) / 30.0);
+        let z = (f64::abs(2.5 - 4.5) - 0.5) / f64::sqrt(variance);
+        approx_eq(res.p_value, russell_lab::math::erfc(z / f64::sqrt(2.0)), 1e-15);
+
+        // all values equal
+        let res = mann_whitney_u_test(&[1.0, 1.0], &[1.0, 1.0]).unwrap();
+        assert_eq!(res.statistic, 2.0);
+        assert_eq!(res.p_value, 1.0);
+    }
+
+    #[test]
+    fn ks_two_sample_test_works() {
+        let res = ks_two_sample_test(&[1.0, 2.0, 3.0, 4.0, 5.0], &[6.0, 7.0, 8.0, 9.0, 10.0]).unwrap();
+        assert_eq!(res.statistic, 1.0);
+        approx_eq(
+            res.p_value,
+            kolmogorov_q(f64::sqrt(2.5) + 0.12 + 0.11 / f64::sqrt(2.5)),
+            1e-15,
+        );
+        approx_eq(res.p_value, 0.00378135405937, 1e-12);
+
+        // identical samples (with ties)
+        let res = ks_two_sample_test(&[1.0, 2.0, 2.0, 3.0], &[3.0, 2.0, 1.0, 2.0]).unwrap();
+        assert_eq!(res.statistic, 0.0);
+        assert_eq!(res.p_value, 1.0);
+
+        // tie groups of different sizes
+        let res = ks_two_sample_test(&[1.0, 1.0], &[1.0]).unwrap();
+        assert_eq!(res.statistic, 0.0);
+        assert_eq!(res.p_value, 1.0);
+        let res = ks_two_sample_test(&[1.0, 1.0], &[1.0, 1.0, 1.0]).unwrap();
+        assert_eq!(res.statistic, 0.0);
+        let res = ks_two_sample_test(&[1.0, 2.0, 2.0, 2.0], &[2.0, 3.0]).unwrap();
+        assert_eq!(res.statistic, 0.5); // at v = 2: |4/4 - 1/2|
+
+        // different sizes: D = max |i/3 - j/2|
+        let res = ks_two_sample_test(&[1.0, 2.0, 3.0], &[2.5, 4.0]).unwrap();
+        approx_eq(res.statistic, 2.0 / 3.0, 1e-15);
+    }
+
+    #[test]
+    fn kolmogorov_q_works() {
+        assert_eq!(kolmogorov_q(0.0), 1.0);
+        approx_eq(kolmogorov_q(1.0), 0.269999671677, 1e-12);
+        approx_eq(kolmogorov_q(1.36), 0.0494858, 1e-6); // 5% critical value ≈ 1.358
+        assert!(kolmogorov_q(10.0) < 1e-80);
+    }
+}
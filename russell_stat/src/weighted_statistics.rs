@@ -0,0 +1,319 @@
+use crate::StrError;
+
+/// Calculates the weighted mean of a dataset
+///
+/// ```text
+///      Σ wᵢ xᵢ
+/// μ = ───────
+///       Σ wᵢ
+/// ```
+///
+/// The weights are non-negative and do not need to be normalized (e.g., importance-sampling weights).
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{weighted_mean, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let mean = weighted_mean(&[1.0, 2.0, 3.0], &[1.0, 1.0, 2.0])?;
+///     assert_eq!(mean, 2.25);
+///     Ok(())
+/// }
+/// ```
+pub fn weighted_mean<T>(x: &[T], w: &[f64]) -> Result<f64, StrError>
+where
+    T: Into<f64> + Copy,
+{
+    let sum_w = check_weights(x.len(), w)?;
+    let sum = x.iter().zip(w).fold(0.0, |acc, (&xi, &wi)| acc + wi * xi.into());
+    Ok(sum / sum_w)
+}
+
+/// Calculates the (unbiased) weighted variance of a dataset
+///
+/// The weights are interpreted as reliability (e.g., importance-sampling) weights:
+///
+/// ```text
+///        Σ wᵢ (xᵢ - μ)²
+/// σ² = ─────────────────    with   V₁ = Σ wᵢ   and   V₂ = Σ wᵢ²
+///       V₁ - V₂ / V₁
+/// ```
+///
+/// where `μ` is the weighted mean. With equal weights, this is the sample variance
+/// with Bessel's correction. The variance is zero if only one weight is positive.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{weighted_variance, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     // equal weights (same as the sample variance)
+///     let var = weighted_variance(&[2, 4, 4, 4, 5, 5, 7, 9], &[0.5; 8])?;
+///     assert_eq!(var, 32.0 / 7.0);
+///     Ok(())
+/// }
+/// ```
+pub fn weighted_variance<T>(x: &[T], w: &[f64]) -> Result<f64, StrError>
+where
+    T: Into<f64> + Copy,
+{
+    let mean = weighted_mean(x, w)?;
+    let mut v1 = 0.0;
+    let mut v2 = 0.0;
+    let mut sum = 0.0;
+    for (&xi, &wi) in x.iter().zip(w) {
+        let diff = xi.into() - mean;
+        v1 += wi;
+        v2 += wi * wi;
+        sum += wi * diff * diff;
+    }
+    let den = v1 - v2 / v1;
+    if den <= 0.0 {
+        return Ok(0.0);
+    }
+    Ok(sum / den)
+}
+
+/// Calculates the effective sample size of a set of weights (Kish's formula)
+///
+/// ```text
+///         (Σ wᵢ)²
+/// n_eff = ────────
+///          Σ wᵢ²
+/// ```
+///
+/// With equal weights, `n_eff` equals the number of samples. A small `n_eff` indicates that
+/// a few samples dominate an importance-sampling estimate.
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{effective_sample_size, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     assert_eq!(effective_sample_size(&[2.0, 2.0, 2.0, 2.0])?, 4.0);
+///     assert_eq!(effective_sample_size(&[1.0, 0.0, 0.0, 0.0])?, 1.0);
+///     Ok(())
+/// }
+/// ```
+pub fn effective_sample_size(w: &[f64]) -> Result<f64, StrError> {
+    let sum_w = check_weights(w.len(), w)?;
+    let sum_w2 = w.iter().fold(0.0, |acc, wi| acc + wi * wi);
+    Ok(sum_w * sum_w / sum_w2)
+}
+
+/// Calculates the weighted quantile of a dataset
+///
+/// The samples are sorted and each one is located at the midpoint of its (normalized) cumulative weight:
+///
+/// ```text
+///      Σⱼ<ₖ wⱼ + wₖ / 2
+/// pₖ = ────────────────
+///           Σ wᵢ
+/// ```
+///
+/// The quantile is linearly interpolated between these points and clamped to the smallest/largest
+/// sample outside `[p₀, pₙ₋₁]`. With equal weights, this is the (Hazen) definition `pₖ = (k + ½) / n`.
+/// Samples with zero weight are ignored.
+///
+/// # Input
+///
+/// * `x` -- the samples
+/// * `w` -- the (non-negative) weights
+/// * `p` -- the probability level in `[0, 1]` (e.g., `0.5` for the median)
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{weighted_quantile, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = [3.0, 1.0, 2.0, 4.0];
+///     assert_eq!(weighted_quantile(&x, &[1.0, 1.0, 1.0, 1.0], 0.5)?, 2.5);
+///     assert_eq!(weighted_quantile(&x, &[1.0, 1.0, 1.0, 5.0], 0.5)?, 3.5);
+///     Ok(())
+/// }
+/// ```
+pub fn weighted_quantile<T>(x: &[T], w: &[f64], p: f64) -> Result<f64, StrError>
+where
+    T: Into<f64> + Copy,
+{
+    let sum_w = check_weights(x.len(), w)?;
+    if !(0.0..=1.0).contains(&p) {
+        return Err("p must be in [0, 1]");
+    }
+    let mut pairs: Vec<(f64, f64)> = x
+        .iter()
+        .zip(w)
+        .filter(|(_, &wi)| wi > 0.0)
+        .map(|(&xi, &wi)| (xi.into(), wi))
+        .collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut cumulative = 0.0;
+    let mut prev: Option<(f64, f64)> = None; // (pₖ, xₖ)
+    for &(xk, wk) in &pairs {
+        let pk = (cumulative + 0.5 * wk) / sum_w;
+        cumulative += wk;
+        if p <= pk {
+            return Ok(match prev {
+                None => xk,
+                Some((pj, xj)) => xj + (xk - xj) * (p - pj) / (pk - pj),
+            });
+        }
+        prev = Some((pk, xk));
+    }
+    Ok(pairs[pairs.len() - 1].0)
+}
+
+/// Calculates the trimmed mean of a dataset
+///
+/// Discards the `⌊proportion ⋅ n⌋` smallest and largest samples and averages the remaining ones.
+/// Thus, the trimmed mean is robust with respect to outliers (`proportion = 0` gives the mean and
+/// `proportion → 0.5` approaches the median).
+///
+/// # Input
+///
+/// * `x` -- the samples (at least one)
+/// * `proportion` -- the proportion to cut from each end in `[0, 0.5)`
+///
+/// # Example
+///
+/// ```
+/// use russell_stat::{trimmed_mean, StrError};
+///
+/// fn main() -> Result<(), StrError> {
+///     let x = [1.0, 2.0, 3.0, 4.0, 100.0];
+///     assert_eq!(trimmed_mean(&x, 0.2)?, 3.0);
+///     Ok(())
+/// }
+/// ```
+pub fn trimmed_mean<T>(x: &[T], proportion: f64) -> Result<f64, StrError>
+where
+    T: Into<f64> + Copy,
+{
+    if x.is_empty() {
+        return Err("x must not be empty");
+    }
+    if !(0.0..0.5).contains(&proportion) {
+        return Err("proportion must be in [0, 0.5)");
+    }
+    let mut sorted: Vec<f64> = x.iter().map(|&xi| xi.into()).collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let cut = f64::floor(proportion * (sorted.len() as f64)) as usize;
+    let kept = &sorted[cut..sorted.len() - cut];
+    Ok(kept.iter().sum::<f64>() / (kept.len() as f64))
+}
+
+/// Checks the weights and returns their sum
+fn check_weights(n: usize, w: &[f64]) -> Result<f64, StrError> {
+    if w.len() != n {
+        return Err("x and w must have the same length");
+    }
+    let mut sum = 0.0;
+    for &wi in w {
+        if wi < 0.0 || wi.is_nan() {
+            return Err("weights must be non-negative");
+        }
+        sum += wi;
+    }
+    if sum <= 0.0 || !sum.is_finite() {
+        return Err("the sum of weights must be positive and finite");
+    }
+    Ok(sum)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{effective_sample_size, trimmed_mean, weighted_mean, weighted_quantile, weighted_variance};
+    use crate::statistics;
+    use russell_chk::approx_eq;
+
+    #[test]
+    fn weighted_functions_capture_errors() {
+        let x = [1.0, 2.0];
+        assert_eq!(
+            weighted_mean(&x, &[1.0]).err(),
+            Some("x and w must have the same length")
+        );
+        assert_eq!(
+            weighted_variance(&x, &[1.0, -1.0]).err(),
+            Some("weights must be non-negative")
+        );
+        assert_eq!(
+            effective_sample_size(&[0.0, 0.0]).err(),
+            Some("the sum of weights must be positive and finite")
+        );
+        assert_eq!(
+            weighted_quantile(&x, &[1.0, 1.0], 1.1).err(),
+            Some("p must be in [0, 1]")
+        );
+        let empty: [f64; 0] = [];
+        assert_eq!(trimmed_mean(&empty, 0.1).err(), Some("x must not be empty"));
+        assert_eq!(trimmed_mean(&x, 0.5).err(), Some("proportion must be in [0, 0.5)"));
+    }
+
+    #[test]
+    fn weighted_mean_and_variance_work() {
+        // equal weights match the unweighted statistics
+        let x = [100, 100, 102, 98, 77, 99, 70, 105, 98];
+        let w = [3.0; 9];
+        let res = statistics(&x);
+        approx_eq(weighted_mean(&x, &w).unwrap(), res.mean, 1e-13);
+        approx_eq(f64::sqrt(weighted_variance(&x, &w).unwrap()), res.std_dev, 1e-13);
+
+        // integer weights match repeated samples (up to the bias correction)
+        let x = [1.0, 2.0, 4.0];
+        let w = [2.0, 1.0, 1.0];
+        let repeated = [1.0, 1.0, 2.0, 4.0];
+        approx_eq(weighted_mean(&x, &w).unwrap(), statistics(&repeated).mean, 1e-15);
+        let mean = 2.0;
+        let sum = 2.0 * (1.0 - mean) * (1.0 - mean) + (2.0 - mean) * (2.0 - mean) + (4.0 - mean) * (4.0 - mean);
+        approx_eq(weighted_variance(&x, &w).unwrap(), sum / (4.0 - 6.0 / 4.0), 1e-15);
+
+        // a single positive weight
+        assert_eq!(weighted_variance(&x, &[0.0, 1.0, 0.0]).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn effective_sample_size_works() {
+        approx_eq(effective_sample_size(&[1.0, 2.0, 3.0]).unwrap(), 36.0 / 14.0, 1e-15);
+    }
+
+    #[test]
+    fn weighted_quantile_works() {
+        let x = [3.0, 1.0, 2.0, 4.0];
+        let w = [1.0, 1.0, 1.0, 1.0];
+        // p = 0.125, 0.375, 0.625, 0.875 at x = 1, 2, 3, 4
+        assert_eq!(weighted_quantile(&x, &w, 0.0).unwrap(), 1.0);
+        assert_eq!(weighted_quantile(&x, &w, 0.125).unwrap(), 1.0);
+        assert_eq!(weighted_quantile(&x, &w, 0.25).unwrap(), 1.5);
+        assert_eq!(weighted_quantile(&x, &w, 0.875).unwrap(), 4.0);
+        assert_eq!(weighted_quantile(&x, &w, 1.0).unwrap(), 4.0);
+
+        // zero weights are ignored
+        let w = [1.0, 1.0, 0.0, 0.0];
+        assert_eq!(weighted_quantile(&x, &w, 0.5).unwrap(), 2.0);
+        assert_eq!(weighted_quantile(&x, &w, 0.25).unwrap(), 1.0);
+
+        // a heavy sample dominates
+        let w = [1.0, 1.0, 1.0, 97.0];
+        assert_eq!(weighted_quantile(&x, &w, 0.6).unwrap(), 4.0);
+        let q = weighted_quantile(&x, &w, 0.02).unwrap();
+        approx_eq(q, 2.5, 1e-14);
+    }
+
+    #[test]
+    fn trimmed_mean_works() {
+        assert_eq!(trimmed_mean(&[5], 0.4).unwrap(), 5.0);
+        assert_eq!(trimmed_mean(&[1, 2, 3, 4], 0.0).unwrap(), 2.5);
+        assert_eq!(trimmed_mean(&[1, 2, 3, 4], 0.3).unwrap(), 2.5);
+        assert_eq!(
+            trimmed_mean(&[-50.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 60.0], 0.1).unwrap(),
+            4.5
+        );
+    }
+}
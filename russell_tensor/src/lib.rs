@@ -5,6 +5,9 @@
 /// Defines a type alias for the error type as a static string
 pub type StrError = &'static str;
 
+/// Defines a typed error with context data (convertible from/into StrError)
+pub use russell_lab::RussellError;
+
 mod constants;
 mod lin_elasticity;
 mod operations;